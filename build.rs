@@ -109,9 +109,17 @@ fn main() {
         println!(
             "cargo:rerun-if-changed=proto/opentelemetry/proto/collector/logs/v1/logs_service.proto"
         );
+        println!(
+            "cargo:rerun-if-changed=proto/opentelemetry/proto/collector/metrics/v1/metrics_service.proto"
+        );
+        println!(
+            "cargo:rerun-if-changed=proto/opentelemetry/proto/collector/trace/v1/trace_service.proto"
+        );
         println!("cargo:rerun-if-changed=proto/opentelemetry/proto/common/v1/common.proto");
         println!("cargo:rerun-if-changed=proto/opentelemetry/proto/logs/v1/logs.proto");
+        println!("cargo:rerun-if-changed=proto/opentelemetry/proto/metrics/v1/metrics.proto");
         println!("cargo:rerun-if-changed=proto/opentelemetry/proto/resource/v1/resource.proto");
+        println!("cargo:rerun-if-changed=proto/opentelemetry/proto/trace/v1/trace.proto");
 
         let mut prost_build = prost_build::Config::new();
         prost_build.btree_map(&["."]);
@@ -129,6 +137,8 @@ fn main() {
                     "proto/google/rpc/status.proto",
                     "proto/vector.proto",
                     "proto/opentelemetry/proto/collector/logs/v1/logs_service.proto",
+                    "proto/opentelemetry/proto/collector/metrics/v1/metrics_service.proto",
+                    "proto/opentelemetry/proto/collector/trace/v1/trace_service.proto",
                 ],
                 &["proto/", "lib/vector-core/proto/"],
             )
@@ -0,0 +1,18 @@
+fn main() {
+    println!(
+        "cargo:rerun-if-changed=../proto/opentelemetry/proto/collector/logs/v1/logs_service.proto"
+    );
+    println!("cargo:rerun-if-changed=../proto/opentelemetry/proto/common/v1/common.proto");
+    println!("cargo:rerun-if-changed=../proto/opentelemetry/proto/logs/v1/logs.proto");
+    println!("cargo:rerun-if-changed=../proto/opentelemetry/proto/resource/v1/resource.proto");
+
+    // Configured like the build of Vector, so that the messages decode the same way.
+    let mut prost_build = prost_build::Config::new();
+    prost_build.btree_map(&["."]);
+    prost_build
+        .compile_protos(
+            &["../proto/opentelemetry/proto/collector/logs/v1/logs_service.proto"],
+            &["../proto/"],
+        )
+        .unwrap();
+}
@@ -0,0 +1,18 @@
+//! Decodes arbitrary bytes as the body of an OTLP log export request, as received by the
+//! `opentelemetry` source.
+//!
+//! Decoding must never panic: invalid input is rejected with a `prost::DecodeError`.
+#![cfg_attr(fuzzing, no_main)]
+
+#[cfg(fuzzing)]
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    use prost::Message;
+    use vector_fuzz::opentelemetry::proto::collector::logs::v1::ExportLogsServiceRequest;
+
+    drop(ExportLogsServiceRequest::decode(data));
+});
+
+#[cfg(not(fuzzing))]
+fn main() {
+    eprintln!("This fuzz target must be run with `cargo fuzz run otlp_proto_parse`.");
+}
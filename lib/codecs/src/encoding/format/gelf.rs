@@ -1,9 +1,15 @@
 use crate::{gelf_fields::*, VALID_FIELD_REGEX};
-use bytes::{BufMut, BytesMut};
-use lookup::path;
+use bytes::{BufMut, Bytes, BytesMut};
+use derivative::Derivative;
+use flate2::{write::ZlibEncoder, Compression};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use snafu::Snafu;
+use std::io::Write;
 use tokio_util::codec::Encoder;
+use value::Value;
+use vector_config::configurable_component;
 use vector_core::{
     config::{log_schema, DataType},
     event::Event,
@@ -19,6 +25,22 @@ use vector_core::{
 ///   The exception is that if 'Additional fields' are found to be missing an underscore prefix and
 ///   are otherwise valid field names, we prepend the underscore.
 
+/// The additional field reserved by Graylog for the ID of stored messages.
+const RESERVED_ID_FIELD: &str = "_id";
+
+/// The magic bytes that start each chunk of a chunked GELF message.
+const CHUNK_MAGIC_BYTES: [u8; 2] = [0x1e, 0x0f];
+
+/// The length of the header of each chunk: the magic bytes, an 8 byte message ID, and the sequence
+/// number and count of the chunk.
+const CHUNK_HEADER_LEN: usize = 12;
+
+/// The maximum number of chunks of a GELF message.
+const MAX_CHUNKS: usize = 128;
+
+/// Matches the characters that aren't valid in field names, the complement of `VALID_FIELD_REGEX`.
+static INVALID_FIELD_CHARS_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^\w\.\-]").unwrap());
+
 /// Errors that can occur during GELF serialization.
 #[derive(Debug, Snafu)]
 pub enum GelfSerializerError {
@@ -37,21 +59,57 @@ pub enum GelfSerializerError {
     },
     #[snafu(display(r#"LogEvent contains an invalid field name. field = "{}""#, field))]
     InvalidFieldName { field: String },
+    #[snafu(display(r#"LogEvent contains a reserved field name. field = "{}""#, field))]
+    ReservedFieldName { field: String },
+}
+
+/// Errors that can occur during GELF chunking.
+#[derive(Debug, Snafu)]
+pub enum GelfChunkingError {
+    /// The message could not be compressed.
+    #[snafu(display("Failed to compress GELF message: {}", source))]
+    Compression {
+        /// The compression error.
+        source: std::io::Error,
+    },
+    /// The message is too large to be chunked.
+    #[snafu(display(
+        "GELF message of {} bytes needs more than {} chunks of {} bytes",
+        message_len,
+        MAX_CHUNKS,
+        chunk_size
+    ))]
+    TooManyChunks {
+        /// The size of the message, after compression.
+        message_len: usize,
+        /// The size of the chunks.
+        chunk_size: usize,
+    },
 }
 
 /// Config used to build a `GelfSerializer`.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
-pub struct GelfSerializerConfig;
+pub struct GelfSerializerConfig {
+    /// Options for the GELF serializer.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub gelf: GelfSerializerOptions,
+}
 
 impl GelfSerializerConfig {
     /// Creates a new `GelfSerializerConfig`.
-    pub const fn new() -> Self {
-        Self
+    pub const fn new(gelf: GelfSerializerOptions) -> Self {
+        Self { gelf }
     }
 
     /// Build the `GelfSerializer` from this configuration.
     pub fn build(&self) -> GelfSerializer {
-        GelfSerializer::new()
+        GelfSerializer::new(
+            self.gelf.flatten_separator.clone(),
+            self.gelf.invalid_field_names,
+        )
     }
 
     /// The data type of events that are accepted by `GelfSerializer`.
@@ -67,28 +125,218 @@ impl GelfSerializerConfig {
     }
 }
 
+/// Options for building a `GelfSerializer`.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+pub struct GelfSerializerOptions {
+    /// The separator joining the names of nested fields flattened into additional fields.
+    ///
+    /// For example, the `user.id` field is sent as the `_user_id` additional field with the default
+    /// separator.
+    #[serde(default = "default_flatten_separator")]
+    #[derivative(Default(value = "default_flatten_separator()"))]
+    pub flatten_separator: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub invalid_field_names: GelfInvalidFieldNames,
+
+    /// The maximum size, in bytes, of the datagrams GELF messages are sent in over UDP.
+    ///
+    /// Larger messages are split into GELF chunks of this size, up to 128 chunks per message. Not
+    /// used by other transports.
+    #[serde(default = "default_chunk_size")]
+    #[derivative(Default(value = "default_chunk_size()"))]
+    pub chunk_size: usize,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub compression: GelfCompression,
+}
+
+fn default_flatten_separator() -> String {
+    "_".to_owned()
+}
+
+const fn default_chunk_size() -> usize {
+    8192
+}
+
+impl GelfSerializerOptions {
+    /// Build the `GelfChunker` splitting the messages sent over UDP.
+    pub fn chunker(&self) -> Result<GelfChunker, crate::encoding::BuildError> {
+        if self.chunk_size <= CHUNK_HEADER_LEN {
+            return Err(format!(
+                "GELF `chunk_size` must be larger than the {} bytes of the chunk header",
+                CHUNK_HEADER_LEN
+            )
+            .into());
+        }
+        Ok(GelfChunker::new(self.chunk_size, self.compression))
+    }
+}
+
+/// How additional fields with invalid names are handled.
+///
+/// Field names must only contain word characters, periods and dashes, and `_id` is reserved.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GelfInvalidFieldNames {
+    /// Events with invalid field names fail to encode.
+    #[derivative(Default)]
+    Error,
+
+    /// The invalid characters of field names are replaced with underscores, and the reserved `_id`
+    /// field is sent as `__id`.
+    Sanitize,
+}
+
+/// The compression of GELF messages sent over UDP.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GelfCompression {
+    /// Messages are sent uncompressed.
+    #[derivative(Default)]
+    None,
+
+    /// Messages are compressed with zlib before they are chunked.
+    Zlib,
+}
+
 /// Serializer that converts an `Event` to bytes using the GELF format.
 /// Spec: https://docs.graylog.org/docs/gelf
 #[derive(Debug, Clone)]
-pub struct GelfSerializer;
+pub struct GelfSerializer {
+    flatten_separator: String,
+    invalid_field_names: GelfInvalidFieldNames,
+}
 
 impl GelfSerializer {
     /// Creates a new `GelfSerializer`.
-    pub fn new() -> Self {
-        GelfSerializer
+    pub const fn new(
+        flatten_separator: String,
+        invalid_field_names: GelfInvalidFieldNames,
+    ) -> Self {
+        GelfSerializer {
+            flatten_separator,
+            invalid_field_names,
+        }
     }
 
     /// Encode event and represent it as JSON value.
     pub fn to_json_value(&self, event: Event) -> Result<serde_json::Value, vector_core::Error> {
         // input_type() restricts the event type to LogEvents
-        let log = to_gelf_event(event.into_log())?;
+        let log = self.to_gelf_event(event.into_log())?;
         serde_json::to_value(&log).map_err(|e| e.to_string().into())
     }
+
+    /// Validate if the input log event is valid GELF, potentially coercing the event into valid GELF.
+    fn to_gelf_event(&self, log: LogEvent) -> vector_core::Result<LogEvent> {
+        coerce_required_fields(log).and_then(|log| self.coerce_field_names_and_values(log))
+    }
+
+    /// Validates rules for field names and value types, coercing in some cases.
+    ///
+    /// Nested fields are flattened into additional fields.
+    fn coerce_field_names_and_values(&self, mut log: LogEvent) -> vector_core::Result<LogEvent> {
+        if let Some(event_data) = log.as_map_mut() {
+            let mut additional_fields = vec![];
+            for (field, value) in std::mem::take(event_data) {
+                match field.as_str() {
+                    VERSION | HOST | SHORT_MESSAGE | FULL_MESSAGE | FACILITY | FILE => {
+                        if !value.is_bytes() {
+                            err_invalid_type(&field, "UTF-8 string", value.kind_str())?;
+                        }
+                    }
+                    TIMESTAMP => {
+                        if !(value.is_timestamp() || value.is_integer()) {
+                            err_invalid_type(&field, "timestamp or integer", value.kind_str())?;
+                        }
+                    }
+                    LEVEL => {
+                        if !value.is_integer() {
+                            err_invalid_type(&field, "integer", value.kind_str())?;
+                        }
+                    }
+                    LINE => {
+                        if !(value.is_float() || value.is_integer()) {
+                            err_invalid_type(&field, "number", value.kind_str())?;
+                        }
+                    }
+                    _ => {
+                        flatten_field(
+                            field,
+                            value,
+                            &self.flatten_separator,
+                            &mut additional_fields,
+                        );
+                        continue;
+                    }
+                }
+                event_data.insert(field, value);
+            }
+
+            for (field, value) in additional_fields {
+                let field = self.additional_field_name(field)?;
+
+                // additional field values must be only strings or numbers
+                if !(value.is_integer() || value.is_float() || value.is_bytes()) {
+                    err_invalid_type(&field, "string or number", value.kind_str())?;
+                }
+                event_data.insert(field, value);
+            }
+        }
+        Ok(log)
+    }
+
+    /// Coerces the name of an additional field into a valid one.
+    fn additional_field_name(&self, field: String) -> vector_core::Result<String> {
+        // additional fields must be only word chars, dashes and periods.
+        let field = if VALID_FIELD_REGEX.is_match(&field) {
+            field
+        } else {
+            match self.invalid_field_names {
+                GelfInvalidFieldNames::Error => {
+                    return InvalidFieldNameSnafu { field }
+                        .fail()
+                        .map_err(|e| e.to_string().into())
+                }
+                GelfInvalidFieldNames::Sanitize => INVALID_FIELD_CHARS_REGEX
+                    .replace_all(&field, "_")
+                    .into_owned(),
+            }
+        };
+
+        // Additional fields must be prefixed with underscores.
+        // Prepending the underscore since vector adds fields such as 'source_type'
+        // which would otherwise throw errors.
+        let field = if field.is_empty() || field.starts_with('_') {
+            field
+        } else {
+            format!("_{}", field)
+        };
+
+        if field == RESERVED_ID_FIELD {
+            match self.invalid_field_names {
+                GelfInvalidFieldNames::Error => ReservedFieldNameSnafu { field }
+                    .fail()
+                    .map_err(|e| e.to_string().into()),
+                GelfInvalidFieldNames::Sanitize => Ok(format!("_{}", field)),
+            }
+        } else {
+            Ok(field)
+        }
+    }
 }
 
 impl Default for GelfSerializer {
     fn default() -> Self {
-        Self::new()
+        GelfSerializerConfig::default().build()
     }
 }
 
@@ -96,13 +344,78 @@ impl Encoder<Event> for GelfSerializer {
     type Error = vector_core::Error;
 
     fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
-        let log = to_gelf_event(event.into_log())?;
+        let log = self.to_gelf_event(event.into_log())?;
         let writer = buffer.writer();
         serde_json::to_writer(writer, &log)?;
         Ok(())
     }
 }
 
+/// Splits encoded GELF messages into the chunks of the GELF UDP transport, optionally compressing
+/// them first.
+///
+/// Messages that fit in a single chunk are sent as is, without a chunk header.
+#[derive(Debug, Clone)]
+pub struct GelfChunker {
+    chunk_size: usize,
+    compression: GelfCompression,
+}
+
+impl GelfChunker {
+    /// Creates a new `GelfChunker`.
+    ///
+    /// The chunk size must be larger than the chunk header.
+    pub const fn new(chunk_size: usize, compression: GelfCompression) -> Self {
+        Self {
+            chunk_size,
+            compression,
+        }
+    }
+
+    /// Splits an encoded message into datagrams.
+    pub fn chunk(&self, message: &[u8]) -> Result<Vec<Bytes>, GelfChunkingError> {
+        let message = match self.compression {
+            GelfCompression::None => Bytes::copy_from_slice(message),
+            GelfCompression::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(message)
+                    .and_then(|_| encoder.finish())
+                    .map(Bytes::from)
+                    .map_err(|source| GelfChunkingError::Compression { source })?
+            }
+        };
+        if message.len() <= self.chunk_size {
+            return Ok(vec![message]);
+        }
+
+        let data_size = self.chunk_size - CHUNK_HEADER_LEN;
+        let count = (message.len() + data_size - 1) / data_size;
+        if count > MAX_CHUNKS {
+            return TooManyChunksSnafu {
+                message_len: message.len(),
+                chunk_size: self.chunk_size,
+            }
+            .fail();
+        }
+
+        let message_id: [u8; 8] = rand::random();
+        Ok(message
+            .chunks(data_size)
+            .enumerate()
+            .map(|(sequence, data)| {
+                let mut chunk = BytesMut::with_capacity(CHUNK_HEADER_LEN + data.len());
+                chunk.put_slice(&CHUNK_MAGIC_BYTES);
+                chunk.put_slice(&message_id);
+                chunk.put_u8(sequence as u8);
+                chunk.put_u8(count as u8);
+                chunk.put_slice(data);
+                chunk.freeze()
+            })
+            .collect())
+    }
+}
+
 /// Returns Error for invalid type.
 fn err_invalid_type(
     field: &str,
@@ -148,92 +461,58 @@ fn coerce_required_fields(mut log: LogEvent) -> vector_core::Result<LogEvent> {
     Ok(log)
 }
 
-/// Validates rules for field names and value types, coercing in some cases.
-fn coerce_field_names_and_values(
-    mut log: LogEvent,
-) -> vector_core::Result<(LogEvent, Vec<String>)> {
-    let mut missing_prefix = vec![];
-    if let Some(event_data) = log.as_map_mut() {
-        for (field, value) in event_data.iter_mut() {
-            match field.as_str() {
-                VERSION | HOST | SHORT_MESSAGE | FULL_MESSAGE | FACILITY | FILE => {
-                    if !value.is_bytes() {
-                        err_invalid_type(field, "UTF-8 string", value.kind_str())?;
-                    }
-                }
-                TIMESTAMP => {
-                    if !(value.is_timestamp() || value.is_integer()) {
-                        err_invalid_type(field, "timestamp or integer", value.kind_str())?;
-                    }
-                }
-                LEVEL => {
-                    if !value.is_integer() {
-                        err_invalid_type(field, "integer", value.kind_str())?;
-                    }
-                }
-                LINE => {
-                    if !(value.is_float() || value.is_integer()) {
-                        err_invalid_type(field, "number", value.kind_str())?;
-                    }
-                }
-                _ => {
-                    // additional fields must be only word chars, dashes and periods.
-                    if !VALID_FIELD_REGEX.is_match(field) {
-                        return MissingFieldSnafu { field }
-                            .fail()
-                            .map_err(|e| e.to_string().into());
-                    }
-
-                    // additional field values must be only strings or numbers
-                    if !(value.is_integer() || value.is_float() || value.is_bytes()) {
-                        err_invalid_type(field, "string or number", value.kind_str())?;
-                    }
-
-                    // Additional fields must be prefixed with underscores.
-                    // Prepending the underscore since vector adds fields such as 'source_type'
-                    // which would otherwise throw errors.
-                    if !field.is_empty() && !field.starts_with('_') {
-                        // flag the field as missing prefix to be modified later
-                        missing_prefix.push(field.to_string());
-                    }
-                }
+/// Flattens the objects and arrays of a field into fields named after their path, joined by the
+/// separator.
+fn flatten_field(field: String, value: Value, separator: &str, fields: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                flatten_field(
+                    format!("{}{}{}", field, separator, key),
+                    value,
+                    separator,
+                    fields,
+                );
             }
         }
-    }
-    Ok((log, missing_prefix))
-}
-
-/// Validate if the input log event is valid GELF, potentially coercing the event into valid GELF.
-fn to_gelf_event(log: LogEvent) -> vector_core::Result<LogEvent> {
-    let log = coerce_required_fields(log).and_then(|log| {
-        coerce_field_names_and_values(log).map(|(mut log, missing_prefix)| {
-            // rename additional fields that were flagged as missing the underscore prefix
-            for field in missing_prefix {
-                log.rename_key(path!(field.as_str()), format!("_{}", &field).as_str());
+        Value::Array(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                flatten_field(
+                    format!("{}{}{}", field, separator, index),
+                    value,
+                    separator,
+                    fields,
+                );
             }
-            log
-        })
-    })?;
-
-    Ok(log)
+        }
+        value => fields.push((field, value)),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
-    use crate::encoding::SerializerConfig;
+    use std::io::Read;
+
+    use crate::{
+        decoding::format::{Deserializer, GelfDeserializerConfig},
+        encoding::SerializerConfig,
+    };
 
     use super::*;
-    use value::Value;
+    use flate2::read::ZlibDecoder;
     use vector_common::btreemap;
-    use vector_core::event::{Event, EventMetadata};
+    use vector_core::{
+        config::LogNamespace,
+        event::{Event, EventMetadata},
+    };
 
     fn do_serialize(
         expect_success: bool,
         event_fields: BTreeMap<String, Value>,
     ) -> Option<serde_json::Value> {
-        let config = GelfSerializerConfig::new();
+        let config = GelfSerializerConfig::default();
         let mut serializer = config.build();
         let event: Event = LogEvent::from_map(event_fields, EventMetadata::default()).into();
         let mut buffer = BytesMut::new();
@@ -252,7 +531,11 @@ mod tests {
 
     #[test]
     fn gelf_serde_json_to_value_supported_success() {
-        let serializer = SerializerConfig::Gelf.build().unwrap();
+        let serializer = SerializerConfig::Gelf {
+            gelf: Default::default(),
+        }
+        .build()
+        .unwrap();
 
         let event_fields = btreemap! {
             VERSION => "1.1",
@@ -267,7 +550,11 @@ mod tests {
 
     #[test]
     fn gelf_serde_json_to_value_supported_failure_to_encode() {
-        let serializer = SerializerConfig::Gelf.build().unwrap();
+        let serializer = SerializerConfig::Gelf {
+            gelf: Default::default(),
+        }
+        .build()
+        .unwrap();
         let event_fields = btreemap! {};
         let log_event: Event = LogEvent::from_map(event_fields, EventMetadata::default()).into();
         assert!(serializer.supports_json());
@@ -400,4 +687,209 @@ mod tests {
             do_serialize(false, event_fields);
         }
     }
+
+    fn serialize_with(
+        options: GelfSerializerOptions,
+        event_fields: BTreeMap<String, Value>,
+    ) -> vector_core::Result<serde_json::Value> {
+        let mut serializer = GelfSerializerConfig::new(options).build();
+        let event: Event = LogEvent::from_map(event_fields, EventMetadata::default()).into();
+        let mut buffer = BytesMut::new();
+        serializer.encode(event, &mut buffer)?;
+        Ok(serde_json::from_slice(&buffer).unwrap())
+    }
+
+    #[test]
+    fn gelf_serializing_flattens_nested_fields() {
+        let event_fields = btreemap! {
+            HOST => "example.org",
+            SHORT_MESSAGE => "Some message",
+            "user" => btreemap! {
+                "id" => 42,
+                "name" => "alice",
+            },
+            "tags" => vec!["a", "b"],
+        };
+
+        let jsn = serialize_with(Default::default(), event_fields.clone()).unwrap();
+        assert_eq!(jsn.get("_user_id").unwrap(), 42);
+        assert_eq!(jsn.get("_user_name").unwrap(), "alice");
+        assert_eq!(jsn.get("_tags_0").unwrap(), "a");
+        assert_eq!(jsn.get("_tags_1").unwrap(), "b");
+        assert!(jsn.get("user").is_none());
+
+        let options = GelfSerializerOptions {
+            flatten_separator: ".".to_owned(),
+            ..Default::default()
+        };
+        let jsn = serialize_with(options, event_fields).unwrap();
+        assert_eq!(jsn.get("_user.id").unwrap(), 42);
+        assert_eq!(jsn.get("_tags.1").unwrap(), "b");
+    }
+
+    #[test]
+    fn gelf_serializing_invalid_field_names() {
+        let event_fields = btreemap! {
+            HOST => "example.org",
+            SHORT_MESSAGE => "Some message",
+            "invalid%field" => "foo",
+        };
+        assert!(serialize_with(Default::default(), event_fields.clone()).is_err());
+
+        let sanitize = GelfSerializerOptions {
+            invalid_field_names: GelfInvalidFieldNames::Sanitize,
+            ..Default::default()
+        };
+        let jsn = serialize_with(sanitize.clone(), event_fields).unwrap();
+        assert_eq!(jsn.get("_invalid_field").unwrap(), "foo");
+
+        // The reserved `_id` field, with or without the underscore prefix.
+        for field in ["id", "_id"] {
+            let event_fields = btreemap! {
+                HOST => "example.org",
+                SHORT_MESSAGE => "Some message",
+                field => "foo",
+            };
+            let error = serialize_with(Default::default(), event_fields.clone()).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                r#"LogEvent contains a reserved field name. field = "_id""#
+            );
+
+            let jsn = serialize_with(sanitize.clone(), event_fields).unwrap();
+            assert_eq!(jsn.get("__id").unwrap(), "foo");
+            assert!(jsn.get("_id").is_none());
+        }
+    }
+
+    fn encode_gelf(message: &str) -> BytesMut {
+        let event_fields = btreemap! {
+            HOST => "example.org",
+            SHORT_MESSAGE => message,
+            "_user_id" => 42,
+        };
+        let event: Event = LogEvent::from_map(event_fields, EventMetadata::default()).into();
+        let mut buffer = BytesMut::new();
+        GelfSerializer::default()
+            .encode(event, &mut buffer)
+            .unwrap();
+        buffer
+    }
+
+    /// Reassembles the chunks of a message, in any order, as a GELF server does.
+    fn reassemble(mut chunks: Vec<Bytes>) -> Vec<u8> {
+        if chunks.len() == 1 && !chunks[0].starts_with(&CHUNK_MAGIC_BYTES) {
+            return chunks.remove(0).to_vec();
+        }
+
+        let message_id = &chunks[0][2..10];
+        let count = chunks[0][11];
+        assert_eq!(usize::from(count), chunks.len());
+        for chunk in &chunks {
+            assert_eq!(&chunk[..2], &CHUNK_MAGIC_BYTES);
+            assert_eq!(&chunk[2..10], message_id);
+            assert_eq!(chunk[11], count);
+        }
+        chunks.sort_by_key(|chunk| chunk[10]);
+        chunks
+            .iter()
+            .flat_map(|chunk| chunk[CHUNK_HEADER_LEN..].to_vec())
+            .collect()
+    }
+
+    fn decode_gelf(message: Vec<u8>) -> Event {
+        let mut events = GelfDeserializerConfig
+            .build()
+            .parse(message.into(), LogNamespace::Legacy)
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        events.remove(0)
+    }
+
+    #[test]
+    fn gelf_chunking_round_trip() {
+        let message = "x".repeat(4000);
+        let encoded = encode_gelf(&message);
+        let chunker = GelfChunker::new(1024, GelfCompression::None);
+
+        let mut chunks = chunker.chunk(&encoded).unwrap();
+        assert_eq!(chunks.len(), (encoded.len() + 1011) / 1012);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 1024));
+
+        // Chunks can arrive out of order.
+        chunks.reverse();
+        let event = decode_gelf(reassemble(chunks));
+        let log = event.as_log();
+        assert_eq!(log["message"], message.into());
+        assert_eq!(log["host"], "example.org".into());
+        assert_eq!(log["_user_id"], 42.into());
+    }
+
+    #[test]
+    fn gelf_chunking_compressed_round_trip() {
+        // A message too random to be compressed into a single chunk, generated with xorshift.
+        let mut state = 0x2545_f491_u32;
+        let message = (0..6000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                char::from(b'a' + (state % 26) as u8)
+            })
+            .collect::<String>();
+        let encoded = encode_gelf(&message);
+        let chunker = GelfChunker::new(1024, GelfCompression::Zlib);
+
+        let chunks = chunker.chunk(&encoded).unwrap();
+        assert!(chunks.len() > 1);
+        assert!(chunks.len() < (encoded.len() + 1011) / 1012);
+
+        let mut decompressed = vec![];
+        ZlibDecoder::new(reassemble(chunks).as_slice())
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        let event = decode_gelf(decompressed);
+        assert_eq!(event.as_log()["message"], message.into());
+    }
+
+    #[test]
+    fn gelf_chunking_small_messages() {
+        let encoded = encode_gelf("Some message");
+
+        let chunks = GelfChunker::new(1024, GelfCompression::None)
+            .chunk(&encoded)
+            .unwrap();
+        assert_eq!(chunks, vec![encoded.clone().freeze()]);
+
+        let chunks = GelfChunker::new(1024, GelfCompression::Zlib)
+            .chunk(&encoded)
+            .unwrap();
+        assert_eq!(chunks.len(), 1);
+        let event = decode_gelf({
+            let mut decompressed = vec![];
+            ZlibDecoder::new(&chunks[0][..])
+                .read_to_end(&mut decompressed)
+                .unwrap();
+            decompressed
+        });
+        assert_eq!(event.as_log()["message"], "Some message".into());
+    }
+
+    #[test]
+    fn gelf_chunking_too_many_chunks() {
+        let encoded = encode_gelf(&"x".repeat(200 * 100));
+        let error = GelfChunker::new(100, GelfCompression::None)
+            .chunk(&encoded)
+            .unwrap_err();
+        assert!(matches!(error, GelfChunkingError::TooManyChunks { .. }));
+    }
+
+    #[test]
+    fn gelf_chunk_size_must_exceed_header() {
+        let options = GelfSerializerOptions {
+            chunk_size: CHUNK_HEADER_LEN,
+            ..Default::default()
+        };
+        assert!(options.chunker().is_err());
+    }
 }
@@ -16,7 +16,10 @@ use std::fmt::Debug;
 
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
 use dyn_clone::DynClone;
-pub use gelf::{GelfSerializer, GelfSerializerConfig};
+pub use gelf::{
+    GelfChunker, GelfChunkingError, GelfCompression, GelfInvalidFieldNames, GelfSerializer,
+    GelfSerializerConfig, GelfSerializerOptions,
+};
 pub use json::{JsonSerializer, JsonSerializerConfig};
 pub use logfmt::{LogfmtSerializer, LogfmtSerializerConfig};
 pub use native::{NativeSerializer, NativeSerializerConfig};
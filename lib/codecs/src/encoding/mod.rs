@@ -8,8 +8,9 @@ use std::fmt::Debug;
 
 use bytes::BytesMut;
 pub use format::{
-    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, GelfSerializer,
-    GelfSerializerConfig, JsonSerializer, JsonSerializerConfig, LogfmtSerializer,
+    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, GelfChunker, GelfChunkingError,
+    GelfCompression, GelfInvalidFieldNames, GelfSerializer, GelfSerializerConfig,
+    GelfSerializerOptions, JsonSerializer, JsonSerializerConfig, LogfmtSerializer,
     LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
     NativeSerializerConfig, RawMessageSerializer, RawMessageSerializerConfig, TextSerializer,
     TextSerializerConfig,
@@ -194,7 +195,14 @@ pub enum SerializerConfig {
     },
 
     /// GELF serialization.
-    Gelf,
+    Gelf {
+        /// GELF serializer options.
+        #[serde(
+            default,
+            skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+        )]
+        gelf: GelfSerializerOptions,
+    },
 
     /// JSON serialization.
     Json,
@@ -232,8 +240,8 @@ impl From<AvroSerializerConfig> for SerializerConfig {
 }
 
 impl From<GelfSerializerConfig> for SerializerConfig {
-    fn from(_: GelfSerializerConfig) -> Self {
-        Self::Gelf
+    fn from(config: GelfSerializerConfig) -> Self {
+        Self::Gelf { gelf: config.gelf }
     }
 }
 
@@ -280,7 +288,9 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => Ok(Serializer::Avro(
                 AvroSerializerConfig::new(avro.schema.clone()).build()?,
             )),
-            SerializerConfig::Gelf => Ok(Serializer::Gelf(GelfSerializerConfig::new().build())),
+            SerializerConfig::Gelf { gelf } => Ok(Serializer::Gelf(
+                GelfSerializerConfig::new(gelf.clone()).build(),
+            )),
             SerializerConfig::Json => Ok(Serializer::Json(JsonSerializerConfig.build())),
             SerializerConfig::Logfmt => Ok(Serializer::Logfmt(LogfmtSerializerConfig.build())),
             SerializerConfig::Native => Ok(Serializer::Native(NativeSerializerConfig.build())),
@@ -71,6 +71,12 @@ pub trait Table: DynClone {
     /// Returns a list of the field names that are in each index
     fn index_fields(&self) -> Vec<(Case, Vec<String>)>;
 
+    /// Returns the names of the fields of the rows of the table, if they're known before searching
+    /// it.
+    fn columns(&self) -> Option<Vec<String>> {
+        None
+    }
+
     /// Returns true if the underlying data has changed and the table needs reloading.
     fn needs_reload(&self) -> bool;
 }
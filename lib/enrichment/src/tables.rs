@@ -151,6 +151,25 @@ impl TableRegistry {
         }
     }
 
+    /// Returns the names of the fields of the rows of the given Enrichment Table, if they're known.
+    ///
+    /// If we are in the reading stage, this function will error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Mutex is poisoned.
+    pub fn columns(&self, table: &str) -> Result<Option<Vec<String>>, String> {
+        let locked = self.loading.lock().unwrap();
+
+        match *locked {
+            None => Err("finish_load has been called".to_string()),
+            Some(ref tables) => match tables.get(table) {
+                None => Err(format!("table '{}' not loaded", table)),
+                Some(table) => Ok(table.columns()),
+            },
+        }
+    }
+
     /// Returns a cheaply clonable struct through that provides lock free read
     /// access to the enrichment tables.
     pub fn as_readonly(&self) -> TableSearch {
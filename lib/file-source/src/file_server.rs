@@ -40,9 +40,11 @@ where
 {
     pub paths_provider: PP,
     pub max_read_bytes: usize,
+    pub max_read_bytes_per_second: Option<usize>,
     pub ignore_checkpoints: bool,
     pub read_from: ReadFrom,
     pub ignore_before: Option<DateTime<Utc>>,
+    pub ignore_older: Option<Duration>,
     pub max_line_bytes: usize,
     pub line_delimiter: Bytes,
     pub data_dir: PathBuf,
@@ -231,14 +233,27 @@ where
 
             // Collect lines by polling files.
             let mut global_bytes_read: usize = 0;
-            let mut maxed_out_reading_single_file = false;
+            // Idle files are only checked for modifications as often as the loop backs off.
+            let modified_check_interval = Duration::from_millis(backoff_cap as u64);
             for (&file_id, watcher) in &mut fp_map {
-                if !watcher.should_read() {
+                if !watcher.should_read() || watcher.is_ignored(modified_check_interval) {
+                    continue;
+                }
+
+                // Files which used up their budget for the current second are skipped, so that
+                // replaying a large backlog doesn't hold up reading the other files, even when
+                // reading the oldest files first.
+                let read_budget = self
+                    .max_read_bytes_per_second
+                    .map(|max_bytes_per_second| watcher.read_budget(max_bytes_per_second));
+                if read_budget == Some(0) {
                     continue;
                 }
 
                 let start = time::Instant::now();
                 let mut bytes_read: usize = 0;
+                let mut reached_eof = true;
+                let mut maxed_out_reading_single_file = false;
                 while let Ok(Some(line)) = watcher.read_line() {
                     let sz = line.len();
                     trace!(
@@ -259,11 +274,23 @@ where
 
                     if bytes_read > self.max_read_bytes {
                         maxed_out_reading_single_file = true;
+                        reached_eof = false;
+                        break;
+                    }
+                    if read_budget.map_or(false, |budget| bytes_read >= budget) {
+                        reached_eof = false;
                         break;
                     }
                 }
+                watcher.track_bytes_read(bytes_read);
                 stats.record("reading", start.elapsed());
 
+                // Stop reading files which went idle for longer than `ignore_older` until they
+                // are modified again.
+                if let (true, Some(ignore_older)) = (reached_eof, self.ignore_older) {
+                    watcher.ignore_if_older_than(ignore_older, modified_check_interval);
+                }
+
                 if bytes_read > 0 {
                     global_bytes_read = global_bytes_read.saturating_add(bytes_read);
                 } else {
@@ -285,8 +312,9 @@ where
                     }
                 }
 
-                // Do not move on to newer files if we are behind on an older file
-                if self.oldest_first && maxed_out_reading_single_file {
+                // Do not move on to newer files if we are behind on an older file, except for one
+                // pass every second so that they aren't starved even when reads aren't throttled.
+                if self.oldest_first && maxed_out_reading_single_file && !watcher.should_yield() {
                     break;
                 }
             }
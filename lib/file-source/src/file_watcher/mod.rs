@@ -2,7 +2,7 @@ use std::{
     fs::{self, File},
     io::{self, BufRead, Seek},
     path::PathBuf,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime},
 };
 
 use bytes::{Bytes, BytesMut};
@@ -26,6 +26,7 @@ mod tests;
 pub struct FileWatcher {
     pub path: PathBuf,
     findable: bool,
+    file: File,
     reader: Box<dyn BufRead>,
     file_position: FilePosition,
     devno: u64,
@@ -36,6 +37,11 @@ pub struct FileWatcher {
     max_line_bytes: usize,
     line_delimiter: Bytes,
     buf: BytesMut,
+    ignored_modified_time: Option<SystemTime>,
+    last_modified_check: Instant,
+    priority_window_start: Instant,
+    throttle_window_start: Instant,
+    throttle_window_bytes: usize,
 }
 
 impl FileWatcher {
@@ -54,6 +60,7 @@ impl FileWatcher {
         let f = fs::File::open(&path)?;
         let (devno, ino) = (f.portable_dev()?, f.portable_ino()?);
         let metadata = f.metadata()?;
+        let file = f.try_clone()?;
         let mut reader = io::BufReader::new(f);
 
         let too_old = if let (Some(ignore_before), Ok(modified_time)) = (
@@ -127,6 +134,7 @@ impl FileWatcher {
         Ok(FileWatcher {
             path,
             findable: true,
+            file,
             reader,
             file_position,
             devno,
@@ -137,6 +145,11 @@ impl FileWatcher {
             max_line_bytes,
             line_delimiter,
             buf: BytesMut::new(),
+            ignored_modified_time: None,
+            last_modified_check: Instant::now(),
+            priority_window_start: Instant::now(),
+            throttle_window_start: Instant::now(),
+            throttle_window_bytes: 0,
         })
     }
 
@@ -158,6 +171,7 @@ impl FileWatcher {
             self.reader = new_reader;
             self.devno = file_handle.portable_dev()?;
             self.inode = file_handle.portable_ino()?;
+            self.file = file_handle;
         }
         self.path = path;
         Ok(())
@@ -230,6 +244,72 @@ impl FileWatcher {
         }
     }
 
+    /// Stops reading the file until it is modified again if its last modification is older than
+    /// `ignore_older`. The modification time is checked at most once every `check_interval`.
+    ///
+    /// This should only be called once the file has been read to its end, so that no data written
+    /// before the file went idle is skipped.
+    pub fn ignore_if_older_than(&mut self, ignore_older: Duration, check_interval: Duration) {
+        if let Some(Ok(modified_time)) = self.check_modified_time(check_interval) {
+            if modified_time
+                .elapsed()
+                .map_or(false, |age| age >= ignore_older)
+            {
+                self.ignored_modified_time = Some(modified_time);
+            }
+        }
+    }
+
+    /// Whether reads of the file are skipped because it was idle for longer than `ignore_older`
+    /// and hasn't been modified since. The modification time is checked at most once every
+    /// `check_interval`.
+    pub fn is_ignored(&mut self, check_interval: Duration) -> bool {
+        if let Some(ignored_modified_time) = self.ignored_modified_time {
+            match self.check_modified_time(check_interval) {
+                None => return true,
+                Some(Ok(modified_time)) if modified_time == ignored_modified_time => return true,
+                Some(_) => self.ignored_modified_time = None,
+            }
+        }
+        false
+    }
+
+    /// Gets the modification time of the open file, or `None` if it was already checked within
+    /// the last `check_interval`.
+    fn check_modified_time(&mut self, check_interval: Duration) -> Option<io::Result<SystemTime>> {
+        if self.last_modified_check.elapsed() < check_interval {
+            return None;
+        }
+        self.last_modified_check = Instant::now();
+        Some(self.file.metadata().and_then(|m| m.modified()))
+    }
+
+    /// Whether a file that is behind should let newer files be read for one pass when reading the
+    /// oldest files first, which it does once a second so that a large backlog doesn't starve them.
+    pub fn should_yield(&mut self) -> bool {
+        if self.priority_window_start.elapsed() >= Duration::from_secs(1) {
+            self.priority_window_start = Instant::now();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Gets the number of bytes that can still be read from the file during the current second
+    /// without exceeding `max_bytes_per_second`.
+    pub fn read_budget(&mut self, max_bytes_per_second: usize) -> usize {
+        if self.throttle_window_start.elapsed() >= Duration::from_secs(1) {
+            self.throttle_window_start = Instant::now();
+            self.throttle_window_bytes = 0;
+        }
+        max_bytes_per_second.saturating_sub(self.throttle_window_bytes)
+    }
+
+    /// Counts bytes read from the file against its read budget.
+    pub fn track_bytes_read(&mut self, bytes: usize) {
+        self.throttle_window_bytes = self.throttle_window_bytes.saturating_add(bytes);
+    }
+
     #[inline]
     fn track_read_attempt(&mut self) {
         self.last_read_attempt = Instant::now();
@@ -0,0 +1,135 @@
+use std::{fs, io::Write, time::Duration};
+
+use bytes::Bytes;
+
+use crate::{file_watcher::FileWatcher, ReadFrom};
+
+/// Sets the modification time of a file, with a precision of one second.
+#[cfg(unix)]
+fn set_modified_time(file: &fs::File, time: std::time::SystemTime) {
+    use std::{os::unix::io::AsRawFd, time::SystemTime};
+
+    let time = libc::timeval {
+        tv_sec: time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as _,
+        tv_usec: 0,
+    };
+    let times = [time, time];
+    assert_eq!(
+        unsafe { libc::futimes(file.as_raw_fd(), times.as_ptr()) },
+        0
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn ignores_idle_file_until_modified() {
+    let dir = tempfile::TempDir::new().expect("could not create tempdir");
+    let path = dir.path().join("a_file.log");
+    let mut file = fs::File::create(&path).expect("could not create");
+    let idle_since = std::time::SystemTime::now() - Duration::from_secs(60);
+
+    writeln!(file, "first line").unwrap();
+    set_modified_time(&file, idle_since);
+
+    let mut watcher =
+        FileWatcher::new(path, ReadFrom::Beginning, None, 100_000, Bytes::from("\n")).unwrap();
+    assert_eq!(
+        watcher.read_line().unwrap(),
+        Some(Bytes::from("first line"))
+    );
+    assert_eq!(watcher.read_line().unwrap(), None);
+
+    watcher.ignore_if_older_than(Duration::from_secs(30), Duration::ZERO);
+    assert!(watcher.is_ignored(Duration::ZERO));
+
+    // Data written without a new modification time stays ignored.
+    writeln!(file, "second line").unwrap();
+    set_modified_time(&file, idle_since);
+    assert!(watcher.is_ignored(Duration::ZERO));
+
+    // Once the file is modified, reading resumes where it left off.
+    writeln!(file, "third line").unwrap();
+    assert!(!watcher.is_ignored(Duration::ZERO));
+    assert_eq!(
+        watcher.read_line().unwrap(),
+        Some(Bytes::from("second line"))
+    );
+    assert_eq!(
+        watcher.read_line().unwrap(),
+        Some(Bytes::from("third line"))
+    );
+}
+
+#[test]
+fn does_not_ignore_recently_modified_file() {
+    let dir = tempfile::TempDir::new().expect("could not create tempdir");
+    let path = dir.path().join("a_file.log");
+    let mut file = fs::File::create(&path).expect("could not create");
+    writeln!(file, "first line").unwrap();
+
+    let mut watcher =
+        FileWatcher::new(path, ReadFrom::Beginning, None, 100_000, Bytes::from("\n")).unwrap();
+    watcher.ignore_if_older_than(Duration::from_secs(30), Duration::ZERO);
+    assert!(!watcher.is_ignored(Duration::ZERO));
+}
+
+#[cfg(unix)]
+#[test]
+fn checks_ignored_file_once_per_interval() {
+    let dir = tempfile::TempDir::new().expect("could not create tempdir");
+    let path = dir.path().join("a_file.log");
+    let mut file = fs::File::create(&path).expect("could not create");
+    let idle_since = std::time::SystemTime::now() - Duration::from_secs(60);
+    writeln!(file, "first line").unwrap();
+    set_modified_time(&file, idle_since);
+
+    let mut watcher =
+        FileWatcher::new(path, ReadFrom::Beginning, None, 100_000, Bytes::from("\n")).unwrap();
+    watcher.ignore_if_older_than(Duration::from_secs(30), Duration::ZERO);
+
+    // The modification isn't noticed until the interval since the last check elapses.
+    writeln!(file, "second line").unwrap();
+    let check_interval = Duration::from_millis(500);
+    assert!(watcher.is_ignored(check_interval));
+    std::thread::sleep(check_interval);
+    assert!(!watcher.is_ignored(check_interval));
+}
+
+#[test]
+fn yields_once_per_second() {
+    let dir = tempfile::TempDir::new().expect("could not create tempdir");
+    let path = dir.path().join("a_file.log");
+    fs::File::create(&path).expect("could not create");
+
+    let mut watcher =
+        FileWatcher::new(path, ReadFrom::Beginning, None, 100_000, Bytes::from("\n")).unwrap();
+    assert!(!watcher.should_yield());
+
+    std::thread::sleep(Duration::from_millis(1100));
+    assert!(watcher.should_yield());
+    assert!(!watcher.should_yield());
+}
+
+#[test]
+fn read_budget() {
+    let dir = tempfile::TempDir::new().expect("could not create tempdir");
+    let path = dir.path().join("a_file.log");
+    fs::File::create(&path).expect("could not create");
+
+    let mut watcher =
+        FileWatcher::new(path, ReadFrom::Beginning, None, 100_000, Bytes::from("\n")).unwrap();
+    assert_eq!(watcher.read_budget(100), 100);
+
+    watcher.track_bytes_read(60);
+    assert_eq!(watcher.read_budget(100), 40);
+
+    watcher.track_bytes_read(60);
+    assert_eq!(watcher.read_budget(100), 0);
+
+    // The budget is refilled every second.
+    std::thread::sleep(Duration::from_millis(1100));
+    assert_eq!(watcher.read_budget(100), 100);
+}
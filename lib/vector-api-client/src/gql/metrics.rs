@@ -167,6 +167,16 @@ impl component_sent_events_throughputs_subscription::ComponentSentEventsThroughp
 )]
 pub struct ComponentErrorsTotalsSubscription;
 
+/// ComponentRequestsInFlightSubscription contains the number of requests that sinks have
+/// dispatched and not yet seen complete, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_requests_in_flight.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentRequestsInFlightSubscription;
+
 /// Extension methods for metrics subscriptions
 pub trait MetricsSubscriptionExt {
     /// Executes an uptime metrics subscription.
@@ -242,6 +252,12 @@ pub trait MetricsSubscriptionExt {
         &self,
         interval: i64,
     ) -> crate::BoxedSubscription<ComponentErrorsTotalsSubscription>;
+
+    /// Executes a component sink requests in flight subscription.
+    fn component_requests_in_flight_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentRequestsInFlightSubscription>;
 }
 
 impl MetricsSubscriptionExt for crate::SubscriptionClient {
@@ -394,4 +410,16 @@ impl MetricsSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<ComponentErrorsTotalsSubscription>(&request_body)
     }
+
+    /// Executes a component sink requests in flight subscription.
+    fn component_requests_in_flight_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentRequestsInFlightSubscription> {
+        let request_body = ComponentRequestsInFlightSubscription::build_query(
+            component_requests_in_flight_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentRequestsInFlightSubscription>(&request_body)
+    }
 }
@@ -1,5 +1,6 @@
 #![allow(clippy::upper_case_acronyms)]
 
+use async_trait::async_trait;
 use graphql_client::GraphQLQuery;
 
 use crate::BoxedSubscription;
@@ -17,6 +18,16 @@ type DateTime = chrono::DateTime<chrono::Utc>;
 )]
 pub struct OutputEventsByComponentIdPatternsSubscription;
 
+/// ComponentErrorsQuery returns the events most recently dropped by a component, if it's
+/// configured to retain them.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/component_errors.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentErrorsQuery;
+
 /// Tap encoding format type that is more convenient to use for public clients than the
 /// generated `output_events_by_component_id_patterns_subscription::EventEncodingType`.
 #[derive(Debug, Clone, Copy)]
@@ -53,6 +64,17 @@ impl From<TapEncodingFormat>
     }
 }
 
+/// Map the public-facing `TapEncodingFormat` to the internal `EventEncodingType`.
+impl From<TapEncodingFormat> for component_errors_query::EventEncodingType {
+    fn from(encoding: TapEncodingFormat) -> Self {
+        match encoding {
+            TapEncodingFormat::Json => Self::JSON,
+            TapEncodingFormat::Yaml => Self::YAML,
+            TapEncodingFormat::Logfmt => Self::LOGFMT,
+        }
+    }
+}
+
 pub trait TapSubscriptionExt {
     /// Executes an output events subscription.
     fn output_events_by_component_id_patterns_subscription(
@@ -88,3 +110,34 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
         self.start::<OutputEventsByComponentIdPatternsSubscription>(&request_body)
     }
 }
+
+/// Extension methods for dropped events queries.
+#[async_trait]
+pub trait ComponentErrorsQueryExt {
+    /// Executes a dropped events query, returning up to `limit` events, newest first.
+    async fn component_errors_query(
+        &self,
+        component_id: String,
+        encoding: TapEncodingFormat,
+        limit: i64,
+    ) -> crate::QueryResult<ComponentErrorsQuery>;
+}
+
+#[async_trait]
+impl ComponentErrorsQueryExt for crate::Client {
+    /// Executes a dropped events query, returning up to `limit` events, newest first.
+    async fn component_errors_query(
+        &self,
+        component_id: String,
+        encoding: TapEncodingFormat,
+        limit: i64,
+    ) -> crate::QueryResult<ComponentErrorsQuery> {
+        let request_body = ComponentErrorsQuery::build_query(component_errors_query::Variables {
+            component_id,
+            limit,
+            encoding: encoding.into(),
+        });
+
+        self.query::<ComponentErrorsQuery>(&request_body).await
+    }
+}
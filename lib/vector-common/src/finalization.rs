@@ -3,7 +3,14 @@
 //! as it flows through transforms, being duplicated and merged, and
 //! then report its status when the last copy is delivered or dropped.
 
-use std::{cmp, future::Future, mem, pin::Pin, sync::Arc, task::Poll};
+use std::{
+    cmp,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::Poll,
+};
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures::future::FutureExt;
@@ -86,6 +93,24 @@ impl EventFinalizers {
         }
     }
 
+    /// Subscribes to the final status of each batch the event finalizers in the collection belong to.
+    ///
+    /// The status of a batch is received once all of its events have been finalized, by every
+    /// component they were sent to.
+    #[must_use]
+    pub fn subscribe_batches(&self) -> Vec<BatchStatusReceiver> {
+        let mut batches: Vec<&BatchNotifier> = Vec::new();
+        for finalizer in &self.0 {
+            if !batches
+                .iter()
+                .any(|batch| Arc::ptr_eq(&batch.0, &finalizer.batch.0))
+            {
+                batches.push(&finalizer.batch);
+            }
+        }
+        batches.into_iter().map(BatchNotifier::subscribe).collect()
+    }
+
     /// Consumes all event finalizers and updates their underlying batches immediately.
     pub fn update_sources(&mut self) {
         let finalizers = mem::take(&mut self.0);
@@ -202,6 +227,7 @@ impl BatchNotifier {
         let notifier = OwnedBatchNotifier {
             status: AtomicCell::new(BatchStatus::Delivered),
             notifier: Some(sender),
+            subscribers: Mutex::default(),
         };
         (Self(Arc::new(notifier)), BatchStatusReceiver(receiver))
     }
@@ -239,6 +265,19 @@ impl BatchNotifier {
         enabled.then(|| Self::apply_to(items))
     }
 
+    /// Subscribes to the final status of the batch, which is received along with the source once
+    /// all of its events have been finalized.
+    #[must_use]
+    pub fn subscribe(&self) -> BatchStatusReceiver {
+        let (sender, receiver) = oneshot::channel();
+        self.0
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(sender);
+        BatchStatusReceiver(receiver)
+    }
+
     /// Updates the status of the notifier.
     fn update_status(&self, status: EventStatus) {
         // The status starts as Delivered and can only change if the new
@@ -257,17 +296,25 @@ impl BatchNotifier {
 pub struct OwnedBatchNotifier {
     status: AtomicCell<BatchStatus>,
     notifier: Option<oneshot::Sender<BatchStatus>>,
+    subscribers: Mutex<Vec<oneshot::Sender<BatchStatus>>>,
 }
 
 impl OwnedBatchNotifier {
-    /// Sends the status of the notifier back to the source.
+    /// Sends the status of the notifier back to the source, and to its subscribers.
     fn send_status(&mut self) {
+        let status = self.status.load();
         if let Some(notifier) = self.notifier.take() {
-            let status = self.status.load();
             // Ignore the error case, as it will happen during normal
             // source shutdown and we can't detect that here.
             let _ = notifier.send(status);
         }
+        let subscribers = self
+            .subscribers
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        for subscriber in subscribers.drain(..) {
+            let _ = subscriber.send(status);
+        }
     }
 }
 
@@ -428,6 +475,22 @@ mod tests {
         assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
     }
 
+    #[test]
+    fn subscribe_batches() {
+        let (fin1, mut receiver) = make_finalizer();
+        let mut fin2 = fin1.clone();
+        fin2.merge(fin1.clone());
+        let mut subscribers = fin2.subscribe_batches();
+        assert_eq!(subscribers.len(), 1);
+
+        fin1.update_status(EventStatus::Rejected);
+        drop(fin1);
+        assert_eq!(subscribers[0].try_recv(), Err(Empty));
+        drop(fin2);
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+        assert_eq!(subscribers[0].try_recv(), Ok(BatchStatus::Rejected));
+    }
+
     #[test]
     fn clone_events() {
         let (fin1, mut receiver) = make_finalizer();
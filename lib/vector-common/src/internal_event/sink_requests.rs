@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use metrics::{gauge, histogram};
+use tracing::trace;
+
+use crate::internal_event::InternalEvent;
+
+/// The number of requests a sink has dispatched to its service and not yet seen complete.
+#[derive(Debug)]
+pub struct SinkRequestsInFlight {
+    pub count: usize,
+}
+
+impl InternalEvent for SinkRequestsInFlight {
+    fn emit(self) {
+        trace!(message = "Sink requests in flight.", count = %self.count);
+        gauge!("sink_requests_in_flight", self.count as f64);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("SinkRequestsInFlight")
+    }
+}
+
+/// The number of events a sink has batched into requests but not yet dispatched, typically because
+/// its service is at its concurrency limit.
+#[derive(Debug)]
+pub struct SinkRequestQueueDepth {
+    pub count: usize,
+}
+
+impl InternalEvent for SinkRequestQueueDepth {
+    fn emit(self) {
+        trace!(message = "Sink request queue depth.", count = %self.count);
+        gauge!("sink_request_queue_depth", self.count as f64);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("SinkRequestQueueDepth")
+    }
+}
+
+/// The time taken by a sink's service to complete a single request, successful or not.
+#[derive(Debug)]
+pub struct SinkRequestDuration {
+    pub duration: Duration,
+}
+
+impl InternalEvent for SinkRequestDuration {
+    fn emit(self) {
+        trace!(message = "Sink request completed.", duration = ?self.duration);
+        histogram!("sink_request_duration_seconds", self.duration);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("SinkRequestDuration")
+    }
+}
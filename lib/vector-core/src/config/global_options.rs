@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use snafu::{ResultExt, Snafu};
 use vector_common::TimeZone;
 
-use super::{proxy::ProxyConfig, AcknowledgementsConfig, LogSchema};
+use super::{proxy::ProxyConfig, AcknowledgementsConfig, LogSchema, TelemetryConfig};
 use crate::serde::bool_or_struct;
 
 #[derive(Debug, Snafu)]
@@ -48,6 +48,8 @@ pub struct GlobalOptions {
     pub acknowledgements: AcknowledgementsConfig,
     #[serde(skip_serializing_if = "crate::serde::skip_serializing_if_default")]
     pub expire_metrics: Option<Duration>,
+    #[serde(skip_serializing_if = "crate::serde::skip_serializing_if_default")]
+    pub telemetry: TelemetryConfig,
 }
 
 impl GlobalOptions {
@@ -5,6 +5,7 @@ use bitmask_enum::bitmask;
 mod global_options;
 mod log_schema;
 pub mod proxy;
+mod telemetry;
 
 use crate::event::LogEvent;
 pub use global_options::GlobalOptions;
@@ -12,6 +13,8 @@ pub use log_schema::{init_log_schema, log_schema, LogSchema};
 use lookup::lookup_v2::Path;
 use lookup::path;
 use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+pub use telemetry::TelemetryConfig;
 use value::Value;
 pub use vector_common::config::ComponentKey;
 use vector_config::configurable_component;
@@ -206,6 +209,28 @@ impl From<bool> for LogNamespace {
     }
 }
 
+#[derive(Debug, PartialEq, Snafu)]
+pub enum LogNamespaceError {
+    #[snafu(display("invalid log namespace {:?}, expected \"legacy\" or \"vector\"", value))]
+    InvalidLogNamespace { value: String },
+}
+
+/// Parses the name of a namespace, regardless of its case, such as from a command line flag or an
+/// environment variable.
+impl TryFrom<String> for LogNamespace {
+    type Error = LogNamespaceError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.eq_ignore_ascii_case("legacy") {
+            Ok(LogNamespace::Legacy)
+        } else if value.eq_ignore_ascii_case("vector") {
+            Ok(LogNamespace::Vector)
+        } else {
+            Err(LogNamespaceError::InvalidLogNamespace { value })
+        }
+    }
+}
+
 impl Default for LogNamespace {
     fn default() -> Self {
         Self::Legacy
@@ -268,3 +293,39 @@ impl LogNamespace {
         override_value.map_or(*self, Into::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_namespace_from_string() {
+        for (value, expected) in [
+            ("legacy", LogNamespace::Legacy),
+            ("vector", LogNamespace::Vector),
+            ("Legacy", LogNamespace::Legacy),
+            ("VECTOR", LogNamespace::Vector),
+            ("vEcToR", LogNamespace::Vector),
+        ] {
+            assert_eq!(LogNamespace::try_from(value.to_owned()), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn log_namespace_from_invalid_string() {
+        for value in ["", "true", "vectors", " legacy", "native"] {
+            assert_eq!(
+                LogNamespace::try_from(value.to_owned()),
+                Err(LogNamespaceError::InvalidLogNamespace {
+                    value: value.to_owned()
+                })
+            );
+        }
+        assert_eq!(
+            LogNamespace::try_from("bogus".to_owned())
+                .unwrap_err()
+                .to_string(),
+            r#"invalid log namespace "bogus", expected "legacy" or "vector""#
+        );
+    }
+}
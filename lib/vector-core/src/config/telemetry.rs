@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the internal metrics of Vector.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryConfig {
+    /// The upper limits of the buckets of internal histograms, replacing the default powers of two.
+    ///
+    /// A bucket for values larger than the last limit is always added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub histogram_buckets: Option<Vec<f64>>,
+
+    /// The upper limits of the buckets of specific internal histograms, by metric name.
+    ///
+    /// These take precedence over `histogram_buckets`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub histogram_buckets_by_name: BTreeMap<String, Vec<f64>>,
+
+    /// The names of the internal histograms emitted as summaries of the `summary_quantiles`, instead of buckets.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub summary_histograms: Vec<String>,
+
+    /// The quantiles computed for the histograms of `summary_histograms`.
+    #[serde(default = "default_summary_quantiles")]
+    pub summary_quantiles: Vec<f64>,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            histogram_buckets: None,
+            histogram_buckets_by_name: BTreeMap::new(),
+            summary_histograms: Vec::new(),
+            summary_quantiles: default_summary_quantiles(),
+        }
+    }
+}
+
+fn default_summary_quantiles() -> Vec<f64> {
+    vec![0.5, 0.9, 0.99]
+}
+
+impl TelemetryConfig {
+    /// Gets the upper limits of the buckets of the named histogram, if they are overridden.
+    pub fn histogram_buckets(&self, name: &str) -> Option<&[f64]> {
+        self.histogram_buckets_by_name
+            .get(name)
+            .or(self.histogram_buckets.as_ref())
+            .map(Vec::as_slice)
+    }
+
+    /// Validates the bucket limits and quantiles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for each empty or non-finite list of bucket limits, and each quantile outside of `0` to `1`.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        let buckets = self
+            .histogram_buckets
+            .iter()
+            .map(|buckets| ("telemetry.histogram_buckets".to_owned(), buckets))
+            .chain(
+                self.histogram_buckets_by_name
+                    .iter()
+                    .map(|(name, buckets)| {
+                        (
+                            format!("telemetry.histogram_buckets_by_name.{}", name),
+                            buckets,
+                        )
+                    }),
+            );
+        for (option, buckets) in buckets {
+            if buckets.is_empty() {
+                errors.push(format!("'{}' must not be empty", option));
+            } else if !buckets.iter().all(|limit| limit.is_finite()) {
+                errors.push(format!("'{}' must only contain finite numbers", option));
+            }
+        }
+
+        if let Some(quantile) = self
+            .summary_quantiles
+            .iter()
+            .find(|quantile| !(0.0..=1.0).contains(*quantile))
+        {
+            errors.push(format!(
+                "'telemetry.summary_quantiles' must be between 0 and 1, found {}",
+                quantile
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
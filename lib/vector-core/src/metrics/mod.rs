@@ -14,7 +14,10 @@ use snafu::Snafu;
 
 pub use self::ddsketch::{AgentDDSketch, BinMap, Config};
 use self::{label_filter::VectorLabelFilter, recorder::Registry, recorder::VectorRecorder};
-use crate::event::{Metric, MetricKind, MetricValue};
+use crate::{
+    config::TelemetryConfig,
+    event::{Metric, MetricKind, MetricValue},
+};
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -136,6 +139,29 @@ impl Controller {
             .with_registry(|registry| registry.set_expiry(timeout));
     }
 
+    /// Exempt the gauges named `name` from expiry. This is meant for gauges describing state, such
+    /// as info gauges, which are only set when that state changes.
+    pub fn exempt_gauges_from_expiry(&self, name: &'static str) {
+        self.recorder
+            .with_registry(|registry| registry.exempt_gauges_from_expiry(name));
+    }
+
+    /// Remove the gauges named `name` whose key matches the `predicate`, so that they're no longer
+    /// captured until they're set again.
+    pub fn remove_gauges(&self, name: &str, predicate: impl Fn(&Key) -> bool) {
+        self.recorder
+            .with_registry(|registry| registry.remove_gauges(name, predicate));
+    }
+
+    /// Set the configuration of histogram buckets and summaries.
+    ///
+    /// Bucket overrides only apply to histograms registered afterwards, while summaries apply to
+    /// all histograms from the next snapshot.
+    pub fn set_telemetry(&self, config: &TelemetryConfig) {
+        self.recorder
+            .with_registry(|registry| registry.set_telemetry(config.clone()));
+    }
+
     /// Take a snapshot of all gathered metrics and expose them as metric
     /// [`Event`](crate::event::Event)s.
     pub fn capture_metrics(&self) -> Vec<Metric> {
@@ -232,7 +258,10 @@ macro_rules! update_counter {
 mod tests {
     use super::*;
 
-    use crate::event::MetricKind;
+    use crate::event::{
+        metric::{Bucket, Quantile},
+        MetricKind,
+    };
 
     const IDLE_TIMEOUT: Duration = Duration::from_millis(500);
 
@@ -292,4 +321,102 @@ mod tests {
         metrics::counter!("test2", 3);
         assert_eq!(controller.capture_metrics().len(), 3);
     }
+
+    #[test]
+    fn does_not_expire_exempt_gauges() {
+        let controller = init_metrics();
+        controller.set_expiry(Some(IDLE_TIMEOUT));
+        controller.exempt_gauges_from_expiry("test_info");
+
+        metrics::gauge!("test_info", 1.0);
+        metrics::gauge!("test_idle", 1.0);
+        assert_eq!(controller.capture_metrics().len(), 4);
+
+        std::thread::sleep(IDLE_TIMEOUT * 2);
+        assert!(controller
+            .capture_metrics()
+            .iter()
+            .any(|metric| metric.name() == "test_info"));
+        assert_eq!(controller.capture_metrics().len(), 3);
+    }
+
+    fn captured_value(controller: &Controller, name: &str) -> MetricValue {
+        controller
+            .capture_metrics()
+            .into_iter()
+            .find(|metric| metric.name() == name)
+            .expect("Metric was not captured")
+            .value()
+            .clone()
+    }
+
+    #[test]
+    fn histogram_buckets_override() {
+        let controller = init_metrics();
+        controller.reset();
+        controller.set_telemetry(&TelemetryConfig {
+            histogram_buckets: Some(vec![1.0, 0.5]),
+            histogram_buckets_by_name: [("named".to_owned(), vec![10.0])].into(),
+            ..Default::default()
+        });
+
+        for value in [0.25, 0.75, 2.0] {
+            metrics::histogram!("unnamed", value);
+            metrics::histogram!("named", value);
+        }
+        let bucket = |upper_limit, count| Bucket { upper_limit, count };
+        assert_eq!(
+            captured_value(controller, "unnamed"),
+            MetricValue::AggregatedHistogram {
+                buckets: vec![bucket(0.5, 1), bucket(1.0, 1), bucket(f64::INFINITY, 1)],
+                count: 3,
+                sum: 3.0,
+            }
+        );
+        assert_eq!(
+            captured_value(controller, "named"),
+            MetricValue::AggregatedHistogram {
+                buckets: vec![bucket(10.0, 3), bucket(f64::INFINITY, 0)],
+                count: 3,
+                sum: 3.0,
+            }
+        );
+
+        controller.set_telemetry(&TelemetryConfig::default());
+    }
+
+    #[test]
+    fn histogram_summaries() {
+        let controller = init_metrics();
+        controller.reset();
+        controller.set_telemetry(&TelemetryConfig {
+            histogram_buckets: Some((1..=10).map(|limit| f64::from(limit) * 10.0).collect()),
+            summary_histograms: vec!["latency".to_owned()],
+            summary_quantiles: vec![0.5, 0.9],
+            ..Default::default()
+        });
+
+        for value in 1..=100 {
+            metrics::histogram!("latency", f64::from(value));
+        }
+        assert_eq!(
+            captured_value(controller, "latency"),
+            MetricValue::AggregatedSummary {
+                quantiles: vec![
+                    Quantile {
+                        quantile: 0.5,
+                        value: 50.0,
+                    },
+                    Quantile {
+                        quantile: 0.9,
+                        value: 90.0,
+                    },
+                ],
+                count: 100,
+                sum: 5050.0,
+            }
+        );
+
+        controller.set_telemetry(&TelemetryConfig::default());
+    }
 }
@@ -11,7 +11,10 @@ use once_cell::unsync::OnceCell;
 use quanta::Clock;
 
 use super::storage::VectorStorage;
-use crate::event::{Metric, MetricKind, MetricValue};
+use crate::{
+    config::TelemetryConfig,
+    event::{Metric, MetricKind, MetricValue},
+};
 
 thread_local!(static LOCAL_REGISTRY: OnceCell<Registry> = OnceCell::new());
 
@@ -19,13 +22,20 @@ thread_local!(static LOCAL_REGISTRY: OnceCell<Registry> = OnceCell::new());
 pub(super) struct Registry {
     registry: MetricsRegistry<Key, GenerationalStorage<VectorStorage>>,
     recency: RwLock<Option<Recency<Key>>>,
+    expiry_exempt_gauges: RwLock<Vec<&'static str>>,
+    telemetry: Arc<RwLock<TelemetryConfig>>,
 }
 
 impl Registry {
     fn new() -> Self {
+        let telemetry = Arc::<RwLock<TelemetryConfig>>::default();
         Self {
-            registry: MetricsRegistry::new(GenerationalStorage::new(VectorStorage)),
+            registry: MetricsRegistry::new(GenerationalStorage::new(VectorStorage::new(
+                Arc::clone(&telemetry),
+            ))),
             recency: RwLock::new(None),
+            expiry_exempt_gauges: RwLock::default(),
+            telemetry,
         }
     }
 
@@ -38,6 +48,27 @@ impl Registry {
         *(self.recency.write()).expect("Failed to acquire write lock on recency map") = recency;
     }
 
+    pub(super) fn exempt_gauges_from_expiry(&self, name: &'static str) {
+        let mut exempt = (self.expiry_exempt_gauges.write())
+            .expect("Failed to acquire write lock on expiry exempt gauges");
+        if !exempt.contains(&name) {
+            exempt.push(name);
+        }
+    }
+
+    pub(super) fn remove_gauges(&self, name: &str, predicate: impl Fn(&Key) -> bool) {
+        for key in self.registry.get_gauge_handles().into_keys() {
+            if key.name() == name && predicate(&key) {
+                self.registry.delete_gauge(&key);
+            }
+        }
+    }
+
+    pub(super) fn set_telemetry(&self, config: TelemetryConfig) {
+        *(self.telemetry.write()).expect("Failed to acquire write lock on telemetry config") =
+            config;
+    }
+
     pub(super) fn visit_metrics(&self) -> Vec<Metric> {
         let timestamp = Utc::now();
 
@@ -47,6 +78,14 @@ impl Registry {
             .read()
             .expect("Failed to acquire read lock on recency map");
         let recency = recency.as_ref();
+        let expiry_exempt_gauges = self
+            .expiry_exempt_gauges
+            .read()
+            .expect("Failed to acquire read lock on expiry exempt gauges");
+        let telemetry = self
+            .telemetry
+            .read()
+            .expect("Failed to acquire read lock on telemetry config");
 
         for (key, counter) in self.registry.get_counter_handles() {
             if recency.map_or(true, |recency| {
@@ -64,9 +103,11 @@ impl Registry {
             }
         }
         for (key, gauge) in self.registry.get_gauge_handles() {
-            if recency.map_or(true, |recency| {
-                recency.should_store_gauge(&key, gauge.get_generation(), &self.registry)
-            }) {
+            if expiry_exempt_gauges.contains(&key.name())
+                || recency.map_or(true, |recency| {
+                    recency.should_store_gauge(&key, gauge.get_generation(), &self.registry)
+                })
+            {
                 let value = gauge.get_inner().load(Ordering::Relaxed);
                 metrics.push(Metric::from_metric_kv(
                     &key,
@@ -80,7 +121,16 @@ impl Registry {
             if recency.map_or(true, |recency| {
                 recency.should_store_histogram(&key, histogram.get_generation(), &self.registry)
             }) {
-                let value = histogram.get_inner().make_metric();
+                let histogram = histogram.get_inner();
+                let value = if telemetry
+                    .summary_histograms
+                    .iter()
+                    .any(|name| name == key.name())
+                {
+                    histogram.make_summary(&telemetry.summary_quantiles)
+                } else {
+                    histogram.make_metric()
+                };
                 metrics.push(Metric::from_metric_kv(
                     &key,
                     MetricKind::Absolute,
@@ -1,30 +1,51 @@
 use std::sync::{
     atomic::{AtomicU32, AtomicU64, Ordering},
-    Arc,
+    Arc, RwLock,
 };
 
-use metrics::{GaugeFn, HistogramFn};
+use metrics::{GaugeFn, HistogramFn, Key};
 use metrics_util::registry::Storage;
 
-use crate::event::{metric::Bucket, MetricValue};
+use crate::{
+    config::TelemetryConfig,
+    event::{
+        metric::{Bucket, Quantile},
+        MetricValue,
+    },
+};
 
-pub(super) struct VectorStorage;
+pub(super) struct VectorStorage {
+    telemetry: Arc<RwLock<TelemetryConfig>>,
+}
 
-impl<K> Storage<K> for VectorStorage {
+impl VectorStorage {
+    pub(super) fn new(telemetry: Arc<RwLock<TelemetryConfig>>) -> Self {
+        Self { telemetry }
+    }
+}
+
+impl Storage<Key> for VectorStorage {
     type Counter = Arc<AtomicU64>;
     type Gauge = Arc<AtomicF64>;
     type Histogram = Arc<Histogram>;
 
-    fn counter(&self, _: &K) -> Self::Counter {
+    fn counter(&self, _: &Key) -> Self::Counter {
         Arc::new(AtomicU64::new(0))
     }
 
-    fn gauge(&self, _: &K) -> Self::Gauge {
+    fn gauge(&self, _: &Key) -> Self::Gauge {
         Arc::new(AtomicF64::new(0.0))
     }
 
-    fn histogram(&self, _: &K) -> Self::Histogram {
-        Arc::new(Histogram::new())
+    fn histogram(&self, key: &Key) -> Self::Histogram {
+        let telemetry = self
+            .telemetry
+            .read()
+            .expect("Failed to acquire read lock on telemetry config");
+        let histogram = telemetry
+            .histogram_buckets(key.name())
+            .map_or_else(Histogram::new, Histogram::with_buckets);
+        Arc::new(histogram)
     }
 }
 
@@ -74,7 +95,9 @@ impl GaugeFn for AtomicF64 {
 
 #[derive(Debug)]
 pub(super) struct Histogram {
-    buckets: Box<[(f64, AtomicU32); 20]>,
+    buckets: Box<[(f64, AtomicU32)]>,
+    /// Whether the buckets are the default powers of two, whose index can be computed directly.
+    default_buckets: bool,
     count: AtomicU64,
     sum: AtomicF64,
 }
@@ -118,6 +141,26 @@ impl Histogram {
         ]);
         Self {
             buckets,
+            default_buckets: true,
+            count: AtomicU64::new(0),
+            sum: AtomicF64::new(0.0),
+        }
+    }
+
+    /// Creates a histogram with buckets of the given upper limits, plus one for larger values.
+    pub(crate) fn with_buckets(limits: &[f64]) -> Self {
+        let mut limits = limits.to_vec();
+        limits.sort_by(f64::total_cmp);
+        limits.dedup();
+        if limits.last() != Some(&f64::INFINITY) {
+            limits.push(f64::INFINITY);
+        }
+        Self {
+            buckets: limits
+                .into_iter()
+                .map(|limit| (limit, AtomicU32::new(0)))
+                .collect(),
+            default_buckets: false,
             count: AtomicU64::new(0),
             sum: AtomicF64::new(0.0),
         }
@@ -135,6 +178,16 @@ impl Histogram {
         index.min(Self::BUCKETS - 1)
     }
 
+    fn index(&self, value: f64) -> usize {
+        if self.default_buckets {
+            Self::bucket_index(value)
+        } else {
+            self.buckets
+                .partition_point(|(upper_limit, _)| *upper_limit < value)
+                .min(self.buckets.len() - 1)
+        }
+    }
+
     pub(super) fn count(&self) -> u64 {
         self.count.load(Ordering::Relaxed)
     }
@@ -160,11 +213,55 @@ impl Histogram {
             sum: self.sum(),
         }
     }
+
+    /// Makes a summary of the given quantiles, estimated from the buckets.
+    pub(super) fn make_summary(&self, quantiles: &[f64]) -> MetricValue {
+        let buckets = self.buckets();
+        MetricValue::AggregatedSummary {
+            quantiles: quantiles
+                .iter()
+                .map(|&quantile| Quantile {
+                    quantile,
+                    value: bucket_quantile(&buckets, quantile),
+                })
+                .collect(),
+            count: self.count(),
+            sum: self.sum(),
+        }
+    }
+}
+
+/// Estimates a quantile of the values counted in the buckets, interpolating linearly within the
+/// bucket it falls in, the same way as the `histogram_quantile` function of Prometheus.
+///
+/// The lower limit of the first bucket is zero, unless its upper limit is negative. Quantiles
+/// falling in the bucket of values larger than the last limit are estimated as that limit.
+#[allow(clippy::cast_precision_loss)]
+fn bucket_quantile(buckets: &[Bucket], quantile: f64) -> f64 {
+    let total: u64 = buckets.iter().map(|bucket| bucket.count).sum();
+    let rank = quantile * total as f64;
+
+    let mut lower_limit = buckets
+        .first()
+        .map_or(0.0, |bucket| bucket.upper_limit.min(0.0));
+    let mut seen = 0;
+    for bucket in buckets {
+        if bucket.count > 0 && (seen + bucket.count) as f64 >= rank {
+            if bucket.upper_limit.is_infinite() {
+                return lower_limit;
+            }
+            let fraction = (rank - seen as f64) / bucket.count as f64;
+            return lower_limit + (bucket.upper_limit - lower_limit) * fraction;
+        }
+        seen += bucket.count;
+        lower_limit = bucket.upper_limit;
+    }
+    0.0
 }
 
 impl HistogramFn for Histogram {
     fn record(&self, value: f64) {
-        let index = Self::bucket_index(value);
+        let index = self.index(value);
         self.buckets[index].1.fetch_add(1, Ordering::Relaxed);
         self.count.fetch_add(1, Ordering::Relaxed);
         self.sum
@@ -1,16 +1,19 @@
-use std::{collections::VecDeque, fmt, task::Poll};
+use std::{collections::VecDeque, fmt, task::Poll, time::Instant};
 
 use futures::{poll, FutureExt, Stream, StreamExt, TryFutureExt};
 use futures_util::future::poll_fn;
 use tokio::{pin, select};
 use tower::Service;
 use tracing::Instrument;
+use vector_buffers::EventCount;
 use vector_common::internal_event::BytesSent;
 
 use super::FuturesUnorderedCount;
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
-    internal_event::{emit, EventsSent},
+    internal_event::{
+        emit, EventsSent, SinkRequestDuration, SinkRequestQueueDepth, SinkRequestsInFlight,
+    },
 };
 
 pub trait DriverResponse {
@@ -32,7 +35,8 @@ pub trait DriverResponse {
 /// managing waiting for the service to be ready before processing more items, and so on.
 ///
 /// Additionally, `Driver` handles event finalization, which triggers acknowledgements
-/// to the source or disk buffer.
+/// to the source or disk buffer, and reports how many requests are in flight, how many events are
+/// queued waiting for the service to become ready, and how long each request took to complete.
 ///
 /// This capability is parameterized so any implementation which can define how to interpret the
 /// response for each request, as well as define how many events a request is compromised of, can be
@@ -51,7 +55,7 @@ impl<St, Svc> Driver<St, Svc> {
 impl<St, Svc> Driver<St, Svc>
 where
     St: Stream,
-    St::Item: Finalizable,
+    St::Item: Finalizable + EventCount,
     Svc: Service<St::Item>,
     Svc::Error: fmt::Debug + 'static,
     Svc::Future: Send + 'static,
@@ -102,7 +106,9 @@ where
                 biased;
 
                 // One or more of our service calls have completed.
-                Some(_count) = in_flight.next(), if !in_flight.is_empty() => {}
+                Some(_count) = in_flight.next(), if !in_flight.is_empty() => {
+                    emit(SinkRequestsInFlight { count: in_flight.len() });
+                }
 
                 // We've got an input batch to process and the service is ready to accept a request.
                 maybe_ready = poll_fn(|cx| service.poll_ready(cx)), if next_batch.is_some() => {
@@ -139,18 +145,29 @@ where
                             request_id,
                         );
                         let finalizers = req.take_finalizers();
+                        let start = Instant::now();
 
                         let fut = svc.call(req)
                             .err_into()
-                            .map(move |result| Self::handle_response(result, request_id, finalizers))
+                            .map(move |result| {
+                                emit(SinkRequestDuration { duration: start.elapsed() });
+                                Self::handle_response(result, request_id, finalizers);
+                            })
                             .instrument(info_span!("request", request_id).or_current());
 
                         in_flight.push(fut);
                     }
+
+                    emit(SinkRequestsInFlight { count: in_flight.len() });
+                    let queued = next_batch
+                        .as_ref()
+                        .map_or(0, |batch| batch.iter().map(EventCount::event_count).sum());
+                    emit(SinkRequestQueueDepth { count: queued });
                 }
 
                 // We've received some items from the input stream.
                 Some(reqs) = batched_input.next(), if next_batch.is_none() => {
+                    emit(SinkRequestQueueDepth { count: reqs.event_count() });
                     next_batch = Some(reqs.into());
                 }
 
@@ -158,6 +175,9 @@ where
             }
         }
 
+        emit(SinkRequestsInFlight { count: 0 });
+        emit(SinkRequestQueueDepth { count: 0 });
+
         Ok(())
     }
 
@@ -205,6 +225,7 @@ mod tests {
     };
     use tokio_util::sync::PollSemaphore;
     use tower::Service;
+    use vector_buffers::EventCount;
     use vector_common::finalization::{
         BatchNotifier, EventFinalizer, EventFinalizers, EventStatus, Finalizable,
     };
@@ -235,6 +256,12 @@ mod tests {
         }
     }
 
+    impl EventCount for DelayRequest {
+        fn event_count(&self) -> usize {
+            1
+        }
+    }
+
     struct DelayResponse;
 
     impl DriverResponse for DelayResponse {
@@ -7,4 +7,6 @@ mod partitioned_batcher;
 pub use concurrent_map::ConcurrentMap;
 pub use driver::{Driver, DriverResponse};
 pub(self) use futures_unordered_count::FuturesUnorderedCount;
-pub use partitioned_batcher::{BatcherSettings, ExpirationQueue, PartitionedBatcher};
+pub use partitioned_batcher::{
+    BatcherSettings, ExpirationQueue, PartitionSettings, PartitionedBatcher,
+};
@@ -74,14 +74,18 @@ where
     }
 
     fn insert(&mut self, item_key: K) {
+        self.insert_with_timeout(item_key, self.timeout);
+    }
+
+    fn insert_with_timeout(&mut self, item_key: K, timeout: Duration) {
         if let Some(expiration_key) = self.expiration_map.get(&item_key) {
             // We already have an expiration entry for this item key, so
             // just reset the expiration.
-            self.expirations.reset(expiration_key, self.timeout);
+            self.expirations.reset(expiration_key, timeout);
         } else {
             // This is a yet-unseen item key, so create a new expiration
             // entry.
-            let expiration_key = self.expirations.insert(item_key.clone(), self.timeout);
+            let expiration_key = self.expirations.insert(item_key.clone(), timeout);
             assert!(self
                 .expiration_map
                 .insert(item_key, expiration_key)
@@ -89,6 +93,12 @@ where
         }
     }
 
+    fn remove(&mut self, item_key: &K) {
+        if let Some(expiration_key) = self.expiration_map.remove(item_key) {
+            self.expirations.remove(&expiration_key);
+        }
+    }
+
     fn poll_expired(&mut self, cx: &mut Context) -> Poll<Option<K>> {
         match ready!(self.expirations.poll_expired(cx)) {
             // No expirations yet.
@@ -289,6 +299,10 @@ impl BatcherSettings {
     }
 }
 
+/// Resolves the settings of the batches of a partition, returning `None` for partitions using the
+/// batcher's own settings.
+pub type PartitionSettings<K> = Box<dyn Fn(&K) -> Option<BatcherSettings> + Send + Sync>;
+
 #[pin_project]
 pub struct PartitionedBatcher<St, Prt, KT>
 where
@@ -299,6 +313,12 @@ where
     batch_allocation_limit: usize,
     /// The maximum number of items that are allowed per-batch
     batch_item_limit: usize,
+    /// The settings overriding the limits above, and the timer's timeout, for
+    /// some partitions.
+    partition_settings: Option<PartitionSettings<Prt::Key>>,
+    /// The maximum number of live batches. When a new partition would exceed
+    /// it, the largest live batch is closed to make room.
+    max_partitions: Option<usize>,
     /// The store of live batches. Note that the key here is an option type,
     /// on account of the interface of `Prt`.
     batches: HashMap<Prt::Key, Batch<Prt::Item>, BuildHasherDefault<XxHash64>>,
@@ -326,6 +346,8 @@ where
         Self {
             batch_allocation_limit: settings.size_limit,
             batch_item_limit: settings.item_limit,
+            partition_settings: None,
+            max_partitions: None,
             batches: HashMap::default(),
             closed_batches: Vec::default(),
             timer: ExpirationQueue::new(settings.timeout),
@@ -353,6 +375,8 @@ where
             batch_allocation_limit: batch_allocation_limit
                 .map_or(usize::max_value(), NonZeroUsize::get),
             batch_item_limit: batch_item_limit.get(),
+            partition_settings: None,
+            max_partitions: None,
             batches: HashMap::default(),
             closed_batches: Vec::default(),
             timer,
@@ -360,6 +384,28 @@ where
             stream: stream.fuse(),
         }
     }
+
+    /// Overrides the batch settings of the partitions for which `settings` returns some settings.
+    ///
+    /// The settings of a partition are resolved whenever a new batch is started for it.
+    #[must_use]
+    pub fn with_partition_settings<F>(mut self, settings: F) -> Self
+    where
+        F: Fn(&Prt::Key) -> Option<BatcherSettings> + Send + Sync + 'static,
+    {
+        self.partition_settings = Some(Box::new(settings));
+        self
+    }
+
+    /// Bounds the number of partitions batched at once.
+    ///
+    /// When an item of a new partition arrives while `max_partitions` batches are live, the largest
+    /// of them is closed early, bounding the memory held by sparse partitions of a large key space.
+    #[must_use]
+    pub fn with_max_partitions(mut self, max_partitions: NonZeroUsize) -> Self {
+        self.max_partitions = Some(max_partitions.get());
+        self
+    }
 }
 
 impl<St, Prt, KT> Stream for PartitionedBatcher<St, Prt, KT>
@@ -416,8 +462,20 @@ where
                 }
                 Poll::Ready(Some(item)) => {
                     let item_key = this.partitioner.partition(&item);
-                    let item_limit: usize = *this.batch_item_limit;
-                    let alloc_limit: usize = *this.batch_allocation_limit;
+                    let default_limits = (*this.batch_item_limit, *this.batch_allocation_limit);
+                    let partition_settings = this.partition_settings.as_ref();
+                    // Returns the item and allocation limits of a new batch
+                    // for the partition, along with its timeout if overridden.
+                    let resolve = |key: &Prt::Key| match partition_settings
+                        .and_then(|settings| settings(key))
+                    {
+                        Some(settings) => (
+                            settings.item_limit,
+                            settings.size_limit,
+                            Some(settings.timeout),
+                        ),
+                        None => (default_limits.0, default_limits.1, None),
+                    };
 
                     if let Some(batch) = this.batches.get_mut(&item_key) {
                         if batch.has_space(&item) {
@@ -425,13 +483,19 @@ where
                             // push the item in and loop back around.
                             batch.push(item);
                         } else {
+                            let (item_limit, alloc_limit, timeout) = resolve(&item_key);
                             let new_batch = Batch::new(item_limit, alloc_limit).with(item);
                             let batch = mem::replace(batch, new_batch);
 
                             // The batch for this partition key was set to
                             // expire, but now it's overflowed and must be
                             // pushed out, so now we reset the batch timeout.
-                            this.timer.insert(item_key.clone());
+                            match timeout {
+                                Some(timeout) => {
+                                    this.timer.insert_with_timeout(item_key.clone(), timeout);
+                                }
+                                None => this.timer.insert(item_key.clone()),
+                            }
 
                             this.closed_batches.push((item_key, batch.into_inner()));
                         }
@@ -440,9 +504,31 @@ where
                         // create one and create the expiration entries as well.
                         // This allows the batch to expire before filling up,
                         // and vise versa.
+                        if this
+                            .max_partitions
+                            .map_or(false, |max| this.batches.len() >= max)
+                        {
+                            let largest = this
+                                .batches
+                                .iter()
+                                .max_by_key(|(_, batch)| batch.allocated_bytes())
+                                .map(|(key, _)| key.clone());
+                            if let Some(key) = largest {
+                                let batch = this
+                                    .batches
+                                    .remove(&key)
+                                    .expect("largest batch should exist");
+                                this.timer.remove(&key);
+                                this.closed_batches.push((key, batch.into_inner()));
+                            }
+                        }
+                        let (item_limit, alloc_limit, timeout) = resolve(&item_key);
                         let batch = Batch::new(item_limit, alloc_limit).with(item);
                         this.batches.insert(item_key.clone(), batch);
-                        this.timer.insert(item_key);
+                        match timeout {
+                            Some(timeout) => this.timer.insert_with_timeout(item_key, timeout),
+                            None => this.timer.insert(item_key),
+                        }
                     }
                 }
             }
@@ -460,14 +546,14 @@ mod test {
         time::Duration,
     };
 
-    use futures::{stream, Stream};
+    use futures::{stream, Stream, StreamExt};
     use pin_project::pin_project;
     use proptest::prelude::*;
     use tokio::{pin, time::advance};
 
     use crate::{
         partition::Partitioner,
-        stream::partitioned_batcher::{ExpirationQueue, PartitionedBatcher},
+        stream::partitioned_batcher::{BatcherSettings, ExpirationQueue, PartitionedBatcher},
         time::KeyedTimer,
     };
 
@@ -501,6 +587,14 @@ mod test {
             self.valid_keys.insert(item_key);
         }
 
+        fn insert_with_timeout(&mut self, item_key: u8, _timeout: Duration) {
+            self.insert(item_key);
+        }
+
+        fn remove(&mut self, item_key: &u8) {
+            self.valid_keys.remove(item_key);
+        }
+
         fn poll_expired(&mut self, _cx: &mut Context) -> Poll<Option<u8>> {
             match self.responses.pop() {
                 Some(Poll::Pending) => unreachable!(),
@@ -786,6 +880,80 @@ mod test {
         assert_eq!(result, Poll::Ready(None));
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn partition_settings_override_batch_limits_and_timeout() {
+        // Asserts that a partition with overridden settings flushes small
+        // batches quickly, while the other partitions keep the batcher's own
+        // settings.
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        let partitioner = TestPartitioner {
+            key_space: NonZeroU8::new(2).unwrap(),
+        };
+        let settings = BatcherSettings::new(
+            Duration::from_secs(10),
+            NonZeroUsize::new(1024).unwrap(),
+            NonZeroUsize::new(10).unwrap(),
+        );
+        let low_latency = BatcherSettings::new(
+            Duration::from_secs(1),
+            NonZeroUsize::new(1024).unwrap(),
+            NonZeroUsize::new(2).unwrap(),
+        );
+        let batcher = PartitionedBatcher::new(receiver, partitioner, settings)
+            .with_partition_settings(move |key: &u8| (*key == 1).then_some(low_latency));
+        pin!(batcher);
+
+        for item in [1, 3, 5, 0, 2, 4] {
+            sender.unbounded_send(item).unwrap();
+        }
+        // Partition 1 flushes after two items, partition 0 keeps batching.
+        assert_eq!(batcher.next().await, Some((1, vec![1, 3])));
+        let result = single_poll(|cx| batcher.as_mut().poll_next(cx));
+        assert_eq!(result, Poll::Pending);
+
+        // Partition 1 times out after a second, partition 0 after ten.
+        advance(Duration::from_secs(1) + Duration::from_nanos(1)).await;
+        assert_eq!(batcher.next().await, Some((1, vec![5])));
+        let result = single_poll(|cx| batcher.as_mut().poll_next(cx));
+        assert_eq!(result, Poll::Pending);
+
+        advance(Duration::from_secs(9)).await;
+        assert_eq!(batcher.next().await, Some((0, vec![0, 2, 4])));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn max_partitions_closes_largest_batch() {
+        // Asserts that a new partition beyond the maximum closes the largest
+        // live batch, and that its expiration is dropped along with it.
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        let partitioner = TestPartitioner {
+            key_space: NonZeroU8::new(3).unwrap(),
+        };
+        let settings = BatcherSettings::new(
+            Duration::from_secs(10),
+            NonZeroUsize::new(1024).unwrap(),
+            NonZeroUsize::new(10).unwrap(),
+        );
+        let batcher = PartitionedBatcher::new(receiver, partitioner, settings)
+            .with_max_partitions(NonZeroUsize::new(2).unwrap());
+        pin!(batcher);
+
+        for item in [0, 3, 1, 2] {
+            sender.unbounded_send(item).unwrap();
+        }
+        // Partition 2 makes room by closing partition 0, the largest.
+        assert_eq!(batcher.next().await, Some((0, vec![0, 3])));
+        let result = single_poll(|cx| batcher.as_mut().poll_next(cx));
+        assert_eq!(result, Poll::Pending);
+
+        advance(Duration::from_secs(10) + Duration::from_nanos(1)).await;
+        let mut expired = vec![batcher.next().await, batcher.next().await];
+        expired.sort();
+        assert_eq!(expired, vec![Some((1, vec![1])), Some((2, vec![2]))]);
+        let result = single_poll(|cx| batcher.as_mut().poll_next(cx));
+        assert_eq!(result, Poll::Pending);
+    }
+
     fn single_poll<T, F>(mut f: F) -> Poll<T>
     where
         F: FnMut(&mut Context<'_>) -> Poll<T>,
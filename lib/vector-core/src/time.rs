@@ -1,6 +1,9 @@
 //! Time utilities for vector-core
 
-use std::task::{Context, Poll};
+use std::{
+    task::{Context, Poll},
+    time::Duration,
+};
 
 /// A trait for representing a timer which holds multiple subtimers, mapped by an arbitrary key, `K`.
 ///
@@ -21,6 +24,15 @@ pub trait KeyedTimer<K> {
     /// If the given key already exists in the timer, the underlying subtimer is reset.
     fn insert(&mut self, item_key: K);
 
+    /// Insert a new subtimer, keyed by `K`, expiring after the given timeout rather than the
+    /// timer's own.
+    ///
+    /// If the given key already exists in the timer, the underlying subtimer is reset.
+    fn insert_with_timeout(&mut self, item_key: K, timeout: Duration);
+
+    /// Removes the subtimer keyed by `K`, if any, so that it never expires.
+    fn remove(&mut self, item_key: &K);
+
     /// Attempts to pull out the next expired subtimer in the queue.
     ///
     /// The key of the subtimer is returned if it has expired, otherwise, returns `None` if the
@@ -17,11 +17,13 @@ use crate::topology::schema::merged_definition;
 use crate::{
     api::schema::{
         components::state::component_by_component_key,
+        events::dropped::DroppedEvent,
         filter::{self, filter_items},
         relay, sort,
     },
-    config::{ComponentKey, Config},
+    config::{ComponentKey, Config, OutputId},
     filter_check,
+    topology::{dropped_samples, TapOutput},
 };
 
 #[derive(Debug, Clone, Interface)]
@@ -104,6 +106,9 @@ impl sort::SortableByField<ComponentsSortFieldName> for Component {
     }
 }
 
+/// The number of dropped events returned by `component_errors` if no limit is given.
+const DEFAULT_DROPPED_EVENTS_LIMIT: usize = 10;
+
 #[derive(Default)]
 pub struct ComponentsQuery;
 
@@ -215,6 +220,36 @@ impl ComponentsQuery {
         let key = ComponentKey::from(component_id);
         component_by_component_key(&key)
     }
+
+    /// Events most recently dropped by a component, newest first. Only retained for components
+    /// configured with `dropped_event_samples`
+    async fn component_errors(
+        &self,
+        component_id: String,
+        limit: Option<i32>,
+    ) -> Vec<DroppedEvent> {
+        let key = ComponentKey::from(component_id);
+        let component = match component_by_component_key(&key) {
+            Some(component) => component,
+            None => return Vec::new(),
+        };
+        let (component_kind, component_type) = match &component {
+            Component::Source(c) => ("source", c.get_component_type()),
+            Component::Transform(c) => ("transform", c.get_component_type()),
+            Component::Sink(c) => ("sink", c.get_component_type()),
+        };
+        let output = TapOutput {
+            output_id: OutputId::from(&key),
+            component_kind,
+            component_type: component_type.to_owned(),
+        };
+
+        let limit = limit.map_or(DEFAULT_DROPPED_EVENTS_LIMIT, |limit| limit.max(0) as usize);
+        dropped_samples::latest(&key, limit)
+            .into_iter()
+            .map(|sample| DroppedEvent::new(output.clone(), sample))
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug)]
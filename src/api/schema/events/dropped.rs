@@ -0,0 +1,44 @@
+use async_graphql::Object;
+use chrono::{DateTime, Utc};
+
+use super::{log::Log, metric::Metric, output::OutputEventsPayload, trace::Trace};
+use crate::{
+    event::Event,
+    topology::{dropped_samples::DroppedEventSample, TapOutput},
+};
+
+#[derive(Debug, Clone)]
+pub struct DroppedEvent {
+    output: TapOutput,
+    sample: DroppedEventSample,
+}
+
+impl DroppedEvent {
+    pub const fn new(output: TapOutput, sample: DroppedEventSample) -> Self {
+        Self { output, sample }
+    }
+}
+
+#[Object]
+/// An event dropped by a component, retained for debugging
+impl DroppedEvent {
+    /// Why the event was dropped
+    async fn reason(&self) -> &str {
+        self.sample.reason.as_str()
+    }
+
+    /// When the event was dropped
+    async fn timestamp(&self) -> &DateTime<Utc> {
+        &self.sample.timestamp
+    }
+
+    /// The dropped event, with its sensitive fields redacted
+    async fn event(&self) -> OutputEventsPayload {
+        let output = self.output.clone();
+        match self.sample.event.clone() {
+            Event::Log(log) => OutputEventsPayload::Log(Log::new(output, log)),
+            Event::Metric(metric) => OutputEventsPayload::Metric(Metric::new(output, metric)),
+            Event::Trace(trace) => OutputEventsPayload::Trace(Trace::new(output, trace)),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod dropped;
 mod encoding;
 pub mod log;
 pub mod metric;
@@ -6,6 +6,8 @@ mod output;
 mod processed_bytes;
 mod processed_events;
 mod received_events;
+mod request_queue_depth;
+mod requests_in_flight;
 mod sent_events;
 mod sink;
 pub mod source;
@@ -31,6 +33,8 @@ pub use processed_events::{
 pub use received_events::{
     ComponentReceivedEventsThroughput, ComponentReceivedEventsTotal, ReceivedEventsTotal,
 };
+pub use request_queue_depth::{ComponentRequestQueueDepth, RequestQueueDepth};
+pub use requests_in_flight::{ComponentRequestsInFlight, RequestsInFlight};
 pub use sent_events::{ComponentSentEventsThroughput, ComponentSentEventsTotal, SentEventsTotal};
 pub use sink::{IntoSinkMetrics, SinkMetrics};
 pub use source::{IntoSourceMetrics, SourceMetrics};
@@ -297,6 +301,24 @@ impl MetricsSubscription {
             .map(|m| m.into_iter().map(ComponentErrorsTotal::new).collect())
     }
 
+    /// Component sink requests in flight over `interval`.
+    async fn component_requests_in_flight(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentRequestsInFlight>> {
+        component_gauge_metrics(interval, &|m| m.name() == "sink_requests_in_flight")
+            .map(|m| m.into_iter().map(ComponentRequestsInFlight::new).collect())
+    }
+
+    /// Component sink request queue depth over `interval`.
+    async fn component_request_queue_depth(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentRequestQueueDepth>> {
+        component_gauge_metrics(interval, &|m| m.name() == "sink_request_queue_depth")
+            .map(|m| m.into_iter().map(ComponentRequestQueueDepth::new).collect())
+    }
+
     /// All metrics.
     async fn metrics(
         &self,
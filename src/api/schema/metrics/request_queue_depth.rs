@@ -0,0 +1,71 @@
+use async_graphql::Object;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+pub struct RequestQueueDepth(Metric);
+
+impl RequestQueueDepth {
+    pub const fn new(m: Metric) -> Self {
+        Self(m)
+    }
+}
+
+#[Object]
+impl RequestQueueDepth {
+    /// Metric timestamp
+    pub async fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.0.timestamp()
+    }
+
+    /// Number of events batched by the sink and not yet dispatched
+    pub async fn request_queue_depth(&self) -> f64 {
+        match self.0.value() {
+            MetricValue::Gauge { value } => *value,
+            _ => 0.00,
+        }
+    }
+}
+
+impl From<Metric> for RequestQueueDepth {
+    fn from(m: Metric) -> Self {
+        Self(m)
+    }
+}
+
+pub struct ComponentRequestQueueDepth {
+    component_key: ComponentKey,
+    metric: Metric,
+}
+
+impl ComponentRequestQueueDepth {
+    /// Returns a new `ComponentRequestQueueDepth` struct, which is a GraphQL type. The
+    /// component id is hoisted for clear field resolution in the resulting payload
+    pub fn new(metric: Metric) -> Self {
+        let component_key = metric.tag_value("component_id").expect(
+            "Returned a metric without a `component_id`, which shouldn't happen. Please report.",
+        );
+        let component_key = ComponentKey::from(component_key);
+
+        Self {
+            component_key,
+            metric,
+        }
+    }
+}
+
+#[Object]
+impl ComponentRequestQueueDepth {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Request queue depth metric
+    async fn metric(&self) -> RequestQueueDepth {
+        RequestQueueDepth::new(self.metric.clone())
+    }
+}
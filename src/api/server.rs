@@ -31,6 +31,7 @@ impl Server {
         let routes = make_routes(config.api.playground, watch_rx, running);
 
         let (_shutdown, rx) = oneshot::channel();
+        // TODO: Accept a hostname for `api.address`, as source listeners do.
         let (addr, server) = warp::serve(routes).bind_with_graceful_shutdown(
             config.api.address.expect("No socket address"),
             async {
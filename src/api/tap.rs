@@ -580,6 +580,9 @@ mod tests {
             BlackholeConfig {
                 print_interval_secs: 1,
                 rate: None,
+                smoothing: None,
+                metrics_flush_interval_secs: None,
+                openmetrics_address: None,
                 acknowledgements: Default::default(),
             },
         );
@@ -637,6 +640,9 @@ mod tests {
             BlackholeConfig {
                 print_interval_secs: 1,
                 rate: None,
+                smoothing: None,
+                metrics_flush_interval_secs: None,
+                openmetrics_address: None,
                 acknowledgements: Default::default(),
             },
         );
@@ -687,6 +693,9 @@ mod tests {
             BlackholeConfig {
                 print_interval_secs: 1,
                 rate: None,
+                smoothing: None,
+                metrics_flush_interval_secs: None,
+                openmetrics_address: None,
                 acknowledgements: Default::default(),
             },
         );
@@ -740,6 +749,9 @@ mod tests {
             BlackholeConfig {
                 print_interval_secs: 1,
                 rate: None,
+                smoothing: None,
+                metrics_flush_interval_secs: None,
+                openmetrics_address: None,
                 acknowledgements: Default::default(),
             },
         );
@@ -814,6 +826,9 @@ mod tests {
             BlackholeConfig {
                 print_interval_secs: 1,
                 rate: None,
+                smoothing: None,
+                metrics_flush_interval_secs: None,
+                openmetrics_address: None,
                 acknowledgements: Default::default(),
             },
         );
@@ -874,6 +889,9 @@ mod tests {
             BlackholeConfig {
                 print_interval_secs: 1,
                 rate: None,
+                smoothing: None,
+                metrics_flush_interval_secs: None,
+                openmetrics_address: None,
                 acknowledgements: Default::default(),
             },
         );
@@ -950,6 +968,9 @@ mod tests {
             BlackholeConfig {
                 print_interval_secs: 1,
                 rate: None,
+                smoothing: None,
+                metrics_flush_interval_secs: None,
+                openmetrics_address: None,
                 acknowledgements: Default::default(),
             },
         );
@@ -142,6 +142,9 @@ async fn integration_test_source_log() {
         BlackholeConfig {
             print_interval_secs: 1,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
         },
     );
 
@@ -192,6 +195,9 @@ async fn integration_test_source_metric() {
         BlackholeConfig {
             print_interval_secs: 1,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
         },
     );
 
@@ -235,6 +241,9 @@ async fn integration_test_transform() {
         BlackholeConfig {
             print_interval_secs: 1,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
         },
     );
 
@@ -283,6 +292,9 @@ async fn integration_test_tap_non_default_output() {
         BlackholeConfig {
             print_interval_secs: 1,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
         },
     );
 
@@ -355,6 +367,9 @@ async fn integration_test_tap_multiple_outputs() {
         BlackholeConfig {
             print_interval_secs: 1,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
         },
     );
 
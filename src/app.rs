@@ -1,4 +1,4 @@
-use std::{collections::HashMap, num::NonZeroUsize, path::PathBuf};
+use std::{collections::HashMap, num::NonZeroUsize, path::PathBuf, sync::atomic::Ordering};
 
 use futures::StreamExt;
 #[cfg(feature = "enterprise")]
@@ -25,7 +25,7 @@ use crate::{
     cli::{handle_config_errors, Color, LogFormat, Opts, RootOpts, SubCommand},
     config::{self},
     generate, graph, heartbeat, list,
-    signal::{self, SignalTo},
+    signal::{self, ShutdownRequest, SignalTo},
     topology::{self, RunningTopology},
     trace, unit_test, validate,
 };
@@ -111,6 +111,12 @@ impl Application {
             LogFormat::Json => true,
         };
 
+        config::STRICT_ENV_VARS.store(root_opts.strict_env_vars, Ordering::Relaxed);
+        config::DENY_DANGLING.store(
+            root_opts.deny.iter().any(|lint| lint == "dangling"),
+            Ordering::Relaxed,
+        );
+
         #[cfg(not(feature = "enterprise-tests"))]
         metrics::init_global().expect("metrics initialization failed");
 
@@ -247,7 +253,10 @@ impl Application {
         })
     }
 
-    pub fn run(self) {
+    /// Runs Vector until it's shut down, returning the exit code of the process.
+    ///
+    /// Shutting down because a component requested it on a failure exits with a failure code.
+    pub fn run(self) -> exitcode::ExitCode {
         let rt = self.runtime;
 
         let mut graceful_crash = UnboundedReceiverStream::new(self.config.graceful_crash);
@@ -265,6 +274,7 @@ impl Application {
 
         let mut signal_handler = self.config.signal_handler;
         let mut signal_rx = self.config.signal_rx;
+        let mut shutdown_requests = signal::shutdown_requests();
 
         // Any internal_logs sources will have grabbed a copy of the
         // early buffer by this point and set up a subscriber.
@@ -291,6 +301,7 @@ impl Application {
             };
 
             let mut sources_finished = topology.sources_finished();
+            let mut failed = false;
 
             let signal = loop {
                 tokio::select! {
@@ -406,6 +417,11 @@ impl Application {
                     // Trigger graceful shutdown if a component crashed, or all sources have ended.
                     _ = graceful_crash.next() => break SignalTo::Shutdown,
                     _ = &mut sources_finished => break SignalTo::Shutdown,
+                    // Trigger graceful shutdown if a component requested it.
+                    request = shutdown_requests.recv() => {
+                        failed = matches!(request, Ok(ShutdownRequest::Failed));
+                        break SignalTo::Shutdown;
+                    }
                     else => unreachable!("Signal streams never end"),
                 }
             };
@@ -429,6 +445,12 @@ impl Application {
                 }
                 _ => unreachable!(),
             }
-        });
+
+            if failed {
+                exitcode::SOFTWARE
+            } else {
+                exitcode::OK
+            }
+        })
     }
 }
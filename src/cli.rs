@@ -149,6 +149,24 @@ pub struct RootOpts {
     /// Watch for changes in configuration file, and reload accordingly.
     #[clap(short, long, env = "VECTOR_WATCH_CONFIG")]
     pub watch_config: bool,
+
+    /// Fail to load configs referencing environment variables that aren't set and have no
+    /// default, instead of interpolating them as empty strings.
+    #[clap(long, env = "VECTOR_STRICT_ENV_VARS")]
+    pub strict_env_vars: bool,
+
+    /// Turn the given kinds of config warnings into errors.
+    ///
+    /// `dangling` fails configs with components whose outputs don't reach any sink. When the API
+    /// is enabled, components without consumers aren't considered dangling, as they can be
+    /// observed with `vector tap`.
+    #[clap(
+        long,
+        env = "VECTOR_DENY",
+        possible_values = &["dangling"],
+        use_value_delimiter(true)
+    )]
+    pub deny: Vec<String>,
 }
 
 impl RootOpts {
@@ -9,6 +9,12 @@
 ))]
 pub(crate) mod datadog;
 
+#[cfg(any(
+    feature = "sources-aws_cloudwatch_logs",
+    feature = "sinks-aws_cloudwatch_logs"
+))]
+pub(crate) mod cloudwatch_logs;
+
 #[cfg(any(
     feature = "sources-aws_sqs",
     feature = "sinks-aws_sqs",
@@ -45,11 +45,22 @@ impl ConditionalConfig for VrlConfig {
             .chain(vector_vrl_functions::vrl_functions())
             .collect::<Vec<_>>();
 
+        self.build_with_functions(&functions, enrichment_tables)
+    }
+}
+
+impl VrlConfig {
+    /// Builds the condition, with only the given functions available to the VRL program.
+    pub(crate) fn build_with_functions(
+        &self,
+        functions: &[Box<dyn vrl::Function>],
+        enrichment_tables: &enrichment::TableRegistry,
+    ) -> crate::Result<Condition> {
         let mut state = vrl::state::ExternalEnv::default().read_only();
         state.set_external_context(enrichment_tables.clone());
 
         let (program, warnings) =
-            compile_vrl(&self.source, &functions, &mut state, LocalEnv::default()).map_err(
+            compile_vrl(&self.source, functions, &mut state, LocalEnv::default()).map_err(
                 |diagnostics| {
                     Formatter::new(&self.source, diagnostics)
                         .colored()
@@ -1,12 +1,14 @@
-#[cfg(feature = "enterprise")]
 use std::collections::BTreeMap;
 use std::path::Path;
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "enterprise")]
 use serde_json::Value;
-use vector_core::{config::GlobalOptions, default_data_dir, transform::TransformConfig};
+use vector_core::{
+    config::{GlobalOptions, TelemetryConfig},
+    default_data_dir,
+    transform::TransformConfig,
+};
 
 #[cfg(feature = "api")]
 use super::api;
@@ -48,7 +50,6 @@ pub struct ConfigBuilder {
     pub secret: IndexMap<ComponentKey, Box<dyn SecretBackend>>,
 }
 
-#[cfg(feature = "enterprise")]
 #[derive(Serialize)]
 struct ConfigBuilderHash<'a> {
     #[cfg(feature = "api")]
@@ -65,20 +66,19 @@ struct ConfigBuilderHash<'a> {
     secret: BTreeMap<&'a ComponentKey, &'a dyn SecretBackend>,
 }
 
-#[cfg(feature = "enterprise")]
 impl ConfigBuilderHash<'_> {
     /// Sort inner JSON values to maintain a consistent ordering. This prevents
     /// non-deterministically serializable structures like HashMap from
     /// affecting the resulting hash. As a consequence, ordering that does not
     /// affect the actual semantics of a configuration is not considered when
     /// calculating the hash.
-    fn into_hash(self) -> String {
+    fn into_hash(self) -> Result<String, serde_json::Error> {
         use sha2::{Digest, Sha256};
 
-        let value = to_sorted_json_string(self);
+        let value = to_sorted_json_string(self)?;
         let output = Sha256::digest(value.as_bytes());
 
-        hex::encode(output)
+        Ok(hex::encode(output))
     }
 }
 
@@ -92,18 +92,16 @@ impl ConfigBuilderHash<'_> {
 ///
 /// Rather than rely on the opaque underlying serde structures, we are explicit
 /// about sorting, sacrificing a bit of potential convenience for correctness.
-#[cfg(feature = "enterprise")]
-fn to_sorted_json_string<T>(value: T) -> String
+fn to_sorted_json_string<T>(value: T) -> Result<String, serde_json::Error>
 where
     T: Serialize,
 {
-    let mut value = serde_json::to_value(value).expect("Should serialize to JSON. Please report.");
+    let mut value = serde_json::to_value(value)?;
     sort_json_value(&mut value);
 
-    serde_json::to_string(&value).expect("Should serialize Value to JSON string. Please report.")
+    serde_json::to_string(&value)
 }
 
-#[cfg(feature = "enterprise")]
 fn sort_json_value(value: &mut Value) {
     match value {
         Value::Array(arr) => {
@@ -125,7 +123,6 @@ fn sort_json_value(value: &mut Value) {
     }
 }
 
-#[cfg(feature = "enterprise")]
 impl<'a> From<&'a ConfigBuilder> for ConfigBuilderHash<'a> {
     fn from(value: &'a ConfigBuilder) -> Self {
         ConfigBuilderHash {
@@ -270,6 +267,7 @@ impl ConfigBuilder {
         let transform = TransformOuter {
             inner: Box::new(transform),
             inputs,
+            dropped_event_samples: None,
         };
 
         self.transforms
@@ -321,9 +319,22 @@ impl ConfigBuilder {
 
         self.global.expire_metrics = self.global.expire_metrics.or(with.global.expire_metrics);
 
-        self.schema.append(with.schema, &mut errors);
+        if self.global.telemetry == TelemetryConfig::default() {
+            self.global.telemetry = with.global.telemetry;
+        } else if with.global.telemetry != TelemetryConfig::default()
+            && self.global.telemetry != with.global.telemetry
+        {
+            errors.push("conflicting values for 'telemetry' found".to_owned());
+        }
 
-        self.schema.log_namespace = self.schema.log_namespace.or(with.schema.log_namespace);
+        if self.schema.log_namespace.is_some()
+            && with.schema.log_namespace.is_some()
+            && self.schema.log_namespace != with.schema.log_namespace
+        {
+            errors.push("conflicting values for 'schema.log_namespace' found".to_owned());
+        }
+
+        self.schema.append(with.schema);
 
         if self.global.data_dir.is_none() || self.global.data_dir == default_data_dir() {
             self.global.data_dir = with.global.data_dir;
@@ -387,10 +398,11 @@ impl ConfigBuilder {
         Ok(())
     }
 
-    #[cfg(feature = "enterprise")]
     /// SHA256 hexadecimal representation of a config builder. This is generated by serializing
     /// an order-stable JSON of the config builder and feeding its bytes into a SHA256 hasher.
-    pub fn sha256_hash(&self) -> String {
+    ///
+    /// Fails if a component's configuration can't be serialized to JSON.
+    pub fn sha256_hash(&self) -> Result<String, serde_json::Error> {
         ConfigBuilderHash::from(self).into_hash()
     }
 
@@ -468,7 +480,7 @@ mod tests {
     fn version_hash_match() {
         assert_eq!(
             "bc0825487e137ee1d1fc76c616795d041c4825b4ca5a7236455ea4515238885c",
-            ConfigBuilder::default().sha256_hash()
+            ConfigBuilder::default().sha256_hash().unwrap()
         );
     }
 
@@ -585,7 +597,7 @@ mod tests {
         let ordered_map = IndexMap::from([("z", 26), ("a", 1), ("d", 4), ("c", 3), ("b", 2)]);
         assert_eq!(
             r#"{"a":1,"b":2,"c":3,"d":4,"z":26}"#.to_string(),
-            to_sorted_json_string(ordered_map)
+            to_sorted_json_string(ordered_map).unwrap()
         );
     }
 }
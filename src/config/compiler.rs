@@ -1,4 +1,7 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use indexmap::{IndexMap, IndexSet};
 
@@ -6,6 +9,10 @@ use super::{
     builder::ConfigBuilder, graph::Graph, schema, validation, ComponentKey, Config, OutputId,
 };
 
+/// Whether configs with components whose outputs don't reach any sink fail to compile, instead of
+/// being warned about.
+pub static DENY_DANGLING: AtomicBool = AtomicBool::new(false);
+
 /// to handle the expansions when building the graph we need to be able to get the list of inputs
 /// that will replace a single input, as a String.
 pub(crate) fn to_string_expansions(
@@ -53,11 +60,28 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
         errors.extend(output_errors);
     }
 
-    #[cfg(feature = "enterprise")]
-    let version = Some(builder.sha256_hash());
+    if let Err(fallback_errors) = validation::check_fallbacks(&builder) {
+        errors.extend(fallback_errors);
+    }
 
-    #[cfg(not(feature = "enterprise"))]
-    let version = None;
+    if let Err(telemetry_errors) = builder.global.telemetry.validate() {
+        errors.extend(telemetry_errors);
+    }
+
+    // The hash is reported as a `config_hash_info` gauge, but is only required to report the
+    // configuration to Datadog Observability Pipelines.
+    let version = match builder.sha256_hash() {
+        Ok(hash) => Some(hash),
+        #[cfg(feature = "enterprise")]
+        Err(error) if builder.enterprise.is_some() => {
+            errors.push(format!("Failed to hash the configuration: {}", error));
+            None
+        }
+        Err(error) => {
+            warn!(message = "Failed to hash the configuration.", %error);
+            None
+        }
+    };
 
     let ConfigBuilder {
         global,
@@ -135,6 +159,10 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
 
         config.propagate_acknowledgements()?;
 
+        if DENY_DANGLING.load(Ordering::Relaxed) {
+            validation::check_dangling(&config)?;
+        }
+
         let warnings = validation::warnings(&config);
 
         Ok((config, warnings))
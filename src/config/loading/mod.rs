@@ -8,7 +8,10 @@ use std::{
     fmt::Debug,
     fs::{File, ReadDir},
     path::{Path, PathBuf},
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
 };
 
 use config_builder::ConfigBuilderLoader;
@@ -27,6 +30,10 @@ use crate::signal;
 
 pub static CONFIG_PATHS: Lazy<Mutex<Vec<ConfigPath>>> = Lazy::new(Mutex::default);
 
+/// Whether configs referencing environment variables that aren't set and have no default fail to
+/// load, instead of interpolating them as empty strings.
+pub static STRICT_ENV_VARS: AtomicBool = AtomicBool::new(false);
+
 pub(super) fn read_dir<P: AsRef<Path> + Debug>(path: P) -> Result<ReadDir, Vec<String>> {
     path.as_ref()
         .read_dir()
@@ -290,7 +297,11 @@ pub fn prepare_input<R: std::io::Read>(mut input: R) -> Result<(String, Vec<Stri
             vars.insert("HOSTNAME".into(), hostname);
         }
     }
-    vars::interpolate(&source_string, &vars)
+    if STRICT_ENV_VARS.load(Ordering::Relaxed) {
+        vars::interpolate_strict(&source_string, &vars)
+    } else {
+        vars::interpolate(&source_string, &vars)
+    }
 }
 
 pub fn load<R: std::io::Read, T>(input: R, format: Format) -> Result<(T, Vec<String>), Vec<String>>
@@ -2,7 +2,7 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::{self, Display, Formatter},
     hash::Hash,
-    net::SocketAddr,
+    net::{SocketAddr, ToSocketAddrs},
     path::PathBuf,
 };
 
@@ -28,7 +28,7 @@ mod graph;
 mod id;
 mod loading;
 pub mod provider;
-mod schema;
+pub mod schema;
 mod sink;
 mod source;
 mod transform;
@@ -39,15 +39,18 @@ pub mod watcher;
 
 pub use builder::ConfigBuilder;
 pub use cmd::{cmd, Opts};
+pub use compiler::DENY_DANGLING;
 pub use diff::ConfigDiff;
 pub use format::{Format, FormatHint};
 pub use id::{ComponentKey, OutputId};
 pub use loading::{
     load, load_builder_from_paths, load_from_paths, load_from_paths_with_provider_and_secrets,
     load_from_str, load_source_from_paths, merge_path_lists, process_paths, SecretBackend,
-    CONFIG_PATHS,
+    CONFIG_PATHS, STRICT_ENV_VARS,
+};
+pub use sink::{
+    SinkConfig, SinkContext, SinkDescription, SinkFallbackConfig, SinkHealthcheckOptions, SinkOuter,
 };
-pub use sink::{SinkConfig, SinkContext, SinkDescription, SinkHealthcheckOptions, SinkOuter};
 pub use source::{SourceConfig, SourceContext, SourceDescription, SourceOuter};
 pub use transform::{TransformDescription, TransformOuter};
 pub use unit_test::{build_unit_tests, build_unit_tests_main, UnitTestResult};
@@ -275,6 +278,10 @@ inventory::collect!(EnrichmentTableDescription);
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum Resource {
     Port(SocketAddr, Protocol),
+    /// A `host:port` address, resolved when binding.
+    ///
+    /// Conflicts are detected between the addresses it resolves to.
+    Host(String, Protocol),
     SystemFdOffset(usize),
     Stdin,
     DiskBuffer(String),
@@ -295,6 +302,23 @@ impl Resource {
         Self::Port(addr, Protocol::Udp)
     }
 
+    /// Resolves a `Host` into a `Port` for each of its addresses, so it conflicts with the other
+    /// resources of these addresses. Hosts that don't resolve are kept as they are.
+    fn resolve(self) -> Vec<Resource> {
+        let resolved = match &self {
+            Resource::Host(host, protocol) => host
+                .to_socket_addrs()
+                .map(|addrs| addrs.map(|addr| Resource::Port(addr, *protocol)).collect())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        if resolved.is_empty() {
+            vec![self]
+        } else {
+            resolved
+        }
+    }
+
     /// From given components returns all that have a resource conflict with any other component.
     pub fn conflicts<K: Eq + Hash + Clone>(
         components: impl IntoIterator<Item = (K, Vec<Resource>)>,
@@ -304,7 +328,7 @@ impl Resource {
 
         // Find equality based conflicts
         for (key, resources) in components {
-            for resource in resources {
+            for resource in resources.into_iter().flat_map(Resource::resolve) {
                 if let Resource::Port(address, protocol) = &resource {
                     if address.ip().is_unspecified() {
                         unspecified.push((key.clone(), address.port(), *protocol));
@@ -350,6 +374,7 @@ impl Display for Resource {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         match self {
             Resource::Port(address, protocol) => write!(fmt, "{} {}", protocol, address),
+            Resource::Host(host, protocol) => write!(fmt, "{} {}", protocol, host),
             Resource::SystemFdOffset(offset) => write!(fmt, "systemd {}th socket", offset + 1),
             Resource::Stdin => write!(fmt, "stdin"),
             Resource::DiskBuffer(name) => write!(fmt, "disk buffer {:?}", name),
@@ -536,7 +561,9 @@ mod tests {
     use crate::{config, topology};
     use indoc::indoc;
 
-    use super::{builder::ConfigBuilder, format, load_from_str, ComponentKey, ConfigDiff, Format};
+    use super::{
+        builder::ConfigBuilder, format, load_from_str, validation, ComponentKey, ConfigDiff, Format,
+    };
 
     async fn load(config: &str, format: config::Format) -> Result<Vec<String>, Vec<String>> {
         match config::load_from_str(config, format) {
@@ -625,6 +652,116 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn bad_fallbacks() {
+        let err = load(
+            r#"
+            acknowledgements.enabled = true
+
+            [sources.in]
+            type = "basic_source"
+
+            [sinks.out]
+            type = "basic_sink"
+            inputs = ["in"]
+            fallback.sink = "archive"
+
+            [sinks.self_fallback]
+            type = "basic_sink"
+            inputs = ["in"]
+            fallback.sink = "self_fallback"
+            fallback.consecutive_failures = 0
+
+            [sinks.rate]
+            type = "basic_sink"
+            inputs = ["in"]
+            fallback.sink = "out"
+            fallback.error_rate = 1.5
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            vec![
+                "Fallback \"archive\" for sink \"out\" doesn't match any sinks.",
+                "Sink \"self_fallback\" cannot be its own fallback.",
+                "Sink \"self_fallback\" must have a fallback `consecutive_failures` greater than 0.",
+                "Fallback \"out\" for sink \"rate\" cannot have a fallback itself.",
+                "Sink \"rate\" must have a fallback `error_rate` greater than 0 and at most 1.",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fallback_requires_acknowledgements() {
+        let err = load(
+            r#"
+            [sources.in]
+            type = "basic_source"
+
+            [sinks.out]
+            type = "basic_sink"
+            inputs = ["in"]
+            fallback.sink = "archive"
+
+            [sinks.archive]
+            type = "basic_sink"
+            inputs = ["in"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            vec!["Sink \"out\" must have acknowledgements enabled to use a fallback."]
+        );
+    }
+
+    #[cfg(feature = "sinks-http")]
+    #[tokio::test]
+    async fn fallback_requires_bounded_retries() {
+        let err = load(
+            r#"
+            acknowledgements.enabled = true
+
+            [sources.in]
+            type = "basic_source"
+
+            [sinks.out]
+            type = "http"
+            inputs = ["in"]
+            uri = "http://localhost:8080"
+            encoding.codec = "json"
+            fallback.sink = "archive"
+
+            [sinks.bounded]
+            type = "http"
+            inputs = ["in"]
+            uri = "http://localhost:8080"
+            encoding.codec = "json"
+            request.retry_attempts = 3
+            fallback.sink = "archive"
+
+            [sinks.archive]
+            type = "basic_sink"
+            inputs = ["in"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            vec!["Sink \"out\" must set `request.retry_attempts` to use a fallback."]
+        );
+    }
+
     #[tokio::test]
     async fn warnings() {
         let warnings = load(
@@ -665,6 +802,136 @@ mod tests {
         )
     }
 
+    const DANGLING_CONFIG: &str = r#"
+        [sources.in1]
+        type = "basic_source"
+
+        [sources.in2]
+        type = "basic_source"
+
+        [transforms.sample1]
+        type = "basic_transform"
+        inputs = ["in1"]
+        suffix = "foo"
+        increase = 1.25
+
+        [transforms.sample2]
+        type = "basic_transform"
+        inputs = ["in2"]
+        suffix = "foo"
+        increase = 1.25
+
+        [transforms.sample3]
+        type = "basic_transform"
+        inputs = ["sample2"]
+        suffix = "foo"
+        increase = 1.25
+
+        [sinks.out]
+        type = "basic_sink"
+        inputs = ["sample1"]
+        "#;
+
+    #[tokio::test]
+    async fn dangling_warnings() {
+        let mut warnings = load(DANGLING_CONFIG, Format::Toml).await.unwrap();
+        warnings.sort();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Source \"in2\" doesn't reach any sink",
+                "Transform \"sample2\" doesn't reach any sink",
+                "Transform \"sample3\" has no consumers",
+            ]
+        )
+    }
+
+    #[test]
+    fn deny_dangling() {
+        let config = load_from_str(DANGLING_CONFIG, Format::Toml).unwrap();
+        let mut errors = validation::check_dangling(&config).unwrap_err();
+        errors.sort();
+
+        assert_eq!(
+            errors,
+            vec![
+                "Source \"in2\" doesn't reach any sink",
+                "Transform \"sample2\" doesn't reach any sink",
+                "Transform \"sample3\" has no consumers",
+            ]
+        );
+
+        let config = load_from_str(
+            r#"
+            [sources.in]
+            type = "basic_source"
+
+            [transforms.sample]
+            type = "basic_transform"
+            inputs = ["in"]
+            suffix = "foo"
+            increase = 1.25
+
+            [sinks.out]
+            type = "basic_sink"
+            inputs = ["sample"]
+            "#,
+            Format::Toml,
+        )
+        .unwrap();
+        assert!(validation::check_dangling(&config).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "api")]
+    fn dangling_allows_api_tap() {
+        // Components without consumers may be observed with `vector tap`.
+        let config = load_from_str(
+            &format!("[api]\nenabled = true\n{}", DANGLING_CONFIG),
+            Format::Toml,
+        )
+        .unwrap();
+
+        assert!(validation::check_dangling(&config).is_ok());
+        assert_eq!(
+            config::warnings(&config),
+            vec!["Transform \"sample3\" has no consumers"]
+        );
+    }
+
+    #[test]
+    fn invalid_telemetry() {
+        let errors = load_from_str(
+            r#"
+            [telemetry]
+            histogram_buckets = []
+            summary_quantiles = [0.5, 1.5]
+
+            [telemetry.histogram_buckets_by_name]
+            http_client_rtt_seconds = [0.1, inf]
+
+            [sources.in]
+            type = "basic_source"
+
+            [sinks.out]
+            type = "basic_sink"
+            inputs = ["in"]
+            "#,
+            Format::Toml,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                "'telemetry.histogram_buckets' must not be empty",
+                "'telemetry.histogram_buckets_by_name.http_client_rtt_seconds' must only contain finite numbers",
+                "'telemetry.summary_quantiles' must be between 0 and 1, found 1.5",
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn cycle() {
         let errors = load(
@@ -1052,7 +1319,10 @@ mod tests {
         )
         .unwrap();
 
-        assert_eq!(config1.sha256_hash(), config2.sha256_hash())
+        assert_eq!(
+            config1.sha256_hash().unwrap(),
+            config2.sha256_hash().unwrap()
+        )
     }
 
     #[test]
@@ -1100,7 +1370,10 @@ mod tests {
         )
         .unwrap();
 
-        assert_eq!(config1.sha256_hash(), config2.sha256_hash())
+        assert_eq!(
+            config1.sha256_hash().unwrap(),
+            config2.sha256_hash().unwrap()
+        )
     }
 }
 
@@ -1177,7 +1450,7 @@ mod resource_tests {
 
     use indoc::indoc;
 
-    use super::{load_from_str, Format, Resource};
+    use super::{load_from_str, Format, Protocol, Resource};
 
     fn localhost(port: u16) -> Resource {
         Resource::tcp(SocketAddr::new(Ipv4Addr::LOCALHOST.into(), port))
@@ -1267,6 +1540,38 @@ mod resource_tests {
         );
     }
 
+    #[test]
+    fn unspecified_network_interface_and_host() {
+        let components = vec![
+            (
+                "sink_0",
+                vec![Resource::Host("localhost:9000".into(), Protocol::Tcp)],
+            ),
+            (
+                "sink_1",
+                vec![Resource::tcp(SocketAddr::new(
+                    Ipv4Addr::UNSPECIFIED.into(),
+                    9000,
+                ))],
+            ),
+        ];
+        let conflicting = Resource::conflicts(components);
+        assert_eq!(conflicting[&localhost(9000)], ["sink_0", "sink_1"].into());
+    }
+
+    #[test]
+    fn host_and_resolved_address() {
+        let components = vec![
+            (
+                "sink_0",
+                vec![Resource::Host("localhost:514".into(), Protocol::Tcp)],
+            ),
+            ("sink_1", vec![localhost(514)]),
+        ];
+        let conflicting = Resource::conflicts(components);
+        assert_eq!(conflicting[&localhost(514)], ["sink_0", "sink_1"].into());
+    }
+
     #[test]
     fn different_protocol() {
         let components = vec![
@@ -1,5 +1,6 @@
+use lookup::LookupBuf;
 use serde::{Deserialize, Serialize};
-use vector_core::config::LogNamespace;
+use vector_core::config::{log_schema, LogNamespace};
 
 pub(crate) use crate::schema::Definition;
 
@@ -12,9 +13,54 @@ pub struct Options {
     #[serde(default = "default_validation")]
     pub validation: bool,
 
+    #[serde(deserialize_with = "log_namespace_string::deserialize")]
     pub log_namespace: Option<bool>,
 }
 
+/// Serializes a `log_namespace` option as the name of the namespace, `"vector"` or `"legacy"`,
+/// rather than as a boolean. Opt in with `#[serde(with = "log_namespace_string")]`.
+///
+/// Both the names and the booleans are accepted when deserializing, so existing configurations
+/// keep working. [`Options`] uses it to accept the names, but still serializes booleans.
+pub mod log_namespace_string {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use vector_core::config::LogNamespace;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrString {
+        Bool(bool),
+        String(String),
+    }
+
+    pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value.map(LogNamespace::from) {
+            Some(LogNamespace::Vector) => serializer.serialize_some("vector"),
+            Some(LogNamespace::Legacy) => serializer.serialize_some("legacy"),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Option::<BoolOrString>::deserialize(deserializer)? {
+            Some(BoolOrString::Bool(use_vector_namespace)) => Some(use_vector_namespace),
+            Some(BoolOrString::String(name)) => {
+                match LogNamespace::try_from(name).map_err(de::Error::custom)? {
+                    LogNamespace::Vector => Some(true),
+                    LogNamespace::Legacy => Some(false),
+                }
+            }
+            None => None,
+        })
+    }
+}
+
 impl Options {
     /// Gets the value of the globally configured log namespace, or the default if it wasn't set.
     pub fn log_namespace(self) -> LogNamespace {
@@ -24,17 +70,11 @@ impl Options {
             })
     }
 
-    /// Merges two schema options together.
-    pub fn append(&mut self, with: Self, errors: &mut Vec<String>) {
-        if self.log_namespace.is_some()
-            && with.log_namespace.is_some()
-            && self.log_namespace != with.log_namespace
-        {
-            errors.push(
-                format!("conflicting values for 'log_namespace' found. Both {:?} and {:?} used in the same component",
-                        self.log_namespace(), with.log_namespace())
-            );
-        }
+    /// Merges the options of a component into these global ones.
+    ///
+    /// The component's `log_namespace` takes precedence over the global one when it's set, and
+    /// the global one is inherited otherwise.
+    pub fn append(&mut self, with: Self) {
         if let Some(log_namespace) = with.log_namespace {
             self.log_namespace = Some(log_namespace);
         }
@@ -55,6 +95,170 @@ impl Default for Options {
     }
 }
 
+/// A change to the schema options that can break a configuration which worked with the previous
+/// options.
+struct BreakingChange {
+    /// The Vector version, as a semantic version, that introduced the option being changed.
+    since: &'static str,
+    check: fn(&Options, &Options) -> Option<String>,
+}
+
+const BREAKING_CHANGES: &[BreakingChange] = &[
+    BreakingChange {
+        since: "0.21.0",
+        check: schema_enabled,
+    },
+    BreakingChange {
+        since: "0.22.0",
+        check: validation_enabled,
+    },
+    BreakingChange {
+        since: "0.24.0",
+        check: log_namespace_changed,
+    },
+];
+
+fn schema_enabled(current: &Options, previous: &Options) -> Option<String> {
+    (current.enabled && !previous.enabled).then(|| {
+        "schema support was enabled, so transforms are now type checked against the schema \
+         definitions of their inputs"
+            .to_owned()
+    })
+}
+
+fn validation_enabled(current: &Options, previous: &Options) -> Option<String> {
+    (current.validation && !previous.validation).then(|| {
+        "schema validation was enabled, so sinks now reject inputs that don't meet their schema \
+         requirements"
+            .to_owned()
+    })
+}
+
+fn log_namespace_changed(current: &Options, previous: &Options) -> Option<String> {
+    let (from, to) = (previous.log_namespace(), current.log_namespace());
+    (from != to).then(|| {
+        let moved = field_aliases(from, to)
+            .into_iter()
+            .map(|(from_path, to_path)| {
+                format!(
+                    "{} moved to {}",
+                    namespaced_path(from, &from_path),
+                    namespaced_path(to, &to_path)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "the log namespace changed from {:?} to {:?}, so events now have a different shape ({})",
+            from, to, moved
+        )
+    })
+}
+
+/// Gets the fields that move when events migrate from the `from` log namespace to the `to` one, as
+/// pairs of their path before and after the migration.
+///
+/// Paths in the `Vector` namespace point into the event metadata rather than the event itself.
+pub fn field_aliases(from: LogNamespace, to: LogNamespace) -> Vec<(LookupBuf, LookupBuf)> {
+    let aliases = [
+        (log_schema().source_type_key(), "source_type"),
+        (log_schema().timestamp_key(), "ingest_timestamp"),
+    ]
+    .into_iter()
+    .map(|(legacy_key, vector_key)| {
+        let mut vector_path = LookupBuf::from(vector_key);
+        vector_path.push_front("vector");
+        (LookupBuf::from(legacy_key), vector_path)
+    });
+
+    match (from, to) {
+        (LogNamespace::Legacy, LogNamespace::Vector) => aliases.collect(),
+        (LogNamespace::Vector, LogNamespace::Legacy) => aliases
+            .map(|(legacy_path, vector_path)| (vector_path, legacy_path))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Formats a path the way VRL refers to it in the given log namespace.
+fn namespaced_path(namespace: LogNamespace, path: &LookupBuf) -> String {
+    match namespace {
+        LogNamespace::Legacy => format!("`.{}`", path),
+        LogNamespace::Vector => format!("`%{}`", path),
+    }
+}
+
+/// Checks whether configurations working with the `previous` schema options keep working with the
+/// `current` ones, such as when upgrading Vector changes their defaults.
+///
+/// Returns a description of each breaking change, along with the Vector version that introduced the
+/// changed option.
+pub fn is_compatible_with(current: &Options, previous: &Options) -> Result<(), Vec<String>> {
+    let errors: Vec<_> = BREAKING_CHANGES
+        .iter()
+        .filter_map(|change| {
+            (change.check)(current, previous).map(|error| {
+                format!(
+                    "Breaking schema change (option introduced in Vector {}): {}.",
+                    change.since, error
+                )
+            })
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks whether the `new` schema options can be applied to a running topology that was built with
+/// the `current` ones.
+///
+/// Components that don't change during a reload keep running with the options they were built with,
+/// so options changing the shape of events can't be applied without a restart.
+pub fn validate_reload(current: &Options, new: &Options) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+
+    if current.enabled && !new.enabled && new.validation {
+        errors.push(
+            "schema support can't be disabled while schema validation is still enabled".to_owned(),
+        );
+    }
+    if current.log_namespace() != new.log_namespace() {
+        errors.push(format!(
+            "the log namespace can't be changed from {:?} to {:?} without restarting Vector",
+            current.log_namespace(),
+            new.log_namespace()
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks whether the schema options of a source meet its requirements.
+///
+/// Sources storing fields in the event metadata only work with the `Vector` log namespace.
+pub fn validate_source_requirements(
+    opts: &Options,
+    source_requires_vector_ns: bool,
+) -> Result<(), String> {
+    if source_requires_vector_ns && opts.log_namespace() == LogNamespace::Legacy {
+        Err(
+            "the source requires the Vector log namespace, but the Legacy one is configured; \
+             set `schema.log_namespace` to `true`"
+                .to_owned(),
+        )
+    } else {
+        Ok(())
+    }
+}
+
 const fn default_enabled() -> bool {
     false
 }
@@ -82,25 +286,47 @@ mod test {
                     validation: false,
                     log_namespace: Some(true),
                 },
-                Some(Options {
+                Options {
                     enabled: false,
                     validation: false,
                     log_namespace: Some(true),
-                }),
+                },
             ),
             (
-                "log namespace conflict",
+                "inherit global log namespace",
                 Options {
                     enabled: false,
                     validation: false,
-                    log_namespace: Some(false),
+                    log_namespace: Some(true),
+                },
+                Options {
+                    enabled: false,
+                    validation: false,
+                    log_namespace: None,
                 },
                 Options {
                     enabled: false,
                     validation: false,
                     log_namespace: Some(true),
                 },
-                None,
+            ),
+            (
+                "override global log namespace",
+                Options {
+                    enabled: false,
+                    validation: false,
+                    log_namespace: Some(true),
+                },
+                Options {
+                    enabled: false,
+                    validation: false,
+                    log_namespace: Some(false),
+                },
+                Options {
+                    enabled: false,
+                    validation: false,
+                    log_namespace: Some(false),
+                },
             ),
             (
                 "enable schemas",
@@ -114,11 +340,11 @@ mod test {
                     validation: false,
                     log_namespace: None,
                 },
-                Some(Options {
+                Options {
                     enabled: true,
                     validation: false,
                     log_namespace: None,
-                }),
+                },
             ),
             (
                 "enable sink requirements",
@@ -132,25 +358,340 @@ mod test {
                     validation: true,
                     log_namespace: None,
                 },
-                Some(Options {
+                Options {
                     enabled: false,
                     validation: true,
                     log_namespace: None,
-                }),
+                },
             ),
         ] {
-            let mut errors = vec![];
-            a.append(b, &mut errors);
-            if errors.is_empty() {
-                assert_eq!(Some(a), expected, "result mismatch: {}", test);
-            } else {
-                assert_eq!(
-                    errors.is_empty(),
-                    expected.is_some(),
-                    "error mismatch: {}",
-                    test
-                );
-            }
+            a.append(b);
+            assert_eq!(a, expected, "result mismatch: {}", test);
+        }
+    }
+
+    #[test]
+    fn log_namespace_bool_round_trip() {
+        for (toml, expected) in [
+            ("log_namespace = true", Some(true)),
+            ("log_namespace = false", Some(false)),
+            ("", None),
+        ] {
+            let options: Options = toml::from_str(toml).unwrap();
+            assert_eq!(options.log_namespace, expected, "{}", toml);
+
+            let serialized = toml::Value::try_from(&options).unwrap();
+            assert_eq!(
+                serialized
+                    .get("log_namespace")
+                    .and_then(toml::Value::as_bool),
+                expected,
+                "{}",
+                toml
+            );
+            assert_eq!(serialized.try_into::<Options>().unwrap(), options);
+        }
+    }
+
+    #[test]
+    fn log_namespace_accepts_names() {
+        for (toml, expected) in [
+            (r#"log_namespace = "vector""#, Some(true)),
+            (r#"log_namespace = "legacy""#, Some(false)),
+        ] {
+            let options: Options = toml::from_str(toml).unwrap();
+            assert_eq!(options.log_namespace, expected, "{}", toml);
+        }
+    }
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct NamedLogNamespace {
+        #[serde(default, with = "log_namespace_string")]
+        log_namespace: Option<bool>,
+    }
+
+    #[test]
+    fn log_namespace_string_round_trip() {
+        for (toml, expected, name) in [
+            (r#"log_namespace = "vector""#, Some(true), Some("vector")),
+            (r#"log_namespace = "legacy""#, Some(false), Some("legacy")),
+            (r#"log_namespace = "Vector""#, Some(true), Some("vector")),
+            ("log_namespace = true", Some(true), Some("vector")),
+            ("", None, None),
+        ] {
+            let options: NamedLogNamespace = toml::from_str(toml).unwrap();
+            assert_eq!(options.log_namespace, expected, "{}", toml);
+
+            let serialized = toml::Value::try_from(&options).unwrap();
+            assert_eq!(
+                serialized
+                    .get("log_namespace")
+                    .and_then(toml::Value::as_str),
+                name,
+                "{}",
+                toml
+            );
+            assert_eq!(serialized.try_into::<NamedLogNamespace>().unwrap(), options);
+        }
+    }
+
+    #[test]
+    fn invalid_log_namespace_name() {
+        let error = toml::from_str::<Options>(r#"log_namespace = "bogus""#).unwrap_err();
+        assert!(error.to_string().contains("bogus"), "{}", error);
+    }
+
+    #[test]
+    fn source_log_namespace() {
+        let (out, _) = crate::SourceSender::new_test();
+        let mut cx = crate::config::SourceContext::new_test(out, None);
+        cx.schema.log_namespace = Some(true);
+
+        // A source without its own `log_namespace` inherits the global one.
+        assert_eq!(cx.log_namespace(None), LogNamespace::Vector);
+        assert_eq!(cx.log_namespace(Some(false)), LogNamespace::Legacy);
+
+        cx.schema.log_namespace = None;
+        assert_eq!(cx.log_namespace(None), LogNamespace::Legacy);
+        assert_eq!(cx.log_namespace(Some(true)), LogNamespace::Vector);
+    }
+
+    #[test]
+    fn matching_source_requirements() {
+        let vector = Options {
+            log_namespace: Some(true),
+            ..Options::default()
+        };
+        assert_eq!(validate_source_requirements(&vector, true), Ok(()));
+        assert_eq!(validate_source_requirements(&vector, false), Ok(()));
+        assert_eq!(
+            validate_source_requirements(&Options::default(), false),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn mismatching_source_requirements() {
+        for options in [
+            Options::default(),
+            Options {
+                log_namespace: Some(false),
+                ..Options::default()
+            },
+        ] {
+            assert_eq!(
+                validate_source_requirements(&options, true),
+                Err("the source requires the Vector log namespace, but the Legacy one is configured; set `schema.log_namespace` to `true`".to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn source_required_log_namespace() {
+        let (out, _) = crate::SourceSender::new_test();
+        let cx = crate::config::SourceContext::new_test(out, None);
+
+        assert_eq!(
+            cx.required_log_namespace(Some(true), true).unwrap(),
+            LogNamespace::Vector
+        );
+        assert_eq!(
+            cx.required_log_namespace(None, false).unwrap(),
+            LogNamespace::Legacy
+        );
+        assert!(cx.required_log_namespace(None, true).is_err());
+    }
+
+    #[test]
+    fn compatible_options() {
+        let previous = Options::default();
+        assert_eq!(is_compatible_with(&Options::default(), &previous), Ok(()));
+
+        // Explicitly choosing the default namespace doesn't change the shape of events.
+        let current = Options {
+            log_namespace: Some(false),
+            ..Options::default()
+        };
+        assert_eq!(is_compatible_with(&current, &previous), Ok(()));
+
+        // Disabling schema support or validation can't break a working configuration.
+        let previous = Options {
+            enabled: true,
+            validation: true,
+            log_namespace: None,
+        };
+        assert_eq!(is_compatible_with(&Options::default(), &previous), Ok(()));
+    }
+
+    #[test]
+    fn breaking_changes() {
+        for (test, current, previous, expected) in [
+            (
+                "enable schemas",
+                Options {
+                    enabled: true,
+                    ..Options::default()
+                },
+                Options::default(),
+                "Breaking schema change (option introduced in Vector 0.21.0): schema support was enabled, so transforms are now type checked against the schema definitions of their inputs.",
+            ),
+            (
+                "enable validation",
+                Options {
+                    validation: true,
+                    ..Options::default()
+                },
+                Options::default(),
+                "Breaking schema change (option introduced in Vector 0.22.0): schema validation was enabled, so sinks now reject inputs that don't meet their schema requirements.",
+            ),
+            (
+                "legacy to vector namespace",
+                Options {
+                    log_namespace: Some(true),
+                    ..Options::default()
+                },
+                Options::default(),
+                "Breaking schema change (option introduced in Vector 0.24.0): the log namespace changed from Legacy to Vector, so events now have a different shape (`.source_type` moved to `%vector.source_type`, `.timestamp` moved to `%vector.ingest_timestamp`).",
+            ),
+            (
+                "vector to legacy namespace",
+                Options::default(),
+                Options {
+                    log_namespace: Some(true),
+                    ..Options::default()
+                },
+                "Breaking schema change (option introduced in Vector 0.24.0): the log namespace changed from Vector to Legacy, so events now have a different shape (`%vector.source_type` moved to `.source_type`, `%vector.ingest_timestamp` moved to `.timestamp`).",
+            ),
+        ] {
+            assert_eq!(
+                is_compatible_with(&current, &previous),
+                Err(vec![expected.to_owned()]),
+                "{}",
+                test
+            );
+        }
+
+        let current = Options {
+            enabled: true,
+            validation: true,
+            log_namespace: Some(true),
+        };
+        assert_eq!(
+            is_compatible_with(&current, &Options::default())
+                .unwrap_err()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn legacy_to_vector_field_aliases() {
+        assert_eq!(
+            field_aliases(LogNamespace::Legacy, LogNamespace::Vector),
+            vec![
+                (
+                    LookupBuf::from("source_type"),
+                    LookupBuf::from_str("vector.source_type").unwrap()
+                ),
+                (
+                    LookupBuf::from("timestamp"),
+                    LookupBuf::from_str("vector.ingest_timestamp").unwrap()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn vector_to_legacy_field_aliases() {
+        assert_eq!(
+            field_aliases(LogNamespace::Vector, LogNamespace::Legacy),
+            vec![
+                (
+                    LookupBuf::from_str("vector.source_type").unwrap(),
+                    LookupBuf::from("source_type")
+                ),
+                (
+                    LookupBuf::from_str("vector.ingest_timestamp").unwrap(),
+                    LookupBuf::from("timestamp")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unchanged_namespace_field_aliases() {
+        assert!(field_aliases(LogNamespace::Legacy, LogNamespace::Legacy).is_empty());
+        assert!(field_aliases(LogNamespace::Vector, LogNamespace::Vector).is_empty());
+    }
+
+    #[test]
+    fn valid_reloads() {
+        for (test, current, new) in [
+            ("unchanged", Options::default(), Options::default()),
+            (
+                "enable schemas and validation",
+                Options::default(),
+                Options {
+                    enabled: true,
+                    validation: true,
+                    log_namespace: None,
+                },
+            ),
+            (
+                "disable schemas and validation",
+                Options {
+                    enabled: true,
+                    validation: true,
+                    log_namespace: None,
+                },
+                Options::default(),
+            ),
+            (
+                "explicit default namespace",
+                Options::default(),
+                Options {
+                    log_namespace: Some(false),
+                    ..Options::default()
+                },
+            ),
+        ] {
+            assert_eq!(validate_reload(&current, &new), Ok(()), "{}", test);
+        }
+    }
+
+    #[test]
+    fn invalid_reloads() {
+        for (test, current, new, expected) in [
+            (
+                "disable schemas while validating",
+                Options {
+                    enabled: true,
+                    validation: true,
+                    log_namespace: None,
+                },
+                Options {
+                    enabled: false,
+                    validation: true,
+                    log_namespace: None,
+                },
+                "schema support can't be disabled while schema validation is still enabled",
+            ),
+            (
+                "change log namespace",
+                Options::default(),
+                Options {
+                    log_namespace: Some(true),
+                    ..Options::default()
+                },
+                "the log namespace can't be changed from Legacy to Vector without restarting Vector",
+            ),
+        ] {
+            assert_eq!(
+                validate_reload(&current, &new),
+                Err(vec![expected.to_owned()]),
+                "{}",
+                test
+            );
         }
     }
 }
@@ -5,7 +5,10 @@ use vector_buffers::{BufferConfig, BufferType};
 use vector_core::config::{AcknowledgementsConfig, GlobalOptions, Input};
 
 use super::{component, schema, ComponentKey, ProxyConfig, Resource};
-use crate::sinks::{self, util::UriSerde};
+use crate::{
+    sinks::{self, util::UriSerde},
+    topology::dropped_samples::DroppedEventSamplesConfig,
+};
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct SinkOuter<T> {
@@ -28,6 +31,12 @@ pub struct SinkOuter<T> {
     )]
     proxy: ProxyConfig,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<SinkFallbackConfig>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dropped_event_samples: Option<DroppedEventSamplesConfig>,
+
     #[serde(flatten)]
     pub inner: Box<dyn SinkConfig>,
 }
@@ -41,6 +50,8 @@ impl<T> SinkOuter<T> {
             healthcheck_uri: None,
             inner,
             proxy: Default::default(),
+            fallback: None,
+            dropped_event_samples: None,
         }
     }
 
@@ -92,6 +103,8 @@ impl<T> SinkOuter<T> {
             healthcheck: self.healthcheck,
             healthcheck_uri: self.healthcheck_uri,
             proxy: self.proxy,
+            fallback: self.fallback,
+            dropped_event_samples: self.dropped_event_samples,
         }
     }
 }
@@ -127,6 +140,52 @@ impl From<UriSerde> for SinkHealthcheckOptions {
     }
 }
 
+/// Routes events to another sink while this sink is failing.
+///
+/// The outcome of every batch sent to this sink is tracked by a circuit breaker. Once the breaker
+/// opens, new batches are sent to the fallback sink instead, and a single batch is periodically sent
+/// to this sink again to probe whether it has recovered.
+///
+/// The sink must have acknowledgements enabled and, if it makes requests, a bounded
+/// `request.retry_attempts`, so that failed batches are reported.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SinkFallbackConfig {
+    /// The ID of the sink to send events to while the circuit breaker is open.
+    pub sink: ComponentKey,
+
+    /// The number of consecutive failed batches that opens the circuit breaker.
+    #[serde(default = "default_fallback_consecutive_failures")]
+    pub consecutive_failures: usize,
+
+    /// The ratio of failed batches, between `0` and `1`, within the last `error_rate_window`
+    /// batches that opens the circuit breaker.
+    ///
+    /// By default, only `consecutive_failures` opens the circuit breaker.
+    #[serde(default)]
+    pub error_rate: Option<f64>,
+
+    /// The number of most recent batches the error rate is calculated over.
+    #[serde(default = "default_fallback_error_rate_window")]
+    pub error_rate_window: usize,
+
+    /// The number of seconds between probes of this sink while the circuit breaker is open.
+    #[serde(default = "default_fallback_probe_interval_secs")]
+    pub probe_interval_secs: u64,
+}
+
+const fn default_fallback_consecutive_failures() -> usize {
+    5
+}
+
+const fn default_fallback_error_rate_window() -> usize {
+    100
+}
+
+const fn default_fallback_probe_interval_secs() -> u64 {
+    30
+}
+
 #[async_trait]
 #[typetag::serde(tag = "type")]
 pub trait SinkConfig: core::fmt::Debug + Send + Sync {
@@ -145,6 +204,12 @@ pub trait SinkConfig: core::fmt::Debug + Send + Sync {
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig;
+
+    /// Schema options to suggest when scaffolding a configuration for this sink with
+    /// `vector generate`.
+    fn generate_schema_hint(&self) -> Option<schema::Options> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -6,7 +6,10 @@ use serde::{Deserialize, Serialize};
 use vector_core::config::{AcknowledgementsConfig, GlobalOptions, LogNamespace, Output};
 
 use super::{component, schema, ComponentKey, ProxyConfig, Resource};
-use crate::{shutdown::ShutdownSignal, sources, SourceSender};
+use crate::{
+    shutdown::ShutdownSignal, sources, topology::dropped_samples::DroppedEventSamplesConfig,
+    SourceSender,
+};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SourceOuter {
@@ -15,6 +18,8 @@ pub struct SourceOuter {
         skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
     )]
     pub proxy: ProxyConfig,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dropped_event_samples: Option<DroppedEventSamplesConfig>,
     #[serde(flatten)]
     pub(crate) inner: Box<dyn SourceConfig>,
     #[serde(default, skip)]
@@ -26,6 +31,7 @@ impl SourceOuter {
         Self {
             inner: Box::new(source),
             proxy: Default::default(),
+            dropped_event_samples: None,
             sink_acknowledgements: false,
         }
     }
@@ -46,6 +52,18 @@ pub trait SourceConfig: core::fmt::Debug + Send + Sync {
     }
 
     fn can_acknowledge(&self) -> bool;
+
+    /// Whether the source only works with the `Vector` log namespace, such as when it stores fields
+    /// in the event metadata.
+    fn requires_vector_namespace(&self) -> bool {
+        false
+    }
+
+    /// Schema options to suggest when scaffolding a configuration for this source with
+    /// `vector generate`.
+    fn generate_schema_hint(&self) -> Option<schema::Options> {
+        None
+    }
 }
 
 pub struct SourceContext {
@@ -121,10 +139,28 @@ impl SourceContext {
     /// Gets the log namespacing to use. The passed in value is from the source itself
     /// and will override any global default if it's set.
     pub fn log_namespace(&self, namespace: Option<bool>) -> LogNamespace {
-        namespace
-            .or(self.schema.log_namespace)
-            .unwrap_or(false)
-            .into()
+        self.source_schema(namespace).log_namespace()
+    }
+
+    /// Gets the log namespacing to use, like [`Self::log_namespace`], after checking that it meets
+    /// the requirements of the source.
+    pub fn required_log_namespace(
+        &self,
+        namespace: Option<bool>,
+        requires_vector_namespace: bool,
+    ) -> crate::Result<LogNamespace> {
+        let schema = self.source_schema(namespace);
+        schema::validate_source_requirements(&schema, requires_vector_namespace)?;
+        Ok(schema.log_namespace())
+    }
+
+    fn source_schema(&self, namespace: Option<bool>) -> schema::Options {
+        let mut schema = self.schema;
+        schema.append(schema::Options {
+            log_namespace: namespace,
+            ..Default::default()
+        });
+        schema
     }
 }
 
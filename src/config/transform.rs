@@ -6,11 +6,16 @@ use serde::{Deserialize, Serialize};
 use vector_core::transform::TransformConfig;
 
 use super::{component, ComponentKey};
+use crate::topology::dropped_samples::DroppedEventSamplesConfig;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct TransformOuter<T> {
     #[serde(default = "Default::default")] // https://github.com/serde-rs/serde/issues/1541
     pub inputs: Vec<T>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dropped_event_samples: Option<DroppedEventSamplesConfig>,
+
     #[serde(flatten)]
     pub inner: Box<dyn TransformConfig>,
 }
@@ -20,6 +25,7 @@ impl<T> TransformOuter<T> {
     pub(super) fn new(inputs: Vec<T>, transform: impl TransformConfig + 'static) -> Self {
         TransformOuter {
             inputs,
+            dropped_event_samples: None,
             inner: Box::new(transform),
         }
     }
@@ -32,6 +38,7 @@ impl<T> TransformOuter<T> {
     pub(crate) fn with_inputs<U>(self, inputs: Vec<U>) -> TransformOuter<U> {
         TransformOuter {
             inputs,
+            dropped_event_samples: self.dropped_event_samples,
             inner: self.inner,
         }
     }
@@ -76,6 +83,7 @@ impl TransformOuter<String> {
             for (inner_name, inner_transform) in inner_topology.inner {
                 let child = TransformOuter {
                     inputs: inner_transform.inputs,
+                    dropped_event_samples: self.dropped_event_samples.clone(),
                     inner: inner_transform.inner,
                 };
                 children.push(inner_name.clone());
@@ -1,9 +1,10 @@
 use crate::config::schema;
+use crate::sinks::util::service::RETRY_ATTEMPTS_DEFAULT;
 use crate::topology::schema::merged_definition;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use vector_core::internal_event::DEFAULT_OUTPUT;
 
-use super::{builder::ConfigBuilder, ComponentKey, Config, OutputId, Resource};
+use super::{builder::ConfigBuilder, ComponentKey, Config, OutputId, Resource, SinkConfig};
 
 /// Check that provide + topology config aren't present in the same builder, which is an error.
 pub fn check_provider(config: &ConfigBuilder) -> Result<(), Vec<String>> {
@@ -187,8 +188,166 @@ pub fn check_outputs(config: &ConfigBuilder) -> Result<(), Vec<String>> {
     }
 }
 
+/// Check that the fallback of each sink refers to another sink that can accept its events, and that
+/// the circuit breaker thresholds are usable.
+///
+/// The circuit breaker learns from the delivery status of batches, so the sink must have
+/// acknowledgements enabled and a bounded number of request retries, or its batches would never be
+/// reported as failed.
+pub fn check_fallbacks(config: &ConfigBuilder) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    for (key, sink) in config.sinks.iter() {
+        let fallback = match &sink.fallback {
+            Some(fallback) => fallback,
+            None => continue,
+        };
+
+        if !sink
+            .inner
+            .acknowledgements()
+            .merge_default(&config.global.acknowledgements)
+            .enabled()
+        {
+            errors.push(format!(
+                "Sink \"{}\" must have acknowledgements enabled to use a fallback.",
+                key
+            ));
+        }
+        if retries_indefinitely(&*sink.inner) {
+            errors.push(format!(
+                "Sink \"{}\" must set `request.retry_attempts` to use a fallback.",
+                key
+            ));
+        }
+
+        match config.sinks.get(&fallback.sink) {
+            None => errors.push(format!(
+                "Fallback \"{}\" for sink \"{}\" doesn't match any sinks.",
+                fallback.sink, key
+            )),
+            Some(_) if &fallback.sink == key => {
+                errors.push(format!("Sink \"{}\" cannot be its own fallback.", key))
+            }
+            Some(fallback_sink) if fallback_sink.fallback.is_some() => errors.push(format!(
+                "Fallback \"{}\" for sink \"{}\" cannot have a fallback itself.",
+                fallback.sink, key
+            )),
+            Some(fallback_sink) => {
+                let data_type = sink.inner.input().data_type();
+                let fallback_data_type = fallback_sink.inner.input().data_type();
+                if !fallback_data_type.contains(data_type) {
+                    errors.push(format!(
+                        "Fallback \"{}\" for sink \"{}\" doesn't accept all of its data types: {} vs {}.",
+                        fallback.sink, key, fallback_data_type, data_type
+                    ));
+                }
+            }
+        }
+
+        if fallback.consecutive_failures == 0 {
+            errors.push(format!(
+                "Sink \"{}\" must have a fallback `consecutive_failures` greater than 0.",
+                key
+            ));
+        }
+        if let Some(error_rate) = fallback.error_rate {
+            if !(error_rate > 0.0 && error_rate <= 1.0) {
+                errors.push(format!(
+                    "Sink \"{}\" must have a fallback `error_rate` greater than 0 and at most 1.",
+                    key
+                ));
+            }
+            if fallback.error_rate_window == 0 {
+                errors.push(format!(
+                    "Sink \"{}\" must have a fallback `error_rate_window` greater than 0.",
+                    key
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Whether the sink retries failed requests without limit, as it does unless `request.retry_attempts`
+/// is set.
+fn retries_indefinitely(sink: &dyn SinkConfig) -> bool {
+    let request = match serde_json::to_value(sink) {
+        Ok(value) => value.get("request").cloned(),
+        Err(_) => None,
+    };
+    match request {
+        // Sinks without request settings don't retry.
+        None => false,
+        Some(request) => request
+            .get("retry_attempts")
+            .and_then(|attempts| attempts.as_u64())
+            .map_or(true, |attempts| attempts >= RETRY_ATTEMPTS_DEFAULT as u64),
+    }
+}
+
 pub fn warnings(config: &Config) -> Vec<String> {
     let mut warnings = vec![];
+    let outputs = component_outputs(config);
+    let dangling = dangling_outputs(config, &outputs);
+
+    for (output_type, id) in &outputs {
+        if !has_consumers(config, id) {
+            warnings.push(format!(
+                "{} \"{}\" has no consumers",
+                capitalize(output_type),
+                id
+            ));
+        }
+    }
+    // Outputs without consumers are already warned about above.
+    for (output_type, id) in dangling {
+        if has_consumers(config, &id) {
+            warnings.push(format!(
+                "{} \"{}\" doesn't reach any sink",
+                capitalize(output_type),
+                id
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Check that the outputs of all components reach a sink, either directly or through transforms.
+///
+/// When the API is enabled, outputs without consumers can be observed with `vector tap`, so they
+/// and the outputs feeding them aren't considered dangling.
+pub fn check_dangling(config: &Config) -> Result<(), Vec<String>> {
+    let outputs = component_outputs(config);
+    let errors = dangling_outputs(config, &outputs)
+        .into_iter()
+        .map(|(output_type, id)| {
+            if has_consumers(config, &id) {
+                format!(
+                    "{} \"{}\" doesn't reach any sink",
+                    capitalize(output_type),
+                    id
+                )
+            } else {
+                format!("{} \"{}\" has no consumers", capitalize(output_type), id)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// The outputs of all sources and transforms, along with the type of their component.
+fn component_outputs(config: &Config) -> Vec<(&'static str, OutputId)> {
     let mut cache = HashMap::new();
 
     let source_ids = config.sources.iter().flat_map(|(key, source)| {
@@ -220,25 +379,69 @@ pub fn warnings(config: &Config) -> Vec<String> {
             .collect::<Vec<_>>()
     });
 
-    for (input_type, id) in transform_ids.chain(source_ids) {
-        if !config
-            .transforms
+    transform_ids.chain(source_ids).collect()
+}
+
+fn has_consumers(config: &Config, id: &OutputId) -> bool {
+    config
+        .transforms
+        .iter()
+        .any(|(_, transform)| transform.inputs.contains(id))
+        || config
+            .sinks
             .iter()
-            .any(|(_, transform)| transform.inputs.contains(&id))
-            && !config
+            .any(|(_, sink)| sink.inputs.contains(id))
+}
+
+/// The outputs that reach no sink, either directly or through transforms.
+fn dangling_outputs(
+    config: &Config,
+    outputs: &[(&'static str, OutputId)],
+) -> Vec<(&'static str, OutputId)> {
+    #[cfg(feature = "api")]
+    let tap_enabled = config.api.enabled;
+    #[cfg(not(feature = "api"))]
+    let tap_enabled = false;
+
+    let mut reaching = outputs
+        .iter()
+        .filter(|(_, id)| {
+            config
                 .sinks
                 .iter()
-                .any(|(_, sink)| sink.inputs.contains(&id))
-        {
-            warnings.push(format!(
-                "{} \"{}\" has no consumers",
-                capitalize(input_type),
-                id
-            ));
+                .any(|(_, sink)| sink.inputs.contains(id))
+                || (tap_enabled && !has_consumers(config, id))
+        })
+        .map(|(_, id)| id.clone())
+        .collect::<HashSet<_>>();
+
+    // Propagate backwards through the transforms until no more outputs are found to reach a sink,
+    // which terminates as there's a finite number of outputs.
+    loop {
+        let mut changed = false;
+        for (_, id) in outputs {
+            if reaching.contains(id) {
+                continue;
+            }
+            let reaches = config.transforms.iter().any(|(key, transform)| {
+                transform.inputs.contains(id)
+                    && reaching.iter().any(|output| &output.component == key)
+            });
+            if reaches {
+                reaching.insert(id.clone());
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
         }
     }
 
-    warnings
+    outputs
+        .iter()
+        .filter(|(_, id)| !reaching.contains(id))
+        .cloned()
+        .collect()
 }
 
 fn capitalize(s: &str) -> String {
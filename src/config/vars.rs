@@ -6,6 +6,23 @@ use regex::{Captures, Regex};
 pub fn interpolate(
     input: &str,
     vars: &HashMap<String, String>,
+) -> Result<(String, Vec<String>), Vec<String>> {
+    interpolate_vars(input, vars, false)
+}
+
+/// Like `interpolate`, but variables that aren't set and have no default are errors instead of
+/// warnings.
+pub fn interpolate_strict(
+    input: &str,
+    vars: &HashMap<String, String>,
+) -> Result<(String, Vec<String>), Vec<String>> {
+    interpolate_vars(input, vars, true)
+}
+
+fn interpolate_vars(
+    input: &str,
+    vars: &HashMap<String, String>,
+    strict: bool,
 ) -> Result<(String, Vec<String>), Vec<String>> {
     let mut errors = Vec::new();
     let mut warnings = Vec::new();
@@ -58,8 +75,15 @@ pub fn interpolate(
                             ""
                         }),
                         _ => val.unwrap_or_else(|| {
-                            warnings
-                                .push(format!("Unknown env var in config. name = {:?}", name));
+                            if strict {
+                                errors.push(format!(
+                                    "Missing env var required in config. name = {:?}",
+                                    name
+                                ));
+                            } else {
+                                warnings
+                                    .push(format!("Unknown env var in config. name = {:?}", name));
+                            }
                             ""
                         }),
                     }
@@ -78,7 +102,7 @@ pub fn interpolate(
 
 #[cfg(test)]
 mod test {
-    use super::interpolate;
+    use super::{interpolate, interpolate_strict};
     #[test]
     fn interpolation() {
         let vars = vec![
@@ -121,4 +145,33 @@ mod test {
         assert!(interpolate("${NOT?error cats}", &vars).is_err());
         assert!(interpolate("${EMPTY:?error cats}", &vars).is_err());
     }
+
+    #[test]
+    fn strict_interpolation() {
+        let vars = vec![("FOO".into(), "dogs".into()), ("EMPTY".into(), "".into())]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            interpolate("x $NOT_FOO y", &vars).unwrap(),
+            (
+                "x  y".to_owned(),
+                vec![r#"Unknown env var in config. name = "NOT_FOO""#.to_owned()]
+            )
+        );
+        assert_eq!(
+            interpolate_strict("x $NOT_FOO ${NOT_BAR} y", &vars).unwrap_err(),
+            vec![
+                r#"Missing env var required in config. name = "NOT_FOO""#,
+                r#"Missing env var required in config. name = "NOT_BAR""#,
+            ]
+        );
+
+        // Set variables, even empty ones, and variables with defaults are still allowed.
+        assert_eq!("dogs", interpolate_strict("$FOO", &vars).unwrap().0);
+        assert_eq!("", interpolate_strict("${EMPTY}", &vars).unwrap().0);
+        assert_eq!("cats", interpolate_strict("${NOT:-cats}", &vars).unwrap().0);
+        assert_eq!("cats", interpolate_strict("${NOT-cats}", &vars).unwrap().0);
+        assert_eq!("$FOO", interpolate_strict("$$FOO", &vars).unwrap().0);
+    }
 }
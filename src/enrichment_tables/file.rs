@@ -499,6 +499,10 @@ impl Table for File {
             .collect::<Vec<_>>()
     }
 
+    fn columns(&self) -> Option<Vec<String>> {
+        Some(self.headers.clone())
+    }
+
     /// Checks the modified timestamp of the data file to see if data has changed.
     fn needs_reload(&self) -> bool {
         matches!(fs::metadata(&self.config.file.path)
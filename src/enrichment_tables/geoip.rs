@@ -1,14 +1,36 @@
-use std::{collections::BTreeMap, fs, net::IpAddr, sync::Arc, time::SystemTime};
+use std::{
+    collections::BTreeMap,
+    fs,
+    net::IpAddr,
+    path::PathBuf,
+    sync::{Arc, Weak},
+    time::{Duration, SystemTime},
+};
 
+use arc_swap::ArcSwap;
+use bytes::Bytes;
 use enrichment::{Case, Condition, IndexHandle, Table};
+use http::{Request, StatusCode};
+use hyper::Body;
 use maxminddb::{
     geoip2::{City, Isp},
     MaxMindDBError, Reader,
 };
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use snafu::{ResultExt, Snafu};
+use tokio::time::sleep;
 use value::Value;
 
-use crate::config::{EnrichmentTableConfig, EnrichmentTableDescription, GenerateConfig};
+use crate::{
+    config::{
+        EnrichmentTableConfig, EnrichmentTableDescription, GenerateConfig, GlobalOptions,
+        ProxyConfig,
+    },
+    http::{HttpClient, HttpError},
+    internal_events::{GeoipDatabaseDownloadError, GeoipDatabaseDownloaded},
+    tls::{TlsConfig, TlsSettings},
+};
 
 // MaxMind GeoIP database files have a type field we can use to recognize specific
 // products. If we encounter one of these two types, we look for ASN/ISP information;
@@ -16,11 +38,31 @@ use crate::config::{EnrichmentTableConfig, EnrichmentTableDescription, GenerateC
 const ASN_DATABASE_TYPE: &str = "GeoLite2-ASN";
 const ISP_DATABASE_TYPE: &str = "GeoIP2-ISP";
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GeoipConfig {
     pub path: String,
     #[serde(default = "default_locale")]
     pub locale: String,
+    /// Downloads the database from a URL to `path` at startup, and refreshes it periodically.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download: Option<GeoipDownloadConfig>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct GeoipDownloadConfig {
+    /// The URL of the database file.
+    pub url: String,
+    /// The URL of a file containing the hex encoded SHA-256 digest of the database file.
+    ///
+    /// When set, a downloaded database is only used if its digest matches.
+    #[serde(default)]
+    pub sha256_url: Option<String>,
+    /// The interval, in seconds, between downloads of the database.
+    #[serde(default = "default_download_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
 }
 
 // valid locales are: “de”, "en", “es”, “fr”, “ja”, “pt-BR”, “ru”, and “zh-CN”
@@ -32,11 +74,16 @@ fn default_locale() -> String {
     "en".to_string()
 }
 
+const fn default_download_interval_secs() -> u64 {
+    86400
+}
+
 impl GenerateConfig for GeoipConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
             path: "/path/to/GeoLite2-City.mmdb".to_string(),
             locale: default_locale(),
+            download: None,
         })
         .unwrap()
     }
@@ -49,48 +96,214 @@ inventory::submit! {
 #[async_trait::async_trait]
 #[typetag::serde(name = "geoip")]
 impl EnrichmentTableConfig for GeoipConfig {
-    async fn build(
-        &self,
-        _: &crate::config::GlobalOptions,
-    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
-        Ok(Box::new(Geoip::new(self.clone())?))
+    async fn build(&self, globals: &GlobalOptions) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        let download = match &self.download {
+            Some(download) => download,
+            None => return Ok(Box::new(Geoip::new(self.clone())?)),
+        };
+
+        let downloader = Downloader::new(download, &self.path, &globals.proxy)?;
+        let database = match downloader.download().await {
+            Ok(database) => database,
+            Err(error) => {
+                // Keep using the database downloaded last, if there is one.
+                emit!(GeoipDatabaseDownloadError {
+                    url: &download.url,
+                    error: error.into(),
+                });
+                Database::open(&self.path)?
+            }
+        };
+
+        let table = Geoip::with_database(self.clone(), database);
+        tokio::spawn(downloader.run(Arc::downgrade(&table.database)));
+        Ok(Box::new(table))
+    }
+}
+
+#[derive(Debug, Snafu)]
+enum DownloadError {
+    #[snafu(display("Request failed: {}", source))]
+    Request { source: HttpError },
+    #[snafu(display("Invalid URL {:?}: {}", url, source))]
+    InvalidUrl { url: String, source: http::Error },
+    #[snafu(display("Unexpected response status {} for {:?}", status, url))]
+    UnexpectedStatus { url: String, status: StatusCode },
+    #[snafu(display("Failed reading response body: {}", source))]
+    ReadBody { source: hyper::Error },
+    #[snafu(display("Checksum file is empty"))]
+    EmptyChecksum,
+    #[snafu(display("Checksum mismatch: expected {}, got {}", expected, actual))]
+    ChecksumMismatch { expected: String, actual: String },
+    #[snafu(display("Invalid database: {}", source))]
+    InvalidDatabase { source: MaxMindDBError },
+    #[snafu(display("Failed writing database to {:?}: {}", path, source))]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+/// Downloads the database, and swaps it into the table every `interval_secs`.
+struct Downloader {
+    client: HttpClient,
+    config: GeoipDownloadConfig,
+    path: PathBuf,
+}
+
+impl Downloader {
+    fn new(config: &GeoipDownloadConfig, path: &str, proxy: &ProxyConfig) -> crate::Result<Self> {
+        let tls = TlsSettings::from_options(&config.tls)?;
+        let proxy = ProxyConfig::merge_with_env(proxy, &ProxyConfig::default());
+        Ok(Self {
+            client: HttpClient::new(tls, &proxy)?,
+            config: config.clone(),
+            path: path.into(),
+        })
+    }
+
+    async fn run(self, database: Weak<ArcSwap<Database>>) {
+        loop {
+            sleep(Duration::from_secs(self.config.interval_secs)).await;
+
+            let result = self.download().await;
+            // Stop refreshing once the table has been dropped.
+            let database = match database.upgrade() {
+                Some(database) => database,
+                None => break,
+            };
+            match result {
+                Ok(downloaded) => {
+                    database.store(Arc::new(downloaded));
+                    emit!(GeoipDatabaseDownloaded {
+                        url: &self.config.url
+                    });
+                }
+                Err(error) => emit!(GeoipDatabaseDownloadError {
+                    url: &self.config.url,
+                    error: error.into(),
+                }),
+            }
+        }
+    }
+
+    /// Downloads and verifies the database, then replaces the database file with it.
+    ///
+    /// The database file is left untouched if any of that fails.
+    async fn download(&self) -> Result<Database, DownloadError> {
+        let contents = self.get(&self.config.url).await?;
+        if let Some(sha256_url) = &self.config.sha256_url {
+            verify_checksum(&contents, &self.get(sha256_url).await?)?;
+        }
+
+        let reader = Reader::from_source(contents.to_vec()).context(InvalidDatabaseSnafu)?;
+        check_database(&reader).context(InvalidDatabaseSnafu)?;
+
+        // Write to a temporary file first, so the database file is replaced atomically.
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".download");
+        tokio::fs::write(&tmp_path, &contents)
+            .await
+            .context(WriteSnafu { path: &tmp_path })?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .context(WriteSnafu { path: &self.path })?;
+        let last_modified = fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .context(WriteSnafu { path: &self.path })?;
+
+        Ok(Database {
+            reader,
+            last_modified,
+        })
+    }
+
+    async fn get(&self, url: &str) -> Result<Bytes, DownloadError> {
+        let request = Request::get(url)
+            .body(Body::empty())
+            .context(InvalidUrlSnafu { url })?;
+        let response = self.client.send(request).await.context(RequestSnafu)?;
+        if response.status() != StatusCode::OK {
+            return Err(DownloadError::UnexpectedStatus {
+                url: url.to_owned(),
+                status: response.status(),
+            });
+        }
+        hyper::body::to_bytes(response.into_body())
+            .await
+            .context(ReadBodySnafu)
+    }
+}
+
+/// Checks the database against a checksum file in the `sha256sum` format, whose first word is the
+/// hex encoded digest.
+fn verify_checksum(contents: &[u8], checksum_file: &[u8]) -> Result<(), DownloadError> {
+    let expected = String::from_utf8_lossy(checksum_file)
+        .split_whitespace()
+        .next()
+        .ok_or(DownloadError::EmptyChecksum)?
+        .to_lowercase();
+    let actual = hex::encode(Sha256::digest(contents));
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(DownloadError::ChecksumMismatch { expected, actual })
+    }
+}
+
+fn has_isp_db(reader: &Reader<Vec<u8>>) -> bool {
+    reader.metadata.database_type == ASN_DATABASE_TYPE
+        || reader.metadata.database_type == ISP_DATABASE_TYPE
+}
+
+/// Checks if we can read the database with a dummy IP.
+fn check_database(reader: &Reader<Vec<u8>>) -> Result<(), MaxMindDBError> {
+    let ip = IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0));
+    let result = if has_isp_db(reader) {
+        reader.lookup::<Isp>(ip).map(|_| ())
+    } else {
+        reader.lookup::<City>(ip).map(|_| ())
+    };
+
+    match result {
+        Ok(_) | Err(MaxMindDBError::AddressNotFoundError(_)) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+struct Database {
+    reader: Reader<Vec<u8>>,
+    last_modified: SystemTime,
+}
+
+impl Database {
+    fn open(path: &str) -> crate::Result<Self> {
+        let database = Database {
+            last_modified: fs::metadata(path)?.modified()?,
+            reader: Reader::open_readfile(path)?,
+        };
+        check_database(&database.reader)?;
+        Ok(database)
     }
 }
 
 #[derive(Clone)]
 pub struct Geoip {
     config: GeoipConfig,
-    dbreader: Arc<maxminddb::Reader<Vec<u8>>>,
-    last_modified: SystemTime,
+    database: Arc<ArcSwap<Database>>,
 }
 
 impl Geoip {
     pub fn new(config: GeoipConfig) -> crate::Result<Self> {
-        let table = Geoip {
-            last_modified: fs::metadata(&config.path)?.modified()?,
-            dbreader: Arc::new(Reader::open_readfile(config.path.clone())?),
-            config,
-        };
-
-        // Check if we can read database with dummy Ip.
-        let ip = IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0));
-        let result = if table.has_isp_db() {
-            table.dbreader.lookup::<Isp>(ip).map(|_| ())
-        } else {
-            table.dbreader.lookup::<City>(ip).map(|_| ())
-        };
-
-        match result {
-            Ok(_) | Err(MaxMindDBError::AddressNotFoundError(_)) => (),
-            Err(error) => return Err(error.into()),
-        }
-
-        Ok(table)
+        let database = Database::open(&config.path)?;
+        Ok(Self::with_database(config, database))
     }
 
-    fn has_isp_db(&self) -> bool {
-        self.dbreader.metadata.database_type == ASN_DATABASE_TYPE
-            || self.dbreader.metadata.database_type == ISP_DATABASE_TYPE
+    fn with_database(config: GeoipConfig, database: Database) -> Self {
+        Geoip {
+            config,
+            database: Arc::new(ArcSwap::from_pointee(database)),
+        }
     }
 
     fn lookup(&self, ip: IpAddr, select: Option<&[String]>) -> Option<BTreeMap<String, Value>> {
@@ -104,8 +317,9 @@ impl Geoip {
             }
         };
 
-        if self.has_isp_db() {
-            let data = self.dbreader.lookup::<Isp>(ip).ok()?;
+        let database = self.database.load();
+        if has_isp_db(&database.reader) {
+            let data = database.reader.lookup::<Isp>(ip).ok()?;
 
             add_field(
                 "autonomous_system_number",
@@ -120,7 +334,7 @@ impl Geoip {
 
             add_field("organization", data.organization.map(Into::into));
         } else {
-            let data = self.dbreader.lookup::<City>(ip).ok()?;
+            let data = database.reader.lookup::<City>(ip).ok()?;
 
             add_field(
                 "city_name",
@@ -280,7 +494,7 @@ impl Table for Geoip {
     fn needs_reload(&self) -> bool {
         matches!(fs::metadata(&self.config.path)
             .and_then(|metadata| metadata.modified()),
-            Ok(modified) if modified > self.last_modified)
+            Ok(modified) if modified > self.database.load().last_modified)
     }
 }
 
@@ -296,7 +510,15 @@ impl std::fmt::Debug for Geoip {
 
 #[cfg(test)]
 mod tests {
+    use std::path::Path;
+
+    use tempfile::tempdir;
+    use warp::Filter;
+
     use super::*;
+    use crate::test_util::next_addr;
+
+    const CITY_DATABASE: &str = "tests/data/GeoIP2-City-Test.mmdb";
 
     #[test]
     fn city_lookup() {
@@ -400,6 +622,98 @@ mod tests {
         assert!(values.is_none());
     }
 
+    fn city_database_checksum() -> String {
+        hex::encode(Sha256::digest(fs::read(CITY_DATABASE).unwrap()))
+    }
+
+    /// Serves the city database along with the given checksum, and returns the base URL.
+    fn serve_city_database(checksum: String) -> String {
+        let addr = next_addr();
+        let database = warp::path("GeoIP2-City-Test.mmdb").map(|| fs::read(CITY_DATABASE).unwrap());
+        let sha256 = warp::path("GeoIP2-City-Test.mmdb.sha256")
+            .map(move || format!("{}  GeoIP2-City-Test.mmdb\n", checksum));
+        tokio::spawn(warp::serve(sha256.or(database)).bind(addr));
+        format!("http://{}", addr)
+    }
+
+    fn download_config(path: &Path, base_url: &str) -> GeoipConfig {
+        GeoipConfig {
+            path: path.to_str().unwrap().to_owned(),
+            locale: default_locale(),
+            download: Some(GeoipDownloadConfig {
+                url: format!("{}/GeoIP2-City-Test.mmdb", base_url),
+                sha256_url: Some(format!("{}/GeoIP2-City-Test.mmdb.sha256", base_url)),
+                interval_secs: default_download_interval_secs(),
+                tls: None,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn downloads_database() {
+        let base_url = serve_city_database(city_database_checksum());
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("GeoIP2-City.mmdb");
+
+        let table = download_config(&path, &base_url)
+            .build(&GlobalOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), fs::read(CITY_DATABASE).unwrap());
+        assert!(!table.needs_reload());
+        let values = table
+            .find_table_row(
+                Case::Insensitive,
+                &[Condition::Equals {
+                    field: "ip",
+                    value: "2.125.160.216".into(),
+                }],
+                Some(&["city_name".to_string()]),
+                None,
+            )
+            .unwrap();
+        assert_eq!(values["city_name"], "Boxford".into());
+    }
+
+    #[tokio::test]
+    async fn keeps_previous_database_on_checksum_mismatch() {
+        let base_url = serve_city_database("0".repeat(64));
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("GeoIP2-City.mmdb");
+
+        // Without a previous database, there's nothing to fall back to.
+        assert!(download_config(&path, &base_url)
+            .build(&GlobalOptions::default())
+            .await
+            .is_err());
+
+        fs::copy("tests/data/GeoLite2-ASN-Test.mmdb", &path).unwrap();
+        download_config(&path, &base_url)
+            .build(&GlobalOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            fs::read(&path).unwrap(),
+            fs::read("tests/data/GeoLite2-ASN-Test.mmdb").unwrap()
+        );
+    }
+
+    #[test]
+    fn verifies_checksum() {
+        let digest = hex::encode(Sha256::digest(b"database"));
+        assert!(verify_checksum(b"database", digest.as_bytes()).is_ok());
+        assert!(verify_checksum(b"database", digest.to_uppercase().as_bytes()).is_ok());
+        assert!(matches!(
+            verify_checksum(b"other", digest.as_bytes()),
+            Err(DownloadError::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(
+            verify_checksum(b"database", b" \n"),
+            Err(DownloadError::EmptyChecksum)
+        ));
+    }
+
     fn find(ip: &str, database: &str) -> Option<BTreeMap<String, Value>> {
         find_select(ip, database, None)
     }
@@ -412,6 +726,7 @@ mod tests {
         Geoip::new(GeoipConfig {
             path: database.to_string(),
             locale: default_locale(),
+            download: None,
         })
         .unwrap()
         .find_table_rows(
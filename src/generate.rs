@@ -12,8 +12,8 @@ use toml::{map::Map, Value};
 use vector_core::{buffers::BufferConfig, config::GlobalOptions, default_data_dir};
 
 use crate::config::{
-    component::ExampleError, SinkDescription, SinkHealthcheckOptions, SourceDescription,
-    TransformDescription,
+    component::ExampleError, schema, SinkConfig, SinkDescription, SinkHealthcheckOptions,
+    SourceConfig, SourceDescription, TransformDescription,
 };
 
 #[derive(Parser, Debug)]
@@ -75,6 +75,11 @@ pub struct TransformOuter {
     pub inner: Value,
 }
 
+#[derive(Serialize)]
+struct SchemaOuter {
+    schema: schema::Options,
+}
+
 #[derive(Serialize, Default)]
 pub struct Config {
     pub sources: Option<IndexMap<String, Value>>,
@@ -119,6 +124,7 @@ pub(crate) fn generate_example(
         ..Default::default()
     };
     let mut config = Config::default();
+    let mut schema_hint: Option<schema::Options> = None;
 
     let mut errs = Vec::new();
 
@@ -162,6 +168,10 @@ pub(crate) fn generate_example(
                 .expect("examples are always tables")
                 .insert("type".into(), source_type.to_owned().into());
 
+            if let Ok(source) = example.clone().try_into::<Box<dyn SourceConfig>>() {
+                merge_schema_hint(&mut schema_hint, source.generate_schema_hint());
+            }
+
             sources.insert(name, example);
         }
 
@@ -274,6 +284,10 @@ pub(crate) fn generate_example(
                 .expect("examples are always tables")
                 .insert("type".into(), sink_type.to_owned().into());
 
+            if let Ok(sink) = example.clone().try_into::<Box<dyn SinkConfig>>() {
+                merge_schema_hint(&mut schema_hint, sink.generate_schema_hint());
+            }
+
             sinks.insert(
                 name,
                 SinkOuter {
@@ -315,6 +329,12 @@ pub(crate) fn generate_example(
     } else {
         String::new()
     };
+    if let Some(schema) = schema_hint.filter(|_| include_globals) {
+        match toml::to_string(&SchemaOuter { schema }) {
+            Ok(v) => builder = [builder, v].join("\n"),
+            Err(e) => errs.push(format!("failed to marshal schema: {}", e)),
+        }
+    }
     if let Some(sources) = config.sources {
         match toml::to_string(&{
             Config {
@@ -369,6 +389,22 @@ pub(crate) fn generate_example(
     }
 }
 
+/// Merges the schema options suggested by a generated component into those suggested so far.
+///
+/// Hints are only suggestions, so conflicting `log_namespace` values keep the value suggested
+/// first instead of failing the generation.
+fn merge_schema_hint(hint: &mut Option<schema::Options>, with: Option<schema::Options>) {
+    match (hint.as_mut(), with) {
+        (Some(hint), Some(with)) => {
+            let log_namespace = hint.log_namespace.or(with.log_namespace);
+            hint.append(with);
+            hint.log_namespace = log_namespace;
+        }
+        (None, with) => *hint = with,
+        (Some(_), None) => {}
+    }
+}
+
 pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
     match generate_example(
         !opts.fragment,
@@ -669,4 +705,84 @@ mod tests {
             .to_string())
         );
     }
+
+    #[cfg(feature = "sources-demo_logs")]
+    #[test]
+    fn generate_schema_hint_from_source() {
+        let cfg =
+            generate_example(true, "demo_logs//", &None, TransformInputsStrategy::Auto).unwrap();
+        assert!(cfg.contains("[schema]\n"));
+        assert!(cfg.contains("log_namespace = false\n"));
+
+        let builder = toml::from_str::<crate::config::ConfigBuilder>(&cfg).unwrap();
+        assert_eq!(builder.schema.log_namespace, Some(false));
+    }
+
+    #[cfg(all(feature = "sources-demo_logs", feature = "sinks-datadog_logs"))]
+    #[test]
+    fn generate_schema_hint_merged() {
+        let cfg = generate_example(
+            true,
+            "demo_logs//datadog_logs",
+            &None,
+            TransformInputsStrategy::Auto,
+        )
+        .unwrap();
+        assert_eq!(cfg.matches("[schema]").count(), 1);
+
+        let builder = toml::from_str::<crate::config::ConfigBuilder>(&cfg).unwrap();
+        assert!(builder.schema.enabled);
+        assert!(builder.schema.validation);
+        assert_eq!(builder.schema.log_namespace, Some(false));
+    }
+
+    #[cfg(feature = "sources-datadog_agent")]
+    #[test]
+    fn generate_schema_hint_from_datadog_agent() {
+        let cfg = generate_example(
+            true,
+            "datadog_agent//",
+            &None,
+            TransformInputsStrategy::Auto,
+        )
+        .unwrap();
+        let builder = toml::from_str::<crate::config::ConfigBuilder>(&cfg).unwrap();
+        assert_eq!(builder.schema.log_namespace, Some(false));
+    }
+
+    #[cfg(feature = "sources-opentelemetry")]
+    #[test]
+    fn generate_schema_hint_from_opentelemetry() {
+        let cfg = generate_example(
+            true,
+            "opentelemetry//",
+            &None,
+            TransformInputsStrategy::Auto,
+        )
+        .unwrap();
+        let builder = toml::from_str::<crate::config::ConfigBuilder>(&cfg).unwrap();
+        assert_eq!(builder.schema.log_namespace, Some(false));
+    }
+
+    #[cfg(feature = "sinks-datadog_logs")]
+    #[test]
+    fn generate_schema_hint_from_sink() {
+        let cfg =
+            generate_example(true, "//datadog_logs", &None, TransformInputsStrategy::Auto).unwrap();
+        assert!(cfg.contains("[schema]\n"));
+        assert!(cfg.contains("validation = true\n"));
+
+        let builder = toml::from_str::<crate::config::ConfigBuilder>(&cfg).unwrap();
+        assert!(builder.schema.enabled);
+        assert!(builder.schema.validation);
+        assert_eq!(builder.schema.log_namespace, None);
+    }
+
+    #[cfg(feature = "sources-demo_logs")]
+    #[test]
+    fn generate_schema_hint_without_globals() {
+        let cfg =
+            generate_example(false, "demo_logs//", &None, TransformInputsStrategy::Auto).unwrap();
+        assert!(!cfg.contains("[schema]"));
+    }
 }
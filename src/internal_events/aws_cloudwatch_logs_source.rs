@@ -0,0 +1,96 @@
+use std::path::Path;
+
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use super::prelude::{error_stage, error_type, io_error_code};
+
+#[derive(Debug)]
+pub struct AwsCloudwatchLogsDescribeLogGroupsError<'a, E> {
+    pub error: &'a E,
+}
+
+impl<'a, E: std::fmt::Display> InternalEvent for AwsCloudwatchLogsDescribeLogGroupsError<'a, E> {
+    fn emit(self) {
+        error!(
+            message = "Failed to list log groups.",
+            error = %self.error,
+            error_code = "failed_describing_log_groups",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "failed_describing_log_groups",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct AwsCloudwatchLogsFilterLogEventsError<'a, E> {
+    pub group_name: &'a str,
+    pub error: &'a E,
+}
+
+impl<'a, E: std::fmt::Display> InternalEvent for AwsCloudwatchLogsFilterLogEventsError<'a, E> {
+    fn emit(self) {
+        error!(
+            message = "Failed to fetch log events.",
+            group_name = %self.group_name,
+            error = %self.error,
+            error_code = "failed_filtering_log_events",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "failed_filtering_log_events",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct AwsCloudwatchLogsCheckpointWriteError<'a> {
+    pub path: &'a Path,
+    pub error: std::io::Error,
+}
+
+impl<'a> InternalEvent for AwsCloudwatchLogsCheckpointWriteError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed writing checkpoints.",
+            path = ?self.path,
+            error = %self.error,
+            error_code = io_error_code(&self.error),
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => io_error_code(&self.error),
+            "error_type" => error_type::IO_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct AwsCloudwatchLogsGroupDeleted<'a> {
+    pub group_name: &'a str,
+}
+
+impl<'a> InternalEvent for AwsCloudwatchLogsGroupDeleted<'a> {
+    fn emit(self) {
+        info!(
+            message = "Log group no longer exists, stopped reading it.",
+            group_name = %self.group_name,
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use metrics::{counter, histogram};
+use vector_core::internal_event::InternalEvent;
+
+use super::prelude::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct BlackholeEventsReceived {
+    pub count: usize,
+    pub event_type: &'static str,
+}
+
+impl InternalEvent for BlackholeEventsReceived {
+    fn emit(self) {
+        trace!(
+            message = "Events received.",
+            count = self.count,
+            event_type = self.event_type,
+        );
+        counter!(
+            "component_received_events_total", self.count as u64,
+            "event_type" => self.event_type,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct BlackholeAckLatency {
+    pub latency: std::time::Duration,
+}
+
+impl InternalEvent for BlackholeAckLatency {
+    fn emit(self) {
+        trace!(message = "Events acknowledged.", latency = ?self.latency);
+        histogram!("blackhole_ack_latency_seconds", self.latency);
+    }
+}
+
+#[derive(Debug)]
+pub struct BlackholeIdleShutdown {
+    pub idle_secs: u64,
+}
+
+impl InternalEvent for BlackholeIdleShutdown {
+    fn emit(self) {
+        info!(
+            message = "No events received within the idle timeout, shutting down.",
+            idle_secs = self.idle_secs,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct BlackholeRejectedEventsError;
+
+impl InternalEvent for BlackholeRejectedEventsError {
+    fn emit(self) {
+        error!(
+            message = "Events consumed were rejected by another component, shutting down.",
+            error_code = "rejected_event",
+            error_type = error_type::CONDITION_FAILED,
+            stage = error_stage::SENDING,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "rejected_event",
+            "error_type" => error_type::CONDITION_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct BlackholeMissingFinalizersError {
+    pub count: usize,
+}
+
+impl InternalEvent for BlackholeMissingFinalizersError {
+    fn emit(self) {
+        error!(
+            message = "Received events without finalizers.",
+            count = self.count,
+            error_code = "missing_finalizers",
+            error_type = error_type::CONDITION_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "missing_finalizers",
+            "error_type" => error_type::CONDITION_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct BlackholeTempFileWriteError {
+    pub error: std::io::Error,
+}
+
+impl InternalEvent for BlackholeTempFileWriteError {
+    fn emit(self) {
+        error!(
+            message = "Failed to write events to temporary file.",
+            error = %self.error,
+            error_code = "temp_file_write_failed",
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "temp_file_write_failed",
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct BlackholeSnapshotWriteError<'a> {
+    pub path: &'a std::path::Path,
+    pub error: std::io::Error,
+}
+
+impl InternalEvent for BlackholeSnapshotWriteError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Failed to write snapshot of counters.",
+            path = %self.path.display(),
+            error = %self.error,
+            error_code = "snapshot_write_failed",
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "snapshot_write_failed",
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
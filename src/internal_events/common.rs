@@ -5,6 +5,7 @@ use vector_core::internal_event::InternalEvent;
 pub use vector_core::internal_event::{EventsReceived, OldEventsReceived};
 
 use super::prelude::{error_stage, error_type};
+use crate::{event::Event, topology::dropped_samples};
 
 #[derive(Debug)]
 pub struct BytesReceived<'a> {
@@ -65,6 +66,30 @@ impl<'a> InternalEvent for EndpointBytesSent<'a> {
     }
 }
 
+/// Events dropped by a component, counted by `component_discarded_events_total`.
+///
+/// The dropped events, when the component still holds them, are retained by components configured
+/// with `dropped_event_samples`.
+#[derive(Debug)]
+pub struct ComponentEventsDropped<'a> {
+    pub count: usize,
+    pub events: &'a [Event],
+    pub reason: &'a str,
+    pub error_type: &'static str,
+    pub stage: &'static str,
+}
+
+impl<'a> InternalEvent for ComponentEventsDropped<'a> {
+    fn emit(self) {
+        counter!(
+            "component_discarded_events_total", self.count as u64,
+            "error_type" => self.error_type,
+            "stage" => self.stage,
+        );
+        dropped_samples::record(self.events, self.reason);
+    }
+}
+
 const STREAM_CLOSED: &str = "stream_closed";
 
 #[derive(Debug)]
@@ -0,0 +1,49 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use super::prelude::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct DatadogAgentPassthroughRequest<'a> {
+    pub path: &'a str,
+    /// How the request was passed through, `accept` or `proxy`.
+    pub mode: &'static str,
+}
+
+impl<'a> InternalEvent for DatadogAgentPassthroughRequest<'a> {
+    fn emit(self) {
+        trace!(
+            message = "Passing through request to unsupported intake.",
+            path = %self.path,
+            mode = %self.mode,
+        );
+        counter!(
+            "passthrough_requests_total", 1,
+            "mode" => self.mode,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct DatadogAgentPassthroughError<'a, E> {
+    pub path: &'a str,
+    pub error: E,
+}
+
+impl<'a, E: std::fmt::Display> InternalEvent for DatadogAgentPassthroughError<'a, E> {
+    fn emit(self) {
+        error!(
+            message = "Failed to proxy request to upstream endpoint.",
+            path = %self.path,
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use super::prelude::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct EnrichLookupError<'a> {
+    pub table: &'a str,
+    pub error: String,
+}
+
+impl InternalEvent for EnrichLookupError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Failed to look up enrichment table row.",
+            table = %self.table,
+            error = %self.error,
+            error_code = "lookup_failed",
+            error_type = error_type::CONDITION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "lookup_failed",
+            "error_type" => error_type::CONDITION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct EnrichEventDiscarded;
+
+impl InternalEvent for EnrichEventDiscarded {
+    fn emit(self) {
+        debug!(
+            message = "Event did not match any enrichment table row; discarding event.",
+            internal_log_rate_secs = 30,
+        );
+        counter!("component_discarded_events_total", 1);
+    }
+}
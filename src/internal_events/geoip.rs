@@ -35,3 +35,41 @@ impl<'a> InternalEvent for GeoipIpAddressParseError<'a> {
         );
     }
 }
+
+#[derive(Debug)]
+pub struct GeoipDatabaseDownloaded<'a> {
+    pub url: &'a str,
+}
+
+impl<'a> InternalEvent for GeoipDatabaseDownloaded<'a> {
+    fn emit(self) {
+        debug!(message = "GeoIP database downloaded.", url = %self.url);
+        counter!("geoip_database_downloads_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct GeoipDatabaseDownloadError<'a> {
+    pub url: &'a str,
+    pub error: crate::Error,
+}
+
+impl<'a> InternalEvent for GeoipDatabaseDownloadError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed downloading GeoIP database; keeping the previous database.",
+            url = %self.url,
+            error = %self.error,
+            error_code = "database_download_failed",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "database_download_failed",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
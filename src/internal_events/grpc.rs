@@ -0,0 +1,28 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use super::prelude::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct GrpcTruncatedMessageError {
+    pub byte_size: usize,
+}
+
+impl InternalEvent for GrpcTruncatedMessageError {
+    fn emit(self) {
+        error!(
+            message = "Request body ended within a gRPC message; dropping the partial message.",
+            byte_size = %self.byte_size,
+            error_code = "truncated_message",
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "truncated_message",
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
@@ -111,7 +111,8 @@ impl InternalEvent for KafkaReadError {
 
 #[derive(Debug)]
 pub struct KafkaStatisticsReceived<'a> {
-    pub statistics: &'a rdkafka::Statistics,
+    pub statistics: &'a crate::kafka::Statistics,
+    pub partition_tags: bool,
 }
 
 impl InternalEvent for KafkaStatisticsReceived<'_> {
@@ -147,6 +148,57 @@ impl InternalEvent for KafkaStatisticsReceived<'_> {
             "kafka_consumed_messages_bytes_total",
             self.statistics.rxmsg_bytes as u64
         );
+        for (broker, latency) in self.statistics.request_latencies() {
+            gauge!("kafka_request_latency_avg", latency, "broker" => broker.to_owned());
+        }
+        for lag in self.statistics.consumer_lags(self.partition_tags) {
+            match lag.partition {
+                Some(partition) => gauge!(
+                    "kafka_consumer_lag", lag.lag as f64,
+                    "topic" => lag.topic.to_owned(),
+                    "partition" => partition.to_string(),
+                ),
+                None => gauge!(
+                    "kafka_consumer_lag", lag.lag as f64,
+                    "topic" => lag.topic.to_owned(),
+                ),
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct KafkaStatisticsParseError {
+    pub error: serde_json::Error,
+}
+
+impl InternalEvent for KafkaStatisticsParseError {
+    fn emit(self) {
+        warn!(
+            message = "Failed to parse librdkafka statistics.",
+            error = %self.error,
+            internal_log_rate_secs = 10,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct KafkaDeliveryError<'a> {
+    pub topic: &'a str,
+    pub error: &'a rdkafka::error::KafkaError,
+}
+
+impl InternalEvent for KafkaDeliveryError<'_> {
+    fn emit(self) {
+        debug!(
+            message = "Failed to deliver message.",
+            topic = self.topic,
+            error = %self.error,
+        );
+        counter!(
+            "kafka_delivery_errors_total", 1,
+            "topic" => self.topic.to_owned(),
+        );
     }
 }
 
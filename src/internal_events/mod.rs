@@ -10,6 +10,8 @@ mod api;
 mod aws;
 #[cfg(feature = "sinks-aws_cloudwatch_logs")]
 mod aws_cloudwatch_logs;
+#[cfg(feature = "sources-aws_cloudwatch_logs")]
+mod aws_cloudwatch_logs_source;
 #[cfg(feature = "transforms-aws_ec2_metadata")]
 mod aws_ec2_metadata;
 #[cfg(feature = "sources-aws_ecs_metrics")]
@@ -21,8 +23,12 @@ mod aws_sqs;
 #[cfg(any(feature = "sinks-azure_blob", feature = "sinks-datadog_archives"))]
 pub(crate) mod azure_blob;
 mod batch;
+#[cfg(feature = "sinks-blackhole")]
+mod blackhole;
 mod common;
 mod conditions;
+#[cfg(feature = "sources-datadog_agent")]
+mod datadog_agent;
 #[cfg(feature = "sinks-datadog_metrics")]
 mod datadog_metrics;
 #[cfg(feature = "sinks-datadog_traces")]
@@ -38,6 +44,8 @@ mod dnstap;
 mod docker_logs;
 mod elasticsearch;
 mod encoding_transcode;
+#[cfg(feature = "transforms-enrich")]
+mod enrich;
 #[cfg(feature = "sources-eventstoredb_metrics")]
 mod eventstoredb_metrics;
 #[cfg(feature = "sources-exec")]
@@ -48,8 +56,10 @@ mod filter;
 mod fluent;
 #[cfg(feature = "sources-gcp_pubsub")]
 mod gcp_pubsub;
-#[cfg(feature = "transforms-geoip")]
+#[cfg(any(feature = "enrichment-tables-geoip", feature = "transforms-geoip"))]
 mod geoip;
+#[cfg(any(feature = "sources-vector", feature = "sources-opentelemetry"))]
+mod grpc;
 mod heartbeat;
 mod http;
 pub mod http_client;
@@ -77,6 +87,8 @@ mod nats;
 #[cfg(feature = "sources-nginx_metrics")]
 mod nginx_metrics;
 mod open;
+#[cfg(any(feature = "sources-opentelemetry", feature = "sinks-opentelemetry"))]
+mod opentelemetry;
 #[cfg(any(
     feature = "sinks-datadog_events",
     feature = "sources-kubernetes_logs",
@@ -86,6 +98,8 @@ mod open;
 mod parser;
 #[cfg(feature = "sources-postgresql_metrics")]
 mod postgresql_metrics;
+#[cfg(feature = "sources-probe")]
+mod probe;
 mod process;
 #[cfg(any(feature = "sources-prometheus", feature = "sinks-prometheus"))]
 mod prometheus;
@@ -97,6 +111,7 @@ mod remap;
 mod sample;
 #[cfg(feature = "sinks-sematext")]
 mod sematext_metrics;
+mod sink_fallback;
 mod socket;
 #[cfg(any(feature = "sources-splunk_hec", feature = "sinks-splunk_hec"))]
 mod splunk_hec;
@@ -139,6 +154,8 @@ pub(crate) use self::api::*;
 pub(crate) use self::aws::*;
 #[cfg(feature = "sinks-aws_cloudwatch_logs")]
 pub(crate) use self::aws_cloudwatch_logs::*;
+#[cfg(feature = "sources-aws_cloudwatch_logs")]
+pub(crate) use self::aws_cloudwatch_logs_source::*;
 #[cfg(feature = "transforms-aws_ec2_metadata")]
 pub(crate) use self::aws_ec2_metadata::*;
 #[cfg(feature = "sources-aws_ecs_metrics")]
@@ -147,6 +164,10 @@ pub(crate) use self::aws_ecs_metrics::*;
 pub(crate) use self::aws_kinesis_firehose::*;
 #[cfg(any(feature = "sources-aws_s3", feature = "sources-aws_sqs",))]
 pub(crate) use self::aws_sqs::*;
+#[cfg(feature = "sinks-blackhole")]
+pub(crate) use self::blackhole::*;
+#[cfg(feature = "sources-datadog_agent")]
+pub(crate) use self::datadog_agent::*;
 #[cfg(feature = "sinks-datadog_metrics")]
 pub(crate) use self::datadog_metrics::*;
 #[cfg(feature = "sinks-datadog_traces")]
@@ -162,6 +183,8 @@ pub(crate) use self::dnstap::*;
 pub(crate) use self::docker_logs::*;
 #[cfg(feature = "sinks-elasticsearch")]
 pub(crate) use self::elasticsearch::*;
+#[cfg(feature = "transforms-enrich")]
+pub(crate) use self::enrich::*;
 #[cfg(feature = "sources-eventstoredb_metrics")]
 pub(crate) use self::eventstoredb_metrics::*;
 #[cfg(feature = "sources-exec")]
@@ -178,8 +201,10 @@ pub(crate) use self::filter::*;
 pub(crate) use self::fluent::*;
 #[cfg(feature = "sources-gcp_pubsub")]
 pub(crate) use self::gcp_pubsub::*;
-#[cfg(feature = "transforms-geoip")]
+#[cfg(any(feature = "enrichment-tables-geoip", feature = "transforms-geoip"))]
 pub(crate) use self::geoip::*;
+#[cfg(any(feature = "sources-vector", feature = "sources-opentelemetry"))]
+pub(crate) use self::grpc::*;
 #[cfg(any(
     feature = "sources-utils-http",
     feature = "sources-utils-http-encoding",
@@ -209,6 +234,8 @@ pub(crate) use self::metric_to_log::*;
 pub(crate) use self::nats::*;
 #[cfg(feature = "sources-nginx_metrics")]
 pub(crate) use self::nginx_metrics::*;
+#[cfg(any(feature = "sources-opentelemetry", feature = "sinks-opentelemetry"))]
+pub(crate) use self::opentelemetry::*;
 #[cfg(any(
     feature = "sinks-datadog_events",
     feature = "sources-kubernetes_logs",
@@ -218,6 +245,8 @@ pub(crate) use self::nginx_metrics::*;
 pub(crate) use self::parser::*;
 #[cfg(feature = "sources-postgresql_metrics")]
 pub(crate) use self::postgresql_metrics::*;
+#[cfg(feature = "sources-probe")]
+pub(crate) use self::probe::*;
 #[cfg(any(feature = "sources-prometheus", feature = "sinks-prometheus"))]
 pub(crate) use self::prometheus::*;
 #[cfg(any(feature = "sources-redis", feature = "sinks-redis"))]
@@ -263,7 +292,7 @@ pub(crate) use self::websocket::*;
 pub(crate) use self::windows::*;
 pub(crate) use self::{
     adaptive_concurrency::*, batch::*, common::*, conditions::*, encoding_transcode::*,
-    heartbeat::*, open::*, process::*, socket::*, tcp::*, template::*, udp::*,
+    heartbeat::*, open::*, process::*, sink_fallback::*, socket::*, tcp::*, template::*, udp::*,
 };
 
 // this version won't be needed once all `InternalEvent`s implement `name()`
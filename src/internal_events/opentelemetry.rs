@@ -0,0 +1,272 @@
+use metrics::{counter, histogram};
+use vector_core::internal_event::InternalEvent;
+
+use crate::{
+    internal_events::prelude::{error_stage, error_type},
+    opentelemetry::convert::ConvertError,
+};
+
+#[derive(Debug)]
+pub struct OpentelemetryRecordConversionError<'a> {
+    pub error: &'a ConvertError,
+    pub record_index: usize,
+}
+
+impl<'a> InternalEvent for OpentelemetryRecordConversionError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed to convert log record into an event.",
+            error = %self.error,
+            record_index = self.record_index,
+            error_code = "failed_converting_record",
+            error_type = error_type::CONVERSION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_secs = 10,
+        );
+
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "failed_converting_record",
+            "error_type" => error_type::CONVERSION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+        // The record is skipped, and the rest of the request is still accepted.
+        counter!(
+            "component_discarded_events_total", 1,
+            "error_code" => "failed_converting_record",
+            "error_type" => error_type::CONVERSION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryRecordsRejected<'a> {
+    pub count: usize,
+    pub error_message: &'a str,
+}
+
+impl<'a> InternalEvent for OpentelemetryRecordsRejected<'a> {
+    fn emit(self) {
+        error!(
+            message = "Log records were rejected by the receiver.",
+            count = %self.count,
+            receiver_message = %self.error_message,
+            error_code = "records_rejected",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_secs = 10,
+        );
+
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "records_rejected",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        // The rest of the records of the request were accepted, so it isn't retried.
+        counter!(
+            "component_discarded_events_total", self.count as u64,
+            "error_code" => "records_rejected",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetrySpanConversionError<'a> {
+    pub error: &'a ConvertError,
+    pub span_index: usize,
+}
+
+impl<'a> InternalEvent for OpentelemetrySpanConversionError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed to convert span into an event.",
+            error = %self.error,
+            span_index = self.span_index,
+            error_code = "failed_converting_span",
+            error_type = error_type::CONVERSION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_secs = 10,
+        );
+
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "failed_converting_span",
+            "error_type" => error_type::CONVERSION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryMetricConversionError<'a> {
+    pub error: &'a ConvertError,
+    pub data_point_index: usize,
+}
+
+impl<'a> InternalEvent for OpentelemetryMetricConversionError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed to convert metric data point into an event.",
+            error = %self.error,
+            data_point_index = self.data_point_index,
+            error_code = "failed_converting_metric",
+            error_type = error_type::CONVERSION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_secs = 10,
+        );
+
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "failed_converting_metric",
+            "error_type" => error_type::CONVERSION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
+
+/// Events that could not be emitted because of an unexpected error, as opposed to events dropped
+/// intentionally, for example by `resource_filter`.
+#[derive(Debug)]
+pub struct OpentelemetryEventsFailed<'a> {
+    pub count: usize,
+    pub output: &'a str,
+}
+
+impl InternalEvent for OpentelemetryEventsFailed<'_> {
+    fn emit(self) {
+        counter!(
+            "component_events_failed_total", self.count as u64,
+            "output" => self.output.to_owned(),
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryBodyPatternMismatch;
+
+impl InternalEvent for OpentelemetryBodyPatternMismatch {
+    fn emit(self) {
+        debug!(
+            message = "Log record body did not match `body_pattern`; passing it through unchanged.",
+            internal_log_rate_secs = 10,
+        );
+        counter!("body_pattern_misses_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryJsonBodyMiss;
+
+impl InternalEvent for OpentelemetryJsonBodyMiss {
+    fn emit(self) {
+        debug!(
+            message = "Log record body is not a JSON object; passing it through unchanged.",
+            internal_log_rate_secs = 10,
+        );
+        counter!("json_body_misses_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryTenantThrottled<'a> {
+    pub tenant: &'a str,
+    pub count: usize,
+}
+
+impl<'a> InternalEvent for OpentelemetryTenantThrottled<'a> {
+    fn emit(self) {
+        warn!(
+            message = "Tenant exceeded its rate limit; rejecting export request.",
+            tenant = %self.tenant,
+            count = %self.count,
+            internal_log_rate_secs = 10,
+        );
+        counter!("tenant_requests_throttled_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryDuplicateRequest<'a> {
+    pub key: &'a str,
+}
+
+impl<'a> InternalEvent for OpentelemetryDuplicateRequest<'a> {
+    fn emit(self) {
+        debug!(
+            message = "Export request was already processed; acknowledging it without emitting its log records.",
+            key = %self.key,
+            internal_log_rate_secs = 10,
+        );
+        counter!("duplicate_requests_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryResourceFiltered {
+    pub count: usize,
+}
+
+impl InternalEvent for OpentelemetryResourceFiltered {
+    fn emit(self) {
+        debug!(
+            message = "Resource did not match `resource_filter`; dropping its log records.",
+            count = %self.count,
+            internal_log_rate_secs = 10,
+        );
+        counter!("filtered_records_total", self.count as u64);
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryRecordsReceived<'a> {
+    pub count: usize,
+    /// The name and value of the tag of the records, from an attribute of their resource.
+    pub tag: Option<(&'a str, &'a str)>,
+}
+
+impl<'a> InternalEvent for OpentelemetryRecordsReceived<'a> {
+    fn emit(self) {
+        trace!(message = "Log records received.", count = %self.count);
+        match self.tag {
+            Some((key, value)) => counter!(
+                "component_received_records_total", self.count as u64,
+                key.to_owned() => value.to_owned(),
+            ),
+            None => counter!("component_received_records_total", self.count as u64),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryEventSize {
+    pub byte_size: usize,
+}
+
+impl InternalEvent for OpentelemetryEventSize {
+    fn emit(self) {
+        histogram!(
+            "component_output_event_bytes_histogram",
+            self.byte_size as f64
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct OpentelemetryConnectionRejected {
+    pub max_connections: usize,
+}
+
+impl InternalEvent for OpentelemetryConnectionRejected {
+    fn emit(self) {
+        warn!(
+            message = "HTTP server reached its connection limit; rejecting connection.",
+            max_connections = %self.max_connections,
+            internal_log_rate_secs = 10,
+        );
+        counter!("connections_rejected_total", 1);
+    }
+}
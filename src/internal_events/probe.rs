@@ -0,0 +1,47 @@
+use vector_core::internal_event::InternalEvent;
+
+use crate::sources::probe::ProbeError;
+
+#[derive(Debug)]
+pub struct ProbeFailed<'a> {
+    pub target: &'a str,
+    pub probe: &'static str,
+    pub error: &'a ProbeError,
+}
+
+impl InternalEvent for ProbeFailed<'_> {
+    fn emit(self) {
+        // Failed probes are reported by the `probe_success` metric, they aren't errors of the source.
+        debug!(
+            message = "Probe failed.",
+            target = self.target,
+            probe = self.probe,
+            error = %self.error,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct ProbeTargetStateChanged<'a> {
+    pub target: &'a str,
+    pub probe: &'static str,
+    pub up: bool,
+}
+
+impl InternalEvent for ProbeTargetStateChanged<'_> {
+    fn emit(self) {
+        if self.up {
+            info!(
+                message = "Probe target is up.",
+                target = self.target,
+                probe = self.probe,
+            );
+        } else {
+            warn!(
+                message = "Probe target is down.",
+                target = self.target,
+                probe = self.probe,
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use metrics::counter;
+use metrics::{counter, gauge};
 use vector_core::internal_event::InternalEvent;
 
 use super::prelude::{error_stage, error_type};
@@ -21,6 +21,53 @@ impl InternalEvent for VectorStarted {
     }
 }
 
+#[derive(Debug)]
+pub struct VectorBuildInfo;
+
+impl InternalEvent for VectorBuildInfo {
+    fn emit(self) {
+        // The build description starts with the revision Vector was built from.
+        let revision = built_info::VECTOR_BUILD_DESC
+            .and_then(|desc| desc.split_whitespace().next())
+            .unwrap_or("none");
+        gauge!(
+            "build_info", 1.0,
+            "version" => built_info::PKG_VERSION,
+            "arch" => built_info::TARGET_ARCH,
+            "revision" => revision,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct VectorConfigHashInfo<'a> {
+    pub hash: &'a str,
+}
+
+impl InternalEvent for VectorConfigHashInfo<'_> {
+    fn emit(self) {
+        gauge!("config_hash_info", 1.0, "hash" => self.hash.to_owned());
+    }
+}
+
+#[derive(Debug)]
+pub struct VectorComponentInfo<'a> {
+    pub component_id: &'a str,
+    pub kind: &'static str,
+    pub component_type: &'static str,
+}
+
+impl InternalEvent for VectorComponentInfo<'_> {
+    fn emit(self) {
+        gauge!(
+            "component_info", 1.0,
+            "component_id" => self.component_id.to_owned(),
+            "kind" => self.kind,
+            "type" => self.component_type,
+        );
+    }
+}
+
 #[derive(Debug)]
 pub struct VectorReloaded<'a> {
     pub config_paths: &'a [config::ConfigPath],
@@ -31,13 +31,6 @@ impl InternalEvent for RemapMappingError {
             "error_type" => error_type::CONVERSION_FAILED,
             "stage" => error_stage::PROCESSING,
         );
-        if self.event_dropped {
-            counter!(
-                "component_discarded_events_total", 1,
-                "error_type" => error_type::CONVERSION_FAILED,
-                "stage" => error_stage::PROCESSING,
-            );
-        }
         // deprecated
         counter!("processing_errors_total", 1);
     }
@@ -59,13 +52,5 @@ impl InternalEvent for RemapMappingAbort {
         };
 
         debug!(message, internal_log_rate_secs = 30);
-
-        if self.event_dropped {
-            counter!(
-                "component_discarded_events_total", 1,
-                "error_type" => error_type::CONVERSION_FAILED,
-                "stage" => error_stage::PROCESSING,
-            );
-        }
     }
 }
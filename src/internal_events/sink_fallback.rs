@@ -0,0 +1,61 @@
+use metrics::counter;
+use vector_core::{config::ComponentKey, internal_event::InternalEvent};
+
+use super::prelude::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct SinkCircuitBreakerOpened<'a> {
+    pub fallback: &'a ComponentKey,
+}
+
+impl<'a> InternalEvent for SinkCircuitBreakerOpened<'a> {
+    fn emit(self) {
+        warn!(
+            message = "Circuit breaker opened; sending events to fallback sink.",
+            fallback = %self.fallback,
+        );
+        counter!("sink_circuit_breaker_opened_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct SinkCircuitBreakerClosed;
+
+impl InternalEvent for SinkCircuitBreakerClosed {
+    fn emit(self) {
+        info!(message = "Circuit breaker closed; sending events to sink again.");
+        counter!("sink_circuit_breaker_closed_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct SinkFallbackSendError<'a> {
+    pub fallback: &'a ComponentKey,
+    pub count: usize,
+}
+
+impl<'a> InternalEvent for SinkFallbackSendError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed sending events to fallback sink; dropping events.",
+            fallback = %self.fallback,
+            count = %self.count,
+            error_code = "fallback_closed",
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_secs = 10,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "fallback_closed",
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        counter!(
+            "component_discarded_events_total", self.count as u64,
+            "error_code" => "fallback_closed",
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
@@ -48,6 +48,48 @@ impl<E: std::error::Error> InternalEvent for TcpSocketConnectionError<E> {
     }
 }
 
+#[derive(Debug)]
+pub struct TlsHandshakeError<'a> {
+    pub error: &'a TlsError,
+    pub peer_addr: SocketAddr,
+}
+
+impl<'a> InternalEvent for TlsHandshakeError<'a> {
+    fn emit(self) {
+        match self.error {
+            // Connections closed before the handshake, such as by TCP healthchecks.
+            TlsError::Handshake { source }
+                if source.code() == openssl::ssl::ErrorCode::SYSCALL
+                    && source.io_error().is_none() =>
+            {
+                debug!(
+                    message = "Connection closed during the TLS handshake, probably a healthcheck.",
+                    error = %self.error,
+                    peer_addr = %self.peer_addr,
+                    internal_log_rate_secs = 10,
+                );
+            }
+            _ => {
+                error!(
+                    message = "TLS handshake failed, connection rejected.",
+                    error = %self.error,
+                    peer_addr = %self.peer_addr,
+                    error_code = "tls_handshake_failed",
+                    error_type = error_type::CONNECTION_FAILED,
+                    stage = error_stage::RECEIVING,
+                    internal_log_rate_secs = 10,
+                );
+                counter!(
+                    "component_errors_total", 1,
+                    "error_code" => "tls_handshake_failed",
+                    "error_type" => error_type::CONNECTION_FAILED,
+                    "stage" => error_stage::RECEIVING,
+                );
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TcpSocketConnectionShutdown;
 
@@ -190,3 +232,21 @@ impl InternalEvent for TcpBytesReceived {
         );
     }
 }
+
+#[derive(Debug)]
+pub struct TcpConnectionThrottled {
+    pub peer_addr: SocketAddr,
+    pub delay: std::time::Duration,
+}
+
+impl InternalEvent for TcpConnectionThrottled {
+    fn emit(self) {
+        debug!(
+            message = "Connection exceeded its rate limit; pausing reads.",
+            peer_addr = %self.peer_addr,
+            delay_ms = %self.delay.as_millis(),
+            internal_log_rate_secs = 10,
+        );
+        counter!("connection_throttled_total", 1, "mode" => "tcp");
+    }
+}
@@ -1,4 +1,4 @@
-use metrics::counter;
+use metrics::{counter, gauge};
 use vector_core::internal_event::InternalEvent;
 
 #[derive(Debug)]
@@ -15,3 +15,36 @@ impl InternalEvent for ThrottleEventDiscarded {
         );
     }
 }
+
+#[derive(Debug)]
+pub(crate) struct ThrottleKeyUtilization {
+    pub key: String,
+    pub utilization: f64,
+}
+
+impl InternalEvent for ThrottleKeyUtilization {
+    fn emit(self) {
+        gauge!(
+            "throttle_key_utilization", self.utilization,
+            "key" => self.key,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ThrottleKeySeen;
+
+impl InternalEvent for ThrottleKeySeen {
+    fn emit(self) {
+        counter!("throttle_unique_keys_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ThrottleKeyUntracked;
+
+impl InternalEvent for ThrottleKeyUntracked {
+    fn emit(self) {
+        counter!("throttle_untracked_keys_total", 1);
+    }
+}
@@ -87,3 +87,27 @@ impl InternalEvent for UdpSendIncompleteError {
         counter!("connection_send_errors_total", 1, "mode" => "udp");
     }
 }
+
+#[derive(Debug)]
+pub struct UdpChunkingError<E> {
+    pub error: E,
+    pub data_size: usize,
+}
+
+impl<E: std::fmt::Display> InternalEvent for UdpChunkingError<E> {
+    fn emit(self) {
+        error!(
+            message = "Could not split data into UDP packets; dropping it.",
+            error = %self.error,
+            data_size = self.data_size,
+            internal_log_rate_secs = 30,
+            error_type = error_type::ENCODER_FAILED,
+            stage = error_stage::SENDING,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::ENCODER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
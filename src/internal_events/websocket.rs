@@ -87,3 +87,57 @@ impl InternalEvent for WsConnectionError {
         Some("WsConnectionError")
     }
 }
+
+#[derive(Debug)]
+pub struct WsServerClientConnected<'a> {
+    pub peer_addr: &'a std::net::SocketAddr,
+}
+
+impl<'a> InternalEvent for WsServerClientConnected<'a> {
+    fn emit(self) {
+        debug!(message = "WebSocket client connected.", peer_addr = %self.peer_addr);
+        counter!("connection_established_total", 1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("WsServerClientConnected")
+    }
+}
+
+#[derive(Debug)]
+pub struct WsServerClientDisconnected<'a> {
+    pub peer_addr: &'a std::net::SocketAddr,
+}
+
+impl<'a> InternalEvent for WsServerClientDisconnected<'a> {
+    fn emit(self) {
+        debug!(message = "WebSocket client disconnected.", peer_addr = %self.peer_addr);
+        counter!("connection_shutdown_total", 1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("WsServerClientDisconnected")
+    }
+}
+
+#[derive(Debug)]
+pub struct WsServerMessagesDropped<'a> {
+    pub peer_addr: &'a std::net::SocketAddr,
+    pub count: usize,
+}
+
+impl<'a> InternalEvent for WsServerMessagesDropped<'a> {
+    fn emit(self) {
+        warn!(
+            message = "WebSocket client is too slow; dropped its oldest buffered messages.",
+            peer_addr = %self.peer_addr,
+            count = %self.count,
+            internal_log_rate_secs = 10,
+        );
+        counter!("websocket_messages_dropped_total", self.count as u64);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("WsServerMessagesDropped")
+    }
+}
@@ -1,10 +1,17 @@
 use std::path::{Path, PathBuf};
 
-use rdkafka::{consumer::ConsumerContext, ClientConfig, ClientContext, Statistics};
+use rdkafka::{consumer::ConsumerContext, ClientConfig, ClientContext};
 use snafu::Snafu;
 use vector_config::configurable_component;
 
-use crate::{internal_events::KafkaStatisticsReceived, tls::TlsEnableableConfig};
+use crate::{
+    internal_events::{KafkaStatisticsParseError, KafkaStatisticsReceived},
+    tls::TlsEnableableConfig,
+};
+
+mod statistics;
+
+pub(crate) use statistics::Statistics;
 
 #[derive(Debug, Snafu)]
 enum KafkaError {
@@ -122,13 +129,23 @@ fn pathbuf_to_string(path: &Path) -> crate::Result<&str> {
         .ok_or_else(|| KafkaError::InvalidPath { path: path.into() }.into())
 }
 
-pub(crate) struct KafkaStatisticsContext;
+/// Translates the statistics periodically reported by `librdkafka` into internal metrics.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct KafkaStatisticsContext {
+    /// Whether per-partition metrics are tagged with the partition, rather than aggregated per
+    /// topic.
+    pub(crate) partition_tags: bool,
+}
 
 impl ClientContext for KafkaStatisticsContext {
-    fn stats(&self, statistics: Statistics) {
-        emit!(KafkaStatisticsReceived {
-            statistics: &statistics
-        });
+    fn stats_raw(&self, statistics: &[u8]) {
+        match serde_json::from_slice::<Statistics>(statistics) {
+            Ok(statistics) => emit!(KafkaStatisticsReceived {
+                statistics: &statistics,
+                partition_tags: self.partition_tags,
+            }),
+            Err(error) => emit!(KafkaStatisticsParseError { error }),
+        }
     }
 }
 
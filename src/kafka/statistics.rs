@@ -0,0 +1,230 @@
+//! The subset of `librdkafka`'s statistics that are translated into internal metrics.
+//!
+//! `librdkafka` periodically reports its statistics as a JSON document, described in
+//! <https://github.com/edenhill/librdkafka/blob/master/STATISTICS.md>. Only the fields used by
+//! Vector are deserialized, and all of them are optional since their availability depends on the
+//! version of `librdkafka` and on the type of client.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+/// The partition `librdkafka` uses for messages that aren't assigned to a partition yet.
+const UNASSIGNED_PARTITION: i32 = -1;
+
+/// Top-level statistics of a `librdkafka` client.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Statistics {
+    /// Current number of messages in producer queues.
+    pub msg_cnt: u64,
+    /// Current total size of messages in producer queues.
+    pub msg_size: u64,
+    /// Total number of requests sent to brokers.
+    pub tx: u64,
+    /// Total number of bytes transmitted to brokers.
+    pub tx_bytes: u64,
+    /// Total number of responses received from brokers.
+    pub rx: u64,
+    /// Total number of bytes received from brokers.
+    pub rx_bytes: u64,
+    /// Total number of messages produced.
+    pub txmsgs: u64,
+    /// Total size of the messages produced.
+    pub txmsg_bytes: u64,
+    /// Total number of messages consumed.
+    pub rxmsgs: u64,
+    /// Total size of the messages consumed.
+    pub rxmsg_bytes: u64,
+    /// The brokers, keyed by name.
+    pub brokers: BTreeMap<String, Broker>,
+    /// The topics, keyed by name.
+    pub topics: BTreeMap<String, Topic>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Broker {
+    /// The name of the broker, in the form `<host>:<port>/<id>`.
+    pub name: String,
+    /// How the broker was learned: `configured`, `learned`, `internal` or `logical`.
+    pub source: String,
+    /// Round-trip time of the requests to the broker.
+    pub rtt: Window,
+}
+
+/// Rolling window statistics, in microseconds.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Window {
+    pub avg: i64,
+    /// The number of values sampled in the window.
+    pub cnt: i64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Topic {
+    /// The partitions of the topic, keyed by their id.
+    pub partitions: BTreeMap<String, Partition>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Partition {
+    pub partition: i32,
+    /// The difference between the high watermark and the committed offset, or `-1` if unknown.
+    pub consumer_lag: i64,
+}
+
+/// The consumer lag of a topic, or of one of its partitions.
+#[derive(Debug, PartialEq)]
+pub struct ConsumerLag<'a> {
+    pub topic: &'a str,
+    /// The partition, unless the lag is the sum of the lags of all partitions of the topic.
+    pub partition: Option<i32>,
+    pub lag: i64,
+}
+
+impl Statistics {
+    /// The average round-trip time, in seconds, of the requests sent to each broker in the last
+    /// statistics window.
+    ///
+    /// The internal and logical brokers `librdkafka` uses for bookkeeping are skipped, as are the
+    /// brokers without requests in the window.
+    pub fn request_latencies(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.brokers
+            .values()
+            .filter(|broker| {
+                broker.source != "internal" && broker.source != "logical" && broker.rtt.cnt > 0
+            })
+            .map(|broker| (broker.name.as_str(), broker.rtt.avg as f64 / 1_000_000.0))
+    }
+
+    /// The consumer lag of each assigned partition, or of each topic if `partition_tags` is
+    /// `false`, in which case the lags of its partitions are summed.
+    ///
+    /// Partitions whose lag isn't known yet are skipped.
+    pub fn consumer_lags(&self, partition_tags: bool) -> Vec<ConsumerLag<'_>> {
+        let mut lags = Vec::new();
+        for (topic, stats) in &self.topics {
+            let partitions = stats.partitions.values().filter(|partition| {
+                partition.partition != UNASSIGNED_PARTITION && partition.consumer_lag >= 0
+            });
+
+            if partition_tags {
+                lags.extend(partitions.map(|partition| ConsumerLag {
+                    topic,
+                    partition: Some(partition.partition),
+                    lag: partition.consumer_lag,
+                }));
+            } else {
+                let mut partitions = partitions.peekable();
+                if partitions.peek().is_some() {
+                    lags.push(ConsumerLag {
+                        topic,
+                        partition: None,
+                        lag: partitions.map(|partition| partition.consumer_lag).sum(),
+                    });
+                }
+            }
+        }
+        lags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(name: &str) -> Statistics {
+        let path = format!("tests/data/kafka/{}", name);
+        let payload = std::fs::read(&path).unwrap();
+        serde_json::from_slice(&payload).unwrap()
+    }
+
+    #[test]
+    fn parses_producer_statistics() {
+        let statistics = load("statistics_producer.json");
+
+        assert_eq!(statistics.msg_cnt, 42);
+        assert_eq!(statistics.msg_size, 8_400);
+        assert_eq!(statistics.tx, 310);
+        assert_eq!(statistics.txmsgs, 12_000);
+        assert_eq!(
+            statistics.request_latencies().collect::<Vec<_>>(),
+            vec![("kafka-1:9092/1", 0.002_5), ("kafka-2:9092/2", 0.004)]
+        );
+        // Producers don't have a consumer lag.
+        assert!(statistics.consumer_lags(true).is_empty());
+    }
+
+    #[test]
+    fn parses_consumer_statistics() {
+        let statistics = load("statistics_consumer.json");
+
+        assert_eq!(statistics.rxmsgs, 5_000);
+        assert_eq!(statistics.rxmsg_bytes, 1_250_000);
+        assert_eq!(
+            statistics.request_latencies().collect::<Vec<_>>(),
+            vec![("kafka-1:9092/1", 0.010)]
+        );
+    }
+
+    #[test]
+    fn consumer_lag_per_partition() {
+        let statistics = load("statistics_consumer.json");
+
+        assert_eq!(
+            statistics.consumer_lags(true),
+            vec![
+                ConsumerLag {
+                    topic: "events",
+                    partition: Some(0),
+                    lag: 120,
+                },
+                ConsumerLag {
+                    topic: "events",
+                    partition: Some(1),
+                    lag: 30,
+                },
+                ConsumerLag {
+                    topic: "logs",
+                    partition: Some(0),
+                    lag: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn consumer_lag_per_topic() {
+        let statistics = load("statistics_consumer.json");
+
+        assert_eq!(
+            statistics.consumer_lags(false),
+            vec![
+                ConsumerLag {
+                    topic: "events",
+                    partition: None,
+                    lag: 150,
+                },
+                ConsumerLag {
+                    topic: "logs",
+                    partition: None,
+                    lag: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tolerates_missing_fields() {
+        let statistics: Statistics =
+            serde_json::from_str(r#"{"name": "rdkafka#producer-1", "brokers": {"b": {}}}"#)
+                .unwrap();
+
+        assert_eq!(statistics.msg_cnt, 0);
+        assert_eq!(statistics.request_latencies().count(), 0);
+    }
+}
@@ -71,6 +71,7 @@ pub mod opentelemetry;
 #[allow(unreachable_pub)]
 pub(crate) mod proto;
 pub mod providers;
+pub mod sensitive_string;
 pub mod serde;
 #[cfg(windows)]
 pub mod service;
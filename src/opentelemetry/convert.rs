@@ -1,19 +1,37 @@
 use super::{
-    Common::{any_value::Value as PBValue, KeyValue},
-    Logs::{LogRecord, ResourceLogs, SeverityNumber},
+    Common::{
+        any_value::Value as PBValue, AnyValue, ArrayValue, InstrumentationScope, KeyValue,
+        KeyValueList,
+    },
+    LogService::ExportLogsServiceRequest,
+    Logs::{LogRecord, ResourceLogs, ScopeLogs, SeverityNumber},
+    Metrics::{
+        exemplar, metric::Data, number_data_point, AggregationTemporality, Exemplar,
+        ExponentialHistogramDataPoint, Metric as OtelMetric, NumberDataPoint, ResourceMetrics,
+    },
     Resource as OtelResource,
+    Trace::{span, ResourceSpans, Span},
 };
 use bytes::Bytes;
 use chrono::{TimeZone, Utc};
+use lookup::path;
 use ordered_float::NotNan;
-use std::collections::BTreeMap;
+use prost::Message;
+use snafu::Snafu;
+use std::{collections::BTreeMap, num::NonZeroU32};
 use value::Value;
 use vector_core::{
-    config::log_schema,
-    event::{Event, LogEvent},
+    config::{log_schema, LogNamespace},
+    event::{
+        metric::{Bucket, MetricKind, MetricTags, MetricValue, Quantile},
+        Event, LogEvent, Metric, TraceEvent,
+    },
 };
 
+/// The source metadata of log events of the Vector namespace, holding the fields of their log record.
+const SOURCE_METADATA_KEY: &str = "opentelemetry";
 const RESOURCE_KEY: &str = "resources";
+const SCOPE_KEY: &str = "scope";
 const ATTRIBUTES_KEY: &str = "attributes";
 const TRACE_ID_KEY: &str = "trace_id";
 const SPAN_ID_KEY: &str = "span_id";
@@ -22,21 +40,118 @@ const SEVERITY_NUMBER_KEY: &str = "severity_number";
 const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
 const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
 const FLAGS_KEY: &str = "flags";
+const TRACE_STATE_KEY: &str = "trace_state";
+const PARENT_SPAN_ID_KEY: &str = "parent_span_id";
+const NAME_KEY: &str = "name";
+const KIND_KEY: &str = "kind";
+const START_TIME_KEY: &str = "start_time_unix_nano";
+const END_TIME_KEY: &str = "end_time_unix_nano";
+const EVENTS_KEY: &str = "events";
+const DROPPED_EVENTS_COUNT_KEY: &str = "dropped_events_count";
+const LINKS_KEY: &str = "links";
+const DROPPED_LINKS_COUNT_KEY: &str = "dropped_links_count";
+const STATUS_KEY: &str = "status";
+const METRIC_KEY: &str = "metric";
+const VALUE_KEY: &str = "value";
+const TAGS_KEY: &str = "tags";
+
+/// Errors that can occur while converting an OTLP log record into an event.
+#[derive(Debug, Snafu)]
+pub enum ConvertError {
+    #[snafu(display("Double values must not be NaN"))]
+    NanDoubleValue,
+    #[snafu(display("Data points of metric {:?} have no value", name))]
+    MissingMetricValue { name: String },
+}
+
+/// A log record that could not be converted into an event.
+#[derive(Debug)]
+pub struct RecordError {
+    pub record: LogRecord,
+    pub error: ConvertError,
+}
+
+impl ResourceLogs {
+    /// Removes the resource from the log records, and returns its attributes along with an ID derived
+    /// from them, so the resource can be emitted separately.
+    ///
+    /// Resources without attributes are dropped. Resources that cannot be converted are left in
+    /// place, so that the conversion of each log record reports the error.
+    pub fn take_resource(&mut self) -> Option<(String, Value)> {
+        let resource = self.resource.as_ref()?;
+        if validate_kv_list(&resource.attributes).is_err() {
+            return None;
+        }
+        let resource = self.resource.take()?;
+        if resource.attributes.is_empty() {
+            return None;
+        }
+        let id = format!("{:016x}", seahash::hash(&resource.encode_to_vec()));
+        Some((id, kv_list_into_value(resource.attributes)))
+    }
+
+    /// Gets a digest of the resource attributes, independent of their order.
+    ///
+    /// Returns `None` if the resource has no attributes.
+    pub fn resource_digest(&self) -> Option<String> {
+        let mut attributes = self
+            .resource
+            .as_ref()?
+            .attributes
+            .iter()
+            .collect::<Vec<_>>();
+        if attributes.is_empty() {
+            return None;
+        }
+        attributes.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let mut buf = Vec::new();
+        for attribute in attributes {
+            attribute
+                .encode_length_delimited(&mut buf)
+                .expect("encoding into a vector can't fail");
+        }
+        Some(format!("{:016x}", seahash::hash(&buf)))
+    }
+
+    /// Gets the value of a string, integer or boolean attribute of the resource, as a string.
+    pub fn resource_attribute(&self, key: &str) -> Option<String> {
+        let attribute = self
+            .resource
+            .as_ref()?
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == key)?;
+        match attribute.value.as_ref()?.value.as_ref()? {
+            PBValue::StringValue(value) => Some(value.clone()),
+            PBValue::IntValue(value) => Some(value.to_string()),
+            PBValue::BoolValue(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+}
 
 impl IntoIterator for ResourceLogs {
-    type Item = Event;
+    type Item = Result<Event, RecordError>;
     type IntoIter = std::vec::IntoIter<Self::Item>;
     fn into_iter(self) -> Self::IntoIter {
         let resource = self.resource;
         self.scope_logs
             .into_iter()
-            .flat_map(|scope_log| scope_log.log_records)
-            .map(|log_record| {
+            .flat_map(|scope_log| {
+                let scope = scope_into_value(scope_log.scope, scope_log.schema_url);
+                scope_log
+                    .log_records
+                    .into_iter()
+                    .map(move |log_record| (scope.clone(), log_record))
+            })
+            .map(|(scope, log_record)| {
                 ResourceLog {
                     resource: resource.clone(),
+                    scope,
                     log_record,
                 }
-                .into()
+                .try_into()
             })
             .collect::<Vec<Self::Item>>()
             .into_iter()
@@ -49,8 +164,10 @@ impl From<PBValue> for Value {
             PBValue::StringValue(v) => Value::Bytes(Bytes::from(v)),
             PBValue::BoolValue(v) => Value::Boolean(v),
             PBValue::IntValue(v) => Value::Integer(v),
-            PBValue::DoubleValue(v) => Value::Float(NotNan::new(v).unwrap()),
-            PBValue::BytesValue(v) => Value::Bytes(Bytes::from(v)),
+            // NaN doubles are rejected by `validate_value` before conversion.
+            PBValue::DoubleValue(v) => Value::Float(NotNan::new(v).unwrap_or_default()),
+            // Events hold strings as bytes, so raw bytes are base64 encoded to tell them apart.
+            PBValue::BytesValue(v) => Value::Bytes(Bytes::from(base64::encode(v))),
             PBValue::ArrayValue(arr) => Value::Array(
                 arr.values
                     .into_iter()
@@ -62,11 +179,56 @@ impl From<PBValue> for Value {
     }
 }
 
+/// Checks that a value can be represented as an event value.
+fn validate_value(av: &PBValue) -> Result<(), ConvertError> {
+    match av {
+        PBValue::DoubleValue(v) if v.is_nan() => Err(ConvertError::NanDoubleValue),
+        PBValue::ArrayValue(arr) => arr
+            .values
+            .iter()
+            .filter_map(|av| av.value.as_ref())
+            .try_for_each(validate_value),
+        PBValue::KvlistValue(arr) => validate_kv_list(&arr.values),
+        _ => Ok(()),
+    }
+}
+
+fn validate_kv_list(arr: &[KeyValue]) -> Result<(), ConvertError> {
+    arr.iter()
+        .filter_map(|kv| kv.value.as_ref().and_then(|av| av.value.as_ref()))
+        .try_for_each(validate_value)
+}
+
 struct ResourceLog {
     resource: Option<OtelResource>,
+    scope: Option<Value>,
     log_record: LogRecord,
 }
 
+/// Converts the instrumentation scope of a `ScopeLogs`, along with its schema URL, into an object
+/// holding whichever of its name, version, attributes and schema URL are set.
+fn scope_into_value(scope: Option<InstrumentationScope>, schema_url: String) -> Option<Value> {
+    let mut fields = BTreeMap::new();
+    if let Some(scope) = scope {
+        if !scope.name.is_empty() {
+            fields.insert("name".to_owned(), scope.name.into());
+        }
+        if !scope.version.is_empty() {
+            fields.insert("version".to_owned(), scope.version.into());
+        }
+        if !scope.attributes.is_empty() {
+            fields.insert(
+                ATTRIBUTES_KEY.to_owned(),
+                kv_list_into_value(scope.attributes),
+            );
+        }
+    }
+    if !schema_url.is_empty() {
+        fields.insert("schema_url".to_owned(), schema_url.into());
+    }
+    (!fields.is_empty()).then(|| Value::Object(fields))
+}
+
 fn kv_list_into_value(arr: Vec<KeyValue>) -> Value {
     Value::Object(
         arr.into_iter()
@@ -78,8 +240,35 @@ fn kv_list_into_value(arr: Vec<KeyValue>) -> Value {
     )
 }
 
-impl From<ResourceLog> for Event {
-    fn from(rl: ResourceLog) -> Self {
+impl ResourceLog {
+    fn validate(&self) -> Result<(), ConvertError> {
+        if let Some(resource) = &self.resource {
+            validate_kv_list(&resource.attributes)?;
+        }
+        validate_kv_list(&self.log_record.attributes)?;
+        if let Some(v) = self
+            .log_record
+            .body
+            .as_ref()
+            .and_then(|av| av.value.as_ref())
+        {
+            validate_value(v)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<ResourceLog> for Event {
+    type Error = RecordError;
+
+    fn try_from(rl: ResourceLog) -> Result<Self, Self::Error> {
+        if let Err(error) = rl.validate() {
+            return Err(RecordError {
+                record: rl.log_record,
+                error,
+            });
+        }
+
         let mut le = LogEvent::default();
 
         // optional fields
@@ -88,6 +277,9 @@ impl From<ResourceLog> for Event {
                 le.insert(RESOURCE_KEY, kv_list_into_value(resource.attributes));
             }
         }
+        if let Some(scope) = rl.scope {
+            le.insert(SCOPE_KEY, scope);
+        }
         if !rl.log_record.attributes.is_empty() {
             le.insert(ATTRIBUTES_KEY, kv_list_into_value(rl.log_record.attributes));
         }
@@ -139,6 +331,1022 @@ impl From<ResourceLog> for Event {
             rl.log_record.dropped_attributes_count,
         );
 
-        le.into()
+        Ok(le.into())
+    }
+}
+
+impl IntoIterator for ResourceSpans {
+    type Item = Result<Event, ConvertError>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let resource = self.resource;
+        self.scope_spans
+            .into_iter()
+            .flat_map(|scope_spans| {
+                let scope = scope_into_value(scope_spans.scope, scope_spans.schema_url);
+                scope_spans
+                    .spans
+                    .into_iter()
+                    .map(move |span| (scope.clone(), span))
+            })
+            .map(|(scope, span)| {
+                ResourceSpan {
+                    resource: resource.clone(),
+                    scope,
+                    span,
+                }
+                .try_into()
+            })
+            .collect::<Vec<Self::Item>>()
+            .into_iter()
+    }
+}
+
+struct ResourceSpan {
+    resource: Option<OtelResource>,
+    scope: Option<Value>,
+    span: Span,
+}
+
+impl ResourceSpan {
+    fn validate(&self) -> Result<(), ConvertError> {
+        if let Some(resource) = &self.resource {
+            validate_kv_list(&resource.attributes)?;
+        }
+        validate_kv_list(&self.span.attributes)?;
+        for event in &self.span.events {
+            validate_kv_list(&event.attributes)?;
+        }
+        for link in &self.span.links {
+            validate_kv_list(&link.attributes)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a span into a trace event, with the same conventions as the conversion of log records.
+///
+/// IDs are hex-encoded, times become timestamps, and the kind and status code keep their numeric
+/// values.
+impl TryFrom<ResourceSpan> for Event {
+    type Error = ConvertError;
+
+    fn try_from(rs: ResourceSpan) -> Result<Self, Self::Error> {
+        rs.validate()?;
+
+        let mut trace = LogEvent::default();
+        let span = rs.span;
+
+        if let Some(resource) = rs.resource {
+            if !resource.attributes.is_empty() {
+                trace.insert(RESOURCE_KEY, kv_list_into_value(resource.attributes));
+            }
+        }
+        if let Some(scope) = rs.scope {
+            trace.insert(SCOPE_KEY, scope);
+        }
+        if !span.attributes.is_empty() {
+            trace.insert(ATTRIBUTES_KEY, kv_list_into_value(span.attributes));
+        }
+        trace.insert(TRACE_ID_KEY, hex::encode(span.trace_id));
+        trace.insert(SPAN_ID_KEY, hex::encode(span.span_id));
+        if !span.parent_span_id.is_empty() {
+            trace.insert(PARENT_SPAN_ID_KEY, hex::encode(span.parent_span_id));
+        }
+        if !span.trace_state.is_empty() {
+            trace.insert(TRACE_STATE_KEY, span.trace_state);
+        }
+        trace.insert(NAME_KEY, span.name);
+        trace.insert(KIND_KEY, span.kind);
+        trace.insert(
+            START_TIME_KEY,
+            nanos_into_timestamp(span.start_time_unix_nano),
+        );
+        trace.insert(END_TIME_KEY, nanos_into_timestamp(span.end_time_unix_nano));
+        if let Some(status) = span.status {
+            let mut fields = BTreeMap::new();
+            fields.insert("code".to_owned(), status.code.into());
+            if !status.message.is_empty() {
+                fields.insert("message".to_owned(), status.message.into());
+            }
+            trace.insert(STATUS_KEY, Value::Object(fields));
+        }
+        if !span.events.is_empty() {
+            trace.insert(
+                EVENTS_KEY,
+                Value::Array(span.events.into_iter().map(span_event_into_value).collect()),
+            );
+        }
+        if !span.links.is_empty() {
+            trace.insert(
+                LINKS_KEY,
+                Value::Array(span.links.into_iter().map(span_link_into_value).collect()),
+            );
+        }
+        trace.insert(DROPPED_ATTRIBUTES_COUNT_KEY, span.dropped_attributes_count);
+        trace.insert(DROPPED_EVENTS_COUNT_KEY, span.dropped_events_count);
+        trace.insert(DROPPED_LINKS_COUNT_KEY, span.dropped_links_count);
+
+        Ok(Event::Trace(TraceEvent::from(trace)))
+    }
+}
+
+fn span_event_into_value(event: span::Event) -> Value {
+    let mut fields = BTreeMap::new();
+    fields.insert(NAME_KEY.to_owned(), event.name.into());
+    fields.insert(
+        log_schema().timestamp_key().to_owned(),
+        nanos_into_timestamp(event.time_unix_nano),
+    );
+    if !event.attributes.is_empty() {
+        fields.insert(
+            ATTRIBUTES_KEY.to_owned(),
+            kv_list_into_value(event.attributes),
+        );
+    }
+    fields.insert(
+        DROPPED_ATTRIBUTES_COUNT_KEY.to_owned(),
+        event.dropped_attributes_count.into(),
+    );
+    Value::Object(fields)
+}
+
+fn span_link_into_value(link: span::Link) -> Value {
+    let mut fields = BTreeMap::new();
+    fields.insert(TRACE_ID_KEY.to_owned(), hex::encode(link.trace_id).into());
+    fields.insert(SPAN_ID_KEY.to_owned(), hex::encode(link.span_id).into());
+    if !link.trace_state.is_empty() {
+        fields.insert(TRACE_STATE_KEY.to_owned(), link.trace_state.into());
+    }
+    if !link.attributes.is_empty() {
+        fields.insert(
+            ATTRIBUTES_KEY.to_owned(),
+            kv_list_into_value(link.attributes),
+        );
+    }
+    fields.insert(
+        DROPPED_ATTRIBUTES_COUNT_KEY.to_owned(),
+        link.dropped_attributes_count.into(),
+    );
+    Value::Object(fields)
+}
+
+impl IntoIterator for ResourceMetrics {
+    type Item = Result<Event, ConvertError>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    /// Converts each data point of the metrics into a metric event.
+    ///
+    /// The attributes of the resource, of the instrumentation scope and of the data point become the
+    /// tags of the metric event, in increasing order of precedence. The data point's `time_unix_nano`
+    /// becomes the timestamp, and for delta data points, the window since `start_time_unix_nano`
+    /// becomes the interval. Exemplars have no equivalent in metric events, and are converted
+    /// separately by [`ResourceMetrics::exemplars`].
+    fn into_iter(self) -> Self::IntoIter {
+        let resource_tags = self
+            .resource
+            .map(|resource| kv_list_into_tags(resource.attributes, MetricTags::new()))
+            .unwrap_or_default();
+        self.scope_metrics
+            .into_iter()
+            .flat_map(|scope_metrics| {
+                let scope_tags = match scope_metrics.scope {
+                    Some(scope) => kv_list_into_tags(scope.attributes, resource_tags.clone()),
+                    None => resource_tags.clone(),
+                };
+                scope_metrics
+                    .metrics
+                    .into_iter()
+                    .flat_map(move |metric| metric_into_events(metric, &scope_tags))
+            })
+            .collect::<Vec<Self::Item>>()
+            .into_iter()
+    }
+}
+
+impl ResourceMetrics {
+    /// Converts the exemplars of the data points of the metrics into log events.
+    ///
+    /// Each event holds the name of the metric, the value of the exemplar, its hex-encoded
+    /// `trace_id` and `span_id`, its filtered attributes, and the tags of the metric event of its
+    /// data point, so that the exemplar can be linked to both the trace and the metric.
+    pub fn exemplars(&self) -> Vec<LogEvent> {
+        let resource_tags = self
+            .resource
+            .as_ref()
+            .map(|resource| kv_list_into_tags(resource.attributes.clone(), MetricTags::new()))
+            .unwrap_or_default();
+        let mut events = Vec::new();
+        for scope_metrics in &self.scope_metrics {
+            let scope_tags = match &scope_metrics.scope {
+                Some(scope) => kv_list_into_tags(scope.attributes.clone(), resource_tags.clone()),
+                None => resource_tags.clone(),
+            };
+            for metric in &scope_metrics.metrics {
+                // Summaries have no exemplars.
+                let data_points: Vec<(&[KeyValue], &[Exemplar])> = match &metric.data {
+                    Some(Data::Gauge(gauge)) => gauge
+                        .data_points
+                        .iter()
+                        .map(|point| (&point.attributes[..], &point.exemplars[..]))
+                        .collect(),
+                    Some(Data::Sum(sum)) => sum
+                        .data_points
+                        .iter()
+                        .map(|point| (&point.attributes[..], &point.exemplars[..]))
+                        .collect(),
+                    Some(Data::Histogram(histogram)) => histogram
+                        .data_points
+                        .iter()
+                        .map(|point| (&point.attributes[..], &point.exemplars[..]))
+                        .collect(),
+                    Some(Data::ExponentialHistogram(histogram)) => histogram
+                        .data_points
+                        .iter()
+                        .map(|point| (&point.attributes[..], &point.exemplars[..]))
+                        .collect(),
+                    Some(Data::Summary(_)) | None => Vec::new(),
+                };
+                for (attributes, exemplars) in data_points {
+                    if exemplars.is_empty() {
+                        continue;
+                    }
+                    let tags = kv_list_into_tags(attributes.to_vec(), scope_tags.clone());
+                    events.extend(
+                        exemplars.iter().map(|exemplar| {
+                            exemplar_into_log(exemplar.clone(), &metric.name, &tags)
+                        }),
+                    );
+                }
+            }
+        }
+        events
+    }
+}
+
+fn exemplar_into_log(exemplar: Exemplar, name: &str, tags: &MetricTags) -> LogEvent {
+    let mut log = LogEvent::default();
+    log.insert(METRIC_KEY, name);
+    match exemplar.value {
+        Some(exemplar::Value::AsDouble(value)) => {
+            log.insert(VALUE_KEY, NotNan::new(value).unwrap_or_default());
+        }
+        Some(exemplar::Value::AsInt(value)) => {
+            log.insert(VALUE_KEY, value);
+        }
+        None => {}
+    }
+    if !exemplar.trace_id.is_empty() {
+        log.insert(TRACE_ID_KEY, hex::encode(exemplar.trace_id));
+    }
+    if !exemplar.span_id.is_empty() {
+        log.insert(SPAN_ID_KEY, hex::encode(exemplar.span_id));
+    }
+    if !exemplar.filtered_attributes.is_empty() {
+        log.insert(
+            ATTRIBUTES_KEY,
+            kv_list_into_value(exemplar.filtered_attributes),
+        );
+    }
+    if !tags.is_empty() {
+        log.insert(
+            TAGS_KEY,
+            Value::Object(
+                tags.iter()
+                    .map(|(key, value)| (key.clone(), Value::from(value.as_str())))
+                    .collect(),
+            ),
+        );
+    }
+    if exemplar.time_unix_nano != 0 {
+        log.insert(
+            log_schema().timestamp_key(),
+            nanos_into_timestamp(exemplar.time_unix_nano),
+        );
+    }
+    log
+}
+
+fn metric_into_events(
+    metric: OtelMetric,
+    scope_tags: &MetricTags,
+) -> Vec<Result<Event, ConvertError>> {
+    let name = metric.name;
+    let new_metric = |kind: MetricKind,
+                      value: MetricValue,
+                      attributes: Vec<KeyValue>,
+                      start_time_unix_nano: u64,
+                      time_unix_nano: u64| {
+        let interval_ms = (kind == MetricKind::Incremental && start_time_unix_nano != 0)
+            .then(|| time_unix_nano.saturating_sub(start_time_unix_nano) / 1_000_000)
+            .and_then(|ms| NonZeroU32::new(u32::try_from(ms).unwrap_or(u32::MAX)));
+        Event::Metric(
+            Metric::new(name.clone(), kind, value)
+                .with_tags(Some(kv_list_into_tags(attributes, scope_tags.clone())))
+                .with_timestamp(
+                    (time_unix_nano != 0).then(|| Utc.timestamp_nanos(time_unix_nano as i64)),
+                )
+                .with_interval_ms(interval_ms),
+        )
+    };
+    match metric.data {
+        Some(Data::Gauge(gauge)) => gauge
+            .data_points
+            .into_iter()
+            .map(|point| {
+                let value = number_point_value(&point, &name)?;
+                Ok(new_metric(
+                    MetricKind::Absolute,
+                    MetricValue::Gauge { value },
+                    point.attributes,
+                    point.start_time_unix_nano,
+                    point.time_unix_nano,
+                ))
+            })
+            .collect(),
+        // Monotonic sums are counters, and other sums gauges.
+        Some(Data::Sum(sum)) => {
+            let kind = temporality_into_kind(sum.aggregation_temporality);
+            sum.data_points
+                .into_iter()
+                .map(|point| {
+                    let value = number_point_value(&point, &name)?;
+                    let value = if sum.is_monotonic {
+                        MetricValue::Counter { value }
+                    } else {
+                        MetricValue::Gauge { value }
+                    };
+                    Ok(new_metric(
+                        kind,
+                        value,
+                        point.attributes,
+                        point.start_time_unix_nano,
+                        point.time_unix_nano,
+                    ))
+                })
+                .collect()
+        }
+        Some(Data::Histogram(histogram)) => {
+            let kind = temporality_into_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .map(|point| {
+                    // The last bucket is the overflow bucket, without an explicit bound.
+                    let buckets = point
+                        .bucket_counts
+                        .iter()
+                        .enumerate()
+                        .map(|(index, &count)| Bucket {
+                            upper_limit: point
+                                .explicit_bounds
+                                .get(index)
+                                .copied()
+                                .unwrap_or(f64::INFINITY),
+                            count,
+                        })
+                        .collect();
+                    let value = MetricValue::AggregatedHistogram {
+                        buckets,
+                        count: point.count,
+                        sum: point.sum,
+                    };
+                    Ok(new_metric(
+                        kind,
+                        value,
+                        point.attributes,
+                        point.start_time_unix_nano,
+                        point.time_unix_nano,
+                    ))
+                })
+                .collect()
+        }
+        Some(Data::ExponentialHistogram(histogram)) => {
+            let kind = temporality_into_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .map(|point| {
+                    let value = exponential_histogram_value(&point);
+                    Ok(new_metric(
+                        kind,
+                        value,
+                        point.attributes,
+                        point.start_time_unix_nano,
+                        point.time_unix_nano,
+                    ))
+                })
+                .collect()
+        }
+        Some(Data::Summary(summary)) => summary
+            .data_points
+            .into_iter()
+            .map(|point| {
+                let value = MetricValue::AggregatedSummary {
+                    quantiles: point
+                        .quantile_values
+                        .iter()
+                        .map(|quantile| Quantile {
+                            quantile: quantile.quantile,
+                            value: quantile.value,
+                        })
+                        .collect(),
+                    count: point.count,
+                    sum: point.sum,
+                };
+                Ok(new_metric(
+                    MetricKind::Absolute,
+                    value,
+                    point.attributes,
+                    point.start_time_unix_nano,
+                    point.time_unix_nano,
+                ))
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Delta data points are incremental, and cumulative ones absolute.
+const fn temporality_into_kind(temporality: i32) -> MetricKind {
+    if temporality == AggregationTemporality::Delta as i32 {
+        MetricKind::Incremental
+    } else {
+        MetricKind::Absolute
+    }
+}
+
+fn number_point_value(point: &NumberDataPoint, name: &str) -> Result<f64, ConvertError> {
+    match point.value {
+        Some(number_data_point::Value::AsDouble(value)) => Ok(value),
+        Some(number_data_point::Value::AsInt(value)) => Ok(value as f64),
+        None => Err(ConvertError::MissingMetricValue {
+            name: name.to_owned(),
+        }),
+    }
+}
+
+/// Converts the exponential buckets of a data point into buckets with explicit upper limits, in
+/// increasing order.
+///
+/// With a base of `2^(2^-scale)`, the positive bucket at index `i` holds the values in
+/// `(base^i, base^(i+1)]`, the negative bucket at index `i` those in `[-base^(i+1), -base^i)`, and
+/// the zero count becomes a bucket with an upper limit of zero.
+fn exponential_histogram_value(point: &ExponentialHistogramDataPoint) -> MetricValue {
+    let base = 2f64.powf(2f64.powi(-point.scale));
+    let mut buckets = Vec::new();
+    if let Some(negative) = &point.negative {
+        buckets.extend(
+            negative
+                .bucket_counts
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(index, &count)| Bucket {
+                    upper_limit: -base.powi(negative.offset + index as i32),
+                    count,
+                }),
+        );
+    }
+    buckets.push(Bucket {
+        upper_limit: 0.0,
+        count: point.zero_count,
+    });
+    if let Some(positive) = &point.positive {
+        buckets.extend(
+            positive
+                .bucket_counts
+                .iter()
+                .enumerate()
+                .map(|(index, &count)| Bucket {
+                    upper_limit: base.powi(positive.offset + index as i32 + 1),
+                    count,
+                }),
+        );
+    }
+    MetricValue::AggregatedHistogram {
+        buckets,
+        count: point.count,
+        sum: point.sum,
+    }
+}
+
+/// Inserts attributes into metric tags, with their values converted into strings.
+fn kv_list_into_tags(arr: Vec<KeyValue>, mut tags: MetricTags) -> MetricTags {
+    for kv in arr {
+        let value = match kv.value.and_then(|av| av.value) {
+            Some(PBValue::StringValue(value)) => value,
+            Some(PBValue::DoubleValue(value)) => value.to_string(),
+            Some(value) => Value::from(value).to_string_lossy(),
+            None => continue,
+        };
+        tags.insert(kv.key, value);
+    }
+    tags
+}
+
+fn nanos_into_timestamp(nanos: u64) -> Value {
+    Utc.timestamp_nanos(nanos as i64).into()
+}
+
+/// Converts an event value into an OTLP value. Nulls have no OTLP value.
+fn value_into_pb(value: Value) -> Option<PBValue> {
+    match value {
+        Value::Bytes(v) => Some(PBValue::StringValue(
+            String::from_utf8_lossy(&v).into_owned(),
+        )),
+        Value::Regex(v) => Some(PBValue::StringValue(v.as_str().to_owned())),
+        Value::Integer(v) => Some(PBValue::IntValue(v)),
+        Value::Float(v) => Some(PBValue::DoubleValue(v.into_inner())),
+        Value::Boolean(v) => Some(PBValue::BoolValue(v)),
+        Value::Timestamp(v) => Some(PBValue::StringValue(v.to_rfc3339())),
+        Value::Object(v) => Some(PBValue::KvlistValue(KeyValueList {
+            values: value_into_kv_list(Value::Object(v)),
+        })),
+        Value::Array(v) => Some(PBValue::ArrayValue(ArrayValue {
+            values: v
+                .into_iter()
+                .map(|value| AnyValue {
+                    value: value_into_pb(value),
+                })
+                .collect(),
+        })),
+        Value::Null => None,
+    }
+}
+
+/// Converts the fields of an object into key/value pairs. Other values have no fields.
+fn value_into_kv_list(value: Value) -> Vec<KeyValue> {
+    match value {
+        Value::Object(fields) => fields
+            .into_iter()
+            .map(|(key, value)| KeyValue {
+                key,
+                value: Some(AnyValue {
+                    value: value_into_pb(value),
+                }),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn timestamp_into_nanos(value: Option<Value>) -> u64 {
+    value
+        .as_ref()
+        .and_then(Value::as_timestamp)
+        .and_then(|timestamp| u64::try_from(timestamp.timestamp_nanos()).ok())
+        .unwrap_or_default()
+}
+
+fn hex_into_bytes(value: Option<Value>) -> Vec<u8> {
+    value
+        .as_ref()
+        .and_then(Value::as_str)
+        .and_then(|id| hex::decode(id.as_ref()).ok())
+        .unwrap_or_default()
+}
+
+fn integer_field<T: TryFrom<i64> + Default>(value: Option<Value>) -> T {
+    value
+        .as_ref()
+        .and_then(Value::as_integer)
+        .and_then(|v| T::try_from(v).ok())
+        .unwrap_or_default()
+}
+
+fn string_field(value: Option<Value>) -> String {
+    value
+        .as_ref()
+        .and_then(Value::as_str)
+        .map(|v| v.into_owned())
+        .unwrap_or_default()
+}
+
+/// Moves the fields of an event into the layout of the Legacy namespace, where the fields of the log record are at
+/// the root of the event and its body is the message.
+///
+/// Events of the Vector namespace are their body, and hold the other fields as the source metadata of the
+/// `opentelemetry` source. The metadata of other sources is not part of a log record, and is dropped.
+fn into_legacy_layout(log: LogEvent) -> LogEvent {
+    if log.namespace() == LogNamespace::Legacy {
+        return log;
+    }
+    let (body, mut metadata) = log.into_parts();
+    let fields = match metadata
+        .value_mut()
+        .remove(path!(SOURCE_METADATA_KEY), false)
+    {
+        Some(Value::Object(fields)) => fields,
+        _ => BTreeMap::new(),
+    };
+    let mut log = LogEvent::from(fields);
+    if body != Value::Null {
+        log.insert(log_schema().message_key(), body);
+    }
+    log
+}
+
+/// Converts an event back into a log record, using the field names of the conversion of log records into events.
+///
+/// Events of both namespaces are converted. The `resources` and `scope` fields are not part of a log record, and are
+/// ignored. See the conversion into an `ExportLogsServiceRequest` for them. Any other field is added to the
+/// attributes, unless an attribute of the same name exists.
+///
+/// Not every log record survives a round trip through an event:
+/// - `bytes` values become `string` values, as event values don't tell them apart.
+/// - Attributes are ordered by key, and only the last of duplicate keys is kept.
+/// - Attributes whose `KeyValue` has no value are dropped.
+/// - A missing `time_unix_nano` becomes the observed time, and a missing `observed_time_unix_nano` becomes the time
+///   the record was converted.
+impl From<LogEvent> for LogRecord {
+    fn from(log: LogEvent) -> Self {
+        let mut log = into_legacy_layout(log);
+        log.remove(RESOURCE_KEY);
+        log.remove(SCOPE_KEY);
+
+        let mut record = LogRecord {
+            time_unix_nano: timestamp_into_nanos(log.remove(log_schema().timestamp_key())),
+            observed_time_unix_nano: timestamp_into_nanos(log.remove(OBSERVED_TIMESTAMP_KEY)),
+            severity_number: integer_field(log.remove(SEVERITY_NUMBER_KEY)),
+            severity_text: string_field(log.remove(SEVERITY_TEXT_KEY)),
+            body: log.remove(log_schema().message_key()).map(|body| AnyValue {
+                value: value_into_pb(body),
+            }),
+            attributes: log
+                .remove(ATTRIBUTES_KEY)
+                .map(value_into_kv_list)
+                .unwrap_or_default(),
+            dropped_attributes_count: integer_field(log.remove(DROPPED_ATTRIBUTES_COUNT_KEY)),
+            flags: integer_field(log.remove(FLAGS_KEY)),
+            trace_id: hex_into_bytes(log.remove(TRACE_ID_KEY)),
+            span_id: hex_into_bytes(log.remove(SPAN_ID_KEY)),
+        };
+
+        let (fields, _) = log.into_parts();
+        for attribute in value_into_kv_list(fields) {
+            if !record
+                .attributes
+                .iter()
+                .any(|existing| existing.key == attribute.key)
+            {
+                record.attributes.push(attribute);
+            }
+        }
+        record
+    }
+}
+
+/// Converts an event of either namespace into an export request of a single log record, along with its resource and
+/// instrumentation scope.
+///
+/// On top of the fields lost by the conversion into a log record, the `schema_url` and
+/// `dropped_attributes_count` of the resource, and the `dropped_attributes_count` of the scope, are
+/// not part of events and are lost.
+impl From<LogEvent> for ExportLogsServiceRequest {
+    fn from(log: LogEvent) -> Self {
+        let mut log = into_legacy_layout(log);
+        let resource = log.remove(RESOURCE_KEY).map(|attributes| OtelResource {
+            attributes: value_into_kv_list(attributes),
+            dropped_attributes_count: 0,
+        });
+
+        let (scope, schema_url) = match log.remove(SCOPE_KEY) {
+            Some(Value::Object(mut fields)) => {
+                let name = string_field(fields.remove("name"));
+                let version = string_field(fields.remove("version"));
+                let attributes = fields
+                    .remove(ATTRIBUTES_KEY)
+                    .map(value_into_kv_list)
+                    .unwrap_or_default();
+                let scope = (!name.is_empty() || !version.is_empty() || !attributes.is_empty())
+                    .then(|| InstrumentationScope {
+                        name,
+                        version,
+                        attributes,
+                        dropped_attributes_count: 0,
+                    });
+                (scope, string_field(fields.remove("schema_url")))
+            }
+            _ => (None, String::new()),
+        };
+
+        ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource,
+                scope_logs: vec![ScopeLogs {
+                    scope,
+                    log_records: vec![log.into()],
+                    schema_url,
+                }],
+                schema_url: String::new(),
+            }],
+        }
+    }
+}
+
+/// Converts events into a single export request, grouping their log records by resource and instrumentation scope.
+impl FromIterator<LogEvent> for ExportLogsServiceRequest {
+    fn from_iter<I: IntoIterator<Item = LogEvent>>(logs: I) -> Self {
+        let mut request = Self::default();
+        for log in logs {
+            request.merge(log.into());
+        }
+        request
+    }
+}
+
+impl ExportLogsServiceRequest {
+    /// Moves the log records of another request into this one, next to the records of the same resource and
+    /// instrumentation scope.
+    pub fn merge(&mut self, other: Self) {
+        for resource_logs in other.resource_logs {
+            let existing = self.resource_logs.iter_mut().find(|existing| {
+                existing.resource == resource_logs.resource
+                    && existing.schema_url == resource_logs.schema_url
+            });
+            let existing = match existing {
+                Some(existing) => existing,
+                None => {
+                    self.resource_logs.push(resource_logs);
+                    continue;
+                }
+            };
+            for scope_logs in resource_logs.scope_logs {
+                match existing.scope_logs.iter_mut().find(|existing| {
+                    existing.scope == scope_logs.scope
+                        && existing.schema_url == scope_logs.schema_url
+                }) {
+                    Some(existing) => existing.log_records.extend(scope_logs.log_records),
+                    None => existing.scope_logs.push(scope_logs),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_common::btreemap;
+
+    use super::*;
+
+    fn string_value(value: &str) -> Option<AnyValue> {
+        Some(AnyValue {
+            value: Some(PBValue::StringValue(value.into())),
+        })
+    }
+
+    fn into_log(resource_logs: ResourceLogs) -> LogEvent {
+        let mut events = resource_logs.into_iter().collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+        events.pop().unwrap().unwrap().into_log()
+    }
+
+    #[test]
+    fn log_record_round_trip() {
+        let request = ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: Some(OtelResource {
+                    attributes: vec![KeyValue {
+                        key: "service.name".into(),
+                        value: string_value("checkout"),
+                    }],
+                    dropped_attributes_count: 0,
+                }),
+                scope_logs: vec![ScopeLogs {
+                    scope: Some(InstrumentationScope {
+                        name: "io.opentelemetry.example".into(),
+                        version: "1.2.3".into(),
+                        attributes: vec![KeyValue {
+                            key: "library.language".into(),
+                            value: string_value("java"),
+                        }],
+                        dropped_attributes_count: 0,
+                    }),
+                    log_records: vec![LogRecord {
+                        time_unix_nano: 1_579_134_612_000_000_011,
+                        observed_time_unix_nano: 1_579_134_612_000_000_012,
+                        severity_number: SeverityNumber::Warn as i32,
+                        severity_text: "WARN".into(),
+                        body: Some(AnyValue {
+                            value: Some(PBValue::KvlistValue(KeyValueList {
+                                values: vec![
+                                    KeyValue {
+                                        key: "array".into(),
+                                        value: Some(AnyValue {
+                                            value: Some(PBValue::ArrayValue(ArrayValue {
+                                                values: vec![
+                                                    AnyValue {
+                                                        value: Some(PBValue::BoolValue(true)),
+                                                    },
+                                                    AnyValue { value: None },
+                                                ],
+                                            })),
+                                        }),
+                                    },
+                                    KeyValue {
+                                        key: "double".into(),
+                                        value: Some(AnyValue {
+                                            value: Some(PBValue::DoubleValue(1.5)),
+                                        }),
+                                    },
+                                ],
+                            })),
+                        }),
+                        // Attributes are ordered by key once converted.
+                        attributes: vec![
+                            KeyValue {
+                                key: "a".into(),
+                                value: Some(AnyValue {
+                                    value: Some(PBValue::IntValue(42)),
+                                }),
+                            },
+                            KeyValue {
+                                key: "b".into(),
+                                value: string_value("value"),
+                            },
+                        ],
+                        dropped_attributes_count: 3,
+                        flags: 1,
+                        trace_id: hex::decode("4ac52aadf321c2e531db005df08792f5").unwrap(),
+                        span_id: hex::decode("0b9e4bda2a55530d").unwrap(),
+                    }],
+                    schema_url: "https://opentelemetry.io/schemas/1.9.0".into(),
+                }],
+                schema_url: String::new(),
+            }],
+        };
+
+        let log = into_log(request.resource_logs[0].clone());
+        assert_eq!(ExportLogsServiceRequest::from(log), request);
+    }
+
+    #[test]
+    fn unknown_fields_become_attributes() {
+        let mut log = LogEvent::from("body");
+        log.insert("attributes.user", "ferris");
+        log.insert("user", "frank");
+        log.insert("host", "example.com");
+
+        let record = LogRecord::from(log);
+        assert_eq!(record.body, string_value("body"));
+        // Existing attributes take precedence over fields of the same name.
+        assert_eq!(
+            record.attributes,
+            vec![
+                KeyValue {
+                    key: "user".into(),
+                    value: string_value("ferris"),
+                },
+                KeyValue {
+                    key: "host".into(),
+                    value: string_value("example.com"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bytes_values_become_base64_strings() {
+        let record = LogRecord {
+            time_unix_nano: 1,
+            observed_time_unix_nano: 2,
+            body: Some(AnyValue {
+                value: Some(PBValue::BytesValue(b"body".to_vec())),
+            }),
+            ..Default::default()
+        };
+        let log = into_log(ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![record.clone()],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        });
+
+        assert_eq!(
+            LogRecord::from(log),
+            LogRecord {
+                body: string_value("Ym9keQ=="),
+                ..record
+            }
+        );
+    }
+
+    #[test]
+    fn nested_kvlist_body_becomes_object() {
+        let log = into_log(ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord {
+                    body: Some(AnyValue {
+                        value: Some(PBValue::KvlistValue(KeyValueList {
+                            values: vec![
+                                KeyValue {
+                                    key: "user".into(),
+                                    value: Some(AnyValue {
+                                        value: Some(PBValue::KvlistValue(KeyValueList {
+                                            values: vec![
+                                                KeyValue {
+                                                    key: "id".into(),
+                                                    value: Some(AnyValue {
+                                                        value: Some(PBValue::IntValue(42)),
+                                                    }),
+                                                },
+                                                KeyValue {
+                                                    key: "admin".into(),
+                                                    value: Some(AnyValue {
+                                                        value: Some(PBValue::BoolValue(false)),
+                                                    }),
+                                                },
+                                            ],
+                                        })),
+                                    }),
+                                },
+                                KeyValue {
+                                    key: "ratio".into(),
+                                    value: Some(AnyValue {
+                                        value: Some(PBValue::DoubleValue(0.5)),
+                                    }),
+                                },
+                                KeyValue {
+                                    key: "token".into(),
+                                    value: Some(AnyValue {
+                                        value: Some(PBValue::BytesValue(b"hi".to_vec())),
+                                    }),
+                                },
+                            ],
+                        })),
+                    }),
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        });
+
+        let user: BTreeMap<String, Value> = btreemap! {
+            "id" => 42,
+            "admin" => false,
+        };
+        let body: BTreeMap<String, Value> = btreemap! {
+            "user" => user,
+            "ratio" => 0.5,
+            "token" => "aGk=",
+        };
+        assert_eq!(
+            log.get(log_schema().message_key()),
+            Some(&Value::from(body))
+        );
+    }
+
+    #[test]
+    fn array_attribute_becomes_array() {
+        let log = into_log(ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord {
+                    attributes: vec![KeyValue {
+                        key: "tags".into(),
+                        value: Some(AnyValue {
+                            value: Some(PBValue::ArrayValue(ArrayValue {
+                                values: vec![
+                                    string_value("a").unwrap(),
+                                    AnyValue {
+                                        value: Some(PBValue::IntValue(1)),
+                                    },
+                                    AnyValue {
+                                        value: Some(PBValue::ArrayValue(ArrayValue {
+                                            values: vec![AnyValue {
+                                                value: Some(PBValue::BoolValue(true)),
+                                            }],
+                                        })),
+                                    },
+                                    AnyValue { value: None },
+                                ],
+                            })),
+                        }),
+                    }],
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        });
+
+        assert_eq!(
+            log.get("attributes.tags"),
+            Some(&Value::Array(vec![
+                "a".into(),
+                1.into(),
+                Value::Array(vec![true.into()]),
+                Value::Null,
+            ]))
+        );
     }
 }
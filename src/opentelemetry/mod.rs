@@ -1,9 +1,13 @@
 #![allow(clippy::clone_on_ref_ptr)]
 
 pub use proto::collector::logs::v1 as LogService;
+pub use proto::collector::metrics::v1 as MetricsService;
+pub use proto::collector::trace::v1 as TraceService;
 pub use proto::common::v1 as Common;
 pub use proto::logs::v1 as Logs;
+pub use proto::metrics::v1 as Metrics;
 pub use proto::resource::v1::Resource;
+pub use proto::trace::v1 as Trace;
 
 pub mod convert;
 pub mod proto;
@@ -0,0 +1,80 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+use vector_config::{configurable_component, ConfigurableString};
+
+/// The text standing in for the secret of a `SensitiveString` in its output.
+const REDACTED: &str = "**REDACTED**";
+
+/// A string holding a secret, such as a token or a key.
+///
+/// The secret is redacted when formatted, including with `Debug`, and when serialized, so that it doesn't leak into
+/// logs or printed configurations.
+#[configurable_component(no_ser)]
+#[derive(Clone, Default, Eq, PartialEq)]
+#[serde(from = "String")]
+pub struct SensitiveString(String);
+
+impl SensitiveString {
+    /// Gets the secret.
+    pub fn inner(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl From<String> for SensitiveString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SensitiveString {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl fmt::Debug for SensitiveString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for SensitiveString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl Serialize for SensitiveString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED)
+    }
+}
+
+// This is safe because we literally defer to `String` for the schema of `SensitiveString`.
+impl ConfigurableString for SensitiveString {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_secret() {
+        let secret = SensitiveString::from("hunter2");
+
+        assert_eq!(secret.inner(), "hunter2");
+        assert_eq!(format!("{:?}", secret), REDACTED);
+        assert_eq!(secret.to_string(), REDACTED);
+        assert_eq!(
+            serde_json::to_string(&secret).unwrap(),
+            format!("{:?}", REDACTED)
+        );
+    }
+
+    #[test]
+    fn deserializes_from_string() {
+        let secret: SensitiveString = serde_json::from_str(r#""hunter2""#).unwrap();
+        assert_eq!(secret.inner(), "hunter2");
+    }
+}
@@ -1,3 +1,4 @@
+use once_cell::sync::Lazy;
 use tokio::sync::broadcast;
 use tokio_stream::{Stream, StreamExt};
 
@@ -7,6 +8,37 @@ pub type ShutdownTx = broadcast::Sender<()>;
 pub type SignalTx = broadcast::Sender<SignalTo>;
 pub type SignalRx = broadcast::Receiver<SignalTo>;
 
+/// The requests of components to shut Vector down.
+static SHUTDOWN_REQUESTS: Lazy<broadcast::Sender<ShutdownRequest>> =
+    Lazy::new(|| broadcast::channel(1).0);
+
+/// A request of a component to shut Vector down.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShutdownRequest {
+    /// The component has completed its work, so Vector exits successfully.
+    Completed,
+    /// The component has detected a failure, so Vector exits with a failure code.
+    Failed,
+}
+
+/// Requests Vector to shut down gracefully, as when receiving `SIGTERM`, such as once a component
+/// has completed its work.
+pub fn request_shutdown() {
+    // There are no receivers unless Vector is running, in which case there's nothing to shut down.
+    let _ = SHUTDOWN_REQUESTS.send(ShutdownRequest::Completed);
+}
+
+/// Requests Vector to shut down gracefully and exit with a failure code, such as once a component
+/// has detected a failure.
+pub fn request_failure_shutdown() {
+    let _ = SHUTDOWN_REQUESTS.send(ShutdownRequest::Failed);
+}
+
+/// Subscribes to the shutdown requests of components.
+pub fn shutdown_requests() -> broadcast::Receiver<ShutdownRequest> {
+    SHUTDOWN_REQUESTS.subscribe()
+}
+
 #[derive(Debug, Clone)]
 /// Control messages used by Vector to drive topology and shutdown events.
 #[allow(clippy::large_enum_variant)] // discovered during Rust upgrade to 1.57; just allowing for now since we did previously
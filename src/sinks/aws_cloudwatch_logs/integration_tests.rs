@@ -15,14 +15,14 @@ use pretty_assertions::assert_eq;
 use super::*;
 use crate::aws::create_client;
 use crate::aws::{AwsAuthentication, RegionOrEndpoint};
-use crate::sinks::aws_cloudwatch_logs::config::CloudwatchLogsClientBuilder;
+use crate::common::cloudwatch_logs::CloudwatchLogsClientBuilder;
 use crate::{
     config::{log_schema, ProxyConfig, SinkConfig, SinkContext},
     event::{Event, LogEvent, Value},
     sinks::util::BatchConfig,
     template::Template,
     test_util::{
-        components::{run_and_assert_sink_compliance, AWS_SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, AWS_SINK_TAGS},
         random_lines, random_lines_with_stream, random_string, trace_init,
     },
 };
@@ -61,7 +61,7 @@ async fn cloudwatch_insert_log_event() {
     let timestamp = chrono::Utc::now();
 
     let (input_lines, events) = random_lines_with_stream(100, 11, None);
-    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
 
     let response = create_client_test()
         .await
@@ -127,7 +127,7 @@ async fn cloudwatch_insert_log_events_sorted() {
 
         events
     });
-    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
 
     let response = create_client_test()
         .await
@@ -203,7 +203,7 @@ async fn cloudwatch_insert_out_of_range_timestamp() {
     lines.push(add_event(Duration::days(-1)));
     lines.push(add_event(Duration::days(-13)));
 
-    run_and_assert_sink_compliance(sink, stream::iter(events), &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &AWS_SINK_TAGS).await;
 
     let response = create_client_test()
         .await
@@ -253,7 +253,7 @@ async fn cloudwatch_dynamic_group_and_stream_creation() {
     let timestamp = chrono::Utc::now();
 
     let (input_lines, events) = random_lines_with_stream(100, 11, None);
-    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
 
     let response = create_client_test()
         .await
@@ -308,7 +308,7 @@ async fn cloudwatch_insert_log_event_batched() {
     let timestamp = chrono::Utc::now();
 
     let (input_lines, events) = random_lines_with_stream(100, 11, None);
-    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
 
     let response = create_client_test()
         .await
@@ -370,7 +370,7 @@ async fn cloudwatch_insert_log_event_partitioned() {
             Event::Log(event)
         })
         .collect::<Vec<_>>();
-    run_and_assert_sink_compliance(sink, stream::iter(events), &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &AWS_SINK_TAGS).await;
 
     let response = create_client_test()
         .await
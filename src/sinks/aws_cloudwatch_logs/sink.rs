@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{Duration, Utc};
 use futures::{future, stream::BoxStream, StreamExt};
 use tower::Service;
+use vector_buffers::EventCount;
 use vector_core::{
     partition::Partitioner,
     sink::StreamSink,
@@ -68,6 +69,12 @@ impl Finalizable for BatchCloudwatchRequest {
     }
 }
 
+impl EventCount for BatchCloudwatchRequest {
+    fn event_count(&self) -> usize {
+        self.events.len()
+    }
+}
+
 struct CloudwatchParititoner;
 
 impl Partitioner for CloudwatchParititoner {
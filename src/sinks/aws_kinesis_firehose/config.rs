@@ -1,6 +1,6 @@
-use aws_sdk_firehose::error::{
-    DescribeDeliveryStreamError, PutRecordBatchError, PutRecordBatchErrorKind,
-};
+use std::num::NonZeroUsize;
+
+use aws_sdk_firehose::error::{DescribeDeliveryStreamError, PutRecordBatchErrorKind};
 use aws_sdk_firehose::types::SdkError;
 use aws_sdk_firehose::Client as KinesisFirehoseClient;
 use futures::FutureExt;
@@ -18,7 +18,7 @@ use crate::{
     sinks::{
         aws_kinesis_firehose::{
             request_builder::KinesisRequestBuilder,
-            service::{KinesisResponse, KinesisService},
+            service::{KinesisError, KinesisResponse, KinesisService},
             sink::KinesisSink,
         },
         util::{
@@ -27,6 +27,7 @@ use crate::{
         },
         Healthcheck, VectorSink,
     },
+    template::Template,
     tls::TlsConfig,
 };
 
@@ -34,6 +35,12 @@ use crate::{
 // https://docs.aws.amazon.com/firehose/latest/dev/limits.html
 pub const MAX_PAYLOAD_SIZE: usize = 1024 * 1024 * 4;
 pub const MAX_PAYLOAD_EVENTS: usize = 500;
+// A single record is limited to 1,000 KiB before base64 encoding
+pub const MAX_RECORD_SIZE: usize = 1000 * 1024;
+
+const fn default_max_open_streams() -> usize {
+    100
+}
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct KinesisFirehoseDefaultBatchSettings;
@@ -51,8 +58,25 @@ impl SinkBatchSettings for KinesisFirehoseDefaultBatchSettings {
 pub struct KinesisFirehoseSinkConfig {
     /// The [stream name][stream_name] of the target Kinesis Firehose delivery stream.
     ///
+    /// This is a template, so events can be routed to a delivery stream each. Events are batched
+    /// separately for every rendered stream name.
+    ///
     /// [stream_name]: https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html
-    pub stream_name: String,
+    pub stream_name: Template,
+
+    /// The maximum number of delivery streams batched at once.
+    ///
+    /// When an event for another delivery stream arrives while this many batches are open, the
+    /// largest open batch is sent early.
+    #[serde(default = "default_max_open_streams")]
+    pub max_open_streams: usize,
+
+    /// Whether or not to join encoded events, separated by newlines, into aggregated records.
+    ///
+    /// Records are filled up to the Firehose limit of 1,000 KiB, reducing the number of records
+    /// billed. Aggregation can't be combined with `compression`.
+    #[serde(default)]
+    pub aggregate_records: bool,
 
     #[serde(flatten)]
     pub region: RegionOrEndpoint,
@@ -100,6 +124,10 @@ pub enum BuildError {
         MAX_PAYLOAD_EVENTS
     ))]
     BatchMaxEvents,
+    #[snafu(display("max_open_streams must be greater than zero"))]
+    MaxOpenStreamsZero,
+    #[snafu(display("aggregate_records can't be combined with compression"))]
+    AggregateWithCompression,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -144,6 +172,12 @@ impl ClientBuilder for KinesisFirehoseClientBuilder {
 #[typetag::serde(name = "aws_kinesis_firehose")]
 impl SinkConfig for KinesisFirehoseSinkConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let max_open_streams =
+            NonZeroUsize::new(self.max_open_streams).ok_or(BuildError::MaxOpenStreamsZero)?;
+        if self.aggregate_records && self.compression != Compression::None {
+            return Err(BuildError::AggregateWithCompression.into());
+        }
+
         let client = self.create_client(&cx.proxy).await?;
         let healthcheck = self.clone().healthcheck(client.clone()).boxed();
 
@@ -156,14 +190,9 @@ impl SinkConfig for KinesisFirehoseSinkConfig {
 
         let request_limits = self.request.unwrap_with(&TowerRequestConfig::default());
 
-        let region = self.region.region();
         let service = ServiceBuilder::new()
             .settings(request_limits, KinesisRetryLogic)
-            .service(KinesisService {
-                client,
-                region,
-                stream_name: self.stream_name.clone(),
-            });
+            .service(KinesisService::new(client));
 
         let transformer = self.encoding.transformer();
         let serializer = self.encoding.build()?;
@@ -176,7 +205,9 @@ impl SinkConfig for KinesisFirehoseSinkConfig {
 
         let sink = KinesisSink {
             batch_settings,
-
+            max_open_streams,
+            aggregate_records: self.aggregate_records,
+            stream_name: self.stream_name.clone(),
             service,
             request_builder,
         };
@@ -198,7 +229,11 @@ impl SinkConfig for KinesisFirehoseSinkConfig {
 
 impl KinesisFirehoseSinkConfig {
     async fn healthcheck(self, client: KinesisFirehoseClient) -> crate::Result<()> {
-        let stream_name = self.stream_name;
+        if self.stream_name.is_dynamic() {
+            info!("Skipping healthcheck delivery stream check: `stream_name` is dynamic.");
+            return Ok(());
+        }
+        let stream_name = String::from(self.stream_name);
 
         let result = client
             .describe_delivery_stream()
@@ -241,15 +276,23 @@ impl KinesisFirehoseSinkConfig {
 pub struct KinesisRetryLogic;
 
 impl RetryLogic for KinesisRetryLogic {
-    type Error = SdkError<PutRecordBatchError>;
+    type Error = KinesisError;
     type Response = KinesisResponse;
 
     fn is_retriable_error(&self, error: &Self::Error) -> bool {
-        if let SdkError::ServiceError { err, raw: _ } = error {
-            if let PutRecordBatchErrorKind::ServiceUnavailableException(_) = err.kind {
-                return true;
+        match error {
+            KinesisError::PutRecordBatch { source } => {
+                if let SdkError::ServiceError { err, raw: _ } = source {
+                    if let PutRecordBatchErrorKind::ServiceUnavailableException(_) = err.kind {
+                        return true;
+                    }
+                }
+                is_retriable_error(source)
             }
+            // Retrying the whole batch would duplicate the records already accepted.
+            KinesisError::PartialPutRecordBatch { .. } => false,
+            // The failed records were already retried by the service.
+            KinesisError::FailedRecords { .. } => false,
         }
-        is_retriable_error(error)
     }
 }
@@ -20,8 +20,9 @@ use crate::{
         elasticsearch::{ElasticsearchAuth, ElasticsearchCommon, ElasticsearchConfig},
         util::{BatchConfig, Compression, TowerRequestConfig},
     },
+    template::Template,
     test_util::{
-        components::{run_and_assert_sink_compliance, AWS_SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, AWS_SINK_TAGS},
         random_events_with_stream, random_string, wait_for_duration,
     },
 };
@@ -48,7 +49,9 @@ async fn firehose_put_records() {
     let region = RegionOrEndpoint::with_both("localstack", kinesis_address().as_str());
 
     let config = KinesisFirehoseSinkConfig {
-        stream_name: stream.clone(),
+        stream_name: Template::try_from(stream.clone()).unwrap(),
+        max_open_streams: 100,
+        aggregate_records: false,
         region: region.clone(),
         encoding: JsonSerializerConfig::new().into(), // required for ES destination w/ localstack
         compression: Compression::None,
@@ -69,7 +72,7 @@ async fn firehose_put_records() {
 
     let (input, events) = random_events_with_stream(100, 100, None);
 
-    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
 
     sleep(Duration::from_secs(5)).await;
 
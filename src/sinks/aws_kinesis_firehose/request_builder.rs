@@ -1,14 +1,18 @@
-use std::io;
+use std::{io, mem};
 
 use aws_sdk_firehose::model::Record;
 use aws_sdk_firehose::types::Blob;
 use bytes::Bytes;
+use vector_buffers::EventCount;
 use vector_core::ByteSizeOf;
 
 use crate::{
     codecs::{Encoder, Transformer},
-    event::{Event, EventFinalizers, Finalizable, LogEvent},
-    sinks::util::{request_builder::EncodeResult, Compression, RequestBuilder},
+    event::{Event, EventFinalizers, Finalizable},
+    sinks::{
+        aws_kinesis_firehose::{config::MAX_RECORD_SIZE, sink::KinesisProcessedEvent},
+        util::{request_builder::EncodeResult, Compression, RequestBuilder},
+    },
 };
 
 pub struct KinesisRequestBuilder {
@@ -18,12 +22,14 @@ pub struct KinesisRequestBuilder {
 
 pub struct Metadata {
     pub finalizers: EventFinalizers,
+    pub stream_name: String,
     pub event_byte_size: usize,
 }
 
 #[derive(Clone)]
 pub struct KinesisRequest {
     pub record: Record,
+    pub stream_name: String,
     pub finalizers: EventFinalizers,
     pub event_byte_size: usize,
 }
@@ -60,7 +66,77 @@ impl ByteSizeOf for KinesisRequest {
     }
 }
 
-impl RequestBuilder<LogEvent> for KinesisRequestBuilder {
+/// The records sent to a single delivery stream by one `PutRecordBatch` call.
+pub struct KinesisBatch {
+    pub stream_name: String,
+    pub records: Vec<Record>,
+    pub finalizers: EventFinalizers,
+    pub event_byte_size: usize,
+    pub event_count: usize,
+}
+
+impl Finalizable for KinesisBatch {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        std::mem::take(&mut self.finalizers)
+    }
+}
+
+impl EventCount for KinesisBatch {
+    fn event_count(&self) -> usize {
+        self.event_count
+    }
+}
+
+impl KinesisBatch {
+    /// Batches the `requests` for `stream_name`. With `aggregate`, their records are joined with
+    /// newlines into records of up to `MAX_RECORD_SIZE` bytes.
+    pub fn new(stream_name: String, requests: Vec<KinesisRequest>, aggregate: bool) -> Self {
+        let event_count = requests.len();
+        let event_byte_size = requests.iter().map(|req| req.event_byte_size).sum();
+
+        let mut finalizers = EventFinalizers::default();
+        let mut records = Vec::new();
+        let mut aggregated = Vec::new();
+        for mut request in requests {
+            finalizers.merge(request.take_finalizers());
+            if !aggregate {
+                records.push(request.record);
+                continue;
+            }
+
+            let data = request
+                .record
+                .data
+                .as_ref()
+                .map(|x| x.as_ref())
+                .unwrap_or_default();
+            if !aggregated.is_empty() && aggregated.len() + 1 + data.len() > MAX_RECORD_SIZE {
+                records.push(aggregated_record(mem::take(&mut aggregated)));
+            }
+            if !aggregated.is_empty() {
+                aggregated.push(b'\n');
+            }
+            aggregated.extend_from_slice(data);
+        }
+        if !aggregated.is_empty() {
+            records.push(aggregated_record(aggregated));
+        }
+
+        Self {
+            stream_name,
+            records,
+            finalizers,
+            event_byte_size,
+            event_count,
+        }
+    }
+}
+
+fn aggregated_record(data: Vec<u8>) -> Record {
+    Record::builder().data(Blob::new(data)).build()
+}
+
+impl RequestBuilder<KinesisProcessedEvent> for KinesisRequestBuilder {
     type Metadata = Metadata;
     type Events = Event;
     type Encoder = (Transformer, Encoder<()>);
@@ -76,12 +152,13 @@ impl RequestBuilder<LogEvent> for KinesisRequestBuilder {
         &self.encoder
     }
 
-    fn split_input(&self, mut event: LogEvent) -> (Self::Metadata, Self::Events) {
+    fn split_input(&self, mut event: KinesisProcessedEvent) -> (Self::Metadata, Self::Events) {
         let metadata = Metadata {
-            finalizers: event.take_finalizers(),
-            event_byte_size: event.size_of(),
+            finalizers: event.event.take_finalizers(),
+            stream_name: event.metadata.stream_name,
+            event_byte_size: event.event.size_of(),
         };
-        (metadata, Event::from(event))
+        (metadata, Event::from(event.event))
     }
 
     fn build_request(
@@ -92,8 +169,54 @@ impl RequestBuilder<LogEvent> for KinesisRequestBuilder {
         let payload = payload.into_payload();
         KinesisRequest {
             record: Record::builder().data(Blob::new(&payload[..])).build(),
+            stream_name: metadata.stream_name,
             finalizers: metadata.finalizers,
             event_byte_size: metadata.event_byte_size,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(data: &str) -> KinesisRequest {
+        KinesisRequest {
+            record: Record::builder().data(Blob::new(data)).build(),
+            stream_name: "stream".into(),
+            finalizers: Default::default(),
+            event_byte_size: data.len(),
+        }
+    }
+
+    fn record_data(batch: &KinesisBatch) -> Vec<&[u8]> {
+        batch
+            .records
+            .iter()
+            .map(|record| record.data.as_ref().unwrap().as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn batch_keeps_records_without_aggregation() {
+        let batch = KinesisBatch::new("stream".into(), vec![request("a"), request("bc")], false);
+
+        assert_eq!(record_data(&batch), vec![&b"a"[..], &b"bc"[..]]);
+        assert_eq!(batch.event_count, 2);
+        assert_eq!(batch.event_byte_size, 3);
+    }
+
+    #[test]
+    fn batch_aggregates_records_up_to_record_limit() {
+        let large = "x".repeat(MAX_RECORD_SIZE - 2);
+        let batch = KinesisBatch::new(
+            "stream".into(),
+            vec![request("a"), request(&large), request("b"), request("c")],
+            true,
+        );
+
+        let expected = format!("a\n{}", large);
+        assert_eq!(record_data(&batch), vec![expected.as_bytes(), &b"b\nc"[..]]);
+        assert_eq!(batch.event_count, 4);
+    }
+}
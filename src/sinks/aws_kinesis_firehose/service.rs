@@ -1,20 +1,81 @@
-use std::task::{Context, Poll};
+use std::{
+    task::{Context, Poll},
+    time::Duration,
+};
 
+use async_trait::async_trait;
 use aws_sdk_firehose::error::PutRecordBatchError;
+use aws_sdk_firehose::model::Record;
+use aws_sdk_firehose::output::PutRecordBatchOutput;
 use aws_sdk_firehose::types::SdkError;
-use aws_sdk_firehose::{Client as KinesisFirehoseClient, Region};
+use aws_sdk_firehose::Client as KinesisFirehoseClient;
 use futures::future::BoxFuture;
 use hyper::service::Service;
+use snafu::Snafu;
 use tracing::Instrument;
 use vector_core::{internal_event::EventsSent, stream::DriverResponse};
 
-use crate::{event::EventStatus, sinks::aws_kinesis_firehose::request_builder::KinesisRequest};
+use crate::{event::EventStatus, sinks::aws_kinesis_firehose::request_builder::KinesisBatch};
+
+// Records rejected by a partially failed `PutRecordBatch` call are sent again
+// up to this many times in total, backing off exponentially in between.
+const MAX_PARTIAL_FAILURE_ATTEMPTS: usize = 5;
+const PARTIAL_FAILURE_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Sends records to a delivery stream with `PutRecordBatch`.
+#[async_trait]
+pub trait PutRecordBatchClient: Clone + Send + Sync + 'static {
+    async fn put_records(
+        &self,
+        stream_name: String,
+        records: Vec<Record>,
+    ) -> Result<PutRecordBatchOutput, SdkError<PutRecordBatchError>>;
+}
+
+#[async_trait]
+impl PutRecordBatchClient for KinesisFirehoseClient {
+    async fn put_records(
+        &self,
+        stream_name: String,
+        records: Vec<Record>,
+    ) -> Result<PutRecordBatchOutput, SdkError<PutRecordBatchError>> {
+        self.put_record_batch()
+            .set_records(Some(records))
+            .delivery_stream_name(stream_name)
+            .send()
+            .await
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Snafu)]
+pub enum KinesisError {
+    #[snafu(display("PutRecordBatch failed: {}", source))]
+    PutRecordBatch {
+        source: SdkError<PutRecordBatchError>,
+    },
+    #[snafu(display(
+        "PutRecordBatch failed after {} records were accepted: {}",
+        accepted,
+        source
+    ))]
+    PartialPutRecordBatch {
+        accepted: usize,
+        source: SdkError<PutRecordBatchError>,
+    },
+    #[snafu(display("{} records were rejected after {} attempts", count, attempts))]
+    FailedRecords { count: usize, attempts: usize },
+}
 
 #[derive(Clone)]
-pub struct KinesisService {
-    pub client: KinesisFirehoseClient,
-    pub region: Option<Region>,
-    pub stream_name: String,
+pub struct KinesisService<C = KinesisFirehoseClient> {
+    pub client: C,
+}
+
+impl<C> KinesisService<C> {
+    pub const fn new(client: C) -> Self {
+        Self { client }
+    }
 }
 
 pub struct KinesisResponse {
@@ -36,42 +97,99 @@ impl DriverResponse for KinesisResponse {
     }
 }
 
-impl Service<Vec<KinesisRequest>> for KinesisService {
+impl<C> Service<KinesisBatch> for KinesisService<C>
+where
+    C: PutRecordBatchClient,
+{
     type Response = KinesisResponse;
-    type Error = SdkError<PutRecordBatchError>;
+    type Error = KinesisError;
     type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
 
     fn poll_ready(&mut self, _cx: &mut Context) -> Poll<Result<(), Self::Error>> {
         Poll::Ready(Ok(()))
     }
 
-    fn call(&mut self, requests: Vec<KinesisRequest>) -> Self::Future {
+    fn call(&mut self, batch: KinesisBatch) -> Self::Future {
         debug!(
             message = "Sending records.",
-            events = %requests.len(),
+            events = %batch.event_count,
+            records = %batch.records.len(),
+            stream_name = %batch.stream_name,
         );
 
-        let events_byte_size = requests.iter().map(|req| req.event_byte_size).sum();
-        let count = requests.len();
-
-        let records = requests.into_iter().map(|req| req.record).collect();
-
         let client = self.client.clone();
-
-        let stream_name = self.stream_name.clone();
         Box::pin(async move {
-            client
-                .put_record_batch()
-                .set_records(Some(records))
-                .delivery_stream_name(stream_name)
-                .send()
+            put_records(&client, batch.stream_name, batch.records)
                 .instrument(info_span!("request").or_current())
                 .await?;
 
             Ok(KinesisResponse {
-                events_byte_size,
-                count,
+                events_byte_size: batch.event_byte_size,
+                count: batch.event_count,
             })
         })
     }
 }
+
+/// Puts the `records`, sending only the rejected ones again when a call partially fails.
+///
+/// Once some records were accepted, a failed call can't be retried with the whole batch without
+/// duplicating them, so it's reported as a `PartialPutRecordBatch` error instead.
+async fn put_records<C: PutRecordBatchClient>(
+    client: &C,
+    stream_name: String,
+    mut records: Vec<Record>,
+) -> Result<(), KinesisError> {
+    let mut backoff = PARTIAL_FAILURE_BACKOFF;
+    let mut accepted = 0;
+    for attempt in 1.. {
+        let result = client
+            .put_records(stream_name.clone(), records.clone())
+            .await;
+        let output = match result {
+            Ok(output) => output,
+            Err(source) if accepted == 0 => return Err(KinesisError::PutRecordBatch { source }),
+            Err(source) => return Err(KinesisError::PartialPutRecordBatch { accepted, source }),
+        };
+
+        let sent = records.len();
+        records = failed_records(records, &output);
+        accepted += sent - records.len();
+        if records.is_empty() {
+            break;
+        }
+        if attempt >= MAX_PARTIAL_FAILURE_ATTEMPTS {
+            return Err(KinesisError::FailedRecords {
+                count: records.len(),
+                attempts: attempt,
+            });
+        }
+
+        debug!(
+            message = "Retrying records rejected by the delivery stream.",
+            count = %records.len(),
+            %attempt,
+        );
+        tokio::time::sleep(backoff).await;
+        backoff *= 2;
+    }
+    Ok(())
+}
+
+/// Returns the records rejected in the `output` of putting the `records`.
+fn failed_records(records: Vec<Record>, output: &PutRecordBatchOutput) -> Vec<Record> {
+    if output.failed_put_count().unwrap_or_default() == 0 {
+        return Vec::new();
+    }
+    match output.request_responses() {
+        // The responses are in the order of the records.
+        Some(responses) if responses.len() == records.len() => records
+            .into_iter()
+            .zip(responses)
+            .filter(|(_, response)| response.error_code().is_some())
+            .map(|(record, _)| record)
+            .collect(),
+        // Without the responses of each record, all of them are sent again.
+        _ => records,
+    }
+}
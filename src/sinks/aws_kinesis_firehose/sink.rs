@@ -1,33 +1,44 @@
 use std::{fmt, num::NonZeroUsize};
 
 use async_trait::async_trait;
-use futures::{stream::BoxStream, StreamExt};
+use futures::{future, stream::BoxStream, StreamExt};
 use tower::Service;
 use vector_core::{
+    partition::Partitioner,
     sink::StreamSink,
     stream::{BatcherSettings, DriverResponse},
 };
 
 use crate::{
-    event::Event,
+    event::{Event, LogEvent},
+    internal_events::TemplateRenderingError,
     sinks::{
-        aws_kinesis_firehose::request_builder::{KinesisRequest, KinesisRequestBuilder},
-        util::SinkBuilderExt,
+        aws_kinesis_firehose::request_builder::{
+            KinesisBatch, KinesisRequest, KinesisRequestBuilder,
+        },
+        util::{processed_event::ProcessedEvent, SinkBuilderExt},
     },
+    template::Template,
 };
 
-#[derive(Debug, Clone)]
-struct KinesisFirehoseRetryLogic;
+pub type KinesisProcessedEvent = ProcessedEvent<LogEvent, KinesisMetadata>;
+
+pub struct KinesisMetadata {
+    pub stream_name: String,
+}
 
 pub struct KinesisSink<S> {
     pub batch_settings: BatcherSettings,
+    pub max_open_streams: NonZeroUsize,
+    pub aggregate_records: bool,
+    pub stream_name: Template,
     pub service: S,
     pub request_builder: KinesisRequestBuilder,
 }
 
 impl<S> KinesisSink<S>
 where
-    S: Service<Vec<KinesisRequest>> + Send + 'static,
+    S: Service<KinesisBatch> + Send + 'static,
     S::Future: Send + 'static,
     S::Response: DriverResponse + Send + 'static,
     S::Error: fmt::Debug + Into<crate::Error> + Send,
@@ -35,11 +46,14 @@ where
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
         let request_builder_concurrency_limit = NonZeroUsize::new(50);
 
+        let stream_name = self.stream_name;
+        let aggregate_records = self.aggregate_records;
         let sink = input
             .map(|event| {
                 // Panic: This sink only accepts Logs, so this should never panic
                 event.into_log()
             })
+            .filter_map(move |log| future::ready(process_log(log, &stream_name)))
             .request_builder(request_builder_concurrency_limit, self.request_builder)
             .filter_map(|request| async move {
                 match request {
@@ -50,7 +64,11 @@ where
                     Ok(req) => Some(req),
                 }
             })
-            .batched(self.batch_settings.into_byte_size_config())
+            .batched_partitioned(StreamNamePartitioner, self.batch_settings)
+            .with_max_partitions(self.max_open_streams)
+            .map(move |(stream_name, requests)| {
+                KinesisBatch::new(stream_name, requests, aggregate_records)
+            })
             .into_driver(self.service);
 
         sink.run().await
@@ -60,7 +78,7 @@ where
 #[async_trait]
 impl<S> StreamSink<Event> for KinesisSink<S>
 where
-    S: Service<Vec<KinesisRequest>> + Send + 'static,
+    S: Service<KinesisBatch> + Send + 'static,
     S::Future: Send + 'static,
     S::Response: DriverResponse + Send + 'static,
     S::Error: fmt::Debug + Into<crate::Error> + Send,
@@ -69,3 +87,34 @@ where
         self.run_inner(input).await
     }
 }
+
+/// Renders the delivery stream name of the `log`, dropping it when the template fails to render.
+pub fn process_log(log: LogEvent, stream_name: &Template) -> Option<KinesisProcessedEvent> {
+    let stream_name = stream_name
+        .render_string(&log)
+        .map_err(|error| {
+            emit!(TemplateRenderingError {
+                error,
+                field: Some("stream_name"),
+                drop_event: true,
+            });
+        })
+        .ok()?;
+
+    Some(KinesisProcessedEvent {
+        event: log,
+        metadata: KinesisMetadata { stream_name },
+    })
+}
+
+/// Partitions requests by the delivery stream they're sent to.
+struct StreamNamePartitioner;
+
+impl Partitioner for StreamNamePartitioner {
+    type Item = KinesisRequest;
+    type Key = String;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        item.stream_name.clone()
+    }
+}
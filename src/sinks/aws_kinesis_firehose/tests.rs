@@ -1,19 +1,59 @@
 #![cfg(test)]
 
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use aws_sdk_firehose::error::PutRecordBatchError;
+use aws_sdk_firehose::model::{PutRecordBatchResponseEntry, Record};
+use aws_sdk_firehose::output::PutRecordBatchOutput;
+use aws_sdk_firehose::types::{Blob, SdkError};
 use codecs::JsonSerializerConfig;
+use tower::Service;
+use vector_core::stream::BatcherSettings;
 
 use super::*;
 use crate::{
     aws::RegionOrEndpoint,
+    codecs::{Encoder, EncodingConfig},
     config::{SinkConfig, SinkContext},
+    event::{Event, LogEvent},
     sinks::{
-        aws_kinesis_firehose::config::{
-            KinesisFirehoseDefaultBatchSettings, MAX_PAYLOAD_EVENTS, MAX_PAYLOAD_SIZE,
+        aws_kinesis_firehose::{
+            config::{
+                BuildError, KinesisFirehoseDefaultBatchSettings, KinesisRetryLogic,
+                MAX_PAYLOAD_EVENTS, MAX_PAYLOAD_SIZE,
+            },
+            request_builder::{KinesisBatch, KinesisRequestBuilder},
+            service::{KinesisError, KinesisService, PutRecordBatchClient},
+            sink::KinesisSink,
         },
-        util::{batch::BatchError, BatchConfig, Compression},
+        util::{batch::BatchError, retries::RetryLogic, BatchConfig, Compression},
+        VectorSink,
     },
+    template::Template,
 };
 
+fn config(batch: BatchConfig<KinesisFirehoseDefaultBatchSettings>) -> KinesisFirehoseSinkConfig {
+    KinesisFirehoseSinkConfig {
+        stream_name: Template::try_from("test").unwrap(),
+        max_open_streams: 100,
+        aggregate_records: false,
+        region: RegionOrEndpoint::with_both("local", "http://localhost:4566"),
+        encoding: JsonSerializerConfig::new().into(),
+        compression: Compression::None,
+        batch,
+        request: Default::default(),
+        tls: None,
+        auth: Default::default(),
+        acknowledgements: Default::default(),
+    }
+}
+
 #[test]
 fn generate_config() {
     crate::test_util::test_generate_config::<KinesisFirehoseSinkConfig>();
@@ -25,17 +65,7 @@ async fn check_batch_size() {
     let mut batch = BatchConfig::<KinesisFirehoseDefaultBatchSettings>::default();
     batch.max_bytes = Some(MAX_PAYLOAD_SIZE + 1);
 
-    let config = KinesisFirehoseSinkConfig {
-        stream_name: String::from("test"),
-        region: RegionOrEndpoint::with_both("local", "http://localhost:4566"),
-        encoding: JsonSerializerConfig::new().into(),
-        compression: Compression::None,
-        batch,
-        request: Default::default(),
-        tls: None,
-        auth: Default::default(),
-        acknowledgements: Default::default(),
-    };
+    let config = config(batch);
 
     let cx = SinkContext::new_test();
     let res = config.build(cx).await;
@@ -53,17 +83,7 @@ async fn check_batch_events() {
     let mut batch = BatchConfig::<KinesisFirehoseDefaultBatchSettings>::default();
     batch.max_events = Some(MAX_PAYLOAD_EVENTS + 1);
 
-    let config = KinesisFirehoseSinkConfig {
-        stream_name: String::from("test"),
-        region: RegionOrEndpoint::with_both("local", "http://localhost:4566"),
-        encoding: JsonSerializerConfig::new().into(),
-        compression: Compression::None,
-        batch,
-        request: Default::default(),
-        tls: None,
-        auth: Default::default(),
-        acknowledgements: Default::default(),
-    };
+    let config = config(batch);
 
     let cx = SinkContext::new_test();
     let res = config.build(cx).await;
@@ -75,3 +95,236 @@ async fn check_batch_events() {
         }))
     );
 }
+
+#[tokio::test]
+async fn check_aggregate_records_without_compression() {
+    let mut config = config(Default::default());
+    config.aggregate_records = true;
+    config.compression = Compression::gzip_default();
+
+    let cx = SinkContext::new_test();
+    let res = config.build(cx).await;
+
+    assert_eq!(
+        res.err().and_then(|e| e.downcast::<BuildError>().ok()),
+        Some(Box::new(BuildError::AggregateWithCompression))
+    );
+}
+
+/// A `PutRecordBatch` client recording the records put to each delivery stream, and rejecting
+/// records by their data as many times as configured.
+#[derive(Clone, Default)]
+struct MockClient {
+    puts: Arc<Mutex<Vec<(String, Vec<String>)>>>,
+    rejections: Arc<Mutex<HashMap<String, usize>>>,
+    /// The number of successful puts after which every put times out, if any.
+    timeout_after: Option<usize>,
+}
+
+impl MockClient {
+    fn rejecting(data: &str, times: usize) -> Self {
+        let client = Self::default();
+        client
+            .rejections
+            .lock()
+            .unwrap()
+            .insert(data.to_owned(), times);
+        client
+    }
+
+    const fn timing_out_after(mut self, puts: usize) -> Self {
+        self.timeout_after = Some(puts);
+        self
+    }
+
+    fn puts(&self) -> Vec<(String, Vec<String>)> {
+        self.puts.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl PutRecordBatchClient for MockClient {
+    async fn put_records(
+        &self,
+        stream_name: String,
+        records: Vec<Record>,
+    ) -> Result<PutRecordBatchOutput, SdkError<PutRecordBatchError>> {
+        if let Some(puts) = self.timeout_after {
+            if self.puts.lock().unwrap().len() >= puts {
+                return Err(SdkError::TimeoutError("timed out".into()));
+            }
+        }
+
+        let records = records
+            .iter()
+            .map(|record| String::from_utf8(record.data.clone().unwrap().into_inner()).unwrap())
+            .collect::<Vec<_>>();
+
+        let mut rejections = self.rejections.lock().unwrap();
+        let mut failed_put_count = 0;
+        let responses = records
+            .iter()
+            .map(|data| match rejections.get_mut(data) {
+                Some(times) if *times > 0 => {
+                    *times -= 1;
+                    failed_put_count += 1;
+                    PutRecordBatchResponseEntry::builder()
+                        .error_code("ServiceUnavailableException")
+                        .error_message("Slow down.")
+                        .build()
+                }
+                _ => PutRecordBatchResponseEntry::builder()
+                    .record_id(data.clone())
+                    .build(),
+            })
+            .collect();
+
+        self.puts.lock().unwrap().push((stream_name, records));
+        Ok(PutRecordBatchOutput::builder()
+            .failed_put_count(failed_put_count)
+            .set_request_responses(Some(responses))
+            .build())
+    }
+}
+
+fn batch(records: &[&str]) -> KinesisBatch {
+    KinesisBatch {
+        stream_name: "stream".into(),
+        records: records
+            .iter()
+            .map(|data| Record::builder().data(Blob::new(*data)).build())
+            .collect(),
+        finalizers: Default::default(),
+        event_byte_size: 0,
+        event_count: records.len(),
+    }
+}
+
+#[tokio::test]
+async fn partitions_records_by_stream_name() {
+    let client = MockClient::default();
+    let encoding: EncodingConfig = JsonSerializerConfig::new().into();
+    let sink = KinesisSink {
+        batch_settings: BatcherSettings::new(
+            Duration::from_secs(1),
+            NonZeroUsize::new(MAX_PAYLOAD_SIZE).unwrap(),
+            NonZeroUsize::new(MAX_PAYLOAD_EVENTS).unwrap(),
+        ),
+        max_open_streams: NonZeroUsize::new(10).unwrap(),
+        aggregate_records: false,
+        stream_name: Template::try_from("stream-{{ tenant }}").unwrap(),
+        service: KinesisService::new(client.clone()),
+        request_builder: KinesisRequestBuilder {
+            compression: Compression::None,
+            encoder: (
+                encoding.transformer(),
+                Encoder::<()>::new(encoding.build().unwrap()),
+            ),
+        },
+    };
+
+    let events = [("a", "one"), ("b", "two"), ("a", "three"), ("c", "four")]
+        .into_iter()
+        .map(|(tenant, message)| {
+            let mut log = LogEvent::from(message);
+            log.insert("tenant", tenant);
+            Event::from(log)
+        })
+        .chain(std::iter::once(Event::from(LogEvent::from("untenanted"))))
+        .collect::<Vec<_>>();
+    VectorSink::from_event_streamsink(sink)
+        .run_events(events)
+        .await
+        .unwrap();
+
+    let mut puts = client
+        .puts()
+        .into_iter()
+        .map(|(stream_name, records)| {
+            let messages = records
+                .iter()
+                .map(|data| {
+                    serde_json::from_str::<serde_json::Value>(data).unwrap()["message"].clone()
+                })
+                .collect::<Vec<_>>();
+            (stream_name, messages)
+        })
+        .collect::<Vec<_>>();
+    puts.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        puts,
+        vec![
+            (
+                "stream-a".to_owned(),
+                vec![serde_json::json!("one"), serde_json::json!("three")]
+            ),
+            ("stream-b".to_owned(), vec![serde_json::json!("two")]),
+            ("stream-c".to_owned(), vec![serde_json::json!("four")]),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn retries_only_failed_records() {
+    let client = MockClient::rejecting("b", 2);
+    let mut service = KinesisService::new(client.clone());
+
+    service.call(batch(&["a", "b", "c"])).await.unwrap();
+
+    assert_eq!(
+        client.puts(),
+        vec![
+            (
+                "stream".to_owned(),
+                vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]
+            ),
+            ("stream".to_owned(), vec!["b".to_owned()]),
+            ("stream".to_owned(), vec!["b".to_owned()]),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn gives_up_on_records_failing_repeatedly() {
+    let client = MockClient::rejecting("b", usize::MAX);
+    let mut service = KinesisService::new(client.clone());
+
+    let error = service.call(batch(&["a", "b"])).await.err().unwrap();
+
+    assert!(matches!(
+        error,
+        KinesisError::FailedRecords {
+            count: 1,
+            attempts: 5
+        }
+    ));
+    assert_eq!(client.puts().len(), 5);
+    assert!(!KinesisRetryLogic.is_retriable_error(&error));
+}
+
+#[tokio::test(start_paused = true)]
+async fn does_not_retry_batch_after_partial_success() {
+    // Nothing was accepted yet, so the whole batch can be retried.
+    let client = MockClient::default().timing_out_after(0);
+    let error = KinesisService::new(client)
+        .call(batch(&["a", "b"]))
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(error, KinesisError::PutRecordBatch { .. }));
+    assert!(KinesisRetryLogic.is_retriable_error(&error));
+
+    // Retrying the whole batch would put "a" again.
+    let client = MockClient::rejecting("b", 1).timing_out_after(1);
+    let error = KinesisService::new(client.clone())
+        .call(batch(&["a", "b"]))
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(
+        error,
+        KinesisError::PartialPutRecordBatch { accepted: 1, .. }
+    ));
+    assert_eq!(client.puts().len(), 1);
+    assert!(!KinesisRetryLogic.is_retriable_error(&error));
+}
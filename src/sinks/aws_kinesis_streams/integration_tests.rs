@@ -15,7 +15,7 @@ use crate::{
         util::{BatchConfig, Compression},
     },
     test_util::{
-        components::{run_and_assert_sink_compliance, AWS_SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, AWS_SINK_TAGS},
         random_lines_with_stream, random_string,
     },
 };
@@ -54,7 +54,7 @@ async fn kinesis_put_records() {
 
     let (mut input_lines, events) = random_lines_with_stream(100, 11, None);
 
-    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
 
     sleep(Duration::from_secs(1)).await;
 
@@ -3,6 +3,7 @@ use std::io;
 use aws_sdk_kinesis::model::PutRecordsRequestEntry;
 use aws_sdk_kinesis::types::Blob;
 use bytes::Bytes;
+use vector_buffers::EventCount;
 use vector_core::ByteSizeOf;
 
 use crate::{
@@ -38,6 +39,12 @@ impl Finalizable for KinesisRequest {
     }
 }
 
+impl EventCount for KinesisRequest {
+    fn event_count(&self) -> usize {
+        1
+    }
+}
+
 impl KinesisRequest {
     fn encoded_length(&self) -> usize {
         let hash_key_size = self
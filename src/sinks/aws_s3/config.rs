@@ -23,8 +23,8 @@ use crate::{
             sink::S3Sink,
         },
         util::{
-            partitioner::KeyPartitioner, BatchConfig, BulkSizeBasedDefaultBatchSettings,
-            Compression, ServiceBuilderExt, TowerRequestConfig,
+            partitioner::KeyPartitioner, BulkSizeBasedDefaultBatchSettings, Compression,
+            PartitionedBatchConfig, ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck,
     },
@@ -99,7 +99,7 @@ pub struct S3SinkConfig {
 
     #[configurable(derived)]
     #[serde(default)]
-    pub batch: BatchConfig<BulkSizeBasedDefaultBatchSettings>,
+    pub batch: PartitionedBatchConfig<BulkSizeBasedDefaultBatchSettings>,
 
     #[configurable(derived)]
     #[serde(default)]
@@ -133,7 +133,7 @@ impl GenerateConfig for S3SinkConfig {
             region: RegionOrEndpoint::default(),
             encoding: (None::<FramingConfig>, TextSerializerConfig::new()).into(),
             compression: Compression::gzip_default(),
-            batch: BatchConfig::default(),
+            batch: PartitionedBatchConfig::default(),
             request: TowerRequestConfig::default(),
             tls: Some(TlsConfig::default()),
             auth: AwsAuthentication::default(),
@@ -178,7 +178,8 @@ impl S3SinkConfig {
             .service(service);
 
         // Configure our partitioning/batching.
-        let batch_settings = self.batch.into_batcher_settings()?;
+        let batch_settings = self.batch.batcher_settings()?;
+        let batch_overrides = self.batch.batcher_overrides()?;
         let key_prefix = self
             .key_prefix
             .as_ref()
@@ -211,7 +212,8 @@ impl S3SinkConfig {
             compression: self.compression,
         };
 
-        let sink = S3Sink::new(service, request_options, partitioner, batch_settings);
+        let sink = S3Sink::new(service, request_options, partitioner, batch_settings)
+            .with_batcher_overrides(batch_overrides);
 
         Ok(VectorSink::from_event_streamsink(sink))
     }
@@ -33,10 +33,10 @@ mod integration_tests {
         sinks::{
             aws_s3::S3SinkConfig,
             s3_common::config::S3Options,
-            util::{BatchConfig, Compression, TowerRequestConfig},
+            util::{Compression, PartitionedBatchConfig, TowerRequestConfig},
         },
         test_util::{
-            components::{run_and_assert_sink_compliance, AWS_SINK_TAGS},
+            components::{run_and_assert_driver_sink_compliance, AWS_SINK_TAGS},
             random_lines_with_stream, random_string,
         },
     };
@@ -60,7 +60,7 @@ mod integration_tests {
         let sink = config.build_processor(service).unwrap();
 
         let (lines, events, receiver) = make_events_batch(100, 10);
-        run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
         assert_eq!(receiver.await, BatchStatus::Delivered);
 
         let keys = get_keys(&bucket, prefix.unwrap()).await;
@@ -94,7 +94,7 @@ mod integration_tests {
         let sink = config.build_processor(service).unwrap();
 
         let (lines, events, receiver) = make_events_batch(100, 10);
-        run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
         assert_eq!(receiver.await, BatchStatus::Delivered);
 
         let keys = get_keys(&bucket, prefix.unwrap()).await;
@@ -146,7 +146,7 @@ mod integration_tests {
             Event::from(e)
         });
 
-        run_and_assert_sink_compliance(sink, stream::iter(events), &AWS_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, stream::iter(events), &AWS_SINK_TAGS).await;
 
         // Hard-coded sleeps are bad, but we're waiting on localstack's state to converge.
         tokio::time::sleep(Duration::from_secs(1)).await;
@@ -191,7 +191,7 @@ mod integration_tests {
         let sink = config.build_processor(service).unwrap();
 
         let (lines, events, receiver) = make_events_batch(100, batch_size * batch_multiplier);
-        run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
         assert_eq!(receiver.await, BatchStatus::Delivered);
 
         let keys = get_keys(&bucket, prefix.unwrap()).await;
@@ -253,7 +253,7 @@ mod integration_tests {
         let sink = config.build_processor(service).unwrap();
 
         let (lines, events, receiver) = make_events_batch(100, 10);
-        run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
         assert_eq!(receiver.await, BatchStatus::Delivered);
 
         let keys = get_keys(&bucket, prefix.unwrap()).await;
@@ -338,9 +338,9 @@ mod integration_tests {
     }
 
     fn config(bucket: &str, batch_size: usize) -> S3SinkConfig {
-        let mut batch = BatchConfig::default();
-        batch.max_events = Some(batch_size);
-        batch.timeout_secs = Some(5.0);
+        let mut batch = PartitionedBatchConfig::default();
+        batch.base.max_events = Some(batch_size);
+        batch.base.timeout_secs = Some(5.0);
 
         S3SinkConfig {
             bucket: bucket.to_string(),
@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use vector_buffers::EventCount;
 use vector_core::ByteSizeOf;
 
 use super::config::SqsSinkConfig;
@@ -142,3 +143,9 @@ impl Finalizable for SendMessageEntry {
         self.finalizers.take_finalizers()
     }
 }
+
+impl EventCount for SendMessageEntry {
+    fn event_count(&self) -> usize {
+        1
+    }
+}
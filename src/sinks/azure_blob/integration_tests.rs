@@ -24,7 +24,7 @@ use crate::{
         VectorSink,
     },
     test_util::{
-        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, SINK_TAGS},
         random_events_with_stream, random_lines, random_lines_with_stream, random_string,
     },
 };
@@ -79,7 +79,7 @@ async fn azure_blob_insert_lines_into_blob() {
     let sink = config.to_sink();
     let (lines, input) = random_lines_with_stream(100, 10, None);
 
-    run_and_assert_sink_compliance(sink, input, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, input, &SINK_TAGS).await;
 
     let blobs = config.list_blobs(blob_prefix.as_str()).await;
     assert_eq!(blobs.len(), 1);
@@ -105,7 +105,7 @@ async fn azure_blob_insert_json_into_blob() {
     let sink = config.to_sink();
     let (events, input) = random_events_with_stream(100, 10, None);
 
-    run_and_assert_sink_compliance(sink, input, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, input, &SINK_TAGS).await;
 
     let blobs = config.list_blobs(blob_prefix.as_str()).await;
     assert_eq!(blobs.len(), 1);
@@ -133,7 +133,7 @@ async fn azure_blob_insert_lines_into_blob_gzip() {
     let sink = config.to_sink();
     let (lines, events) = random_lines_with_stream(100, 10, None);
 
-    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &SINK_TAGS).await;
 
     let blobs = config.list_blobs(blob_prefix.as_str()).await;
     assert_eq!(blobs.len(), 1);
@@ -166,7 +166,7 @@ async fn azure_blob_insert_json_into_blob_gzip() {
     let sink = config.to_sink();
     let (events, input) = random_events_with_stream(100, 10, None);
 
-    run_and_assert_sink_compliance(sink, input, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, input, &SINK_TAGS).await;
 
     let blobs = config.list_blobs(blob_prefix.as_str()).await;
     assert_eq!(blobs.len(), 1);
@@ -201,7 +201,7 @@ async fn azure_blob_rotate_files_after_the_buffer_size_is_reached() {
     };
 
     let sink = config.to_sink();
-    run_and_assert_sink_compliance(sink, input, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, input, &SINK_TAGS).await;
 
     let blobs = config.list_blobs(blob_prefix.as_str()).await;
     assert_eq!(blobs.len(), 3);
@@ -8,6 +8,7 @@ use bytes::Bytes;
 use futures::FutureExt;
 use http::StatusCode;
 use snafu::Snafu;
+use vector_buffers::EventCount;
 use vector_core::{internal_event::EventsSent, stream::DriverResponse};
 
 use crate::{
@@ -29,6 +30,12 @@ impl Finalizable for AzureBlobRequest {
     }
 }
 
+impl EventCount for AzureBlobRequest {
+    fn event_count(&self) -> usize {
+        self.metadata.count
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AzureBlobMetadata {
     pub partition_key: String,
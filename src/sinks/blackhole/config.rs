@@ -1,4 +1,8 @@
+use std::{net::SocketAddr, path::PathBuf};
+
 use futures::{future, FutureExt};
+use hyper::server::conn::AddrIncoming;
+use snafu::{ResultExt, Snafu};
 use vector_config::configurable_component;
 
 use crate::{
@@ -10,6 +14,34 @@ const fn default_print_interval_secs() -> u64 {
     1
 }
 
+const fn default_snapshot_interval_secs() -> u64 {
+    10
+}
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display(
+        "`smoothing.alpha` must be greater than 0 and at most 1, got {}",
+        alpha
+    ))]
+    InvalidSmoothingAlpha { alpha: f64 },
+    #[snafu(display("`metrics_flush_interval_secs` must be greater than 0"))]
+    ZeroMetricsFlushInterval,
+    #[snafu(display("`snapshot_interval_secs` must be greater than 0"))]
+    ZeroSnapshotInterval,
+    #[snafu(display("`ack_delay_secs` must be finite and not negative, got {}", secs))]
+    InvalidAckDelay { secs: f64 },
+    #[snafu(display("`shutdown_after_idle_secs` must be greater than 0"))]
+    ZeroIdleTimeout,
+    #[snafu(display("`burst` requires `rate` to be set and greater than 0"))]
+    BurstWithoutRate,
+    #[snafu(display("Failed to bind `openmetrics_address` {}: {}", address, source))]
+    OpenMetricsBind {
+        address: SocketAddr,
+        source: hyper::Error,
+    },
+}
+
 /// Configuration for the `blackhole` sink.
 #[configurable_component(sink)]
 #[derive(Clone, Debug, Derivative)]
@@ -18,6 +50,9 @@ const fn default_print_interval_secs() -> u64 {
 pub struct BlackholeConfig {
     /// The number of seconds between reporting a summary of activity.
     ///
+    /// The summary includes the total number of events and raw bytes consumed, and the rates of
+    /// events and bytes consumed per second.
+    ///
     /// Set to `0` to disable reporting.
     #[derivative(Default(value = "1"))]
     #[serde(default = "default_print_interval_secs")]
@@ -28,6 +63,86 @@ pub struct BlackholeConfig {
     /// By default, there is no limit.
     pub rate: Option<usize>,
 
+    /// The number of events that the sink is allowed to consume at once, before being limited to `rate`.
+    ///
+    /// The sink is rate limited with a token bucket holding up to `burst` events, refilled at `rate` events per
+    /// second, so that spiky traffic can be simulated. Requires `rate` to be set. By default, events are consumed at
+    /// a steady `rate`.
+    pub burst: Option<usize>,
+
+    #[configurable(derived)]
+    pub smoothing: Option<SmoothingConfig>,
+
+    /// The number of seconds between flushing the internal metrics of the events consumed.
+    ///
+    /// By default, internal metrics are updated as soon as events are consumed. When set, they are
+    /// accumulated and flushed on this interval instead, independently of `print_interval_secs`.
+    pub metrics_flush_interval_secs: Option<u64>,
+
+    /// The address to expose the counters of the sink on, in the OpenMetrics text format.
+    ///
+    /// The total number of events and raw bytes consumed are exposed as counters, along with the time
+    /// they were created. The sink fails to build if the address can't be bound.
+    pub openmetrics_address: Option<SocketAddr>,
+
+    /// Whether to shut down Vector with a failure exit code once an event consumed is rejected by another
+    /// component.
+    ///
+    /// Events are shared with the other sinks they're sent to, so this is useful to fail fast, such as
+    /// in CI pipelines, when another sink rejects them. Whether an event was rejected is known once every
+    /// sink it was sent to has finalized it, which requires the source to have acknowledgements enabled.
+    pub exit_on_rejected: bool,
+
+    /// Whether to report events received without finalizers as errors.
+    ///
+    /// Sources attach finalizers to events when acknowledgements are enabled, so this is useful to validate that a
+    /// source wires them up. Events without finalizers have no status to reject, so they're counted as errors and
+    /// still consumed.
+    pub require_finalizers: bool,
+
+    /// Whether to write the events consumed to a temporary file, for inspection.
+    ///
+    /// Each event is written as a line of JSON. The path of the file is logged at startup, and the file is deleted
+    /// when the sink shuts down.
+    pub write_to_temp_file: bool,
+
+    /// The path of a CSV file to periodically append snapshots of the counters of the sink to.
+    ///
+    /// Each row contains the time of the snapshot, the total number of events and raw bytes consumed, and the rate of
+    /// events consumed per second since the previous snapshot. A header row is written first if the file is empty.
+    /// Failures to write to the file are logged, and don't stop the sink.
+    pub snapshot_to: Option<PathBuf>,
+
+    /// The number of seconds between snapshots written to `snapshot_to`.
+    #[derivative(Default(value = "10"))]
+    #[serde(default = "default_snapshot_interval_secs")]
+    pub snapshot_interval_secs: u64,
+
+    /// Whether to count the events consumed separately for each type of event.
+    ///
+    /// When enabled, `component_received_events_total` is also emitted with an `event_type` tag of `log`, `metric`,
+    /// or `trace`, since the sink accepts all of them.
+    pub per_type_metrics: bool,
+
+    /// The number of seconds to delay acknowledging the events consumed.
+    ///
+    /// Events are consumed right away, but their finalizers are only completed after the delay, which is useful to
+    /// exercise how sources handle slow acknowledgements. By default, events are acknowledged once consumed.
+    pub ack_delay_secs: Option<f64>,
+
+    /// Whether to report the latency of acknowledging the events consumed.
+    ///
+    /// The time from receiving events to completing their finalizers is recorded in the
+    /// `blackhole_ack_latency_seconds` histogram, and its percentiles are logged along with the activity summary.
+    pub report_ack_latency: bool,
+
+    /// The number of seconds without receiving events after which to shut Vector down.
+    ///
+    /// This is useful to exit once one-shot jobs, such as imports, are done. The whole topology is shut down
+    /// gracefully, as when Vector receives `SIGTERM`, and the events still in flight are consumed. By default, the
+    /// sink consumes events until the topology is shut down.
+    pub shutdown_after_idle_secs: Option<u64>,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -37,11 +152,69 @@ pub struct BlackholeConfig {
     pub acknowledgements: AcknowledgementsConfig,
 }
 
+/// Smoothing of the rates reported in the activity summary.
+///
+/// When enabled, the rates of events and bytes consumed per second are reported as exponential moving
+/// averages, rather than the rates of the latest reporting interval alone.
+#[configurable_component]
+#[derive(Clone, Copy, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SmoothingConfig {
+    /// The weight given to the rate of the latest reporting interval.
+    ///
+    /// Must be greater than `0` and at most `1`. Lower values give a smoother, but slower to react, average.
+    pub alpha: f64,
+}
+
 #[async_trait::async_trait]
 #[typetag::serde(name = "blackhole")]
 impl SinkConfig for BlackholeConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let sink = BlackholeSink::new(self.clone());
+        if let Some(SmoothingConfig { alpha }) = self.smoothing {
+            if !(alpha > 0.0 && alpha <= 1.0) {
+                return Err(BuildError::InvalidSmoothingAlpha { alpha }.into());
+            }
+        }
+
+        if self.metrics_flush_interval_secs == Some(0) {
+            return Err(BuildError::ZeroMetricsFlushInterval.into());
+        }
+
+        if self.snapshot_to.is_some() && self.snapshot_interval_secs == 0 {
+            return Err(BuildError::ZeroSnapshotInterval.into());
+        }
+
+        if let Some(secs) = self.ack_delay_secs {
+            if !secs.is_finite() || secs < 0.0 {
+                return Err(BuildError::InvalidAckDelay { secs }.into());
+            }
+        }
+
+        if self.shutdown_after_idle_secs == Some(0) {
+            return Err(BuildError::ZeroIdleTimeout.into());
+        }
+
+        if self.burst.is_some() && matches!(self.rate, None | Some(0)) {
+            return Err(BuildError::BurstWithoutRate.into());
+        }
+
+        let mut sink = BlackholeSink::new(self.clone());
+        if self.write_to_temp_file {
+            let file = tempfile::Builder::new()
+                .prefix("vector-blackhole-")
+                .suffix(".jsonl")
+                .tempfile()?;
+            info!(
+                message = "Writing events to temporary file.",
+                path = %file.path().display(),
+            );
+            sink = sink.with_temp_file(file);
+        }
+        if let Some(address) = self.openmetrics_address {
+            let incoming =
+                AddrIncoming::bind(&address).context(OpenMetricsBindSnafu { address })?;
+            sink = sink.with_openmetrics(incoming);
+        }
         let healthcheck = future::ok(()).boxed();
 
         Ok((VectorSink::Stream(Box::new(sink)), healthcheck))
@@ -68,10 +241,71 @@ impl GenerateConfig for BlackholeConfig {
 
 #[cfg(test)]
 mod tests {
-    use crate::sinks::blackhole::config::BlackholeConfig;
+    use crate::{
+        config::{SinkConfig, SinkContext},
+        sinks::blackhole::config::BlackholeConfig,
+    };
 
     #[test]
     fn generate_config() {
         crate::test_util::test_generate_config::<BlackholeConfig>();
     }
+
+    #[tokio::test]
+    async fn rejects_invalid_smoothing_alpha() {
+        for alpha in ["0.0", "1.5", "-0.5"] {
+            let config: BlackholeConfig =
+                toml::from_str(&format!("smoothing.alpha = {}", alpha)).unwrap();
+            let error = config.build(SinkContext::new_test()).await.err().unwrap();
+            assert!(error.to_string().starts_with("`smoothing.alpha` must be"));
+        }
+
+        let config: BlackholeConfig = toml::from_str("smoothing.alpha = 1.0").unwrap();
+        assert!(config.build(SinkContext::new_test()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_zero_metrics_flush_interval() {
+        let config: BlackholeConfig = toml::from_str("metrics_flush_interval_secs = 0").unwrap();
+        let error = config.build(SinkContext::new_test()).await.err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`metrics_flush_interval_secs` must be greater than 0"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_negative_ack_delay() {
+        let config: BlackholeConfig = toml::from_str("ack_delay_secs = -1.0").unwrap();
+        let error = config.build(SinkContext::new_test()).await.err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`ack_delay_secs` must be finite and not negative, got -1"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_zero_idle_timeout() {
+        let config: BlackholeConfig = toml::from_str("shutdown_after_idle_secs = 0").unwrap();
+        let error = config.build(SinkContext::new_test()).await.err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`shutdown_after_idle_secs` must be greater than 0"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_burst_without_rate() {
+        for config in ["burst = 10", "burst = 10\nrate = 0"] {
+            let config: BlackholeConfig = toml::from_str(config).unwrap();
+            let error = config.build(SinkContext::new_test()).await.err().unwrap();
+            assert_eq!(
+                error.to_string(),
+                "`burst` requires `rate` to be set and greater than 0"
+            );
+        }
+
+        let config: BlackholeConfig = toml::from_str("burst = 10\nrate = 5").unwrap();
+        assert!(config.build(SinkContext::new_test()).await.is_ok());
+    }
 }
@@ -1,4 +1,5 @@
 mod config;
+mod openmetrics;
 mod sink;
 
 pub use config::BlackholeConfig;
@@ -11,14 +12,19 @@ inventory::submit! {
 
 #[cfg(test)]
 mod tests {
+    use futures::{channel::mpsc, StreamExt};
+    use hyper::body::to_bytes;
 
     use crate::{
+        config::{SinkConfig, SinkContext},
+        event::{Event, LogEvent},
         sinks::{
             blackhole::{config::BlackholeConfig, sink::BlackholeSink},
             VectorSink,
         },
         test_util::{
-            components::run_and_assert_nonsending_sink_compliance, random_events_with_stream,
+            components::run_and_assert_nonsending_sink_compliance, next_addr,
+            random_events_with_stream, wait_for_tcp,
         },
     };
 
@@ -27,6 +33,19 @@ mod tests {
         let config = BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            burst: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
+            exit_on_rejected: false,
+            require_finalizers: false,
+            write_to_temp_file: false,
+            snapshot_to: None,
+            snapshot_interval_secs: 10,
+            per_type_metrics: false,
+            ack_delay_secs: None,
+            report_ack_latency: false,
+            shutdown_after_idle_secs: None,
             acknowledgements: Default::default(),
         };
         let sink = BlackholeSink::new(config);
@@ -35,4 +54,72 @@ mod tests {
         let (_input_lines, events) = random_events_with_stream(100, 10, None);
         run_and_assert_nonsending_sink_compliance(sink, events, &[]).await;
     }
+
+    #[tokio::test]
+    async fn blackhole_openmetrics() {
+        let address = next_addr();
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            openmetrics_address: Some(address),
+            ..Default::default()
+        };
+        let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(sink.run(rx.boxed()));
+
+        for _ in 0..3 {
+            tx.unbounded_send(Event::from(LogEvent::from("event")).into())
+                .unwrap();
+        }
+        wait_for_tcp(address).await;
+
+        let response = hyper::Client::new()
+            .get(format!("http://{}/metrics", address).parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/openmetrics-text; version=1.0.0; charset=utf-8"
+        );
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        let lines = body.lines().collect::<Vec<_>>();
+        assert_eq!(lines.last(), Some(&"# EOF"));
+        assert!(lines.contains(&"# TYPE blackhole_events counter"));
+        assert!(lines.contains(&"blackhole_events_total 3"));
+        assert!(lines.contains(&"# UNIT blackhole_raw_bytes bytes"));
+        for name in ["blackhole_events", "blackhole_raw_bytes"] {
+            let created = lines
+                .iter()
+                .find_map(|line| line.strip_prefix(&format!("{}_created ", name)))
+                .unwrap();
+            assert!(created.parse::<f64>().unwrap() > 0.0);
+        }
+
+        drop(tx);
+        sink.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn blackhole_openmetrics_address_in_use() {
+        let address = next_addr();
+        let _listener = std::net::TcpListener::bind(address).unwrap();
+        let config = BlackholeConfig {
+            openmetrics_address: Some(address),
+            ..Default::default()
+        };
+
+        let error = config
+            .build(SinkContext::new_test())
+            .await
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            error.starts_with(&format!("Failed to bind `openmetrics_address` {}", address)),
+            "{}",
+            error
+        );
+    }
 }
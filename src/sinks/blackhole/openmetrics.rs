@@ -0,0 +1,139 @@
+//! OpenMetrics exposition of the counters of the `blackhole` sink.
+
+use std::{
+    convert::Infallible,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures::FutureExt;
+use hyper::{
+    header::CONTENT_TYPE,
+    server::conn::AddrIncoming,
+    service::{make_service_fn, service_fn},
+    Body, Method, Response, Server, StatusCode,
+};
+use tokio::sync::watch;
+
+const CONTENT_TYPE_OPENMETRICS: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// The counters of the sink, along with the time they were created.
+#[derive(Clone)]
+pub(super) struct Counters {
+    total_events: Arc<AtomicUsize>,
+    total_raw_bytes: Arc<AtomicUsize>,
+    created: f64,
+}
+
+impl Counters {
+    pub(super) fn new(total_events: Arc<AtomicUsize>, total_raw_bytes: Arc<AtomicUsize>) -> Self {
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64();
+        Self {
+            total_events,
+            total_raw_bytes,
+            created,
+        }
+    }
+
+    /// Renders the counters in the OpenMetrics text format.
+    fn render(&self) -> String {
+        let mut output = String::new();
+        for (name, help, unit, value) in [
+            (
+                "blackhole_events",
+                "The total number of events consumed.",
+                None,
+                &self.total_events,
+            ),
+            (
+                "blackhole_raw_bytes",
+                "The total number of raw bytes consumed.",
+                Some("bytes"),
+                &self.total_raw_bytes,
+            ),
+        ] {
+            let _ = writeln!(output, "# TYPE {} counter", name);
+            if let Some(unit) = unit {
+                let _ = writeln!(output, "# UNIT {} {}", name, unit);
+            }
+            let _ = writeln!(output, "# HELP {} {}", name, help);
+            let _ = writeln!(output, "{}_total {}", name, value.load(Ordering::Relaxed));
+            let _ = writeln!(output, "{}_created {:.3}", name, self.created);
+        }
+        output.push_str("# EOF\n");
+        output
+    }
+}
+
+fn handle(method: &Method, counters: &Counters) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    if method == Method::GET {
+        *response.body_mut() = counters.render().into();
+        response.headers_mut().insert(
+            CONTENT_TYPE,
+            CONTENT_TYPE_OPENMETRICS
+                .parse()
+                .expect("valid content type"),
+        );
+    } else {
+        *response.status_mut() = StatusCode::METHOD_NOT_ALLOWED;
+    }
+    response
+}
+
+/// Serves the counters on `incoming` until `tripwire` changes.
+pub(super) async fn serve(
+    incoming: AddrIncoming,
+    counters: Counters,
+    mut tripwire: watch::Receiver<()>,
+) -> Result<(), ()> {
+    let new_service = make_service_fn(move |_| {
+        let counters = counters.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                futures::future::ok::<_, Infallible>(handle(req.method(), &counters))
+            }))
+        }
+    });
+
+    Server::builder(incoming)
+        .serve(new_service)
+        .with_graceful_shutdown(tripwire.changed().map(|_| ()))
+        .await
+        .map_err(|error| error!(message = "OpenMetrics endpoint error.", %error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_openmetrics() {
+        let counters = Counters {
+            total_events: Arc::new(AtomicUsize::new(3)),
+            total_raw_bytes: Arc::new(AtomicUsize::new(42)),
+            created: 1_600_000_000.5,
+        };
+
+        assert_eq!(
+            counters.render(),
+            "# TYPE blackhole_events counter\n\
+             # HELP blackhole_events The total number of events consumed.\n\
+             blackhole_events_total 3\n\
+             blackhole_events_created 1600000000.500\n\
+             # TYPE blackhole_raw_bytes counter\n\
+             # UNIT blackhole_raw_bytes bytes\n\
+             # HELP blackhole_raw_bytes The total number of raw bytes consumed.\n\
+             blackhole_raw_bytes_total 42\n\
+             blackhole_raw_bytes_created 1600000000.500\n\
+             # EOF\n"
+        );
+    }
+}
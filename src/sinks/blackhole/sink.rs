@@ -1,43 +1,268 @@
 use std::{
+    fs::OpenOptions,
+    io::{self, BufWriter, Write},
+    path::Path,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc,
+        Arc, Mutex,
     },
     time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
 use futures::{stream::BoxStream, StreamExt};
+use hyper::server::conn::AddrIncoming;
+use tempfile::NamedTempFile;
 use tokio::{
     select,
     sync::watch,
-    time::{interval, sleep_until},
+    time::{interval, interval_at, sleep_until},
 };
-use vector_core::{internal_event::EventsSent, ByteSizeOf};
+use vector_core::{internal_event::EventsSent, metrics::AgentDDSketch, ByteSizeOf};
 
 use crate::{
-    event::{EventArray, EventContainer},
-    sinks::{blackhole::config::BlackholeConfig, util::StreamSink},
+    event::{
+        BatchStatus, BatchStatusReceiver, EventArray, EventContainer, EventFinalizers, EventRef,
+        Finalizable,
+    },
+    internal_events::{
+        BlackholeAckLatency, BlackholeEventsReceived, BlackholeIdleShutdown,
+        BlackholeMissingFinalizersError, BlackholeRejectedEventsError, BlackholeSnapshotWriteError,
+        BlackholeTempFileWriteError,
+    },
+    sinks::{
+        blackhole::{
+            config::BlackholeConfig,
+            openmetrics::{self, Counters},
+        },
+        util::StreamSink,
+    },
 };
 
 pub struct BlackholeSink {
     total_events: Arc<AtomicUsize>,
     total_raw_bytes: Arc<AtomicUsize>,
+    /// Events received without finalizers, if they're required.
+    missing_finalizers: Arc<AtomicUsize>,
+    /// Events consumed since the internal metrics were last flushed, if they're flushed on an interval.
+    pending_metrics: Arc<PendingMetrics>,
     config: BlackholeConfig,
     last: Option<Instant>,
+    /// The token bucket limiting the rate of events consumed, if bursts are allowed.
+    token_bucket: Option<TokenBucket>,
+    /// The file the events consumed are written to, which is deleted when the sink is dropped.
+    temp_file: Option<BufWriter<NamedTempFile>>,
+    /// The latencies of acknowledging the events consumed, in seconds, if they're reported.
+    ack_latencies: Option<Arc<Mutex<AgentDDSketch>>>,
+    /// The listener the counters are exposed on in the OpenMetrics text format, if they're exposed.
+    openmetrics: Option<AddrIncoming>,
 }
 
 impl BlackholeSink {
     pub fn new(config: BlackholeConfig) -> Self {
+        let ack_latencies = config
+            .report_ack_latency
+            .then(|| Arc::new(Mutex::new(AgentDDSketch::with_agent_defaults())));
+        let token_bucket = config
+            .burst
+            .zip(config.rate)
+            .map(|(burst, rate)| TokenBucket::new(burst, rate));
         BlackholeSink {
             config,
             total_events: Arc::new(AtomicUsize::new(0)),
             total_raw_bytes: Arc::new(AtomicUsize::new(0)),
+            missing_finalizers: Arc::new(AtomicUsize::new(0)),
+            pending_metrics: Arc::new(PendingMetrics::default()),
             last: None,
+            token_bucket,
+            temp_file: None,
+            ack_latencies,
+            openmetrics: None,
+        }
+    }
+
+    /// Writes the events consumed to `file`, as lines of JSON.
+    pub fn with_temp_file(mut self, file: NamedTempFile) -> Self {
+        self.temp_file = Some(BufWriter::new(file));
+        self
+    }
+
+    /// Exposes the counters of the sink on `incoming`, in the OpenMetrics text format.
+    pub fn with_openmetrics(mut self, incoming: AddrIncoming) -> Self {
+        self.openmetrics = Some(incoming);
+        self
+    }
+}
+
+/// Token bucket holding up to `capacity` events, refilled at `rate` events per second.
+///
+/// The bucket starts full, so that a burst of up to `capacity` events is admitted at once. Taking more events than
+/// the bucket holds puts it in debt, which is paid back by waiting for it to be refilled.
+struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    tokens: f64,
+    last_refill: tokio::time::Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: usize, rate: usize) -> Self {
+        Self {
+            capacity: capacity as f64,
+            rate: rate as f64,
+            tokens: capacity as f64,
+            last_refill: tokio::time::Instant::now(),
+        }
+    }
+
+    /// Takes `count` events from the bucket, returning the instant at which they may be consumed.
+    fn take(&mut self, count: usize, now: tokio::time::Instant) -> tokio::time::Instant {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity) - count as f64;
+        self.last_refill = now;
+
+        if self.tokens >= 0.0 {
+            now
+        } else {
+            now + Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+/// Writes events as lines of JSON, flushing them so that the file can be inspected right away.
+fn write_events(writer: &mut impl Write, events: &EventArray) -> io::Result<()> {
+    for event in events.iter_events() {
+        match event {
+            EventRef::Log(log) => serde_json::to_writer(&mut *writer, log)?,
+            EventRef::Metric(metric) => serde_json::to_writer(&mut *writer, metric)?,
+            EventRef::Trace(trace) => serde_json::to_writer(&mut *writer, trace)?,
         }
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()
+}
+
+/// Logs the percentiles of the latencies of acknowledging the events consumed so far.
+fn log_ack_latencies(ack_latencies: &Mutex<AgentDDSketch>) {
+    let sketch = ack_latencies.lock().expect("ack latencies mutex poisoned");
+    if let (Some(p50), Some(p90), Some(p99), Some(max)) = (
+        sketch.quantile(0.5),
+        sketch.quantile(0.9),
+        sketch.quantile(0.99),
+        sketch.max(),
+    ) {
+        info!({
+            p50_secs = p50,
+            p90_secs = p90,
+            p99_secs = p99,
+            max_secs = max,
+        }, "Acknowledgement latency");
     }
 }
 
+/// The type of the events in `events`, as reported by the per-type metrics.
+const fn event_type(events: &EventArray) -> &'static str {
+    match events {
+        EventArray::Logs(_) => "log",
+        EventArray::Metrics(_) => "metric",
+        EventArray::Traces(_) => "trace",
+    }
+}
+
+/// Requests Vector to shut down with a failure code once any of the batches is rejected.
+async fn exit_on_rejected(batches: Vec<BatchStatusReceiver>) {
+    for batch in batches {
+        if batch.await == BatchStatus::Rejected {
+            emit!(BlackholeRejectedEventsError);
+            crate::signal::request_failure_shutdown();
+            return;
+        }
+    }
+}
+
+#[derive(Default)]
+struct PendingMetrics {
+    count: AtomicUsize,
+    byte_size: AtomicUsize,
+}
+
+impl PendingMetrics {
+    fn add(&self, count: usize, byte_size: usize) {
+        self.count.fetch_add(count, Ordering::AcqRel);
+        self.byte_size.fetch_add(byte_size, Ordering::AcqRel);
+    }
+
+    /// Emits the events consumed since the previous flush.
+    fn flush(&self) {
+        let count = self.count.swap(0, Ordering::AcqRel);
+        let byte_size = self.byte_size.swap(0, Ordering::AcqRel);
+        if count > 0 {
+            emit!(EventsSent {
+                count,
+                byte_size,
+                output: None,
+            });
+        }
+    }
+}
+
+/// Exponential moving average of the rate of events or bytes consumed per second, sampled from the
+/// running total.
+struct SmoothedRate {
+    alpha: f64,
+    last_total: usize,
+    last_sample: Instant,
+    average: Option<f64>,
+}
+
+impl SmoothedRate {
+    fn new(alpha: f64) -> Self {
+        Self {
+            alpha,
+            last_total: 0,
+            last_sample: Instant::now(),
+            average: None,
+        }
+    }
+
+    /// Updates the average with the rate since the previous sample, returning the new average.
+    fn update(&mut self, total: usize, now: Instant) -> f64 {
+        let elapsed = now.duration_since(self.last_sample).as_secs_f64();
+        if elapsed > 0.0 {
+            let rate = total.saturating_sub(self.last_total) as f64 / elapsed;
+            self.average = Some(match self.average {
+                Some(average) => self.alpha * rate + (1.0 - self.alpha) * average,
+                None => rate,
+            });
+            self.last_total = total;
+            self.last_sample = now;
+        }
+        self.average.unwrap_or_default()
+    }
+}
+
+/// The header of the CSV file of snapshots of the counters.
+const SNAPSHOT_HEADER: &str = "timestamp,events,bytes,rate\n";
+
+/// Appends a snapshot of the counters to a CSV file, preceded by the header if the file is empty.
+fn append_snapshot(path: &Path, events: usize, bytes: usize, rate: f64) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut row = String::new();
+    if file.metadata()?.len() == 0 {
+        row.push_str(SNAPSHOT_HEADER);
+    }
+    row.push_str(&format!(
+        "{},{},{},{:.3}\n",
+        chrono::Utc::now().to_rfc3339(),
+        events,
+        bytes,
+        rate
+    ));
+    file.write_all(row.as_bytes())
+}
+
 #[async_trait]
 impl StreamSink<EventArray> for BlackholeSink {
     async fn run(mut self: Box<Self>, mut input: BoxStream<'_, EventArray>) -> Result<(), ()> {
@@ -47,18 +272,81 @@ impl StreamSink<EventArray> for BlackholeSink {
         let total_events = Arc::clone(&self.total_events);
         let total_raw_bytes = Arc::clone(&self.total_raw_bytes);
         let (shutdown, mut tripwire) = watch::channel(());
+        let mut metrics_tripwire = shutdown.subscribe();
+
+        if let Some(path) = self.config.snapshot_to.clone() {
+            let interval_dur = Duration::from_secs(self.config.snapshot_interval_secs);
+            let total_events = Arc::clone(&self.total_events);
+            let total_raw_bytes = Arc::clone(&self.total_raw_bytes);
+            let mut tripwire = shutdown.subscribe();
+            tokio::spawn(async move {
+                let mut snapshot_interval =
+                    interval_at(tokio::time::Instant::now() + interval_dur, interval_dur);
+                let mut last_events = 0;
+                let mut last_snapshot = tokio::time::Instant::now();
+                let mut snapshot = move || {
+                    let events = total_events.load(Ordering::Relaxed);
+                    let now = tokio::time::Instant::now();
+                    let elapsed = now.duration_since(last_snapshot).as_secs_f64();
+                    let rate = if elapsed > 0.0 {
+                        events.saturating_sub(last_events) as f64 / elapsed
+                    } else {
+                        0.0
+                    };
+                    last_events = events;
+                    last_snapshot = now;
+
+                    let bytes = total_raw_bytes.load(Ordering::Relaxed);
+                    if let Err(error) = append_snapshot(&path, events, bytes, rate) {
+                        emit!(BlackholeSnapshotWriteError { path: &path, error });
+                    }
+                };
+                loop {
+                    select! {
+                        _ = snapshot_interval.tick() => snapshot(),
+                        _ = tripwire.changed() => break,
+                    }
+                }
+
+                snapshot();
+            });
+        }
+
+        if let Some(incoming) = self.openmetrics.take() {
+            let counters = Counters::new(
+                Arc::clone(&self.total_events),
+                Arc::clone(&self.total_raw_bytes),
+            );
+            tokio::spawn(openmetrics::serve(incoming, counters, shutdown.subscribe()));
+        }
 
         if self.config.print_interval_secs > 0 {
             let interval_dur = Duration::from_secs(self.config.print_interval_secs);
+            // Without smoothing, the rates are those of the latest reporting interval alone.
+            let alpha = self
+                .config
+                .smoothing
+                .map_or(1.0, |smoothing| smoothing.alpha);
+            let mut events_rate = SmoothedRate::new(alpha);
+            let mut bytes_rate = SmoothedRate::new(alpha);
+            let ack_latencies = self.ack_latencies.clone();
             tokio::spawn(async move {
                 let mut print_interval = interval(interval_dur);
                 loop {
                     select! {
                         _ = print_interval.tick() => {
+                            let events = total_events.load(Ordering::Relaxed);
+                            let raw_bytes = total_raw_bytes.load(Ordering::Relaxed);
+                            let now = Instant::now();
                             info!({
-                                events = total_events.load(Ordering::Relaxed),
-                                raw_bytes_collected = total_raw_bytes.load(Ordering::Relaxed),
+                                events,
+                                raw_bytes_collected = raw_bytes,
+                                events_per_sec = events_rate.update(events, now),
+                                bytes_per_sec = bytes_rate.update(raw_bytes, now),
                             }, "Total events collected");
+                            if let Some(ack_latencies) = &ack_latencies {
+                                log_ack_latencies(ack_latencies);
+                            }
                         },
                         _ = tripwire.changed() => break,
                     }
@@ -68,11 +356,94 @@ impl StreamSink<EventArray> for BlackholeSink {
                     events = total_events.load(Ordering::Relaxed),
                     raw_bytes_collected = total_raw_bytes.load(Ordering::Relaxed)
                 }, "Total events collected");
+                if let Some(ack_latencies) = &ack_latencies {
+                    log_ack_latencies(ack_latencies);
+                }
+            });
+        }
+
+        let metrics_flush_interval = self
+            .config
+            .metrics_flush_interval_secs
+            .map(Duration::from_secs);
+        if let Some(interval_dur) = metrics_flush_interval {
+            let pending_metrics = Arc::clone(&self.pending_metrics);
+            tokio::spawn(async move {
+                // Unlike the activity summary, there's nothing to flush right away.
+                let mut flush_interval =
+                    interval_at(tokio::time::Instant::now() + interval_dur, interval_dur);
+                loop {
+                    select! {
+                        _ = flush_interval.tick() => pending_metrics.flush(),
+                        _ = metrics_tripwire.changed() => break,
+                    }
+                }
+
+                pending_metrics.flush();
             });
         }
 
-        while let Some(events) = input.next().await {
-            if let Some(rate) = self.config.rate {
+        let ack_delay = self.config.ack_delay_secs.map(Duration::from_secs_f64);
+        let mut idle_timeout = self
+            .config
+            .shutdown_after_idle_secs
+            .map(Duration::from_secs);
+        loop {
+            let next = match idle_timeout {
+                Some(timeout) => match tokio::time::timeout(timeout, input.next()).await {
+                    Ok(next) => next,
+                    Err(_) => {
+                        emit!(BlackholeIdleShutdown {
+                            idle_secs: timeout.as_secs()
+                        });
+                        crate::signal::request_shutdown();
+                        // The events received until the topology has shut down are still consumed.
+                        idle_timeout = None;
+                        continue;
+                    }
+                },
+                None => input.next().await,
+            };
+            let mut events = match next {
+                Some(events) => events,
+                None => break,
+            };
+            let received = tokio::time::Instant::now();
+            if self.config.per_type_metrics {
+                emit!(BlackholeEventsReceived {
+                    count: events.len(),
+                    event_type: event_type(&events),
+                });
+            }
+
+            if self.config.exit_on_rejected {
+                // The events are shared with the other components they were sent to, so whether
+                // they were rejected is only known once every copy of them has been finalized.
+                let batches = events
+                    .iter_events()
+                    .map(|event| event.metadata().finalizers().clone())
+                    .collect::<EventFinalizers>()
+                    .subscribe_batches();
+                if !batches.is_empty() {
+                    tokio::spawn(exit_on_rejected(batches));
+                }
+            }
+
+            if self.config.require_finalizers {
+                let count = events
+                    .iter_events()
+                    .filter(|event| event.metadata().finalizers().is_empty())
+                    .count();
+                if count > 0 {
+                    self.missing_finalizers.fetch_add(count, Ordering::AcqRel);
+                    emit!(BlackholeMissingFinalizersError { count });
+                }
+            }
+
+            if let Some(token_bucket) = self.token_bucket.as_mut() {
+                let until = token_bucket.take(events.len(), tokio::time::Instant::now());
+                sleep_until(until).await;
+            } else if let Some(rate) = self.config.rate {
                 let factor: f32 = 1.0 / rate as f32;
                 let secs: f32 = factor * (events.len() as f32);
                 let until = self.last.unwrap_or_else(Instant::now) + Duration::from_secs_f32(secs);
@@ -80,6 +451,12 @@ impl StreamSink<EventArray> for BlackholeSink {
                 self.last = Some(until);
             }
 
+            if let Some(writer) = self.temp_file.as_mut() {
+                if let Err(error) = write_events(writer, &events) {
+                    emit!(BlackholeTempFileWriteError { error });
+                }
+            }
+
             let message_len = events.size_of();
 
             let _ = self.total_events.fetch_add(events.len(), Ordering::AcqRel);
@@ -87,11 +464,40 @@ impl StreamSink<EventArray> for BlackholeSink {
                 .total_raw_bytes
                 .fetch_add(message_len, Ordering::AcqRel);
 
-            emit!(EventsSent {
-                count: events.len(),
-                byte_size: message_len,
-                output: None,
-            });
+            if metrics_flush_interval.is_some() {
+                self.pending_metrics.add(events.len(), message_len);
+            } else {
+                emit!(EventsSent {
+                    count: events.len(),
+                    byte_size: message_len,
+                    output: None,
+                });
+            }
+
+            if ack_delay.is_some() || self.ack_latencies.is_some() {
+                let finalizers = events.take_finalizers();
+                let ack_latencies = self.ack_latencies.clone();
+                let acknowledge = move || {
+                    drop(finalizers);
+                    if let Some(ack_latencies) = ack_latencies {
+                        let latency = received.elapsed();
+                        ack_latencies
+                            .lock()
+                            .expect("ack latencies mutex poisoned")
+                            .insert(latency.as_secs_f64());
+                        emit!(BlackholeAckLatency { latency });
+                    }
+                };
+                match ack_delay {
+                    Some(delay) => {
+                        tokio::spawn(async move {
+                            sleep_until(received + delay).await;
+                            acknowledge();
+                        });
+                    }
+                    None => acknowledge(),
+                }
+            }
         }
 
         // Notify the reporting task to shutdown.
@@ -100,3 +506,502 @@ impl StreamSink<EventArray> for BlackholeSink {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::mpsc;
+    use tokio::{
+        sync::broadcast::{self, error::TryRecvError},
+        time::sleep,
+    };
+
+    use super::*;
+    use crate::{
+        event::{
+            BatchNotifier, Event, EventStatus, LogEvent, Metric, MetricKind, MetricValue,
+            TraceEvent,
+        },
+        signal::ShutdownRequest,
+    };
+
+    /// Receives the next shutdown request of the given kind, ignoring the others, which are sent by
+    /// tests running concurrently.
+    fn try_recv_request(
+        requests: &mut broadcast::Receiver<ShutdownRequest>,
+        kind: ShutdownRequest,
+    ) -> Result<(), TryRecvError> {
+        loop {
+            match requests.try_recv() {
+                Ok(request) if request == kind => return Ok(()),
+                Ok(_) | Err(TryRecvError::Lagged(_)) => continue,
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Runs the sink with a single consumed event, returning the events pending a metrics flush
+    /// after each of the given delays.
+    async fn pending_after(config: BlackholeConfig, delays: &[u64]) -> Vec<usize> {
+        let sink = BlackholeSink::new(config);
+        let pending_metrics = Arc::clone(&sink.pending_metrics);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        tx.unbounded_send(Event::from(LogEvent::from("event")).into())
+            .unwrap();
+        sleep(Duration::from_millis(100)).await;
+
+        let mut pending = Vec::new();
+        for delay in delays {
+            sleep(Duration::from_secs(*delay)).await;
+            pending.push(pending_metrics.count.load(Ordering::Acquire));
+        }
+
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        pending
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn metrics_flush_independently_of_print_interval() {
+        // The activity summary is printed every second, but metrics are only flushed every 5 seconds.
+        let config = BlackholeConfig {
+            print_interval_secs: 1,
+            metrics_flush_interval_secs: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(pending_after(config, &[3, 2]).await, vec![1, 0]);
+
+        // Metrics are flushed every second, long before the activity summary is printed.
+        let config = BlackholeConfig {
+            print_interval_secs: 10,
+            metrics_flush_interval_secs: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(pending_after(config, &[1]).await, vec![0]);
+
+        // Metrics are still flushed with the activity summary disabled.
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            metrics_flush_interval_secs: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(pending_after(config, &[1]).await, vec![0]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn metrics_emitted_immediately_without_flush_interval() {
+        let config = BlackholeConfig {
+            print_interval_secs: 1,
+            ..Default::default()
+        };
+        assert_eq!(pending_after(config, &[2]).await, vec![0]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn admits_burst_before_pacing() {
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            rate: Some(10),
+            burst: Some(5),
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let total_events = Arc::clone(&sink.total_events);
+        let (tx, rx) = mpsc::unbounded();
+        let start = tokio::time::Instant::now();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        for _ in 0..10 {
+            tx.unbounded_send(Event::from(LogEvent::from("event")).into())
+                .unwrap();
+        }
+
+        // The burst is consumed right away.
+        sleep(Duration::from_millis(1)).await;
+        assert_eq!(total_events.load(Ordering::Acquire), 5);
+
+        // The remaining events are paced at 10 per second.
+        sleep(Duration::from_millis(249)).await;
+        assert_eq!(total_events.load(Ordering::Acquire), 7);
+
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        assert_eq!(total_events.load(Ordering::Acquire), 10);
+        assert!(start.elapsed() >= Duration::from_millis(500));
+    }
+
+    #[test]
+    fn token_bucket_refills_up_to_capacity() {
+        let now = tokio::time::Instant::now();
+        let mut token_bucket = TokenBucket::new(5, 10);
+        token_bucket.last_refill = now;
+
+        assert_eq!(token_bucket.take(5, now), now);
+        assert_eq!(token_bucket.take(1, now), now + Duration::from_millis(100));
+
+        // Idling for longer than it takes to refill the bucket only allows for another burst of its capacity.
+        let later = now + Duration::from_secs(10);
+        assert_eq!(token_bucket.take(5, later), later);
+        assert_eq!(
+            token_bucket.take(2, later),
+            later + Duration::from_millis(200)
+        );
+    }
+
+    #[tokio::test]
+    async fn requests_failure_shutdown_on_rejected_event() {
+        let mut shutdown_requests = crate::signal::shutdown_requests();
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            exit_on_rejected: true,
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let total_events = Arc::clone(&sink.total_events);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        // The event is also sent to another sink, which only rejects its copy once the blackhole
+        // has consumed its own.
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let event = Event::from(LogEvent::from("rejected")).with_batch_notifier(&batch);
+        drop(batch);
+        let other_copy = event.clone();
+        tx.unbounded_send(event.into()).unwrap();
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(total_events.load(Ordering::Acquire), 1);
+        assert_eq!(
+            try_recv_request(&mut shutdown_requests, ShutdownRequest::Failed),
+            Err(TryRecvError::Empty)
+        );
+
+        other_copy
+            .metadata()
+            .finalizers()
+            .update_status(EventStatus::Rejected);
+        drop(other_copy);
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+        assert_eq!(
+            try_recv_request(&mut shutdown_requests, ShutdownRequest::Failed),
+            Ok(())
+        );
+
+        // Events are still consumed until the topology has shut down.
+        drop(tx);
+        sink.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn ignores_delivered_event_when_exiting_on_rejected() {
+        let mut shutdown_requests = crate::signal::shutdown_requests();
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            exit_on_rejected: true,
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let event = Event::from(LogEvent::from("delivered")).with_batch_notifier(&batch);
+        drop(batch);
+        tx.unbounded_send(event.into()).unwrap();
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+        assert_eq!(
+            try_recv_request(&mut shutdown_requests, ShutdownRequest::Failed),
+            Err(TryRecvError::Empty)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_shutdown_after_idle_timeout() {
+        let mut shutdown_requests = crate::signal::shutdown_requests();
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            shutdown_after_idle_secs: Some(5),
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let total_events = Arc::clone(&sink.total_events);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        // Receiving events restarts the idle window.
+        sleep(Duration::from_secs(4)).await;
+        tx.unbounded_send(Event::from(LogEvent::from("first")).into())
+            .unwrap();
+        sleep(Duration::from_secs(4)).await;
+        assert_eq!(
+            try_recv_request(&mut shutdown_requests, ShutdownRequest::Completed),
+            Err(TryRecvError::Empty)
+        );
+
+        sleep(Duration::from_secs(2)).await;
+        assert_eq!(
+            try_recv_request(&mut shutdown_requests, ShutdownRequest::Completed),
+            Ok(())
+        );
+
+        // Events are still consumed until the topology has shut down.
+        tx.unbounded_send(Event::from(LogEvent::from("second")).into())
+            .unwrap();
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        assert_eq!(total_events.load(Ordering::Acquire), 2);
+        assert_eq!(
+            try_recv_request(&mut shutdown_requests, ShutdownRequest::Completed),
+            Err(TryRecvError::Empty)
+        );
+    }
+
+    #[tokio::test]
+    async fn counts_events_without_finalizers() {
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            require_finalizers: true,
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let missing_finalizers = Arc::clone(&sink.missing_finalizers);
+        let total_events = Arc::clone(&sink.total_events);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let finalized = Event::from(LogEvent::from("finalized")).with_batch_notifier(&batch);
+        drop(batch);
+        tx.unbounded_send(finalized.into()).unwrap();
+        for message in ["first", "second"] {
+            tx.unbounded_send(Event::from(LogEvent::from(message)).into())
+                .unwrap();
+        }
+
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        assert_eq!(missing_finalizers.load(Ordering::Acquire), 2);
+        // Events without finalizers are still consumed.
+        assert_eq!(total_events.load(Ordering::Acquire), 3);
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+    }
+
+    #[tokio::test]
+    async fn counts_bytes_of_consumed_events() {
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let total_events = Arc::clone(&sink.total_events);
+        let total_raw_bytes = Arc::clone(&sink.total_raw_bytes);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        let long = "long".repeat(256);
+        let mut expected = 0;
+        for message in ["short", long.as_str()] {
+            let events = EventArray::from(Event::from(LogEvent::from(message)));
+            expected += events.size_of();
+            tx.unbounded_send(events).unwrap();
+        }
+
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        assert_eq!(total_events.load(Ordering::Acquire), 2);
+        assert!(expected > 1024);
+        assert_eq!(total_raw_bytes.load(Ordering::Acquire), expected);
+    }
+
+    #[tokio::test]
+    async fn writes_events_to_temp_file() {
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            write_to_temp_file: true,
+            ..Default::default()
+        };
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_owned();
+        let sink = BlackholeSink::new(config).with_temp_file(file);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        for message in ["first", "second", "third"] {
+            tx.unbounded_send(Event::from(LogEvent::from(message)).into())
+                .unwrap();
+        }
+        sleep(Duration::from_millis(100)).await;
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        let event: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(event["message"], "second");
+
+        // The file is deleted once the sink is done.
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn snapshots_counters_on_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshots.csv");
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            snapshot_to: Some(path.clone()),
+            snapshot_interval_secs: 2,
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        tx.unbounded_send(Event::from(LogEvent::from("event")).into())
+            .unwrap();
+        sleep(Duration::from_millis(100)).await;
+        assert!(!path.exists());
+
+        // Snapshots are taken 2 and 4 seconds after the sink started.
+        sleep(Duration::from_secs(5)).await;
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], SNAPSHOT_HEADER.trim_end());
+        for row in &lines[1..] {
+            let fields = row.split(',').collect::<Vec<_>>();
+            assert_eq!(fields.len(), 4);
+            assert!(chrono::DateTime::parse_from_rfc3339(fields[0]).is_ok());
+            assert_eq!(fields[1], "1");
+            assert!(fields[2].parse::<usize>().unwrap() > 0);
+        }
+        assert_eq!(lines[1].split(',').nth(3), Some("0.500"));
+        assert_eq!(lines[2].split(',').nth(3), Some("0.000"));
+
+        // A final snapshot is taken when the sink shuts down.
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        sleep(Duration::from_millis(100)).await;
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 4);
+    }
+
+    #[tokio::test]
+    async fn counts_events_per_type() {
+        let _ = crate::metrics::init_test();
+        let received = |event_type: &str| {
+            crate::metrics::Controller::get()
+                .unwrap()
+                .capture_metrics()
+                .into_iter()
+                .filter(|metric| {
+                    metric.name() == "component_received_events_total"
+                        && metric.tag_value("event_type").as_deref() == Some(event_type)
+                })
+                .map(|metric| match metric.value() {
+                    MetricValue::Counter { value } => *value,
+                    value => panic!("Unexpected metric value {:?}", value),
+                })
+                .sum::<f64>()
+        };
+        let before = ["log", "metric", "trace"].map(received);
+
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            per_type_metrics: true,
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        let logs = vec![LogEvent::from("first"), LogEvent::from("second")];
+        tx.unbounded_send(logs.into()).unwrap();
+        let metrics = (0..3)
+            .map(|_| {
+                Metric::new(
+                    "counter",
+                    MetricKind::Incremental,
+                    MetricValue::Counter { value: 1.0 },
+                )
+            })
+            .collect::<Vec<_>>();
+        tx.unbounded_send(metrics.into()).unwrap();
+        tx.unbounded_send(Event::from(TraceEvent::from(LogEvent::from("trace"))).into())
+            .unwrap();
+
+        drop(tx);
+        sink.await.unwrap().unwrap();
+        let after = ["log", "metric", "trace"].map(received);
+        assert_eq!(after[0] - before[0], 2.0);
+        assert_eq!(after[1] - before[1], 3.0);
+        assert_eq!(after[2] - before[2], 1.0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reports_ack_latency_of_delayed_acks() {
+        let config = BlackholeConfig {
+            print_interval_secs: 0,
+            ack_delay_secs: Some(0.5),
+            report_ack_latency: true,
+            ..Default::default()
+        };
+        let sink = BlackholeSink::new(config);
+        let ack_latencies = Arc::clone(sink.ack_latencies.as_ref().unwrap());
+        let (tx, rx) = mpsc::unbounded();
+        let sink = tokio::spawn(Box::new(sink).run(rx.boxed()));
+
+        let mut receivers = Vec::new();
+        for _ in 0..10 {
+            let (batch, receiver) = BatchNotifier::new_with_receiver();
+            let event = Event::from(LogEvent::from("event")).with_batch_notifier(&batch);
+            tx.unbounded_send(event.into()).unwrap();
+            receivers.push(receiver);
+            sleep(Duration::from_millis(100)).await;
+        }
+        for receiver in receivers {
+            assert_eq!(receiver.await, BatchStatus::Delivered);
+        }
+
+        let sketch = ack_latencies.lock().unwrap();
+        assert_eq!(sketch.count(), 10);
+        for quantile in [0.1, 0.5, 0.9] {
+            let latency = sketch.quantile(quantile).unwrap();
+            assert!((latency - 0.5).abs() < 0.01, "{}", latency);
+        }
+        drop(sketch);
+
+        drop(tx);
+        sink.await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn smoothed_rate_converges_after_step_change() {
+        let mut smoothed_rate = SmoothedRate::new(0.5);
+        let mut now = smoothed_rate.last_sample;
+        let mut total = 0;
+
+        for _ in 0..10 {
+            now += Duration::from_secs(1);
+            total += 100;
+            assert_eq!(smoothed_rate.update(total, now), 100.0);
+        }
+
+        // The rate steps up to 1000 events per second.
+        let mut previous = 100.0;
+        for _ in 0..20 {
+            now += Duration::from_secs(1);
+            total += 1000;
+            let average = smoothed_rate.update(total, now);
+            assert!(average > previous && average <= 1000.0);
+            previous = average;
+        }
+        assert!((1000.0 - previous).abs() < 0.01);
+    }
+}
@@ -3,6 +3,7 @@ use std::{io, sync::Arc};
 use bytes::Bytes;
 use codecs::JsonSerializer;
 use lookup::lookup_v2::OwnedSegment;
+use vector_buffers::EventCount;
 use vector_core::ByteSizeOf;
 
 use crate::{
@@ -23,6 +24,12 @@ impl Finalizable for DatadogEventsRequest {
     }
 }
 
+impl EventCount for DatadogEventsRequest {
+    fn event_count(&self) -> usize {
+        1
+    }
+}
+
 impl ByteSizeOf for DatadogEventsRequest {
     fn allocated_bytes(&self) -> usize {
         self.body.allocated_bytes() + self.metadata.finalizers.allocated_bytes()
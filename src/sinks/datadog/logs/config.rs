@@ -205,6 +205,15 @@ impl SinkConfig for DatadogLogsConfig {
         Input::log().with_schema_requirement(requirement)
     }
 
+    fn generate_schema_hint(&self) -> Option<crate::config::schema::Options> {
+        // The schema requirement above is only checked when schema validation is enabled.
+        Some(crate::config::schema::Options {
+            enabled: true,
+            validation: true,
+            ..Default::default()
+        })
+    }
+
     fn sink_type(&self) -> &'static str {
         "datadog_logs"
     }
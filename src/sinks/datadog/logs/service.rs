@@ -12,6 +12,7 @@ use http::{
 use hyper::Body;
 use tower::Service;
 use tracing::Instrument;
+use vector_buffers::EventCount;
 use vector_common::internal_event::BytesSent;
 use vector_core::{
     event::{EventFinalizers, EventStatus, Finalizable},
@@ -54,6 +55,12 @@ impl Finalizable for LogApiRequest {
     }
 }
 
+impl EventCount for LogApiRequest {
+    fn event_count(&self) -> usize {
+        self.batch_size
+    }
+}
+
 #[derive(Debug)]
 pub struct LogApiResponse {
     event_status: EventStatus,
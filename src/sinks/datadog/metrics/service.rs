@@ -10,6 +10,7 @@ use http::{
 use hyper::Body;
 use snafu::ResultExt;
 use tower::Service;
+use vector_buffers::EventCount;
 use vector_common::internal_event::BytesSent;
 use vector_core::{
     event::{EventFinalizers, EventStatus, Finalizable},
@@ -111,6 +112,12 @@ impl Finalizable for DatadogMetricsRequest {
     }
 }
 
+impl EventCount for DatadogMetricsRequest {
+    fn event_count(&self) -> usize {
+        self.batch_size
+    }
+}
+
 // Generalized wrapper around the raw response from Hyper.
 #[derive(Debug)]
 pub struct DatadogMetricsResponse {
@@ -9,6 +9,7 @@ use http::{Request, StatusCode, Uri};
 use hyper::Body;
 use snafu::ResultExt;
 use tower::Service;
+use vector_buffers::EventCount;
 use vector_common::internal_event::BytesSent;
 use vector_core::{
     event::{EventFinalizers, EventStatus, Finalizable},
@@ -80,6 +81,12 @@ impl Finalizable for TraceApiRequest {
     }
 }
 
+impl EventCount for TraceApiRequest {
+    fn event_count(&self) -> usize {
+        self.batch_size
+    }
+}
+
 #[derive(Debug)]
 pub struct TraceApiResponse {
     status_code: StatusCode,
@@ -22,7 +22,7 @@ use crate::{
         HealthcheckError,
     },
     test_util::{
-        components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, HTTP_SINK_TAGS},
         random_events_with_stream, random_string, trace_init,
     },
     tls::{self, TlsConfig},
@@ -152,7 +152,7 @@ async fn structures_events_correctly() {
 
     let timestamp = input_event[crate::config::log_schema().timestamp_key()].clone();
 
-    run_and_assert_sink_compliance(
+    run_and_assert_driver_sink_compliance(
         sink,
         stream::once(ready(Event::from(input_event))),
         &HTTP_SINK_TAGS,
@@ -402,9 +402,9 @@ async fn run_insert_tests_with_config(
             events
         });
 
-        run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
     } else {
-        run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
     }
 
     assert_eq!(receiver.try_recv(), Ok(batch_status));
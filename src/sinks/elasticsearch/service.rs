@@ -11,6 +11,7 @@ use futures::future::BoxFuture;
 use http::{Response, Uri};
 use hyper::{service::Service, Body, Request};
 use tower::ServiceExt;
+use vector_buffers::EventCount;
 use vector_core::{internal_event::EventsSent, stream::DriverResponse, ByteSizeOf};
 
 use crate::sinks::elasticsearch::sign_request;
@@ -50,6 +51,12 @@ impl Finalizable for ElasticsearchRequest {
     }
 }
 
+impl EventCount for ElasticsearchRequest {
+    fn event_count(&self) -> usize {
+        self.batch_size
+    }
+}
+
 #[derive(Clone)]
 pub struct ElasticsearchService {
     batch_service: HttpBatchService<
@@ -401,7 +401,7 @@ mod tests {
 
     use crate::event::LogEvent;
     use crate::test_util::{
-        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, SINK_TAGS},
         http::{always_200_response, spawn_blackhole_http_server},
     };
 
@@ -428,7 +428,7 @@ mod tests {
             .expect("failed to build sink");
 
         let event = Event::Log(LogEvent::from("simple message"));
-        run_and_assert_sink_compliance(sink, stream::once(ready(event)), &SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &SINK_TAGS).await;
     }
 
     #[test]
@@ -8,6 +8,7 @@ use http::{
 };
 use hyper::Body;
 use tower::Service;
+use vector_buffers::EventCount;
 use vector_common::internal_event::BytesSent;
 use vector_core::{internal_event::EventsSent, stream::DriverResponse};
 
@@ -50,6 +51,12 @@ impl Finalizable for GcsRequest {
     }
 }
 
+impl EventCount for GcsRequest {
+    fn event_count(&self) -> usize {
+        self.metadata.event_count()
+    }
+}
+
 // Settings required to produce a request that do not change per
 // request. All possible values are pre-computed for direct use in
 // producing a request.
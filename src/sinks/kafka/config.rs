@@ -64,6 +64,12 @@ pub struct KafkaSinkConfig {
     #[serde(default = "default_message_timeout_ms")]
     pub message_timeout_ms: u64,
 
+    /// The interval, in milliseconds, at which `librdkafka` reports the statistics translated into internal metrics.
+    ///
+    /// Set to `0` to disable the statistics.
+    #[serde(default = "default_statistics_interval_ms")]
+    pub statistics_interval_ms: u64,
+
     /// A map of advanced options to pass directly to the underlying `librdkafka` client.
     ///
     /// For more information on configuration options, see [Configuration properties][config_props_docs].
@@ -95,6 +101,10 @@ const fn default_message_timeout_ms() -> u64 {
     300000 // default in librdkafka
 }
 
+const fn default_statistics_interval_ms() -> u64 {
+    1000
+}
+
 /// Used to determine the options to set in configs, since both Kafka consumers and producers have
 /// unique options, they use the same struct, and the error if given the wrong options.
 #[derive(Debug, PartialOrd, PartialEq)]
@@ -109,7 +119,10 @@ impl KafkaSinkConfig {
         client_config
             .set("bootstrap.servers", &self.bootstrap_servers)
             .set("socket.timeout.ms", &self.socket_timeout_ms.to_string())
-            .set("statistics.interval.ms", "1000");
+            .set(
+                "statistics.interval.ms",
+                &self.statistics_interval_ms.to_string(),
+            );
 
         self.auth.apply(&mut client_config)?;
 
@@ -207,6 +220,7 @@ impl GenerateConfig for KafkaSinkConfig {
             auth: Default::default(),
             socket_timeout_ms: default_socket_timeout_ms(),
             message_timeout_ms: default_message_timeout_ms(),
+            statistics_interval_ms: default_statistics_interval_ms(),
             librdkafka_options: Default::default(),
             headers_key: None,
             acknowledgements: Default::default(),
@@ -9,6 +9,7 @@ use rdkafka::{
     util::Timeout,
 };
 use tower::Service;
+use vector_buffers::EventCount;
 use vector_core::{
     internal_event::{BytesSent, EventsSent},
     stream::DriverResponse,
@@ -16,6 +17,7 @@ use vector_core::{
 
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
+    internal_events::KafkaDeliveryError,
     kafka::KafkaStatisticsContext,
 };
 
@@ -57,6 +59,12 @@ impl Finalizable for KafkaRequest {
     }
 }
 
+impl EventCount for KafkaRequest {
+    fn event_count(&self) -> usize {
+        1
+    }
+}
+
 pub struct KafkaService {
     kafka_producer: FutureProducer<KafkaStatisticsContext>,
 }
@@ -106,7 +114,13 @@ impl Service<KafkaRequest> for KafkaService {
                         event_byte_size: request.event_byte_size,
                     })
                 }
-                Err((kafka_err, _original_record)) => Err(kafka_err),
+                Err((kafka_err, _original_record)) => {
+                    emit!(KafkaDeliveryError {
+                        topic: &request.metadata.topic,
+                        error: &kafka_err,
+                    });
+                    Err(kafka_err)
+                }
             };
             result
         })
@@ -33,7 +33,7 @@ mod integration_test {
             VectorSink,
         },
         test_util::{
-            components::{run_and_assert_sink_compliance, SINK_TAGS},
+            components::{run_and_assert_driver_sink_compliance, SINK_TAGS},
             random_lines_with_stream, random_string, wait_for,
         },
         tls::{TlsConfig, TlsEnableableConfig, TEST_PEM_INTERMEDIATE_CA_PATH},
@@ -63,6 +63,7 @@ mod integration_test {
             auth: KafkaAuthConfig::default(),
             socket_timeout_ms: 60000,
             message_timeout_ms: 300000,
+            statistics_interval_ms: 1000,
             librdkafka_options: HashMap::new(),
             headers_key: None,
             acknowledgements: Default::default(),
@@ -117,6 +118,7 @@ mod integration_test {
             },
             socket_timeout_ms: 60000,
             message_timeout_ms: 300000,
+            statistics_interval_ms: 1000,
             batch,
             librdkafka_options,
             headers_key: None,
@@ -249,6 +251,7 @@ mod integration_test {
             auth: kafka_auth.clone(),
             socket_timeout_ms: 60000,
             message_timeout_ms: 300000,
+            statistics_interval_ms: 1000,
             librdkafka_options: HashMap::new(),
             headers_key: Some(headers_key.clone()),
             acknowledgements: Default::default(),
@@ -276,7 +279,7 @@ mod integration_test {
             });
             events
         });
-        run_and_assert_sink_compliance(sink, input_events, &SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, input_events, &SINK_TAGS).await;
         assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
         // read back everything from the beginning
@@ -9,7 +9,9 @@ use crate::{
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     http::{Auth, HttpClient, MaybeAuth},
     sinks::{
-        util::{BatchConfig, Compression, SinkBatchSettings, TowerRequestConfig, UriSerde},
+        util::{
+            Compression, PartitionedBatchConfig, SinkBatchSettings, TowerRequestConfig, UriSerde,
+        },
         VectorSink,
     },
     template::Template,
@@ -76,7 +78,7 @@ pub struct LokiConfig {
 
     #[configurable(derived)]
     #[serde(default)]
-    pub batch: BatchConfig<LokiDefaultBatchSettings>,
+    pub batch: PartitionedBatchConfig<LokiDefaultBatchSettings>,
 
     #[configurable(derived)]
     pub tls: Option<TlsConfig>,
@@ -12,7 +12,7 @@ use crate::{
     sinks::{util::test::load_sink, VectorSink},
     template::Template,
     test_util::{
-        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, SINK_TAGS},
         generate_events_with_stream, generate_lines_with_stream, random_lines,
     },
 };
@@ -65,7 +65,7 @@ async fn text() {
 
     let (batch, mut receiver) = BatchNotifier::new_with_receiver();
     let (lines, events) = generate_lines_with_stream(line_generator, 10, Some(batch));
-    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
@@ -83,7 +83,7 @@ async fn json() {
 
     let (batch, mut receiver) = BatchNotifier::new_with_receiver();
     let (lines, events) = generate_events_with_stream(event_generator, 10, Some(batch));
-    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
@@ -109,7 +109,7 @@ async fn json_nested_fields() {
         event
     };
     let (lines, events) = generate_events_with_stream(generator, 10, Some(batch));
-    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
@@ -128,7 +128,7 @@ async fn logfmt() {
 
     let (batch, mut receiver) = BatchNotifier::new_with_receiver();
     let (lines, events) = generate_events_with_stream(event_generator, 10, Some(batch));
-    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
@@ -171,7 +171,7 @@ async fn many_streams() {
     };
     let (lines, events) = generate_events_with_stream(generator, 10, Some(batch));
 
-    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
@@ -230,7 +230,7 @@ async fn interpolate_stream_key() {
     };
     let (lines, events) = generate_events_with_stream(generator, 10, Some(batch));
 
-    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
@@ -285,7 +285,7 @@ async fn many_tenants() {
         log.insert("tenant_id", if i % 2 == 0 { "tenant1" } else { "tenant2" });
     }
 
-    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
 
@@ -452,11 +452,11 @@ async fn test_out_of_order_events(
         Template::try_from("test_name").unwrap(),
         Template::try_from(stream.to_string()).unwrap(),
     );
-    config.batch.max_events = Some(batch_size);
-    config.batch.max_bytes = Some(4_000_000);
+    config.batch.base.max_events = Some(batch_size);
+    config.batch.base.max_bytes = Some(4_000_000);
 
     let (sink, _) = config.build(cx).await.unwrap();
-    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
 
     tokio::time::sleep(tokio::time::Duration::new(1, 0)).await;
 
@@ -6,6 +6,7 @@ use http::StatusCode;
 use snafu::Snafu;
 use tower::Service;
 use tracing::Instrument;
+use vector_buffers::EventCount;
 use vector_common::internal_event::BytesSent;
 use vector_core::{
     event::{EventFinalizers, EventStatus, Finalizable},
@@ -88,6 +89,12 @@ impl Finalizable for LokiRequest {
     }
 }
 
+impl EventCount for LokiRequest {
+    fn event_count(&self) -> usize {
+        self.metadata.event_count()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct LokiService {
     endpoint: UriSerde,
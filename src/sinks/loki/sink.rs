@@ -315,6 +315,8 @@ pub struct LokiSink {
     request_builder: LokiRequestBuilder,
     pub(super) encoder: EventEncoder,
     batch_settings: BatcherSettings,
+    /// The batch settings of the tenants with overridden settings.
+    pub(super) batch_overrides: HashMap<String, BatcherSettings>,
     out_of_order_action: OutOfOrderAction,
     service: Svc<LokiService, LokiRetryLogic>,
 }
@@ -362,7 +364,8 @@ impl LokiSink {
                 remove_label_fields: config.remove_label_fields,
                 remove_timestamp: config.remove_timestamp,
             },
-            batch_settings: config.batch.into_batcher_settings()?,
+            batch_settings: config.batch.batcher_settings()?,
+            batch_overrides: config.batch.batcher_overrides()?,
             out_of_order_action: config.out_of_order_action,
             service,
         })
@@ -371,6 +374,7 @@ impl LokiSink {
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
         let mut encoder = self.encoder.clone();
         let mut filter = RecordFilter::new(self.out_of_order_action);
+        let batch_overrides = self.batch_overrides;
 
         // out_of_order_action's that require a complete ordering are limited to building 1 request
         // at a time
@@ -386,6 +390,13 @@ impl LokiSink {
             .filter_map(|event| async { event })
             .map(|record| filter.filter_record(record))
             .batched_partitioned(RecordPartitioner::default(), self.batch_settings)
+            .with_partition_settings(move |partition: &Option<PartitionKey>| {
+                partition
+                    .as_ref()
+                    .and_then(|partition| partition.tenant_id.as_ref())
+                    .and_then(|tenant_id| batch_overrides.get(tenant_id))
+                    .copied()
+            })
             .filter_map(|(partition, batch)| async {
                 if let Some(partition) = partition {
                     let mut count: usize = 0;
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use futures::StreamExt;
 
 use super::{config::LokiConfig, healthcheck::healthcheck, sink::LokiSink};
@@ -56,6 +58,35 @@ async fn interpolate_labels() {
     assert_eq!(record.labels[3], ("label3".to_string(), "bar".to_string()));
 }
 
+#[tokio::test]
+async fn batch_overrides_per_tenant() {
+    let (config, cx) = load_sink::<LokiConfig>(
+        r#"
+        endpoint = "http://localhost:3100"
+        labels = {label1 = "static"}
+        encoding.codec = "json"
+        tenant_id = "{{ tenant }}"
+        batch.max_events = 1000
+        batch.timeout_secs = 10
+
+        [[batch.overrides]]
+        key = "premium"
+        max_events = 10
+        timeout_secs = 0.5
+    "#,
+    )
+    .unwrap();
+    let client = config.build_client(cx).unwrap();
+    let sink = LokiSink::new(config, client).unwrap();
+
+    let premium = sink.batch_overrides["premium"];
+    assert_eq!(premium.item_limit, 10);
+    assert_eq!(premium.timeout, Duration::from_millis(500));
+    // Settings not set by the override fall back to the sink's settings.
+    assert_eq!(premium.size_limit, 1_000_000);
+    assert!(!sink.batch_overrides.contains_key("standard"));
+}
+
 #[tokio::test]
 async fn use_label_from_dropped_fields() {
     let (config, cx) = load_sink::<LokiConfig>(
@@ -68,6 +68,8 @@ pub mod nats;
 pub mod new_relic;
 #[cfg(feature = "sinks-new_relic_logs")]
 pub mod new_relic_logs;
+#[cfg(feature = "sinks-opentelemetry")]
+pub mod opentelemetry;
 #[cfg(feature = "sinks-papertrail")]
 pub mod papertrail;
 #[cfg(feature = "sinks-prometheus")]
@@ -91,6 +93,8 @@ pub mod splunk_hec;
 pub mod statsd;
 #[cfg(feature = "sinks-vector")]
 pub mod vector;
+#[cfg(feature = "sinks-victorialogs")]
+pub mod victorialogs;
 #[cfg(feature = "sinks-websocket")]
 pub mod websocket;
 
@@ -273,6 +277,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-new_relic_logs")]
     NewrelicLogs(#[configurable(derived)] new_relic_logs::NewRelicLogsConfig),
 
+    /// OpenTelemetry.
+    #[cfg(feature = "sinks-opentelemetry")]
+    Opentelemetry(#[configurable(derived)] opentelemetry::OpentelemetrySinkConfig),
+
     /// Papertrail.
     #[cfg(feature = "sinks-papertrail")]
     Papertrail(#[configurable(derived)] papertrail::PapertrailConfig),
@@ -321,6 +329,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-vector")]
     Vector(#[configurable(derived)] vector::VectorConfig),
 
+    /// VictoriaLogs.
+    #[cfg(feature = "sinks-victorialogs")]
+    Victorialogs(#[configurable(derived)] victorialogs::VictoriaLogsConfig),
+
     /// Websocket.
     #[cfg(feature = "sinks-websocket")]
     Websocket(#[configurable(derived)] websocket::WebSocketSinkConfig),
@@ -13,6 +13,7 @@ use http::{
 use hyper::Body;
 use tower::Service;
 use tracing::Instrument;
+use vector_buffers::EventCount;
 use vector_common::internal_event::BytesSent;
 use vector_core::{
     event::{EventFinalizers, EventStatus, Finalizable},
@@ -41,6 +42,12 @@ impl Finalizable for NewRelicApiRequest {
     }
 }
 
+impl EventCount for NewRelicApiRequest {
+    fn event_count(&self) -> usize {
+        self.metadata.event_count()
+    }
+}
+
 #[derive(Debug)]
 pub struct NewRelicApiResponse {
     event_status: EventStatus,
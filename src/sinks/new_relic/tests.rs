@@ -8,7 +8,7 @@ use crate::{
     config::{GenerateConfig, SinkConfig, SinkContext},
     event::{Event, LogEvent, Metric, MetricKind, MetricValue, Value},
     test_util::{
-        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, SINK_TAGS},
         http::{always_200_response, spawn_blackhole_http_server},
     },
 };
@@ -30,7 +30,7 @@ async fn component_spec_compliance() {
     let (sink, _healthcheck) = config.build(context).await.unwrap();
 
     let event = Event::Log(LogEvent::from("simple message"));
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &SINK_TAGS).await;
 }
 
 #[test]
@@ -0,0 +1,211 @@
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use hyper::client::HttpConnector;
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use indexmap::IndexMap;
+use snafu::ResultExt;
+use tonic::body::BoxBody;
+use tower::ServiceBuilder;
+use vector_config::configurable_component;
+
+use super::{
+    service::{OpentelemetryResponse, OpentelemetryService},
+    sink::OpentelemetrySink,
+    InvalidHeaderNameSnafu, InvalidHeaderValueSnafu, OpentelemetrySinkError,
+};
+use crate::{
+    config::{
+        AcknowledgementsConfig, GenerateConfig, Input, ProxyConfig, SinkConfig, SinkContext,
+        SinkHealthcheckOptions,
+    },
+    opentelemetry::LogService::ExportLogsServiceRequest,
+    sinks::{
+        util::{
+            retries::RetryLogic, BatchConfig, RealtimeEventBasedDefaultBatchSettings,
+            ServiceBuilderExt, TowerRequestConfig, UriSerde,
+        },
+        Healthcheck, VectorSink,
+    },
+    tls::{tls_connector_builder, MaybeTlsSettings, TlsEnableableConfig},
+};
+
+/// Configuration for the `opentelemetry` sink.
+#[configurable_component(sink)]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct OpentelemetrySinkConfig {
+    /// The endpoint of the OTLP/gRPC receiver to export logs to.
+    ///
+    /// The endpoint should include the port, such as `http://localhost:4317`. Use the `https` scheme to connect with
+    /// TLS.
+    pub endpoint: UriSerde,
+
+    /// Whether or not to compress requests.
+    ///
+    /// If set to `true`, requests will be compressed with [`gzip`][gzip_docs].
+    ///
+    /// [gzip_docs]: https://en.wikipedia.org/wiki/Gzip
+    #[serde(default)]
+    pub compression: bool,
+
+    /// A list of custom headers to add to each export request, sent as gRPC metadata.
+    ///
+    /// This is commonly used to authenticate against OTLP backends, such as with an API key header.
+    #[serde(default)]
+    pub headers: IndexMap<String, String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<RealtimeEventBasedDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    acknowledgements: AcknowledgementsConfig,
+}
+
+impl GenerateConfig for OpentelemetrySinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(r#"endpoint = "http://127.0.0.1:4317""#).unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "opentelemetry")]
+impl SinkConfig for OpentelemetrySinkConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let tls = MaybeTlsSettings::from_config(&self.tls, false)?;
+        let uri = self.endpoint.with_default_parts().uri;
+        let headers = build_headers(&self.headers)?;
+        let client = new_client(&tls, cx.proxy())?;
+
+        let healthcheck_service =
+            OpentelemetryService::new(client.clone(), uri.clone(), headers.clone(), false)?;
+        let healthcheck = healthcheck(healthcheck_service, cx.healthcheck);
+
+        let service = OpentelemetryService::new(client, uri, headers, self.compression)?;
+        let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
+        let batch_settings = self.batch.into_batcher_settings()?;
+
+        let service = ServiceBuilder::new()
+            .settings(request_settings, OpentelemetryRetryLogic)
+            .service(service);
+
+        let sink = OpentelemetrySink {
+            batch_settings,
+            service,
+        };
+
+        Ok((
+            VectorSink::from_event_streamsink(sink),
+            Box::pin(healthcheck),
+        ))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn sink_type(&self) -> &'static str {
+        "opentelemetry"
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+/// Checks that the receiver accepts exports, by exporting an empty request.
+///
+/// OTLP has no health check service, but receivers accept empty export requests.
+async fn healthcheck(
+    mut service: OpentelemetryService,
+    options: SinkHealthcheckOptions,
+) -> crate::Result<()> {
+    if !options.enabled {
+        return Ok(());
+    }
+
+    service
+        .client
+        .export(ExportLogsServiceRequest::default())
+        .await
+        .map(|_| ())
+        .map_err(|source| OpentelemetrySinkError::Request { source }.into())
+}
+
+pub(super) fn build_headers(headers: &IndexMap<String, String>) -> crate::Result<HeaderMap> {
+    let mut map = HeaderMap::with_capacity(headers.len());
+    for (name, value) in headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .with_context(|_| InvalidHeaderNameSnafu { name })?;
+        let value = HeaderValue::from_bytes(value.as_bytes())
+            .with_context(|_| InvalidHeaderValueSnafu { name })?;
+        map.insert(header_name, value);
+    }
+    Ok(map)
+}
+
+fn new_client(
+    tls_settings: &MaybeTlsSettings,
+    proxy_config: &ProxyConfig,
+) -> crate::Result<hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>> {
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+
+    let tls = tls_connector_builder(tls_settings)?;
+    let mut https = HttpsConnector::with_connector(http, tls)?;
+
+    let settings = tls_settings.tls().cloned();
+    https.set_callback(move |c, _uri| {
+        if let Some(settings) = &settings {
+            settings.apply_connect_configuration(c);
+        }
+
+        Ok(())
+    });
+
+    let mut proxy = ProxyConnector::new(https).unwrap();
+    proxy_config.configure(&mut proxy)?;
+
+    Ok(hyper::Client::builder().http2_only(true).build(proxy))
+}
+
+#[derive(Debug, Clone)]
+struct OpentelemetryRetryLogic;
+
+impl RetryLogic for OpentelemetryRetryLogic {
+    type Error = OpentelemetrySinkError;
+    type Response = OpentelemetryResponse;
+
+    fn is_retriable_error(&self, err: &Self::Error) -> bool {
+        use tonic::Code::*;
+
+        match err {
+            // Retryable codes taken from
+            //
+            // <https://github.com/open-telemetry/opentelemetry-proto/blob/main/docs/specification.md#failures>
+            OpentelemetrySinkError::Request { source } => matches!(
+                source.code(),
+                Cancelled
+                    | DeadlineExceeded
+                    | ResourceExhausted
+                    | Aborted
+                    | OutOfRange
+                    | Unavailable
+                    | DataLoss
+            ),
+            _ => false,
+        }
+    }
+}
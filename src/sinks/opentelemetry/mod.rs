@@ -0,0 +1,44 @@
+//! OpenTelemetry sink
+//!
+//! This sink exports logs to an OTLP receiver over gRPC, such as the OpenTelemetry collector or an
+//! OTLP-compatible backend.
+//!
+//! <https://opentelemetry.io/docs/reference/specification/protocol/otlp/#otlpgrpc>
+//!
+//! Events are converted back into log records with the reverse of the mapping used by the
+//! `opentelemetry` source, and each batch is sent as a single `ExportLogsServiceRequest`, holding the
+//! log records of each resource and instrumentation scope together.
+mod config;
+mod service;
+mod sink;
+#[cfg(test)]
+mod tests;
+
+use snafu::Snafu;
+
+pub use self::config::OpentelemetrySinkConfig;
+
+use crate::config::SinkDescription;
+
+inventory::submit! {
+    SinkDescription::new::<OpentelemetrySinkConfig>("opentelemetry")
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub))]
+pub enum OpentelemetrySinkError {
+    #[snafu(display("Request failed: {}", source))]
+    Request { source: tonic::Status },
+
+    #[snafu(display("{}: {}", source, name))]
+    InvalidHeaderName {
+        name: String,
+        source: http::header::InvalidHeaderName,
+    },
+
+    #[snafu(display("{} for header {}", source, name))]
+    InvalidHeaderValue {
+        name: String,
+        source: http::header::InvalidHeaderValue,
+    },
+}
@@ -0,0 +1,160 @@
+use std::task::{Context, Poll};
+
+use futures::{future::BoxFuture, TryFutureExt};
+use http::{HeaderMap, Uri};
+use hyper::client::HttpConnector;
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use prost::Message;
+use tonic::{body::BoxBody, IntoRequest};
+use vector_buffers::EventCount;
+use vector_core::{internal_event::EventsSent, stream::DriverResponse};
+
+use super::OpentelemetrySinkError;
+use crate::{
+    event::{EventFinalizers, EventStatus, Finalizable},
+    internal_events::{EndpointBytesSent, OpentelemetryRecordsRejected},
+    opentelemetry::LogService::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+    sinks::util::{grpc::HyperSvc, uri},
+    Error,
+};
+
+#[derive(Clone, Debug)]
+pub struct OpentelemetryService {
+    pub client: LogsServiceClient<HyperSvc>,
+    pub protocol: String,
+    pub endpoint: String,
+}
+
+pub struct OpentelemetryResponse {
+    events_count: usize,
+    events_byte_size: usize,
+    /// The number of log records the receiver reported as rejected in a partial success.
+    rejected_count: usize,
+}
+
+impl DriverResponse for OpentelemetryResponse {
+    fn event_status(&self) -> EventStatus {
+        // Each event is sent as a single log record, so the request is only rejected as a whole
+        // when the receiver rejected all of them.
+        if self.rejected_count > 0 && self.rejected_count >= self.events_count {
+            EventStatus::Rejected
+        } else {
+            EventStatus::Delivered
+        }
+    }
+
+    fn events_sent(&self) -> EventsSent {
+        let count = self.events_count - self.rejected_count;
+        let byte_size = if self.rejected_count == 0 {
+            self.events_byte_size
+        } else {
+            self.events_byte_size * count / self.events_count
+        };
+        EventsSent {
+            count,
+            byte_size,
+            output: None,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct OpentelemetryRequest {
+    pub request: ExportLogsServiceRequest,
+    pub finalizers: EventFinalizers,
+    pub events_count: usize,
+    pub events_byte_size: usize,
+}
+
+impl Finalizable for OpentelemetryRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl EventCount for OpentelemetryRequest {
+    fn event_count(&self) -> usize {
+        self.events_count
+    }
+}
+
+impl OpentelemetryService {
+    pub fn new(
+        hyper_client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
+        uri: Uri,
+        headers: HeaderMap,
+        compression: bool,
+    ) -> crate::Result<Self> {
+        let transport = HyperSvc::new(hyper_client, &uri, headers)?;
+        let (protocol, endpoint) = uri::protocol_endpoint(uri);
+        let mut client = LogsServiceClient::new(transport);
+
+        if compression {
+            client = client.send_gzip();
+        }
+        Ok(Self {
+            client,
+            protocol,
+            endpoint,
+        })
+    }
+}
+
+impl tower::Service<OpentelemetryRequest> for OpentelemetryService {
+    type Response = OpentelemetryResponse;
+    type Error = Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Readiness check of the client is done through the `export()` call happening inside
+        // `call()`. That check blocks until the client is ready to perform another request.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: OpentelemetryRequest) -> Self::Future {
+        let mut service = self.clone();
+        let OpentelemetryRequest {
+            request,
+            events_count,
+            events_byte_size,
+            ..
+        } = request;
+
+        let byte_size = request.encoded_len();
+        let future = async move {
+            service
+                .client
+                .export(request.into_request())
+                .map_ok(|response| {
+                    emit!(EndpointBytesSent {
+                        byte_size,
+                        protocol: &service.protocol,
+                        endpoint: &service.endpoint,
+                    });
+
+                    let rejected_count = match response.into_inner().partial_success {
+                        Some(partial_success) if partial_success.rejected_log_records > 0 => {
+                            let count =
+                                (partial_success.rejected_log_records as usize).min(events_count);
+                            emit!(OpentelemetryRecordsRejected {
+                                count,
+                                error_message: &partial_success.error_message,
+                            });
+                            count
+                        }
+                        _ => 0,
+                    };
+                    OpentelemetryResponse {
+                        events_count,
+                        events_byte_size,
+                        rejected_count,
+                    }
+                })
+                .map_err(|source| OpentelemetrySinkError::Request { source }.into())
+                .await
+        };
+
+        Box::pin(future)
+    }
+}
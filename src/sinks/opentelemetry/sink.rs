@@ -0,0 +1,70 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use prost::Message;
+use tower::Service;
+use vector_core::{
+    stream::{BatcherSettings, DriverResponse},
+    ByteSizeOf,
+};
+
+use super::service::OpentelemetryRequest;
+use crate::{
+    event::{Event, EventFinalizers, Finalizable},
+    opentelemetry::LogService::ExportLogsServiceRequest,
+    sinks::util::{SinkBuilderExt, StreamSink},
+};
+
+struct EventData {
+    byte_size: usize,
+    finalizers: EventFinalizers,
+    request: ExportLogsServiceRequest,
+}
+
+pub struct OpentelemetrySink<S> {
+    pub batch_settings: BatcherSettings,
+    pub service: S,
+}
+
+impl<S> OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        input
+            .map(|mut event| EventData {
+                byte_size: event.size_of(),
+                finalizers: event.take_finalizers(),
+                request: event.into_log().into(),
+            })
+            .batched(self.batch_settings.into_reducer_config(
+                |data: &EventData| data.request.encoded_len(),
+                |req: &mut OpentelemetryRequest, item: EventData| {
+                    req.events_count += 1;
+                    req.events_byte_size += item.byte_size;
+                    req.finalizers.merge(item.finalizers);
+                    req.request.merge(item.request);
+                },
+            ))
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
@@ -0,0 +1,264 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::{channel::mpsc, stream, StreamExt};
+use http::request::Parts;
+use hyper::Method;
+use prost::Message;
+use vector_core::event::{BatchNotifier, BatchStatus};
+
+use super::config::OpentelemetrySinkConfig;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    event::{Event, LogEvent},
+    metrics::Controller,
+    opentelemetry::{
+        Common::any_value::Value as PBValue,
+        LogService::{
+            ExportLogsPartialSuccess, ExportLogsServiceRequest, ExportLogsServiceResponse,
+        },
+    },
+    sinks::util::test::build_test_server_generic,
+    test_util::{
+        components::{init_test, run_and_assert_driver_sink_compliance, HTTP_SINK_TAGS},
+        next_addr,
+    },
+};
+
+// one byte for the compression flag plus four bytes for the length
+const GRPC_HEADER_SIZE: usize = 5;
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<OpentelemetrySinkConfig>();
+}
+
+fn event(message: &str, batch: &BatchNotifier) -> Event {
+    let mut log = LogEvent::from(message).with_batch_notifier(batch);
+    log.insert("severity_text", "WARN");
+    log.insert("severity_number", 13);
+    log.insert("trace_id", "4ac52aadf321c2e531db005df08792f5");
+    log.insert("span_id", "0b9e4bda2a55530d");
+    log.insert("attributes.user", "frank");
+    log.insert("resources.service", "checkout");
+    log.into()
+}
+
+#[tokio::test]
+async fn exports_logs() {
+    let in_addr = next_addr();
+    let config: OpentelemetrySinkConfig = toml::from_str(&format!(
+        r#"endpoint = "http://{}/"
+        headers.x-api-key = "secret""#,
+        in_addr
+    ))
+    .unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .header("grpc-status", "0") // OK
+            .header("content-type", "application/grpc")
+            .body(hyper::Body::from(encode_body(
+                ExportLogsServiceResponse::default(),
+            )))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![event("first", &batch), event("second", &batch)];
+    drop(batch);
+
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let requests = get_received(rx, |parts| {
+        assert_eq!(Method::POST, parts.method);
+        assert_eq!(
+            "/opentelemetry.proto.collector.logs.v1.LogsService/Export",
+            parts.uri.path()
+        );
+        assert_eq!(parts.headers["x-api-key"], "secret");
+    })
+    .await;
+    assert_eq!(requests.len(), 1);
+
+    // Both records share their resource and scope.
+    let resource_logs = &requests[0].resource_logs;
+    assert_eq!(resource_logs.len(), 1);
+    let resource = resource_logs[0].resource.as_ref().unwrap();
+    assert_eq!(resource.attributes[0].key, "service");
+    assert_eq!(resource_logs[0].scope_logs.len(), 1);
+    assert_eq!(resource_logs[0].scope_logs[0].log_records.len(), 2);
+
+    let record = &resource_logs[0].scope_logs[0].log_records[0];
+    assert_eq!(
+        record.body.as_ref().unwrap().value,
+        Some(PBValue::StringValue("first".into()))
+    );
+    assert_eq!(record.severity_text, "WARN");
+    assert_eq!(record.severity_number, 13);
+    assert_eq!(
+        record.trace_id,
+        hex::decode("4ac52aadf321c2e531db005df08792f5").unwrap()
+    );
+    assert_eq!(record.span_id, hex::decode("0b9e4bda2a55530d").unwrap());
+    assert_eq!(record.attributes[0].key, "user");
+}
+
+#[tokio::test]
+async fn acknowledges_error() {
+    let in_addr = next_addr();
+    let config: OpentelemetrySinkConfig =
+        toml::from_str(&format!(r#"endpoint = "http://{}/""#, in_addr)).unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (_rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .header("grpc-status", "3") // invalid argument
+            .header("content-type", "application/grpc")
+            .body(tonic::body::empty_body())
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![event("first", &batch)];
+    drop(batch);
+
+    sink.run(stream::iter(events))
+        .await
+        .expect("Running sink failed");
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+}
+
+async fn export_with_partial_success(events_count: usize, rejected: i64) -> BatchStatus {
+    let in_addr = next_addr();
+    let config: OpentelemetrySinkConfig =
+        toml::from_str(&format!(r#"endpoint = "http://{}/""#, in_addr)).unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (_rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .header("grpc-status", "0") // OK
+            .header("content-type", "application/grpc")
+            .body(hyper::Body::from(encode_body(ExportLogsServiceResponse {
+                partial_success: Some(ExportLogsPartialSuccess {
+                    rejected_log_records: rejected,
+                    error_message: "missing timestamp".into(),
+                }),
+            })))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = (0..events_count)
+        .map(|i| event(&i.to_string(), &batch))
+        .collect::<Vec<_>>();
+    drop(batch);
+
+    sink.run(stream::iter(events))
+        .await
+        .expect("Running sink failed");
+    drop(trigger);
+
+    receiver.try_recv().unwrap()
+}
+
+#[tokio::test]
+async fn reports_rejected_records() {
+    init_test();
+
+    // The records accepted by the receiver are delivered, so the request isn't rejected.
+    assert_eq!(
+        export_with_partial_success(2, 1).await,
+        BatchStatus::Delivered
+    );
+    assert_eq!(
+        export_with_partial_success(1, 1).await,
+        BatchStatus::Rejected
+    );
+
+    let metrics = Controller::get().unwrap().capture_metrics();
+    for name in ["component_errors_total", "component_discarded_events_total"] {
+        assert!(
+            metrics.iter().any(|metric| metric.name() == name
+                && metric.tag_value("error_code").as_deref() == Some("records_rejected")),
+            "missing {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn rejects_invalid_headers() {
+    let config: OpentelemetrySinkConfig = toml::from_str(
+        r#"endpoint = "http://localhost:4317/"
+        headers."in valid" = "value""#,
+    )
+    .unwrap();
+    assert!(super::config::build_headers(&config.headers).is_err());
+}
+
+#[test]
+fn invalid_header_value_error_omits_value() {
+    let config: OpentelemetrySinkConfig = toml::from_str(
+        r#"endpoint = "http://localhost:4317/"
+        headers.authorization = "Bearer secret\n""#,
+    )
+    .unwrap();
+    let error = super::config::build_headers(&config.headers)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("authorization"), "{}", error);
+    assert!(!error.contains("secret"), "{}", error);
+}
+
+async fn get_received(
+    rx: mpsc::Receiver<(Parts, Bytes)>,
+    assert_parts: impl Fn(Parts),
+) -> Vec<ExportLogsServiceRequest> {
+    rx.map(|(parts, body)| {
+        assert_parts(parts);
+        ExportLogsServiceRequest::decode(body.slice(GRPC_HEADER_SIZE..)).unwrap()
+    })
+    .collect()
+    .await
+}
+
+// taken from <https://github.com/hyperium/tonic/blob/5aa8ae1fec27377cd4c2a41d309945d7e38087d0/examples/src/grpc-web/client.rs#L45-L75>
+fn encode_body<T>(msg: T) -> Bytes
+where
+    T: prost::Message,
+{
+    let mut buf = BytesMut::with_capacity(1024);
+
+    // first skip past the header
+    // cannot write it yet since we don't know the size of the
+    // encoded message
+    buf.reserve(GRPC_HEADER_SIZE);
+    unsafe {
+        buf.advance_mut(GRPC_HEADER_SIZE);
+    }
+
+    // write the message
+    msg.encode(&mut buf).unwrap();
+
+    // now we know the size of encoded message and can write the
+    // header
+    let len = buf.len() - GRPC_HEADER_SIZE;
+    {
+        let mut buf = &mut buf[..GRPC_HEADER_SIZE];
+
+        // compression flag, 0 means "no compression"
+        buf.put_u8(0);
+
+        buf.put_u32(len as u32);
+    }
+
+    buf.split_to(len + GRPC_HEADER_SIZE).freeze()
+}
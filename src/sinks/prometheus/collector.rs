@@ -445,6 +445,8 @@ mod tests {
     use indoc::indoc;
     use pretty_assertions::assert_eq;
 
+    use vector_core::config::TelemetryConfig;
+
     use super::{super::default_summary_quantiles, *};
     use crate::{
         event::metric::{Metric, MetricKind, MetricValue, StatisticKind},
@@ -732,6 +734,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encodes_internal_histogram_with_custom_buckets_text() {
+        let _ = crate::metrics::init_test();
+        let controller = crate::metrics::Controller::get().unwrap();
+        controller.reset();
+        controller.set_telemetry(&TelemetryConfig {
+            histogram_buckets: Some(vec![0.25, 0.5]),
+            ..Default::default()
+        });
+
+        metrics::histogram!("request_duration_seconds", 0.125);
+        metrics::histogram!("request_duration_seconds", 0.375);
+        let metric = controller
+            .capture_metrics()
+            .into_iter()
+            .find(|metric| metric.name() == "request_duration_seconds")
+            .unwrap()
+            .with_timestamp(Some(timestamp()));
+        controller.set_telemetry(&TelemetryConfig::default());
+
+        assert_eq!(
+            encode_one::<StringCollector>(Some("vector"), &[], &[], &metric),
+            indoc! {r#"
+                # HELP vector_request_duration_seconds request_duration_seconds
+                # TYPE vector_request_duration_seconds histogram
+                vector_request_duration_seconds_bucket{le="0.25"} 1 1612325106789
+                vector_request_duration_seconds_bucket{le="0.5"} 2 1612325106789
+                vector_request_duration_seconds_bucket{le="+Inf"} 2 1612325106789
+                vector_request_duration_seconds_sum 0.5 1612325106789
+                vector_request_duration_seconds_count 2 1612325106789
+            "#}
+        );
+    }
+
     #[test]
     fn encodes_histogram_request_with_extra_infinity_bound() {
         assert_eq!(
@@ -8,6 +8,7 @@ use futures::future::BoxFuture;
 use md5::Digest;
 use tower::Service;
 use tracing::Instrument;
+use vector_buffers::EventCount;
 use vector_core::{
     event::{EventFinalizers, EventStatus, Finalizable},
     internal_event::EventsSent,
@@ -31,6 +32,12 @@ impl Finalizable for S3Request {
     }
 }
 
+impl EventCount for S3Request {
+    fn event_count(&self) -> usize {
+        self.metadata.count
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct S3Metadata {
     pub partition_key: String,
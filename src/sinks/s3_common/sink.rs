@@ -1,4 +1,4 @@
-use std::{fmt, num::NonZeroUsize};
+use std::{collections::HashMap, fmt, num::NonZeroUsize};
 
 use async_trait::async_trait;
 use futures::stream::BoxStream;
@@ -20,6 +20,7 @@ pub struct S3Sink<Svc, RB> {
     request_builder: RB,
     partitioner: KeyPartitioner,
     batcher_settings: BatcherSettings,
+    batcher_overrides: Option<HashMap<String, BatcherSettings>>,
 }
 
 impl<Svc, RB> S3Sink<Svc, RB> {
@@ -34,8 +35,16 @@ impl<Svc, RB> S3Sink<Svc, RB> {
             service,
             request_builder,
             batcher_settings,
+            batcher_overrides: None,
         }
     }
+
+    /// Overrides the batch settings of the partitions whose key matches one of the given keys.
+    #[must_use]
+    pub fn with_batcher_overrides(mut self, overrides: HashMap<String, BatcherSettings>) -> Self {
+        self.batcher_overrides = Some(overrides);
+        self
+    }
 }
 
 impl<Svc, RB> S3Sink<Svc, RB>
@@ -51,12 +60,16 @@ where
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
         let partitioner = self.partitioner;
         let settings = self.batcher_settings;
+        let overrides = self.batcher_overrides.unwrap_or_default();
 
         let builder_limit = NonZeroUsize::new(64);
         let request_builder = self.request_builder;
 
         let sink = input
             .batched_partitioned(partitioner, settings)
+            .with_partition_settings(move |key: &Option<String>| {
+                key.as_ref().and_then(|key| overrides.get(key)).copied()
+            })
             .filter_map(|(key, batch)| async move { key.map(move |k| (k, batch)) })
             .request_builder(builder_limit, request_builder)
             .filter_map(|request| async move {
@@ -1,5 +1,5 @@
 use codecs::{
-    encoding::{Framer, FramingConfig},
+    encoding::{Framer, FramingConfig, SerializerConfig},
     TextSerializerConfig,
 };
 use vector_config::configurable_component;
@@ -136,7 +136,11 @@ impl SinkConfig for SocketSinkConfig {
                 let transformer = encoding.transformer();
                 let serializer = encoding.build()?;
                 let encoder = Encoder::<()>::new(serializer);
-                config.build(transformer, encoder)
+                let chunker = match encoding.config() {
+                    SerializerConfig::Gelf { gelf } => Some(gelf.chunker()?),
+                    _ => None,
+                };
+                config.build(transformer, encoder, chunker)
             }
             #[cfg(unix)]
             Mode::Unix(UnixMode { config, encoding }) => {
@@ -174,7 +178,7 @@ mod test {
         net::{SocketAddr, UdpSocket},
     };
 
-    use codecs::JsonSerializerConfig;
+    use codecs::{encoding::GelfSerializerOptions, JsonSerializerConfig};
     use futures::stream::StreamExt;
     use futures_util::stream;
     use serde_json::Value;
@@ -243,6 +247,60 @@ mod test {
         test_udp(next_addr_v6()).await;
     }
 
+    #[tokio::test]
+    async fn udp_gelf_chunked() {
+        trace_init();
+
+        let addr = next_addr();
+        let receiver = UdpSocket::bind(addr).unwrap();
+
+        let config = SocketSinkConfig {
+            mode: Mode::Udp(UdpMode {
+                config: UdpSinkConfig::from_address(addr.to_string()),
+                encoding: SerializerConfig::Gelf {
+                    gelf: GelfSerializerOptions {
+                        chunk_size: 100,
+                        ..Default::default()
+                    },
+                }
+                .into(),
+            }),
+            acknowledgements: Default::default(),
+        };
+        let context = SinkContext::new_test();
+        let (sink, _healthcheck) = config.build(context).await.unwrap();
+
+        let mut event = LogEvent::from("x".repeat(200));
+        event.insert("host", "example.org");
+        run_and_assert_sink_compliance(sink, stream::once(ready(Event::Log(event))), &SINK_TAGS)
+            .await;
+
+        let mut chunks = Vec::new();
+        let mut buf = [0; 256];
+        loop {
+            let (size, _src_addr) = receiver
+                .recv_from(&mut buf)
+                .expect("Did not receive message");
+            let chunk = buf[..size].to_vec();
+            assert!(size <= 100);
+            assert_eq!(&chunk[..2], &[0x1e, 0x0f]);
+            let count = chunk[11] as usize;
+            chunks.push(chunk);
+            if chunks.len() == count {
+                break;
+            }
+        }
+
+        chunks.sort_by_key(|chunk| chunk[10]);
+        let message = chunks
+            .iter()
+            .flat_map(|chunk| chunk[12..].to_vec())
+            .collect::<Vec<u8>>();
+        let data = serde_json::from_slice::<Value>(&message).expect("Invalid JSON received");
+        assert_eq!(data["short_message"], Value::String("x".repeat(200)));
+        assert_eq!(data["host"], Value::String("example.org".into()));
+    }
+
     #[tokio::test]
     async fn tcp_stream() {
         trace_init();
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use bytes::Bytes;
+use vector_buffers::EventCount;
 use vector_core::{
     event::{EventFinalizers, Finalizable},
     ByteSizeOf,
@@ -38,3 +39,9 @@ impl Finalizable for HecRequest {
         std::mem::take(&mut self.finalizers)
     }
 }
+
+impl EventCount for HecRequest {
+    fn event_count(&self) -> usize {
+        self.events_count
+    }
+}
@@ -22,7 +22,7 @@ use crate::{
     },
     template::Template,
     test_util::{
-        components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+        components::{run_and_assert_driver_sink_compliance, HTTP_SINK_TAGS},
         random_lines_with_stream, random_string,
     },
 };
@@ -132,7 +132,7 @@ async fn splunk_insert_message() {
     let (batch, mut receiver) = BatchNotifier::new_with_receiver();
     let event = LogEvent::from(message.clone()).with_batch_notifier(&batch);
     drop(batch);
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     let entry = find_entry(message.as_str()).await;
@@ -156,7 +156,7 @@ async fn splunk_insert_raw_message() {
     let (batch, mut receiver) = BatchNotifier::new_with_receiver();
     let event = LogEvent::from(message.clone()).with_batch_notifier(&batch);
     drop(batch);
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     let entry = find_entry(message.as_str()).await;
@@ -193,7 +193,7 @@ async fn splunk_insert_source() {
 
     let message = random_string(100);
     let event = Event::Log(LogEvent::from(message.clone()));
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
 
     let entry = find_entry(message.as_str()).await;
 
@@ -210,7 +210,7 @@ async fn splunk_insert_index() {
 
     let message = random_string(100);
     let event = LogEvent::from(message.clone());
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
 
     let entry = find_entry(message.as_str()).await;
 
@@ -230,7 +230,7 @@ async fn splunk_index_is_interpolated() {
     let message = random_string(100);
     let mut event = LogEvent::from(message.clone());
     event.insert("index_name", "custom_index");
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
 
     let entry = find_entry(message.as_str()).await;
 
@@ -246,7 +246,7 @@ async fn splunk_insert_many() {
     let (sink, _) = config.build(cx).await.unwrap();
 
     let (messages, events) = random_lines_with_stream(100, 10, None);
-    run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
 
     assert!(find_entries(messages.as_slice()).await);
 }
@@ -262,7 +262,7 @@ async fn splunk_custom_fields() {
     let message = random_string(100);
     let mut event = LogEvent::from(message.clone());
     event.insert("asdf", "hello");
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
 
     let entry = find_entry(message.as_str()).await;
 
@@ -283,7 +283,7 @@ async fn splunk_hostname() {
     let mut event = LogEvent::from(message.clone());
     event.insert("asdf", "hello");
     event.insert("host", "example.com:1234");
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
 
     let entry = find_entry(message.as_str()).await;
 
@@ -307,7 +307,7 @@ async fn splunk_sourcetype() {
     let message = random_string(100);
     let mut event = LogEvent::from(message.clone());
     event.insert("asdf", "hello");
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
 
     let entry = find_entry(message.as_str()).await;
 
@@ -334,7 +334,7 @@ async fn splunk_configure_hostname() {
     event.insert("asdf", "hello");
     event.insert("host", "example.com:1234");
     event.insert("roast", "beef.example.com:1234");
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
 
     let entry = find_entry(message.as_str()).await;
 
@@ -365,7 +365,7 @@ async fn splunk_indexer_acknowledgements() {
     let (tx, mut rx) = BatchNotifier::new_with_receiver();
     let (messages, events) = random_lines_with_stream(100, 10, Some(tx.clone()));
     drop(tx);
-    run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
 
     assert_eq!(rx.try_recv(), Ok(BatchStatus::Delivered));
     assert!(find_entries(messages.as_slice()).await);
@@ -381,7 +381,7 @@ async fn splunk_indexer_acknowledgements_disabled_on_server() {
     let (tx, mut rx) = BatchNotifier::new_with_receiver();
     let (messages, events) = random_lines_with_stream(100, 10, Some(tx.clone()));
     drop(tx);
-    run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
 
     // With indexer acknowledgements disabled on the server, events are still
     // acknowledged based on 200 OK
@@ -16,7 +16,7 @@ use crate::{
         util::{BatchConfig, Compression, TowerRequestConfig},
     },
     template::Template,
-    test_util::components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+    test_util::components::{run_and_assert_driver_sink_compliance, HTTP_SINK_TAGS},
 };
 
 const USERNAME: &str = "admin";
@@ -79,7 +79,7 @@ async fn splunk_insert_counter_metric() {
     let (batch, mut receiver) = BatchNotifier::new_with_receiver();
     let event = get_counter(batch.clone());
     drop(batch);
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     assert!(
@@ -102,7 +102,7 @@ async fn splunk_insert_gauge_metric() {
     let (batch, mut receiver) = BatchNotifier::new_with_receiver();
     let event = get_gauge(batch.clone());
     drop(batch);
-    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     assert!(
@@ -129,7 +129,7 @@ async fn splunk_insert_multiple_counter_metrics() {
     }
     drop(batch);
 
-    run_and_assert_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     assert!(
@@ -156,7 +156,7 @@ async fn splunk_insert_multiple_gauge_metrics() {
     }
     drop(batch);
 
-    run_and_assert_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
     assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
 
     assert!(
@@ -25,7 +25,7 @@ use crate::{
     },
     internal_events::StatsdInvalidMetricError,
     sinks::util::{
-        buffer::metrics::compress_distribution,
+        buffer::metrics::{compress_distribution, AggregatedSummarySplitter, MetricSplit},
         encode_namespace,
         tcp::TcpSinkConfig,
         udp::{UdpService, UdpSinkConfig},
@@ -265,6 +265,13 @@ impl Encoder<Event> for StatsdEncoder {
                     push_event(&mut buf, metric, val, "s", None);
                 }
             }
+            MetricValue::AggregatedSummary { .. } => {
+                // Summaries are sent as their count and sum, and a gauge of each quantile tagged with it.
+                for metric in AggregatedSummarySplitter.split(metric.clone()) {
+                    self.encode(Event::Metric(metric), bytes)?;
+                }
+                return Ok(());
+            }
             _ => {
                 emit!(StatsdInvalidMetricError {
                     value: metric.value(),
@@ -492,6 +499,31 @@ mod test {
         vector_common::assert_event_data_eq!(metric1, metric2);
     }
 
+    #[test]
+    fn test_encode_summary() {
+        let metric = Metric::new(
+            "requests",
+            MetricKind::Absolute,
+            MetricValue::AggregatedSummary {
+                quantiles: vector_core::quantiles![0.5 => 2.0, 0.99 => 3.0],
+                count: 6,
+                sum: 12.0,
+            },
+        );
+        let mut encoder = StatsdEncoder {
+            default_namespace: None,
+        };
+        let mut frame = BytesMut::new();
+        encoder.encode(Event::Metric(metric), &mut frame).unwrap();
+        assert_eq!(
+            "requests_count:6|c\n\
+             requests:2|g|#quantile:0.5\n\
+             requests:3|g|#quantile:0.99\n\
+             requests_sum:12|c\n",
+            std::str::from_utf8(&frame).unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn test_send_to_statsd() {
         trace_init();
@@ -1,4 +1,4 @@
-use std::{marker::PhantomData, num::NonZeroUsize, time::Duration};
+use std::{collections::HashMap, marker::PhantomData, num::NonZeroUsize, time::Duration};
 
 use derivative::Derivative;
 use snafu::Snafu;
@@ -255,6 +255,66 @@ where
     }
 }
 
+/// Batch settings for a single partition.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct BatchOverride {
+    /// The partition key the settings apply to.
+    ///
+    /// This is matched exactly against the rendered partition key of the sink, such as the tenant
+    /// ID or the key prefix.
+    pub key: String,
+
+    /// The maximum size of a batch, in bytes, before it is flushed.
+    pub max_bytes: Option<usize>,
+
+    /// The maximum size of a batch, in events, before it is flushed.
+    pub max_events: Option<usize>,
+
+    /// The maximum age of a batch, in seconds, before it is flushed.
+    pub timeout_secs: Option<f64>,
+}
+
+/// Event batching behavior, overridable per partition.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+pub struct PartitionedBatchConfig<D: SinkBatchSettings + Clone> {
+    #[serde(flatten)]
+    pub base: BatchConfig<D>,
+
+    /// Batch settings overriding the settings above for the matching partitions.
+    ///
+    /// Settings not set in an override fall back to the settings above.
+    #[serde(default)]
+    pub overrides: Vec<BatchOverride>,
+}
+
+impl<D: SinkBatchSettings + Clone> PartitionedBatchConfig<D> {
+    /// Converts the base settings into [`BatcherSettings`].
+    pub fn batcher_settings(&self) -> Result<BatcherSettings, BatchError> {
+        self.base.into_batcher_settings()
+    }
+
+    /// Converts the overrides into [`BatcherSettings`], keyed by their partition key.
+    pub fn batcher_overrides(&self) -> Result<HashMap<String, BatcherSettings>, BatchError> {
+        let base = self.base.validate()?;
+        self.overrides
+            .iter()
+            .map(|batch_override| {
+                let config: BatchConfig<D> = BatchConfig {
+                    max_bytes: batch_override.max_bytes.or(base.max_bytes),
+                    max_events: batch_override.max_events.or(base.max_events),
+                    timeout_secs: batch_override.timeout_secs.or(base.timeout_secs),
+                    _d: PhantomData,
+                    _s: PhantomData,
+                };
+                let settings = config.into_batcher_settings()?;
+                Ok((batch_override.key.clone(), settings))
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Derivative)]
 #[derivative(Clone(bound = ""))]
 #[derivative(Copy(bound = ""))]
@@ -2,6 +2,7 @@ use std::{fmt, future::Future, hash::Hash, num::NonZeroUsize, pin::Pin, sync::Ar
 
 use futures_util::{stream::Map, Stream, StreamExt};
 use tower::Service;
+use vector_buffers::EventCount;
 use vector_core::{
     event::{Finalizable, Metric},
     partition::Partitioner,
@@ -193,7 +194,7 @@ pub trait SinkBuilderExt: Stream {
     fn into_driver<Svc>(self, service: Svc) -> Driver<Self, Svc>
     where
         Self: Sized,
-        Self::Item: Finalizable,
+        Self::Item: Finalizable + EventCount,
         Svc: Service<Self::Item>,
         Svc::Error: fmt::Debug + 'static,
         Svc::Future: Send + 'static,
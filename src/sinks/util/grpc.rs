@@ -0,0 +1,74 @@
+use std::task::{Context, Poll};
+
+use futures::{
+    future::{self, BoxFuture},
+    FutureExt, TryFutureExt,
+};
+use http::{
+    uri::{Authority, Scheme},
+    HeaderMap, Uri,
+};
+use hyper::client::HttpConnector;
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use snafu::Snafu;
+use tonic::body::BoxBody;
+
+#[derive(Debug, Snafu)]
+pub enum GrpcTransportError {
+    #[snafu(display("URI {} must have a scheme and an authority", uri))]
+    IncompleteUri { uri: Uri },
+}
+
+/// The transport of the `tonic` clients of sinks, sending their requests to the scheme and authority
+/// of an endpoint, along with additional headers.
+#[derive(Clone, Debug)]
+pub struct HyperSvc {
+    scheme: Scheme,
+    authority: Authority,
+    headers: HeaderMap,
+    client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
+}
+
+impl HyperSvc {
+    pub fn new(
+        client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
+        uri: &Uri,
+        headers: HeaderMap,
+    ) -> Result<Self, GrpcTransportError> {
+        match (uri.scheme(), uri.authority()) {
+            (Some(scheme), Some(authority)) => Ok(Self {
+                scheme: scheme.clone(),
+                authority: authority.clone(),
+                headers,
+                client,
+            }),
+            _ => IncompleteUriSnafu { uri: uri.clone() }.fail(),
+        }
+    }
+}
+
+impl tower::Service<hyper::Request<BoxBody>> for HyperSvc {
+    type Response = hyper::Response<hyper::Body>;
+    type Error = crate::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut req: hyper::Request<BoxBody>) -> Self::Future {
+        let mut parts = req.uri().clone().into_parts();
+        parts.scheme = Some(self.scheme.clone());
+        parts.authority = Some(self.authority.clone());
+        match Uri::from_parts(parts) {
+            Ok(uri) => *req.uri_mut() = uri,
+            Err(error) => return future::ready(Err(error.into())).boxed(),
+        }
+        for (name, value) in &self.headers {
+            req.headers_mut().insert(name, value.clone());
+        }
+
+        self.client.request(req).err_into().boxed()
+    }
+}
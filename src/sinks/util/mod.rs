@@ -4,6 +4,8 @@ pub mod buffer;
 pub mod builder;
 pub mod compressor;
 pub mod encoding;
+#[cfg(any(feature = "sinks-opentelemetry", feature = "sinks-vector"))]
+pub mod grpc;
 pub mod http;
 pub mod metadata;
 pub mod normalizer;
@@ -26,9 +28,10 @@ pub mod uri;
 use std::borrow::Cow;
 
 pub use batch::{
-    Batch, BatchConfig, BatchSettings, BatchSize, BulkSizeBasedDefaultBatchSettings, Merged,
-    NoDefaultsBatchSettings, PushResult, RealtimeEventBasedDefaultBatchSettings,
-    RealtimeSizeBasedDefaultBatchSettings, SinkBatchSettings, Unmerged,
+    Batch, BatchConfig, BatchOverride, BatchSettings, BatchSize, BulkSizeBasedDefaultBatchSettings,
+    Merged, NoDefaultsBatchSettings, PartitionedBatchConfig, PushResult,
+    RealtimeEventBasedDefaultBatchSettings, RealtimeSizeBasedDefaultBatchSettings,
+    SinkBatchSettings, Unmerged,
 };
 pub use buffer::{
     json::{BoxedRawValue, JsonArrayBuffer},
@@ -6,7 +6,8 @@ use std::{
 };
 
 use async_trait::async_trait;
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
+use codecs::encoding::GelfChunker;
 use futures::{future::BoxFuture, ready, stream::BoxStream, FutureExt, StreamExt};
 use snafu::{ResultExt, Snafu};
 use tokio::{net::UdpSocket, sync::oneshot, time::sleep};
@@ -21,8 +22,8 @@ use crate::{
     dns,
     event::{Event, EventStatus, Finalizable},
     internal_events::{
-        SocketEventsSent, SocketMode, UdpSendIncompleteError, UdpSocketConnectionError,
-        UdpSocketConnectionEstablished, UdpSocketError,
+        SocketEventsSent, SocketMode, UdpChunkingError, UdpSendIncompleteError,
+        UdpSocketConnectionError, UdpSocketConnectionEstablished, UdpSocketError,
     },
     sinks::{
         util::{retries::ExponentialBackoff, StreamSink},
@@ -85,13 +86,16 @@ impl UdpSinkConfig {
         ))
     }
 
+    /// Builds the sink, sending each encoded event in a datagram, or in the datagrams of its GELF
+    /// chunks if `chunker` is set.
     pub fn build(
         &self,
         transformer: Transformer,
         encoder: impl Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync + 'static,
+        chunker: Option<GelfChunker>,
     ) -> crate::Result<(VectorSink, Healthcheck)> {
         let connector = self.build_connector()?;
-        let sink = UdpSink::new(connector.clone(), transformer, encoder);
+        let sink = UdpSink::new(connector.clone(), transformer, encoder, chunker);
         Ok((
             VectorSink::from_event_streamsink(sink),
             async move { connector.healthcheck().await }.boxed(),
@@ -258,17 +262,41 @@ where
     connector: UdpConnector,
     transformer: Transformer,
     encoder: E,
+    chunker: Option<GelfChunker>,
 }
 
 impl<E> UdpSink<E>
 where
     E: Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync,
 {
-    const fn new(connector: UdpConnector, transformer: Transformer, encoder: E) -> Self {
+    const fn new(
+        connector: UdpConnector,
+        transformer: Transformer,
+        encoder: E,
+        chunker: Option<GelfChunker>,
+    ) -> Self {
         Self {
             connector,
             transformer,
             encoder,
+            chunker,
+        }
+    }
+
+    /// Splits the encoded event into the datagrams it is sent in.
+    fn datagrams(&self, bytes: BytesMut) -> Option<Vec<Bytes>> {
+        match &self.chunker {
+            None => Some(vec![bytes.freeze()]),
+            Some(chunker) => match chunker.chunk(&bytes) {
+                Ok(chunks) => Some(chunks),
+                Err(error) => {
+                    emit!(UdpChunkingError {
+                        error,
+                        data_size: bytes.len(),
+                    });
+                    None
+                }
+            },
         }
     }
 }
@@ -294,8 +322,15 @@ where
                 if encoder.encode(event, &mut bytes).is_err() {
                     continue;
                 }
+                let datagrams = match self.datagrams(bytes) {
+                    Some(datagrams) => datagrams,
+                    None => {
+                        finalizers.update_status(EventStatus::Rejected);
+                        continue;
+                    }
+                };
 
-                match udp_send(&mut socket, &bytes).await {
+                match udp_send_all(&mut socket, &datagrams).await {
                     Ok(()) => {
                         emit!(SocketEventsSent {
                             mode: SocketMode::Udp,
@@ -304,7 +339,7 @@ where
                         });
 
                         emit!(BytesSent {
-                            byte_size: bytes.len(),
+                            byte_size: datagrams.iter().map(Bytes::len).sum(),
                             protocol: "udp",
                         });
                         finalizers.update_status(EventStatus::Delivered);
@@ -322,6 +357,13 @@ where
     }
 }
 
+async fn udp_send_all(socket: &mut UdpSocket, datagrams: &[Bytes]) -> tokio::io::Result<()> {
+    for datagram in datagrams {
+        udp_send(socket, datagram).await?;
+    }
+    Ok(())
+}
+
 async fn udp_send(socket: &mut UdpSocket, buf: &[u8]) -> tokio::io::Result<()> {
     let sent = socket.send(buf).await?;
     if sent != buf.len() {
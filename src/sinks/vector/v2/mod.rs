@@ -35,7 +35,7 @@ mod tests {
         proto::vector as proto,
         sinks::{util::test::build_test_server_generic, vector::v2::config::with_default_scheme},
         test_util::{
-            components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+            components::{run_and_assert_driver_sink_compliance, HTTP_SINK_TAGS},
             next_addr, random_lines_with_stream,
         },
     };
@@ -73,7 +73,7 @@ mod tests {
         let (batch, mut receiver) = BatchNotifier::new_with_receiver();
         let (input_lines, events) = random_lines_with_stream(8, num_lines, Some(batch));
 
-        run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+        run_and_assert_driver_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
         drop(trigger);
 
         assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
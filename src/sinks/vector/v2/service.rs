@@ -1,13 +1,14 @@
 use std::task::{Context, Poll};
 
 use futures::{future::BoxFuture, TryFutureExt};
-use http::Uri;
+use http::{HeaderMap, Uri};
 use hyper::client::HttpConnector;
 use hyper_openssl::HttpsConnector;
 use hyper_proxy::ProxyConnector;
 use prost::Message;
 use proto_event::EventWrapper;
 use tonic::{body::BoxBody, IntoRequest};
+use vector_buffers::EventCount;
 use vector_core::{
     event::proto as proto_event, internal_event::EventsSent, stream::DriverResponse,
 };
@@ -16,7 +17,10 @@ use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
     internal_events::EndpointBytesSent,
     proto::vector as proto_vector,
-    sinks::{util::uri, vector::v2::VectorSinkError},
+    sinks::{
+        util::{grpc::HyperSvc, uri},
+        vector::v2::VectorSinkError,
+    },
     Error,
 };
 
@@ -59,26 +63,30 @@ impl Finalizable for VectorRequest {
     }
 }
 
+impl EventCount for VectorRequest {
+    fn event_count(&self) -> usize {
+        self.events.len()
+    }
+}
+
 impl VectorService {
     pub fn new(
         hyper_client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
         uri: Uri,
         compression: bool,
-    ) -> Self {
-        let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
-        let mut proto_client = proto_vector::Client::new(HyperSvc {
-            uri,
-            client: hyper_client,
-        });
+    ) -> crate::Result<Self> {
+        let transport = HyperSvc::new(hyper_client, &uri, HeaderMap::new())?;
+        let (protocol, endpoint) = uri::protocol_endpoint(uri);
+        let mut proto_client = proto_vector::Client::new(transport);
 
         if compression {
             proto_client = proto_client.send_gzip();
         }
-        Self {
+        Ok(Self {
             client: proto_client,
             protocol,
             endpoint,
-        }
+        })
     }
 }
 
@@ -127,32 +135,3 @@ impl tower::Service<VectorRequest> for VectorService {
         Box::pin(future)
     }
 }
-
-#[derive(Clone, Debug)]
-pub struct HyperSvc {
-    uri: Uri,
-    client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
-}
-
-impl tower::Service<hyper::Request<BoxBody>> for HyperSvc {
-    type Response = hyper::Response<hyper::Body>;
-    type Error = hyper::Error;
-    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
-
-    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        Poll::Ready(Ok(()))
-    }
-
-    fn call(&mut self, mut req: hyper::Request<BoxBody>) -> Self::Future {
-        let uri = Uri::builder()
-            .scheme(self.uri.scheme().unwrap().clone())
-            .authority(self.uri.authority().unwrap().clone())
-            .path_and_query(req.uri().path_and_query().unwrap().clone())
-            .build()
-            .unwrap();
-
-        *req.uri_mut() = uri;
-
-        Box::pin(self.client.request(req))
-    }
-}
@@ -0,0 +1,166 @@
+use futures::future::FutureExt;
+use vector_config::configurable_component;
+
+use super::{
+    encoder::VictoriaLogsEncoder, healthcheck::healthcheck, service::VictoriaLogsService,
+    sink::VictoriaLogsSink,
+};
+use crate::{
+    codecs::Transformer,
+    config::{log_schema, AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    http::{Auth, HttpClient, MaybeAuth},
+    sinks::{
+        util::{BatchConfig, Compression, SinkBatchSettings, TowerRequestConfig, UriSerde},
+        Healthcheck, VectorSink,
+    },
+    tls::{TlsConfig, TlsSettings},
+};
+
+/// Configuration for the `victorialogs` sink.
+#[configurable_component(sink)]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct VictoriaLogsConfig {
+    /// The base URL of the VictoriaLogs instance.
+    ///
+    /// Vector will append `/insert/jsonline` to this.
+    pub endpoint: UriSerde,
+
+    /// The fields of events that form their log stream.
+    ///
+    /// Events with the same values of these fields belong to the same stream. The fields should have a low
+    /// cardinality, such as `host` or `kubernetes.pod_name`.
+    #[serde(default)]
+    pub stream_fields: Vec<String>,
+
+    /// The field of events sent as their message, in the `_msg` field.
+    ///
+    /// By default, the [global `log_schema.message_key` option][global_message_key] is used.
+    ///
+    /// [global_message_key]: https://vector.dev/docs/reference/configuration/global-options/#log_schema.message_key
+    pub message_field: Option<String>,
+
+    /// The field of events sent as their timestamp, in the `_time` field.
+    ///
+    /// By default, the [global `log_schema.timestamp_key` option][global_timestamp_key] is used.
+    ///
+    /// [global_timestamp_key]: https://vector.dev/docs/reference/configuration/global-options/#log_schema.timestamp_key
+    pub timestamp_field: Option<String>,
+
+    /// The ID of the account to ingest events into, sent as the `AccountID` header.
+    ///
+    /// By default, VictoriaLogs ingests events into the account `0`.
+    pub account_id: Option<u32>,
+
+    /// The ID of the project to ingest events into, sent as the `ProjectID` header.
+    ///
+    /// By default, VictoriaLogs ingests events into the project `0`.
+    pub project_id: Option<u32>,
+
+    #[configurable(derived)]
+    #[serde(default = "Compression::gzip_default")]
+    pub compression: Compression,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub encoding: Transformer,
+
+    #[configurable(derived)]
+    pub auth: Option<Auth>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<VictoriaLogsDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    acknowledgements: AcknowledgementsConfig,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VictoriaLogsDefaultBatchSettings;
+
+impl SinkBatchSettings for VictoriaLogsDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = None;
+    const MAX_BYTES: Option<usize> = Some(10_000_000);
+    const TIMEOUT_SECS: f64 = 1.0;
+}
+
+impl GenerateConfig for VictoriaLogsConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"endpoint = "http://localhost:9428"
+            stream_fields = ["host"]"#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "victorialogs")]
+impl SinkConfig for VictoriaLogsConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let tls = TlsSettings::from_options(&self.tls)?;
+        let client = HttpClient::new(tls, cx.proxy())?;
+        let endpoint = self
+            .endpoint
+            .clone()
+            .with_auth(self.auth.choose_one(&self.endpoint.auth)?);
+
+        let healthcheck = healthcheck(endpoint.clone(), client.clone()).boxed();
+
+        let service = VictoriaLogsService::new(
+            client,
+            endpoint,
+            &self.stream_fields,
+            self.account_id,
+            self.project_id,
+        )?;
+        let encoder = VictoriaLogsEncoder {
+            transformer: self.encoding.clone(),
+            message_field: self
+                .message_field
+                .clone()
+                .unwrap_or_else(|| log_schema().message_key().to_owned()),
+            timestamp_field: self
+                .timestamp_field
+                .clone()
+                .unwrap_or_else(|| log_schema().timestamp_key().to_owned()),
+        };
+        let sink = VictoriaLogsSink::new(
+            service,
+            encoder,
+            self.compression,
+            self.request.unwrap_with(&Default::default()),
+            self.batch.into_batcher_settings()?,
+        );
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn sink_type(&self) -> &'static str {
+        "victorialogs"
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
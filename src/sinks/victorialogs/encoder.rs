@@ -0,0 +1,55 @@
+use std::io;
+
+use chrono::SecondsFormat;
+use vector_core::event::{Event, LogEvent, Value};
+
+use crate::{codecs::Transformer, sinks::util::encoding::Encoder};
+
+/// The field VictoriaLogs reads the message of events from.
+pub(super) const MESSAGE_KEY: &str = "_msg";
+
+/// The field VictoriaLogs reads the timestamp of events from.
+pub(super) const TIMESTAMP_KEY: &str = "_time";
+
+/// Encodes events as lines of JSON.
+#[derive(Clone, Debug)]
+pub struct VictoriaLogsEncoder {
+    pub(super) transformer: Transformer,
+    pub(super) message_field: String,
+    pub(super) timestamp_field: String,
+}
+
+impl VictoriaLogsEncoder {
+    /// Moves the message and timestamp of an event to the fields VictoriaLogs reads them from.
+    fn encode_event(&self, mut event: Event) -> LogEvent {
+        self.transformer.transform(&mut event);
+        let mut log = event.into_log();
+
+        if let Some(message) = log.remove(self.message_field.as_str()) {
+            log.insert(MESSAGE_KEY, message);
+        }
+        if let Some(timestamp) = log.remove(self.timestamp_field.as_str()) {
+            let timestamp = match timestamp {
+                Value::Timestamp(timestamp) => timestamp
+                    .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                    .into(),
+                value => value,
+            };
+            log.insert(TIMESTAMP_KEY, timestamp);
+        }
+        log
+    }
+}
+
+impl Encoder<Vec<Event>> for VictoriaLogsEncoder {
+    fn encode_input(&self, events: Vec<Event>, writer: &mut dyn io::Write) -> io::Result<usize> {
+        let mut written = 0;
+        for event in events {
+            let mut line = serde_json::to_vec(&self.encode_event(event))?;
+            line.push(b'\n');
+            writer.write_all(&line)?;
+            written += line.len();
+        }
+        Ok(written)
+    }
+}
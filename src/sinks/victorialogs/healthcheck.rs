@@ -0,0 +1,21 @@
+use crate::{
+    http::HttpClient,
+    sinks::{util::UriSerde, HealthcheckError},
+};
+
+pub async fn healthcheck(endpoint: UriSerde, client: HttpClient) -> crate::Result<()> {
+    let endpoint = endpoint.append_path("health")?;
+
+    let mut request = http::Request::get(endpoint.uri)
+        .body(hyper::Body::empty())
+        .expect("Building request never fails.");
+    if let Some(auth) = &endpoint.auth {
+        auth.apply(&mut request);
+    }
+
+    let response = client.send(request).await?;
+    match response.status() {
+        status if status.is_success() => Ok(()),
+        status => Err(HealthcheckError::UnexpectedStatus { status }.into()),
+    }
+}
@@ -0,0 +1,24 @@
+//! VictoriaLogs sink
+//!
+//! This sink pushes logs to the JSON lines ingestion API of VictoriaLogs.
+//!
+//! <https://docs.victoriametrics.com/VictoriaLogs/data-ingestion/#json-stream-api>
+//!
+//! Each event is sent as a line of JSON, with its message and timestamp moved to the `_msg` and
+//! `_time` fields expected by VictoriaLogs. The fields forming the log stream of events are passed
+//! in the `_stream_fields` query argument.
+mod config;
+mod encoder;
+mod healthcheck;
+mod service;
+mod sink;
+#[cfg(test)]
+mod tests;
+
+pub use self::config::VictoriaLogsConfig;
+
+use crate::config::SinkDescription;
+
+inventory::submit! {
+    SinkDescription::new::<VictoriaLogsConfig>("victorialogs")
+}
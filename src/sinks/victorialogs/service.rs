@@ -0,0 +1,177 @@
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::{
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+    StatusCode,
+};
+use snafu::Snafu;
+use tower::Service;
+use tracing::Instrument;
+use vector_buffers::EventCount;
+use vector_common::internal_event::BytesSent;
+use vector_core::{
+    event::{EventFinalizers, EventStatus, Finalizable},
+    internal_event::EventsSent,
+    stream::DriverResponse,
+};
+
+use crate::{
+    http::{get_http_scheme_from_uri, HttpClient},
+    sinks::util::{metadata::RequestMetadata, retries::RetryLogic, Compression, UriSerde},
+};
+
+#[derive(Clone)]
+pub struct VictoriaLogsRetryLogic;
+
+impl RetryLogic for VictoriaLogsRetryLogic {
+    type Error = VictoriaLogsError;
+    type Response = VictoriaLogsResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        match error {
+            VictoriaLogsError::ServerError { code } => {
+                *code == StatusCode::TOO_MANY_REQUESTS || code.is_server_error()
+            }
+            VictoriaLogsError::HttpError { .. } => true,
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum VictoriaLogsError {
+    #[snafu(display("Server responded with an error: {}", code))]
+    ServerError { code: StatusCode },
+    #[snafu(display("Failed to make HTTP(S) request: {}", error))]
+    HttpError { error: crate::http::HttpError },
+}
+
+#[derive(Debug)]
+pub struct VictoriaLogsResponse {
+    protocol: &'static str,
+    metadata: RequestMetadata,
+}
+
+impl DriverResponse for VictoriaLogsResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> EventsSent {
+        EventsSent {
+            count: self.metadata.event_count(),
+            byte_size: self.metadata.events_byte_size(),
+            output: None,
+        }
+    }
+
+    fn bytes_sent(&self) -> Option<BytesSent> {
+        Some(BytesSent {
+            byte_size: self.metadata.request_encoded_size(),
+            protocol: self.protocol,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct VictoriaLogsRequest {
+    pub compression: Compression,
+    pub finalizers: EventFinalizers,
+    pub payload: Bytes,
+    pub metadata: RequestMetadata,
+}
+
+impl Finalizable for VictoriaLogsRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl EventCount for VictoriaLogsRequest {
+    fn event_count(&self) -> usize {
+        self.metadata.event_count()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VictoriaLogsService {
+    endpoint: UriSerde,
+    client: HttpClient,
+    account_id: Option<u32>,
+    project_id: Option<u32>,
+}
+
+impl VictoriaLogsService {
+    pub fn new(
+        client: HttpClient,
+        endpoint: UriSerde,
+        stream_fields: &[String],
+        account_id: Option<u32>,
+        project_id: Option<u32>,
+    ) -> crate::Result<Self> {
+        let mut endpoint = endpoint.append_path("insert/jsonline")?;
+        if !stream_fields.is_empty() {
+            let query = url::form_urlencoded::Serializer::new(String::new())
+                .append_pair("_stream_fields", &stream_fields.join(","))
+                .finish();
+            endpoint.uri = format!("{}?{}", endpoint.uri, query).parse()?;
+        }
+
+        Ok(Self {
+            endpoint,
+            client,
+            account_id,
+            project_id,
+        })
+    }
+}
+
+impl Service<VictoriaLogsRequest> for VictoriaLogsService {
+    type Response = VictoriaLogsResponse;
+    type Error = VictoriaLogsError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: VictoriaLogsRequest) -> Self::Future {
+        let mut builder =
+            http::Request::post(&self.endpoint.uri).header(CONTENT_TYPE, "application/stream+json");
+        let protocol = get_http_scheme_from_uri(&self.endpoint.uri);
+
+        if let Some(account_id) = self.account_id {
+            builder = builder.header("AccountID", account_id);
+        }
+        if let Some(project_id) = self.project_id {
+            builder = builder.header("ProjectID", project_id);
+        }
+        if let Some(ce) = request.compression.content_encoding() {
+            builder = builder.header(CONTENT_ENCODING, ce);
+        }
+
+        let mut http_request = builder
+            .body(hyper::Body::from(request.payload))
+            .expect("building HTTP request failed unexpectedly");
+        if let Some(auth) = &self.endpoint.auth {
+            auth.apply(&mut http_request);
+        }
+
+        let mut client = self.client.clone();
+        let metadata = request.metadata;
+        Box::pin(async move {
+            match client.call(http_request).in_current_span().await {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() {
+                        Ok(VictoriaLogsResponse { protocol, metadata })
+                    } else {
+                        Err(VictoriaLogsError::ServerError { code: status })
+                    }
+                }
+                Err(error) => Err(VictoriaLogsError::HttpError { error }),
+            }
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use std::num::NonZeroUsize;
+
+use bytes::Bytes;
+use futures::{stream::BoxStream, StreamExt};
+use snafu::Snafu;
+use vector_core::{
+    event::{Event, EventFinalizers, Finalizable},
+    sink::StreamSink,
+    stream::BatcherSettings,
+};
+
+use super::{
+    encoder::VictoriaLogsEncoder,
+    service::{VictoriaLogsRequest, VictoriaLogsRetryLogic, VictoriaLogsService},
+};
+use crate::sinks::util::{
+    builder::SinkBuilderExt,
+    metadata::{RequestMetadata, RequestMetadataBuilder},
+    request_builder::EncodeResult,
+    service::{ServiceBuilderExt, Svc},
+    Compression, RequestBuilder, TowerRequestSettings,
+};
+
+#[derive(Debug, Snafu)]
+pub enum RequestBuildError {
+    #[snafu(display("Failed to build payload with error: {}", error))]
+    Io { error: std::io::Error },
+}
+
+impl From<std::io::Error> for RequestBuildError {
+    fn from(error: std::io::Error) -> RequestBuildError {
+        RequestBuildError::Io { error }
+    }
+}
+
+struct VictoriaLogsRequestBuilder {
+    compression: Compression,
+    encoder: VictoriaLogsEncoder,
+}
+
+impl RequestBuilder<Vec<Event>> for VictoriaLogsRequestBuilder {
+    type Metadata = (EventFinalizers, RequestMetadataBuilder);
+    type Events = Vec<Event>;
+    type Encoder = VictoriaLogsEncoder;
+    type Payload = Bytes;
+    type Request = VictoriaLogsRequest;
+    type Error = RequestBuildError;
+
+    fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    fn encoder(&self) -> &Self::Encoder {
+        &self.encoder
+    }
+
+    fn split_input(&self, mut events: Vec<Event>) -> (Self::Metadata, Self::Events) {
+        let metadata_builder = RequestMetadata::builder(&events);
+        let finalizers = events.take_finalizers();
+
+        ((finalizers, metadata_builder), events)
+    }
+
+    fn build_request(
+        &self,
+        metadata: Self::Metadata,
+        payload: EncodeResult<Self::Payload>,
+    ) -> Self::Request {
+        let (finalizers, metadata_builder) = metadata;
+        let metadata = metadata_builder.build(&payload);
+
+        VictoriaLogsRequest {
+            compression: self.compression,
+            finalizers,
+            payload: payload.into_payload(),
+            metadata,
+        }
+    }
+}
+
+pub struct VictoriaLogsSink {
+    request_builder: VictoriaLogsRequestBuilder,
+    batch_settings: BatcherSettings,
+    service: Svc<VictoriaLogsService, VictoriaLogsRetryLogic>,
+}
+
+impl VictoriaLogsSink {
+    pub fn new(
+        service: VictoriaLogsService,
+        encoder: VictoriaLogsEncoder,
+        compression: Compression,
+        request_settings: TowerRequestSettings,
+        batch_settings: BatcherSettings,
+    ) -> Self {
+        let service = tower::ServiceBuilder::new()
+            .settings(request_settings, VictoriaLogsRetryLogic)
+            .service(service);
+
+        Self {
+            request_builder: VictoriaLogsRequestBuilder {
+                compression,
+                encoder,
+            },
+            batch_settings,
+            service,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let builder_limit = NonZeroUsize::new(64);
+
+        input
+            .batched(self.batch_settings.into_byte_size_config())
+            .request_builder(builder_limit, self.request_builder)
+            .filter_map(|request| async move {
+                match request {
+                    Err(error) => {
+                        error!("Failed to build VictoriaLogs request: {:?}.", error);
+                        None
+                    }
+                    Ok(request) => Some(request),
+                }
+            })
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl StreamSink<Event> for VictoriaLogsSink {
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
@@ -0,0 +1,131 @@
+use std::io::Read;
+
+use chrono::{TimeZone, Utc};
+use flate2::read::MultiGzDecoder;
+use futures::{stream, StreamExt};
+
+use super::config::VictoriaLogsConfig;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    event::{Event, LogEvent},
+    sinks::util::test::{build_test_server, load_sink},
+    test_util::{
+        self,
+        components::{run_and_assert_driver_sink_compliance, HTTP_SINK_TAGS},
+    },
+};
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<VictoriaLogsConfig>();
+}
+
+fn event(message: &str, host: &str) -> Event {
+    let mut log = LogEvent::from(message);
+    log.insert(
+        "timestamp",
+        Utc.ymd(2022, 8, 5).and_hms_milli(9, 30, 15, 250),
+    );
+    log.insert("host", host);
+    log.insert("app", "checkout");
+    log.into()
+}
+
+/// Runs the sink configured with `config` against a stub server, returning the headers and
+/// decompressed body of the request it received.
+async fn push(config: &str, events: Vec<Event>) -> (http::request::Parts, String) {
+    let addr = test_util::next_addr();
+    let (config, cx) = load_sink::<VictoriaLogsConfig>(&format!(
+        r#"endpoint = "http://{}/"
+        {}"#,
+        addr, config
+    ))
+    .unwrap();
+    let (sink, _healthcheck) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = build_test_server(addr);
+    tokio::spawn(server);
+
+    run_and_assert_driver_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
+
+    let (parts, body) = rx.take(1).collect::<Vec<_>>().await.remove(0);
+    let body = match parts.headers.get("content-encoding") {
+        Some(encoding) => {
+            assert_eq!(encoding, "gzip");
+            let mut body_string = String::new();
+            MultiGzDecoder::new(&body[..])
+                .read_to_string(&mut body_string)
+                .unwrap();
+            body_string
+        }
+        None => String::from_utf8(body.to_vec()).unwrap(),
+    };
+    (parts, body)
+}
+
+#[tokio::test]
+async fn pushes_json_lines() {
+    let (parts, body) = push(
+        r#"stream_fields = ["host", "app"]
+        account_id = 12
+        project_id = 34
+        compression = "none""#,
+        vec![event("first", "web-1"), event("second", "web-2")],
+    )
+    .await;
+
+    assert_eq!(parts.method, http::Method::POST);
+    assert_eq!(parts.uri.path(), "/insert/jsonline");
+    assert_eq!(parts.uri.query(), Some("_stream_fields=host%2Capp"));
+    assert_eq!(parts.headers["content-type"], "application/stream+json");
+    assert_eq!(parts.headers["accountid"], "12");
+    assert_eq!(parts.headers["projectid"], "34");
+
+    let lines = body
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            serde_json::json!({
+                "_msg": "first",
+                "_time": "2022-08-05T09:30:15.250Z",
+                "host": "web-1",
+                "app": "checkout",
+            }),
+            serde_json::json!({
+                "_msg": "second",
+                "_time": "2022-08-05T09:30:15.250Z",
+                "host": "web-2",
+                "app": "checkout",
+            }),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn pushes_custom_fields_compressed() {
+    let mut event = event("ignored", "web-1");
+    event.as_mut_log().insert("line", "custom message");
+    event.as_mut_log().insert("ts", "2022-08-05T09:30:15Z");
+
+    let (parts, body) = push(
+        r#"message_field = "line"
+        timestamp_field = "ts""#,
+        vec![event],
+    )
+    .await;
+
+    // Without stream fields or tenant IDs, neither the query argument nor the headers are sent.
+    assert_eq!(parts.uri.query(), None);
+    assert!(!parts.headers.contains_key("accountid"));
+    assert!(!parts.headers.contains_key("projectid"));
+
+    let line = serde_json::from_str::<serde_json::Value>(body.trim_end()).unwrap();
+    assert_eq!(line["_msg"], "custom message");
+    assert_eq!(line["_time"], "2022-08-05T09:30:15Z");
+    assert_eq!(line["message"], "ignored");
+    assert!(line.get("line").is_none());
+    assert!(line.get("ts").is_none());
+}
@@ -1,5 +1,9 @@
+use std::net::SocketAddr;
+
 use codecs::JsonSerializerConfig;
-use snafu::ResultExt;
+use derivative::Derivative;
+use futures::{future, FutureExt};
+use snafu::{ResultExt, Snafu};
 use vector_config::configurable_component;
 
 use crate::{
@@ -7,21 +11,56 @@ use crate::{
     config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
     http::Auth,
     sinks::{
-        websocket::sink::{ConnectSnafu, WebSocketConnector, WebSocketError, WebSocketSink},
+        websocket::{
+            server::WebSocketServerSink,
+            sink::{ConnectSnafu, WebSocketConnector, WebSocketError, WebSocketSink},
+        },
         Healthcheck, VectorSink,
     },
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
 
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("`uri` must be set in client mode"))]
+    MissingUri,
+    #[snafu(display("`address` must be set in server mode"))]
+    MissingAddress,
+    #[snafu(display("`client_buffer_size` must be greater than zero"))]
+    ZeroClientBufferSize,
+    #[snafu(display("Failed to bind `address` {}: {}", address, source))]
+    Bind {
+        address: SocketAddr,
+        source: crate::tls::TlsError,
+    },
+}
+
 /// Configuration for the `websocket` sink.
 #[configurable_component(sink)]
 #[derive(Clone, Debug)]
 pub struct WebSocketSinkConfig {
-    /// The WebSocket URI to connect to.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub mode: WebSocketMode,
+
+    /// The WebSocket URI to connect to, in client mode.
     ///
     /// This should include the protocol and host, but can also include the port, path, and any other valid part of a URI.
+    #[serde(default)]
     pub uri: String,
 
+    /// The address to listen for connections on, in server mode.
+    ///
+    /// It _must_ include a port.
+    pub address: Option<SocketAddr>,
+
+    /// The maximum number of messages buffered for each client, in server mode.
+    ///
+    /// When a client doesn't keep up with the events, the oldest messages buffered for it are dropped, so that a slow
+    /// client never holds up the sink or the other clients.
+    #[serde(default = "default_client_buffer_size")]
+    pub client_buffer_size: usize,
+
     #[configurable(derived)]
     pub tls: Option<TlsEnableableConfig>,
 
@@ -48,10 +87,37 @@ pub struct WebSocketSinkConfig {
     pub auth: Option<Auth>,
 }
 
+/// The mode of the `websocket` sink.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WebSocketMode {
+    /// Connects to the WebSocket server at `uri`, and sends it all events.
+    ///
+    /// When `auth` is set, it is used to authenticate to the server.
+    #[derivative(Default)]
+    Client,
+
+    /// Listens on `address` for WebSocket clients, and streams events to all connected clients.
+    ///
+    /// When `auth` is set, clients must send the matching `Authorization` header. With bearer authentication, the
+    /// token can also be passed as the `token` query parameter, for clients such as browsers that can't set headers.
+    /// Clients can pass a VRL condition as the `filter` query parameter to only receive the events matching it.
+    Server,
+}
+
+const fn default_client_buffer_size() -> usize {
+    1000
+}
+
 impl GenerateConfig for WebSocketSinkConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
+            mode: WebSocketMode::Client,
             uri: "ws://127.0.0.1:9000/endpoint".into(),
+            address: None,
+            client_buffer_size: default_client_buffer_size(),
             tls: None,
             encoding: JsonSerializerConfig::new().into(),
             ping_interval: None,
@@ -67,6 +133,13 @@ impl GenerateConfig for WebSocketSinkConfig {
 #[typetag::serde(name = "websocket")]
 impl SinkConfig for WebSocketSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        if self.mode == WebSocketMode::Server {
+            return self.build_server().await;
+        }
+
+        if self.uri.is_empty() {
+            return Err(BuildError::MissingUri.into());
+        }
         let connector = self.build_connector()?;
         let ws_sink = WebSocketSink::new(self, connector.clone())?;
 
@@ -94,6 +167,21 @@ impl WebSocketSinkConfig {
         let tls = MaybeTlsSettings::from_config(&self.tls, false).context(ConnectSnafu)?;
         WebSocketConnector::new(self.uri.clone(), tls, self.auth.clone())
     }
+
+    async fn build_server(&self) -> crate::Result<(VectorSink, Healthcheck)> {
+        let address = self.address.ok_or(BuildError::MissingAddress)?;
+        if self.client_buffer_size == 0 {
+            return Err(BuildError::ZeroClientBufferSize.into());
+        }
+        let tls = MaybeTlsSettings::from_config(&self.tls, true).context(ConnectSnafu)?;
+        let listener = tls.bind(&address).await.context(BindSnafu { address })?;
+        let sink = WebSocketServerSink::new(self, listener)?;
+
+        Ok((
+            VectorSink::from_event_streamsink(sink),
+            future::ok(()).boxed(),
+        ))
+    }
 }
 
 #[cfg(test)]
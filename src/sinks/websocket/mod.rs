@@ -1,7 +1,8 @@
 mod config;
+mod server;
 mod sink;
 
-pub use config::WebSocketSinkConfig;
+pub use config::{WebSocketMode, WebSocketSinkConfig};
 
 use crate::config::SinkDescription;
 
@@ -0,0 +1,601 @@
+//! Server mode of the `websocket` sink, which streams events to the clients connected to it.
+
+use std::{
+    collections::VecDeque,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use async_trait::async_trait;
+use bytes::BytesMut;
+use futures::{stream::BoxStream, SinkExt, StreamExt};
+use http::{header::AUTHORIZATION, StatusCode, Uri};
+use subtle::ConstantTimeEq;
+use tokio::{net::TcpStream, sync::Notify};
+use tokio_tungstenite::{
+    accept_hdr_async,
+    tungstenite::{
+        handshake::server::{ErrorResponse, Request, Response},
+        Message,
+    },
+};
+use tokio_util::codec::Encoder as _;
+use vector_core::{
+    internal_event::{BytesSent, EventsSent},
+    ByteSizeOf,
+};
+
+use crate::{
+    codecs::{Encoder, Transformer},
+    conditions::{Condition, VrlConfig},
+    emit,
+    event::{Event, EventStatus, Finalizable},
+    http::Auth,
+    internal_events::{
+        ConnectionOpen, OpenGauge, WsConnectionError, WsServerClientConnected,
+        WsServerClientDisconnected, WsServerMessagesDropped,
+    },
+    sinks::{util::StreamSink, websocket::config::WebSocketSinkConfig},
+    tls::{MaybeTlsIncomingStream, MaybeTlsListener},
+};
+
+/// The query parameter holding the VRL condition that events must match to be sent to a client.
+const FILTER_PARAM: &str = "filter";
+/// The query parameter holding the bearer token, for clients that can't set the `Authorization` header.
+const TOKEN_PARAM: &str = "token";
+
+/// The VRL functions available to client filters.
+///
+/// Filters are written by clients, so they are limited to functions that only inspect the event,
+/// unlike ones reading the environment or enrichment tables, or logging.
+const FILTER_FUNCTIONS: &[&str] = &[
+    "contains",
+    "downcase",
+    "ends_with",
+    "exists",
+    "includes",
+    "is_array",
+    "is_boolean",
+    "is_empty",
+    "is_float",
+    "is_integer",
+    "is_null",
+    "is_object",
+    "is_regex",
+    "is_string",
+    "is_timestamp",
+    "length",
+    "match",
+    "match_any",
+    "starts_with",
+    "to_string",
+    "upcase",
+];
+
+/// Messages waiting to be sent to a client.
+///
+/// When the queue is full, the oldest message is dropped to make room for the new one.
+struct ClientQueue {
+    capacity: usize,
+    messages: Mutex<VecDeque<Message>>,
+    notify: Notify,
+    closed: AtomicBool,
+}
+
+impl ClientQueue {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            messages: Mutex::new(VecDeque::with_capacity(capacity)),
+            notify: Notify::new(),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Queues a message, and returns whether the oldest message was dropped to make room for it.
+    fn push(&self, message: Message) -> bool {
+        let dropped = {
+            let mut messages = self.messages.lock().unwrap();
+            let dropped = messages.len() >= self.capacity && messages.pop_front().is_some();
+            messages.push_back(message);
+            dropped
+        };
+        self.notify.notify_one();
+        dropped
+    }
+
+    /// Waits for the next message, or returns `None` once the queue is closed and empty.
+    async fn pop(&self) -> Option<Message> {
+        loop {
+            if let Some(message) = self.messages.lock().unwrap().pop_front() {
+                return Some(message);
+            }
+            if self.is_closed() {
+                return None;
+            }
+            self.notify.notified().await;
+        }
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+}
+
+struct Client {
+    peer_addr: SocketAddr,
+    filter: Option<Condition>,
+    queue: Arc<ClientQueue>,
+}
+
+type Clients = Arc<Mutex<Vec<Client>>>;
+
+pub struct WebSocketServerSink {
+    transformer: Transformer,
+    encoder: Encoder<()>,
+    address: SocketAddr,
+    listener: Option<MaybeTlsListener>,
+    auth: Option<Auth>,
+    client_buffer_size: usize,
+    clients: Clients,
+}
+
+impl WebSocketServerSink {
+    pub fn new(config: &WebSocketSinkConfig, listener: MaybeTlsListener) -> crate::Result<Self> {
+        let transformer = config.encoding.transformer();
+        let serializer = config.encoding.build()?;
+        let encoder = Encoder::<()>::new(serializer);
+
+        Ok(Self {
+            transformer,
+            encoder,
+            address: listener.local_addr()?,
+            listener: Some(listener),
+            auth: config.auth.clone(),
+            client_buffer_size: config.client_buffer_size,
+            clients: Arc::default(),
+        })
+    }
+
+    /// Sends the event to the queues of all clients whose filter it matches.
+    fn broadcast(&mut self, mut event: Event) {
+        let finalizers = event.take_finalizers();
+        self.transformer.transform(&mut event);
+        let byte_size = event.size_of();
+
+        let mut clients = self.clients.lock().unwrap();
+        clients.retain(|client| !client.queue.is_closed());
+        if clients.is_empty() {
+            finalizers.update_status(EventStatus::Delivered);
+            return;
+        }
+
+        // Events are encoded once, for all clients.
+        let mut bytes = BytesMut::new();
+        let message = match self.encoder.encode(event.clone(), &mut bytes) {
+            Ok(()) => Message::text(String::from_utf8_lossy(&bytes)),
+            Err(_) => {
+                // Error is handled by `Encoder`.
+                finalizers.update_status(EventStatus::Errored);
+                return;
+            }
+        };
+
+        let mut sent = 0;
+        for client in clients.iter() {
+            // Each filter checks its own copy, so that no client sees changes made by another.
+            if let Some(filter) = &client.filter {
+                if !filter.check(event.clone()).0 {
+                    continue;
+                }
+            }
+            if client.queue.push(message.clone()) {
+                emit!(WsServerMessagesDropped {
+                    peer_addr: &client.peer_addr,
+                    count: 1,
+                });
+            }
+            sent += 1;
+        }
+
+        // Clients only get a live view of the events, so events are delivered once they are queued.
+        finalizers.update_status(EventStatus::Delivered);
+        if sent > 0 {
+            emit!(EventsSent {
+                count: 1,
+                byte_size,
+                output: None,
+            });
+            emit!(BytesSent {
+                byte_size: message.len() * sent,
+                protocol: "websocket",
+            });
+        }
+    }
+}
+
+#[async_trait]
+impl StreamSink<Event> for WebSocketServerSink {
+    async fn run(mut self: Box<Self>, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let listener = self
+            .listener
+            .take()
+            .expect("The WebSocket server can only run once.");
+        info!(message = "Building WebSocket server.", address = %self.address);
+
+        let server = tokio::spawn(accept_clients(
+            listener.accept_stream(),
+            self.auth.clone(),
+            self.client_buffer_size,
+            Arc::clone(&self.clients),
+        ));
+
+        while let Some(event) = input.next().await {
+            self.broadcast(event);
+        }
+
+        // Let the clients receive the messages already queued for them before they are disconnected.
+        server.abort();
+        for client in self.clients.lock().unwrap().drain(..) {
+            client.queue.close();
+        }
+        Ok(())
+    }
+}
+
+async fn accept_clients(
+    incoming: impl futures::Stream<Item = crate::tls::Result<MaybeTlsIncomingStream<TcpStream>>>,
+    auth: Option<Auth>,
+    client_buffer_size: usize,
+    clients: Clients,
+) {
+    let open_gauge = OpenGauge::new();
+    futures::pin_mut!(incoming);
+    while let Some(stream) = incoming.next().await {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(error) => {
+                warn!(message = "Failed to accept WebSocket connection.", %error);
+                continue;
+            }
+        };
+        tokio::spawn(serve_client(
+            stream,
+            auth.clone(),
+            client_buffer_size,
+            Arc::clone(&clients),
+            open_gauge.clone(),
+        ));
+    }
+}
+
+async fn serve_client(
+    stream: MaybeTlsIncomingStream<TcpStream>,
+    auth: Option<Auth>,
+    client_buffer_size: usize,
+    clients: Clients,
+    open_gauge: OpenGauge,
+) {
+    let peer_addr = stream.peer_addr();
+
+    let mut filter = None;
+    let callback = |request: &Request, response: Response| -> Result<Response, ErrorResponse> {
+        if !is_authorized(auth.as_ref(), request) {
+            return Err(error_response(StatusCode::UNAUTHORIZED, "Unauthorized"));
+        }
+        filter = query_param(request.uri(), FILTER_PARAM)
+            .map(|source| compile_filter(&source))
+            .transpose()
+            .map_err(|error| error_response(StatusCode::BAD_REQUEST, &error))?;
+        Ok(response)
+    };
+    let ws_stream = match accept_hdr_async(stream, callback).await {
+        Ok(ws_stream) => ws_stream,
+        Err(error) => {
+            emit!(WsConnectionError { error });
+            return;
+        }
+    };
+
+    let _open_token = open_gauge.open(|count| emit!(ConnectionOpen { count }));
+    emit!(WsServerClientConnected {
+        peer_addr: &peer_addr
+    });
+
+    let queue = Arc::new(ClientQueue::new(client_buffer_size));
+    clients.lock().unwrap().push(Client {
+        peer_addr,
+        filter,
+        queue: Arc::clone(&queue),
+    });
+
+    let (mut ws_sink, mut ws_stream) = ws_stream.split();
+    loop {
+        tokio::select! {
+            message = queue.pop() => match message {
+                Some(message) => {
+                    if let Err(error) = ws_sink.send(message).await {
+                        emit!(WsConnectionError { error });
+                        break;
+                    }
+                }
+                None => {
+                    let _ = ws_sink.close().await;
+                    break;
+                }
+            },
+            // Pings are answered while reading, and anything else the client sends is ignored.
+            message = ws_stream.next() => match message {
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => {}
+                Some(Err(error)) => {
+                    emit!(WsConnectionError { error });
+                    break;
+                }
+            },
+        }
+    }
+
+    queue.close();
+    emit!(WsServerClientDisconnected {
+        peer_addr: &peer_addr
+    });
+}
+
+fn is_authorized(auth: Option<&Auth>, request: &Request) -> bool {
+    let auth = match auth {
+        Some(auth) => auth,
+        None => return true,
+    };
+
+    let mut expected = http::Request::new(());
+    auth.apply(&mut expected);
+    if let (Some(expected), Some(actual)) = (
+        expected.headers().get(AUTHORIZATION),
+        request.headers().get(AUTHORIZATION),
+    ) {
+        if constant_time_eq(expected.as_bytes(), actual.as_bytes()) {
+            return true;
+        }
+    }
+
+    match (auth, query_param(request.uri(), TOKEN_PARAM)) {
+        (Auth::Bearer { token }, Some(actual)) => {
+            constant_time_eq(token.as_bytes(), actual.as_bytes())
+        }
+        _ => false,
+    }
+}
+
+/// Compares credentials in a time that doesn't depend on how much of them matches.
+fn constant_time_eq(expected: &[u8], actual: &[u8]) -> bool {
+    expected.ct_eq(actual).into()
+}
+
+fn query_param(uri: &Uri, name: &str) -> Option<String> {
+    url::form_urlencoded::parse(uri.query()?.as_bytes())
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+fn compile_filter(source: &str) -> Result<Condition, String> {
+    let functions = vrl_stdlib::all()
+        .into_iter()
+        .filter(|function| FILTER_FUNCTIONS.contains(&function.identifier()))
+        .collect::<Vec<_>>();
+    VrlConfig {
+        source: source.to_owned(),
+        runtime: Default::default(),
+    }
+    .build_with_functions(&functions, &Default::default())
+    .map_err(|error| format!("Invalid filter: {}", error))
+}
+
+fn error_response(status: StatusCode, message: &str) -> ErrorResponse {
+    let mut response = ErrorResponse::new(Some(message.to_owned()));
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use codecs::JsonSerializerConfig;
+    use futures::channel::mpsc;
+    use tokio::time::{sleep, timeout};
+    use tokio_tungstenite::{
+        connect_async,
+        tungstenite::{client::IntoClientRequest, Error as WsError},
+        MaybeTlsStream, WebSocketStream,
+    };
+
+    use super::*;
+    use crate::{
+        config::{SinkConfig, SinkContext},
+        event::LogEvent,
+        sinks::websocket::WebSocketMode,
+        test_util::{next_addr, trace_init, wait_for_tcp},
+    };
+
+    type TestClient = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    fn config(address: SocketAddr, client_buffer_size: usize) -> WebSocketSinkConfig {
+        WebSocketSinkConfig {
+            mode: WebSocketMode::Server,
+            uri: String::new(),
+            address: Some(address),
+            client_buffer_size,
+            tls: None,
+            encoding: JsonSerializerConfig::new().into(),
+            ping_interval: None,
+            ping_timeout: None,
+            acknowledgements: Default::default(),
+            auth: Some(Auth::Bearer {
+                token: "secret".into(),
+            }),
+        }
+    }
+
+    /// Starts the sink, and returns the sender for its input.
+    async fn start_sink(config: WebSocketSinkConfig) -> mpsc::UnboundedSender<Event> {
+        let address = config.address.unwrap();
+        let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+        let (tx, rx) = mpsc::unbounded();
+        tokio::spawn(sink.run(rx));
+        wait_for_tcp(address).await;
+        tx
+    }
+
+    async fn connect(address: SocketAddr, query: &str) -> Result<TestClient, WsError> {
+        let client = connect_async(format!("ws://{}/?{}", address, query))
+            .await?
+            .0;
+        // Give the server time to register the client.
+        sleep(Duration::from_millis(100)).await;
+        Ok(client)
+    }
+
+    fn event(message: &str) -> Event {
+        LogEvent::from(message).into()
+    }
+
+    /// Receives the messages of all events sent to the client, until none arrive for a while.
+    async fn receive_messages(client: &mut TestClient) -> Vec<String> {
+        let mut messages = Vec::new();
+        while let Ok(Some(Ok(message))) = timeout(Duration::from_millis(500), client.next()).await {
+            if let Message::Text(text) = message {
+                let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+                messages.push(json["message"].as_str().unwrap().to_owned());
+            }
+        }
+        messages
+    }
+
+    #[tokio::test]
+    async fn streams_filtered_events_to_clients() {
+        trace_init();
+
+        let address = next_addr();
+        let tx = start_sink(config(address, 100)).await;
+
+        let mut all = connect(address, "token=secret").await.unwrap();
+        let filter: String = url::form_urlencoded::byte_serialize(br#".message == "b""#).collect();
+        let mut filtered = connect(address, &format!("token=secret&filter={}", filter))
+            .await
+            .unwrap();
+
+        for message in ["a", "b", "c"] {
+            tx.unbounded_send(event(message)).unwrap();
+        }
+
+        assert_eq!(receive_messages(&mut all).await, vec!["a", "b", "c"]);
+        assert_eq!(receive_messages(&mut filtered).await, vec!["b"]);
+    }
+
+    #[tokio::test]
+    async fn rejects_unauthorized_clients() {
+        trace_init();
+
+        let address = next_addr();
+        let _tx = start_sink(config(address, 100)).await;
+
+        assert!(connect(address, "token=wrong").await.is_err());
+        assert!(connect(address, "").await.is_err());
+
+        let mut request = format!("ws://{}/", address).into_client_request().unwrap();
+        request
+            .headers_mut()
+            .insert(AUTHORIZATION, "Bearer secret".parse().unwrap());
+        assert!(connect_async(request).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_filters() {
+        trace_init();
+
+        let address = next_addr();
+        let _tx = start_sink(config(address, 100)).await;
+
+        assert!(connect(address, "token=secret&filter=.message%20%3D%3D")
+            .await
+            .is_err());
+
+        // Filters can't read the environment of the server.
+        let filter: String =
+            url::form_urlencoded::byte_serialize(br#"get_env_var!("HOME") == "/root""#).collect();
+        assert!(connect(address, &format!("token=secret&filter={}", filter))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn fails_to_build_when_address_in_use() {
+        trace_init();
+
+        let address = next_addr();
+        let _listener = std::net::TcpListener::bind(address).unwrap();
+
+        let error = config(address, 100)
+            .build(SinkContext::new_test())
+            .await
+            .err()
+            .unwrap();
+        assert!(error.to_string().starts_with("Failed to bind `address`"));
+    }
+
+    #[tokio::test]
+    async fn drops_oldest_messages_of_slow_clients() {
+        trace_init();
+
+        let address = next_addr();
+        let tx = start_sink(config(address, 10)).await;
+
+        let mut slow = connect(address, "token=secret").await.unwrap();
+        let mut fast = connect(address, "token=secret").await.unwrap();
+
+        // Enough data to fill the socket buffers of the slow client, which doesn't read anything
+        // until all events were sent.
+        let padding = "x".repeat(16 * 1024);
+        let count = 2000;
+        let fast_messages = tokio::spawn(async move { receive_messages(&mut fast).await });
+        for i in 0..count {
+            tx.unbounded_send(event(&format!("{} {}", i, padding)))
+                .unwrap();
+        }
+
+        // The slow client doesn't hold up the sink, so the fast client receives every event.
+        let fast_messages = fast_messages.await.unwrap();
+        assert_eq!(fast_messages.len(), count);
+
+        let slow_messages = receive_messages(&mut slow).await;
+        assert!(slow_messages.len() < count);
+        // The newest messages are kept.
+        assert!(slow_messages
+            .last()
+            .unwrap()
+            .starts_with(&format!("{} ", count - 1)));
+    }
+
+    #[test]
+    fn queue_drops_oldest_message() {
+        let queue = ClientQueue::new(2);
+        assert!(!queue.push(Message::text("a")));
+        assert!(!queue.push(Message::text("b")));
+        assert!(queue.push(Message::text("c")));
+
+        let messages = queue.messages.lock().unwrap();
+        assert_eq!(
+            messages.iter().cloned().collect::<Vec<_>>(),
+            vec![Message::text("b"), Message::text("c")]
+        );
+    }
+}
@@ -405,7 +405,10 @@ mod tests {
 
         let addr = next_addr();
         let config = WebSocketSinkConfig {
+            mode: Default::default(),
             uri: format!("ws://{}", addr),
+            address: None,
+            client_buffer_size: 1000,
             tls: None,
             encoding: JsonSerializerConfig::new().into(),
             ping_interval: None,
@@ -428,7 +431,10 @@ mod tests {
         let auth_clone = auth.clone();
         let addr = next_addr();
         let config = WebSocketSinkConfig {
+            mode: Default::default(),
             uri: format!("ws://{}", addr),
+            address: None,
+            client_buffer_size: 1000,
             tls: None,
             encoding: JsonSerializerConfig::new().into(),
             ping_interval: None,
@@ -450,7 +456,10 @@ mod tests {
         let tls = MaybeTlsSettings::from_config(&tls_config, true).unwrap();
 
         let config = WebSocketSinkConfig {
+            mode: Default::default(),
             uri: format!("wss://{}", addr),
+            address: None,
+            client_buffer_size: 1000,
             tls: Some(TlsEnableableConfig {
                 enabled: Some(true),
                 options: TlsConfig {
@@ -476,7 +485,10 @@ mod tests {
 
         let addr = next_addr();
         let config = WebSocketSinkConfig {
+            mode: Default::default(),
             uri: format!("ws://{}", addr),
+            address: None,
+            client_buffer_size: 1000,
             tls: None,
             encoding: JsonSerializerConfig::new().into(),
             ping_interval: None,
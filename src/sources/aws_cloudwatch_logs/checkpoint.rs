@@ -0,0 +1,180 @@
+use std::{collections::HashMap, io, path::PathBuf, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::internal_events::AwsCloudwatchLogsCheckpointWriteError;
+
+const CHECKPOINT_FILENAME: &str = "checkpoints.json";
+const CHECKPOINT_TMP_FILENAME: &str = "checkpoints.new.json";
+
+/// The read position within a single log group.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub(super) struct GroupCheckpoint {
+    /// The start time, in milliseconds since the epoch, of the query `next_token` belongs to.
+    pub(super) query_start_time: i64,
+
+    /// The token of the next page of the in-progress query, if any.
+    ///
+    /// Tokens expire after a while, in which case reading resumes from `timestamp`.
+    pub(super) next_token: Option<String>,
+
+    /// The timestamp, in milliseconds since the epoch, of the newest event read.
+    pub(super) timestamp: i64,
+
+    /// The IDs of the events read with `timestamp`, which are skipped when reading resumes from `timestamp`.
+    pub(super) event_ids: Vec<String>,
+}
+
+impl GroupCheckpoint {
+    pub(super) fn new(start_time: i64) -> Self {
+        Self {
+            query_start_time: start_time,
+            next_token: None,
+            timestamp: start_time,
+            event_ids: Vec::new(),
+        }
+    }
+
+    /// Whether the event has already been read according to this checkpoint.
+    pub(super) fn contains(&self, timestamp: i64, event_id: &str) -> bool {
+        timestamp < self.timestamp
+            || (timestamp == self.timestamp && self.event_ids.iter().any(|id| id == event_id))
+    }
+
+    /// Advances the checkpoint past the given event.
+    pub(super) fn advance(&mut self, timestamp: i64, event_id: String) {
+        if timestamp > self.timestamp {
+            self.timestamp = timestamp;
+            self.event_ids.clear();
+        }
+        self.event_ids.push(event_id);
+    }
+
+    /// Records the token of the next page, or starts a new query from the newest event read if the
+    /// current query is exhausted.
+    pub(super) fn set_next_token(&mut self, next_token: Option<String>) {
+        if next_token.is_none() {
+            self.query_start_time = self.timestamp;
+        }
+        self.next_token = next_token;
+    }
+
+    /// Abandons the in-progress query, so the next query starts from the newest event read.
+    pub(super) fn reset_query(&mut self) {
+        self.set_next_token(None);
+    }
+}
+
+pub(super) struct Checkpointer {
+    directory: PathBuf,
+    checkpoints: HashMap<String, GroupCheckpoint>,
+}
+
+impl Checkpointer {
+    pub(super) async fn load(directory: PathBuf) -> Result<Self, io::Error> {
+        let checkpoints = match tokio::fs::read(directory.join(CHECKPOINT_FILENAME)).await {
+            Ok(contents) => serde_json::from_slice(&contents)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(error) => return Err(error),
+        };
+        Ok(Self {
+            directory,
+            checkpoints,
+        })
+    }
+
+    pub(super) fn get(&self, group_name: &str) -> Option<&GroupCheckpoint> {
+        self.checkpoints.get(group_name)
+    }
+
+    pub(super) async fn set(&mut self, group_name: &str, checkpoint: GroupCheckpoint) {
+        self.checkpoints.insert(group_name.to_owned(), checkpoint);
+        if let Err(error) = self.write().await {
+            emit!(AwsCloudwatchLogsCheckpointWriteError {
+                path: &self.directory.join(CHECKPOINT_FILENAME),
+                error,
+            });
+        }
+    }
+
+    /// Writes all checkpoints to a temporary file which is then renamed over the checkpoint file, so
+    /// an interrupted write never leaves a truncated checkpoint file behind.
+    async fn write(&self) -> Result<(), io::Error> {
+        let contents = serde_json::to_vec(&self.checkpoints)?;
+        let tmp_path = self.directory.join(CHECKPOINT_TMP_FILENAME);
+        tokio::fs::write(&tmp_path, contents).await?;
+        tokio::fs::rename(&tmp_path, self.directory.join(CHECKPOINT_FILENAME)).await
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct SharedCheckpointer(Arc<Mutex<Checkpointer>>);
+
+impl SharedCheckpointer {
+    pub(super) fn new(checkpointer: Checkpointer) -> Self {
+        Self(Arc::new(Mutex::new(checkpointer)))
+    }
+
+    pub(super) async fn get(&self, group_name: &str) -> Option<GroupCheckpoint> {
+        self.0.lock().await.get(group_name).cloned()
+    }
+
+    pub(super) async fn set(&self, group_name: &str, checkpoint: GroupCheckpoint) {
+        self.0.lock().await.set(group_name, checkpoint).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn advance_tracks_events_at_newest_timestamp() {
+        let mut checkpoint = GroupCheckpoint::new(10);
+        checkpoint.advance(20, "a".into());
+        checkpoint.advance(20, "b".into());
+        assert!(checkpoint.contains(20, "a"));
+        assert!(checkpoint.contains(20, "b"));
+        assert!(checkpoint.contains(15, "c"));
+        assert!(!checkpoint.contains(20, "c"));
+
+        checkpoint.advance(30, "c".into());
+        assert_eq!(checkpoint.event_ids, vec!["c".to_owned()]);
+        assert!(!checkpoint.contains(30, "a"));
+    }
+
+    #[test]
+    fn exhausted_query_restarts_from_newest_event() {
+        let mut checkpoint = GroupCheckpoint::new(10);
+        checkpoint.advance(20, "a".into());
+        checkpoint.set_next_token(Some("token".into()));
+        assert_eq!(checkpoint.query_start_time, 10);
+
+        checkpoint.advance(25, "b".into());
+        checkpoint.set_next_token(None);
+        assert_eq!(checkpoint.query_start_time, 25);
+        assert_eq!(checkpoint.next_token, None);
+    }
+
+    #[tokio::test]
+    async fn checkpoints_persist_across_loads() {
+        let directory = tempdir().unwrap();
+        let mut checkpoint = GroupCheckpoint::new(10);
+        checkpoint.advance(20, "a".into());
+
+        let mut checkpointer = Checkpointer::load(directory.path().to_path_buf())
+            .await
+            .unwrap();
+        assert_eq!(checkpointer.get("group"), None);
+        checkpointer.set("group", checkpoint.clone()).await;
+
+        let checkpointer = Checkpointer::load(directory.path().to_path_buf())
+            .await
+            .unwrap();
+        assert_eq!(checkpointer.get("group"), Some(&checkpoint));
+    }
+}
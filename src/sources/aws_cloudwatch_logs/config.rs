@@ -0,0 +1,224 @@
+use std::{path::PathBuf, time::Duration};
+
+use codecs::decoding::{DeserializerConfig, FramingConfig};
+use snafu::Snafu;
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    aws::{auth::AwsAuthentication, create_client, region::RegionOrEndpoint},
+    codecs::DecodingConfig,
+    common::cloudwatch_logs::CloudwatchLogsClientBuilder,
+    config::{Output, SourceConfig, SourceContext},
+    serde::{default_decoding, default_framing_message_based},
+    sources::aws_cloudwatch_logs::{
+        checkpoint::{Checkpointer, SharedCheckpointer},
+        source::CloudwatchLogsSource,
+    },
+    tls::TlsConfig,
+};
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("At least one of `group_names` or `group_name_prefixes` must be set"))]
+    MissingGroups,
+    #[snafu(display("`stream_names` and `stream_name_prefix` cannot be used together"))]
+    ConflictingStreamFilters,
+}
+
+/// Configuration for the `aws_cloudwatch_logs` source.
+///
+/// Log groups are polled with the `FilterLogEvents` API. The `StartLiveTail` streaming API isn't supported, as the
+/// version of the AWS SDK in use doesn't provide it.
+#[configurable_component(source)]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields)]
+pub struct AwsCloudwatchLogsConfig {
+    #[serde(flatten)]
+    pub region: RegionOrEndpoint,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub auth: AwsAuthentication,
+
+    /// The names of the log groups to read events from.
+    #[serde(default)]
+    pub group_names: Vec<String>,
+
+    /// Prefixes of the names of the log groups to read events from.
+    ///
+    /// Matching log groups are discovered every `poll_secs`, so log groups created after Vector starts are read as
+    /// well.
+    #[serde(default)]
+    pub group_name_prefixes: Vec<String>,
+
+    /// The names of the log streams to read events from.
+    ///
+    /// If empty, events from all log streams in a log group are read. Cannot be used together with
+    /// `stream_name_prefix`.
+    #[serde(default)]
+    pub stream_names: Vec<String>,
+
+    /// A prefix of the names of the log streams to read events from.
+    ///
+    /// Cannot be used together with `stream_names`.
+    pub stream_name_prefix: Option<String>,
+
+    /// A CloudWatch Logs [filter pattern][filter_pattern] that events must match to be read.
+    ///
+    /// [filter_pattern]: https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/FilterAndPatternSyntax.html
+    pub filter_pattern: Option<String>,
+
+    /// How long to wait between polling a log group for new events, in seconds.
+    #[serde(default = "default_poll_secs")]
+    #[derivative(Default(value = "default_poll_secs()"))]
+    pub poll_secs: u32,
+
+    /// Ignore events older than this many seconds when a log group is read for the first time.
+    ///
+    /// By default, all events in a log group are read. Once a log group has a checkpoint, reading resumes from the
+    /// checkpoint regardless of this setting.
+    pub ignore_older_secs: Option<u64>,
+
+    /// The directory used to persist checkpoints of the events read from each log group.
+    ///
+    /// By default, the global `data_dir` option is used. Please make sure the user Vector is running as has write
+    /// permissions to this directory.
+    pub data_dir: Option<PathBuf>,
+
+    #[configurable(derived)]
+    #[serde(default = "default_framing_message_based")]
+    #[derivative(Default(value = "default_framing_message_based()"))]
+    pub framing: FramingConfig,
+
+    #[configurable(derived)]
+    #[serde(default = "default_decoding")]
+    #[derivative(Default(value = "default_decoding()"))]
+    pub decoding: DeserializerConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+}
+
+const fn default_poll_secs() -> u32 {
+    15
+}
+
+impl_generate_config_from_default!(AwsCloudwatchLogsConfig);
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "aws_cloudwatch_logs")]
+impl SourceConfig for AwsCloudwatchLogsConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<crate::sources::Source> {
+        if self.group_names.is_empty() && self.group_name_prefixes.is_empty() {
+            return Err(BuildError::MissingGroups.into());
+        }
+        if !self.stream_names.is_empty() && self.stream_name_prefix.is_some() {
+            return Err(BuildError::ConflictingStreamFilters.into());
+        }
+
+        let data_dir = cx
+            .globals
+            // source are only global, name can be used for subdir
+            .resolve_and_make_data_subdir(self.data_dir.as_ref(), cx.key.id())?;
+        let checkpointer = SharedCheckpointer::new(Checkpointer::load(data_dir).await?);
+
+        let client = self.build_client(&cx).await?;
+        let decoder = DecodingConfig::new(
+            self.framing.clone(),
+            self.decoding.clone(),
+            LogNamespace::Legacy,
+        )
+        .build();
+
+        Ok(Box::pin(
+            CloudwatchLogsSource {
+                client,
+                group_names: self.group_names.clone(),
+                group_name_prefixes: self.group_name_prefixes.clone(),
+                stream_names: self.stream_names.clone(),
+                stream_name_prefix: self.stream_name_prefix.clone(),
+                filter_pattern: self.filter_pattern.clone(),
+                poll_interval: Duration::from_secs(self.poll_secs.into()),
+                ignore_older: self.ignore_older_secs.map(Duration::from_secs),
+                decoder,
+                checkpointer,
+            }
+            .run(cx.out, cx.shutdown),
+        ))
+    }
+
+    fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
+        vec![Output::default(self.decoding.output_type())]
+    }
+
+    fn source_type(&self) -> &'static str {
+        "aws_cloudwatch_logs"
+    }
+}
+
+impl AwsCloudwatchLogsConfig {
+    async fn build_client(
+        &self,
+        cx: &SourceContext,
+    ) -> crate::Result<aws_sdk_cloudwatchlogs::Client> {
+        create_client::<CloudwatchLogsClientBuilder>(
+            &self.auth,
+            self.region.region(),
+            self.region.endpoint()?,
+            &cx.proxy,
+            &self.tls,
+            false,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<AwsCloudwatchLogsConfig>();
+    }
+
+    #[tokio::test]
+    async fn requires_log_groups() {
+        let config: AwsCloudwatchLogsConfig = toml::from_str(r#"region = "us-east-1""#).unwrap();
+        let (sender, _) = crate::SourceSender::new_test();
+        let error = config
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "At least one of `group_names` or `group_name_prefixes` must be set"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_conflicting_stream_filters() {
+        let config: AwsCloudwatchLogsConfig = toml::from_str(
+            r#"
+            region = "us-east-1"
+            group_names = ["group"]
+            stream_names = ["stream"]
+            stream_name_prefix = "str"
+            "#,
+        )
+        .unwrap();
+        let (sender, _) = crate::SourceSender::new_test();
+        let error = config
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`stream_names` and `stream_name_prefix` cannot be used together"
+        );
+    }
+}
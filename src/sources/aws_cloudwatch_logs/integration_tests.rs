@@ -0,0 +1,160 @@
+#![cfg(feature = "aws-cloudwatch-logs-integration-tests")]
+#![cfg(test)]
+
+use std::{path::Path, str::FromStr};
+
+use aws_sdk_cloudwatchlogs::{model::InputLogEvent, Client as CloudwatchLogsClient, Endpoint};
+use aws_types::region::Region;
+use chrono::Utc;
+use futures::{Stream, StreamExt};
+use http::Uri;
+use tempfile::tempdir;
+use tokio::time::{timeout, Duration, Instant};
+
+use crate::{
+    aws::{auth::AwsAuthentication, create_client, region::RegionOrEndpoint},
+    common::cloudwatch_logs::CloudwatchLogsClientBuilder,
+    config::{log_schema, ProxyConfig, SourceConfig, SourceContext},
+    event::Event,
+    sources::aws_cloudwatch_logs::AwsCloudwatchLogsConfig,
+    test_util::{
+        collect_ready,
+        components::{assert_source_compliance, HTTP_PULL_SOURCE_TAGS},
+        random_string, trace_init,
+    },
+    SourceSender,
+};
+
+fn cloudwatch_logs_address() -> String {
+    std::env::var("CLOUDWATCH_LOGS_ADDRESS").unwrap_or_else(|_| "http://localhost:4566".into())
+}
+
+fn gen_name() -> String {
+    format!("vector-{}", random_string(10).to_lowercase())
+}
+
+async fn create_client_test() -> CloudwatchLogsClient {
+    let auth = AwsAuthentication::test_auth();
+    let region = Some(Region::new("us-east-1"));
+    let endpoint = Some(Endpoint::immutable(
+        Uri::from_str(&cloudwatch_logs_address()).unwrap(),
+    ));
+    let proxy = ProxyConfig::default();
+
+    create_client::<CloudwatchLogsClientBuilder>(&auth, region, endpoint, &proxy, &None, false)
+        .await
+        .unwrap()
+}
+
+async fn put_messages(client: &CloudwatchLogsClient, group_name: &str, messages: &[String]) {
+    client
+        .create_log_group()
+        .log_group_name(group_name)
+        .send()
+        .await
+        .unwrap();
+    client
+        .create_log_stream()
+        .log_group_name(group_name)
+        .log_stream_name("stream")
+        .send()
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp_millis();
+    let log_events = messages
+        .iter()
+        .map(|message| {
+            InputLogEvent::builder()
+                .message(message)
+                .timestamp(now)
+                .build()
+        })
+        .collect();
+    client
+        .put_log_events()
+        .log_group_name(group_name)
+        .log_stream_name("stream")
+        .set_log_events(Some(log_events))
+        .send()
+        .await
+        .unwrap();
+}
+
+fn config(group_name_prefix: &str, data_dir: &Path) -> AwsCloudwatchLogsConfig {
+    AwsCloudwatchLogsConfig {
+        region: RegionOrEndpoint::with_both("us-east-1", cloudwatch_logs_address().as_str()),
+        auth: AwsAuthentication::test_auth(),
+        group_name_prefixes: vec![group_name_prefix.to_owned()],
+        poll_secs: 1,
+        data_dir: Some(data_dir.to_path_buf()),
+        ..Default::default()
+    }
+}
+
+async fn run_source(
+    config: AwsCloudwatchLogsConfig,
+    expected: usize,
+) -> (Vec<Event>, impl Stream<Item = Event>) {
+    let (tx, mut rx) = SourceSender::new_test();
+    let (cx, mut shutdown) = SourceContext::new_shutdown(&"aws_cloudwatch_logs".into(), tx);
+    let source = config.build(cx).await.unwrap();
+    tokio::spawn(async move { source.await.unwrap() });
+
+    let mut events = Vec::with_capacity(expected);
+    while events.len() < expected {
+        match timeout(Duration::from_secs(10), rx.next()).await {
+            Ok(Some(event)) => events.push(event),
+            _ => break,
+        }
+    }
+
+    shutdown
+        .shutdown_all(Instant::now() + Duration::from_secs(5))
+        .await;
+    (events, rx)
+}
+
+#[tokio::test]
+async fn reads_log_groups_matching_prefix() {
+    trace_init();
+
+    assert_source_compliance(&HTTP_PULL_SOURCE_TAGS, async {
+        let client = create_client_test().await;
+        let group_name = gen_name();
+        let messages = vec!["first".to_owned(), "second".to_owned()];
+        put_messages(&client, &group_name, &messages).await;
+
+        let data_dir = tempdir().unwrap();
+        let (events, _) = run_source(config(&group_name, data_dir.path()), messages.len()).await;
+
+        assert_eq!(events.len(), messages.len());
+        for (event, message) in events.iter().zip(&messages) {
+            let log = event.as_log();
+            assert_eq!(log[log_schema().message_key()], message.as_str().into());
+            assert_eq!(log["group_name"], group_name.as_str().into());
+            assert_eq!(log["stream_name"], "stream".into());
+            assert!(log.get("ingestion_timestamp").is_some());
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn resumes_from_checkpoint() {
+    trace_init();
+
+    let client = create_client_test().await;
+    let group_name = gen_name();
+    let messages = vec!["first".to_owned(), "second".to_owned()];
+    put_messages(&client, &group_name, &messages).await;
+
+    let data_dir = tempdir().unwrap();
+    let (events, _) = run_source(config(&group_name, data_dir.path()), messages.len()).await;
+    assert_eq!(events.len(), messages.len());
+
+    // A restarted source must not read the same events again.
+    let (events, rx) = run_source(config(&group_name, data_dir.path()), 1).await;
+    assert!(events.is_empty());
+    assert!(collect_ready(rx).await.is_empty());
+}
@@ -0,0 +1,12 @@
+mod checkpoint;
+mod config;
+mod integration_tests;
+mod source;
+
+pub use config::AwsCloudwatchLogsConfig;
+
+use crate::config::SourceDescription;
+
+inventory::submit! {
+    SourceDescription::new::<AwsCloudwatchLogsConfig>("aws_cloudwatch_logs")
+}
@@ -0,0 +1,301 @@
+use std::{collections::HashSet, panic, sync::Arc, time::Duration};
+
+use aws_sdk_cloudwatchlogs::{
+    error::{FilterLogEventsError, FilterLogEventsErrorKind},
+    types::SdkError,
+    Client as CloudwatchLogsClient,
+};
+use chrono::{TimeZone, Utc};
+use futures::FutureExt;
+use tokio::{pin, select, sync::mpsc, time::interval};
+
+use crate::{
+    aws::is_retriable_error,
+    codecs::Decoder,
+    event::Event,
+    internal_events::{
+        AwsCloudwatchLogsDescribeLogGroupsError, AwsCloudwatchLogsFilterLogEventsError,
+        AwsCloudwatchLogsGroupDeleted, EndpointBytesReceived, StreamClosedError,
+    },
+    shutdown::ShutdownSignal,
+    sinks::util::retries::ExponentialBackoff,
+    sources::{
+        aws_cloudwatch_logs::checkpoint::{GroupCheckpoint, SharedCheckpointer},
+        util,
+    },
+    SourceSender,
+};
+
+const GROUP_NAME_KEY: &str = "group_name";
+const STREAM_NAME_KEY: &str = "stream_name";
+const INGESTION_TIMESTAMP_KEY: &str = "ingestion_timestamp";
+
+enum ReadError {
+    Request(SdkError<FilterLogEventsError>),
+    StreamClosed,
+}
+
+/// Why a log group stopped being read.
+#[derive(Debug, PartialEq)]
+enum TailEnd {
+    Shutdown,
+    Deleted,
+}
+
+pub(super) struct CloudwatchLogsSource {
+    pub(super) client: CloudwatchLogsClient,
+    pub(super) group_names: Vec<String>,
+    pub(super) group_name_prefixes: Vec<String>,
+    pub(super) stream_names: Vec<String>,
+    pub(super) stream_name_prefix: Option<String>,
+    pub(super) filter_pattern: Option<String>,
+    pub(super) poll_interval: Duration,
+    pub(super) ignore_older: Option<Duration>,
+    pub(super) decoder: Decoder,
+    pub(super) checkpointer: SharedCheckpointer,
+}
+
+impl CloudwatchLogsSource {
+    pub(super) async fn run(self, out: SourceSender, shutdown: ShutdownSignal) -> Result<(), ()> {
+        let source = Arc::new(self);
+        let mut tailed_groups = HashSet::new();
+        let mut task_handles = vec![];
+
+        // Each log group is tailed by its own task, so that throttling of one group only backs off
+        // reads of that group. Tasks stop once their log group is deleted, which is then tailed
+        // again only if it's discovered again.
+        let (deleted_sender, mut deleted_groups) = mpsc::unbounded_channel();
+        let mut discover_interval = interval(source.poll_interval);
+        let discover_shutdown = shutdown.clone().fuse();
+        pin!(discover_shutdown);
+        loop {
+            select! {
+                _ = &mut discover_shutdown => break,
+                Some(group_name) = deleted_groups.recv() => {
+                    tailed_groups.remove(&group_name);
+                    continue;
+                }
+                _ = discover_interval.tick() => {},
+            }
+
+            for group_name in source.discover_groups().await {
+                if tailed_groups.insert(group_name.clone()) {
+                    let source = Arc::clone(&source);
+                    let out = out.clone();
+                    let shutdown = shutdown.clone();
+                    let deleted_sender = deleted_sender.clone();
+                    task_handles.push(tokio::spawn(async move {
+                        if source.tail_group(&group_name, out, shutdown).await == TailEnd::Deleted {
+                            let _ = deleted_sender.send(group_name);
+                        }
+                    }));
+                }
+            }
+        }
+
+        // Wait for all of the tasks to finish.  If any one of them panics, we resume
+        // that panic here to properly shutdown Vector.
+        for task_handle in task_handles.drain(..) {
+            if let Err(e) = task_handle.await {
+                if e.is_panic() {
+                    panic::resume_unwind(e.into_panic());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the configured log groups along with the log groups matching the configured prefixes.
+    async fn discover_groups(&self) -> Vec<String> {
+        let mut group_names = self.group_names.clone();
+        for prefix in &self.group_name_prefixes {
+            let mut next_token = None;
+            loop {
+                let result = self
+                    .client
+                    .describe_log_groups()
+                    .log_group_name_prefix(prefix)
+                    .set_next_token(next_token)
+                    .send()
+                    .await;
+
+                match result {
+                    Ok(output) => {
+                        group_names.extend(
+                            output
+                                .log_groups
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter_map(|group| group.log_group_name),
+                        );
+                        next_token = output.next_token;
+                        if next_token.is_none() {
+                            break;
+                        }
+                    }
+                    Err(error) => {
+                        emit!(AwsCloudwatchLogsDescribeLogGroupsError { error: &error });
+                        break;
+                    }
+                }
+            }
+        }
+        group_names
+    }
+
+    /// Reads the log group until shutdown, or until it is deleted.
+    async fn tail_group(
+        &self,
+        group_name: &str,
+        mut out: SourceSender,
+        shutdown: ShutdownSignal,
+    ) -> TailEnd {
+        let mut checkpoint = match self.checkpointer.get(group_name).await {
+            Some(checkpoint) => checkpoint,
+            None => GroupCheckpoint::new(self.initial_start_time()),
+        };
+        let mut backoff = fresh_backoff();
+
+        let shutdown = shutdown.fuse();
+        pin!(shutdown);
+        loop {
+            let delay = match self.read_group(group_name, &mut checkpoint, &mut out).await {
+                Ok(()) => {
+                    backoff = fresh_backoff();
+                    self.poll_interval
+                }
+                Err(ReadError::Request(error)) if is_group_not_found(&error) => {
+                    emit!(AwsCloudwatchLogsGroupDeleted { group_name });
+                    return TailEnd::Deleted;
+                }
+                Err(ReadError::Request(error)) => {
+                    emit!(AwsCloudwatchLogsFilterLogEventsError {
+                        group_name,
+                        error: &error,
+                    });
+                    if is_retriable_error(&error) {
+                        backoff.next().unwrap_or(self.poll_interval)
+                    } else {
+                        // The pagination token may have expired, so start over from the newest
+                        // event read.
+                        checkpoint.reset_query();
+                        self.poll_interval
+                    }
+                }
+                Err(ReadError::StreamClosed) => return TailEnd::Shutdown,
+            };
+
+            select! {
+                _ = &mut shutdown => return TailEnd::Shutdown,
+                _ = tokio::time::sleep(delay) => {},
+            }
+        }
+    }
+
+    /// Reads all events of the log group that are newer than the checkpoint.
+    async fn read_group(
+        &self,
+        group_name: &str,
+        checkpoint: &mut GroupCheckpoint,
+        out: &mut SourceSender,
+    ) -> Result<(), ReadError> {
+        loop {
+            let output = self
+                .client
+                .filter_log_events()
+                .log_group_name(group_name)
+                .set_log_stream_names(
+                    (!self.stream_names.is_empty()).then(|| self.stream_names.clone()),
+                )
+                .set_log_stream_name_prefix(self.stream_name_prefix.clone())
+                .set_filter_pattern(self.filter_pattern.clone())
+                .start_time(checkpoint.query_start_time)
+                .set_next_token(checkpoint.next_token.clone())
+                .send()
+                .await
+                .map_err(ReadError::Request)?;
+
+            let log_events = output.events.unwrap_or_default();
+            emit!(EndpointBytesReceived {
+                byte_size: log_events
+                    .iter()
+                    .map(|log_event| log_event.message().map_or(0, str::len))
+                    .sum(),
+                protocol: "http",
+                endpoint: group_name,
+            });
+
+            let mut events = Vec::with_capacity(log_events.len());
+            for log_event in log_events {
+                let timestamp = log_event.timestamp.unwrap_or_default();
+                let event_id = log_event.event_id.unwrap_or_default();
+                if checkpoint.contains(timestamp, &event_id) {
+                    continue;
+                }
+
+                let message = log_event.message.unwrap_or_default();
+                let decoded = util::decode_message(
+                    self.decoder.clone(),
+                    "aws_cloudwatch_logs",
+                    message.as_bytes(),
+                    Utc.timestamp_millis_opt(timestamp).single(),
+                    &None,
+                );
+                events.extend(decoded.map(|mut event| {
+                    if let Event::Log(ref mut log) = event {
+                        log.insert(GROUP_NAME_KEY, group_name.to_owned());
+                        if let Some(stream_name) = &log_event.log_stream_name {
+                            log.insert(STREAM_NAME_KEY, stream_name.clone());
+                        }
+                        if let Some(ingestion_time) = log_event
+                            .ingestion_time
+                            .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+                        {
+                            log.insert(INGESTION_TIMESTAMP_KEY, ingestion_time);
+                        }
+                    }
+                    event
+                }));
+                checkpoint.advance(timestamp, event_id);
+            }
+
+            if !events.is_empty() {
+                let count = events.len();
+                out.send_batch(events).await.map_err(|error| {
+                    emit!(StreamClosedError { error, count });
+                    ReadError::StreamClosed
+                })?;
+            }
+
+            let exhausted = output.next_token.is_none();
+            checkpoint.set_next_token(output.next_token);
+            self.checkpointer.set(group_name, checkpoint.clone()).await;
+
+            if exhausted {
+                return Ok(());
+            }
+        }
+    }
+
+    fn initial_start_time(&self) -> i64 {
+        self.ignore_older
+            .and_then(|ignore_older| chrono::Duration::from_std(ignore_older).ok())
+            .map_or(0, |ignore_older| {
+                (Utc::now() - ignore_older).timestamp_millis()
+            })
+    }
+}
+
+fn is_group_not_found(error: &SdkError<FilterLogEventsError>) -> bool {
+    matches!(
+        error,
+        SdkError::ServiceError { err, .. }
+            if matches!(err.kind, FilterLogEventsErrorKind::ResourceNotFoundException(_))
+    )
+}
+
+const fn fresh_backoff() -> ExponentialBackoff {
+    ExponentialBackoff::from_millis(2)
+        .factor(250)
+        .max_delay(Duration::from_secs(60))
+}
@@ -5,6 +5,7 @@ mod tests;
 
 pub mod logs;
 pub mod metrics;
+mod passthrough;
 pub mod traces;
 
 pub(crate) mod ddmetric_proto {
@@ -33,6 +34,8 @@ use vector_core::config::LogNamespace;
 use vector_core::event::{BatchNotifier, BatchStatus};
 use warp::{filters::BoxedFilter, reject::Rejection, reply::Response, Filter, Reply};
 
+use self::passthrough::Passthrough;
+pub use self::passthrough::PassthroughConfig;
 use crate::{
     codecs::{Decoder, DecodingConfig},
     config::{
@@ -103,6 +106,10 @@ pub struct DatadogAgentConfig {
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: AcknowledgementsConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    passthrough: PassthroughConfig,
 }
 
 impl GenerateConfig for DatadogAgentConfig {
@@ -119,6 +126,7 @@ impl GenerateConfig for DatadogAgentConfig {
             disable_traces: false,
             multiple_outputs: false,
             log_namespace: Some(false),
+            passthrough: PassthroughConfig::default(),
         })
         .unwrap()
     }
@@ -132,7 +140,8 @@ inventory::submit! {
 #[typetag::serde(name = "datadog_agent")]
 impl SourceConfig for DatadogAgentConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<sources::Source> {
-        let log_namespace = cx.log_namespace(self.log_namespace);
+        let log_namespace =
+            cx.required_log_namespace(self.log_namespace, self.requires_vector_namespace())?;
 
         let logs_schema_definition = cx
             .schema_definitions
@@ -160,9 +169,10 @@ impl SourceConfig for DatadogAgentConfig {
             metrics_schema_definition,
             log_namespace,
         );
+        let passthrough = Passthrough::build(&self.passthrough, &cx.proxy)?;
         let listener = tls.bind(&self.address).await?;
         let acknowledgements = cx.do_acknowledgements(&self.acknowledgements);
-        let filters = source.build_warp_filters(cx.out, acknowledgements, self)?;
+        let filters = source.build_warp_filters(cx.out, acknowledgements, self, passthrough)?;
         let shutdown = cx.shutdown;
 
         info!(message = "Building HTTP server.", address = %self.address);
@@ -271,6 +281,13 @@ impl SourceConfig for DatadogAgentConfig {
     fn can_acknowledge(&self) -> bool {
         true
     }
+
+    fn generate_schema_hint(&self) -> Option<crate::config::schema::Options> {
+        Some(crate::config::schema::Options {
+            log_namespace: Some(self.log_namespace.unwrap_or(false)),
+            ..Default::default()
+        })
+    }
 }
 
 #[derive(Clone, Copy, Debug, Snafu)]
@@ -359,6 +376,7 @@ impl DatadogAgentSource {
         out: SourceSender,
         acknowledgements: bool,
         config: &DatadogAgentConfig,
+        passthrough: Option<Passthrough>,
     ) -> crate::Result<BoxedFilter<(Response,)>> {
         let mut filters = (!config.disable_logs).then(|| {
             logs::build_warp_filter(
@@ -393,7 +411,17 @@ impl DatadogAgentSource {
                 .or(Some(metrics_filter));
         }
 
-        filters.ok_or_else(|| "At least one of the supported data type shall be enabled".into())
+        let filters =
+            filters.ok_or_else(|| "At least one of the supported data type shall be enabled")?;
+
+        // The passthrough filter matches any other path, so it must be tried last.
+        Ok(match passthrough {
+            Some(passthrough) => filters
+                .or(passthrough::build_warp_filter(passthrough))
+                .unify()
+                .boxed(),
+            None => filters,
+        })
     }
 
     pub(crate) fn decode(
@@ -0,0 +1,208 @@
+use bytes::Bytes;
+use derivative::Derivative;
+use http::{HeaderMap, Method, Request, StatusCode, Uri};
+use hyper::Body;
+use snafu::Snafu;
+use vector_config::configurable_component;
+use warp::{filters::BoxedFilter, path::FullPath, reply::Response, Filter, Rejection, Reply};
+
+use crate::{
+    config::ProxyConfig,
+    http::HttpClient,
+    internal_events::{DatadogAgentPassthroughError, DatadogAgentPassthroughRequest},
+    sources::util::ErrorMessage,
+    tls::TlsSettings,
+};
+
+/// The path prefixes of the intake endpoints handled by the source itself.
+const SUPPORTED_PATHS: [&str; 7] = [
+    "/v1/input",
+    "/api/v2/logs",
+    "/api/beta/sketches",
+    "/api/v1/series",
+    "/api/v2/series",
+    "/api/v0.2/traces",
+    "/api/v0.2/stats",
+];
+
+/// The headers that only apply to a single connection, which aren't proxied.
+const HOP_BY_HOP_HEADERS: [&str; 8] = [
+    "connection",
+    "host",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "upgrade",
+];
+
+/// How requests to the intake endpoints not supported by the source are handled.
+///
+/// The Agent also sends the payloads of other products, such as processes, orchestrator resources and host metadata,
+/// to its intake endpoints, and retries them when they fail.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "mode")]
+pub enum PassthroughConfig {
+    /// Requests are rejected with `404 Not Found`.
+    #[derivative(Default)]
+    Reject,
+
+    /// Requests are acknowledged with `202 Accepted`, and their payloads are discarded.
+    Accept,
+
+    /// Requests are proxied verbatim to an upstream endpoint, and its responses are returned to the Agent.
+    Proxy {
+        /// The base URL of the upstream endpoint, such as `https://app.datadoghq.com`.
+        ///
+        /// The path and query of requests are appended to it.
+        endpoint: String,
+    },
+}
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display(
+        "Invalid passthrough `endpoint` {:?}, it must be an absolute URL",
+        endpoint
+    ))]
+    InvalidEndpoint { endpoint: String },
+}
+
+/// Handles the requests to unsupported intake endpoints.
+#[derive(Clone, Debug)]
+pub(crate) enum Passthrough {
+    Accept,
+    Proxy {
+        endpoint: String,
+        client: HttpClient,
+    },
+}
+
+impl Passthrough {
+    /// Builds the handler of the requests to unsupported intake endpoints, or `None` if they're
+    /// rejected.
+    pub(crate) fn build(
+        config: &PassthroughConfig,
+        proxy: &ProxyConfig,
+    ) -> crate::Result<Option<Self>> {
+        Ok(match config {
+            PassthroughConfig::Reject => None,
+            PassthroughConfig::Accept => Some(Self::Accept),
+            PassthroughConfig::Proxy { endpoint } => {
+                let valid = endpoint.parse::<Uri>().map_or(false, |uri| {
+                    uri.scheme().is_some() && uri.authority().is_some()
+                });
+                if !valid {
+                    return Err(BuildError::InvalidEndpoint {
+                        endpoint: endpoint.clone(),
+                    }
+                    .into());
+                }
+                Some(Self::Proxy {
+                    endpoint: endpoint.trim_end_matches('/').to_owned(),
+                    client: HttpClient::new(TlsSettings::from_options(&None)?, proxy)?,
+                })
+            }
+        })
+    }
+
+    async fn handle(
+        self,
+        path: FullPath,
+        method: Method,
+        query: String,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> Result<Response, Rejection> {
+        let path = path.as_str();
+        match self {
+            Self::Accept => {
+                emit!(DatadogAgentPassthroughRequest {
+                    path,
+                    mode: "accept",
+                });
+                Ok(warp::reply::with_status(warp::reply(), StatusCode::ACCEPTED).into_response())
+            }
+            Self::Proxy { endpoint, client } => {
+                emit!(DatadogAgentPassthroughRequest {
+                    path,
+                    mode: "proxy",
+                });
+                let mut uri = format!("{}{}", endpoint, path);
+                if !query.is_empty() {
+                    uri.push('?');
+                    uri.push_str(&query);
+                }
+
+                let mut request = Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .body(Body::from(body))
+                    .map_err(|error| bad_gateway(path, error))?;
+                *request.headers_mut() = headers;
+                for name in HOP_BY_HOP_HEADERS {
+                    request.headers_mut().remove(name);
+                }
+
+                client
+                    .send(request)
+                    .await
+                    .map_err(|error| bad_gateway(path, error))
+            }
+        }
+    }
+}
+
+fn bad_gateway(path: &str, error: impl std::fmt::Display) -> Rejection {
+    let message = format!("Failed to proxy request: {}", error);
+    emit!(DatadogAgentPassthroughError { path, error });
+    warp::reject::custom(ErrorMessage::new(StatusCode::BAD_GATEWAY, message))
+}
+
+fn is_supported(path: &str) -> bool {
+    SUPPORTED_PATHS.iter().any(|prefix| {
+        path.strip_prefix(prefix)
+            .map_or(false, |rest| rest.is_empty() || rest.starts_with('/'))
+    })
+}
+
+/// Builds the filter passing through the requests to any path but the supported intake endpoints,
+/// which must be tried last.
+pub(crate) fn build_warp_filter(passthrough: Passthrough) -> BoxedFilter<(Response,)> {
+    warp::path::full()
+        .and_then(|path: FullPath| async move {
+            if is_supported(path.as_str()) {
+                Err(warp::reject::not_found())
+            } else {
+                Ok(path)
+            }
+        })
+        .and(warp::method())
+        .and(warp::query::raw().or(warp::any().map(String::new)).unify())
+        .and(warp::header::headers_cloned())
+        .and(warp::body::bytes())
+        .and_then(move |path, method, query, headers, body| {
+            passthrough
+                .clone()
+                .handle(path, method, query, headers, body)
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supported_paths() {
+        assert!(is_supported("/v1/input"));
+        assert!(is_supported("/v1/input/0123456789abcdef0123456789abcdef"));
+        assert!(is_supported("/api/v2/series"));
+        assert!(!is_supported("/api/v1/collector"));
+        assert!(!is_supported("/api/v2/orch"));
+        assert!(!is_supported("/api/v1/seriesx"));
+    }
+}
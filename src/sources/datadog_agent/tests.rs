@@ -1542,6 +1542,7 @@ fn test_config_outputs() {
             disable_metrics: false,
             disable_traces: false,
             log_namespace: Some(false),
+            passthrough: Default::default(),
         };
 
         let mut outputs = config
@@ -1923,3 +1924,97 @@ fn test_output_schema_definition_bytes_legacy_namespace() {
         .with_field("timestamp", Kind::timestamp(), Some("timestamp"))
     )
 }
+
+async fn passthrough_source(passthrough: &str) -> SocketAddr {
+    let (sender, _) = SourceSender::new_test();
+    let address = next_addr();
+    let config = toml::from_str::<DatadogAgentConfig>(&format!(
+        indoc! { r#"
+            address = "{}"
+            passthrough = {}
+        "#},
+        address, passthrough
+    ))
+    .unwrap();
+    let schema_definitions = HashMap::from([
+        (Some(LOGS.to_owned()), test_logs_schema_definition()),
+        (Some(METRICS.to_owned()), test_metrics_schema_definition()),
+    ]);
+    let context = SourceContext::new_test(sender, Some(schema_definitions));
+    tokio::spawn(async move {
+        config.build(context).await.unwrap().await.unwrap();
+    });
+    wait_for_tcp(address).await;
+    address
+}
+
+#[tokio::test]
+async fn passthrough_accepts_unsupported_intakes() {
+    let addr = passthrough_source(r#"{ mode = "accept" }"#).await;
+
+    assert_eq!(
+        202,
+        send_with_path(addr, "{}", HeaderMap::new(), "/api/v1/collector").await
+    );
+    assert_eq!(
+        202,
+        send_with_path(addr, "{}", HeaderMap::new(), "/intake/").await
+    );
+    // Invalid payloads of supported intakes are still rejected.
+    assert_eq!(
+        422,
+        send_with_path(addr, "garbage", HeaderMap::new(), "/api/v2/series").await
+    );
+}
+
+#[tokio::test]
+async fn passthrough_rejects_unsupported_intakes_by_default() {
+    let addr = passthrough_source(r#"{ mode = "reject" }"#).await;
+
+    assert_eq!(
+        404,
+        send_with_path(addr, "{}", HeaderMap::new(), "/api/v1/collector").await
+    );
+}
+
+#[tokio::test]
+async fn passthrough_proxies_unsupported_intakes() {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let upstream = warp::path::full()
+        .and(warp::query::raw())
+        .and(warp::header::headers_cloned())
+        .and(warp::body::bytes())
+        .map(
+            move |path: warp::path::FullPath, query: String, headers: HeaderMap, body: Bytes| {
+                tx.send((path.as_str().to_owned(), query, headers, body))
+                    .unwrap();
+                warp::reply::with_status("upstream", http::StatusCode::CREATED)
+            },
+        );
+    let upstream_address = next_addr();
+    tokio::spawn(warp::serve(upstream).run(upstream_address));
+    wait_for_tcp(upstream_address).await;
+
+    let addr = passthrough_source(&format!(
+        r#"{{ mode = "proxy", endpoint = "http://{}/" }}"#,
+        upstream_address
+    ))
+    .await;
+
+    let response = reqwest::Client::new()
+        .post(&format!("http://{}/api/v1/collector?dd-api-key=abc", addr))
+        .header("dd-evp-origin", "agent")
+        .body("payload")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status().as_u16(), 201);
+    assert_eq!(response.text().await.unwrap(), "upstream");
+
+    let (path, query, headers, body) = rx.recv().await.unwrap();
+    assert_eq!(path, "/api/v1/collector");
+    assert_eq!(query, "dd-api-key=abc");
+    assert_eq!(headers["dd-evp-origin"], "agent");
+    assert_eq!(headers["host"], upstream_address.to_string().as_str());
+    assert_eq!(body, Bytes::from("payload"));
+}
@@ -16,7 +16,7 @@ use vector_core::ByteSizeOf;
 
 use crate::{
     codecs::{Decoder, DecodingConfig},
-    config::{log_schema, Output, SourceConfig, SourceContext, SourceDescription},
+    config::{log_schema, schema, Output, SourceConfig, SourceContext, SourceDescription},
     internal_events::{BytesReceived, DemoLogsEventProcessed, EventsReceived, StreamClosedError},
     serde::{default_decoding, default_framing_message_based},
     shutdown::ShutdownSignal,
@@ -260,7 +260,8 @@ impl_generate_config_from_default!(DemoLogsConfig);
 #[typetag::serde(name = "demo_logs")]
 impl SourceConfig for DemoLogsConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
-        let log_namespace = cx.log_namespace(self.log_namespace);
+        let log_namespace =
+            cx.required_log_namespace(self.log_namespace, self.requires_vector_namespace())?;
 
         self.format.validate()?;
         let decoder =
@@ -296,6 +297,13 @@ impl SourceConfig for DemoLogsConfig {
     fn can_acknowledge(&self) -> bool {
         false
     }
+
+    fn generate_schema_hint(&self) -> Option<schema::Options> {
+        Some(schema::Options {
+            log_namespace: Some(self.log_namespace.unwrap_or(false)),
+            ..Default::default()
+        })
+    }
 }
 
 /// Configuration for the `generator` source.
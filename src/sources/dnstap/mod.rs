@@ -1,10 +1,12 @@
-use std::path::PathBuf;
+use std::{net::SocketAddr, path::PathBuf};
 
 use bytes::Bytes;
 use vector_config::configurable_component;
 use vector_core::ByteSizeOf;
 
-use super::util::framestream::{build_framestream_unix_source, FrameHandler};
+use super::util::framestream::{
+    build_framestream_tcp_source, build_framestream_unix_source, FrameHandler,
+};
 use crate::{
     config::{log_schema, DataType, Output, SourceConfig, SourceContext, SourceDescription},
     event::{Event, LogEvent},
@@ -13,7 +15,7 @@ use crate::{
 };
 
 pub mod parser;
-pub use parser::{parse_dnstap_data, DnstapParser};
+pub use parser::{parse_dnstap_data, parse_dnstap_envelope, DnstapParser};
 
 pub mod schema;
 use dnsmsg_parser::{dns_message, dns_message_parser};
@@ -28,9 +30,18 @@ pub struct DnstapConfig {
     #[serde(default = "default_max_frame_length")]
     pub max_frame_length: usize,
 
+    /// The type of socket to listen on for DNSTAP data.
+    #[serde(default)]
+    pub mode: DnstapMode,
+
+    /// The address to listen for DNSTAP connections on.
+    ///
+    /// Only relevant, and required, when `mode` is `tcp`.
+    pub address: Option<SocketAddr>,
+
     /// Overrides the name of the log field used to add the source path to each event.
     ///
-    /// The value will be the socket path itself.
+    /// The value will be the socket path itself, or the IP address of the peer when listening over TCP.
     ///
     /// By default, the [global `log_schema.host_key` option][global_host_key] is used.
     ///
@@ -41,6 +52,9 @@ pub struct DnstapConfig {
     ///
     /// The DNS server must be configured to send its DNSTAP data to this socket file. The socket file will be created,
     /// if it doesn't already exist, when the source first starts.
+    ///
+    /// Only relevant when `mode` is `unix`.
+    #[serde(default = "default_socket_path")]
     pub socket_path: PathBuf,
 
     /// Whether or not to skip parsing/decoding of DNSTAP frames.
@@ -49,6 +63,13 @@ pub struct DnstapConfig {
     /// (called `rawData`) and encoded as a base64 string.
     raw_data_only: Option<bool>,
 
+    /// Whether or not to skip parsing/decoding of the DNS messages within DNSTAP frames.
+    ///
+    /// If set to `true`, only the DNSTAP envelope of each frame is decoded. The DNS query and response messages will
+    /// not be parsed, and are instead set on the event as raw bytes, in the `rawData` field of `requestData` and
+    /// `responseData` respectively. Has no effect if `raw_data_only` is set to `true`.
+    envelope_only: Option<bool>,
+
     /// Whether or not to concurrently process DNSTAP frames.
     pub multithreaded: Option<bool>,
 
@@ -61,6 +82,16 @@ pub struct DnstapConfig {
     /// language, but it is most intuitive to use an octal number.
     pub socket_file_mode: Option<u32>,
 
+    /// The user ID to set as the owner of the unix socket file.
+    ///
+    /// If not set, the owner will be the user Vector is running as.
+    pub socket_file_owner: Option<u32>,
+
+    /// The group ID to set as the group of the unix socket file.
+    ///
+    /// If not set, the group will be the primary group of the user Vector is running as.
+    pub socket_file_group: Option<u32>,
+
     /// The size, in bytes, of the receive buffer used for the socket.
     ///
     /// This should not typically needed to be changed.
@@ -72,10 +103,28 @@ pub struct DnstapConfig {
     pub socket_send_buffer_size: Option<usize>,
 }
 
+/// The type of socket to listen on for DNSTAP data.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DnstapMode {
+    /// Listen on a unix socket, at `socket_path`.
+    #[derivative(Default)]
+    Unix,
+
+    /// Listen on a TCP socket, at `address`.
+    Tcp,
+}
+
 fn default_max_frame_length() -> usize {
     bytesize::kib(100u64) as usize
 }
 
+fn default_socket_path() -> PathBuf {
+    PathBuf::from("/run/bind/dnstap.sock")
+}
+
 impl DnstapConfig {
     pub fn new(socket_path: PathBuf) -> Self {
         Self {
@@ -93,13 +142,18 @@ impl DnstapConfig {
 impl Default for DnstapConfig {
     fn default() -> Self {
         Self {
+            mode: DnstapMode::Unix,
+            address: None,
             host_key: None,
             max_frame_length: default_max_frame_length(),
-            socket_path: PathBuf::from("/run/bind/dnstap.sock"),
+            socket_path: default_socket_path(),
             raw_data_only: None,
+            envelope_only: None,
             multithreaded: None,
             max_frame_handling_tasks: None,
             socket_file_mode: None,
+            socket_file_owner: None,
+            socket_file_group: None,
             socket_receive_buffer_size: None,
             socket_send_buffer_size: None,
         }
@@ -117,7 +171,15 @@ impl_generate_config_from_default!(DnstapConfig);
 impl SourceConfig for DnstapConfig {
     async fn build(&self, cx: SourceContext) -> Result<super::Source> {
         let frame_handler = DnstapFrameHandler::new(self);
-        build_framestream_unix_source(frame_handler, cx.shutdown, cx.out)
+        match self.mode {
+            DnstapMode::Unix => build_framestream_unix_source(frame_handler, cx.shutdown, cx.out),
+            DnstapMode::Tcp => {
+                let address = self
+                    .address
+                    .ok_or("The `address` option is required when `mode` is `tcp`.")?;
+                build_framestream_tcp_source(frame_handler, address, cx.shutdown, cx.out)
+            }
+        }
     }
 
     fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
@@ -140,9 +202,12 @@ pub struct DnstapFrameHandler {
     content_type: String,
     schema: DnstapEventSchema,
     raw_data_only: bool,
+    envelope_only: bool,
     multithreaded: bool,
     max_frame_handling_tasks: u32,
     socket_file_mode: Option<u32>,
+    socket_file_owner: Option<u32>,
+    socket_file_group: Option<u32>,
     socket_receive_buffer_size: Option<usize>,
     socket_send_buffer_size: Option<usize>,
     host_key: String,
@@ -169,9 +234,12 @@ impl DnstapFrameHandler {
             content_type: config.content_type(),
             schema,
             raw_data_only: config.raw_data_only.unwrap_or(false),
+            envelope_only: config.envelope_only.unwrap_or(false),
             multithreaded: config.multithreaded.unwrap_or(false),
             max_frame_handling_tasks: config.max_frame_handling_tasks.unwrap_or(1000),
             socket_file_mode: config.socket_file_mode,
+            socket_file_owner: config.socket_file_owner,
+            socket_file_group: config.socket_file_group,
             socket_receive_buffer_size: config.socket_receive_buffer_size,
             socket_send_buffer_size: config.socket_send_buffer_size,
             host_key,
@@ -216,7 +284,13 @@ impl FrameHandler for DnstapFrameHandler {
             });
             Some(event)
         } else {
-            match parse_dnstap_data(&self.schema, &mut log_event, frame) {
+            let parse_result = if self.envelope_only {
+                parse_dnstap_envelope(&self.schema, &mut log_event, frame)
+            } else {
+                parse_dnstap_data(&self.schema, &mut log_event, frame)
+            };
+
+            match parse_result {
                 Err(err) => {
                     emit!(DnstapParseError {
                         error: format!("Dnstap protobuf decode error {:?}.", err).as_str()
@@ -234,6 +308,7 @@ impl FrameHandler for DnstapFrameHandler {
             }
         }
     }
+
     fn socket_path(&self) -> PathBuf {
         self.socket_path.clone()
     }
@@ -250,6 +325,14 @@ impl FrameHandler for DnstapFrameHandler {
         self.socket_file_mode
     }
 
+    fn socket_file_owner(&self) -> Option<u32> {
+        self.socket_file_owner
+    }
+
+    fn socket_file_group(&self) -> Option<u32> {
+        self.socket_file_group
+    }
+
     fn socket_receive_buffer_size(&self) -> Option<usize> {
         self.socket_receive_buffer_size
     }
@@ -295,13 +378,18 @@ mod integration_tests {
                 let socket = get_socket(raw_data, query_type);
 
                 DnstapConfig {
+                    mode: DnstapMode::Unix,
+                    address: None,
                     max_frame_length: 102400,
                     host_key: Some("key".to_string()),
                     socket_path: socket,
                     raw_data_only: Some(raw_data),
+                    envelope_only: None,
                     multithreaded: Some(false),
                     max_frame_handling_tasks: Some(100000),
                     socket_file_mode: Some(511),
+                    socket_file_owner: None,
+                    socket_file_group: None,
                     socket_receive_buffer_size: Some(10485760),
                     socket_send_buffer_size: Some(10485760),
                 }
@@ -78,6 +78,7 @@ pub struct DnstapParser<'a> {
     event_schema: &'a DnstapEventSchema,
     parent_key_path: OwnedPath,
     log_event: &'a mut LogEvent,
+    raw_data_only: bool,
 }
 
 pub fn parse_dnstap_data(
@@ -88,15 +89,35 @@ pub fn parse_dnstap_data(
     DnstapParser::new(event_schema, log_event).parse_dnstap_data(frame)
 }
 
+/// Parses the dnstap envelope of a frame, without decoding the DNS messages it carries.
+///
+/// The DNS query and response messages are set on the event as raw bytes.
+pub fn parse_dnstap_envelope(
+    event_schema: &DnstapEventSchema,
+    log_event: &mut LogEvent,
+    frame: Bytes,
+) -> Result<()> {
+    DnstapParser::new(event_schema, log_event)
+        .raw_data_only(true)
+        .parse_dnstap_data(frame)
+}
+
 impl<'a> DnstapParser<'a> {
     pub fn new(event_schema: &'a DnstapEventSchema, log_event: &'a mut LogEvent) -> Self {
         Self {
             event_schema,
             parent_key_path: Vec::new().into(),
             log_event,
+            raw_data_only: false,
         }
     }
 
+    /// Skips the parsing of the DNS wire format messages, setting them on the event as raw bytes instead.
+    pub const fn raw_data_only(mut self, raw_data_only: bool) -> Self {
+        self.raw_data_only = raw_data_only;
+        self
+    }
+
     fn insert<V>(&mut self, key: &'static str, value: V) -> Option<Value>
     where
         V: Into<Value> + Debug,
@@ -378,6 +399,18 @@ impl<'a> DnstapParser<'a> {
             }
         }
 
+        if self.raw_data_only {
+            if let Some(query_message) = dnstap_message.query_message {
+                self.log_raw_dns_message_bytes(request_message_key, query_message);
+            }
+
+            if let Some(response_message) = dnstap_message.response_message {
+                self.log_raw_dns_message_bytes(response_message_key, response_message);
+            }
+
+            return Ok(());
+        }
+
         match dnstap_message_type_id {
             1..=12 => {
                 if let Some(query_message) = dnstap_message.query_message {
@@ -476,6 +509,17 @@ impl<'a> DnstapParser<'a> {
         self.parent_key_path.segments.pop();
     }
 
+    fn log_raw_dns_message_bytes(&mut self, key_prefix: &'static str, raw_dns_message: Vec<u8>) {
+        self.parent_key_path.push_field(key_prefix);
+
+        self.insert(
+            self.event_schema.dns_query_message_schema().raw_data(),
+            Bytes::from(raw_dns_message),
+        );
+
+        self.parent_key_path.segments.pop();
+    }
+
     fn parse_dns_query_message(
         &mut self,
         key_prefix: &'static str,
@@ -1066,6 +1110,32 @@ mod tests {
             }));
     }
 
+    #[test]
+    fn test_parse_dnstap_data_raw_data_only() {
+        let mut log_event = LogEvent::default();
+        let schema = DnstapEventSchema::new();
+        let raw_dnstap_data = "ChVqYW1lcy1WaXJ0dWFsLU1hY2hpbmUSC0JJTkQgOS4xNi4zcnoIAxACGAEiEAAAAAAAAA\
+        AAAAAAAAAAAAAqECABBQJwlAAAAAAAAAAAADAw8+0CODVA7+zq9wVNMU3WNlI2kwIAAAABAAAAAAABCWZhY2Vib29rMQNjb\
+        20AAAEAAQAAKQIAAACAAAAMAAoACOxjCAG9zVgzWgUDY29tAHgB";
+        let dnstap_data = base64::decode(raw_dnstap_data).expect("Invalid base64 encoded data.");
+        let parse_result = parse_dnstap_envelope(&schema, &mut log_event, Bytes::from(dnstap_data));
+        assert!(parse_result.is_ok());
+        assert!(log_event
+            .all_fields()
+            .unwrap()
+            .any(|(key, value)| key == "time"
+                && match *value {
+                    Value::Integer(time) => time == 1_593_489_007_920_014_129,
+                    _ => false,
+                }));
+        assert!(matches!(
+            log_event.get("requestData.rawData"),
+            Some(Value::Bytes(raw_data)) if raw_data.len() == 54
+        ));
+        assert!(log_event.get("requestData.header").is_none());
+        assert!(log_event.get("requestData.question").is_none());
+    }
+
     #[test]
     fn test_parse_dnstap_data_with_invalid_data() {
         let mut log_event = LogEvent::default();
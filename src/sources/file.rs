@@ -101,6 +101,9 @@ pub struct FileConfig {
     pub read_from: Option<ReadFromConfig>,
 
     /// Ignore files with a data modification date older than the specified number of seconds.
+    ///
+    /// This also applies to files that go idle while being watched: once they have been read to
+    /// their end, they are not read again until they are modified.
     #[serde(alias = "ignore_older")]
     pub ignore_older_secs: Option<u64>,
 
@@ -159,7 +162,15 @@ pub struct FileConfig {
     /// An approximate limit on the amount of data read from a single file at a given time.
     pub max_read_bytes: usize,
 
+    /// An approximate limit on the amount of data read from a single file per second.
+    ///
+    /// Files which reach this limit are skipped for the rest of the second, so that replaying a
+    /// large file doesn't delay reading other files. By default, reads are not throttled.
+    pub max_read_bytes_per_second: Option<usize>,
+
     /// Instead of balancing read capacity fairly across all watched files, prioritize draining the oldest files before moving on to read data from younger files.
+    ///
+    /// A file that is behind still lets the younger files be read once a second, so that they aren't starved.
     pub oldest_first: bool,
 
     /// Timeout from reaching `EOF` after which file will be removed from filesystem, unless new data is written in the meantime.
@@ -300,6 +311,7 @@ impl Default for FileConfig {
             multi_line_timeout: 1000, // millis
             multiline: None,
             max_read_bytes: 2048,
+            max_read_bytes_per_second: None,
             oldest_first: false,
             remove_after_secs: None,
             line_delimiter: "\n".to_string(),
@@ -403,9 +415,11 @@ pub fn file_source(
     let file_server = FileServer {
         paths_provider,
         max_read_bytes: config.max_read_bytes,
+        max_read_bytes_per_second: config.max_read_bytes_per_second,
         ignore_checkpoints,
         read_from,
         ignore_before,
+        ignore_older: config.ignore_older_secs.map(Duration::from_secs),
         max_line_bytes: config.max_line_bytes,
         line_delimiter: line_delimiter_as_bytes,
         data_dir,
@@ -1707,6 +1721,107 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_throttled_backlog_does_not_starve_live_files() {
+        let dir = tempdir().unwrap();
+        let config = file::FileConfig {
+            include: vec![dir.path().join("*")],
+            max_read_bytes_per_second: Some(1000),
+            oldest_first: true,
+            ..test_default_file_config(&dir)
+        };
+
+        let backlog_path = dir.path().join("z_backlog_file");
+        let mut backlog = File::create(&backlog_path).unwrap();
+        let backlog_lines = (0..10_000)
+            .map(|i| format!("backlog line {:05}\n", i))
+            .collect::<String>();
+        backlog.write_all(backlog_lines.as_bytes()).unwrap();
+
+        sleep_500_millis().await;
+
+        let live_path = dir.path().join("a_live_file");
+        let mut live = File::create(&live_path).unwrap();
+        writeln!(&mut live, "i am a live file").unwrap();
+        writeln!(&mut live, "and i should not wait for the backlog").unwrap();
+
+        sleep_500_millis().await;
+
+        let received = run_file_source(&config, false, NoAcks, async {
+            sleep_500_millis().await;
+            sleep_500_millis().await;
+        })
+        .await;
+
+        let messages = extract_messages_string(received);
+        let first_live = messages
+            .iter()
+            .position(|message| message == "i am a live file")
+            .expect("live file was not read");
+        assert_eq!(
+            messages[first_live + 1],
+            "and i should not wait for the backlog"
+        );
+
+        // The backlog file is still read, but only up to its budget before the live file.
+        assert!(messages[0].starts_with("backlog line"));
+        assert!(
+            first_live < 200,
+            "live file read after {} lines",
+            first_live
+        );
+        let backlog_read = messages
+            .iter()
+            .filter(|message| message.starts_with("backlog line"))
+            .count();
+        assert!(backlog_read < 1000, "read {} backlog lines", backlog_read);
+    }
+
+    #[tokio::test]
+    async fn test_unthrottled_backlog_does_not_starve_live_files() {
+        let dir = tempdir().unwrap();
+        let config = file::FileConfig {
+            include: vec![dir.path().join("*")],
+            max_read_bytes: 1,
+            oldest_first: true,
+            ..test_default_file_config(&dir)
+        };
+
+        let backlog_path = dir.path().join("z_backlog_file");
+        let mut backlog = File::create(&backlog_path).unwrap();
+        let backlog_lines = (0..100_000)
+            .map(|i| format!("backlog line {:06}\n", i))
+            .collect::<String>();
+        backlog.write_all(backlog_lines.as_bytes()).unwrap();
+
+        sleep_500_millis().await;
+
+        let live_path = dir.path().join("a_live_file");
+        let mut live = File::create(&live_path).unwrap();
+        writeln!(&mut live, "i am a live file").unwrap();
+
+        sleep_500_millis().await;
+
+        let received = run_file_source(&config, false, NoAcks, async {
+            sleep_500_millis().await;
+            sleep_500_millis().await;
+            sleep_500_millis().await;
+        })
+        .await;
+
+        // The backlog yields to the live file once a second, before it is fully read.
+        let messages = extract_messages_string(received);
+        let first_live = messages
+            .iter()
+            .position(|message| message == "i am a live file")
+            .expect("live file was not read");
+        assert!(messages[0].starts_with("backlog line"));
+        assert!(
+            first_live < 100_000,
+            "live file read after the whole backlog"
+        );
+    }
+
     // Ignoring on mac: https://github.com/vectordotdev/vector/issues/8373
     #[cfg(not(target_os = "macos"))]
     #[tokio::test]
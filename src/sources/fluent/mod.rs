@@ -85,7 +85,8 @@ impl SourceConfig for FluentConfig {
             .and_then(|tls| tls.client_metadata_key.clone());
         let tls = MaybeTlsSettings::from_config(&tls_config, true)?;
         source.run(
-            self.address,
+            self.address.clone(),
+            None,
             self.keepalive,
             shutdown_secs,
             tls,
@@ -94,6 +95,7 @@ impl SourceConfig for FluentConfig {
             cx,
             self.acknowledgements,
             self.connection_limit,
+            None,
         )
     }
 
@@ -106,7 +108,7 @@ impl SourceConfig for FluentConfig {
     }
 
     fn resources(&self) -> Vec<Resource> {
-        vec![self.address.into()]
+        vec![self.address.clone().into()]
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -27,6 +27,8 @@ mod disk;
 mod filesystem;
 mod memory;
 mod network;
+#[cfg(target_os = "linux")]
+mod tcp;
 
 /// Collector types.
 #[configurable_component]
@@ -57,6 +59,10 @@ pub enum Collector {
 
     /// Network.
     Network,
+
+    /// TCP and UDP sockets.
+    #[cfg(target_os = "linux")]
+    Tcp,
 }
 
 /// Filtering configuration.
@@ -108,6 +114,11 @@ pub struct HostMetricsConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub network: network::NetworkConfig,
+
+    #[cfg(target_os = "linux")]
+    #[configurable(derived)]
+    #[serde(default)]
+    pub(crate) tcp: tcp::TcpConfig,
 }
 
 const fn default_scrape_interval() -> f64 {
@@ -243,6 +254,10 @@ impl HostMetrics {
         if self.config.has_collector(Collector::Network) {
             self.network_metrics(&mut buffer).await;
         }
+        #[cfg(target_os = "linux")]
+        if self.config.has_collector(Collector::Tcp) {
+            self.tcp_metrics(&mut buffer).await;
+        }
 
         let metrics = buffer.metrics;
         emit!(EventsReceived {
@@ -558,6 +573,8 @@ pub(self) mod tests {
             Collector::Host,
             Collector::Memory,
             Collector::Network,
+            #[cfg(target_os = "linux")]
+            Collector::Tcp,
         ] {
             let some_metrics = HostMetrics::new(HostMetricsConfig {
                 collectors: Some(vec![*collector]),
@@ -0,0 +1,368 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use tokio::fs;
+use vector_config::configurable_component;
+
+use super::{HostMetrics, MetricsBuffer};
+
+/// The names of the TCP states, indexed by their number in `/proc/net/tcp` minus one.
+const TCP_STATES: [&str; 12] = [
+    "established",
+    "syn_sent",
+    "syn_recv",
+    "fin_wait1",
+    "fin_wait2",
+    "time_wait",
+    "close",
+    "close_wait",
+    "last_ack",
+    "listen",
+    "closing",
+    "new_syn_recv",
+];
+
+/// Options for the “tcp” metrics collector.
+///
+/// This collector is only available on Linux systems.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+#[serde(default)]
+pub(crate) struct TcpConfig {
+    /// The local ports to additionally report the sockets of, tagged with the `port` tag.
+    ///
+    /// By default, sockets are only reported by protocol and state, which keeps the number of
+    /// metrics bounded regardless of the ports in use.
+    ports: Vec<u16>,
+
+    /// Base procfs directory, for testing use only
+    #[serde(skip_serializing)]
+    proc_dir: Option<PathBuf>,
+}
+
+/// A socket of a `/proc/net/{tcp,tcp6,udp,udp6}` table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Socket {
+    state: u8,
+    port: u16,
+}
+
+impl HostMetrics {
+    pub(super) async fn tcp_metrics(&self, output: &mut MetricsBuffer) {
+        output.name = "tcp";
+        let config = &self.config.tcp;
+        let proc_dir = config.proc_dir.clone().unwrap_or_else(procfs_root);
+
+        for protocol in ["tcp", "tcp6"] {
+            if let Some(contents) = read_net_file(&proc_dir, protocol).await {
+                let mut counts = BTreeMap::<(u8, Option<u16>), u64>::new();
+                for socket in parse_sockets(&contents) {
+                    *counts.entry((socket.state, None)).or_default() += 1;
+                    if config.ports.contains(&socket.port) {
+                        *counts.entry((socket.state, Some(socket.port))).or_default() += 1;
+                    }
+                }
+                for (index, state) in TCP_STATES.iter().enumerate() {
+                    let state_number = index as u8 + 1;
+                    output.gauge(
+                        "tcp_connections",
+                        counts.get(&(state_number, None)).copied().unwrap_or(0) as f64,
+                        BTreeMap::from([
+                            (String::from("protocol"), protocol.to_string()),
+                            (String::from("state"), state.to_string()),
+                        ]),
+                    );
+                    for port in &config.ports {
+                        output.gauge(
+                            "tcp_port_connections",
+                            counts
+                                .get(&(state_number, Some(*port)))
+                                .copied()
+                                .unwrap_or(0) as f64,
+                            BTreeMap::from([
+                                (String::from("protocol"), protocol.to_string()),
+                                (String::from("state"), state.to_string()),
+                                (String::from("port"), port.to_string()),
+                            ]),
+                        );
+                    }
+                }
+            }
+        }
+
+        for protocol in ["udp", "udp6"] {
+            if let Some(contents) = read_net_file(&proc_dir, protocol).await {
+                let sockets = parse_sockets(&contents).collect::<Vec<_>>();
+                output.gauge(
+                    "udp_sockets",
+                    sockets.len() as f64,
+                    BTreeMap::from([(String::from("protocol"), protocol.to_string())]),
+                );
+                for port in &config.ports {
+                    output.gauge(
+                        "udp_port_sockets",
+                        sockets.iter().filter(|socket| socket.port == *port).count() as f64,
+                        BTreeMap::from([
+                            (String::from("protocol"), protocol.to_string()),
+                            (String::from("port"), port.to_string()),
+                        ]),
+                    );
+                }
+            }
+        }
+
+        if let Some(contents) = read_net_file(&proc_dir, "snmp").await {
+            let counters = parse_counters(&contents);
+            for (name, counter) in [
+                ("udp_receive_errors_total", "InErrors"),
+                ("udp_receive_buffer_errors_total", "RcvbufErrors"),
+                ("udp_send_buffer_errors_total", "SndbufErrors"),
+            ] {
+                if let Some(value) = counters.get(&("Udp", counter)) {
+                    output.counter(name, *value, BTreeMap::new());
+                }
+            }
+        }
+
+        if let Some(contents) = read_net_file(&proc_dir, "netstat").await {
+            let counters = parse_counters(&contents);
+            for (name, counter) in [
+                ("tcp_listen_overflows_total", "ListenOverflows"),
+                ("tcp_listen_drops_total", "ListenDrops"),
+            ] {
+                if let Some(value) = counters.get(&("TcpExt", counter)) {
+                    output.counter(name, *value, BTreeMap::new());
+                }
+            }
+        }
+    }
+}
+
+fn procfs_root() -> PathBuf {
+    std::env::var_os("PROCFS_ROOT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/proc"))
+}
+
+async fn read_net_file(proc_dir: &Path, name: &str) -> Option<String> {
+    let filename = proc_dir.join("net").join(name);
+    match fs::read_to_string(&filename).await {
+        Ok(contents) => Some(contents),
+        // The IPv6 tables are missing when IPv6 is disabled.
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => {
+            error!(message = "Failed to load network socket data.", ?filename, %error, internal_log_rate_secs = 60);
+            None
+        }
+    }
+}
+
+/// Parses the sockets of a `/proc/net/{tcp,tcp6,udp,udp6}` table, skipping its header and any
+/// malformed line.
+fn parse_sockets(contents: &str) -> impl Iterator<Item = Socket> + '_ {
+    contents.lines().skip(1).filter_map(|line| {
+        let mut fields = line.split_whitespace();
+        let local_address = fields.nth(1)?;
+        let state = fields.nth(1)?;
+        let (_, port) = local_address.rsplit_once(':')?;
+        Some(Socket {
+            state: u8::from_str_radix(state, 16).ok()?,
+            port: u16::from_str_radix(port, 16).ok()?,
+        })
+    })
+}
+
+/// Parses the counters of `/proc/net/{snmp,netstat}`, by protocol and counter name.
+///
+/// The counters of a protocol are given by a line of names followed by a line of values, both
+/// prefixed with the protocol. Lines that aren't part of such a pair, and values that aren't
+/// numbers, are skipped.
+fn parse_counters(contents: &str) -> BTreeMap<(&str, &str), f64> {
+    let mut counters = BTreeMap::new();
+    let mut names_line: Option<(&str, &str)> = None;
+    for line in contents.lines() {
+        let (protocol, fields) = match line.split_once(':') {
+            Some(split) => split,
+            None => {
+                names_line = None;
+                continue;
+            }
+        };
+        match names_line.take() {
+            Some((names_protocol, names)) if names_protocol == protocol => {
+                for (name, value) in names.split_whitespace().zip(fields.split_whitespace()) {
+                    if let Ok(value) = value.parse() {
+                        counters.insert((protocol, name), value);
+                    }
+                }
+            }
+            _ => names_line = Some((protocol, fields)),
+        }
+    }
+    counters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{tests::count_name, HostMetrics, HostMetricsConfig, MetricsBuffer},
+        parse_counters, parse_sockets, Socket, TcpConfig,
+    };
+    use crate::event::metric::{Metric, MetricValue};
+
+    async fn fixture_metrics(ports: Vec<u16>) -> Vec<Metric> {
+        let mut buffer = MetricsBuffer::new(None);
+        HostMetrics::new(HostMetricsConfig {
+            tcp: TcpConfig {
+                ports,
+                proc_dir: Some("tests/data/host_metrics/proc".into()),
+            },
+            ..Default::default()
+        })
+        .tcp_metrics(&mut buffer)
+        .await;
+        buffer.metrics
+    }
+
+    fn value(metrics: &[Metric], name: &str, tags: &[(&str, &str)]) -> f64 {
+        let metric = metrics
+            .iter()
+            .find(|metric| {
+                metric.name() == name
+                    && tags.iter().all(|(tag, value)| {
+                        metric
+                            .tags()
+                            .and_then(|tags| tags.get(*tag))
+                            .map(String::as_str)
+                            == Some(*value)
+                    })
+            })
+            .unwrap_or_else(|| panic!("Missing metric {} {:?}", name, tags));
+        match metric.value() {
+            MetricValue::Gauge { value } | MetricValue::Counter { value } => *value,
+            value => panic!("Unexpected value {:?}", value),
+        }
+    }
+
+    #[tokio::test]
+    async fn generates_tcp_metrics() {
+        let metrics = fixture_metrics(Vec::new()).await;
+
+        // Every state of both TCP tables is reported, but no port.
+        assert_eq!(count_name(&metrics, "tcp_connections"), 24);
+        assert_eq!(count_name(&metrics, "tcp_port_connections"), 0);
+        let tcp = |state| {
+            value(
+                &metrics,
+                "tcp_connections",
+                &[("protocol", "tcp"), ("state", state)],
+            )
+        };
+        assert_eq!(tcp("listen"), 2.0);
+        assert_eq!(tcp("established"), 2.0);
+        assert_eq!(tcp("time_wait"), 1.0);
+        assert_eq!(tcp("close_wait"), 0.0);
+        let tcp6 = |state| {
+            value(
+                &metrics,
+                "tcp_connections",
+                &[("protocol", "tcp6"), ("state", state)],
+            )
+        };
+        assert_eq!(tcp6("listen"), 1.0);
+
+        // The IPv6 UDP table is missing from the fixtures.
+        assert_eq!(count_name(&metrics, "udp_sockets"), 1);
+        assert_eq!(value(&metrics, "udp_sockets", &[("protocol", "udp")]), 2.0);
+
+        assert_eq!(value(&metrics, "udp_receive_errors_total", &[]), 7.0);
+        assert_eq!(value(&metrics, "udp_receive_buffer_errors_total", &[]), 5.0);
+        assert_eq!(value(&metrics, "udp_send_buffer_errors_total", &[]), 2.0);
+        assert_eq!(value(&metrics, "tcp_listen_overflows_total", &[]), 11.0);
+        assert_eq!(value(&metrics, "tcp_listen_drops_total", &[]), 13.0);
+    }
+
+    #[tokio::test]
+    async fn breaks_down_allowed_ports() {
+        let metrics = fixture_metrics(vec![22, 53]).await;
+
+        assert_eq!(count_name(&metrics, "tcp_port_connections"), 48);
+        let port = |protocol, state, port| {
+            value(
+                &metrics,
+                "tcp_port_connections",
+                &[("protocol", protocol), ("state", state), ("port", port)],
+            )
+        };
+        assert_eq!(port("tcp", "established", "22"), 2.0);
+        assert_eq!(port("tcp", "listen", "22"), 1.0);
+        assert_eq!(port("tcp6", "listen", "22"), 1.0);
+        assert_eq!(port("tcp", "listen", "53"), 0.0);
+        assert_eq!(
+            value(
+                &metrics,
+                "udp_port_sockets",
+                &[("protocol", "udp"), ("port", "53")]
+            ),
+            1.0
+        );
+
+        // Ports outside of the list are only counted in the totals.
+        assert!(!metrics.iter().any(|metric| metric
+            .tags()
+            .and_then(|tags| tags.get("port"))
+            .map_or(false, |port| port == "8080")));
+        assert_eq!(
+            value(
+                &metrics,
+                "tcp_connections",
+                &[("protocol", "tcp"), ("state", "listen")]
+            ),
+            2.0
+        );
+    }
+
+    #[test]
+    fn parses_sockets_skipping_malformed_lines() {
+        let contents = "  sl  local_address rem_address   st tx_queue rx_queue\n   \
+                        0: 0100007F:1F90 00000000:0000 0A 00000000:00000000\n   \
+                        1: 0100007F:1F90\n   \
+                        2: 0100007F 00000000:0000 01 00000000:00000000\n   \
+                        3: 0100007F:ZZZZ 00000000:0000 01 00000000:00000000\n   \
+                        4: 00000000000000000000000001000000:0016 00000000000000000000000000000000:0000 06\n";
+        assert_eq!(
+            parse_sockets(contents).collect::<Vec<_>>(),
+            vec![
+                Socket {
+                    state: 0x0A,
+                    port: 8080
+                },
+                Socket {
+                    state: 0x06,
+                    port: 22
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_counters_skipping_unpaired_lines() {
+        let contents = "Tcp: MaxConn ActiveOpens\n\
+                        Tcp: -1 10\n\
+                        Udp: InErrors RcvbufErrors\n\
+                        UdpLite: InErrors\n\
+                        UdpLite: 3\n\
+                        IpExt: InNoRoutes Broken\n\
+                        IpExt: 4 x\n";
+        let counters = parse_counters(contents);
+        assert_eq!(counters.get(&("Tcp", "MaxConn")), Some(&-1.0));
+        assert_eq!(counters.get(&("Tcp", "ActiveOpens")), Some(&10.0));
+        assert_eq!(counters.get(&("Udp", "InErrors")), None);
+        assert_eq!(counters.get(&("UdpLite", "InErrors")), Some(&3.0));
+        assert_eq!(counters.get(&("IpExt", "InNoRoutes")), Some(&4.0));
+        assert_eq!(counters.get(&("IpExt", "Broken")), None);
+    }
+}
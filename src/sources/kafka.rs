@@ -94,6 +94,20 @@ pub struct KafkaSourceConfig {
     #[serde(default = "default_commit_interval_ms")]
     commit_interval_ms: u64,
 
+    /// The interval, in milliseconds, at which `librdkafka` reports the statistics translated into internal metrics.
+    ///
+    /// Set to `0` to disable the statistics.
+    #[serde(default = "default_statistics_interval_ms")]
+    #[derivative(Default(value = "default_statistics_interval_ms()"))]
+    statistics_interval_ms: u64,
+
+    /// Whether or not to tag the `kafka_consumer_lag` internal metric with the partition.
+    ///
+    /// By default, the consumer lag is summed per topic, as tagging it with the partition produces one metric per
+    /// partition.
+    #[serde(default)]
+    statistics_partition_tags: bool,
+
     /// Overrides the name of the log field used to add the message key to each event.
     ///
     /// The value will be the message key of the Kafka message itself.
@@ -173,6 +187,10 @@ const fn default_commit_interval_ms() -> u64 {
     5000 // default in librdkafka
 }
 
+const fn default_statistics_interval_ms() -> u64 {
+    1000
+}
+
 fn default_auto_offset_reset() -> String {
     "largest".into() // default in librdkafka
 }
@@ -547,7 +565,10 @@ fn create_consumer(
             &config.commit_interval_ms.to_string(),
         )
         .set("enable.auto.offset.store", "false")
-        .set("statistics.interval.ms", "1000")
+        .set(
+            "statistics.interval.ms",
+            &config.statistics_interval_ms.to_string(),
+        )
         .set("client.id", "vector");
 
     config.auth.apply(&mut client_config)?;
@@ -559,7 +580,9 @@ fn create_consumer(
     }
 
     let consumer = client_config
-        .create_with_context::<_, StreamConsumer<_>>(KafkaStatisticsContext)
+        .create_with_context::<_, StreamConsumer<_>>(KafkaStatisticsContext {
+            partition_tags: config.statistics_partition_tags,
+        })
         .context(KafkaCreateSnafu)?;
     let topics: Vec<&str> = config.topics.iter().map(|s| s.as_str()).collect();
     consumer.subscribe(&topics).context(KafkaSubscribeSnafu)?;
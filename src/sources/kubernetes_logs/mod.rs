@@ -420,6 +420,8 @@ impl Source {
             // This allows distributing the reads more or less evenly across
             // the files.
             max_read_bytes,
+            // Reads are not throttled.
+            max_read_bytes_per_second: None,
             // We want to use checkpoining mechanism, and resume from where we
             // left off.
             ignore_checkpoints: false,
@@ -431,6 +433,8 @@ impl Source {
             // be other, more sound ways for users considering the use of this
             // option to solve their use case, so take consideration.
             ignore_before: None,
+            // For the same reason, files aren't ignored when they go idle either.
+            ignore_older: None,
             // The maximum number of bytes a line can contain before being discarded. This
             // protects against malformed lines or tailing incorrect files.
             max_line_bytes,
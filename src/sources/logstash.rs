@@ -87,7 +87,8 @@ impl SourceConfig for LogstashConfig {
             .and_then(|tls| tls.client_metadata_key.clone());
         let tls = MaybeTlsSettings::from_config(&tls_config, true)?;
         source.run(
-            self.address,
+            self.address.clone(),
+            None,
             self.keepalive,
             shutdown_secs,
             tls,
@@ -96,6 +97,7 @@ impl SourceConfig for LogstashConfig {
             cx,
             self.acknowledgements,
             self.connection_limit,
+            None,
         )
     }
 
@@ -108,7 +110,7 @@ impl SourceConfig for LogstashConfig {
     }
 
     fn resources(&self) -> Vec<Resource> {
-        vec![self.address.into()]
+        vec![self.address.clone().into()]
     }
 
     fn can_acknowledge(&self) -> bool {
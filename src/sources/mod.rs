@@ -2,6 +2,8 @@ use snafu::Snafu;
 
 #[cfg(feature = "sources-apache_metrics")]
 pub mod apache_metrics;
+#[cfg(feature = "sources-aws_cloudwatch_logs")]
+pub mod aws_cloudwatch_logs;
 #[cfg(feature = "sources-aws_ecs_metrics")]
 pub mod aws_ecs_metrics;
 #[cfg(feature = "sources-aws_kinesis_firehose")]
@@ -56,6 +58,8 @@ pub mod nginx_metrics;
 pub mod opentelemetry;
 #[cfg(feature = "sources-postgresql_metrics")]
 pub mod postgresql_metrics;
+#[cfg(feature = "sources-probe")]
+pub mod probe;
 #[cfg(feature = "sources-prometheus")]
 pub mod prometheus;
 #[cfg(feature = "sources-redis")]
@@ -206,6 +210,10 @@ pub enum Sources {
     #[cfg(feature = "sources-postgresql_metrics")]
     PostgresqlMetrics(#[configurable(derived)] postgresql_metrics::PostgresqlMetricsConfig),
 
+    /// Probe.
+    #[cfg(feature = "sources-probe")]
+    Probe(#[configurable(derived)] probe::ProbeConfig),
+
     /// Prometheus Scrape.
     #[cfg(feature = "sources-prometheus")]
     PrometheusScrape(#[configurable(derived)] prometheus::PrometheusScrapeConfig),
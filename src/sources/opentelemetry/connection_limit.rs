@@ -0,0 +1,101 @@
+use std::{
+    io,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadBuf};
+
+/// The response to the requests of connections over the limit, closing the connection.
+const SERVICE_UNAVAILABLE_RESPONSE: &[u8] =
+    b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+/// The count of the active connections of the HTTP server, capped at `max_connections`.
+#[derive(Clone, Debug)]
+pub(crate) struct ConnectionLimit {
+    active: Arc<AtomicUsize>,
+    max_connections: Option<usize>,
+}
+
+impl ConnectionLimit {
+    pub(crate) fn new(max_connections: Option<usize>) -> Self {
+        Self {
+            active: Arc::new(AtomicUsize::new(0)),
+            max_connections,
+        }
+    }
+
+    /// Counts an accepted connection as active until it is dropped, or gives it back if the limit
+    /// is reached.
+    pub(crate) fn acquire<S>(&self, stream: S) -> Result<CountedConnection<S>, S> {
+        let active = self.active.fetch_add(1, Ordering::AcqRel);
+        if matches!(self.max_connections, Some(max_connections) if active >= max_connections) {
+            self.active.fetch_sub(1, Ordering::AcqRel);
+            return Err(stream);
+        }
+        Ok(CountedConnection {
+            inner: stream,
+            active: Arc::clone(&self.active),
+        })
+    }
+}
+
+/// Replies to a connection over the limit with `503 Service Unavailable`, and closes it right away
+/// rather than holding on to it while its request is received.
+pub(crate) async fn reject_connection<S>(mut stream: S)
+where
+    S: AsyncWrite + Unpin,
+{
+    let _ = stream.write_all(SERVICE_UNAVAILABLE_RESPONSE).await;
+    let _ = stream.shutdown().await;
+}
+
+/// An accepted connection, counted as active until it is dropped.
+pub(crate) struct CountedConnection<S> {
+    inner: S,
+    active: Arc<AtomicUsize>,
+}
+
+impl<S> CountedConnection<S> {
+    pub(crate) const fn get_ref(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S> Drop for CountedConnection<S> {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for CountedConnection<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for CountedConnection<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use snafu::ResultExt;
+use vector_config::configurable_component;
+
+use super::{
+    BuildError, EnrichmentTableSourceSnafu, InvalidEnrichmentTableSnafu, ReadEnrichmentTableSnafu,
+};
+
+/// A static table of fields added to log records, keyed by the value of a resource attribute.
+///
+/// Each log record of a resource whose attribute value has an entry in the table is enriched with the fields of the
+/// entry, such as the team or owner of a `service.name`. Fields already present in the log record are left as they
+/// are, and the log records of resources without an entry aren't enriched.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct EnrichmentLookupConfig {
+    /// The name of the resource attribute whose value is looked up in the table.
+    attribute: String,
+
+    /// The entries of the table, keyed by attribute value.
+    ///
+    /// Each entry maps the names of the fields added to log records to their values.
+    entries: Option<HashMap<String, BTreeMap<String, String>>>,
+
+    /// The path of a JSON file holding the entries of the table, in the same shape as `entries`.
+    ///
+    /// The file is read when the source is built. Exactly one of `entries` and `path` must be set.
+    path: Option<PathBuf>,
+}
+
+impl EnrichmentLookupConfig {
+    pub(crate) fn build(&self) -> Result<EnrichmentLookup, BuildError> {
+        let entries = match (&self.entries, &self.path) {
+            (Some(entries), None) => entries.clone(),
+            (None, Some(path)) => {
+                let contents = fs::read(path).context(ReadEnrichmentTableSnafu { path })?;
+                serde_json::from_slice(&contents).context(InvalidEnrichmentTableSnafu { path })?
+            }
+            _ => return EnrichmentTableSourceSnafu.fail(),
+        };
+
+        Ok(EnrichmentLookup {
+            attribute: self.attribute.clone(),
+            entries: Arc::new(entries),
+        })
+    }
+}
+
+/// The fields added to log records, keyed by the value of a resource attribute.
+#[derive(Clone, Debug)]
+pub(crate) struct EnrichmentLookup {
+    attribute: String,
+    entries: Arc<HashMap<String, BTreeMap<String, String>>>,
+}
+
+impl EnrichmentLookup {
+    /// The name of the resource attribute whose value is looked up.
+    pub(crate) fn attribute(&self) -> &str {
+        &self.attribute
+    }
+
+    /// Gets the fields added to the log records of a resource with the given attribute value.
+    pub(crate) fn lookup(&self, value: Option<String>) -> Option<&BTreeMap<String, String>> {
+        self.entries.get(&value?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn loads_entries_from_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"{{"checkout": {{"team": "payments"}}}}"#).unwrap();
+        let config = EnrichmentLookupConfig {
+            attribute: "service.name".to_owned(),
+            entries: None,
+            path: Some(file.path().to_owned()),
+        };
+
+        let lookup = config.build().unwrap();
+        assert_eq!(lookup.attribute(), "service.name");
+        assert_eq!(
+            lookup.lookup(Some("checkout".to_owned())),
+            Some(&BTreeMap::from([(
+                "team".to_owned(),
+                "payments".to_owned()
+            )]))
+        );
+        assert_eq!(lookup.lookup(Some("search".to_owned())), None);
+        assert_eq!(lookup.lookup(None), None);
+    }
+
+    #[test]
+    fn requires_exactly_one_source() {
+        let config = EnrichmentLookupConfig {
+            attribute: "service.name".to_owned(),
+            entries: None,
+            path: None,
+        };
+        assert!(matches!(
+            config.build(),
+            Err(BuildError::EnrichmentTableSource)
+        ));
+    }
+}
@@ -1,14 +1,34 @@
 use crate::{
-    internal_events::{EventsReceived, StreamClosedError},
-    opentelemetry::LogService::{
-        logs_service_server::LogsService, ExportLogsServiceRequest, ExportLogsServiceResponse,
+    internal_events::{
+        EventsReceived, OpentelemetryDuplicateRequest, OpentelemetryEventsFailed, StreamClosedError,
     },
-    sources::opentelemetry::LOGS,
+    opentelemetry::{
+        LogService::{
+            logs_service_server::LogsService, ExportLogsPartialSuccess, ExportLogsServiceRequest,
+            ExportLogsServiceResponse,
+        },
+        MetricsService::{
+            metrics_service_server::MetricsService, ExportMetricsServiceRequest,
+            ExportMetricsServiceResponse,
+        },
+        TraceService::{
+            trace_service_server::TraceService, ExportTraceServiceRequest,
+            ExportTraceServiceResponse,
+        },
+    },
+    source_sender::ClosedError,
+    sources::opentelemetry::{
+        convert_metrics_exemplars, convert_metrics_request, convert_request, convert_trace_request,
+        insert_tls_client_metadata, insert_user_agent_version, split_outputs, ConvertOptions,
+        ConvertedRequest, ERRORS, EXEMPLARS, METRICS, RESOURCES, TRACES,
+    },
+    sources::util::grpc::TlsPeer,
     SourceSender,
 };
 use futures::TryFutureExt;
 
 use tonic::{Request, Response, Status};
+use tracing::Instrument;
 
 use vector_core::{
     event::{BatchNotifier, BatchStatus, BatchStatusReceiver, Event},
@@ -19,6 +39,7 @@ use vector_core::{
 pub(crate) struct Service {
     pub(crate) pipeline: SourceSender,
     pub(crate) acknowledgements: bool,
+    pub(crate) options: ConvertOptions,
 }
 
 #[tonic::async_trait]
@@ -27,34 +48,304 @@ impl LogsService for Service {
         &self,
         request: Request<ExportLogsServiceRequest>,
     ) -> Result<Response<ExportLogsServiceResponse>, Status> {
-        let mut events: Vec<Event> = request
-            .into_inner()
-            .resource_logs
-            .into_iter()
-            .flat_map(|v| v.into_iter())
-            .collect();
+        // The response future is dropped when the client closes the connection, so the events of a
+        // complete request are sent from a separate task, which keeps on sending and waiting for
+        // their acknowledgement regardless.
+        let service = self.clone();
+        let metadata = |key: &str| {
+            request
+                .metadata()
+                .get(key)
+                .and_then(|value| value.to_str().ok())
+                .map(ToOwned::to_owned)
+        };
+        let tenant = self
+            .options
+            .rate_limiter
+            .as_ref()
+            .and_then(|limiter| metadata(limiter.header()));
+        let idempotency_key = self
+            .options
+            .idempotency
+            .as_ref()
+            .and_then(|cache| metadata(cache.header()));
+        if let (Some(cache), Some(key)) = (&self.options.idempotency, &idempotency_key) {
+            if cache.is_duplicate(key) {
+                emit!(OpentelemetryDuplicateRequest { key });
+                return Ok(Response::new(ExportLogsServiceResponse::default()));
+            }
+        }
+        let user_agent = self
+            .options
+            .include_proto_version
+            .then(|| metadata("user-agent"))
+            .flatten();
+        let peer = request.extensions().get::<TlsPeer>().cloned();
+        tokio::spawn(
+            service
+                .handle_request(
+                    request.into_inner(),
+                    tenant,
+                    user_agent,
+                    peer,
+                    idempotency_key,
+                )
+                .in_current_span(),
+        )
+        .await
+        .unwrap_or_else(|error| Err(Status::internal(error.to_string())))
+    }
+}
+
+impl Service {
+    async fn handle_request(
+        self,
+        request: ExportLogsServiceRequest,
+        tenant: Option<String>,
+        user_agent: Option<String>,
+        peer: Option<TlsPeer>,
+        idempotency_key: Option<String>,
+    ) -> Result<Response<ExportLogsServiceResponse>, Status> {
+        let ConvertedRequest {
+            logs: mut events,
+            log_outputs,
+            resources,
+            errors,
+            rejected,
+        } = convert_request(request, &self.options);
+        insert_user_agent_version(
+            &mut events,
+            user_agent.as_deref(),
+            self.options.log_namespace,
+        );
+        insert_tls_client_metadata(
+            &mut events,
+            self.options.tls_client_metadata_key.as_deref(),
+            peer.as_ref(),
+            self.options.log_namespace,
+        );
 
         let count = events.len();
-        let byte_size = events.size_of();
+        if let Some(limiter) = &self.options.rate_limiter {
+            if !limiter.check(tenant.as_deref(), count + errors.len()) {
+                return Err(Status::resource_exhausted("Tenant rate limit exceeded"));
+            }
+        }
+
+        let byte_size = events.size_of() + resources.size_of() + errors.size_of();
+
+        emit!(EventsReceived {
+            count: count + resources.len() + errors.len(),
+            byte_size,
+        });
+
+        for (output, batch) in [(ERRORS, errors), (RESOURCES, resources)] {
+            if batch.is_empty() {
+                continue;
+            }
+            let count = batch.len();
+            self.pipeline
+                .clone()
+                .send_batch_named(output, batch)
+                .await
+                .map_err(|error| send_failed(error, count, output))?;
+        }
+
+        let mut batches = split_outputs(events, log_outputs);
+        let receivers = add_batch_notifiers(self.acknowledgements, &mut batches);
+        for (output, batch) in batches {
+            let count = batch.len();
+            self.pipeline
+                .clone()
+                .send_batch_named(&output, batch)
+                .await
+                .map_err(|error| send_failed(error, count, &output))?;
+        }
+        let undelivered = count_undelivered(receivers).await;
+
+        if let (Some(cache), Some(key)) = (&self.options.idempotency, idempotency_key) {
+            cache.insert(key);
+        }
+        Ok(Response::new(ExportLogsServiceResponse {
+            partial_success: partial_success(rejected, undelivered),
+        }))
+    }
+}
+
+/// Attaches a notifier to each batch of events if `acknowledgements` are enabled, returning its
+/// receiver along with the number of events in the batch.
+pub(crate) fn add_batch_notifiers(
+    acknowledgements: bool,
+    batches: &mut [(String, Vec<Event>)],
+) -> Vec<(usize, BatchStatusReceiver)> {
+    batches
+        .iter_mut()
+        .filter_map(|(_, batch)| {
+            BatchNotifier::maybe_apply_to(acknowledgements, batch)
+                .map(|receiver| (batch.len(), receiver))
+        })
+        .collect()
+}
+
+/// Waits for the batches to be acknowledged, and counts the events of those that weren't delivered.
+pub(crate) async fn count_undelivered(receivers: Vec<(usize, BatchStatusReceiver)>) -> usize {
+    let mut undelivered = 0;
+    for (count, receiver) in receivers {
+        if receiver.await != BatchStatus::Delivered {
+            undelivered += count;
+        }
+    }
+    undelivered
+}
+
+/// Reports the log records that failed conversion or acknowledgement as a partial success, so that
+/// clients don't assume they were all accepted.
+pub(crate) fn partial_success(
+    unconverted: usize,
+    undelivered: usize,
+) -> Option<ExportLogsPartialSuccess> {
+    let mut messages = Vec::new();
+    if unconverted > 0 {
+        messages.push(format!(
+            "{} log records could not be converted into events",
+            unconverted
+        ));
+    }
+    if undelivered > 0 {
+        messages.push(format!(
+            "{} log records were not delivered to their sinks",
+            undelivered
+        ));
+    }
+    (!messages.is_empty()).then(|| ExportLogsPartialSuccess {
+        rejected_log_records: (unconverted + undelivered) as i64,
+        error_message: messages.join(", and "),
+    })
+}
+
+/// The gRPC service receiving spans, which are sent to the `traces` output.
+#[derive(Debug, Clone)]
+pub(crate) struct TraceExportService {
+    pub(crate) pipeline: SourceSender,
+    pub(crate) acknowledgements: bool,
+}
 
-        emit!(EventsReceived { count, byte_size });
+#[tonic::async_trait]
+impl TraceService for TraceExportService {
+    async fn export(
+        &self,
+        request: Request<ExportTraceServiceRequest>,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        // As for log records, the events are sent from a separate task so that a client closing the
+        // connection doesn't interrupt them.
+        tokio::spawn(
+            self.clone()
+                .handle_request(request.into_inner())
+                .in_current_span(),
+        )
+        .await
+        .unwrap_or_else(|error| Err(Status::internal(error.to_string())))
+    }
+}
+
+impl TraceExportService {
+    async fn handle_request(
+        self,
+        request: ExportTraceServiceRequest,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        let mut events = convert_trace_request(request);
+        let count = events.len();
+
+        emit!(EventsReceived {
+            count,
+            byte_size: events.size_of(),
+        });
 
         let receiver = BatchNotifier::maybe_apply_to(self.acknowledgements, &mut events);
 
         self.pipeline
             .clone()
-            .send_batch_named(LOGS, events)
-            .map_err(|error| {
-                let message = error.to_string();
-                emit!(StreamClosedError { error, count });
-                Status::unavailable(message)
-            })
+            .send_batch_named(TRACES, events)
+            .map_err(|error| send_failed(error, count, TRACES))
             .and_then(|_| handle_batch_status(receiver))
             .await?;
-        Ok(Response::new(ExportLogsServiceResponse {}))
+
+        Ok(Response::new(ExportTraceServiceResponse {}))
+    }
+}
+
+/// The gRPC service receiving metrics, which are sent to the `metrics` output, and their exemplars
+/// to the `exemplars` output if they're emitted.
+#[derive(Debug, Clone)]
+pub(crate) struct MetricsExportService {
+    pub(crate) pipeline: SourceSender,
+    pub(crate) acknowledgements: bool,
+    pub(crate) emit_exemplars: bool,
+}
+
+#[tonic::async_trait]
+impl MetricsService for MetricsExportService {
+    async fn export(
+        &self,
+        request: Request<ExportMetricsServiceRequest>,
+    ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+        tokio::spawn(
+            self.clone()
+                .handle_request(request.into_inner())
+                .in_current_span(),
+        )
+        .await
+        .unwrap_or_else(|error| Err(Status::internal(error.to_string())))
     }
 }
 
+impl MetricsExportService {
+    async fn handle_request(
+        self,
+        request: ExportMetricsServiceRequest,
+    ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+        let exemplars = if self.emit_exemplars {
+            convert_metrics_exemplars(&request)
+        } else {
+            Vec::new()
+        };
+        let mut events = convert_metrics_request(request);
+        let count = events.len();
+
+        emit!(EventsReceived {
+            count: count + exemplars.len(),
+            byte_size: events.size_of() + exemplars.size_of(),
+        });
+
+        if !exemplars.is_empty() {
+            let count = exemplars.len();
+            self.pipeline
+                .clone()
+                .send_batch_named(EXEMPLARS, exemplars)
+                .await
+                .map_err(|error| send_failed(error, count, EXEMPLARS))?;
+        }
+
+        let receiver = BatchNotifier::maybe_apply_to(self.acknowledgements, &mut events);
+
+        self.pipeline
+            .clone()
+            .send_batch_named(METRICS, events)
+            .map_err(|error| send_failed(error, count, METRICS))
+            .and_then(|_| handle_batch_status(receiver))
+            .await?;
+
+        Ok(Response::new(ExportMetricsServiceResponse {}))
+    }
+}
+
+fn send_failed(error: ClosedError, count: usize, output: &str) -> Status {
+    let message = error.to_string();
+    emit!(StreamClosedError { error, count });
+    emit!(OpentelemetryEventsFailed { count, output });
+    Status::unavailable(message)
+}
+
 async fn handle_batch_status(receiver: Option<BatchStatusReceiver>) -> Result<(), Status> {
     let status = match receiver {
         Some(receiver) => receiver.await,
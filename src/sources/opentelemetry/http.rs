@@ -1,28 +1,63 @@
-use std::net::SocketAddr;
+use std::{convert::Infallible, net::SocketAddr};
 
-use bytes::Bytes;
-use futures_util::FutureExt;
-use http::StatusCode;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use futures_util::{future, FutureExt, Stream, StreamExt, TryFutureExt};
+use http::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    HeaderMap, HeaderValue, StatusCode,
+};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Server,
+};
 use prost::Message;
 use snafu::Snafu;
+use subtle::ConstantTimeEq;
+use tower::ServiceExt;
 use tracing::Span;
 use vector_core::{
-    event::{BatchNotifier, BatchStatus},
+    event::{BatchNotifier, BatchStatus, Event},
     ByteSizeOf,
 };
 use warp::{filters::BoxedFilter, reject::Rejection, reply::Response, Filter, Reply};
 
 use crate::{
-    event::Event,
-    internal_events::{BytesReceived, EventsReceived, StreamClosedError},
-    opentelemetry::LogService::{ExportLogsServiceRequest, ExportLogsServiceResponse},
+    internal_events::{
+        BytesReceived, EventsReceived, OpentelemetryConnectionRejected,
+        OpentelemetryDuplicateRequest, StreamClosedError,
+    },
+    opentelemetry::{
+        LogService::{
+            ExportLogsPartialSuccess, ExportLogsServiceRequest, ExportLogsServiceResponse,
+        },
+        MetricsService::{ExportMetricsServiceRequest, ExportMetricsServiceResponse},
+        TraceService::{ExportTraceServiceRequest, ExportTraceServiceResponse},
+    },
     shutdown::ShutdownSignal,
-    sources::util::{decode, ErrorMessage},
+    sources::util::{
+        decode_with_limit,
+        grpc::{RecordTlsPeer, TlsPeer},
+        ErrorMessage,
+    },
     tls::MaybeTlsSettings,
     SourceSender,
 };
 
-use super::{reply::protobuf, status::Status};
+use super::{
+    connection_limit::{reject_connection, ConnectionLimit, CountedConnection},
+    convert_metrics_exemplars, convert_metrics_request, convert_request, convert_trace_request,
+    grpc::{add_batch_notifiers, count_undelivered, partial_success},
+    insert_tls_client_metadata, insert_user_agent_version,
+    json::decode_logs_request,
+    reply::protobuf,
+    split_outputs,
+    status::Status,
+    ConvertOptions, ConvertedRequest, HttpAuthConfig, HttpCompression, ERRORS, EXEMPLARS, METRICS,
+    RESOURCES, TRACES,
+};
+
+/// The JSON encoding of an empty `ExportLogsServiceResponse`, sent by default when a JSON request succeeds.
+pub(crate) const DEFAULT_SUCCESS_RESPONSE_BODY: &str = "{}";
 
 #[derive(Clone, Copy, Debug, Snafu)]
 pub(crate) enum ApiError {
@@ -32,10 +67,31 @@ pub(crate) enum ApiError {
 
 impl warp::reject::Reject for ApiError {}
 
+/// The encoding of the body of an OTLP/HTTP request, given by its `Content-Type` header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BodyEncoding {
+    Protobuf,
+    Json,
+}
+
+impl BodyEncoding {
+    fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        if media_type.eq_ignore_ascii_case("application/x-protobuf") {
+            Some(Self::Protobuf)
+        } else if media_type.eq_ignore_ascii_case("application/json") {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+}
+
 pub(crate) async fn run_http_server(
     address: SocketAddr,
     tls_settings: MaybeTlsSettings,
     filters: BoxedFilter<(Response,)>,
+    max_connections: Option<usize>,
     shutdown: ShutdownSignal,
 ) -> crate::Result<()> {
     let span = Span::current();
@@ -46,54 +102,336 @@ pub(crate) async fn run_http_server(
 
     info!(message = "Building HTTP server.", address = %address);
 
-    warp::serve(routes)
-        .serve_incoming_with_graceful_shutdown(listener.accept_stream(), shutdown.map(|_| ()))
-        .await;
+    // Connections over the limit are replied to with `503 Service Unavailable` rather than left
+    // waiting to be accepted.
+    let limit = ConnectionLimit::new(max_connections);
+    let incoming = listener.accept_stream().filter_map(move |result| {
+        future::ready(match result {
+            Ok(stream) => match limit.acquire(RecordTlsPeer::new(stream)) {
+                Ok(connection) => Some(Ok(connection)),
+                Err(stream) => {
+                    emit!(OpentelemetryConnectionRejected {
+                        max_connections: max_connections.unwrap_or_default(),
+                    });
+                    tokio::spawn(reject_connection(stream));
+                    None
+                }
+            },
+            Err(error) => Some(Err(error)),
+        })
+    });
+
+    // The certificate of the client is known once the connection is read from, so each request
+    // carries the peer of its connection for the filters to read it.
+    let routes = warp::service(routes);
+    let make_service = make_service_fn(move |connection: &CountedConnection<RecordTlsPeer>| {
+        let peer = connection.get_ref().peer().clone();
+        let routes = routes.clone();
+        future::ok::<_, Infallible>(service_fn(
+            move |mut request: http::Request<hyper::Body>| {
+                request.extensions_mut().insert(peer.clone());
+                routes.clone().oneshot(request)
+            },
+        ))
+    });
+
+    Server::builder(hyper::server::accept::from_stream(incoming))
+        .serve(make_service)
+        .with_graceful_shutdown(shutdown.map(|_| ()))
+        .await?;
 
     Ok(())
 }
 
+/// Builds the filter reading the body of requests, rejecting those with a body larger than
+/// `max_request_size` with `413 Payload Too Large`.
+///
+/// The body is read as it is received, so that oversized requests are rejected without buffering
+/// them whole.
+fn build_body_filter(max_request_size: Option<usize>) -> BoxedFilter<(Bytes,)> {
+    match max_request_size {
+        Some(max_request_size) => warp::body::stream()
+            .and_then(move |body| async move {
+                read_body(body, max_request_size)
+                    .await
+                    .map_err(warp::reject::custom)
+            })
+            .boxed(),
+        None => warp::body::bytes().boxed(),
+    }
+}
+
+async fn read_body<B: Buf>(
+    body: impl Stream<Item = Result<B, warp::Error>>,
+    max_request_size: usize,
+) -> Result<Bytes, ErrorMessage> {
+    let mut body = Box::pin(body);
+    let mut bytes = BytesMut::new();
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|error| {
+            ErrorMessage::new(
+                StatusCode::BAD_REQUEST,
+                format!("Could not read request body: {}", error),
+            )
+        })?;
+        if bytes.len() + chunk.remaining() > max_request_size {
+            return Err(ErrorMessage::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "Request body is larger than the maximum of {} bytes",
+                    max_request_size
+                ),
+            ));
+        }
+        bytes.put(chunk);
+    }
+    Ok(bytes.freeze())
+}
+
+/// Builds the filter rejecting requests without the credentials required by `auth`, with
+/// `401 Unauthorized`.
+pub(crate) fn build_auth_filter(auth: HttpAuthConfig) -> BoxedFilter<()> {
+    warp::header::headers_cloned()
+        .and_then(move |headers: HeaderMap| {
+            let authorized = is_authorized(&auth, &headers);
+            async move {
+                if authorized {
+                    Ok(())
+                } else {
+                    Err(warp::reject::custom(ErrorMessage::new(
+                        StatusCode::UNAUTHORIZED,
+                        "Missing or invalid credentials".to_owned(),
+                    )))
+                }
+            }
+        })
+        .untuple_one()
+        .boxed()
+}
+
+/// Checks whether the headers of a request carry the credentials required by `auth`.
+fn is_authorized(auth: &HttpAuthConfig, headers: &HeaderMap) -> bool {
+    let header_value = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    match auth {
+        HttpAuthConfig::Bearer { token } => header_value(AUTHORIZATION.as_str())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map_or(false, |value| constant_time_eq(token.inner(), value)),
+        HttpAuthConfig::ApiKey { header, key } => {
+            header_value(header).map_or(false, |value| constant_time_eq(key.inner(), value))
+        }
+    }
+}
+
+/// Compares credentials in a time that doesn't depend on how much of them matches.
+fn constant_time_eq(expected: &str, actual: &str) -> bool {
+    expected.as_bytes().ct_eq(actual.as_bytes()).into()
+}
+
 pub(crate) fn build_warp_filter(
     acknowledgements: bool,
+    options: ConvertOptions,
     out: SourceSender,
     protocol: &'static str,
+    success_response_body: Option<String>,
+    max_request_size: Option<usize>,
+    compression: Vec<HttpCompression>,
 ) -> BoxedFilter<(Response,)> {
+    let success_response_body = Bytes::from(
+        success_response_body.unwrap_or_else(|| DEFAULT_SUCCESS_RESPONSE_BODY.to_owned()),
+    );
     warp::post()
         .and(warp::path!("v1" / "logs"))
+        .and(warp::header::<String>("content-type"))
+        .and(warp::header::optional::<String>("content-encoding"))
+        .and(warp::header::headers_cloned())
+        .and(warp::ext::optional::<TlsPeer>())
+        .and(build_body_filter(max_request_size))
+        .and_then(
+            move |content_type: String,
+                  encoding_header: Option<String>,
+                  headers: HeaderMap,
+                  peer: Option<TlsPeer>,
+                  body: Bytes| {
+                let body_encoding = BodyEncoding::from_content_type(&content_type);
+                let idempotency = options.idempotency.as_ref().and_then(|cache| {
+                    headers
+                        .get(cache.header())
+                        .and_then(|value| value.to_str().ok())
+                        .map(|key| (cache.clone(), key.to_owned()))
+                });
+                let events = match &idempotency {
+                    Some((cache, key)) if cache.is_duplicate(key) => {
+                        emit!(OpentelemetryDuplicateRequest { key });
+                        Ok(None)
+                    }
+                    _ => body_encoding
+                        .ok_or_else(|| {
+                            ErrorMessage::new(
+                                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                                format!("Unsupported content type: {}", content_type),
+                            )
+                        })
+                        .and_then(|body_encoding| {
+                            let body =
+                                decompress(&encoding_header, body, &compression, max_request_size)?;
+                            emit!(BytesReceived {
+                                byte_size: body.len(),
+                                protocol,
+                            });
+                            decode_body(body, body_encoding, &headers, peer.as_ref(), &options)
+                        })
+                        .map(Some),
+                };
+
+                handle_request(
+                    events,
+                    body_encoding,
+                    acknowledgements,
+                    out.clone(),
+                    success_response_body.clone(),
+                )
+                .map_ok(move |response| {
+                    if let Some((cache, key)) = idempotency {
+                        cache.insert(key);
+                    }
+                    response
+                })
+                .map(move |result| match result {
+                    // Errors are replied to in the encoding of the request.
+                    Err(rejection) if body_encoding == Some(BodyEncoding::Json) => {
+                        Ok(json_rejection(&rejection))
+                    }
+                    result => result,
+                })
+            },
+        )
+        .boxed()
+}
+
+/// Builds the filter receiving spans, which are sent to the `traces` output.
+pub(crate) fn build_traces_warp_filter(
+    acknowledgements: bool,
+    out: SourceSender,
+    protocol: &'static str,
+    max_request_size: Option<usize>,
+    compression: Vec<HttpCompression>,
+) -> BoxedFilter<(Response,)> {
+    warp::post()
+        .and(warp::path!("v1" / "traces"))
         .and(warp::header::exact_ignore_case(
             "content-type",
             "application/x-protobuf",
         ))
         .and(warp::header::optional::<String>("content-encoding"))
-        .and(warp::body::bytes())
+        .and(build_body_filter(max_request_size))
         .and_then(move |encoding_header: Option<String>, body: Bytes| {
-            let events = decode(&encoding_header, body).and_then(|body| {
-                emit!(BytesReceived {
-                    byte_size: body.len(),
-                    protocol,
+            let events = decompress(&encoding_header, body, &compression, max_request_size)
+                .and_then(|body| {
+                    emit!(BytesReceived {
+                        byte_size: body.len(),
+                        protocol,
+                    });
+                    decode_traces_body(body)
                 });
-                decode_body(body)
-            });
+            let out = out.clone();
+            async move {
+                match events {
+                    Ok(events) => {
+                        send_events(events, acknowledgements, out, TRACES).await?;
+                        Ok(protobuf(ExportTraceServiceResponse {}).into_response())
+                    }
+                    Err(error) => Err(warp::reject::custom(error)),
+                }
+            }
+        })
+        .boxed()
+}
 
-            handle_request(events, acknowledgements, out.clone(), super::LOGS)
+/// Builds the filter receiving metrics, which are sent to the `metrics` output, and their exemplars
+/// to the `exemplars` output if they're emitted.
+pub(crate) fn build_metrics_warp_filter(
+    acknowledgements: bool,
+    emit_exemplars: bool,
+    out: SourceSender,
+    protocol: &'static str,
+    max_request_size: Option<usize>,
+    compression: Vec<HttpCompression>,
+) -> BoxedFilter<(Response,)> {
+    warp::post()
+        .and(warp::path!("v1" / "metrics"))
+        .and(warp::header::exact_ignore_case(
+            "content-type",
+            "application/x-protobuf",
+        ))
+        .and(warp::header::optional::<String>("content-encoding"))
+        .and(build_body_filter(max_request_size))
+        .and_then(move |encoding_header: Option<String>, body: Bytes| {
+            let events = decompress(&encoding_header, body, &compression, max_request_size)
+                .and_then(|body| {
+                    emit!(BytesReceived {
+                        byte_size: body.len(),
+                        protocol,
+                    });
+                    decode_metrics_body(body, emit_exemplars)
+                });
+            let mut out = out.clone();
+            async move {
+                match events {
+                    Ok((events, exemplars)) => {
+                        if !exemplars.is_empty() {
+                            let count = exemplars.len();
+                            out.send_batch_named(EXEMPLARS, exemplars).await.map_err(
+                                move |error| {
+                                    emit!(StreamClosedError { error, count });
+                                    warp::reject::custom(ApiError::ServerShutdown)
+                                },
+                            )?;
+                        }
+                        send_events(events, acknowledgements, out, METRICS).await?;
+                        Ok(protobuf(ExportMetricsServiceResponse {}).into_response())
+                    }
+                    Err(error) => Err(warp::reject::custom(error)),
+                }
+            }
         })
         .boxed()
 }
 
-fn decode_body(body: Bytes) -> Result<Vec<Event>, ErrorMessage> {
-    let request = ExportLogsServiceRequest::decode(body).map_err(|error| {
+/// Decodes the metric events of a request, and the log events of their exemplars if they're emitted.
+fn decode_metrics_body(
+    body: Bytes,
+    emit_exemplars: bool,
+) -> Result<(Vec<Event>, Vec<Event>), ErrorMessage> {
+    let request = ExportMetricsServiceRequest::decode(body).map_err(|error| {
         ErrorMessage::new(
             StatusCode::BAD_REQUEST,
             format!("Could not decode request: {}", error),
         )
     })?;
 
-    let events: Vec<Event> = request
-        .resource_logs
-        .into_iter()
-        .flat_map(|v| v.into_iter())
-        .collect();
+    let exemplars = if emit_exemplars {
+        convert_metrics_exemplars(&request)
+    } else {
+        Vec::new()
+    };
+    let events = convert_metrics_request(request);
+    emit!(EventsReceived {
+        byte_size: events.size_of() + exemplars.size_of(),
+        count: events.len() + exemplars.len(),
+    });
+
+    Ok((events, exemplars))
+}
+
+fn decode_traces_body(body: Bytes) -> Result<Vec<Event>, ErrorMessage> {
+    let request = ExportTraceServiceRequest::decode(body).map_err(|error| {
+        ErrorMessage::new(
+            StatusCode::BAD_REQUEST,
+            format!("Could not decode request: {}", error),
+        )
+    })?;
 
+    let events = convert_trace_request(request);
     emit!(EventsReceived {
         byte_size: events.size_of(),
         count: events.len(),
@@ -102,66 +440,273 @@ fn decode_body(body: Bytes) -> Result<Vec<Event>, ErrorMessage> {
     Ok(events)
 }
 
+/// Decompresses the body of a request by its `Content-Encoding` header, if it names only the
+/// `compression` algorithms accepted.
+///
+/// Bodies decompressing to more than `max_request_size` are rejected with `413 Payload Too Large`,
+/// like compressed bodies larger than it.
+///
+/// OTLP/HTTP requires malformed requests to be rejected with `400 Bad Request`, so bodies that fail
+/// to decompress are, instead of with the `422 Unprocessable Entity` of other HTTP sources.
+fn decompress(
+    encoding_header: &Option<String>,
+    body: Bytes,
+    compression: &[HttpCompression],
+    max_request_size: Option<usize>,
+) -> Result<Bytes, ErrorMessage> {
+    let encodings = encoding_header.iter().flat_map(|header| header.split(','));
+    for encoding in encodings.map(str::trim) {
+        let accepted = encoding == "identity"
+            || compression
+                .iter()
+                .any(|compression| compression.content_encoding() == encoding);
+        if !accepted {
+            return Err(ErrorMessage::new(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!("Unsupported encoding {}", encoding),
+            ));
+        }
+    }
+
+    decode_with_limit(encoding_header, body, max_request_size).map_err(|error| {
+        if error.status_code() == StatusCode::UNPROCESSABLE_ENTITY {
+            ErrorMessage::new(StatusCode::BAD_REQUEST, error.message().to_owned())
+        } else {
+            error
+        }
+    })
+}
+
+fn decode_body(
+    body: Bytes,
+    body_encoding: BodyEncoding,
+    headers: &HeaderMap,
+    peer: Option<&TlsPeer>,
+    options: &ConvertOptions,
+) -> Result<ConvertedRequest, ErrorMessage> {
+    let request = match body_encoding {
+        BodyEncoding::Protobuf => {
+            ExportLogsServiceRequest::decode(body).map_err(|error| error.to_string())
+        }
+        BodyEncoding::Json => decode_logs_request(&body).map_err(|error| error.to_string()),
+    }
+    .map_err(|error| {
+        ErrorMessage::new(
+            StatusCode::BAD_REQUEST,
+            format!("Could not decode request: {}", error),
+        )
+    })?;
+
+    let mut converted = convert_request(request, options);
+    if options.include_proto_version {
+        let user_agent = headers
+            .get(http::header::USER_AGENT)
+            .and_then(|value| value.to_str().ok());
+        insert_user_agent_version(&mut converted.logs, user_agent, options.log_namespace);
+    }
+    insert_tls_client_metadata(
+        &mut converted.logs,
+        options.tls_client_metadata_key.as_deref(),
+        peer,
+        options.log_namespace,
+    );
+
+    if let Some(limiter) = &options.rate_limiter {
+        let tenant = headers
+            .get(limiter.header())
+            .and_then(|value| value.to_str().ok());
+        if !limiter.check(tenant, converted.logs.len() + converted.errors.len()) {
+            return Err(ErrorMessage::new(
+                StatusCode::TOO_MANY_REQUESTS,
+                "Tenant rate limit exceeded".to_owned(),
+            ));
+        }
+    }
+
+    emit!(EventsReceived {
+        byte_size: converted.logs.size_of()
+            + converted.resources.size_of()
+            + converted.errors.size_of(),
+        count: converted.logs.len() + converted.resources.len() + converted.errors.len(),
+    });
+
+    Ok(converted)
+}
+
+/// Sends the events of a request, or acknowledges it right away if it's a duplicate.
 async fn handle_request(
-    events: Result<Vec<Event>, ErrorMessage>,
+    events: Result<Option<ConvertedRequest>, ErrorMessage>,
+    body_encoding: Option<BodyEncoding>,
     acknowledgements: bool,
     mut out: SourceSender,
-    output: &str,
+    success_response_body: Bytes,
 ) -> Result<Response, Rejection> {
     match events {
-        Ok(mut events) => {
-            let receiver = BatchNotifier::maybe_apply_to(acknowledgements, &mut events);
-            let count = events.len();
-
-            out.send_batch_named(output, events)
-                .await
-                .map_err(move |error| {
-                    emit!(StreamClosedError { error, count });
-                    warp::reject::custom(ApiError::ServerShutdown)
-                })?;
-
-            match receiver {
-                None => Ok(protobuf(ExportLogsServiceResponse {}).into_response()),
-                Some(receiver) => match receiver.await {
-                    BatchStatus::Delivered => {
-                        Ok(protobuf(ExportLogsServiceResponse {}).into_response())
-                    }
-                    BatchStatus::Errored => Err(warp::reject::custom(Status {
-                        code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
-                        message: "Error delivering contents to sink".into(),
-                        ..Default::default()
-                    })),
-                    BatchStatus::Rejected => Err(warp::reject::custom(Status {
-                        code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
-                        message: "Contents failed to deliver to sink".into(),
-                        ..Default::default()
-                    })),
-                },
+        Ok(None) => Ok(logs_response(body_encoding, None, success_response_body)),
+        Ok(Some(ConvertedRequest {
+            logs,
+            log_outputs,
+            resources,
+            errors,
+            rejected,
+        })) => {
+            for (output, batch) in [(ERRORS, errors), (RESOURCES, resources)] {
+                if batch.is_empty() {
+                    continue;
+                }
+                let count = batch.len();
+                out.send_batch_named(output, batch)
+                    .await
+                    .map_err(move |error| {
+                        emit!(StreamClosedError { error, count });
+                        warp::reject::custom(ApiError::ServerShutdown)
+                    })?;
             }
+
+            // Like over gRPC, records that aren't delivered are reported as a partial success
+            // rather than failing the whole request.
+            let mut batches = split_outputs(logs, log_outputs);
+            let receivers = add_batch_notifiers(acknowledgements, &mut batches);
+            send_batches(batches, out).await?;
+            let undelivered = count_undelivered(receivers).await;
+            Ok(logs_response(
+                body_encoding,
+                partial_success(rejected, undelivered),
+                success_response_body,
+            ))
         }
         Err(err) => Err(warp::reject::custom(err)),
     }
 }
 
+/// Sends events to an output, and waits for their acknowledgement if enabled.
+async fn send_events(
+    events: Vec<Event>,
+    acknowledgements: bool,
+    out: SourceSender,
+    output: &str,
+) -> Result<(), Rejection> {
+    let outputs = vec![(output.to_owned(), events.len())];
+    send_routed_events(events, outputs, acknowledgements, out).await
+}
+
+/// Sends runs of consecutive events to their outputs, and waits for the acknowledgement of all of
+/// them if enabled.
+async fn send_routed_events(
+    mut events: Vec<Event>,
+    outputs: Vec<(String, usize)>,
+    acknowledgements: bool,
+    out: SourceSender,
+) -> Result<(), Rejection> {
+    let receiver = BatchNotifier::maybe_apply_to(acknowledgements, &mut events);
+
+    send_batches(split_outputs(events, outputs), out).await?;
+
+    match receiver {
+        None => Ok(()),
+        Some(receiver) => match receiver.await {
+            BatchStatus::Delivered => Ok(()),
+            BatchStatus::Errored => Err(warp::reject::custom(Status {
+                code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
+                message: "Error delivering contents to sink".into(),
+                ..Default::default()
+            })),
+            BatchStatus::Rejected => Err(warp::reject::custom(Status {
+                code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
+                message: "Contents failed to deliver to sink".into(),
+                ..Default::default()
+            })),
+        },
+    }
+}
+
+/// Sends batches of events to their outputs.
+async fn send_batches(
+    batches: Vec<(String, Vec<Event>)>,
+    mut out: SourceSender,
+) -> Result<(), Rejection> {
+    for (output, batch) in batches {
+        let count = batch.len();
+        out.send_batch_named(&output, batch)
+            .await
+            .map_err(move |error| {
+                emit!(StreamClosedError { error, count });
+                warp::reject::custom(ApiError::ServerShutdown)
+            })?;
+    }
+    Ok(())
+}
+
+/// The JSON encoding of an `ExportLogsServiceResponse` reporting the rejected log records.
+fn partial_success_body(partial_success: &ExportLogsPartialSuccess) -> Bytes {
+    serde_json::json!({
+        "partialSuccess": {
+            "rejectedLogRecords": partial_success.rejected_log_records.to_string(),
+            "errorMessage": partial_success.error_message,
+        }
+    })
+    .to_string()
+    .into()
+}
+
+/// Replies to a successful log export request in the encoding of the request.
+///
+/// OTLP/HTTP responses report the log records that failed conversion or delivery as a partial
+/// success. Other JSON requests are replied to with the configured body, and protobuf ones with an
+/// encoded `ExportLogsServiceResponse`.
+fn logs_response(
+    body_encoding: Option<BodyEncoding>,
+    partial_success: Option<ExportLogsPartialSuccess>,
+    success_response_body: Bytes,
+) -> Response {
+    match (body_encoding, partial_success) {
+        (Some(BodyEncoding::Json), Some(partial_success)) => {
+            json_response(partial_success_body(&partial_success))
+        }
+        (Some(BodyEncoding::Json), None) => json_response(success_response_body),
+        (_, partial_success) => {
+            protobuf(ExportLogsServiceResponse { partial_success }).into_response()
+        }
+    }
+}
+
+fn json_response(body: Bytes) -> Response {
+    let mut response = Response::new(body.into());
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
 async fn handle_rejection(err: Rejection) -> Result<impl Reply, std::convert::Infallible> {
+    let (status, status_code) = rejection_status(&err);
+    Ok(warp::reply::with_status(protobuf(status), status_code))
+}
+
+/// Replies to a rejected request encoded as JSON with the JSON encoding of its `Status`.
+fn json_rejection(err: &Rejection) -> Response {
+    let (status, status_code) = rejection_status(err);
+    let reply = warp::reply::json(&serde_json::json!({
+        "code": status.code,
+        "message": status.message,
+    }));
+    warp::reply::with_status(reply, status_code).into_response()
+}
+
+fn rejection_status(err: &Rejection) -> (Status, StatusCode) {
     if let Some(err_msg) = err.find::<ErrorMessage>() {
-        let reply = protobuf(Status {
+        let status = Status {
             code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
             message: err_msg.message().into(),
             ..Default::default()
-        });
-
-        Ok(warp::reply::with_status(reply, err_msg.status_code()))
+        };
+        (status, err_msg.status_code())
     } else {
-        let reply = protobuf(Status {
+        let status = Status {
             code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
             message: format!("{:?}", err),
             ..Default::default()
-        });
-
-        Ok(warp::reply::with_status(
-            reply,
-            StatusCode::INTERNAL_SERVER_ERROR,
-        ))
+        };
+        (status, StatusCode::INTERNAL_SERVER_ERROR)
     }
 }
@@ -0,0 +1,114 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+/// The number of keys remembered before the oldest ones are forgotten, even if they haven't expired.
+const MAX_TRACKED_KEYS: usize = 100_000;
+
+/// Remembers the idempotency keys of the export requests processed within a TTL.
+#[derive(Clone, Debug)]
+pub(crate) struct IdempotencyCache {
+    header: String,
+    ttl: Duration,
+    keys: Arc<Mutex<Keys>>,
+}
+
+#[derive(Debug, Default)]
+struct Keys {
+    /// The time each key was last processed at.
+    processed: HashMap<String, Instant>,
+    /// The keys in the order they were processed, which is also the order they expire in.
+    order: VecDeque<(String, Instant)>,
+}
+
+impl Keys {
+    /// Forgets the expired keys, and the oldest keys beyond `MAX_TRACKED_KEYS`.
+    fn evict(&mut self, now: Instant, ttl: Duration) {
+        while let Some((key, processed)) = self.order.front() {
+            let expired = now.saturating_duration_since(*processed) >= ttl;
+            if !expired && self.order.len() <= MAX_TRACKED_KEYS {
+                break;
+            }
+            // Only forget the key if it wasn't processed again since.
+            if self.processed.get(key) == Some(processed) {
+                self.processed.remove(key);
+            }
+            self.order.pop_front();
+        }
+    }
+}
+
+impl IdempotencyCache {
+    pub(crate) fn new(header: String, ttl: Duration) -> Self {
+        Self {
+            header,
+            ttl,
+            keys: Default::default(),
+        }
+    }
+
+    /// The name of the header holding the idempotency key.
+    pub(crate) fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Checks whether a request with `key` was already processed within the TTL.
+    pub(crate) fn is_duplicate(&self, key: &str) -> bool {
+        let now = Instant::now();
+        let mut keys = self.keys.lock().expect("poisoned lock");
+        keys.evict(now, self.ttl);
+        keys.processed.contains_key(key)
+    }
+
+    /// Remembers that a request with `key` was processed.
+    ///
+    /// Keys are only remembered once their request has been processed successfully, so that the
+    /// retries of failed requests aren't dropped.
+    pub(crate) fn insert(&self, key: String) {
+        let now = Instant::now();
+        let mut keys = self.keys.lock().expect("poisoned lock");
+        keys.processed.insert(key.clone(), now);
+        keys.order.push_back((key, now));
+        keys.evict(now, self.ttl);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn keys_expire_after_ttl() {
+        let cache = IdempotencyCache::new("idempotency-key".to_owned(), Duration::from_secs(60));
+
+        assert!(!cache.is_duplicate("a"));
+        cache.insert("a".to_owned());
+        assert!(cache.is_duplicate("a"));
+        assert!(!cache.is_duplicate("b"));
+
+        tokio::time::advance(Duration::from_secs(30)).await;
+        // Processing the key again extends its TTL.
+        cache.insert("a".to_owned());
+        tokio::time::advance(Duration::from_secs(45)).await;
+        assert!(cache.is_duplicate("a"));
+
+        tokio::time::advance(Duration::from_secs(15)).await;
+        assert!(!cache.is_duplicate("a"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn forgets_oldest_keys_beyond_capacity() {
+        let cache = IdempotencyCache::new("idempotency-key".to_owned(), Duration::from_secs(60));
+
+        for key in 0..=MAX_TRACKED_KEYS {
+            cache.insert(key.to_string());
+        }
+        assert!(!cache.is_duplicate("0"));
+        assert!(cache.is_duplicate("1"));
+        assert!(cache.is_duplicate(&MAX_TRACKED_KEYS.to_string()));
+    }
+}
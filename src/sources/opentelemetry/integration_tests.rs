@@ -15,7 +15,7 @@ use crate::{
     SourceSender,
 };
 
-use super::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS};
+use super::{default_http_compression, GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS};
 
 fn otel_health_url() -> String {
     std::env::var("OTEL_HEALTH_URL").unwrap_or_else(|_| "http://0.0.0.0:13133".to_owned())
@@ -42,12 +42,55 @@ async fn receive_logs() {
             grpc: GrpcConfig {
                 address: source_grpc_address().parse().unwrap(),
                 tls: Default::default(),
+                client_auth: None,
+                drain_connections_on_restart: true,
+                bind_retry_timeout_secs: None,
+                http2_keepalive_interval_secs: None,
+                http2_keepalive_timeout_secs: None,
+                max_frame_size_bytes: None,
+                max_concurrent_streams: None,
+                max_recv_message_size: None,
             },
             http: HttpConfig {
                 address: source_http_address().parse().unwrap(),
                 tls: Default::default(),
+                success_response_body: None,
+                auth: None,
+                max_request_size_bytes: None,
+                max_connections: None,
+                compression: default_http_compression(),
             },
             acknowledgements: Default::default(),
+            error_output: false,
+            body_pattern: None,
+            emit_resource: Default::default(),
+            repeat_window_ms: None,
+            tenant_rate_limits: None,
+            enrichment_table: None,
+            partition_fields: false,
+            partition_timezone: None,
+            metric_tag_from_attribute: None,
+            metric_tag_max_values: 100,
+            include_proto_version: false,
+            idempotency_header: None,
+            idempotency_ttl_secs: 300,
+            coerce_bool_strings: false,
+            bool_true_strings: vec!["true".to_owned()],
+            bool_false_strings: vec!["false".to_owned()],
+            resource_filter: None,
+            normalize_severity_text: false,
+            normalize_severity: false,
+            resource_id_field: None,
+            enable_metrics: false,
+            emit_exemplars: false,
+            namespace_outputs: None,
+            field_mapping: Default::default(),
+            error_flag: None,
+            structured_body: None,
+            parse_json_body: None,
+            attributes_key: "attributes".into(),
+            resources_key: "resources".into(),
+            log_namespace: None,
         };
 
         let (sender, logs_output, _) = new_source(EventStatus::Delivered);
@@ -0,0 +1,360 @@
+//! Decoding of OTLP/HTTP requests encoded as JSON.
+//!
+//! The OTLP JSON encoding follows the Protobuf JSON mapping, with lowerCamelCase field names and
+//! 64-bit integers encoded as either strings or numbers, except that trace and span IDs are hex
+//! encoded rather than base64 encoded.
+
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::opentelemetry::{
+    Common::{any_value, AnyValue, ArrayValue, InstrumentationScope, KeyValue, KeyValueList},
+    LogService::ExportLogsServiceRequest,
+    Logs::{LogRecord, ResourceLogs, ScopeLogs},
+    Resource,
+};
+
+/// Decodes an export request for log records from its JSON encoding.
+pub(crate) fn decode_logs_request(body: &[u8]) -> serde_json::Result<ExportLogsServiceRequest> {
+    serde_json::from_slice::<JsonLogsRequest>(body).map(Into::into)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLogsRequest {
+    #[serde(default)]
+    resource_logs: Vec<JsonResourceLogs>,
+}
+
+impl From<JsonLogsRequest> for ExportLogsServiceRequest {
+    fn from(request: JsonLogsRequest) -> Self {
+        Self {
+            resource_logs: collect(request.resource_logs),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonResourceLogs {
+    resource: Option<JsonResource>,
+    #[serde(default)]
+    scope_logs: Vec<JsonScopeLogs>,
+    #[serde(default)]
+    schema_url: String,
+}
+
+impl From<JsonResourceLogs> for ResourceLogs {
+    fn from(resource_logs: JsonResourceLogs) -> Self {
+        Self {
+            resource: resource_logs.resource.map(Into::into),
+            scope_logs: collect(resource_logs.scope_logs),
+            schema_url: resource_logs.schema_url,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonResource {
+    #[serde(default)]
+    attributes: Vec<JsonKeyValue>,
+    #[serde(default)]
+    dropped_attributes_count: u32,
+}
+
+impl From<JsonResource> for Resource {
+    fn from(resource: JsonResource) -> Self {
+        Self {
+            attributes: collect(resource.attributes),
+            dropped_attributes_count: resource.dropped_attributes_count,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonScopeLogs {
+    scope: Option<JsonScope>,
+    #[serde(default)]
+    log_records: Vec<JsonLogRecord>,
+    #[serde(default)]
+    schema_url: String,
+}
+
+impl From<JsonScopeLogs> for ScopeLogs {
+    fn from(scope_logs: JsonScopeLogs) -> Self {
+        Self {
+            scope: scope_logs.scope.map(Into::into),
+            log_records: collect(scope_logs.log_records),
+            schema_url: scope_logs.schema_url,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonScope {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    attributes: Vec<JsonKeyValue>,
+    #[serde(default)]
+    dropped_attributes_count: u32,
+}
+
+impl From<JsonScope> for InstrumentationScope {
+    fn from(scope: JsonScope) -> Self {
+        Self {
+            name: scope.name,
+            version: scope.version,
+            attributes: collect(scope.attributes),
+            dropped_attributes_count: scope.dropped_attributes_count,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonLogRecord {
+    #[serde(default, deserialize_with = "number")]
+    time_unix_nano: u64,
+    #[serde(default, deserialize_with = "number")]
+    observed_time_unix_nano: u64,
+    #[serde(default)]
+    severity_number: i32,
+    #[serde(default)]
+    severity_text: String,
+    body: Option<JsonAnyValue>,
+    #[serde(default)]
+    attributes: Vec<JsonKeyValue>,
+    #[serde(default)]
+    dropped_attributes_count: u32,
+    #[serde(default)]
+    flags: u32,
+    #[serde(default, deserialize_with = "hex_bytes")]
+    trace_id: Vec<u8>,
+    #[serde(default, deserialize_with = "hex_bytes")]
+    span_id: Vec<u8>,
+}
+
+impl From<JsonLogRecord> for LogRecord {
+    fn from(record: JsonLogRecord) -> Self {
+        Self {
+            time_unix_nano: record.time_unix_nano,
+            observed_time_unix_nano: record.observed_time_unix_nano,
+            severity_number: record.severity_number,
+            severity_text: record.severity_text,
+            body: record.body.map(Into::into),
+            attributes: collect(record.attributes),
+            dropped_attributes_count: record.dropped_attributes_count,
+            flags: record.flags,
+            trace_id: record.trace_id,
+            span_id: record.span_id,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonKeyValue {
+    #[serde(default)]
+    key: String,
+    value: Option<JsonAnyValue>,
+}
+
+impl From<JsonKeyValue> for KeyValue {
+    fn from(key_value: JsonKeyValue) -> Self {
+        Self {
+            key: key_value.key,
+            value: key_value.value.map(Into::into),
+        }
+    }
+}
+
+/// The JSON encoding of an `AnyValue`, an object holding at most one of the fields.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonAnyValue {
+    string_value: Option<String>,
+    bool_value: Option<bool>,
+    #[serde(default, deserialize_with = "optional_number")]
+    int_value: Option<i64>,
+    #[serde(default, deserialize_with = "optional_number")]
+    double_value: Option<f64>,
+    array_value: Option<JsonValues<JsonAnyValue>>,
+    kvlist_value: Option<JsonValues<JsonKeyValue>>,
+    #[serde(default, deserialize_with = "optional_base64_bytes")]
+    bytes_value: Option<Vec<u8>>,
+}
+
+impl From<JsonAnyValue> for AnyValue {
+    fn from(value: JsonAnyValue) -> Self {
+        let value = if let Some(value) = value.string_value {
+            Some(any_value::Value::StringValue(value))
+        } else if let Some(value) = value.bool_value {
+            Some(any_value::Value::BoolValue(value))
+        } else if let Some(value) = value.int_value {
+            Some(any_value::Value::IntValue(value))
+        } else if let Some(value) = value.double_value {
+            Some(any_value::Value::DoubleValue(value))
+        } else if let Some(array) = value.array_value {
+            Some(any_value::Value::ArrayValue(ArrayValue {
+                values: collect(array.values),
+            }))
+        } else if let Some(kvlist) = value.kvlist_value {
+            Some(any_value::Value::KvlistValue(KeyValueList {
+                values: collect(kvlist.values),
+            }))
+        } else {
+            value.bytes_value.map(any_value::Value::BytesValue)
+        };
+        Self { value }
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonValues<T> {
+    #[serde(default = "Vec::new")]
+    values: Vec<T>,
+}
+
+fn collect<T: Into<U>, U>(values: Vec<T>) -> Vec<U> {
+    values.into_iter().map(Into::into).collect()
+}
+
+/// Deserializes a number encoded as either a JSON number or a string, as the Protobuf JSON mapping
+/// does for 64-bit integers and non-finite doubles.
+fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: fmt::Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number<T> {
+        Number(T),
+        String(String),
+    }
+
+    match Number::deserialize(deserializer)? {
+        Number::Number(value) => Ok(value),
+        Number::String(value) => value.parse().map_err(de::Error::custom),
+    }
+}
+
+fn optional_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: fmt::Display,
+{
+    number(deserializer).map(Some)
+}
+
+fn hex_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    hex::decode(value).map_err(de::Error::custom)
+}
+
+fn optional_base64_bytes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<u8>>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    base64::decode(value).map(Some).map_err(de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_otlp_json_mapping() {
+        let body = br#"{
+            "resourceLogs": [{
+                "resource": {
+                    "attributes": [{"key": "service.name", "value": {"stringValue": "checkout"}}]
+                },
+                "scopeLogs": [{
+                    "scope": {"name": "browser", "version": "1.0.0"},
+                    "logRecords": [{
+                        "timeUnixNano": "1660000000000000000",
+                        "observedTimeUnixNano": 1660000000000000001,
+                        "severityNumber": 9,
+                        "severityText": "INFO",
+                        "body": {"stringValue": "clicked"},
+                        "attributes": [
+                            {"key": "count", "value": {"intValue": "3"}},
+                            {"key": "ratio", "value": {"doubleValue": 0.5}},
+                            {"key": "tags", "value": {"arrayValue": {"values": [{"boolValue": true}]}}},
+                            {"key": "raw", "value": {"bytesValue": "aGk="}}
+                        ],
+                        "flags": 1,
+                        "traceId": "4ac52aadf321c2e531db005df08792f5",
+                        "spanId": "0b9e4bda2a55530d",
+                        "unknownField": "ignored"
+                    }]
+                }],
+                "schemaUrl": "https://opentelemetry.io/schemas/1.9.0"
+            }]
+        }"#;
+
+        let request = decode_logs_request(body).unwrap();
+        let resource_logs = &request.resource_logs[0];
+        assert_eq!(
+            resource_logs.resource.as_ref().unwrap().attributes[0].key,
+            "service.name"
+        );
+        assert_eq!(
+            resource_logs.schema_url,
+            "https://opentelemetry.io/schemas/1.9.0"
+        );
+        let scope_logs = &resource_logs.scope_logs[0];
+        assert_eq!(scope_logs.scope.as_ref().unwrap().name, "browser");
+
+        let record = &scope_logs.log_records[0];
+        assert_eq!(record.time_unix_nano, 1_660_000_000_000_000_000);
+        assert_eq!(record.observed_time_unix_nano, 1_660_000_000_000_000_001);
+        assert_eq!(record.severity_number, 9);
+        assert_eq!(record.severity_text, "INFO");
+        assert_eq!(
+            record.body.as_ref().unwrap().value,
+            Some(any_value::Value::StringValue("clicked".into()))
+        );
+        let values = record
+            .attributes
+            .iter()
+            .map(|attribute| attribute.value.clone().unwrap().value.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                any_value::Value::IntValue(3),
+                any_value::Value::DoubleValue(0.5),
+                any_value::Value::ArrayValue(ArrayValue {
+                    values: vec![AnyValue {
+                        value: Some(any_value::Value::BoolValue(true)),
+                    }],
+                }),
+                any_value::Value::BytesValue(b"hi".to_vec()),
+            ]
+        );
+        assert_eq!(record.flags, 1);
+        assert_eq!(
+            record.trace_id,
+            hex::decode("4ac52aadf321c2e531db005df08792f5").unwrap()
+        );
+        assert_eq!(record.span_id, hex::decode("0b9e4bda2a55530d").unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_trace_id() {
+        let body =
+            br#"{"resourceLogs": [{"scopeLogs": [{"logRecords": [{"traceId": "not hex"}]}]}]}"#;
+        assert!(decode_logs_request(body).is_err());
+    }
+}
@@ -3,33 +3,168 @@ mod integration_tests;
 #[cfg(test)]
 mod tests;
 
+mod connection_limit;
+mod enrichment;
 mod grpc;
 mod http;
+mod idempotency;
+mod json;
+mod records_tag;
 mod reply;
+mod server_builder;
 mod status;
+mod tenant_rate_limit;
 
-use std::net::SocketAddr;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    net::SocketAddr,
+    path::PathBuf,
+    time::Duration,
+};
 
+use chrono::{DateTime, Datelike, Local, Timelike, Utc};
+use derivative::Derivative;
 use futures::{future::join, FutureExt, TryFutureExt};
-
+use lookup::path;
+use prost::Message;
+use regex::Regex;
+use snafu::{ResultExt, Snafu};
+use value::{kind::Collection, Kind};
+use vector_common::TimeZone;
 use vector_config::configurable_component;
-use vector_core::config::LogNamespace;
+use vector_core::config::{log_schema, LogNamespace};
+use warp::Filter;
 
 use crate::{
     config::{
-        AcknowledgementsConfig, DataType, GenerateConfig, Output, Resource, SourceConfig,
+        schema, AcknowledgementsConfig, DataType, GenerateConfig, Output, Resource, SourceConfig,
         SourceContext, SourceDescription,
     },
-    opentelemetry::LogService::logs_service_server::LogsServiceServer,
+    event::{Event, LogEvent, Value},
+    internal_events::{
+        OpentelemetryBodyPatternMismatch, OpentelemetryEventSize, OpentelemetryJsonBodyMiss,
+        OpentelemetryMetricConversionError, OpentelemetryRecordConversionError,
+        OpentelemetryRecordsReceived, OpentelemetryResourceFiltered,
+        OpentelemetrySpanConversionError, TemplateRenderingError,
+    },
+    opentelemetry::{
+        convert::RecordError,
+        LogService::{logs_service_server::LogsServiceServer, ExportLogsServiceRequest},
+        Metrics::ResourceMetrics,
+        MetricsService::{
+            metrics_service_server::MetricsServiceServer, ExportMetricsServiceRequest,
+        },
+        TraceService::{trace_service_server::TraceServiceServer, ExportTraceServiceRequest},
+    },
+    schema::Definition,
+    sensitive_string::SensitiveString,
     serde::bool_or_struct,
-    sources::{util::grpc::run_grpc_server, Source},
-    tls::{MaybeTlsSettings, TlsEnableableConfig},
+    sources::{
+        util::grpc::{run_grpc_server_with_routes, GrpcServerSettings, TlsPeer},
+        Source,
+    },
+    template::Template,
+    tls::{MaybeTlsSettings, TlsClientAuthConfig, TlsSourceConfig},
 };
 
+use self::enrichment::EnrichmentLookup;
+pub use self::enrichment::EnrichmentLookupConfig;
 use self::http::run_http_server;
-use self::{grpc::Service, http::build_warp_filter};
+use self::idempotency::IdempotencyCache;
+use self::records_tag::RecordsTag;
+use self::tenant_rate_limit::TenantRateLimiter;
+pub use self::tenant_rate_limit::TenantRateLimitsConfig;
+use self::{
+    grpc::{MetricsExportService, Service, TraceExportService},
+    http::{
+        build_auth_filter, build_metrics_warp_filter, build_traces_warp_filter, build_warp_filter,
+    },
+};
 
 pub const LOGS: &str = "logs";
+pub const TRACES: &str = "traces";
+pub const METRICS: &str = "metrics";
+pub const EXEMPLARS: &str = "exemplars";
+pub const ERRORS: &str = "errors";
+pub const RESOURCES: &str = "resources";
+
+const SOURCE_NAME: &str = "opentelemetry";
+/// The OTLP/gRPC port recommended by the OTLP specification.
+const GRPC_PORT: u16 = 4317;
+/// The OTLP/HTTP port recommended by the OTLP specification.
+const HTTP_PORT: u16 = 4318;
+const RESOURCE_ID_KEY: &str = "resource_id";
+const NAMESPACE_ATTRIBUTE: &str = "service.namespace";
+const RESOURCES_KEY: &str = "resources";
+const SCOPE_KEY: &str = "scope";
+const ATTRIBUTES_KEY: &str = "attributes";
+const REPEAT_COUNT_KEY: &str = "repeat_count";
+const PARTITION_KEYS: [&str; 4] = ["year", "month", "day", "hour"];
+const PROTO_VERSION_KEY: &str = "proto_version";
+const SEVERITY_TEXT_KEY: &str = "severity_text";
+const SEVERITY_NUMBER_KEY: &str = "severity_number";
+const LEVEL_KEY: &str = "level";
+const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
+const TRACE_ID_KEY: &str = "trace_id";
+const SPAN_ID_KEY: &str = "span_id";
+const FLAGS_KEY: &str = "flags";
+const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
+const IS_ERROR_KEY: &str = "is_error";
+/// The levels of the canonical OTLP severity labels, in order of severity number ranges.
+const SEVERITY_LEVELS: [&str; 6] = ["TRACE", "DEBUG", "INFO", "WARN", "ERROR", "FATAL"];
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("Invalid `body_pattern`: {}", source))]
+    InvalidBodyPattern { source: regex::Error },
+    #[snafu(display("`body_pattern` must contain at least one named capture group"))]
+    BodyPatternWithoutCaptures,
+    #[snafu(display(
+        "{:?} can't be in both `bool_true_strings` and `bool_false_strings`",
+        value
+    ))]
+    AmbiguousBoolString { value: String },
+    #[snafu(display("Invalid `resource_filter` pattern for {:?}: {}", attribute, source))]
+    InvalidResourceFilter {
+        attribute: String,
+        source: regex::Error,
+    },
+    #[snafu(display(
+        "`namespace_outputs` has {} namespaces, more than its `max_outputs` of {}",
+        count,
+        max_outputs
+    ))]
+    TooManyNamespaceOutputs { count: usize, max_outputs: usize },
+    #[snafu(display("`{}` can't be combined with `field_mapping.{}`", option, field))]
+    ConflictingFieldMapping {
+        option: &'static str,
+        field: &'static str,
+    },
+    #[snafu(display(
+        "`grpc.max_frame_size_bytes` of {} is not between 16384 and 16777215",
+        size
+    ))]
+    InvalidMaxFrameSize { size: usize },
+    #[snafu(display("Invalid `http.auth.header` {:?}: {}", header, source))]
+    InvalidAuthHeader {
+        header: String,
+        source: ::http::header::InvalidHeaderName,
+    },
+    #[snafu(display(
+        "Exactly one of `enrichment_table.entries` and `enrichment_table.path` must be set"
+    ))]
+    EnrichmentTableSource,
+    #[snafu(display("Could not read `enrichment_table.path` {:?}: {}", path, source))]
+    ReadEnrichmentTable {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[snafu(display("Invalid `enrichment_table.path` {:?}: {}", path, source))]
+    InvalidEnrichmentTable {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
 
 /// Configuration for the `opentelemetry` source.
 #[configurable_component(source)]
@@ -37,17 +172,581 @@ pub const LOGS: &str = "logs";
 #[serde(deny_unknown_fields)]
 pub struct OpentelemetryConfig {
     #[configurable(derived)]
+    #[serde(default)]
     grpc: GrpcConfig,
 
     #[configurable(derived)]
+    #[serde(default)]
     http: HttpConfig,
 
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: AcknowledgementsConfig,
+
+    /// Emits log records that fail conversion as error events on a dedicated `errors` output.
+    ///
+    /// Normally, log records that cannot be converted into events are dropped after the failure is logged. When
+    /// `error_output` is `true`, an event is sent to the `errors` output instead, containing the error message, the
+    /// index of the record within the export request, and the original record as base64-encoded protobuf bytes.
+    #[serde(default)]
+    error_output: bool,
+
+    /// A regular expression with named capture groups, applied to log records with a string body.
+    ///
+    /// When the body matches, each named capture is inserted into the event as a field of the same name, replacing any
+    /// existing field. Bodies that don't match are passed through unchanged.
+    body_pattern: Option<String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    emit_resource: EmitResource,
+
+    /// Collapses runs of consecutive log records with identical bodies into a single event.
+    ///
+    /// Records of the same resource within an export request are collapsed while their timestamps are within this
+    /// many milliseconds of the first record of the run. Only the event of the first record is emitted, with the number
+    /// of records it stands for as the `repeat_count` field.
+    repeat_window_ms: Option<u64>,
+
+    #[configurable(derived)]
+    tenant_rate_limits: Option<TenantRateLimitsConfig>,
+
+    #[configurable(derived)]
+    enrichment_table: Option<EnrichmentLookupConfig>,
+
+    /// Adds `year`, `month`, `day` and `hour` fields to each log record, computed from its timestamp.
+    ///
+    /// The timestamp is the record's `time_unix_nano`, or its `observed_time_unix_nano` if unset. The fields are
+    /// zero-padded strings, such as `2022`, `08`, `05` and `09`, so that they can be used directly in the key prefix of
+    /// object store sinks to partition the records by date.
+    #[serde(default)]
+    partition_fields: bool,
+
+    /// The time zone of the fields added by `partition_fields`.
+    ///
+    /// By default, UTC is used.
+    partition_timezone: Option<TimeZone>,
+
+    /// The name of a resource attribute to tag the `component_received_records_total` internal metric with.
+    ///
+    /// The tag has the same name as the attribute, and the attribute's value for the records of each resource as its
+    /// value, or `_none` for resources without the attribute. String, integer and boolean attributes are supported. The
+    /// attribute should have a low cardinality, such as `service.name`.
+    metric_tag_from_attribute: Option<String>,
+
+    /// The maximum number of distinct values of `metric_tag_from_attribute` to tag the metric with.
+    ///
+    /// The records of resources with any other value are tagged with `_other`.
+    #[serde(default = "default_metric_tag_max_values")]
+    metric_tag_max_values: usize,
+
+    /// Adds the OTLP version the log records were sent with as the `proto_version` field, such as `v0.20.0`.
+    ///
+    /// The version is taken from the `schema_url` of the records' resource, or otherwise from the `User-Agent` header of
+    /// the request. The field is absent if neither contains a version.
+    #[serde(default)]
+    include_proto_version: bool,
+
+    /// The name of the header holding the idempotency key of export requests.
+    ///
+    /// Requests with the key of a request processed successfully within `idempotency_ttl_secs` are acknowledged without
+    /// emitting their log records again. For gRPC requests, this is the name of the metadata entry. Requests without the
+    /// header are always processed. By default, requests are not deduplicated.
+    idempotency_header: Option<String>,
+
+    /// How long the idempotency keys of processed requests are remembered, in seconds.
+    #[serde(default = "default_idempotency_ttl_secs")]
+    idempotency_ttl_secs: u64,
+
+    /// Converts the string values of log record and resource attributes into booleans.
+    ///
+    /// Values exactly matching one of `bool_true_strings` or `bool_false_strings` are converted, and any other string is
+    /// left untouched.
+    #[serde(default)]
+    coerce_bool_strings: bool,
+
+    /// The attribute values converted to `true` when `coerce_bool_strings` is enabled.
+    #[serde(default = "default_bool_true_strings")]
+    bool_true_strings: Vec<String>,
+
+    /// The attribute values converted to `false` when `coerce_bool_strings` is enabled.
+    #[serde(default = "default_bool_false_strings")]
+    bool_false_strings: Vec<String>,
+
+    /// Regular expressions that resource attributes must match for the log records of the resource to be emitted.
+    ///
+    /// Each key is the name of a resource attribute, and each value a pattern its value must match. String, integer and
+    /// boolean attributes are supported. The log records of resources missing any of the attributes, or with a value
+    /// that doesn't match its pattern, are dropped. By default, all log records are emitted.
+    resource_filter: Option<HashMap<String, String>>,
+
+    /// Normalizes the `severity_text` of log records into the canonical uppercase OTLP severity labels.
+    ///
+    /// Texts matching a label regardless of case, such as `Info` or `info`, are converted to it, such as `INFO`. Other
+    /// texts are replaced by the label of the record's `severity_number`, such as `WARN2` for 14, and are left untouched
+    /// if the record has no severity number either.
+    #[serde(default)]
+    normalize_severity_text: bool,
+
+    /// Adds a `level` field to log records, holding the canonical OTLP severity level of their `severity_number`.
+    ///
+    /// Severity numbers are mapped by the bands of the OTLP specification: 1 to 4 to `TRACE`, 5 to 8 to `DEBUG`, 9 to 12
+    /// to `INFO`, 13 to 16 to `WARN`, 17 to 20 to `ERROR` and 21 to 24 to `FATAL`. The `severity_number` and
+    /// `severity_text` fields are kept as they are, and the field is absent for records without a valid severity number.
+    #[serde(default)]
+    normalize_severity: bool,
+
+    /// The name of a field to add to each log record, holding a digest of its resource attributes.
+    ///
+    /// The digest is a hash of the attributes sorted by name, so log records of identical resources get the same value,
+    /// regardless of the order their attributes were sent in. It can be used to group or join log records by resource
+    /// without carrying the resource attributes. The field is absent for log records without resource attributes.
+    resource_id_field: Option<String>,
+
+    /// Accepts OTLP metrics, in addition to log records and spans.
+    ///
+    /// Export requests of metrics are accepted by the gRPC server, and on the `/v1/metrics` path of the HTTP server.
+    /// Their gauges, sums, histograms and summaries are converted into metric events sent to the `metrics` output,
+    /// with the attributes of the data points and of their resource as tags. By default, metrics are not accepted.
+    #[serde(default)]
+    enable_metrics: bool,
+
+    /// Emits the exemplars of the data points of OTLP metrics as log events, on the `exemplars` output.
+    ///
+    /// Each event holds the name of the metric, the value of the exemplar, its trace and span IDs, its filtered
+    /// attributes, and the tags of the metric event of its data point, so that traces can be correlated with metrics.
+    /// Only relevant when `enable_metrics` is `true`. By default, exemplars are dropped.
+    #[serde(default)]
+    emit_exemplars: bool,
+
+    #[configurable(derived)]
+    namespace_outputs: Option<NamespaceOutputsConfig>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    field_mapping: FieldMappingConfig,
+
+    #[configurable(derived)]
+    error_flag: Option<ErrorFlagConfig>,
+
+    #[configurable(derived)]
+    structured_body: Option<StructuredBodyConfig>,
+
+    #[configurable(derived)]
+    parse_json_body: Option<JsonBodyConfig>,
+
+    /// The name of the field holding the attributes of log records.
+    ///
+    /// Paths such as `otel.attributes` relocate the attributes, keeping them apart from fields of events from other
+    /// sources. When empty, the attributes are merged into the root of the event instead, without replacing the fields
+    /// of the log record itself.
+    #[serde(default = "default_attributes_key")]
+    attributes_key: String,
+
+    /// The name of the field holding the attributes of the resource of log records.
+    ///
+    /// Paths such as `otel.resources` relocate the resource attributes, keeping them apart from fields of events from
+    /// other sources. When empty, the resource attributes are merged into the root of the event instead, without
+    /// replacing the fields of the log record itself.
+    #[serde(default = "default_resources_key")]
+    resources_key: String,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    ///
+    /// With the `Vector` namespace, the body of log records becomes the event, and their other fields, such as the
+    /// attributes, resources, and severity, are placed in the `opentelemetry` source metadata.
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+fn default_attributes_key() -> String {
+    ATTRIBUTES_KEY.to_owned()
+}
+
+fn default_resources_key() -> String {
+    RESOURCES_KEY.to_owned()
+}
+
+/// Flagging of log records indicating an error, with an `is_error` field set to `true`.
+///
+/// Records are flagged when their severity number is at least `min_severity_number`, or when they carry any of
+/// `attributes`. The field is absent from other records.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ErrorFlagConfig {
+    /// The lowest severity number of the records flagged as errors.
+    ///
+    /// By default, records of the `ERROR` and `FATAL` severity levels, from 17, are flagged.
+    #[serde(default = "default_error_min_severity_number")]
+    pub(crate) min_severity_number: i64,
+
+    /// The names of the attributes flagging the records carrying them as errors.
+    ///
+    /// Attributes set to `false` don't flag records.
+    #[serde(default = "default_error_attributes")]
+    pub(crate) attributes: Vec<String>,
+}
+
+const fn default_error_min_severity_number() -> i64 {
+    17
+}
+
+fn default_error_attributes() -> Vec<String> {
+    vec!["exception.type".into()]
+}
+
+/// Rendering of the structured bodies of log records into a string message.
+///
+/// The body of log records whose body is a map or an array is moved to `body_key`, and the message is replaced by a
+/// rendering of it, so that sinks expecting a string message can render the records. String bodies are left untouched.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct StructuredBodyConfig {
+    /// The name of the field the structured body is moved to.
+    #[serde(default = "default_structured_body_key")]
+    body_key: String,
+
+    /// A template rendering the message of log records with a structured body, such as `{{ body.event }}`.
+    ///
+    /// The template is rendered once the body is moved to `body_key`, so it refers to the body through that field. By
+    /// default, or when the template fails to render, the message is the body encoded as JSON.
+    #[configurable(metadata(templatable))]
+    message_template: Option<Template>,
+}
+
+fn default_structured_body_key() -> String {
+    "body".to_owned()
+}
+
+/// Parsing of string bodies holding a JSON object into structured fields.
+///
+/// Bodies that aren't a JSON object are passed through unchanged.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct JsonBodyConfig {
+    #[configurable(derived)]
+    #[serde(default)]
+    mode: JsonBodyMode,
+}
+
+/// Where the fields of a parsed JSON body are placed.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum JsonBodyMode {
+    /// The message is replaced by the parsed object.
+    #[derivative(Default)]
+    Nest,
+
+    /// The fields of the parsed object are inserted into the root of the event, replacing any existing field, and the
+    /// message is kept.
+    Merge,
+}
+
+impl JsonBodyConfig {
+    /// Parses a string body holding a JSON object, placing its fields according to `mode`.
+    fn parse(&self, log: &mut LogEvent) {
+        let message_key = log_schema().message_key();
+        let object = match log.get(message_key) {
+            Some(Value::Bytes(body)) => match serde_json::from_slice(body) {
+                Ok(serde_json::Value::Object(object)) => object,
+                _ => {
+                    emit!(OpentelemetryJsonBodyMiss);
+                    return;
+                }
+            },
+            _ => return,
+        };
+
+        match self.mode {
+            JsonBodyMode::Nest => {
+                log.insert(message_key, Value::from(serde_json::Value::Object(object)));
+            }
+            JsonBodyMode::Merge => {
+                if let Some(root) = log.as_map_mut() {
+                    for (key, value) in object {
+                        root.insert(key, value.into());
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl StructuredBodyConfig {
+    /// Moves a structured body to `body_key`, replacing the message with its rendering.
+    fn render(&self, log: &mut LogEvent) {
+        let message_key = log_schema().message_key();
+        let body = match log.get(message_key) {
+            Some(body @ (Value::Object(_) | Value::Array(_))) => body.clone(),
+            _ => return,
+        };
+        let json = serde_json::to_string(&body);
+        log.insert(self.body_key.as_str(), body);
+
+        let rendered = self.message_template.as_ref().and_then(|template| {
+            template
+                .render_string(&*log)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        field: Some("message_template"),
+                        drop_event: false,
+                        error,
+                    })
+                })
+                .ok()
+        });
+        if let Some(message) = rendered.or_else(|| json.ok()) {
+            log.insert(message_key, message);
+        }
+    }
+}
+
+impl ErrorFlagConfig {
+    /// Whether the event's severity or attributes indicate an error.
+    fn is_error(&self, log: &LogEvent) -> bool {
+        let severe = matches!(
+            log.get(SEVERITY_NUMBER_KEY),
+            Some(Value::Integer(number)) if *number >= self.min_severity_number
+        );
+        severe
+            || match log.get(ATTRIBUTES_KEY) {
+                Some(Value::Object(attributes)) => self.attributes.iter().any(|name| {
+                    !matches!(
+                        attributes.get(name.as_str()),
+                        None | Some(Value::Null | Value::Boolean(false))
+                    )
+                }),
+                _ => false,
+            }
+    }
+}
+
+/// Overrides of the names of the fields log records are converted into.
+///
+/// Fields without an override keep their default name. The fields are renamed once log records are fully converted,
+/// so the other options of the source refer to the default names. With the `Vector` log namespace, the fields aren't
+/// renamed, as the body becomes the event and the other fields are placed in the source metadata under their default
+/// names.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct FieldMappingConfig {
+    /// The name of the field holding the body of log records.
+    ///
+    /// By default, the [global `log_schema.message_key` option][global_message_key] is used.
+    ///
+    /// [global_message_key]: https://vector.dev/docs/reference/configuration/global-options/#log_schema.message_key
+    message: Option<String>,
+
+    /// The name of the field holding the timestamp of log records.
+    ///
+    /// By default, the [global `log_schema.timestamp_key` option][global_timestamp_key] is used.
+    ///
+    /// [global_timestamp_key]: https://vector.dev/docs/reference/configuration/global-options/#log_schema.timestamp_key
+    timestamp: Option<String>,
+
+    /// The name of the field holding the observed timestamp of log records. By default, `observed_timestamp` is used.
+    observed_timestamp: Option<String>,
+
+    /// The name of the field holding the severity number of log records. By default, `severity_number` is used.
+    severity_number: Option<String>,
+
+    /// The name of the field holding the severity text of log records. By default, `severity_text` is used.
+    severity_text: Option<String>,
+
+    /// The name of the field holding the hex encoded trace ID of log records. By default, `trace_id` is used.
+    trace_id: Option<String>,
+
+    /// The name of the field holding the hex encoded span ID of log records. By default, `span_id` is used.
+    span_id: Option<String>,
+
+    /// The name of the field holding the flags of log records. By default, `flags` is used.
+    flags: Option<String>,
+
+    /// The name of the field holding the number of dropped attributes of log records. By default,
+    /// `dropped_attributes_count` is used.
+    dropped_attributes_count: Option<String>,
+
+    /// The name of the field holding the attributes of log records. By default, `attributes` is used.
+    attributes: Option<String>,
+
+    /// The name of the field holding the attributes of the resource of log records. By default, `resources` is used.
+    resources: Option<String>,
+}
+
+impl FieldMappingConfig {
+    /// Gets the renames of the fields with an overridden name, from their default name.
+    fn renames(&self) -> Vec<(String, String)> {
+        [
+            (log_schema().message_key(), &self.message),
+            (log_schema().timestamp_key(), &self.timestamp),
+            (OBSERVED_TIMESTAMP_KEY, &self.observed_timestamp),
+            (SEVERITY_NUMBER_KEY, &self.severity_number),
+            (SEVERITY_TEXT_KEY, &self.severity_text),
+            (TRACE_ID_KEY, &self.trace_id),
+            (SPAN_ID_KEY, &self.span_id),
+            (FLAGS_KEY, &self.flags),
+            (DROPPED_ATTRIBUTES_COUNT_KEY, &self.dropped_attributes_count),
+            (ATTRIBUTES_KEY, &self.attributes),
+            (RESOURCES_KEY, &self.resources),
+        ]
+        .into_iter()
+        .filter_map(|(default, name)| {
+            name.as_ref()
+                .filter(|name| name.as_str() != default)
+                .map(|name| (default.to_owned(), name.clone()))
+        })
+        .collect()
+    }
+}
+
+/// Routing of log records into dedicated outputs by the `service.namespace` attribute of their resource.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+struct NamespaceOutputsConfig {
+    /// The namespaces whose log records are sent to a dedicated output, named `logs_<namespace>`.
+    ///
+    /// The log records of resources in other namespaces, or without a `service.namespace` attribute, are sent to the
+    /// `logs` output.
+    namespaces: Vec<String>,
+
+    /// The maximum number of namespaces, as a safety cap on the number of outputs of the source.
+    #[serde(default = "default_max_namespace_outputs")]
+    max_outputs: usize,
+}
+
+const fn default_max_namespace_outputs() -> usize {
+    32
+}
+
+/// The name of the output the log records of a namespace are sent to.
+fn namespace_output(namespace: &str) -> String {
+    format!("{}_{}", LOGS, namespace)
+}
+
+const fn default_idempotency_ttl_secs() -> u64 {
+    300
+}
+
+fn default_bool_true_strings() -> Vec<String> {
+    vec!["true".to_owned()]
+}
+
+fn default_bool_false_strings() -> Vec<String> {
+    vec!["false".to_owned()]
+}
+
+const fn default_metric_tag_max_values() -> usize {
+    100
+}
+
+/// How the resource of log records is emitted.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EmitResource {
+    /// The resource attributes are inserted into every log record as the `resources` field.
+    #[derivative(Default)]
+    Inline,
+
+    /// Each distinct resource of an export request is emitted once, as an event on the `resources` output.
+    ///
+    /// The event contains the resource attributes as the `resources` field, and an ID derived from them as the
+    /// `resource_id` field. Log records contain only the `resource_id` field of their resource instead of its
+    /// attributes, which saves memory when an export request contains many records of the same resource.
+    Reference,
+}
+
+/// Options for converting the log records of export requests into events.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ConvertOptions {
+    pub(crate) error_output: bool,
+    pub(crate) body_pattern: Option<Regex>,
+    pub(crate) emit_resource: EmitResource,
+    pub(crate) repeat_window: Option<chrono::Duration>,
+    pub(crate) rate_limiter: Option<TenantRateLimiter>,
+    pub(crate) partition_fields: bool,
+    /// The time zone of the partition fields, or `None` for UTC.
+    pub(crate) partition_timezone: Option<TimeZone>,
+    pub(crate) records_tag: Option<RecordsTag>,
+    pub(crate) include_proto_version: bool,
+    pub(crate) idempotency: Option<IdempotencyCache>,
+    pub(crate) bool_strings: Option<BoolStrings>,
+    /// The patterns resource attributes must match, by attribute name.
+    pub(crate) resource_filter: Vec<(String, Regex)>,
+    pub(crate) normalize_severity_text: bool,
+    pub(crate) normalize_severity: bool,
+    pub(crate) resource_id_field: Option<String>,
+    /// The outputs of the namespaces routed into dedicated outputs, by namespace.
+    pub(crate) namespace_outputs: HashMap<String, String>,
+    /// The renames of log event fields, from their default name.
+    pub(crate) field_renames: Vec<(String, String)>,
+    /// The fields whose objects are merged into the root of log events.
+    pub(crate) merged_fields: Vec<String>,
+    pub(crate) error_flag: Option<ErrorFlagConfig>,
+    pub(crate) structured_body: Option<StructuredBodyConfig>,
+    pub(crate) json_body: Option<JsonBodyConfig>,
+    /// The field of the client certificate metadata, set separately for each server.
+    pub(crate) tls_client_metadata_key: Option<String>,
+    pub(crate) log_namespace: LogNamespace,
+    pub(crate) enrichment: Option<EnrichmentLookup>,
+}
+
+/// The attribute string values coerced into booleans.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BoolStrings {
+    pub(crate) true_strings: Vec<String>,
+    pub(crate) false_strings: Vec<String>,
+}
+
+impl BoolStrings {
+    /// Converts the matching strings of an attribute map, including those nested in maps and
+    /// arrays, into booleans.
+    fn coerce(&self, value: &mut Value) {
+        match value {
+            Value::Bytes(bytes) => {
+                let matches = |strings: &[String]| {
+                    strings
+                        .iter()
+                        .any(|string| string.as_bytes() == bytes.as_ref())
+                };
+                if matches(&self.true_strings) {
+                    *value = Value::Boolean(true);
+                } else if matches(&self.false_strings) {
+                    *value = Value::Boolean(false);
+                }
+            }
+            Value::Object(map) => map.values_mut().for_each(|value| self.coerce(value)),
+            Value::Array(array) => array.iter_mut().for_each(|value| self.coerce(value)),
+            _ => {}
+        }
+    }
+}
+
+/// The events converted from an export request.
+#[derive(Debug, Default)]
+pub(crate) struct ConvertedRequest {
+    pub(crate) logs: Vec<Event>,
+    /// The outputs of the log events, as runs of consecutive events.
+    pub(crate) log_outputs: Vec<(String, usize)>,
+    /// The resource events, when resources are emitted by reference.
+    pub(crate) resources: Vec<Event>,
+    /// The error events, when `error_output` is enabled.
+    pub(crate) errors: Vec<Event>,
+    /// The number of log records that failed conversion.
+    pub(crate) rejected: usize,
 }
 
 /// Configuration for the `opentelemetry` gRPC server.
+///
+/// By default, the server listens on `127.0.0.1:4317`, the OTLP/gRPC port recommended by the OTLP specification.
 #[configurable_component]
 #[derive(Clone, Debug)]
 #[serde(deny_unknown_fields)]
@@ -59,10 +758,80 @@ struct GrpcConfig {
 
     #[configurable(derived)]
     #[serde(default)]
-    tls: Option<TlsEnableableConfig>,
+    tls: Option<TlsSourceConfig>,
+
+    /// Requires the clients of the gRPC server to authenticate with a TLS client certificate.
+    ///
+    /// TLS must be enabled with `tls`. Connections of clients without a certificate issued by `client_auth.ca_file`, or
+    /// with a certificate revoked by `client_auth.crl_file`, are rejected during the TLS handshake.
+    #[configurable(derived)]
+    #[serde(default)]
+    client_auth: Option<TlsClientAuthConfig>,
+
+    /// Whether or not to wait for the in-flight requests of open connections to complete when the source shuts down.
+    ///
+    /// This is the case when Vector stops, and when the source is restarted on a configuration reload. Otherwise, open
+    /// connections are closed right away and clients must retry their in-flight requests, which makes the source shut
+    /// down faster while its pipeline is backed up.
+    #[serde(default = "crate::serde::default_true")]
+    drain_connections_on_restart: bool,
+
+    /// The maximum amount of time, in seconds, to retry binding the address while it is in use.
+    ///
+    /// This lets the source start while another instance releases the address, such as during a rolling deploy. By
+    /// default, the source fails right away.
+    #[serde(default)]
+    bind_retry_timeout_secs: Option<u64>,
+
+    /// The interval, in seconds, of the HTTP/2 pings sent to check that connections are alive.
+    ///
+    /// Connections of clients that don't acknowledge pings in time, such as clients that went away without closing
+    /// them, are closed. By default, no pings are sent.
+    http2_keepalive_interval_secs: Option<u64>,
+
+    /// The amount of time, in seconds, to wait for the acknowledgement of a ping before closing the connection.
+    ///
+    /// Only used with `http2_keepalive_interval_secs`. Defaults to 20 seconds.
+    http2_keepalive_timeout_secs: Option<u64>,
+
+    /// The maximum size, in bytes, of the HTTP/2 frames received by the gRPC server.
+    ///
+    /// It must be between 16384 (16 KiB), the default, and 16777215 (16 MiB). Larger frames let clients send large
+    /// export requests in fewer frames.
+    max_frame_size_bytes: Option<usize>,
+
+    /// The maximum number of concurrent requests of each connection, as HTTP/2 streams.
+    ///
+    /// By default, the number of concurrent requests isn't limited.
+    max_concurrent_streams: Option<u32>,
+
+    /// The maximum size, in bytes, of a message received by the gRPC server, after decompression.
+    ///
+    /// Larger messages, such as oversized export requests, are rejected with `RESOURCE_EXHAUSTED` before they are
+    /// buffered in full. By default, messages of any size are accepted.
+    max_recv_message_size: Option<usize>,
+}
+
+impl Default for GrpcConfig {
+    fn default() -> Self {
+        Self {
+            address: ([127, 0, 0, 1], GRPC_PORT).into(),
+            tls: None,
+            client_auth: None,
+            drain_connections_on_restart: true,
+            bind_retry_timeout_secs: None,
+            http2_keepalive_interval_secs: None,
+            http2_keepalive_timeout_secs: None,
+            max_frame_size_bytes: None,
+            max_concurrent_streams: None,
+            max_recv_message_size: None,
+        }
+    }
 }
 
 /// Configuration for the `opentelemetry` HTTP server.
+///
+/// By default, the server listens on `127.0.0.1:4318`, the OTLP/HTTP port recommended by the OTLP specification.
 #[configurable_component]
 #[derive(Clone, Debug)]
 #[serde(deny_unknown_fields)]
@@ -74,21 +843,166 @@ struct HttpConfig {
 
     #[configurable(derived)]
     #[serde(default)]
-    tls: Option<TlsEnableableConfig>,
+    tls: Option<TlsSourceConfig>,
+
+    /// The body of the responses to successful JSON export requests, sent with the `application/json` content type.
+    ///
+    /// By default, this is `{}`, the JSON encoding of an empty `ExportLogsServiceResponse`. It can be overridden for
+    /// compatibility with clients expecting a different body, including an empty one. Protobuf export requests are
+    /// always replied to with an encoded export response, and log export requests with records that could not be
+    /// converted with a partial success, in the encoding of the request.
+    success_response_body: Option<String>,
+
+    #[configurable(derived)]
+    auth: Option<HttpAuthConfig>,
+
+    /// The maximum size of the body of requests, in bytes.
+    ///
+    /// Requests with a larger body, either before or after decompression, are rejected with `413 Payload Too Large`.
+    /// By default, the size of requests is unlimited.
+    max_request_size_bytes: Option<usize>,
+
+    /// The maximum number of connections open at once.
+    ///
+    /// Requests of connections opened while the limit is reached are rejected with `503 Service Unavailable`, and the
+    /// connections closed. By default, the number of connections is unlimited.
+    max_connections: Option<usize>,
+
+    /// The compression algorithms request bodies may be compressed with, as named by their `Content-Encoding` header.
+    ///
+    /// Requests compressed with any other algorithm are rejected with `415 Unsupported Media Type`. Uncompressed
+    /// requests are always accepted. By default, only GZIP, which the OTLP specification requires, is accepted.
+    #[serde(default = "default_http_compression")]
+    compression: Vec<HttpCompression>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            address: ([127, 0, 0, 1], HTTP_PORT).into(),
+            tls: None,
+            success_response_body: None,
+            auth: None,
+            max_request_size_bytes: None,
+            max_connections: None,
+            compression: default_http_compression(),
+        }
+    }
+}
+
+fn default_http_compression() -> Vec<HttpCompression> {
+    vec![HttpCompression::Gzip]
+}
+
+/// A compression algorithm of the bodies of requests to the HTTP server.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum HttpCompression {
+    /// GZIP.
+    Gzip,
+    /// DEFLATE, in the zlib format.
+    Deflate,
+    /// Snappy, in the raw format.
+    Snappy,
+    /// ZSTD.
+    Zstd,
+}
+
+impl HttpCompression {
+    /// The name of the algorithm in the `Content-Encoding` header.
+    const fn content_encoding(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Deflate => "deflate",
+            Self::Snappy => "snappy",
+            Self::Zstd => "zstd",
+        }
+    }
+}
+
+/// Authentication of the requests to the HTTP server.
+///
+/// Requests without the configured credentials are rejected with `401 Unauthorized`. As the credentials are sent in
+/// plain text, this should almost always be used along with TLS.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "strategy")]
+pub(crate) enum HttpAuthConfig {
+    /// Bearer authentication.
+    ///
+    /// Requests must carry the token in their `Authorization` header, as `Bearer <token>`.
+    Bearer {
+        /// The token requests must carry.
+        token: SensitiveString,
+    },
+
+    /// API key authentication.
+    ///
+    /// Requests must carry the key as the value of a dedicated header.
+    ApiKey {
+        /// The name of the header carrying the key.
+        header: String,
+
+        /// The key requests must carry.
+        key: SensitiveString,
+    },
+}
+
+/// Listens on the loopback interface, on the OTLP/gRPC and OTLP/HTTP ports recommended by the OTLP specification and
+/// registered with IANA: 4317 for gRPC and 4318 for HTTP.
+impl Default for OpentelemetryConfig {
+    fn default() -> Self {
+        Self {
+            grpc: GrpcConfig::default(),
+            http: HttpConfig::default(),
+            acknowledgements: Default::default(),
+            error_output: false,
+            body_pattern: None,
+            emit_resource: Default::default(),
+            repeat_window_ms: None,
+            tenant_rate_limits: None,
+            enrichment_table: None,
+            partition_fields: false,
+            partition_timezone: None,
+            metric_tag_from_attribute: None,
+            metric_tag_max_values: default_metric_tag_max_values(),
+            include_proto_version: false,
+            idempotency_header: None,
+            idempotency_ttl_secs: default_idempotency_ttl_secs(),
+            coerce_bool_strings: false,
+            bool_true_strings: default_bool_true_strings(),
+            bool_false_strings: default_bool_false_strings(),
+            resource_filter: None,
+            normalize_severity_text: false,
+            normalize_severity: false,
+            resource_id_field: None,
+            enable_metrics: false,
+            emit_exemplars: false,
+            namespace_outputs: None,
+            field_mapping: Default::default(),
+            error_flag: None,
+            structured_body: None,
+            parse_json_body: None,
+            attributes_key: default_attributes_key(),
+            resources_key: default_resources_key(),
+            log_namespace: None,
+        }
+    }
 }
 
 impl GenerateConfig for OpentelemetryConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
             grpc: GrpcConfig {
-                address: "0.0.0.0:4317".parse().unwrap(),
-                tls: Default::default(),
+                address: ([0, 0, 0, 0], GRPC_PORT).into(),
+                ..Default::default()
             },
             http: HttpConfig {
-                address: "0.0.0.0:4318".parse().unwrap(),
-                tls: Default::default(),
+                address: ([0, 0, 0, 0], HTTP_PORT).into(),
+                ..Default::default()
             },
-            acknowledgements: Default::default(),
+            ..Default::default()
         })
         .unwrap()
     }
@@ -103,34 +1017,148 @@ inventory::submit! {
 impl SourceConfig for OpentelemetryConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<Source> {
         let acknowledgements = cx.do_acknowledgements(&self.acknowledgements);
+        let options = ConvertOptions {
+            log_namespace: cx.log_namespace(self.log_namespace),
+            ..self.convert_options()?
+        };
 
-        let grpc_tls_settings = MaybeTlsSettings::from_config(&self.grpc.tls, true)?;
+        let grpc_tls_settings = MaybeTlsSettings::try_from(&self.grpc)?;
+        let grpc_server_settings = GrpcServerSettings::try_from(&self.grpc)?;
         let grpc_service = LogsServiceServer::new(Service {
             pipeline: cx.out.clone(),
             acknowledgements,
+            options: ConvertOptions {
+                tls_client_metadata_key: tls_client_metadata_key(&self.grpc.tls),
+                ..options.clone()
+            },
         })
         .accept_gzip();
-        let grpc_source = run_grpc_server(
+        let grpc_trace_service = TraceServiceServer::new(TraceExportService {
+            pipeline: cx.out.clone(),
+            acknowledgements,
+        })
+        .accept_gzip();
+        let grpc_metrics_service = self.enable_metrics.then(|| {
+            MetricsServiceServer::new(MetricsExportService {
+                pipeline: cx.out.clone(),
+                acknowledgements,
+                emit_exemplars: self.emit_exemplars,
+            })
+            .accept_gzip()
+        });
+        let grpc_source = run_grpc_server_with_routes(
             self.grpc.address,
             grpc_tls_settings,
-            grpc_service,
+            |server| {
+                server
+                    .add_service(grpc_service)
+                    .add_service(grpc_trace_service)
+                    .add_optional_service(grpc_metrics_service)
+            },
             cx.shutdown.clone(),
+            self.grpc.drain_connections_on_restart,
+            self.grpc.bind_retry_timeout_secs.map(Duration::from_secs),
+            grpc_server_settings,
         )
         .map_err(|error| {
             error!(message = "Source future failed.", %error);
         });
 
-        let http_tls_settings = MaybeTlsSettings::from_config(&self.http.tls, true)?;
+        if let Some(HttpAuthConfig::ApiKey { header, .. }) = &self.http.auth {
+            ::http::HeaderName::from_bytes(header.as_bytes())
+                .context(InvalidAuthHeaderSnafu { header })?;
+        }
+
+        let http_tls_settings = MaybeTlsSettings::from_config(
+            &self.http.tls.as_ref().map(|tls| tls.tls_config.clone()),
+            true,
+        )?;
         let protocol = http_tls_settings.http_protocol_name();
-        let filters = build_warp_filter(acknowledgements, cx.out, protocol);
-        let http_source =
-            run_http_server(self.http.address, http_tls_settings, filters, cx.shutdown);
+        let filters = build_warp_filter(
+            acknowledgements,
+            ConvertOptions {
+                tls_client_metadata_key: tls_client_metadata_key(&self.http.tls),
+                ..options
+            },
+            cx.out.clone(),
+            protocol,
+            self.http.success_response_body.clone(),
+            self.http.max_request_size_bytes,
+            self.http.compression.clone(),
+        )
+        .or(build_traces_warp_filter(
+            acknowledgements,
+            cx.out.clone(),
+            protocol,
+            self.http.max_request_size_bytes,
+            self.http.compression.clone(),
+        ))
+        .unify()
+        .boxed();
+        let filters = if self.enable_metrics {
+            filters
+                .or(build_metrics_warp_filter(
+                    acknowledgements,
+                    self.emit_exemplars,
+                    cx.out,
+                    protocol,
+                    self.http.max_request_size_bytes,
+                    self.http.compression.clone(),
+                ))
+                .unify()
+                .boxed()
+        } else {
+            filters
+        };
+        let filters = match self.http.auth.clone() {
+            Some(auth) => build_auth_filter(auth).and(filters).boxed(),
+            None => filters,
+        };
+        let http_source = run_http_server(
+            self.http.address,
+            http_tls_settings,
+            filters,
+            self.http.max_connections,
+            cx.shutdown,
+        );
 
         Ok(join(grpc_source, http_source).map(|_| Ok(())).boxed())
     }
 
-    fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
-        vec![Output::default(DataType::Log).with_port(LOGS)]
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<Output> {
+        let logs_schema_definition =
+            logs_schema_definition(global_log_namespace.merge(self.log_namespace));
+
+        let mut outputs = vec![
+            Output::default(DataType::Log)
+                .with_port(LOGS)
+                .with_schema_definition(logs_schema_definition.clone()),
+            Output::default(DataType::Trace).with_port(TRACES),
+        ];
+        if self.enable_metrics {
+            outputs.push(Output::default(DataType::Metric).with_port(METRICS));
+            if self.emit_exemplars {
+                outputs.push(Output::default(DataType::Log).with_port(EXEMPLARS));
+            }
+        }
+        if self.error_output {
+            outputs.push(Output::default(DataType::Log).with_port(ERRORS));
+        }
+        if self.emit_resource == EmitResource::Reference {
+            outputs.push(Output::default(DataType::Log).with_port(RESOURCES));
+        }
+        for namespace in self
+            .namespace_outputs
+            .iter()
+            .flat_map(|config| &config.namespaces)
+        {
+            outputs.push(
+                Output::default(DataType::Log)
+                    .with_port(namespace_output(namespace))
+                    .with_schema_definition(logs_schema_definition.clone()),
+            );
+        }
+        outputs
     }
 
     fn source_type(&self) -> &'static str {
@@ -147,4 +1175,811 @@ impl SourceConfig for OpentelemetryConfig {
     fn can_acknowledge(&self) -> bool {
         true
     }
+
+    fn generate_schema_hint(&self) -> Option<schema::Options> {
+        Some(schema::Options {
+            log_namespace: Some(self.log_namespace.unwrap_or(false)),
+            ..Default::default()
+        })
+    }
+}
+
+/// The schema definition of the log events of the `logs` and namespace outputs.
+fn logs_schema_definition(log_namespace: LogNamespace) -> Definition {
+    match log_namespace {
+        LogNamespace::Legacy => Definition::default_legacy_namespace(),
+        // Options such as `partition_fields` add source metadata of their own, so the metadata
+        // isn't restricted to the fields of log records.
+        LogNamespace::Vector => Definition::new(
+            Kind::any(),
+            Kind::object(Collection::any()),
+            [LogNamespace::Vector],
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            RESOURCES_KEY,
+            RESOURCES_KEY,
+            Kind::object(Collection::any()).or_undefined(),
+            None,
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            ATTRIBUTES_KEY,
+            ATTRIBUTES_KEY,
+            Kind::object(Collection::any()).or_undefined(),
+            None,
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            SCOPE_KEY,
+            SCOPE_KEY,
+            Kind::object(Collection::any()).or_undefined(),
+            None,
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            TRACE_ID_KEY,
+            TRACE_ID_KEY,
+            Kind::bytes().or_undefined(),
+            Some("trace_id"),
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            SPAN_ID_KEY,
+            SPAN_ID_KEY,
+            Kind::bytes().or_undefined(),
+            Some("span_id"),
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            SEVERITY_TEXT_KEY,
+            SEVERITY_TEXT_KEY,
+            Kind::bytes().or_undefined(),
+            Some("severity"),
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            SEVERITY_NUMBER_KEY,
+            SEVERITY_NUMBER_KEY,
+            Kind::integer().or_undefined(),
+            None,
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            FLAGS_KEY,
+            FLAGS_KEY,
+            Kind::integer().or_undefined(),
+            None,
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            OBSERVED_TIMESTAMP_KEY,
+            OBSERVED_TIMESTAMP_KEY,
+            Kind::timestamp(),
+            None,
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            log_schema().timestamp_key(),
+            "timestamp",
+            Kind::timestamp(),
+            Some("timestamp"),
+        )
+        .with_source_metadata(
+            SOURCE_NAME,
+            DROPPED_ATTRIBUTES_COUNT_KEY,
+            DROPPED_ATTRIBUTES_COUNT_KEY,
+            Kind::integer(),
+            None,
+        )
+        .with_standard_vector_source_metadata(),
+    }
+}
+
+impl OpentelemetryConfig {
+    fn convert_options(&self) -> crate::Result<ConvertOptions> {
+        let body_pattern = match &self.body_pattern {
+            Some(pattern) => {
+                let pattern = Regex::new(pattern).context(InvalidBodyPatternSnafu)?;
+                if !pattern.capture_names().any(|name| name.is_some()) {
+                    return Err(BuildError::BodyPatternWithoutCaptures.into());
+                }
+                Some(pattern)
+            }
+            None => None,
+        };
+
+        let bool_strings = if self.coerce_bool_strings {
+            if let Some(value) = self
+                .bool_true_strings
+                .iter()
+                .find(|value| self.bool_false_strings.contains(value))
+            {
+                return Err(BuildError::AmbiguousBoolString {
+                    value: value.clone(),
+                }
+                .into());
+            }
+            Some(BoolStrings {
+                true_strings: self.bool_true_strings.clone(),
+                false_strings: self.bool_false_strings.clone(),
+            })
+        } else {
+            None
+        };
+
+        let resource_filter = self
+            .resource_filter
+            .iter()
+            .flatten()
+            .map(|(attribute, pattern)| {
+                Regex::new(pattern)
+                    .map(|pattern| (attribute.clone(), pattern))
+                    .context(InvalidResourceFilterSnafu { attribute })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let namespace_outputs = match &self.namespace_outputs {
+            Some(config) if config.namespaces.len() > config.max_outputs => {
+                return Err(BuildError::TooManyNamespaceOutputs {
+                    count: config.namespaces.len(),
+                    max_outputs: config.max_outputs,
+                }
+                .into());
+            }
+            Some(config) => config
+                .namespaces
+                .iter()
+                .map(|namespace| (namespace.clone(), namespace_output(namespace)))
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        let mut field_renames = self.field_mapping.renames();
+        let mut merged_fields = Vec::new();
+        for (key, default, mapping, option) in [
+            (
+                &self.attributes_key,
+                ATTRIBUTES_KEY,
+                &self.field_mapping.attributes,
+                "attributes_key",
+            ),
+            (
+                &self.resources_key,
+                RESOURCES_KEY,
+                &self.field_mapping.resources,
+                "resources_key",
+            ),
+        ] {
+            if key == default {
+                continue;
+            }
+            if mapping.is_some() {
+                return Err(BuildError::ConflictingFieldMapping {
+                    option,
+                    field: default,
+                }
+                .into());
+            }
+            if key.is_empty() {
+                merged_fields.push(default.to_owned());
+            } else {
+                field_renames.push((default.to_owned(), key.clone()));
+            }
+        }
+
+        Ok(ConvertOptions {
+            error_output: self.error_output,
+            body_pattern,
+            emit_resource: self.emit_resource,
+            repeat_window: self
+                .repeat_window_ms
+                .map(|ms| chrono::Duration::milliseconds(ms as i64)),
+            rate_limiter: self.tenant_rate_limits.as_ref().map(Into::into),
+            partition_fields: self.partition_fields,
+            partition_timezone: self.partition_timezone,
+            records_tag: self
+                .metric_tag_from_attribute
+                .clone()
+                .map(|attribute| RecordsTag::new(attribute, self.metric_tag_max_values)),
+            include_proto_version: self.include_proto_version,
+            idempotency: self.idempotency_header.clone().map(|header| {
+                IdempotencyCache::new(header, Duration::from_secs(self.idempotency_ttl_secs))
+            }),
+            bool_strings,
+            resource_filter,
+            normalize_severity_text: self.normalize_severity_text,
+            normalize_severity: self.normalize_severity,
+            resource_id_field: self.resource_id_field.clone(),
+            namespace_outputs,
+            field_renames,
+            merged_fields,
+            error_flag: self.error_flag.clone(),
+            structured_body: self.structured_body.clone(),
+            json_body: self.parse_json_body.clone(),
+            tls_client_metadata_key: None,
+            log_namespace: LogNamespace::Legacy,
+            enrichment: self
+                .enrichment_table
+                .as_ref()
+                .map(EnrichmentLookupConfig::build)
+                .transpose()?,
+        })
+    }
+}
+
+/// Converts the log records of an export request into events.
+///
+/// Records that fail conversion are reported, and returned as error events when `error_output` is
+/// enabled.
+pub(crate) fn convert_request(
+    request: ExportLogsServiceRequest,
+    options: &ConvertOptions,
+) -> ConvertedRequest {
+    let mut converted = ConvertedRequest::default();
+    let mut resource_ids = HashSet::new();
+    let mut record_index = 0;
+
+    for mut resource_logs in request.resource_logs {
+        // The attribute is read before the resource may be taken out of the log records.
+        let records_tag = options.records_tag.as_ref().map(|tag| {
+            let value = tag.value(resource_logs.resource_attribute(tag.attribute()));
+            (tag.attribute(), value)
+        });
+        let enrichment = options.enrichment.as_ref().and_then(|enrichment| {
+            enrichment.lookup(resource_logs.resource_attribute(enrichment.attribute()))
+        });
+        let first_record_index = record_index;
+
+        let filtered = options.resource_filter.iter().any(|(attribute, pattern)| {
+            resource_logs
+                .resource_attribute(attribute)
+                .map_or(true, |value| !pattern.is_match(&value))
+        });
+        if filtered {
+            let count = resource_logs
+                .scope_logs
+                .iter()
+                .map(|scope_logs| scope_logs.log_records.len())
+                .sum();
+            record_index += count;
+            emit!(OpentelemetryResourceFiltered { count });
+            emit!(OpentelemetryRecordsReceived {
+                count,
+                tag: records_tag
+                    .as_ref()
+                    .map(|(attribute, value)| (*attribute, value.as_str())),
+            });
+            continue;
+        }
+        let proto_version = options
+            .include_proto_version
+            .then(|| schema_url_version(&resource_logs.schema_url))
+            .flatten();
+
+        // The output is also chosen before the resource may be taken out of the log records.
+        let output = resource_logs
+            .resource_attribute(NAMESPACE_ATTRIBUTE)
+            .and_then(|namespace| options.namespace_outputs.get(&namespace))
+            .map_or(LOGS, String::as_str)
+            .to_owned();
+        let first_log_index = converted.logs.len();
+
+        // The digest is computed before the resource may be taken out of the log records.
+        let resource_digest = options
+            .resource_id_field
+            .as_ref()
+            .and_then(|field| Some((field, resource_logs.resource_digest()?)));
+
+        let resource_id = match options.emit_resource {
+            EmitResource::Inline => None,
+            EmitResource::Reference => resource_logs.take_resource().map(|(id, mut resource)| {
+                if let Some(bool_strings) = &options.bool_strings {
+                    bool_strings.coerce(&mut resource);
+                }
+                if resource_ids.insert(id.clone()) {
+                    converted.resources.push(resource_event(&id, resource));
+                }
+                id
+            }),
+        };
+
+        let mut repeat_run = None;
+        for result in resource_logs {
+            match result {
+                Ok(mut event) => {
+                    let repeated = options.repeat_window.map_or(false, |window| {
+                        collapse_repeat(
+                            &mut repeat_run,
+                            &mut converted.logs,
+                            event.as_log(),
+                            window,
+                        )
+                    });
+                    if repeated {
+                        record_index += 1;
+                        continue;
+                    }
+
+                    let log = event.as_mut_log();
+                    if let Some(bool_strings) = &options.bool_strings {
+                        for key in [ATTRIBUTES_KEY, RESOURCES_KEY] {
+                            if let Some(attributes) = log.get_mut(key) {
+                                bool_strings.coerce(attributes);
+                            }
+                        }
+                    }
+                    if options.normalize_severity_text {
+                        normalize_severity_text(log);
+                    }
+                    if options.normalize_severity {
+                        insert_severity_level(log);
+                    }
+                    if let Some(error_flag) = &options.error_flag {
+                        if error_flag.is_error(log) {
+                            log.insert(IS_ERROR_KEY, true);
+                        }
+                    }
+                    if options.partition_fields {
+                        insert_partition_fields(log, options.partition_timezone);
+                    }
+                    if let Some(pattern) = &options.body_pattern {
+                        parse_body(pattern, log);
+                    }
+                    if let Some(json_body) = &options.json_body {
+                        json_body.parse(log);
+                    }
+                    if let Some(structured_body) = &options.structured_body {
+                        structured_body.render(log);
+                    }
+                    if let Some(id) = &resource_id {
+                        log.insert(RESOURCE_ID_KEY, id.as_str());
+                    }
+                    if let Some((field, digest)) = &resource_digest {
+                        log.insert(field.as_str(), digest.as_str());
+                    }
+                    if let Some(version) = &proto_version {
+                        log.insert(PROTO_VERSION_KEY, version.as_str());
+                    }
+                    // In the Vector namespace, the body becomes the event and the other fields
+                    // become source metadata with the fixed names of the schema definition.
+                    if options.log_namespace == LogNamespace::Legacy {
+                        rename_fields(log, &options.field_renames);
+                        merge_fields_into_root(log, &options.merged_fields);
+                    }
+                    if let Some(fields) = enrichment {
+                        for (key, value) in fields {
+                            log.try_insert(key.as_str(), value.as_str());
+                        }
+                    }
+                    // The size of the event serialized as JSON stands in for its size in memory.
+                    if let Ok(bytes) = serde_json::to_vec(log) {
+                        emit!(OpentelemetryEventSize {
+                            byte_size: bytes.len()
+                        });
+                    }
+                    converted.logs.push(event);
+                }
+                Err(error) => {
+                    emit!(OpentelemetryRecordConversionError {
+                        error: &error.error,
+                        record_index,
+                    });
+                    converted.rejected += 1;
+                    if options.error_output {
+                        converted.errors.push(error_event(record_index, error));
+                    }
+                }
+            }
+            record_index += 1;
+        }
+
+        // Events are only moved into the Vector namespace once repeats are collapsed into them.
+        if options.log_namespace == LogNamespace::Vector {
+            for event in &mut converted.logs[first_log_index..] {
+                into_vector_namespace(event.as_mut_log());
+            }
+        }
+
+        let count = converted.logs.len() - first_log_index;
+        if count > 0 {
+            let last = converted.log_outputs.last_mut();
+            match last.filter(|(last_output, _)| *last_output == output) {
+                Some((_, last_count)) => *last_count += count,
+                None => converted.log_outputs.push((output, count)),
+            }
+        }
+
+        emit!(OpentelemetryRecordsReceived {
+            count: record_index - first_record_index,
+            tag: records_tag
+                .as_ref()
+                .map(|(attribute, value)| (*attribute, value.as_str())),
+        });
+    }
+
+    converted
+}
+
+/// Splits events into batches by output, following the runs of consecutive events of `outputs`.
+///
+/// The batches of an output are merged, in the order the output first appears in.
+pub(crate) fn split_outputs(
+    events: Vec<Event>,
+    outputs: Vec<(String, usize)>,
+) -> Vec<(String, Vec<Event>)> {
+    let mut batches: Vec<(String, Vec<Event>)> = Vec::new();
+    let mut events = events.into_iter();
+    for (output, count) in outputs {
+        let run = events.by_ref().take(count);
+        match batches.iter_mut().find(|(name, _)| *name == output) {
+            Some((_, batch)) => batch.extend(run),
+            None => batches.push((output, run.collect())),
+        }
+    }
+    batches
+}
+
+/// Converts the spans of an export request into trace events.
+///
+/// Spans that cannot be converted are dropped after the failure is reported.
+pub(crate) fn convert_trace_request(request: ExportTraceServiceRequest) -> Vec<Event> {
+    request
+        .resource_spans
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(span_index, result)| {
+            result
+                .map_err(|error| {
+                    emit!(OpentelemetrySpanConversionError {
+                        error: &error,
+                        span_index
+                    })
+                })
+                .ok()
+        })
+        .collect()
+}
+
+/// Converts the metrics of an export request into metric events, one per data point.
+///
+/// Data points that fail conversion are reported and dropped.
+pub(crate) fn convert_metrics_request(request: ExportMetricsServiceRequest) -> Vec<Event> {
+    request
+        .resource_metrics
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(data_point_index, result)| {
+            result
+                .map_err(|error| {
+                    emit!(OpentelemetryMetricConversionError {
+                        error: &error,
+                        data_point_index
+                    })
+                })
+                .ok()
+        })
+        .collect()
+}
+
+/// Converts the exemplars of the metrics of an export request into log events.
+pub(crate) fn convert_metrics_exemplars(request: &ExportMetricsServiceRequest) -> Vec<Event> {
+    request
+        .resource_metrics
+        .iter()
+        .flat_map(ResourceMetrics::exemplars)
+        .map(Event::from)
+        .collect()
+}
+
+/// Inserts the version of the `User-Agent` header of the request as the `proto_version` field of
+/// the events whose resource doesn't have a versioned schema URL.
+pub(crate) fn insert_user_agent_version(
+    logs: &mut [Event],
+    user_agent: Option<&str>,
+    log_namespace: LogNamespace,
+) {
+    if let Some(version) = user_agent.and_then(user_agent_version) {
+        for event in logs {
+            let log = event.as_mut_log();
+            // The version from the schema URL of the resource takes precedence.
+            let exists = match log_namespace {
+                LogNamespace::Legacy => log.contains(PROTO_VERSION_KEY),
+                LogNamespace::Vector => log
+                    .metadata()
+                    .value()
+                    .get(path!(SOURCE_NAME, PROTO_VERSION_KEY))
+                    .is_some(),
+            };
+            if !exists {
+                log_namespace.insert_source_metadata(
+                    SOURCE_NAME,
+                    log,
+                    path!(PROTO_VERSION_KEY),
+                    version.as_str(),
+                );
+            }
+        }
+    }
+}
+
+/// The field of the client certificate metadata of a server, if configured.
+fn tls_client_metadata_key(tls: &Option<TlsSourceConfig>) -> Option<String> {
+    tls.as_ref().and_then(|tls| tls.client_metadata_key.clone())
+}
+
+/// Inserts the subject of the certificate of the client as the `subject` field of an object under
+/// `key`, if the client presented one.
+pub(crate) fn insert_tls_client_metadata(
+    logs: &mut [Event],
+    key: Option<&str>,
+    peer: Option<&TlsPeer>,
+    log_namespace: LogNamespace,
+) {
+    let metadata = peer.and_then(TlsPeer::certificate_metadata);
+    if let (Some(key), Some(metadata)) = (key, metadata) {
+        let value = Value::from(BTreeMap::from([(
+            String::from("subject"),
+            Value::from(metadata.subject()),
+        )]));
+        for event in logs {
+            log_namespace.insert_source_metadata(
+                SOURCE_NAME,
+                event.as_mut_log(),
+                path!(key),
+                value.clone(),
+            );
+        }
+    }
+}
+
+/// Extracts the version from the last segment of a schema URL, such as
+/// `https://opentelemetry.io/schemas/1.9.0`.
+fn schema_url_version(schema_url: &str) -> Option<String> {
+    schema_url.rsplit('/').next().and_then(parse_version)
+}
+
+/// Extracts the version of the first product of a `User-Agent` header, such as
+/// `OTel-OTLP-Exporter-Go/0.20.0 grpc-go/1.48.0`.
+fn user_agent_version(user_agent: &str) -> Option<String> {
+    user_agent
+        .split_whitespace()
+        .next()
+        .and_then(|product| product.split_once('/'))
+        .and_then(|(_, version)| parse_version(version))
+}
+
+/// Normalizes a version, such as `0.20.0` or `v0.20.0`, into the latter form.
+fn parse_version(version: &str) -> Option<String> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let valid = version.starts_with(|c: char| c.is_ascii_digit())
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'));
+    valid.then(|| format!("v{}", version))
+}
+
+/// A run of consecutive events with identical bodies.
+struct RepeatRun {
+    /// The index of the first event of the run, which stands for the whole run.
+    index: usize,
+    body: Value,
+    timestamp: DateTime<Utc>,
+}
+
+/// Collapses the event into the current run if it has the same body and its timestamp is within
+/// the window, or otherwise starts a new run with the event, which must be pushed to `logs` next.
+///
+/// Returns whether the event was collapsed into the run.
+fn collapse_repeat(
+    run: &mut Option<RepeatRun>,
+    logs: &mut [Event],
+    log: &LogEvent,
+    window: chrono::Duration,
+) -> bool {
+    let body = log.get(log_schema().message_key());
+    let timestamp = match log.get(log_schema().timestamp_key()) {
+        Some(Value::Timestamp(timestamp)) => *timestamp,
+        _ => {
+            *run = None;
+            return false;
+        }
+    };
+
+    if let (Some(current), Some(body)) = (run.as_ref(), body) {
+        let elapsed = timestamp - current.timestamp;
+        if current.body == *body && elapsed <= window && elapsed >= -window {
+            let first = logs[current.index].as_mut_log();
+            let count = match first.get(REPEAT_COUNT_KEY) {
+                Some(Value::Integer(count)) => *count,
+                _ => 1,
+            };
+            first.insert(REPEAT_COUNT_KEY, count + 1);
+            return true;
+        }
+    }
+
+    *run = body.map(|body| RepeatRun {
+        index: logs.len(),
+        body: body.clone(),
+        timestamp,
+    });
+    false
+}
+
+/// Moves a converted log event into the Vector namespace: the body becomes the event, and the other
+/// fields become source metadata.
+fn into_vector_namespace(log: &mut LogEvent) {
+    let body = log
+        .remove(log_schema().message_key())
+        .unwrap_or(Value::Null);
+    if let Value::Object(fields) = std::mem::replace(log.value_mut(), body) {
+        for (key, value) in fields {
+            LogNamespace::Vector.insert_source_metadata(
+                SOURCE_NAME,
+                log,
+                path!(key.as_str()),
+                value,
+            );
+        }
+    }
+    LogNamespace::Vector.insert_vector_metadata(
+        log,
+        path!(log_schema().source_type_key()),
+        path!("source_type"),
+        SOURCE_NAME,
+    );
+    LogNamespace::Vector.insert_vector_metadata(
+        log,
+        path!(log_schema().timestamp_key()),
+        path!("ingest_timestamp"),
+        Utc::now(),
+    );
+}
+
+/// Renames the fields of the event, taking all of them out first so that fields can swap names.
+fn rename_fields(log: &mut LogEvent, renames: &[(String, String)]) {
+    let values = renames
+        .iter()
+        .filter_map(|(from, to)| Some((to, log.remove(from.as_str())?)))
+        .collect::<Vec<_>>();
+    for (to, value) in values {
+        log.insert(to.as_str(), value);
+    }
+}
+
+/// Merges the objects of the fields into the root of the event, keeping the fields it already has.
+fn merge_fields_into_root(log: &mut LogEvent, fields: &[String]) {
+    for field in fields {
+        match log.remove(field.as_str()) {
+            Some(Value::Object(object)) => {
+                if let Some(root) = log.as_map_mut() {
+                    for (key, value) in object {
+                        root.entry(key).or_insert(value);
+                    }
+                }
+            }
+            Some(value) => {
+                log.insert(field.as_str(), value);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Replaces the severity text of the event with its canonical label, or with the label of its
+/// severity number if the text isn't one.
+fn normalize_severity_text(log: &mut LogEvent) {
+    let text = match log.get(SEVERITY_TEXT_KEY) {
+        Some(Value::Bytes(text)) => Some(String::from_utf8_lossy(text).trim().to_ascii_uppercase()),
+        _ => None,
+    };
+    let label = text.filter(|text| is_severity_label(text)).or_else(|| {
+        match log.get(SEVERITY_NUMBER_KEY) {
+            Some(Value::Integer(number)) => severity_label(*number),
+            _ => None,
+        }
+    });
+    if let Some(label) = label {
+        log.insert(SEVERITY_TEXT_KEY, label);
+    }
+}
+
+/// Whether the text is a canonical severity label, such as `INFO` or `INFO2`.
+fn is_severity_label(text: &str) -> bool {
+    SEVERITY_LEVELS.iter().any(|level| {
+        text.strip_prefix(level)
+            .map_or(false, |suffix| matches!(suffix, "" | "2" | "3" | "4"))
+    })
+}
+
+/// Gets the canonical label of a severity number, such as `INFO` for 9 and `INFO2` for 10.
+fn severity_label(number: i64) -> Option<String> {
+    let level = severity_level(number)?;
+    match (number - 1) % 4 {
+        0 => Some(level.to_owned()),
+        offset => Some(format!("{}{}", level, offset + 1)),
+    }
+}
+
+/// Gets the canonical level of a severity number, such as `INFO` for 9 to 12.
+fn severity_level(number: i64) -> Option<&'static str> {
+    (1..=24)
+        .contains(&number)
+        .then(|| SEVERITY_LEVELS[(number - 1) as usize / 4])
+}
+
+/// Inserts the canonical level of the event's severity number, leaving the severity fields as they are.
+fn insert_severity_level(log: &mut LogEvent) {
+    let level = match log.get(SEVERITY_NUMBER_KEY) {
+        Some(Value::Integer(number)) => severity_level(*number),
+        _ => None,
+    };
+    if let Some(level) = level {
+        log.insert(LEVEL_KEY, level);
+    }
+}
+
+/// Inserts the `year`, `month`, `day` and `hour` of the event's timestamp in `timezone`, or in UTC.
+fn insert_partition_fields(log: &mut LogEvent, timezone: Option<TimeZone>) {
+    let timestamp = match log.get(log_schema().timestamp_key()) {
+        Some(Value::Timestamp(timestamp)) => *timestamp,
+        _ => return,
+    };
+    let fields = match timezone {
+        None => partition_fields(&timestamp),
+        Some(TimeZone::Local) => partition_fields(&timestamp.with_timezone(&Local)),
+        Some(TimeZone::Named(tz)) => partition_fields(&timestamp.with_timezone(&tz)),
+    };
+    for (key, value) in PARTITION_KEYS.into_iter().zip(fields) {
+        log.insert(key, value);
+    }
+}
+
+fn partition_fields<Tz: chrono::TimeZone>(timestamp: &DateTime<Tz>) -> [String; 4] {
+    [
+        format!("{:04}", timestamp.year()),
+        format!("{:02}", timestamp.month()),
+        format!("{:02}", timestamp.day()),
+        format!("{:02}", timestamp.hour()),
+    ]
+}
+
+/// Inserts the named captures of `pattern` matching a string body as fields of the event.
+fn parse_body(pattern: &Regex, log: &mut LogEvent) {
+    let body = match log.get(log_schema().message_key()) {
+        Some(Value::Bytes(body)) => body.clone(),
+        _ => return,
+    };
+    let body = String::from_utf8_lossy(&body);
+
+    match pattern.captures(&body) {
+        Some(captures) => {
+            for name in pattern.capture_names().flatten() {
+                if let Some(capture) = captures.name(name) {
+                    log.insert(name, capture.as_str());
+                }
+            }
+        }
+        None => emit!(OpentelemetryBodyPatternMismatch),
+    }
+}
+
+fn resource_event(id: &str, resource: Value) -> Event {
+    let mut log = LogEvent::default();
+    log.insert(RESOURCE_ID_KEY, id);
+    log.insert(RESOURCES_KEY, resource);
+    log.insert(log_schema().timestamp_key(), Utc::now());
+    log.into()
+}
+
+fn error_event(record_index: usize, error: RecordError) -> Event {
+    let mut log = LogEvent::default();
+    log.insert(log_schema().message_key(), error.error.to_string());
+    log.insert(log_schema().timestamp_key(), Utc::now());
+    log.insert("record_index", record_index);
+    log.insert("raw_record", base64::encode(error.record.encode_to_vec()));
+    log.into()
 }
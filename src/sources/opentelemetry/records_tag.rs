@@ -0,0 +1,71 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+/// The tag value of resources without the attribute.
+const MISSING_VALUE: &str = "_none";
+
+/// The tag value of resources whose attribute value exceeds the maximum number of distinct values.
+const OTHER_VALUE: &str = "_other";
+
+/// Tags the `component_received_records_total` metric with the value of a resource attribute.
+///
+/// Only the first `max_values` distinct values are used as tags, so that a high-cardinality
+/// attribute can't create an unbounded number of metric series.
+#[derive(Clone, Debug)]
+pub(crate) struct RecordsTag {
+    attribute: String,
+    max_values: usize,
+    values: Arc<Mutex<HashSet<String>>>,
+}
+
+impl RecordsTag {
+    pub(crate) fn new(attribute: String, max_values: usize) -> Self {
+        Self {
+            attribute,
+            max_values,
+            values: Default::default(),
+        }
+    }
+
+    /// The name of the resource attribute, which is also the name of the tag.
+    pub(crate) fn attribute(&self) -> &str {
+        &self.attribute
+    }
+
+    /// Gets the tag value of the records of a resource with the given attribute value.
+    pub(crate) fn value(&self, value: Option<String>) -> String {
+        let value = match value {
+            Some(value) => value,
+            None => return MISSING_VALUE.to_owned(),
+        };
+
+        let mut values = self.values.lock().expect("poisoned lock");
+        if values.contains(&value) {
+            value
+        } else if values.len() < self.max_values {
+            values.insert(value.clone());
+            value
+        } else {
+            OTHER_VALUE.to_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caps_distinct_values() {
+        let tag = RecordsTag::new("service.name".to_owned(), 2);
+
+        assert_eq!(tag.value(Some("checkout".to_owned())), "checkout");
+        assert_eq!(tag.value(Some("cart".to_owned())), "cart");
+        assert_eq!(tag.value(Some("search".to_owned())), OTHER_VALUE);
+        // Values seen before the cap was reached keep their tag.
+        assert_eq!(tag.value(Some("checkout".to_owned())), "checkout");
+        assert_eq!(tag.value(None), MISSING_VALUE);
+    }
+}
@@ -0,0 +1,186 @@
+//! Conversions of the `opentelemetry` source configuration into the settings of its gRPC server.
+//!
+//! TLS is terminated by the listener handed to `tonic` rather than by `tonic` itself, so that the
+//! gRPC server supports the same TLS options as every other source.
+
+use std::time::Duration;
+
+use super::{BuildError, GrpcConfig};
+use crate::{
+    sources::util::grpc::GrpcServerSettings,
+    tls::{MaybeTlsSettings, TlsError},
+};
+
+/// The range of HTTP/2 frame sizes, as `h2` panics on sizes out of it.
+const FRAME_SIZES: std::ops::RangeInclusive<usize> = 16_384..=16_777_215;
+
+impl TryFrom<&GrpcConfig> for GrpcServerSettings {
+    type Error = BuildError;
+
+    fn try_from(config: &GrpcConfig) -> Result<Self, Self::Error> {
+        let max_frame_size = match config.max_frame_size_bytes {
+            Some(size) if FRAME_SIZES.contains(&size) => Some(size as u32),
+            Some(size) => return Err(BuildError::InvalidMaxFrameSize { size }),
+            None => None,
+        };
+        Ok(Self {
+            keepalive_interval: config
+                .http2_keepalive_interval_secs
+                .map(Duration::from_secs),
+            keepalive_timeout: config.http2_keepalive_timeout_secs.map(Duration::from_secs),
+            max_frame_size,
+            max_concurrent_streams: config.max_concurrent_streams,
+            max_message_size: config.max_recv_message_size,
+        })
+    }
+}
+
+impl TryFrom<&GrpcConfig> for MaybeTlsSettings {
+    type Error = TlsError;
+
+    fn try_from(config: &GrpcConfig) -> Result<Self, Self::Error> {
+        let settings = MaybeTlsSettings::from_config(
+            &config.tls.as_ref().map(|tls| tls.tls_config.clone()),
+            true,
+        )?;
+        match (&config.client_auth, settings) {
+            (None, settings) => Ok(settings),
+            (Some(client_auth), MaybeTlsSettings::Tls(tls)) => {
+                tls.with_client_auth(client_auth).map(MaybeTlsSettings::Tls)
+            }
+            (Some(_), MaybeTlsSettings::Raw(())) => Err(TlsError::ClientAuthWithoutTls),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tls::{
+        TlsClientAuthConfig, TlsConfig, TlsEnableableConfig, TlsSourceConfig, TEST_PEM_CA_PATH,
+    };
+
+    fn grpc_config(tls: Option<TlsEnableableConfig>) -> GrpcConfig {
+        GrpcConfig {
+            address: "0.0.0.0:4317".parse().unwrap(),
+            tls: tls.map(|tls_config| TlsSourceConfig {
+                client_metadata_key: None,
+                tls_config,
+            }),
+            client_auth: None,
+            drain_connections_on_restart: true,
+            bind_retry_timeout_secs: None,
+            http2_keepalive_interval_secs: None,
+            http2_keepalive_timeout_secs: None,
+            max_frame_size_bytes: None,
+            max_concurrent_streams: None,
+            max_recv_message_size: None,
+        }
+    }
+
+    #[test]
+    fn converts_server_settings() {
+        let settings = GrpcServerSettings::try_from(&grpc_config(None)).unwrap();
+        assert_eq!(settings.keepalive_interval, None);
+        assert_eq!(settings.max_frame_size, None);
+        assert_eq!(settings.max_message_size, None);
+
+        let config: GrpcConfig = toml::from_str(
+            r#"
+            address = "0.0.0.0:4317"
+            http2_keepalive_interval_secs = 30
+            http2_keepalive_timeout_secs = 10
+            max_frame_size_bytes = 1048576
+            max_concurrent_streams = 100
+            max_recv_message_size = 4194304
+            "#,
+        )
+        .unwrap();
+        let settings = GrpcServerSettings::try_from(&config).unwrap();
+        assert_eq!(settings.keepalive_interval, Some(Duration::from_secs(30)));
+        assert_eq!(settings.keepalive_timeout, Some(Duration::from_secs(10)));
+        assert_eq!(settings.max_frame_size, Some(1048576));
+        assert_eq!(settings.max_concurrent_streams, Some(100));
+        assert_eq!(settings.max_message_size, Some(4194304));
+    }
+
+    #[test]
+    fn rejects_invalid_max_frame_size() {
+        for size in [1024, 16_777_216] {
+            let config = GrpcConfig {
+                max_frame_size_bytes: Some(size),
+                ..grpc_config(None)
+            };
+            let error = GrpcServerSettings::try_from(&config).unwrap_err();
+            assert!(matches!(error, BuildError::InvalidMaxFrameSize { .. }));
+        }
+    }
+
+    #[test]
+    fn converts_without_tls() {
+        let settings = MaybeTlsSettings::try_from(&grpc_config(None)).unwrap();
+        assert!(settings.is_raw());
+
+        let disabled = TlsEnableableConfig {
+            enabled: Some(false),
+            options: TlsConfig::test_config(),
+        };
+        let settings = MaybeTlsSettings::try_from(&grpc_config(Some(disabled))).unwrap();
+        assert!(settings.is_raw());
+    }
+
+    #[test]
+    fn converts_with_tls() {
+        let config = grpc_config(Some(TlsEnableableConfig::test_config()));
+        let settings = MaybeTlsSettings::try_from(&config).unwrap();
+        assert!(settings.is_tls());
+        assert_eq!(settings.http_protocol_name(), "https");
+    }
+
+    #[test]
+    fn requires_identity_with_tls() {
+        let tls = TlsEnableableConfig {
+            enabled: Some(true),
+            options: TlsConfig::default(),
+        };
+        let error = MaybeTlsSettings::try_from(&grpc_config(Some(tls))).unwrap_err();
+        assert!(matches!(error, TlsError::MissingRequiredIdentity));
+    }
+
+    fn client_auth(crl_file: Option<&str>) -> Option<TlsClientAuthConfig> {
+        Some(TlsClientAuthConfig {
+            ca_file: TEST_PEM_CA_PATH.into(),
+            crl_file: crl_file.map(Into::into),
+        })
+    }
+
+    #[test]
+    fn converts_with_client_auth() {
+        let config = GrpcConfig {
+            client_auth: client_auth(None),
+            ..grpc_config(Some(TlsEnableableConfig::test_config()))
+        };
+        let settings = MaybeTlsSettings::try_from(&config).unwrap();
+        assert!(settings.is_tls());
+    }
+
+    #[test]
+    fn requires_tls_with_client_auth() {
+        let config = GrpcConfig {
+            client_auth: client_auth(None),
+            ..grpc_config(None)
+        };
+        let error = MaybeTlsSettings::try_from(&config).unwrap_err();
+        assert!(matches!(error, TlsError::ClientAuthWithoutTls));
+    }
+
+    #[test]
+    fn requires_existing_crl_file() {
+        let config = GrpcConfig {
+            client_auth: client_auth(Some("tests/data/ca/missing.crl.pem")),
+            ..grpc_config(Some(TlsEnableableConfig::test_config()))
+        };
+        let error = MaybeTlsSettings::try_from(&config).unwrap_err();
+        assert!(matches!(error, TlsError::FileOpenFailed { .. }));
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    collections::HashMap,
+    num::NonZeroU32,
+    sync::{Arc, Mutex},
+};
+
+use tokio::time::Instant;
+use vector_config::configurable_component;
+
+use crate::internal_events::OpentelemetryTenantThrottled;
+
+/// The number of tenants tracked before the buckets of idle tenants not listed in `limits` are
+/// discarded.
+const MAX_TRACKED_TENANTS: usize = 1024;
+
+/// Per-tenant rate limits of the log records accepted by the source.
+///
+/// Each tenant has an independent token bucket, refilled at its rate and holding up to one second's worth of log
+/// records. An export request is accepted while its tenant's bucket holds enough tokens for its records, or is full;
+/// otherwise it's rejected with `429 Too Many Requests` (HTTP) or `RESOURCE_EXHAUSTED` (gRPC), without affecting other
+/// tenants.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TenantRateLimitsConfig {
+    /// The name of the header identifying the tenant of an export request.
+    ///
+    /// For gRPC requests, this is the name of the metadata entry.
+    header: String,
+
+    /// The maximum number of log records per second accepted from each tenant, keyed by the value of `header`.
+    #[serde(default)]
+    limits: HashMap<String, NonZeroU32>,
+
+    /// The maximum number of log records per second accepted from each tenant not listed in `limits`.
+    ///
+    /// Requests without the header count as a single tenant. By default, these tenants are not limited.
+    default_limit: Option<NonZeroU32>,
+}
+
+/// A token bucket holding up to one second's worth of log records.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    /// The number of available tokens, which is negative while the bucket pays back an oversized
+    /// request.
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: NonZeroU32, now: Instant) -> Self {
+        let rate = f64::from(rate.get());
+        Self {
+            rate,
+            tokens: rate,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.updated = now;
+    }
+
+    fn is_full(&self) -> bool {
+        self.tokens >= self.rate
+    }
+
+    /// Takes `count` tokens if they're available. Requests larger than the bucket are accepted
+    /// while it's full, and paid back before any other request is accepted.
+    fn try_take(&mut self, count: usize, now: Instant) -> bool {
+        self.refill(now);
+        let count = count as f64;
+        if self.tokens >= count.min(self.rate) {
+            self.tokens -= count;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Enforces the rate limits of the tenants of export requests.
+#[derive(Clone, Debug)]
+pub(crate) struct TenantRateLimiter {
+    header: String,
+    limits: Arc<HashMap<String, NonZeroU32>>,
+    default_limit: Option<NonZeroU32>,
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+}
+
+impl From<&TenantRateLimitsConfig> for TenantRateLimiter {
+    fn from(config: &TenantRateLimitsConfig) -> Self {
+        Self {
+            header: config.header.clone(),
+            limits: Arc::new(config.limits.clone()),
+            default_limit: config.default_limit,
+            buckets: Default::default(),
+        }
+    }
+}
+
+impl TenantRateLimiter {
+    /// The name of the header identifying the tenant.
+    pub(crate) fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Checks whether `count` log records of `tenant` are within its rate limit, taking them from
+    /// its bucket if they are.
+    pub(crate) fn check(&self, tenant: Option<&str>, count: usize) -> bool {
+        let tenant = tenant.unwrap_or_default();
+        let rate = match self.limits.get(tenant).copied().or(self.default_limit) {
+            Some(rate) if count > 0 => rate,
+            _ => return true,
+        };
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().expect("poisoned lock");
+        if !buckets.contains_key(tenant) && buckets.len() >= MAX_TRACKED_TENANTS {
+            // Discarding a full bucket is the same as recreating it on the next request.
+            buckets.retain(|_, bucket| {
+                bucket.refill(now);
+                !bucket.is_full()
+            });
+        }
+
+        let accepted = buckets
+            .entry(tenant.to_owned())
+            .or_insert_with(|| TokenBucket::new(rate, now))
+            .try_take(count, now);
+        if !accepted {
+            emit!(OpentelemetryTenantThrottled { tenant, count });
+        }
+        accepted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn limiter(limits: &[(&str, u32)], default_limit: Option<u32>) -> TenantRateLimiter {
+        TenantRateLimiter::from(&TenantRateLimitsConfig {
+            header: "x-tenant".to_owned(),
+            limits: limits
+                .iter()
+                .map(|(tenant, limit)| (tenant.to_string(), NonZeroU32::new(*limit).unwrap()))
+                .collect(),
+            default_limit: default_limit.and_then(NonZeroU32::new),
+        })
+    }
+
+    #[test]
+    fn token_bucket_allows_one_second_burst() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(NonZeroU32::new(10).unwrap(), now);
+
+        assert!(bucket.try_take(6, now));
+        assert!(!bucket.try_take(6, now));
+        assert!(bucket.try_take(4, now));
+
+        let later = now + Duration::from_millis(500);
+        assert!(bucket.try_take(5, later));
+        assert!(!bucket.try_take(1, later));
+    }
+
+    #[test]
+    fn token_bucket_pays_back_oversized_requests() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(NonZeroU32::new(10).unwrap(), now);
+
+        assert!(bucket.try_take(30, now));
+        assert!(!bucket.try_take(1, now + Duration::from_secs(2)));
+        assert!(bucket.try_take(1, now + Duration::from_secs(3)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tenants_are_limited_independently() {
+        let limiter = limiter(&[("noisy", 10), ("quiet", 10)], None);
+
+        assert!(limiter.check(Some("noisy"), 10));
+        assert!(!limiter.check(Some("noisy"), 1));
+        assert!(limiter.check(Some("quiet"), 10));
+
+        // Tenants without a limit aren't limited.
+        assert!(limiter.check(Some("other"), 1000));
+        assert!(limiter.check(None, 1000));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn default_limit_applies_to_each_unlisted_tenant() {
+        let limiter = limiter(&[("big", 100)], Some(10));
+
+        assert!(limiter.check(Some("big"), 100));
+        assert!(limiter.check(Some("a"), 10));
+        assert!(!limiter.check(Some("a"), 10));
+        assert!(limiter.check(Some("b"), 10));
+        assert!(limiter.check(None, 10));
+        assert!(!limiter.check(None, 10));
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(limiter.check(Some("a"), 10));
+    }
+}
@@ -1,13 +1,42 @@
 use crate::{
-    config::{SourceConfig, SourceContext},
-    event::{into_event_stream, Event, EventStatus, LogEvent, Value},
+    config::{ComponentKey, DataType, Output, SourceConfig, SourceContext},
+    event::{
+        into_event_stream,
+        metric::{Bucket, MetricKind, Quantile},
+        Event, EventStatus, LogEvent, Metric, MetricValue, TraceEvent, Value,
+    },
     opentelemetry::{
-        Common::{any_value, AnyValue, KeyValue},
-        LogService::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+        Common::{any_value, AnyValue, InstrumentationScope, KeyValue, KeyValueList},
+        LogService::{
+            logs_service_client::LogsServiceClient, ExportLogsServiceRequest,
+            ExportLogsServiceResponse,
+        },
         Logs::{LogRecord, ResourceLogs, ScopeLogs},
+        Metrics::{
+            exemplar, exponential_histogram_data_point::Buckets, metric::Data, number_data_point,
+            summary_data_point::ValueAtQuantile, AggregationTemporality, Exemplar,
+            ExponentialHistogram, ExponentialHistogramDataPoint, Gauge as OtelGauge,
+            Histogram as OtelHistogram, HistogramDataPoint, Metric as OtelMetric, NumberDataPoint,
+            ResourceMetrics, ScopeMetrics, Sum as OtelSum, Summary as OtelSummary,
+            SummaryDataPoint,
+        },
+        MetricsService::{
+            metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest,
+        },
         Resource as OtelResource,
+        Trace::{
+            span::{Event as SpanEvent, Link as SpanLink},
+            ResourceSpans, ScopeSpans, Span, Status as SpanStatus,
+        },
+        TraceService::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+    },
+    sources::opentelemetry::{
+        convert_metrics_exemplars, convert_metrics_request, convert_request, convert_trace_request,
+        default_http_compression, grpc::Service, insert_user_agent_version,
+        records_tag::RecordsTag, BoolStrings, ConvertOptions, ConvertedRequest, EmitResource,
+        ErrorFlagConfig, GrpcConfig, HttpCompression, HttpConfig, OpentelemetryConfig, ERRORS,
+        EXEMPLARS, LOGS, METRICS, TRACES,
     },
-    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS},
     test_util::{
         self,
         components::{assert_source_compliance, SOURCE_TAGS},
@@ -16,14 +45,66 @@ use crate::{
     SourceSender,
 };
 use chrono::{TimeZone, Utc};
+use flate2::{
+    write::{GzEncoder, ZlibEncoder},
+    Compression,
+};
 use futures::Stream;
 use futures_util::StreamExt;
-use std::collections::BTreeMap;
-use tonic::Request;
+use metrics::{Counter, Gauge, Histogram, Key, KeyName, SharedString, Unit};
+use prost::Message;
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    net::SocketAddr,
+    num::NonZeroU32,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::time::{sleep, timeout, Duration, Instant};
+use tonic::{
+    transport::{Certificate, Channel, ClientTlsConfig, Identity},
+    Request,
+};
+use vector_core::config::LogNamespace;
+
+use crate::opentelemetry::LogService::logs_service_server::LogsService;
 
 #[test]
 fn generate_config() {
     crate::test_util::test_generate_config::<OpentelemetryConfig>();
+
+    let config: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        grpc.max_frame_size_bytes = 1048576
+        grpc.http2_keepalive_interval_secs = 30
+        grpc.http2_keepalive_timeout_secs = 10
+        http.address = "127.0.0.1:4318"
+    "#})
+    .unwrap();
+    let config: OpentelemetryConfig = toml::Value::try_from(&config).unwrap().try_into().unwrap();
+    assert_eq!(config.grpc.max_frame_size_bytes, Some(1048576));
+    assert_eq!(config.grpc.http2_keepalive_interval_secs, Some(30));
+    assert_eq!(config.grpc.http2_keepalive_timeout_secs, Some(10));
+}
+
+#[test]
+fn default_config_uses_otlp_ports() {
+    let config: toml::Value = toml::from_str(indoc::indoc! {r#"
+        [sources.otlp]
+    "#})
+    .unwrap();
+    let config: OpentelemetryConfig = config["sources"]["otlp"].clone().try_into().unwrap();
+    assert_eq!(config.grpc.address, "127.0.0.1:4317".parse().unwrap());
+    assert_eq!(config.http.address, "127.0.0.1:4318".parse().unwrap());
+    assert!(config.grpc.drain_connections_on_restart);
+    assert_eq!(config.http.compression, vec![HttpCompression::Gzip]);
+
+    let default = OpentelemetryConfig::default();
+    assert_eq!(default.grpc.address, config.grpc.address);
+    assert_eq!(default.http.address, config.http.address);
 }
 
 #[tokio::test]
@@ -36,12 +117,55 @@ async fn receive_grpc_logs() {
             grpc: GrpcConfig {
                 address: grpc_addr,
                 tls: Default::default(),
+                client_auth: None,
+                drain_connections_on_restart: true,
+                bind_retry_timeout_secs: None,
+                http2_keepalive_interval_secs: None,
+                http2_keepalive_timeout_secs: None,
+                max_frame_size_bytes: None,
+                max_concurrent_streams: None,
+                max_recv_message_size: None,
             },
             http: HttpConfig {
                 address: http_addr,
                 tls: Default::default(),
+                success_response_body: None,
+                auth: None,
+                max_request_size_bytes: None,
+                max_connections: None,
+                compression: default_http_compression(),
             },
             acknowledgements: Default::default(),
+            error_output: false,
+            body_pattern: None,
+            emit_resource: Default::default(),
+            repeat_window_ms: None,
+            tenant_rate_limits: None,
+            enrichment_table: None,
+            partition_fields: false,
+            partition_timezone: None,
+            metric_tag_from_attribute: None,
+            metric_tag_max_values: 100,
+            include_proto_version: false,
+            idempotency_header: None,
+            idempotency_ttl_secs: 300,
+            coerce_bool_strings: false,
+            bool_true_strings: vec!["true".to_owned()],
+            bool_false_strings: vec!["false".to_owned()],
+            resource_filter: None,
+            normalize_severity_text: false,
+            normalize_severity: false,
+            resource_id_field: None,
+            enable_metrics: false,
+            emit_exemplars: false,
+            namespace_outputs: None,
+            field_mapping: Default::default(),
+            error_flag: None,
+            structured_body: None,
+            parse_json_body: None,
+            attributes_key: "attributes".into(),
+            resources_key: "resources".into(),
+            log_namespace: None,
         };
         let (sender, logs_output, _) = new_source(EventStatus::Delivered);
         let server = source
@@ -107,6 +231,10 @@ async fn receive_grpc_logs() {
                 "resources",
                 Value::Object(vec_into_btmap(vec![("res_key", "res_val".into())])),
             ),
+            (
+                "scope",
+                Value::Object(vec_into_btmap(vec![("schema_url", "v1".into())])),
+            ),
             ("message", "log body".into()),
             ("trace_id", "4ac52aadf321c2e531db005df08792f5".into()),
             ("span_id", "0b9e4bda2a55530d".into()),
@@ -123,29 +251,3662 @@ async fn receive_grpc_logs() {
     .await;
 }
 
-fn new_source(
-    status: EventStatus,
-) -> (
-    SourceSender,
-    impl Stream<Item = Event>,
-    impl Stream<Item = Event>,
-) {
-    let (mut sender, recv) = SourceSender::new_test_finalize(status);
-    let logs_output = sender
-        .add_outputs(status, LOGS.to_string())
+fn trace_request() -> ExportTraceServiceRequest {
+    ExportTraceServiceRequest {
+        resource_spans: vec![ResourceSpans {
+            resource: Some(OtelResource {
+                attributes: vec![KeyValue {
+                    key: "res_key".into(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("res_val".into())),
+                    }),
+                }],
+                dropped_attributes_count: 0,
+            }),
+            scope_spans: vec![ScopeSpans {
+                scope: None,
+                spans: vec![Span {
+                    trace_id: str_into_hex_bytes("4ac52aadf321c2e531db005df08792f5"),
+                    span_id: str_into_hex_bytes("0b9e4bda2a55530d"),
+                    trace_state: String::new(),
+                    parent_span_id: str_into_hex_bytes("5f467fe7bf42676c"),
+                    name: "checkout".into(),
+                    kind: 2,
+                    start_time_unix_nano: 1,
+                    end_time_unix_nano: 2,
+                    attributes: vec![KeyValue {
+                        key: "attr_key".into(),
+                        value: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue("attr_val".into())),
+                        }),
+                    }],
+                    dropped_attributes_count: 3,
+                    events: vec![],
+                    dropped_events_count: 4,
+                    links: vec![],
+                    dropped_links_count: 5,
+                    status: Some(SpanStatus {
+                        message: "payment declined".into(),
+                        code: 2,
+                    }),
+                }],
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }],
+    }
+}
+
+fn expected_trace_event() -> Event {
+    Event::from(TraceEvent::from(vec_into_btmap(vec![
+        (
+            "attributes",
+            Value::Object(vec_into_btmap(vec![("attr_key", "attr_val".into())])),
+        ),
+        (
+            "resources",
+            Value::Object(vec_into_btmap(vec![("res_key", "res_val".into())])),
+        ),
+        (
+            "scope",
+            Value::Object(vec_into_btmap(vec![("schema_url", "v1".into())])),
+        ),
+        ("trace_id", "4ac52aadf321c2e531db005df08792f5".into()),
+        ("span_id", "0b9e4bda2a55530d".into()),
+        ("parent_span_id", "5f467fe7bf42676c".into()),
+        ("name", "checkout".into()),
+        ("kind", 2.into()),
+        ("start_time_unix_nano", Utc.timestamp_nanos(1).into()),
+        ("end_time_unix_nano", Utc.timestamp_nanos(2).into()),
+        (
+            "status",
+            Value::Object(vec_into_btmap(vec![
+                ("code", 2.into()),
+                ("message", "payment declined".into()),
+            ])),
+        ),
+        ("dropped_attributes_count", 3.into()),
+        ("dropped_events_count", 4.into()),
+        ("dropped_links_count", 5.into()),
+    ])))
+}
+
+/// Builds and starts a source, returning its gRPC and HTTP addresses along with its `traces` output.
+async fn start_trace_source() -> (SocketAddr, SocketAddr, impl Stream<Item = Event>) {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+        "#},
+        grpc_addr, http_addr
+    ))
+    .unwrap();
+    let (mut sender, _) = SourceSender::new_test_finalize(EventStatus::Delivered);
+    let traces_output = sender
+        .add_outputs(EventStatus::Delivered, TRACES.to_string())
         .flat_map(into_event_stream);
-    (sender, logs_output, recv)
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+    test_util::wait_for_tcp(http_addr).await;
+    (grpc_addr, http_addr, traces_output)
 }
 
-fn str_into_hex_bytes(s: &str) -> Vec<u8> {
-    // unwrap is okay in test
-    hex::decode(s).unwrap()
+#[tokio::test]
+async fn receive_grpc_traces() {
+    let (grpc_addr, _, traces_output) = start_trace_source().await;
+
+    let mut client = TraceServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    client.export(Request::new(trace_request())).await.unwrap();
+
+    let output = test_util::collect_ready(traces_output).await;
+    assert_eq!(output, vec![expected_trace_event()]);
 }
 
-fn vec_into_btmap(arr: Vec<(&'static str, Value)>) -> BTreeMap<String, Value> {
-    BTreeMap::from_iter(
-        arr.into_iter()
-            .map(|(k, v)| (k.to_string(), v))
-            .collect::<Vec<(_, _)>>(),
+#[tokio::test]
+async fn receive_http_traces() {
+    let (_, http_addr, traces_output) = start_trace_source().await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/v1/traces", http_addr))
+        .header("content-type", "application/x-protobuf")
+        .body(trace_request().encode_to_vec())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+
+    let output = test_util::collect_ready(traces_output).await;
+    assert_eq!(output, vec![expected_trace_event()]);
+}
+
+fn string_attribute(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    }
+}
+
+fn number_point(value: number_data_point::Value) -> NumberDataPoint {
+    NumberDataPoint {
+        attributes: vec![string_attribute("host", "a")],
+        start_time_unix_nano: 0,
+        time_unix_nano: 1_000_000_000,
+        value: Some(value),
+        exemplars: vec![],
+        flags: 0,
+    }
+}
+
+fn metrics_request() -> ExportMetricsServiceRequest {
+    let metric = |name: &str, data| OtelMetric {
+        name: name.into(),
+        description: String::new(),
+        unit: String::new(),
+        data: Some(data),
+    };
+    ExportMetricsServiceRequest {
+        resource_metrics: vec![ResourceMetrics {
+            resource: Some(OtelResource {
+                attributes: vec![
+                    string_attribute("service.name", "checkout"),
+                    string_attribute("host", "resource"),
+                ],
+                dropped_attributes_count: 0,
+            }),
+            scope_metrics: vec![ScopeMetrics {
+                scope: Some(InstrumentationScope {
+                    name: "web".into(),
+                    version: String::new(),
+                    attributes: vec![
+                        string_attribute("scope", "web"),
+                        string_attribute("host", "scope"),
+                    ],
+                    dropped_attributes_count: 0,
+                }),
+                metrics: vec![
+                    metric(
+                        "temperature",
+                        Data::Gauge(OtelGauge {
+                            data_points: vec![number_point(number_data_point::Value::AsDouble(
+                                21.5,
+                            ))],
+                        }),
+                    ),
+                    metric(
+                        "requests",
+                        Data::Sum(OtelSum {
+                            data_points: vec![number_point(number_data_point::Value::AsInt(7))],
+                            aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                            is_monotonic: true,
+                        }),
+                    ),
+                    metric(
+                        "queue_depth",
+                        Data::Sum(OtelSum {
+                            data_points: vec![number_point(number_data_point::Value::AsInt(-2))],
+                            aggregation_temporality: AggregationTemporality::Delta as i32,
+                            is_monotonic: false,
+                        }),
+                    ),
+                    metric(
+                        "latency",
+                        Data::Histogram(OtelHistogram {
+                            data_points: vec![HistogramDataPoint {
+                                attributes: vec![string_attribute("host", "a")],
+                                start_time_unix_nano: 500_000_000,
+                                time_unix_nano: 1_000_000_000,
+                                count: 6,
+                                sum: 12.5,
+                                bucket_counts: vec![1, 2, 3],
+                                explicit_bounds: vec![1.0, 5.0],
+                                exemplars: vec![],
+                                flags: 0,
+                            }],
+                            aggregation_temporality: AggregationTemporality::Delta as i32,
+                        }),
+                    ),
+                    metric(
+                        "payload_size",
+                        Data::ExponentialHistogram(ExponentialHistogram {
+                            data_points: vec![ExponentialHistogramDataPoint {
+                                attributes: vec![string_attribute("host", "a")],
+                                start_time_unix_nano: 0,
+                                time_unix_nano: 1_000_000_000,
+                                count: 7,
+                                sum: 12.0,
+                                // A base of 2.
+                                scale: 0,
+                                zero_count: 1,
+                                positive: Some(Buckets {
+                                    offset: 0,
+                                    bucket_counts: vec![2, 3],
+                                }),
+                                negative: Some(Buckets {
+                                    offset: 1,
+                                    bucket_counts: vec![1],
+                                }),
+                                flags: 0,
+                                exemplars: vec![],
+                            }],
+                            aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                        }),
+                    ),
+                    metric(
+                        "response_time",
+                        Data::Summary(OtelSummary {
+                            data_points: vec![SummaryDataPoint {
+                                attributes: vec![string_attribute("host", "a")],
+                                start_time_unix_nano: 0,
+                                time_unix_nano: 1_000_000_000,
+                                count: 4,
+                                sum: 10.0,
+                                quantile_values: vec![
+                                    ValueAtQuantile {
+                                        quantile: 0.5,
+                                        value: 2.0,
+                                    },
+                                    ValueAtQuantile {
+                                        quantile: 0.99,
+                                        value: 4.0,
+                                    },
+                                ],
+                                flags: 0,
+                            }],
+                        }),
+                    ),
+                ],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    }
+}
+
+fn expected_metrics() -> Vec<Event> {
+    let metric = |name: &str, kind, value| {
+        Metric::new(name, kind, value)
+            .with_tags(Some(
+                vec![
+                    ("service.name".to_owned(), "checkout".to_owned()),
+                    ("scope".to_owned(), "web".to_owned()),
+                    // The attributes of data points take precedence over those of the scope and
+                    // the resource.
+                    ("host".to_owned(), "a".to_owned()),
+                ]
+                .into_iter()
+                .collect(),
+            ))
+            .with_timestamp(Some(Utc.timestamp(1, 0)))
+    };
+    vec![
+        metric(
+            "temperature",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value: 21.5 },
+        ),
+        metric(
+            "requests",
+            MetricKind::Absolute,
+            MetricValue::Counter { value: 7.0 },
+        ),
+        metric(
+            "queue_depth",
+            MetricKind::Incremental,
+            MetricValue::Gauge { value: -2.0 },
+        ),
+        metric(
+            "latency",
+            MetricKind::Incremental,
+            MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: 1.0,
+                        count: 1,
+                    },
+                    Bucket {
+                        upper_limit: 5.0,
+                        count: 2,
+                    },
+                    Bucket {
+                        upper_limit: f64::INFINITY,
+                        count: 3,
+                    },
+                ],
+                count: 6,
+                sum: 12.5,
+            },
+        )
+        .with_interval_ms(NonZeroU32::new(500)),
+        metric(
+            "payload_size",
+            MetricKind::Absolute,
+            MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: -2.0,
+                        count: 1,
+                    },
+                    Bucket {
+                        upper_limit: 0.0,
+                        count: 1,
+                    },
+                    Bucket {
+                        upper_limit: 2.0,
+                        count: 2,
+                    },
+                    Bucket {
+                        upper_limit: 4.0,
+                        count: 3,
+                    },
+                ],
+                count: 7,
+                sum: 12.0,
+            },
+        ),
+        metric(
+            "response_time",
+            MetricKind::Absolute,
+            MetricValue::AggregatedSummary {
+                quantiles: vec![
+                    Quantile {
+                        quantile: 0.5,
+                        value: 2.0,
+                    },
+                    Quantile {
+                        quantile: 0.99,
+                        value: 4.0,
+                    },
+                ],
+                count: 4,
+                sum: 10.0,
+            },
+        ),
+    ]
+    .into_iter()
+    .map(Event::from)
+    .collect()
+}
+
+#[test]
+fn convert_metrics() {
+    assert_eq!(
+        convert_metrics_request(metrics_request()),
+        expected_metrics()
+    );
+}
+
+/// Builds and starts a source, returning its gRPC and HTTP addresses along with its `metrics`
+/// output.
+async fn start_metrics_source(
+    enable_metrics: bool,
+) -> (SocketAddr, SocketAddr, impl Stream<Item = Event>) {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            enable_metrics = {}
+        "#},
+        grpc_addr, http_addr, enable_metrics
+    ))
+    .unwrap();
+    let (mut sender, _) = SourceSender::new_test_finalize(EventStatus::Delivered);
+    let metrics_output = sender
+        .add_outputs(EventStatus::Delivered, METRICS.to_string())
+        .flat_map(into_event_stream);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+    test_util::wait_for_tcp(http_addr).await;
+    (grpc_addr, http_addr, metrics_output)
+}
+
+#[tokio::test]
+async fn receive_grpc_metrics() {
+    let (grpc_addr, _, metrics_output) = start_metrics_source(true).await;
+
+    let mut client = MetricsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    client
+        .export(Request::new(metrics_request()))
+        .await
+        .unwrap();
+
+    let output = test_util::collect_ready(metrics_output).await;
+    assert_eq!(output, expected_metrics());
+}
+
+#[tokio::test]
+async fn receive_http_metrics() {
+    let (_, http_addr, metrics_output) = start_metrics_source(true).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/v1/metrics", http_addr))
+        .header("content-type", "application/x-protobuf")
+        .body(metrics_request().encode_to_vec())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+
+    let output = test_util::collect_ready(metrics_output).await;
+    assert_eq!(output, expected_metrics());
+}
+
+#[tokio::test]
+async fn metrics_disabled_by_default() {
+    let (grpc_addr, http_addr, _) = start_metrics_source(false).await;
+
+    let mut client = MetricsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    let status = client
+        .export(Request::new(metrics_request()))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unimplemented);
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/v1/metrics", http_addr))
+        .header("content-type", "application/x-protobuf")
+        .body(metrics_request().encode_to_vec())
+        .send()
+        .await
+        .unwrap();
+    assert_ne!(response.status(), 200);
+
+    let config: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+    "#})
+    .unwrap();
+    assert!(!config
+        .outputs(LogNamespace::Legacy)
+        .iter()
+        .any(|output| output.port.as_deref() == Some(METRICS)));
+}
+
+fn metrics_request_with_exemplar() -> ExportMetricsServiceRequest {
+    let mut request = metrics_request();
+    for metric in &mut request.resource_metrics[0].scope_metrics[0].metrics {
+        if let Some(Data::Histogram(histogram)) = &mut metric.data {
+            histogram.data_points[0].exemplars.push(Exemplar {
+                filtered_attributes: vec![string_attribute("user", "frank")],
+                time_unix_nano: 900_000_000,
+                value: Some(exemplar::Value::AsDouble(3.5)),
+                span_id: hex::decode("0b9e4bda2a55530d").unwrap(),
+                trace_id: hex::decode("4ac52aadf321c2e531db005df08792f5").unwrap(),
+            });
+        }
+    }
+    request
+}
+
+fn expected_exemplar() -> Event {
+    let mut log = LogEvent::default();
+    log.insert("metric", "latency");
+    log.insert("value", 3.5);
+    log.insert("trace_id", "4ac52aadf321c2e531db005df08792f5");
+    log.insert("span_id", "0b9e4bda2a55530d");
+    log.insert("attributes.user", "frank");
+    log.insert(
+        "tags",
+        Value::Object(
+            [
+                ("service.name", "checkout"),
+                ("scope", "web"),
+                ("host", "a"),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), Value::from(value)))
+            .collect(),
+        ),
+    );
+    log.insert("timestamp", Utc.timestamp_nanos(900_000_000));
+    log.into()
+}
+
+#[test]
+fn convert_exemplars() {
+    let request = metrics_request_with_exemplar();
+    assert_eq!(
+        convert_metrics_exemplars(&request),
+        vec![expected_exemplar()]
+    );
+    // The metric events are unchanged by their exemplars.
+    assert_eq!(convert_metrics_request(request), expected_metrics());
+}
+
+#[tokio::test]
+async fn receive_grpc_exemplars() {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            enable_metrics = true
+            emit_exemplars = true
+        "#},
+        grpc_addr, http_addr
+    ))
+    .unwrap();
+    assert!(source
+        .outputs(LogNamespace::Legacy)
+        .iter()
+        .any(|output| output.port.as_deref() == Some(EXEMPLARS)));
+
+    let (mut sender, _) = SourceSender::new_test_finalize(EventStatus::Delivered);
+    let metrics_output = sender
+        .add_outputs(EventStatus::Delivered, METRICS.to_string())
+        .flat_map(into_event_stream);
+    let exemplars_output = sender
+        .add_outputs(EventStatus::Delivered, EXEMPLARS.to_string())
+        .flat_map(into_event_stream);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+
+    let mut client = MetricsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    client
+        .export(Request::new(metrics_request_with_exemplar()))
+        .await
+        .unwrap();
+
+    let metrics = test_util::collect_ready(metrics_output).await;
+    assert_eq!(metrics, expected_metrics());
+    let exemplars = test_util::collect_ready(exemplars_output).await;
+    assert_eq!(exemplars, vec![expected_exemplar()]);
+}
+
+fn namespace_resource_logs(namespace: Option<&str>, body: &str) -> ResourceLogs {
+    ResourceLogs {
+        resource: Some(OtelResource {
+            attributes: namespace
+                .map(|namespace| string_attribute("service.namespace", namespace))
+                .into_iter()
+                .collect(),
+            dropped_attributes_count: 0,
+        }),
+        scope_logs: vec![ScopeLogs {
+            scope: None,
+            log_records: vec![LogRecord {
+                time_unix_nano: 1,
+                body: Some(AnyValue {
+                    value: Some(any_value::Value::StringValue(body.into())),
+                }),
+                ..Default::default()
+            }],
+            schema_url: String::new(),
+        }],
+        schema_url: String::new(),
+    }
+}
+
+#[tokio::test]
+async fn grpc_routes_logs_by_namespace() {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            namespace_outputs.namespaces = ["payments", "search"]
+        "#},
+        grpc_addr, http_addr
+    ))
+    .unwrap();
+    let output_names = source
+        .outputs(LogNamespace::Legacy)
+        .into_iter()
+        .filter_map(|output| output.port)
+        .collect::<Vec<_>>();
+    assert!(output_names.contains(&"logs_payments".to_owned()));
+    assert!(output_names.contains(&"logs_search".to_owned()));
+
+    let (mut sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let payments_output = sender
+        .add_outputs(EventStatus::Delivered, "logs_payments".to_owned())
+        .flat_map(into_event_stream);
+    let search_output = sender
+        .add_outputs(EventStatus::Delivered, "logs_search".to_owned())
+        .flat_map(into_event_stream);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+
+    let mut client = LogsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    client
+        .export(Request::new(ExportLogsServiceRequest {
+            resource_logs: vec![
+                namespace_resource_logs(Some("payments"), "charge"),
+                namespace_resource_logs(Some("search"), "query"),
+                namespace_resource_logs(Some("billing"), "invoice"),
+                namespace_resource_logs(Some("payments"), "refund"),
+                namespace_resource_logs(None, "orphan"),
+            ],
+        }))
+        .await
+        .unwrap();
+
+    let bodies = |events: Vec<Event>| {
+        events
+            .into_iter()
+            .map(|event| event.as_log()["message"].to_string_lossy())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        bodies(test_util::collect_ready(payments_output).await),
+        vec!["charge", "refund"]
+    );
+    assert_eq!(
+        bodies(test_util::collect_ready(search_output).await),
+        vec!["query"]
+    );
+    // Unknown namespaces, and resources without one, fall back to the `logs` output.
+    assert_eq!(
+        bodies(test_util::collect_ready(logs_output).await),
+        vec!["invoice", "orphan"]
+    );
+}
+
+#[tokio::test]
+async fn rejects_too_many_namespace_outputs() {
+    let source: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        namespace_outputs.namespaces = ["payments", "search", "billing"]
+        namespace_outputs.max_outputs = 2
+    "#})
+    .unwrap();
+    let (sender, _) = SourceSender::new_test();
+    let error = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "`namespace_outputs` has 3 namespaces, more than its `max_outputs` of 2"
+    );
+}
+
+#[tokio::test]
+async fn grpc_bind_retries_while_address_in_use() {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+    let dummy_listener = std::net::TcpListener::bind(grpc_addr).unwrap();
+
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            grpc.bind_retry_timeout_secs = 2
+            http.address = "{}"
+        "#},
+        grpc_addr, http_addr
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+
+    sleep(Duration::from_millis(500)).await;
+    drop(dummy_listener);
+    timeout(Duration::from_secs(2), test_util::wait_for_tcp(grpc_addr))
+        .await
+        .expect("source did not bind after the address was released");
+
+    let mut client = LogsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    client
+        .export(Request::new(ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: None,
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![LogRecord {
+                        time_unix_nano: 1,
+                        ..Default::default()
+                    }],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        }))
+        .await
+        .unwrap();
+    assert_eq!(test_util::collect_ready(logs_output).await.len(), 1);
+}
+
+#[test]
+fn convert_span_events_and_links() {
+    let mut request = trace_request();
+    let span = &mut request.resource_spans[0].scope_spans[0].spans[0];
+    span.events = vec![SpanEvent {
+        time_unix_nano: 3,
+        name: "retry".into(),
+        attributes: vec![],
+        dropped_attributes_count: 0,
+    }];
+    span.links = vec![SpanLink {
+        trace_id: str_into_hex_bytes("4ac52aadf321c2e531db005df08792f5"),
+        span_id: str_into_hex_bytes("5f467fe7bf42676c"),
+        trace_state: "vendor=value".into(),
+        attributes: vec![],
+        dropped_attributes_count: 1,
+    }];
+
+    let events = convert_trace_request(request);
+    assert_eq!(events.len(), 1);
+    let trace = events[0].as_trace();
+    assert_eq!(
+        trace.get("events"),
+        Some(&Value::Array(vec![Value::Object(vec_into_btmap(vec![
+            ("name", "retry".into()),
+            ("timestamp", Utc.timestamp_nanos(3).into()),
+            ("dropped_attributes_count", 0.into()),
+        ]))]))
+    );
+    assert_eq!(
+        trace.get("links"),
+        Some(&Value::Array(vec![Value::Object(vec_into_btmap(vec![
+            ("trace_id", "4ac52aadf321c2e531db005df08792f5".into()),
+            ("span_id", "5f467fe7bf42676c".into()),
+            ("trace_state", "vendor=value".into()),
+            ("dropped_attributes_count", 1.into()),
+        ]))]))
+    );
+}
+
+#[tokio::test]
+async fn receive_grpc_logs_with_conversion_error() {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+
+    let source = OpentelemetryConfig {
+        grpc: GrpcConfig {
+            address: grpc_addr,
+            tls: Default::default(),
+            client_auth: None,
+            drain_connections_on_restart: true,
+            bind_retry_timeout_secs: None,
+            http2_keepalive_interval_secs: None,
+            http2_keepalive_timeout_secs: None,
+            max_frame_size_bytes: None,
+            max_concurrent_streams: None,
+            max_recv_message_size: None,
+        },
+        http: HttpConfig {
+            address: http_addr,
+            tls: Default::default(),
+            success_response_body: None,
+            auth: None,
+            max_request_size_bytes: None,
+            max_connections: None,
+            compression: default_http_compression(),
+        },
+        acknowledgements: Default::default(),
+        error_output: true,
+        body_pattern: None,
+        emit_resource: Default::default(),
+        repeat_window_ms: None,
+        tenant_rate_limits: None,
+        enrichment_table: None,
+        partition_fields: false,
+        partition_timezone: None,
+        metric_tag_from_attribute: None,
+        metric_tag_max_values: 100,
+        include_proto_version: false,
+        idempotency_header: None,
+        idempotency_ttl_secs: 300,
+        coerce_bool_strings: false,
+        bool_true_strings: vec!["true".to_owned()],
+        bool_false_strings: vec!["false".to_owned()],
+        resource_filter: None,
+        normalize_severity_text: false,
+        normalize_severity: false,
+        resource_id_field: None,
+        enable_metrics: false,
+        emit_exemplars: false,
+        namespace_outputs: None,
+        field_mapping: Default::default(),
+        error_flag: None,
+        structured_body: None,
+        parse_json_body: None,
+        attributes_key: "attributes".into(),
+        resources_key: "resources".into(),
+        log_namespace: None,
+    };
+    let (mut sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let errors_output = sender
+        .add_outputs(EventStatus::Delivered, ERRORS.to_string())
+        .flat_map(into_event_stream);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+
+    let invalid_record = LogRecord {
+        time_unix_nano: 1,
+        attributes: vec![KeyValue {
+            key: "nan".into(),
+            value: Some(AnyValue {
+                value: Some(any_value::Value::DoubleValue(f64::NAN)),
+            }),
+        }],
+        ..Default::default()
+    };
+    let valid_record = LogRecord {
+        time_unix_nano: 1,
+        body: Some(AnyValue {
+            value: Some(any_value::Value::StringValue("log body".into())),
+        }),
+        ..Default::default()
+    };
+
+    let mut client = LogsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    let req = Request::new(ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![valid_record, invalid_record.clone()],
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }],
+    });
+    let _ = client.export(req).await;
+
+    let logs = test_util::collect_ready(logs_output).await;
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].as_log()["message"], "log body".into());
+
+    let mut errors = test_util::collect_ready(errors_output).await;
+    assert_eq!(errors.len(), 1);
+    let error = errors.pop().unwrap().into_log();
+    assert_eq!(error["message"], "Double values must not be NaN".into());
+    assert_eq!(error["record_index"], 1.into());
+    assert_eq!(
+        error["raw_record"],
+        base64::encode(invalid_record.encode_to_vec()).into()
+    );
+}
+
+#[test]
+fn convert_scope() {
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![
+                ScopeLogs {
+                    scope: Some(InstrumentationScope {
+                        name: "io.opentelemetry.example".into(),
+                        version: "1.2.3".into(),
+                        attributes: vec![string_attribute("library.language", "java")],
+                        dropped_attributes_count: 0,
+                    }),
+                    log_records: vec![LogRecord::default()],
+                    schema_url: "https://opentelemetry.io/schemas/1.9.0".into(),
+                },
+                ScopeLogs {
+                    scope: None,
+                    log_records: vec![LogRecord::default()],
+                    schema_url: String::new(),
+                },
+            ],
+            schema_url: String::new(),
+        }],
+    };
+
+    let ConvertedRequest { logs, errors, .. } = convert_request(request, &Default::default());
+    assert!(errors.is_empty());
+    assert_eq!(logs.len(), 2);
+
+    let log = logs[0].as_log();
+    assert_eq!(log["scope.name"], "io.opentelemetry.example".into());
+    assert_eq!(log["scope.version"], "1.2.3".into());
+    assert_eq!(
+        log["scope.attributes"],
+        Value::Object(vec_into_btmap(vec![("library.language", "java".into())]))
+    );
+    assert_eq!(
+        log["scope.schema_url"],
+        "https://opentelemetry.io/schemas/1.9.0".into()
+    );
+
+    // Records without scope information have no scope field.
+    assert!(logs[1].as_log().get("scope").is_none());
+}
+
+#[test]
+fn convert_scope_attributes() {
+    let scope_logs = |scope| ScopeLogs {
+        scope,
+        log_records: vec![LogRecord::default()],
+        schema_url: String::new(),
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![
+                scope_logs(Some(InstrumentationScope {
+                    name: "opentelemetry-java".into(),
+                    attributes: vec![
+                        string_attribute("library.language", "java"),
+                        KeyValue {
+                            key: "library.options".into(),
+                            value: Some(AnyValue {
+                                value: Some(any_value::Value::KvlistValue(KeyValueList {
+                                    values: vec![string_attribute("mode", "async")],
+                                })),
+                            }),
+                        },
+                    ],
+                    ..Default::default()
+                })),
+                // A scope with neither a name, a version nor attributes is omitted.
+                scope_logs(Some(InstrumentationScope::default())),
+            ],
+            schema_url: String::new(),
+        }],
+    };
+
+    let ConvertedRequest { logs, errors, .. } = convert_request(request, &Default::default());
+    assert!(errors.is_empty());
+    assert_eq!(logs.len(), 2);
+
+    let log = logs[0].as_log();
+    assert_eq!(log["scope.name"], "opentelemetry-java".into());
+    assert!(log.get("scope.version").is_none());
+    assert_eq!(log["scope.attributes.\"library.language\""], "java".into());
+    assert_eq!(
+        log["scope.attributes.\"library.options\".mode"],
+        "async".into()
+    );
+
+    assert!(logs[1].as_log().get("scope").is_none());
+}
+
+#[test]
+fn emit_resource_by_reference() {
+    let resource = || OtelResource {
+        attributes: vec![KeyValue {
+            key: "res_key".into(),
+            value: Some(AnyValue {
+                value: Some(any_value::Value::StringValue("res_val".into())),
+            }),
+        }],
+        dropped_attributes_count: 0,
+    };
+    let resource_logs = || ResourceLogs {
+        resource: Some(resource()),
+        scope_logs: vec![ScopeLogs {
+            scope: None,
+            log_records: vec![LogRecord::default(), LogRecord::default()],
+            schema_url: String::new(),
+        }],
+        schema_url: String::new(),
+    };
+    // The same resource appears in two `ResourceLogs`, as sent by batching collectors.
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![resource_logs(), resource_logs()],
+    };
+    let options = ConvertOptions {
+        emit_resource: EmitResource::Reference,
+        ..Default::default()
+    };
+
+    let ConvertedRequest {
+        logs,
+        resources,
+        errors,
+        ..
+    } = convert_request(request, &options);
+    assert!(errors.is_empty());
+    assert_eq!(resources.len(), 1);
+    assert_eq!(logs.len(), 4);
+
+    let resource = resources[0].as_log();
+    assert_eq!(resource["resources.res_key"], "res_val".into());
+    let resource_id = &resource["resource_id"];
+    for log in &logs {
+        let log = log.as_log();
+        assert_eq!(&log["resource_id"], resource_id);
+        assert!(log.get("resources").is_none());
+    }
+}
+
+#[test]
+fn collapse_repeated_bodies() {
+    let record = |body: &str, time_unix_nano: u64| LogRecord {
+        time_unix_nano,
+        body: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(body.into())),
+        }),
+        ..Default::default()
+    };
+    let millis = 1_000_000;
+    let mut log_records = (0..5)
+        .map(|i| record("retrying", i * 10 * millis))
+        .collect::<Vec<_>>();
+    // A different body ends the run, and so does a record outside of the window.
+    log_records.push(record("connected", 50 * millis));
+    log_records.push(record("retrying", 60 * millis));
+    log_records.push(record("retrying", 200 * millis));
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records,
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+    let options = ConvertOptions {
+        repeat_window: Some(chrono::Duration::milliseconds(100)),
+        ..Default::default()
+    };
+
+    let ConvertedRequest { logs, errors, .. } = convert_request(request, &options);
+    assert!(errors.is_empty());
+    let logs = logs
+        .iter()
+        .map(|event| {
+            let log = event.as_log();
+            (log["message"].clone(), log.get("repeat_count").cloned())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        logs,
+        vec![
+            ("retrying".into(), Some(5.into())),
+            ("connected".into(), None),
+            ("retrying".into(), None),
+            ("retrying".into(), None),
+        ]
+    );
+}
+
+#[test]
+fn parse_body_with_pattern() {
+    let mut source: OpentelemetryConfig = toml::from_str(
+        r#"
+        grpc.address = "0.0.0.0:4317"
+        http.address = "0.0.0.0:4318"
+        body_pattern = '^(?P<client>\S+) \S+ (?P<user>\S+) \[(?P<time>[^\]]+)\] "(?P<method>\S+) (?P<path>\S+) \S+" (?P<status>\d{3}) (?P<size>\d+|-)$'
+        "#,
+    )
+    .unwrap();
+    let options = source.convert_options().unwrap();
+
+    let body =
+        r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326"#;
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![
+                    LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue(body.into())),
+                        }),
+                        ..Default::default()
+                    },
+                    LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue("not apache".into())),
+                        }),
+                        ..Default::default()
+                    },
+                ],
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }],
+    };
+
+    let ConvertedRequest { logs, errors, .. } = convert_request(request, &options);
+    assert!(errors.is_empty());
+    assert_eq!(logs.len(), 2);
+
+    let log = logs[0].as_log();
+    assert_eq!(log["message"], body.into());
+    assert_eq!(log["client"], "127.0.0.1".into());
+    assert_eq!(log["user"], "frank".into());
+    assert_eq!(log["time"], "10/Oct/2000:13:55:36 -0700".into());
+    assert_eq!(log["method"], "GET".into());
+    assert_eq!(log["path"], "/apache_pb.gif".into());
+    assert_eq!(log["status"], "200".into());
+    assert_eq!(log["size"], "2326".into());
+
+    // Non-matching bodies are passed through unchanged.
+    let log = logs[1].as_log();
+    assert_eq!(log["message"], "not apache".into());
+    assert!(log.get("client").is_none());
+
+    source.body_pattern = Some("no captures".into());
+    assert_eq!(
+        source.convert_options().unwrap_err().to_string(),
+        "`body_pattern` must contain at least one named capture group"
+    );
+}
+
+#[test]
+fn render_structured_body() {
+    let request = || ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![
+                    LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::KvlistValue(KeyValueList {
+                                values: vec![
+                                    string_attribute("event", "login"),
+                                    string_attribute("user", "frank"),
+                                ],
+                            })),
+                        }),
+                        ..Default::default()
+                    },
+                    LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue("plain".into())),
+                        }),
+                        ..Default::default()
+                    },
+                ],
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }],
+    };
+
+    let source: OpentelemetryConfig = toml::from_str(
+        r#"
+        grpc.address = "0.0.0.0:4317"
+        http.address = "0.0.0.0:4318"
+        structured_body.body_key = "otel_body"
+        "#,
+    )
+    .unwrap();
+    let ConvertedRequest { logs, .. } =
+        convert_request(request(), &source.convert_options().unwrap());
+    assert_eq!(logs.len(), 2);
+
+    let log = logs[0].as_log();
+    assert_eq!(log["message"], r#"{"event":"login","user":"frank"}"#.into());
+    assert_eq!(log["otel_body.event"], "login".into());
+    assert_eq!(log["otel_body.user"], "frank".into());
+
+    // String bodies are left untouched.
+    let log = logs[1].as_log();
+    assert_eq!(log["message"], "plain".into());
+    assert!(log.get("otel_body").is_none());
+
+    let source: OpentelemetryConfig = toml::from_str(
+        r#"
+        grpc.address = "0.0.0.0:4317"
+        http.address = "0.0.0.0:4318"
+        structured_body.message_template = "{{ body.user }} {{ body.event }}"
+        "#,
+    )
+    .unwrap();
+    let ConvertedRequest { logs, .. } =
+        convert_request(request(), &source.convert_options().unwrap());
+    let log = logs[0].as_log();
+    assert_eq!(log["message"], "frank login".into());
+    assert_eq!(log["body.event"], "login".into());
+}
+
+#[test]
+fn parse_json_body() {
+    let request = || ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![
+                    LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue(
+                                r#"{"user":"frank","status":200}"#.into(),
+                            )),
+                        }),
+                        ..Default::default()
+                    },
+                    LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue("not {json".into())),
+                        }),
+                        ..Default::default()
+                    },
+                ],
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }],
+    };
+
+    let source: OpentelemetryConfig = toml::from_str(
+        r#"
+        grpc.address = "0.0.0.0:4317"
+        http.address = "0.0.0.0:4318"
+        parse_json_body = {}
+        "#,
+    )
+    .unwrap();
+    let ConvertedRequest { logs, .. } =
+        convert_request(request(), &source.convert_options().unwrap());
+    assert_eq!(logs.len(), 2);
+
+    let log = logs[0].as_log();
+    assert_eq!(log["message.user"], "frank".into());
+    assert_eq!(log["message.status"], 200.into());
+
+    // Invalid JSON is passed through unchanged.
+    let log = logs[1].as_log();
+    assert_eq!(log["message"], "not {json".into());
+
+    let source: OpentelemetryConfig = toml::from_str(
+        r#"
+        grpc.address = "0.0.0.0:4317"
+        http.address = "0.0.0.0:4318"
+        parse_json_body.mode = "merge"
+        "#,
     )
+    .unwrap();
+    let ConvertedRequest { logs, .. } =
+        convert_request(request(), &source.convert_options().unwrap());
+    let log = logs[0].as_log();
+    assert_eq!(log["message"], r#"{"user":"frank","status":200}"#.into());
+    assert_eq!(log["user"], "frank".into());
+    assert_eq!(log["status"], 200.into());
+}
+
+#[test]
+fn insert_partition_fields() {
+    let request = || ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![
+                    LogRecord {
+                        time_unix_nano: Utc.ymd(2022, 8, 5).and_hms(23, 30, 0).timestamp_nanos()
+                            as u64,
+                        ..Default::default()
+                    },
+                    // Without a timestamp, the observed timestamp is used.
+                    LogRecord {
+                        observed_time_unix_nano: Utc
+                            .ymd(2021, 12, 31)
+                            .and_hms(1, 0, 0)
+                            .timestamp_nanos()
+                            as u64,
+                        ..Default::default()
+                    },
+                ],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+    let partition = |event: &Event| {
+        let log = event.as_log();
+        ["year", "month", "day", "hour"].map(|key| log[key].to_string_lossy())
+    };
+
+    let options = ConvertOptions {
+        partition_fields: true,
+        ..Default::default()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(request(), &options);
+    assert_eq!(partition(&logs[0]), ["2022", "08", "05", "23"]);
+    assert_eq!(partition(&logs[1]), ["2021", "12", "31", "01"]);
+
+    let options = ConvertOptions {
+        partition_fields: true,
+        partition_timezone: vector_common::TimeZone::parse("Asia/Tokyo"),
+        ..Default::default()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(request(), &options);
+    assert_eq!(partition(&logs[0]), ["2022", "08", "06", "08"]);
+    assert_eq!(partition(&logs[1]), ["2021", "12", "31", "10"]);
+
+    let ConvertedRequest { logs, .. } = convert_request(request(), &Default::default());
+    assert!(logs[0].as_log().get("year").is_none());
+}
+
+#[test]
+fn tag_received_records_with_resource_attribute() {
+    let _ = crate::metrics::init_test();
+
+    let resource_logs = |service: Option<&str>, records: usize| ResourceLogs {
+        resource: service.map(|service| OtelResource {
+            attributes: vec![KeyValue {
+                key: "service.name".into(),
+                value: Some(AnyValue {
+                    value: Some(any_value::Value::StringValue(service.into())),
+                }),
+            }],
+            dropped_attributes_count: 0,
+        }),
+        scope_logs: vec![ScopeLogs {
+            scope: None,
+            log_records: vec![LogRecord::default(); records],
+            schema_url: String::new(),
+        }],
+        schema_url: String::new(),
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![
+            resource_logs(Some("tag_checkout"), 2),
+            resource_logs(Some("tag_cart"), 1),
+            resource_logs(Some("tag_checkout"), 1),
+            resource_logs(Some("tag_search"), 4),
+            resource_logs(None, 5),
+        ],
+    };
+    let options = ConvertOptions {
+        records_tag: Some(RecordsTag::new("service.name".into(), 2)),
+        ..Default::default()
+    };
+    convert_request(request, &options);
+
+    let received = |service: &str| {
+        crate::metrics::Controller::get()
+            .unwrap()
+            .capture_metrics()
+            .into_iter()
+            .filter(|metric| {
+                metric.name() == "component_received_records_total"
+                    && metric.tag_value("service.name").as_deref() == Some(service)
+            })
+            .map(|metric| match metric.value() {
+                MetricValue::Counter { value } => *value,
+                value => panic!("Unexpected metric value {:?}", value),
+            })
+            .sum::<f64>()
+    };
+    assert_eq!(received("tag_checkout"), 3.0);
+    assert_eq!(received("tag_cart"), 1.0);
+    // Values beyond the maximum number of distinct values, and missing values, have fixed tags.
+    assert!(received("_other") >= 4.0);
+    assert!(received("_none") >= 5.0);
+    assert_eq!(received("tag_search"), 0.0);
+}
+
+/// A `metrics::Recorder` standing in for an external metrics backend, counting the increments of
+/// each counter by name and labels.
+#[derive(Default)]
+struct CountingRecorder {
+    counters: std::sync::Mutex<BTreeMap<String, Arc<AtomicU64>>>,
+}
+
+impl CountingRecorder {
+    fn counter(&self, name: &str) -> u64 {
+        self.counters
+            .lock()
+            .unwrap()
+            .get(name)
+            .map_or(0, |counter| counter.load(Ordering::Relaxed))
+    }
+}
+
+impl metrics::Recorder for CountingRecorder {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn register_counter(&self, key: &Key) -> Counter {
+        let mut name = key.name().to_owned();
+        for label in key.labels() {
+            name.push_str(&format!(",{}={}", label.key(), label.value()));
+        }
+        let counter = Arc::clone(self.counters.lock().unwrap().entry(name).or_default());
+        Counter::from_arc(counter)
+    }
+
+    fn register_gauge(&self, _: &Key) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, _: &Key) -> Histogram {
+        Histogram::noop()
+    }
+}
+
+#[test]
+fn internal_metrics_reach_external_recorder() {
+    // The source's internal events emit their counters through the `metrics` facade, so any
+    // recorder installed in place of Vector's own receives them. Only one recorder can be
+    // installed per process, which nextest provides each test with.
+    let recorder: &'static CountingRecorder = Box::leak(Box::default());
+    if metrics::set_recorder(recorder).is_err() {
+        eprintln!("Skipping test, a metrics recorder is already installed in this process.");
+        return;
+    }
+
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: Some(OtelResource {
+                attributes: vec![KeyValue {
+                    key: "service.name".into(),
+                    value: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("checkout".into())),
+                    }),
+                }],
+                dropped_attributes_count: 0,
+            }),
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![
+                    LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue("unmatched".into())),
+                        }),
+                        ..Default::default()
+                    };
+                    3
+                ],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+    let options = ConvertOptions {
+        body_pattern: Some(regex::Regex::new("^user=(?P<user>\\w+)$").unwrap()),
+        records_tag: Some(RecordsTag::new("service.name".into(), 10)),
+        ..Default::default()
+    };
+    convert_request(request, &options);
+
+    assert_eq!(
+        recorder.counter("component_received_records_total,service.name=checkout"),
+        3
+    );
+    assert_eq!(recorder.counter("body_pattern_misses_total"), 3);
+}
+
+#[tokio::test]
+async fn grpc_request_sent_after_client_disconnect() {
+    let mut builder = SourceSender::builder().with_buffer(1);
+    let logs_output = builder.add_output(Output::default(DataType::Log).with_port(LOGS));
+    let mut logs_output = logs_output.into_stream().flat_map(into_event_stream);
+    let pipeline = builder.build();
+
+    // Fill up the pipeline, so that the request is still being sent when the client disconnects.
+    pipeline
+        .clone()
+        .send_batch_named(LOGS, vec![Event::from(LogEvent::from("blocking"))])
+        .await
+        .unwrap();
+
+    let service = Service {
+        pipeline,
+        acknowledgements: false,
+        options: Default::default(),
+    };
+    let request = Request::new(ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord {
+                    body: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("complete".into())),
+                    }),
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    });
+
+    // Closing the connection drops the response future of the request.
+    {
+        let export = service.export(request);
+        futures::pin_mut!(export);
+        assert!(futures::poll!(export).is_pending());
+    }
+
+    let event = logs_output.next().await.unwrap();
+    assert_eq!(event.as_log()["message"], "blocking".into());
+    let event = timeout(Duration::from_secs(5), logs_output.next())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(event.as_log()["message"], "complete".into());
+}
+
+/// Sends an export request that can't complete until the pipeline is drained, and shuts the
+/// source down while it's in flight.
+///
+/// Returns the result of the request, whether the source had shut down before the pipeline was
+/// drained, and the events sent to the pipeline.
+async fn export_during_shutdown(
+    drain_connections_on_restart: bool,
+) -> (Result<(), tonic::Status>, bool, Vec<Event>) {
+    let grpc_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        r#"
+        grpc.address = "{}"
+        grpc.drain_connections_on_restart = {}
+        http.address = "{}"
+        "#,
+        grpc_addr,
+        drain_connections_on_restart,
+        next_addr()
+    ))
+    .unwrap();
+
+    let mut builder = SourceSender::builder().with_buffer(1);
+    let logs_output = builder.add_output(Output::default(DataType::Log).with_port(LOGS));
+    let mut logs_output = logs_output.into_stream().flat_map(into_event_stream);
+    let pipeline = builder.build();
+    pipeline
+        .clone()
+        .send_batch_named(LOGS, vec![Event::from(LogEvent::from("blocking"))])
+        .await
+        .unwrap();
+
+    let key = ComponentKey::from("opentelemetry");
+    let (cx, mut shutdown) = SourceContext::new_shutdown(&key, pipeline);
+    let server = tokio::spawn(source.build(cx).await.unwrap());
+    test_util::wait_for_tcp(grpc_addr).await;
+
+    let mut client = LogsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    let mut export = tokio::spawn(async move {
+        let request = Request::new(ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: None,
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue("slow".into())),
+                        }),
+                        ..Default::default()
+                    }],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        });
+        client.export(request).await.map(|_| ())
+    });
+    // Give the request time to reach the full pipeline.
+    sleep(Duration::from_millis(250)).await;
+    assert!(timeout(Duration::from_millis(100), &mut export)
+        .await
+        .is_err());
+
+    let shutdown_complete =
+        shutdown.shutdown_source(&key, Instant::now() + Duration::from_secs(30));
+    futures::pin_mut!(shutdown_complete);
+    let shut_down_before_drain = match timeout(Duration::from_secs(1), &mut shutdown_complete).await
+    {
+        Ok(completed) => {
+            assert!(completed);
+            true
+        }
+        Err(_) => false,
+    };
+
+    let mut events = vec![logs_output.next().await.unwrap()];
+    let result = export.await.unwrap();
+    if !shut_down_before_drain {
+        assert!(shutdown_complete.await);
+    }
+    server.await.unwrap().unwrap();
+    events.extend(test_util::collect_ready(logs_output).await);
+    (result, shut_down_before_drain, events)
+}
+
+#[tokio::test]
+async fn grpc_drains_connections_on_shutdown() {
+    let (result, shut_down_before_drain, events) = export_during_shutdown(true).await;
+
+    assert!(result.is_ok());
+    assert!(!shut_down_before_drain);
+    let messages: Vec<_> = events
+        .iter()
+        .map(|event| event.as_log()["message"].to_string_lossy())
+        .collect();
+    assert_eq!(messages, vec!["blocking", "slow"]);
+}
+
+#[tokio::test]
+async fn grpc_closes_connections_on_shutdown() {
+    let (result, shut_down_before_drain, _) = export_during_shutdown(false).await;
+
+    assert!(result.is_err());
+    assert!(shut_down_before_drain);
+}
+
+#[tokio::test]
+async fn grpc_throttles_noisy_tenant() {
+    let source: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        tenant_rate_limits.header = "x-tenant"
+        tenant_rate_limits.limits.noisy = 2
+        tenant_rate_limits.limits.quiet = 2
+    "#})
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let service = Service {
+        pipeline: sender,
+        acknowledgements: false,
+        options: source.convert_options().unwrap(),
+    };
+
+    let request = |tenant: &str, records: usize| {
+        let mut request = Request::new(ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: None,
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![
+                        LogRecord {
+                            body: Some(AnyValue {
+                                value: Some(any_value::Value::StringValue(tenant.into())),
+                            }),
+                            ..Default::default()
+                        };
+                        records
+                    ],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        });
+        request
+            .metadata_mut()
+            .insert("x-tenant", tenant.parse().unwrap());
+        request
+    };
+
+    assert!(service.export(request("noisy", 2)).await.is_ok());
+    let status = service.export(request("noisy", 1)).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert!(service.export(request("quiet", 2)).await.is_ok());
+
+    let logs = test_util::collect_ready(logs_output).await;
+    let messages: Vec<_> = logs
+        .iter()
+        .map(|log| log.as_log()["message"].to_string_lossy())
+        .collect();
+    assert_eq!(messages, vec!["noisy", "noisy", "quiet", "quiet"]);
+}
+
+#[tokio::test]
+async fn grpc_reports_undelivered_records_as_partial_success() {
+    let (sender, logs_output, _) = new_source(EventStatus::Rejected);
+    let service = Service {
+        pipeline: sender,
+        acknowledgements: true,
+        options: ConvertOptions::default(),
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![
+            namespace_resource_logs(None, "first"),
+            namespace_resource_logs(None, "second"),
+        ],
+    };
+    let export = tokio::spawn(async move { service.export(Request::new(request)).await });
+
+    // The events are rejected once they are dropped.
+    let logs = logs_output.take(2).collect::<Vec<_>>().await;
+    assert_eq!(logs.len(), 2);
+    drop(logs);
+
+    let response = export.await.unwrap().unwrap().into_inner();
+    let partial_success = response.partial_success.unwrap();
+    assert_eq!(partial_success.rejected_log_records, 2);
+    assert_eq!(
+        partial_success.error_message,
+        "2 log records were not delivered to their sinks"
+    );
+}
+
+#[tokio::test]
+async fn grpc_reports_no_partial_success_when_delivered() {
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let service = Service {
+        pipeline: sender,
+        acknowledgements: true,
+        options: ConvertOptions::default(),
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![namespace_resource_logs(None, "delivered")],
+    };
+    let export = tokio::spawn(async move { service.export(Request::new(request)).await });
+
+    drop(logs_output.take(1).collect::<Vec<_>>().await);
+    let response = export.await.unwrap().unwrap().into_inner();
+    assert_eq!(response.partial_success, None);
+}
+
+#[tokio::test]
+async fn http_reports_undelivered_records_as_partial_success() {
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            acknowledgements.enabled = true
+        "#},
+        next_addr(),
+        http_addr
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Rejected);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(http_addr).await;
+
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![
+            namespace_resource_logs(None, "first"),
+            namespace_resource_logs(None, "second"),
+        ],
+    };
+    let export = tokio::spawn(
+        reqwest::Client::new()
+            .post(format!("http://{}/v1/logs", http_addr))
+            .header("content-type", "application/x-protobuf")
+            .body(request.encode_to_vec())
+            .send(),
+    );
+
+    // The events are rejected once they are dropped.
+    let logs = logs_output.take(2).collect::<Vec<_>>().await;
+    assert_eq!(logs.len(), 2);
+    drop(logs);
+
+    let response = export.await.unwrap().unwrap();
+    assert_eq!(response.status(), 200);
+    let partial_success = ExportLogsServiceResponse::decode(response.bytes().await.unwrap())
+        .unwrap()
+        .partial_success
+        .unwrap();
+    assert_eq!(partial_success.rejected_log_records, 2);
+    assert_eq!(
+        partial_success.error_message,
+        "2 log records were not delivered to their sinks"
+    );
+}
+
+#[tokio::test]
+async fn grpc_drops_duplicate_requests() {
+    let source: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        idempotency_header = "idempotency-key"
+    "#})
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let service = Service {
+        pipeline: sender,
+        acknowledgements: false,
+        options: source.convert_options().unwrap(),
+    };
+
+    let request = |key: Option<&str>, message: &str| {
+        let mut request = Request::new(ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: None,
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![LogRecord {
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue(message.into())),
+                        }),
+                        ..Default::default()
+                    }],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        });
+        if let Some(key) = key {
+            request
+                .metadata_mut()
+                .insert("idempotency-key", key.parse().unwrap());
+        }
+        request
+    };
+
+    service.export(request(Some("1"), "first")).await.unwrap();
+    service.export(request(Some("1"), "retry")).await.unwrap();
+    service.export(request(Some("2"), "second")).await.unwrap();
+    // Requests without a key are never duplicates.
+    service.export(request(None, "unkeyed")).await.unwrap();
+    service.export(request(None, "unkeyed")).await.unwrap();
+
+    let logs = test_util::collect_ready(logs_output).await;
+    let messages: Vec<_> = logs
+        .iter()
+        .map(|log| log.as_log()["message"].to_string_lossy())
+        .collect();
+    assert_eq!(messages, vec!["first", "second", "unkeyed", "unkeyed"]);
+}
+
+#[tokio::test]
+async fn grpc_counts_events_failed_on_closed_output() {
+    let _ = crate::metrics::init_test();
+    let failed = || {
+        crate::metrics::Controller::get()
+            .unwrap()
+            .capture_metrics()
+            .into_iter()
+            .filter(|metric| {
+                metric.name() == "component_events_failed_total"
+                    && metric.tag_value("output").as_deref() == Some(LOGS)
+            })
+            .map(|metric| match metric.value() {
+                MetricValue::Counter { value } => *value,
+                value => panic!("Unexpected metric value {:?}", value),
+            })
+            .sum::<f64>()
+    };
+    let before = failed();
+
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    // Closing the output makes sending the log records fail.
+    drop(logs_output);
+    let service = Service {
+        pipeline: sender,
+        acknowledgements: false,
+        options: ConvertOptions::default(),
+    };
+    let status = service
+        .export(Request::new(proto_version_request("")))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+
+    assert!(failed() >= before + 1.0);
+}
+
+#[test]
+fn records_output_event_size() {
+    let _ = crate::metrics::init_test();
+    let histogram = || {
+        crate::metrics::Controller::get()
+            .unwrap()
+            .capture_metrics()
+            .into_iter()
+            .filter(|metric| metric.name() == "component_output_event_bytes_histogram")
+            .map(|metric| match metric.value() {
+                MetricValue::AggregatedHistogram { count, sum, .. } => (*count, *sum),
+                value => panic!("Unexpected metric value {:?}", value),
+            })
+            .fold((0, 0.0), |(count, sum), (c, s)| (count + c, sum + s))
+    };
+    let (count_before, sum_before) = histogram();
+
+    let mut request = proto_version_request("");
+    request.resource_logs[0].scope_logs[0].log_records[0].body = Some(AnyValue {
+        value: Some(any_value::Value::StringValue("x".repeat(1000))),
+    });
+    let ConvertedRequest { logs, .. } = convert_request(request, &ConvertOptions::default());
+    assert_eq!(logs.len(), 1);
+
+    let (count_after, sum_after) = histogram();
+    assert_eq!(count_after - count_before, 1);
+    // The body makes up most of the event, the remaining fields add a few hundred bytes.
+    let size = sum_after - sum_before;
+    assert!((1000.0..1500.0).contains(&size), "event size {}", size);
+}
+
+fn proto_version_request(schema_url: &str) -> ExportLogsServiceRequest {
+    ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord::default()],
+                schema_url: String::new(),
+            }],
+            schema_url: schema_url.into(),
+        }],
+    }
+}
+
+#[test]
+fn include_proto_version_from_schema_url() {
+    let request = || proto_version_request("https://opentelemetry.io/schemas/0.20.0");
+
+    let options = ConvertOptions {
+        include_proto_version: true,
+        ..Default::default()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(request(), &options);
+    assert_eq!(logs[0].as_log()["proto_version"], "v0.20.0".into());
+
+    let ConvertedRequest { logs, .. } = convert_request(request(), &Default::default());
+    assert!(logs[0].as_log().get("proto_version").is_none());
+}
+
+#[test]
+fn coerce_bool_strings() {
+    let attribute = |key: &str, value: &str| KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: Some(OtelResource {
+                attributes: vec![attribute("sampled", "no")],
+                dropped_attributes_count: 0,
+            }),
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord {
+                    attributes: vec![
+                        attribute("enabled", "true"),
+                        attribute("state", "maybe"),
+                        attribute("uppercase", "TRUE"),
+                    ],
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+    let options = ConvertOptions {
+        bool_strings: Some(BoolStrings {
+            true_strings: vec!["true".into()],
+            false_strings: vec!["false".into(), "no".into()],
+        }),
+        ..Default::default()
+    };
+
+    let ConvertedRequest { logs, .. } = convert_request(request, &options);
+    let log = logs[0].as_log();
+    assert_eq!(log["attributes.enabled"], Value::Boolean(true));
+    assert_eq!(log["attributes.state"], "maybe".into());
+    // Only exact matches are coerced.
+    assert_eq!(log["attributes.uppercase"], "TRUE".into());
+    assert_eq!(log["resources.sampled"], Value::Boolean(false));
+}
+
+#[test]
+fn coerce_bool_strings_rejects_ambiguous_strings() {
+    let source: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        coerce_bool_strings = true
+        bool_true_strings = ["true", "yes"]
+        bool_false_strings = ["false", "yes"]
+    "#})
+    .unwrap();
+
+    assert_eq!(
+        source.convert_options().unwrap_err().to_string(),
+        r#""yes" can't be in both `bool_true_strings` and `bool_false_strings`"#
+    );
+}
+
+fn service_request(services: &[&str]) -> ExportLogsServiceRequest {
+    ExportLogsServiceRequest {
+        resource_logs: services
+            .iter()
+            .map(|service| ResourceLogs {
+                resource: Some(OtelResource {
+                    attributes: vec![KeyValue {
+                        key: "service.name".into(),
+                        value: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue((*service).into())),
+                        }),
+                    }],
+                    dropped_attributes_count: 0,
+                }),
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![LogRecord::default(), LogRecord::default()],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            })
+            .collect(),
+    }
+}
+
+fn resource_filter_options(filter: &str) -> ConvertOptions {
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "127.0.0.1:4317"
+            http.address = "127.0.0.1:4318"
+            resource_filter."service.name" = '{}'
+        "#},
+        filter
+    ))
+    .unwrap();
+    source.convert_options().unwrap()
+}
+
+#[test]
+fn resource_filter_emits_matching_resources() {
+    let options = resource_filter_options("^(api|web)$");
+    let ConvertedRequest { logs, .. } =
+        convert_request(service_request(&["api", "worker", "web"]), &options);
+
+    let services = logs
+        .iter()
+        .map(|log| log.as_log()[r#"resources."service.name""#].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        services,
+        vec!["api".into(), "api".into(), "web".into(), "web".into()]
+    );
+}
+
+#[test]
+fn resource_filter_drops_non_matching_resources() {
+    let options = resource_filter_options("^api$");
+    let ConvertedRequest { logs, .. } =
+        convert_request(service_request(&["worker", "batch"]), &options);
+    assert!(logs.is_empty());
+
+    // Resources without the attribute are dropped too.
+    let mut request = service_request(&["api"]);
+    request.resource_logs[0].resource = None;
+    let ConvertedRequest { logs, .. } = convert_request(request, &options);
+    assert!(logs.is_empty());
+}
+
+#[test]
+fn resource_filter_rejects_invalid_patterns() {
+    let source: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        resource_filter."service.name" = "(api"
+    "#})
+    .unwrap();
+
+    let error = source.convert_options().unwrap_err().to_string();
+    assert!(
+        error.starts_with(r#"Invalid `resource_filter` pattern for "service.name""#),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn enrichment_table_enriches_matching_resources() {
+    let source: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        enrichment_table.attribute = "service.name"
+        enrichment_table.entries.api = { team = "platform", owner = "alice" }
+    "#})
+    .unwrap();
+    let options = source.convert_options().unwrap();
+
+    let ConvertedRequest { logs, .. } =
+        convert_request(service_request(&["api", "worker"]), &options);
+    assert_eq!(logs.len(), 4);
+
+    for log in &logs[..2] {
+        assert_eq!(log.as_log()["team"], "platform".into());
+        assert_eq!(log.as_log()["owner"], "alice".into());
+    }
+    // Unknown services are left unenriched.
+    for log in &logs[2..] {
+        assert!(!log.as_log().contains("team"));
+        assert!(!log.as_log().contains("owner"));
+    }
+}
+
+#[test]
+fn normalize_severity_text() {
+    let record = |severity_text: &str, severity_number: i32| LogRecord {
+        severity_text: severity_text.into(),
+        severity_number,
+        ..Default::default()
+    };
+    let request = || ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![
+                    record("Info", 9),
+                    record("INFO", 9),
+                    record("info", 0),
+                    record("warn2", 14),
+                    record("notice", 10),
+                    record("", 21),
+                    record("custom", 0),
+                ],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+    let severity_texts = |logs: Vec<Event>| {
+        logs.into_iter()
+            .map(|event| event.as_log().get("severity_text").cloned())
+            .collect::<Vec<_>>()
+    };
+
+    let options = ConvertOptions {
+        normalize_severity_text: true,
+        ..Default::default()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(request(), &options);
+    assert_eq!(
+        severity_texts(logs),
+        vec![
+            Some("INFO".into()),
+            Some("INFO".into()),
+            Some("INFO".into()),
+            Some("WARN2".into()),
+            // Unknown texts are derived from the severity number, when there is one.
+            Some("INFO2".into()),
+            Some("FATAL".into()),
+            Some("custom".into()),
+        ]
+    );
+
+    let ConvertedRequest { logs, .. } = convert_request(request(), &Default::default());
+    assert_eq!(severity_texts(logs)[0], Some("Info".into()));
+}
+
+#[test]
+fn normalize_severity() {
+    let record = |severity_number: i32| LogRecord {
+        severity_text: "custom".into(),
+        severity_number,
+        ..Default::default()
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: [0, 1, 4, 5, 8, 9, 12, 13, 16, 17, 20, 21, 24, 25]
+                    .into_iter()
+                    .map(record)
+                    .collect(),
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+
+    let options = ConvertOptions {
+        normalize_severity: true,
+        ..Default::default()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(request, &options);
+    let levels = logs
+        .iter()
+        .map(|event| event.as_log().get("level").cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        levels,
+        vec![
+            None,
+            Some("TRACE".into()),
+            Some("TRACE".into()),
+            Some("DEBUG".into()),
+            Some("DEBUG".into()),
+            Some("INFO".into()),
+            Some("INFO".into()),
+            Some("WARN".into()),
+            Some("WARN".into()),
+            Some("ERROR".into()),
+            Some("ERROR".into()),
+            Some("FATAL".into()),
+            Some("FATAL".into()),
+            None,
+        ]
+    );
+    // The raw severity fields are kept as they are.
+    let log = logs[5].as_log();
+    assert_eq!(log["severity_number"], 9.into());
+    assert_eq!(log["severity_text"], "custom".into());
+}
+
+#[test]
+fn resource_id_field() {
+    let attribute = |key: &str, value: &str| KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    };
+    let resource_logs = |attributes| ResourceLogs {
+        resource: Some(OtelResource {
+            attributes,
+            dropped_attributes_count: 0,
+        }),
+        scope_logs: vec![ScopeLogs {
+            scope: None,
+            log_records: vec![LogRecord::default()],
+            schema_url: String::new(),
+        }],
+        schema_url: String::new(),
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![
+            resource_logs(vec![
+                attribute("service.name", "api"),
+                attribute("host.name", "a"),
+            ]),
+            // The same attributes in another order.
+            resource_logs(vec![
+                attribute("host.name", "a"),
+                attribute("service.name", "api"),
+            ]),
+            resource_logs(vec![
+                attribute("service.name", "api"),
+                attribute("host.name", "b"),
+            ]),
+            resource_logs(vec![]),
+        ],
+    };
+
+    let options = ConvertOptions {
+        resource_id_field: Some("resource_digest".to_owned()),
+        ..Default::default()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(request, &options);
+    let ids = logs
+        .iter()
+        .map(|event| event.as_log().get("resource_digest").cloned())
+        .collect::<Vec<_>>();
+
+    assert!(ids[0].is_some());
+    assert_eq!(ids[0], ids[1]);
+    assert!(ids[2].is_some());
+    assert_ne!(ids[0], ids[2]);
+    assert_eq!(ids[3], None);
+}
+
+#[tokio::test]
+async fn grpc_include_proto_version_from_user_agent() {
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let service = |include_proto_version| Service {
+        pipeline: sender.clone(),
+        acknowledgements: false,
+        options: ConvertOptions {
+            include_proto_version,
+            ..Default::default()
+        },
+    };
+    let request = |schema_url: &str| {
+        let mut request = Request::new(proto_version_request(schema_url));
+        request.metadata_mut().insert(
+            "user-agent",
+            "OTel-OTLP-Exporter-Go/0.19.0 grpc-go/1.48.0"
+                .parse()
+                .unwrap(),
+        );
+        request
+    };
+
+    service(true).export(request("")).await.unwrap();
+    // The version of the schema URL takes precedence.
+    service(true)
+        .export(request("https://opentelemetry.io/schemas/0.20.0"))
+        .await
+        .unwrap();
+    service(false).export(request("")).await.unwrap();
+
+    let logs = test_util::collect_ready(logs_output).await;
+    let versions: Vec<_> = logs
+        .iter()
+        .map(|log| {
+            log.as_log()
+                .get("proto_version")
+                .map(Value::to_string_lossy)
+        })
+        .collect();
+    assert_eq!(
+        versions,
+        vec![Some("v0.19.0".to_owned()), Some("v0.20.0".to_owned()), None]
+    );
+}
+
+/// The JSON encoding of an export request with a single log record.
+const JSON_LOGS_REQUEST: &str =
+    r#"{"resourceLogs": [{"scopeLogs": [{"logRecords": [{"body": {"stringValue": "hello"}}]}]}]}"#;
+
+/// Sends an export request with the given content type to the HTTP server of a source with the
+/// given extra configuration, and returns the response once its record has been received.
+async fn http_export(config: &str, content_type: &str, body: Vec<u8>) -> reqwest::Response {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            {}
+        "#},
+        grpc_addr, http_addr, config
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(http_addr).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/v1/logs", http_addr))
+        .header("content-type", content_type)
+        .body(body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(test_util::collect_ready(logs_output).await.len(), 1);
+    response
+}
+
+/// Sends a JSON export request to the HTTP server of a source with the given extra configuration.
+async fn http_export_json(config: &str) -> reqwest::Response {
+    http_export(config, "application/json", JSON_LOGS_REQUEST.into()).await
+}
+
+#[tokio::test]
+async fn http_default_success_response_body() {
+    let response = http_export_json("").await;
+    assert_eq!(response.headers()["content-type"], "application/json");
+
+    let body = response.bytes().await.unwrap();
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+        serde_json::json!({})
+    );
+}
+
+#[tokio::test]
+async fn http_custom_success_response_body() {
+    let response =
+        http_export_json(r#"http.success_response_body = '{"partialSuccess":{}}'"#).await;
+    assert_eq!(response.text().await.unwrap(), r#"{"partialSuccess":{}}"#);
+}
+
+#[tokio::test]
+async fn http_empty_success_response_body() {
+    let response = http_export_json(r#"http.success_response_body = """#).await;
+    assert!(response.bytes().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn http_protobuf_success_response_ignores_body() {
+    let response = http_export(
+        r#"http.success_response_body = '{"partialSuccess":{}}'"#,
+        "application/x-protobuf",
+        proto_version_request("").encode_to_vec(),
+    )
+    .await;
+    assert_eq!(response.headers()["content-type"], "application/x-protobuf");
+
+    let body = response.bytes().await.unwrap();
+    assert_eq!(
+        ExportLogsServiceResponse::decode(body).unwrap(),
+        ExportLogsServiceResponse::default()
+    );
+}
+
+/// Posts an export request with the given content type to the HTTP server of a source, and returns
+/// the response along with the log events received.
+async fn http_post_logs(content_type: &str, body: &str) -> (reqwest::Response, Vec<Event>) {
+    http_post_encoded_logs(content_type, None, body.as_bytes().to_vec()).await
+}
+
+async fn http_post_encoded_logs(
+    content_type: &str,
+    content_encoding: Option<&str>,
+    body: Vec<u8>,
+) -> (reqwest::Response, Vec<Event>) {
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+        "#},
+        next_addr(),
+        http_addr
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(http_addr).await;
+
+    let mut request = reqwest::Client::new()
+        .post(format!("http://{}/v1/logs", http_addr))
+        .header("content-type", content_type);
+    if let Some(content_encoding) = content_encoding {
+        request = request.header("content-encoding", content_encoding);
+    }
+    let response = request.body(body).send().await.unwrap();
+    (response, test_util::collect_ready(logs_output).await)
+}
+
+fn compressed_logs_request() -> ExportLogsServiceRequest {
+    ExportLogsServiceRequest {
+        resource_logs: vec![namespace_resource_logs(None, "compressed")],
+    }
+}
+
+fn gzip(body: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// Posts logs to a source requiring the `auth` credentials, with the given headers.
+async fn http_post_authenticated_logs(
+    auth: &str,
+    headers: &[(&str, &str)],
+) -> (reqwest::Response, Vec<Event>) {
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            {}
+        "#},
+        next_addr(),
+        http_addr,
+        auth
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(http_addr).await;
+
+    let mut request = reqwest::Client::new()
+        .post(format!("http://{}/v1/logs", http_addr))
+        .header("content-type", "application/x-protobuf");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let response = request
+        .body(compressed_logs_request().encode_to_vec())
+        .send()
+        .await
+        .unwrap();
+    (response, test_util::collect_ready(logs_output).await)
+}
+
+const BEARER_AUTH: &str = r#"http.auth = { strategy = "bearer", token = "s3cr3t" }"#;
+const API_KEY_AUTH: &str =
+    r#"http.auth = { strategy = "api_key", header = "x-api-key", key = "s3cr3t" }"#;
+
+#[tokio::test]
+async fn http_accepts_authenticated_logs() {
+    let (response, events) =
+        http_post_authenticated_logs(BEARER_AUTH, &[("authorization", "Bearer s3cr3t")]).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+
+    let (response, events) =
+        http_post_authenticated_logs(API_KEY_AUTH, &[("x-api-key", "s3cr3t")]).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+}
+
+#[tokio::test]
+async fn http_rejects_unauthenticated_logs() {
+    for (auth, headers) in [
+        (BEARER_AUTH, vec![]),
+        (BEARER_AUTH, vec![("authorization", "Bearer wrong")]),
+        (BEARER_AUTH, vec![("authorization", "s3cr3t")]),
+        (API_KEY_AUTH, vec![]),
+        (API_KEY_AUTH, vec![("x-api-key", "wrong")]),
+        (API_KEY_AUTH, vec![("authorization", "Bearer s3cr3t")]),
+    ] {
+        let (response, events) = http_post_authenticated_logs(auth, &headers).await;
+        assert_eq!(response.status(), 401);
+        assert!(events.is_empty());
+    }
+}
+
+#[test]
+fn http_auth_secrets_are_redacted() {
+    let config: OpentelemetryConfig = toml::from_str(&format!(
+        "grpc.address = \"127.0.0.1:4317\"\nhttp.address = \"127.0.0.1:4318\"\n{}",
+        API_KEY_AUTH
+    ))
+    .unwrap();
+    assert!(!format!("{:?}", config).contains("s3cr3t"));
+    assert!(!serde_json::to_string(&config).unwrap().contains("s3cr3t"));
+}
+
+#[tokio::test]
+async fn http_auth_requires_valid_header_name() {
+    let config: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        http.auth = { strategy = "api_key", header = "x api key", key = "s3cr3t" }
+    "#})
+    .unwrap();
+    let (sender, _, _) = new_source(EventStatus::Delivered);
+    let error = config
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .err()
+        .unwrap();
+    assert!(error
+        .to_string()
+        .starts_with("Invalid `http.auth.header` \"x api key\""));
+}
+
+/// Starts a source whose HTTP server has the given limits, returning its address and logs output.
+async fn start_limited_http_source(limits: &str) -> (SocketAddr, impl Stream<Item = Event>) {
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            {}
+        "#},
+        next_addr(),
+        http_addr,
+        limits
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(http_addr).await;
+    (http_addr, logs_output)
+}
+
+async fn http_post_protobuf_logs(http_addr: SocketAddr, body: Vec<u8>) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("http://{}/v1/logs", http_addr))
+        .header("content-type", "application/x-protobuf")
+        .body(body)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn http_rejects_oversized_requests() {
+    let body = compressed_logs_request().encode_to_vec();
+    let (http_addr, logs_output) =
+        start_limited_http_source(&format!("http.max_request_size_bytes = {}", body.len())).await;
+
+    let response = http_post_protobuf_logs(http_addr, body.clone()).await;
+    assert_eq!(response.status(), 200);
+
+    let mut oversized = body;
+    oversized.extend_from_slice(&compressed_logs_request().encode_to_vec());
+    let response = http_post_protobuf_logs(http_addr, oversized).await;
+    assert_eq!(response.status(), 413);
+
+    // Only the request within the limit is emitted.
+    let events = test_util::collect_ready(logs_output).await;
+    assert_eq!(events.len(), 1);
+}
+
+#[tokio::test]
+async fn http_rejects_oversized_decompressed_requests() {
+    let max_request_size = 1024;
+    let body = gzip(&[0; 64 * 1024]);
+    assert!(body.len() < max_request_size);
+    let (http_addr, logs_output) = start_limited_http_source(&format!(
+        "http.max_request_size_bytes = {}",
+        max_request_size
+    ))
+    .await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/v1/logs", http_addr))
+        .header("content-type", "application/x-protobuf")
+        .header("content-encoding", "gzip")
+        .body(body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 413);
+
+    let events = test_util::collect_ready(logs_output).await;
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn http_rejects_connections_over_limit() {
+    let (http_addr, logs_output) = start_limited_http_source("http.max_connections = 1").await;
+    // Lets the server notice the connection of `wait_for_tcp` being closed.
+    sleep(Duration::from_millis(100)).await;
+
+    let idle = tokio::net::TcpStream::connect(http_addr).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+    let response =
+        http_post_protobuf_logs(http_addr, compressed_logs_request().encode_to_vec()).await;
+    assert_eq!(response.status(), 503);
+
+    drop(idle);
+    sleep(Duration::from_millis(100)).await;
+    let response =
+        http_post_protobuf_logs(http_addr, compressed_logs_request().encode_to_vec()).await;
+    assert_eq!(response.status(), 200);
+
+    let events = test_util::collect_ready(logs_output).await;
+    assert_eq!(events.len(), 1);
+}
+
+#[tokio::test]
+async fn http_accepts_gzip_logs() {
+    let body = gzip(&compressed_logs_request().encode_to_vec());
+    let (response, events) =
+        http_post_encoded_logs("application/x-protobuf", Some("gzip"), body).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+}
+
+#[tokio::test]
+async fn http_accepts_zstd_logs() {
+    let body = zstd::stream::encode_all(&compressed_logs_request().encode_to_vec()[..], 0).unwrap();
+    let (response, events) = http_post_logs_with_compression(r#"["zstd"]"#, "zstd", body).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+}
+
+#[tokio::test]
+async fn http_accepts_deflate_logs() {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&compressed_logs_request().encode_to_vec())
+        .unwrap();
+    let body = encoder.finish().unwrap();
+    let (response, events) =
+        http_post_logs_with_compression(r#"["deflate"]"#, "deflate", body).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+}
+
+#[tokio::test]
+async fn http_accepts_gzip_json_logs() {
+    let body = gzip(
+        br#"{"resourceLogs": [{"scopeLogs": [{"logRecords": [
+            {"body": {"stringValue": "compressed"}}
+        ]}]}]}"#,
+    );
+    let (response, events) = http_post_encoded_logs("application/json", Some("gzip"), body).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+}
+
+/// Posts a compressed request to a source accepting only the `compression` algorithms.
+async fn http_post_logs_with_compression(
+    compression: &str,
+    content_encoding: &str,
+    body: Vec<u8>,
+) -> (reqwest::Response, Vec<Event>) {
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            http.compression = {}
+        "#},
+        next_addr(),
+        http_addr,
+        compression
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(http_addr).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/v1/logs", http_addr))
+        .header("content-type", "application/x-protobuf")
+        .header("content-encoding", content_encoding)
+        .body(body)
+        .send()
+        .await
+        .unwrap();
+    (response, test_util::collect_ready(logs_output).await)
+}
+
+#[tokio::test]
+async fn http_accepts_only_gzip_by_default() {
+    let request = compressed_logs_request().encode_to_vec();
+    let body = zstd::stream::encode_all(&request[..], 0).unwrap();
+    let (response, events) =
+        http_post_encoded_logs("application/x-protobuf", Some("zstd"), body).await;
+    assert_eq!(response.status(), 415);
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn http_rejects_compression_not_accepted() {
+    let request = compressed_logs_request().encode_to_vec();
+    let body = zstd::stream::encode_all(&request[..], 0).unwrap();
+    let (response, events) = http_post_logs_with_compression(r#"["gzip"]"#, "zstd", body).await;
+    assert_eq!(response.status(), 415);
+    assert!(events.is_empty());
+
+    // The accepted algorithms, and uncompressed requests, are still accepted.
+    let (response, events) =
+        http_post_logs_with_compression(r#"["gzip"]"#, "gzip", gzip(&request)).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+
+    let (response, events) = http_post_logs_with_compression("[]", "identity", request).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(events.len(), 1);
+}
+
+#[tokio::test]
+async fn http_rejects_malformed_gzip_logs() {
+    let mut body = gzip(&compressed_logs_request().encode_to_vec());
+    body.truncate(body.len() / 2);
+    let (response, events) =
+        http_post_encoded_logs("application/x-protobuf", Some("gzip"), body).await;
+    assert_eq!(response.status(), 400);
+    assert!(events.is_empty());
+
+    let mut body = gzip(br#"{"resourceLogs": []}"#);
+    body.truncate(body.len() / 2);
+    let (response, events) = http_post_encoded_logs("application/json", Some("gzip"), body).await;
+    assert_eq!(response.status(), 400);
+    let status = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        status["message"],
+        "Failed decompressing payload with gzip decoder."
+    );
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn grpc_accepts_gzip_logs() {
+    let grpc_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+        "#},
+        grpc_addr,
+        next_addr()
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+
+    let mut client = LogsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap()
+        .send_gzip();
+    client
+        .export(Request::new(compressed_logs_request()))
+        .await
+        .unwrap();
+
+    let events = test_util::collect_ready(logs_output).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+}
+
+#[tokio::test]
+async fn grpc_rejects_oversized_messages() {
+    let grpc_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            grpc.max_recv_message_size = 1024
+            http.address = "{}"
+        "#},
+        grpc_addr,
+        next_addr()
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+
+    let oversized = || ExportLogsServiceRequest {
+        resource_logs: vec![namespace_resource_logs(None, &"x".repeat(4096))],
+    };
+    let mut client = LogsServiceClient::connect(format!("http://{}", grpc_addr))
+        .await
+        .unwrap();
+    let status = client.export(Request::new(oversized())).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+
+    // The limit applies to the decompressed size of compressed messages.
+    let status = client
+        .clone()
+        .send_gzip()
+        .export(Request::new(oversized()))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+
+    client
+        .export(Request::new(compressed_logs_request()))
+        .await
+        .unwrap();
+    let events = test_util::collect_ready(logs_output).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+}
+
+#[tokio::test]
+async fn http_accepts_json_logs() {
+    let (response, events) = http_post_logs(
+        "application/json; charset=utf-8",
+        r#"{"resourceLogs": [{"scopeLogs": [{"logRecords": [{
+            "timeUnixNano": "1660000000000000000",
+            "body": {"stringValue": "clicked"},
+            "attributes": [{"key": "count", "value": {"intValue": "3"}}],
+            "traceId": "4ac52aadf321c2e531db005df08792f5",
+            "spanId": "0b9e4bda2a55530d"
+        }]}]}]}"#,
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.json::<serde_json::Value>().await.unwrap(),
+        serde_json::json!({})
+    );
+
+    assert_eq!(events.len(), 1);
+    let log = events[0].as_log();
+    assert_eq!(log["message"], "clicked".into());
+    assert_eq!(log["attributes.count"], 3.into());
+    assert_eq!(log["trace_id"], "4ac52aadf321c2e531db005df08792f5".into());
+    assert_eq!(log["span_id"], "0b9e4bda2a55530d".into());
+    assert_eq!(
+        log["timestamp"],
+        Utc.timestamp_nanos(1_660_000_000_000_000_000).into()
+    );
+}
+
+#[tokio::test]
+async fn http_rejects_invalid_json() {
+    let (response, events) = http_post_logs("application/json", r#"{"resourceLogs": ["#).await;
+    assert_eq!(response.status(), 400);
+    assert_eq!(response.headers()["content-type"], "application/json");
+    let status = response.json::<serde_json::Value>().await.unwrap();
+    assert!(status["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Could not decode request"));
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn http_json_reports_partial_success() {
+    let (response, events) = http_post_logs(
+        "application/json",
+        r#"{"resourceLogs": [{"scopeLogs": [{"logRecords": [
+            {"body": {"stringValue": "converted"}},
+            {"body": {"doubleValue": "NaN"}}
+        ]}]}]}"#,
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["partialSuccess"]["rejectedLogRecords"], "1");
+    assert_eq!(events.len(), 1);
+}
+
+#[tokio::test]
+async fn http_protobuf_reports_partial_success() {
+    let invalid_record = LogRecord {
+        body: Some(AnyValue {
+            value: Some(any_value::Value::DoubleValue(f64::NAN)),
+        }),
+        ..Default::default()
+    };
+    let mut request = compressed_logs_request();
+    request.resource_logs[0].scope_logs[0]
+        .log_records
+        .push(invalid_record);
+    let (response, events) =
+        http_post_encoded_logs("application/x-protobuf", None, request.encode_to_vec()).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["content-type"], "application/x-protobuf");
+    let body = response.bytes().await.unwrap();
+    let partial_success = ExportLogsServiceResponse::decode(body)
+        .unwrap()
+        .partial_success
+        .unwrap();
+    assert_eq!(partial_success.rejected_log_records, 1);
+    assert_eq!(
+        partial_success.error_message,
+        "1 log records could not be converted into events"
+    );
+    // The records that could be converted are still accepted.
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["message"], "compressed".into());
+}
+
+#[tokio::test]
+async fn http_rejects_unsupported_content_type() {
+    let (response, events) = http_post_logs("text/plain", "clicked").await;
+    assert_eq!(response.status(), 415);
+    assert!(events.is_empty());
+}
+
+/// Performs a TLS handshake with the given server, offering at most the given protocol version.
+async fn tls_handshake(
+    addr: std::net::SocketAddr,
+    max_version: openssl::ssl::SslVersion,
+) -> Result<(), openssl::ssl::Error> {
+    use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
+
+    let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    let mut connector = SslConnector::builder(SslMethod::tls()).unwrap();
+    connector.set_verify(SslVerifyMode::NONE);
+    connector.set_max_proto_version(Some(max_version)).unwrap();
+    let ssl = connector
+        .build()
+        .configure()
+        .unwrap()
+        .into_ssl("localhost")
+        .unwrap();
+
+    let mut stream = tokio_openssl::SslStream::new(ssl, stream).unwrap();
+    std::pin::Pin::new(&mut stream).connect().await
+}
+
+#[tokio::test]
+async fn grpc_rejects_tls_below_min_version() {
+    let grpc_addr = next_addr();
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            grpc.tls.enabled = true
+            grpc.tls.crt_file = "{}"
+            grpc.tls.key_file = "{}"
+            grpc.tls.min_tls_version = "TLSv1.3"
+            http.address = "{}"
+        "#},
+        grpc_addr,
+        crate::tls::TEST_PEM_CRT_PATH,
+        crate::tls::TEST_PEM_KEY_PATH,
+        http_addr
+    ))
+    .unwrap();
+    let (sender, _) = SourceSender::new_test();
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+
+    assert!(tls_handshake(grpc_addr, openssl::ssl::SslVersion::TLS1_2)
+        .await
+        .is_err());
+    assert!(tls_handshake(grpc_addr, openssl::ssl::SslVersion::TLS1_3)
+        .await
+        .is_ok());
+}
+
+fn new_source(
+    status: EventStatus,
+) -> (
+    SourceSender,
+    impl Stream<Item = Event>,
+    impl Stream<Item = Event>,
+) {
+    let (mut sender, recv) = SourceSender::new_test_finalize(status);
+    let logs_output = sender
+        .add_outputs(status, LOGS.to_string())
+        .flat_map(into_event_stream);
+    (sender, logs_output, recv)
+}
+
+fn str_into_hex_bytes(s: &str) -> Vec<u8> {
+    // unwrap is okay in test
+    hex::decode(s).unwrap()
+}
+
+fn vec_into_btmap(arr: Vec<(&'static str, Value)>) -> BTreeMap<String, Value> {
+    BTreeMap::from_iter(
+        arr.into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<Vec<(_, _)>>(),
+    )
+}
+
+#[test]
+fn field_mapping_renames_fields() {
+    let config: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        field_mapping.message = "body"
+        field_mapping.trace_id = "traceId"
+        field_mapping.span_id = "trace_id"
+        field_mapping.severity_text = "severity_text"
+    "#})
+    .unwrap();
+    let options = config.convert_options().unwrap();
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord {
+                    severity_text: "INFO".into(),
+                    body: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("clicked".into())),
+                    }),
+                    trace_id: vec![0x4a, 0xc5],
+                    span_id: vec![0x0b, 0x9e],
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+
+    let ConvertedRequest { logs, .. } = convert_request(request, &options);
+    let log = logs[0].as_log();
+    assert_eq!(log.get("body"), Some(&Value::from("clicked")));
+    assert_eq!(log.get("message"), None);
+    assert_eq!(log.get("traceId"), Some(&Value::from("4ac5")));
+    // Fields can take the default name of another renamed field.
+    assert_eq!(log.get("trace_id"), Some(&Value::from("0b9e")));
+    assert_eq!(log.get("span_id"), None);
+    assert_eq!(log.get("severity_text"), Some(&Value::from("INFO")));
+}
+
+#[test]
+fn attributes_and_resources_keys_relocate_fields() {
+    let request = || ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: Some(OtelResource {
+                attributes: vec![string_attribute("service.name", "checkout")],
+                dropped_attributes_count: 0,
+            }),
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord {
+                    severity_text: "INFO".into(),
+                    attributes: vec![
+                        string_attribute("user", "alice"),
+                        string_attribute("severity_text", "ignored"),
+                    ],
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+    let convert = |keys: &str| {
+        let config: OpentelemetryConfig = toml::from_str(&format!(
+            "grpc.address = \"127.0.0.1:4317\"\nhttp.address = \"127.0.0.1:4318\"\n{}",
+            keys
+        ))
+        .unwrap();
+        let ConvertedRequest { mut logs, .. } =
+            convert_request(request(), &config.convert_options().unwrap());
+        logs.remove(0).into_log()
+    };
+
+    let log = convert("");
+    assert_eq!(log.get("attributes.user"), Some(&Value::from("alice")));
+    assert_eq!(
+        log.get("resources.\"service.name\""),
+        Some(&Value::from("checkout"))
+    );
+
+    let log = convert("attributes_key = \"otel.attributes\"\nresources_key = \"otel.resources\"");
+    assert_eq!(log.get("otel.attributes.user"), Some(&Value::from("alice")));
+    assert_eq!(
+        log.get("otel.resources.\"service.name\""),
+        Some(&Value::from("checkout"))
+    );
+    assert_eq!(log.get("attributes"), None);
+    assert_eq!(log.get("resources"), None);
+
+    // Merged attributes don't replace the fields of the log record.
+    let log = convert("attributes_key = \"\"\nresources_key = \"\"");
+    assert_eq!(log.get("user"), Some(&Value::from("alice")));
+    assert_eq!(log.get("\"service.name\""), Some(&Value::from("checkout")));
+    assert_eq!(log.get("severity_text"), Some(&Value::from("INFO")));
+    assert_eq!(log.get("attributes"), None);
+    assert_eq!(log.get("resources"), None);
+}
+
+#[test]
+fn attributes_key_conflicts_with_field_mapping() {
+    let config: OpentelemetryConfig = toml::from_str(indoc::indoc! {r#"
+        grpc.address = "127.0.0.1:4317"
+        http.address = "127.0.0.1:4318"
+        attributes_key = "otel.attributes"
+        field_mapping.attributes = "attrs"
+    "#})
+    .unwrap();
+    let error = config.convert_options().err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "`attributes_key` can't be combined with `field_mapping.attributes`"
+    );
+}
+
+#[test]
+fn error_flag() {
+    let record = |severity_number: i32, attributes: Vec<KeyValue>| LogRecord {
+        severity_number,
+        attributes,
+        ..Default::default()
+    };
+    let request = ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![
+                    record(17, vec![]),
+                    record(9, vec![]),
+                    record(9, vec![string_attribute("exception.type", "IOError")]),
+                    record(0, vec![]),
+                ],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }],
+    };
+
+    let options = ConvertOptions {
+        error_flag: Some(ErrorFlagConfig {
+            min_severity_number: 17,
+            attributes: vec!["exception.type".into()],
+        }),
+        ..Default::default()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(request, &options);
+    let flags = logs
+        .iter()
+        .map(|event| event.as_log().get("is_error").cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        flags,
+        vec![
+            Some(Value::Boolean(true)),
+            None,
+            Some(Value::Boolean(true)),
+            None
+        ]
+    );
+}
+
+async fn start_client_auth_source() -> (SocketAddr, impl Stream<Item = Event>) {
+    let grpc_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            grpc.tls.enabled = true
+            grpc.tls.crt_file = "{}"
+            grpc.tls.key_file = "{}"
+            grpc.tls.client_metadata_key = "tls_peer"
+            grpc.client_auth.ca_file = "{}"
+            http.address = "{}"
+        "#},
+        grpc_addr,
+        crate::tls::TEST_PEM_CRT_PATH,
+        crate::tls::TEST_PEM_KEY_PATH,
+        crate::tls::TEST_PEM_CA_PATH,
+        next_addr()
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(grpc_addr).await;
+    (grpc_addr, logs_output)
+}
+
+async fn export_over_tls(
+    grpc_addr: SocketAddr,
+    identity: Option<Identity>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tls_config = ClientTlsConfig::new()
+        .ca_certificate(Certificate::from_pem(std::fs::read(
+            crate::tls::TEST_PEM_CA_PATH,
+        )?))
+        .domain_name("localhost");
+    if let Some(identity) = identity {
+        tls_config = tls_config.identity(identity);
+    }
+    let channel = Channel::from_shared(format!("https://{}", grpc_addr))?
+        .tls_config(tls_config)?
+        .connect()
+        .await?;
+    LogsServiceClient::new(channel)
+        .export(Request::new(compressed_logs_request()))
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn grpc_accepts_authenticated_clients() {
+    let (grpc_addr, logs_output) = start_client_auth_source().await;
+
+    let identity = Identity::from_pem(
+        std::fs::read(crate::tls::TEST_PEM_CLIENT_CRT_PATH).unwrap(),
+        std::fs::read(crate::tls::TEST_PEM_CLIENT_KEY_PATH).unwrap(),
+    );
+    export_over_tls(grpc_addr, Some(identity)).await.unwrap();
+
+    let events = test_util::collect_ready(logs_output).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].as_log()["tls_peer.subject"],
+        "CN=localhost,OU=Vector,O=Datadog,L=New York,ST=New York,C=US".into()
+    );
+}
+
+#[tokio::test]
+async fn grpc_rejects_unauthenticated_clients() {
+    let (grpc_addr, logs_output) = start_client_auth_source().await;
+
+    assert!(export_over_tls(grpc_addr, None).await.is_err());
+
+    assert!(test_util::collect_ready(logs_output).await.is_empty());
+}
+
+#[tokio::test]
+async fn http_attaches_tls_client_metadata() {
+    use openssl::ssl::{SslConnector, SslFiletype, SslMethod};
+
+    let http_addr = next_addr();
+    let source: OpentelemetryConfig = toml::from_str(&format!(
+        indoc::indoc! {r#"
+            grpc.address = "{}"
+            http.address = "{}"
+            http.tls.enabled = true
+            http.tls.crt_file = "{}"
+            http.tls.key_file = "{}"
+            http.tls.ca_file = "{}"
+            http.tls.verify_certificate = true
+            http.tls.client_metadata_key = "tls_peer"
+        "#},
+        next_addr(),
+        http_addr,
+        crate::tls::TEST_PEM_CRT_PATH,
+        crate::tls::TEST_PEM_KEY_PATH,
+        crate::tls::TEST_PEM_CA_PATH,
+    ))
+    .unwrap();
+    let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+    let server = source
+        .build(SourceContext::new_test(sender, None))
+        .await
+        .unwrap();
+    tokio::spawn(server);
+    test_util::wait_for_tcp(http_addr).await;
+
+    let mut connector = SslConnector::builder(SslMethod::tls()).unwrap();
+    connector.set_ca_file(crate::tls::TEST_PEM_CA_PATH).unwrap();
+    connector
+        .set_certificate_chain_file(crate::tls::TEST_PEM_CLIENT_CRT_PATH)
+        .unwrap();
+    connector
+        .set_private_key_file(crate::tls::TEST_PEM_CLIENT_KEY_PATH, SslFiletype::PEM)
+        .unwrap();
+    let ssl = connector
+        .build()
+        .configure()
+        .unwrap()
+        .into_ssl("localhost")
+        .unwrap();
+    let stream = tokio::net::TcpStream::connect(http_addr).await.unwrap();
+    let mut stream = tokio_openssl::SslStream::new(ssl, stream).unwrap();
+    std::pin::Pin::new(&mut stream).connect().await.unwrap();
+
+    let (mut client, connection) = hyper::client::conn::handshake(stream).await.unwrap();
+    tokio::spawn(connection);
+    let request = http::Request::post("/v1/logs")
+        .header("host", "localhost")
+        .header("content-type", "application/x-protobuf")
+        .body(hyper::Body::from(compressed_logs_request().encode_to_vec()))
+        .unwrap();
+    let response = client.send_request(request).await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    let events = test_util::collect_ready(logs_output).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].as_log()["tls_peer.subject"],
+        "CN=localhost,OU=Vector,O=Datadog,L=New York,ST=New York,C=US".into()
+    );
+}
+
+fn vector_namespace_request() -> ExportLogsServiceRequest {
+    ExportLogsServiceRequest {
+        resource_logs: vec![ResourceLogs {
+            resource: Some(OtelResource {
+                attributes: vec![string_attribute("service.name", "checkout")],
+                dropped_attributes_count: 0,
+            }),
+            scope_logs: vec![ScopeLogs {
+                scope: None,
+                log_records: vec![LogRecord {
+                    time_unix_nano: 1_579_134_612_000_000_011,
+                    severity_text: "WARN".into(),
+                    attributes: vec![string_attribute("user", "ferris")],
+                    body: Some(AnyValue {
+                        value: Some(any_value::Value::StringValue("payment declined".into())),
+                    }),
+                    trace_id: vec![1; 16],
+                    ..Default::default()
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: "https://opentelemetry.io/schemas/0.20.0".into(),
+        }],
+    }
+}
+
+#[test]
+fn converts_logs_into_vector_namespace() {
+    let options = ConvertOptions {
+        include_proto_version: true,
+        log_namespace: LogNamespace::Vector,
+        ..Default::default()
+    };
+    let ConvertedRequest { mut logs, .. } = convert_request(vector_namespace_request(), &options);
+    insert_user_agent_version(
+        &mut logs,
+        Some("OTel-OTLP-Exporter-Go/0.19.0"),
+        LogNamespace::Vector,
+    );
+    assert_eq!(logs.len(), 1);
+
+    let log = logs[0].as_log();
+    assert_eq!(log.value(), &Value::from("payment declined"));
+
+    let metadata = log.metadata().value();
+    let field = |path: &str| metadata.get(path).cloned();
+    assert_eq!(
+        field("opentelemetry.resources.\"service.name\""),
+        Some("checkout".into())
+    );
+    assert_eq!(
+        field("opentelemetry.attributes.user"),
+        Some("ferris".into())
+    );
+    assert_eq!(field("opentelemetry.severity_text"), Some("WARN".into()));
+    assert_eq!(
+        field("opentelemetry.trace_id"),
+        Some("01010101010101010101010101010101".into())
+    );
+    assert_eq!(
+        field("opentelemetry.timestamp"),
+        Some(Utc.timestamp_nanos(1_579_134_612_000_000_011).into())
+    );
+    // The version of the schema URL takes precedence over the user agent.
+    assert_eq!(field("opentelemetry.proto_version"), Some("v0.20.0".into()));
+    assert_eq!(field("vector.source_type"), Some("opentelemetry".into()));
+    assert!(field("vector.ingest_timestamp").is_some());
+}
+
+#[test]
+fn field_mapping_is_ignored_in_vector_namespace() {
+    let config = |field_mapping: &str| {
+        toml::from_str::<OpentelemetryConfig>(&format!(
+            indoc::indoc! {r#"
+                grpc.address = "127.0.0.1:4317"
+                http.address = "127.0.0.1:4318"
+                log_namespace = true
+                {}
+            "#},
+            field_mapping
+        ))
+        .unwrap()
+    };
+    let renamed = config(indoc::indoc! {r#"
+        attributes_key = ""
+        field_mapping.message = "body"
+        field_mapping.trace_id = "traceId"
+    "#});
+    let options = ConvertOptions {
+        log_namespace: LogNamespace::Vector,
+        ..renamed.convert_options().unwrap()
+    };
+    let ConvertedRequest { logs, .. } = convert_request(vector_namespace_request(), &options);
+    assert_eq!(logs.len(), 1);
+
+    let log = logs[0].as_log();
+    assert_eq!(log.value(), &Value::from("payment declined"));
+
+    let metadata = log.metadata().value();
+    let field = |path: &str| metadata.get(path).cloned();
+    assert_eq!(
+        field("opentelemetry.trace_id"),
+        Some("01010101010101010101010101010101".into())
+    );
+    assert_eq!(field("opentelemetry.traceId"), None);
+    assert_eq!(
+        field("opentelemetry.attributes.user"),
+        Some("ferris".into())
+    );
+
+    // The schema definition holds the fields under their default names either way.
+    let definition = |config: OpentelemetryConfig| {
+        config
+            .outputs(LogNamespace::Legacy)
+            .remove(0)
+            .log_schema_definition
+            .unwrap()
+    };
+    assert_eq!(definition(renamed), definition(config("")));
+}
+
+fn round_trip_request() -> ExportLogsServiceRequest {
+    let record = |body: &str, time_unix_nano| LogRecord {
+        time_unix_nano,
+        observed_time_unix_nano: time_unix_nano + 1,
+        severity_text: "INFO".into(),
+        attributes: vec![string_attribute("user", "ferris")],
+        body: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(body.into())),
+        }),
+        trace_id: vec![1; 16],
+        span_id: vec![2; 8],
+        ..Default::default()
+    };
+    ExportLogsServiceRequest {
+        resource_logs: vec![
+            ResourceLogs {
+                resource: Some(OtelResource {
+                    attributes: vec![string_attribute("service.name", "checkout")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_logs: vec![ScopeLogs {
+                    scope: Some(InstrumentationScope {
+                        name: "io.opentelemetry.example".into(),
+                        version: "1.2.3".into(),
+                        ..Default::default()
+                    }),
+                    log_records: vec![
+                        record("payment declined", 1_579_134_612_000_000_011),
+                        record("payment retried", 1_579_134_612_000_000_021),
+                    ],
+                    schema_url: "https://opentelemetry.io/schemas/1.9.0".into(),
+                }],
+                schema_url: String::new(),
+            },
+            ResourceLogs {
+                resource: Some(OtelResource {
+                    attributes: vec![string_attribute("service.name", "payments")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![record("card charged", 1_579_134_612_000_000_031)],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            },
+        ],
+    }
+}
+
+#[test]
+fn logs_round_trip_through_both_namespaces() {
+    for log_namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+        let options = ConvertOptions {
+            log_namespace,
+            ..Default::default()
+        };
+        let ConvertedRequest { logs, .. } = convert_request(round_trip_request(), &options);
+        assert_eq!(logs.len(), 3);
+
+        // The sink groups the records back by resource and scope.
+        let request = logs
+            .into_iter()
+            .map(Event::into_log)
+            .collect::<ExportLogsServiceRequest>();
+        assert_eq!(request, round_trip_request(), "{:?}", log_namespace);
+    }
+}
+
+#[test]
+fn logs_output_schema_definition_follows_log_namespace() {
+    let config = |log_namespace: &str| {
+        toml::from_str::<OpentelemetryConfig>(&format!(
+            indoc::indoc! {r#"
+                grpc.address = "127.0.0.1:4317"
+                http.address = "127.0.0.1:4318"
+                {}
+            "#},
+            log_namespace
+        ))
+        .unwrap()
+    };
+    let logs_namespaces = |config: OpentelemetryConfig, global_log_namespace| {
+        let output = config
+            .outputs(global_log_namespace)
+            .into_iter()
+            .find(|output| output.port.as_deref() == Some(LOGS))
+            .unwrap();
+        output
+            .log_schema_definition
+            .unwrap()
+            .log_namespaces()
+            .clone()
+    };
+
+    assert_eq!(
+        logs_namespaces(config(""), LogNamespace::Legacy),
+        [LogNamespace::Legacy].into()
+    );
+    assert_eq!(
+        logs_namespaces(config(""), LogNamespace::Vector),
+        [LogNamespace::Vector].into()
+    );
+    // The option of the source overrides the global setting.
+    assert_eq!(
+        logs_namespaces(config("log_namespace = true"), LogNamespace::Legacy),
+        [LogNamespace::Vector].into()
+    );
+
+    let definition = config("log_namespace = true")
+        .outputs(LogNamespace::Legacy)
+        .remove(0)
+        .log_schema_definition
+        .unwrap();
+    assert_eq!(definition.event_kind(), &value::Kind::any());
+    assert!(definition
+        .metadata_kind()
+        .at_path("opentelemetry.severity_text")
+        .contains_bytes());
 }
@@ -0,0 +1,213 @@
+//! ICMP echo requests, as sent by `ping`.
+//!
+//! Raw ICMP sockets need the `CAP_NET_RAW` capability, so the probe falls back to the unprivileged
+//! ICMP sockets supported by Linux and macOS when they aren't permitted. On Linux, these are only
+//! available to the groups in the `net.ipv4.ping_group_range` sysctl.
+
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+};
+
+use snafu::{ResultExt, Snafu};
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::UdpSocket;
+
+const ECHO_REQUEST_V4: u8 = 8;
+const ECHO_REPLY_V4: u8 = 0;
+const ECHO_REQUEST_V6: u8 = 128;
+const ECHO_REPLY_V6: u8 = 129;
+
+/// The length of the header of echo requests and replies.
+const HEADER_LEN: usize = 8;
+
+const PAYLOAD: &[u8] = b"vector probe";
+
+#[derive(Debug, Snafu)]
+pub enum IcmpError {
+    #[snafu(display(
+        "Permission denied opening an ICMP socket. ICMP probes need either the CAP_NET_RAW \
+         capability, or unprivileged ICMP sockets, which Linux only allows to the groups in the \
+         `net.ipv4.ping_group_range` sysctl"
+    ))]
+    PermissionDenied,
+    #[snafu(display("Failed to open ICMP socket: {}", source))]
+    OpenSocket { source: io::Error },
+    #[snafu(display("Failed to send echo request: {}", source))]
+    Send { source: io::Error },
+    #[snafu(display("Failed to receive echo reply: {}", source))]
+    Receive { source: io::Error },
+}
+
+/// An ICMP socket.
+pub struct IcmpSocket {
+    socket: UdpSocket,
+    /// Whether the socket is a raw socket, which receives all ICMP packets instead of only the
+    /// replies to its own requests.
+    raw: bool,
+}
+
+impl IcmpSocket {
+    /// Opens a socket to ping `ip`, falling back to an unprivileged socket if raw sockets aren't
+    /// permitted.
+    pub fn open(ip: IpAddr) -> Result<Self, IcmpError> {
+        let (domain, protocol) = match ip {
+            IpAddr::V4(_) => (Domain::IPV4, Protocol::ICMPV4),
+            IpAddr::V6(_) => (Domain::IPV6, Protocol::ICMPV6),
+        };
+
+        let (socket, raw) = match Socket::new(domain, Type::RAW, Some(protocol)) {
+            Ok(socket) => (socket, true),
+            Err(error) if is_permission_denied(&error) => {
+                match Socket::new(domain, Type::DGRAM, Some(protocol)) {
+                    Ok(socket) => (socket, false),
+                    Err(error) if is_permission_denied(&error) => {
+                        return Err(IcmpError::PermissionDenied)
+                    }
+                    Err(source) => return Err(IcmpError::OpenSocket { source }),
+                }
+            }
+            Err(source) => return Err(IcmpError::OpenSocket { source }),
+        };
+
+        socket.set_nonblocking(true).context(OpenSocketSnafu)?;
+        let socket = UdpSocket::from_std(socket.into()).context(OpenSocketSnafu)?;
+        Ok(Self { socket, raw })
+    }
+
+    /// Sends an echo request to `ip`, waiting for its reply.
+    pub async fn ping(&self, ip: IpAddr, identifier: u16, sequence: u16) -> Result<(), IcmpError> {
+        let request = echo_request(ip, identifier, sequence);
+        self.socket
+            .send_to(&request, SocketAddr::new(ip, 0))
+            .await
+            .context(SendSnafu)?;
+
+        let mut buffer = [0; 1024];
+        loop {
+            let (len, from) = self
+                .socket
+                .recv_from(&mut buffer)
+                .await
+                .context(ReceiveSnafu)?;
+            if from.ip() != ip {
+                continue;
+            }
+            // Unprivileged sockets replace the identifier with their own, and only receive the
+            // replies to their requests.
+            match parse_echo_reply(ip, &buffer[..len]) {
+                Some((reply_identifier, reply_sequence))
+                    if reply_sequence == sequence
+                        && (!self.raw || reply_identifier == identifier) =>
+                {
+                    return Ok(())
+                }
+                _ => continue,
+            }
+        }
+    }
+}
+
+fn is_permission_denied(error: &io::Error) -> bool {
+    // Both `EPERM` and `EACCES` are reported as `PermissionDenied`.
+    error.kind() == io::ErrorKind::PermissionDenied
+}
+
+fn echo_request(ip: IpAddr, identifier: u16, sequence: u16) -> Vec<u8> {
+    let kind = match ip {
+        IpAddr::V4(_) => ECHO_REQUEST_V4,
+        IpAddr::V6(_) => ECHO_REQUEST_V6,
+    };
+
+    let mut packet = Vec::with_capacity(HEADER_LEN + PAYLOAD.len());
+    packet.extend([kind, 0, 0, 0]);
+    packet.extend(identifier.to_be_bytes());
+    packet.extend(sequence.to_be_bytes());
+    packet.extend(PAYLOAD);
+
+    // The kernel computes the checksum of ICMPv6 packets, as it covers the IPv6 pseudo-header.
+    if ip.is_ipv4() {
+        let checksum = checksum(&packet);
+        packet[2..4].copy_from_slice(&checksum.to_be_bytes());
+    }
+    packet
+}
+
+/// Computes the internet checksum, as described in RFC 1071.
+fn checksum(data: &[u8]) -> u16 {
+    let mut sum = data
+        .chunks(2)
+        .map(|chunk| u32::from(u16::from_be_bytes([chunk[0], *chunk.get(1).unwrap_or(&0)])))
+        .sum::<u32>();
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+/// Parses an echo reply, returning its identifier and sequence number.
+fn parse_echo_reply(ip: IpAddr, packet: &[u8]) -> Option<(u16, u16)> {
+    let (packet, reply) = match ip {
+        IpAddr::V4(_) => {
+            // Raw IPv4 sockets, as well as unprivileged ones on macOS, receive the IP header along
+            // with the ICMP packet.
+            let packet = match packet.first() {
+                Some(byte) if byte >> 4 == 4 => packet.get(usize::from(byte & 0x0f) * 4..)?,
+                _ => packet,
+            };
+            (packet, ECHO_REPLY_V4)
+        }
+        IpAddr::V6(_) => (packet, ECHO_REPLY_V6),
+    };
+
+    (packet.len() >= HEADER_LEN && packet[0] == reply).then(|| {
+        (
+            u16::from_be_bytes([packet[4], packet[5]]),
+            u16::from_be_bytes([packet[6], packet[7]]),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    const V4: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    const V6: IpAddr = IpAddr::V6(Ipv6Addr::LOCALHOST);
+
+    #[test]
+    fn echo_request_checksum() {
+        let request = echo_request(V4, 0x1234, 7);
+        assert_eq!(&request[..2], &[ECHO_REQUEST_V4, 0]);
+        assert_eq!(&request[4..8], &[0x12, 0x34, 0, 7]);
+        // The checksum of a packet including its checksum is zero.
+        assert_eq!(checksum(&request), 0);
+
+        let request = echo_request(V6, 0x1234, 7);
+        assert_eq!(&request[..4], &[ECHO_REQUEST_V6, 0, 0, 0]);
+    }
+
+    #[test]
+    fn parses_echo_replies() {
+        let mut reply = echo_request(V4, 0x1234, 7);
+        reply[0] = ECHO_REPLY_V4;
+        assert_eq!(parse_echo_reply(V4, &reply), Some((0x1234, 7)));
+
+        // With the IP header received by raw sockets.
+        let mut packet = vec![0x45];
+        packet.extend([0; 19]);
+        packet.extend(&reply);
+        assert_eq!(parse_echo_reply(V4, &packet), Some((0x1234, 7)));
+
+        // Requests aren't replies, such as those looped back by raw sockets.
+        let request = echo_request(V4, 0x1234, 7);
+        assert_eq!(parse_echo_reply(V4, &request), None);
+        assert_eq!(parse_echo_reply(V4, &reply[..4]), None);
+
+        let mut reply = echo_request(V6, 0x1234, 7);
+        reply[0] = ECHO_REPLY_V6;
+        assert_eq!(parse_echo_reply(V6, &reply), Some((0x1234, 7)));
+    }
+}
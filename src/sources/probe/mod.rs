@@ -0,0 +1,607 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::join_all, StreamExt};
+use http::{Request, StatusCode, Uri};
+use hyper::Body;
+use rand::Rng;
+use snafu::{ResultExt, Snafu};
+use tokio::{
+    net::{lookup_host, TcpStream},
+    time::{self, Instant, MissedTickBehavior},
+};
+use tokio_stream::wrappers::IntervalStream;
+use vector_config::configurable_component;
+use vector_core::{config::LogNamespace, internal_event::EventsReceived, ByteSizeOf};
+
+use crate::{
+    config::{DataType, Output, SourceConfig, SourceContext, SourceDescription},
+    event::metric::{Metric, MetricKind, MetricValue},
+    http::{HttpClient, HttpError},
+    internal_events::{ProbeFailed, ProbeTargetStateChanged, StreamClosedError},
+    shutdown::ShutdownSignal,
+    tls::{TlsConfig, TlsSettings},
+    SourceSender,
+};
+
+mod icmp;
+
+use icmp::{IcmpError, IcmpSocket};
+
+const NAMESPACE: &str = "probe";
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("`interval_secs` must be greater than 0"))]
+    ZeroInterval,
+    #[snafu(display("`timeout_secs` must be greater than 0"))]
+    ZeroTimeout,
+    #[snafu(display("Invalid URL {:?}: {}", url, source))]
+    InvalidUrl {
+        url: String,
+        source: http::uri::InvalidUri,
+    },
+    #[snafu(display("Can't probe ICMP targets: {}", source))]
+    IcmpUnavailable { source: IcmpError },
+}
+
+/// The reason a probe failed.
+#[derive(Debug, Snafu)]
+pub enum ProbeError {
+    #[snafu(display("Timed out"))]
+    TimedOut,
+    #[snafu(display("Failed to resolve host: {}", source))]
+    Resolve { source: io::Error },
+    #[snafu(display("No address found for host"))]
+    NoAddress,
+    #[snafu(display("Failed to connect: {}", source))]
+    Connect { source: io::Error },
+    #[snafu(display("{}", source))]
+    Icmp { source: IcmpError },
+    #[snafu(display("HTTP request failed: {}", source))]
+    Request { source: HttpError },
+    #[snafu(display("Unexpected HTTP status: {}", status))]
+    HttpStatus { status: StatusCode },
+}
+
+/// Configuration for the `probe` source.
+#[configurable_component(source)]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields)]
+pub struct ProbeConfig {
+    /// The targets to probe.
+    targets: Vec<ProbeTargetConfig>,
+
+    /// The interval between probes of each target, in seconds.
+    ///
+    /// The first probe of each target happens at a random offset within the interval, so that the
+    /// targets aren't all probed at once.
+    #[serde(default = "default_interval_secs")]
+    #[derivative(Default(value = "default_interval_secs()"))]
+    interval_secs: u64,
+
+    /// The time to wait for a probe to succeed before considering it failed, in seconds.
+    #[serde(default = "default_timeout_secs")]
+    #[derivative(Default(value = "default_timeout_secs()"))]
+    timeout_secs: u64,
+
+    #[configurable(derived)]
+    tls: Option<TlsConfig>,
+}
+
+/// A target to probe.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "type")]
+pub enum ProbeTargetConfig {
+    /// Sends an ICMP echo request, succeeding when the host replies.
+    ///
+    /// ICMP probes need either the `CAP_NET_RAW` capability, or unprivileged ICMP sockets.
+    Icmp {
+        /// The hostname or IP address of the host.
+        host: String,
+    },
+
+    /// Opens a TCP connection, succeeding once it's established.
+    Tcp {
+        /// The address to connect to, as `host:port`.
+        address: String,
+    },
+
+    /// Sends an HTTP `GET` request, succeeding when the response has a `2xx` status code.
+    Http {
+        /// The URL to request.
+        url: String,
+    },
+}
+
+const fn default_interval_secs() -> u64 {
+    15
+}
+
+const fn default_timeout_secs() -> u64 {
+    5
+}
+
+inventory::submit! {
+    SourceDescription::new::<ProbeConfig>("probe")
+}
+
+impl_generate_config_from_default!(ProbeConfig);
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "probe")]
+impl SourceConfig for ProbeConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        if self.interval_secs == 0 {
+            return Err(BuildError::ZeroInterval.into());
+        }
+        if self.timeout_secs == 0 {
+            return Err(BuildError::ZeroTimeout.into());
+        }
+
+        let targets = self
+            .targets
+            .iter()
+            .map(Target::new)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Fail right away if ICMP sockets aren't permitted, rather than on every probe.
+        if targets
+            .iter()
+            .any(|target| matches!(target.probe, Probe::Icmp { .. }))
+        {
+            if let Err(source @ IcmpError::PermissionDenied) =
+                IcmpSocket::open(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+            {
+                return Err(BuildError::IcmpUnavailable { source }.into());
+            }
+        }
+
+        let tls = TlsSettings::from_options(&self.tls)?;
+        let client = HttpClient::new(tls, &cx.proxy)?;
+        let interval = Duration::from_secs(self.interval_secs);
+        let timeout = Duration::from_secs(self.timeout_secs);
+        let out = cx.out;
+        let shutdown = cx.shutdown;
+
+        Ok(Box::pin(async move {
+            let targets = targets.into_iter().map(|target| {
+                target.run(
+                    client.clone(),
+                    interval,
+                    timeout,
+                    out.clone(),
+                    shutdown.clone(),
+                )
+            });
+            join_all(targets).await.into_iter().collect()
+        }))
+    }
+
+    fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
+        vec![Output::default(DataType::Metric)]
+    }
+
+    fn source_type(&self) -> &'static str {
+        "probe"
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug)]
+enum Probe {
+    Icmp { host: String },
+    Tcp { address: String },
+    Http { uri: Uri },
+}
+
+impl Probe {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Icmp { .. } => "icmp",
+            Self::Tcp { .. } => "tcp",
+            Self::Http { .. } => "http",
+        }
+    }
+}
+
+/// A probed target, along with its state across probes.
+#[derive(Debug)]
+struct Target {
+    probe: Probe,
+    name: String,
+    tags: BTreeMap<String, String>,
+    /// The identifier of the ICMP echo requests sent to the target.
+    identifier: u16,
+    /// The sequence number of the next ICMP echo request.
+    sequence: u16,
+    /// Whether the previous probe succeeded.
+    up: Option<bool>,
+}
+
+impl Target {
+    fn new(config: &ProbeTargetConfig) -> Result<Self, BuildError> {
+        let (probe, name) = match config {
+            ProbeTargetConfig::Icmp { host } => (Probe::Icmp { host: host.clone() }, host),
+            ProbeTargetConfig::Tcp { address } => (
+                Probe::Tcp {
+                    address: address.clone(),
+                },
+                address,
+            ),
+            ProbeTargetConfig::Http { url } => (
+                Probe::Http {
+                    uri: url.parse().context(InvalidUrlSnafu { url })?,
+                },
+                url,
+            ),
+        };
+
+        let mut tags = BTreeMap::new();
+        tags.insert("target".to_owned(), name.clone());
+        tags.insert("probe".to_owned(), probe.name().to_owned());
+
+        Ok(Self {
+            probe,
+            name: name.clone(),
+            tags,
+            identifier: rand::thread_rng().gen(),
+            sequence: 0,
+            up: None,
+        })
+    }
+
+    /// Probes the target on every interval until the source shuts down.
+    async fn run(
+        mut self,
+        client: HttpClient,
+        interval: Duration,
+        timeout: Duration,
+        mut out: SourceSender,
+        shutdown: ShutdownSignal,
+    ) -> Result<(), ()> {
+        let splay = interval.mul_f64(rand::thread_rng().gen_range(0.0..1.0));
+        let mut interval = time::interval_at(Instant::now() + splay, interval);
+        // A probe taking longer than the interval delays the next one, instead of causing a burst.
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut ticks = IntervalStream::new(interval).take_until(shutdown);
+        while ticks.next().await.is_some() {
+            let metrics = self.probe(&client, timeout).await;
+            let count = metrics.len();
+            emit!(EventsReceived {
+                count,
+                byte_size: metrics.size_of(),
+            });
+
+            if let Err(error) = out.send_batch(metrics).await {
+                emit!(StreamClosedError { error, count });
+                return Err(());
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn probe(&mut self, client: &HttpClient, timeout: Duration) -> Vec<Metric> {
+        let sequence = self.sequence;
+        self.sequence = self.sequence.wrapping_add(1);
+
+        let start = Instant::now();
+        let mut status = None;
+        let result = time::timeout(timeout, async {
+            match &self.probe {
+                Probe::Icmp { host } => probe_icmp(host, self.identifier, sequence).await,
+                Probe::Tcp { address } => probe_tcp(address).await,
+                Probe::Http { uri } => {
+                    let code = probe_http(client, uri).await?;
+                    status = Some(code);
+                    if code.is_success() {
+                        Ok(())
+                    } else {
+                        Err(ProbeError::HttpStatus { status: code })
+                    }
+                }
+            }
+        })
+        .await
+        .unwrap_or(Err(ProbeError::TimedOut));
+        let duration = start.elapsed();
+
+        let success = result.is_ok();
+        if let Err(error) = &result {
+            emit!(ProbeFailed {
+                target: &self.name,
+                probe: self.probe.name(),
+                error,
+            });
+        }
+        if self.up.map_or(!success, |up| up != success) {
+            emit!(ProbeTargetStateChanged {
+                target: &self.name,
+                probe: self.probe.name(),
+                up: success,
+            });
+        }
+        self.up = Some(success);
+
+        let timestamp = Utc::now();
+        let mut metrics = vec![
+            self.create_metric("success", if success { 1.0 } else { 0.0 }, timestamp),
+            self.create_metric("duration_seconds", duration.as_secs_f64(), timestamp),
+        ];
+        if let Some(status) = status {
+            metrics.push(self.create_metric(
+                "http_status_code",
+                f64::from(status.as_u16()),
+                timestamp,
+            ));
+        }
+        metrics
+    }
+
+    fn create_metric(&self, name: &str, value: f64, timestamp: DateTime<Utc>) -> Metric {
+        Metric::new(name, MetricKind::Absolute, MetricValue::Gauge { value })
+            .with_namespace(Some(NAMESPACE))
+            .with_tags(Some(self.tags.clone()))
+            .with_timestamp(Some(timestamp))
+    }
+}
+
+async fn probe_icmp(host: &str, identifier: u16, sequence: u16) -> Result<(), ProbeError> {
+    let ip = match host.parse() {
+        Ok(ip) => ip,
+        Err(_) => lookup_host((host, 0))
+            .await
+            .context(ResolveSnafu)?
+            .next()
+            .ok_or(ProbeError::NoAddress)?
+            .ip(),
+    };
+
+    let socket = IcmpSocket::open(ip).context(IcmpSnafu)?;
+    socket
+        .ping(ip, identifier, sequence)
+        .await
+        .context(IcmpSnafu)
+}
+
+async fn probe_tcp(address: &str) -> Result<(), ProbeError> {
+    TcpStream::connect(address).await.context(ConnectSnafu)?;
+    Ok(())
+}
+
+async fn probe_http(client: &HttpClient, uri: &Uri) -> Result<StatusCode, ProbeError> {
+    let request = Request::get(uri.clone())
+        .body(Body::empty())
+        .expect("a GET request without headers is valid");
+    let response = client.send(request).await.context(RequestSnafu)?;
+    Ok(response.status())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, net::SocketAddr};
+
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Response, Server,
+    };
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::{
+        config::ProxyConfig,
+        event::Event,
+        test_util::{collect_ready, next_addr},
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    fn client() -> HttpClient {
+        HttpClient::new(None, &ProxyConfig::default()).unwrap()
+    }
+
+    /// Probes a target once, returning its metrics keyed by name.
+    async fn probe(config: ProbeTargetConfig) -> BTreeMap<String, f64> {
+        let mut target = Target::new(&config).unwrap();
+        let metrics = target.probe(&client(), TIMEOUT).await;
+
+        metrics
+            .into_iter()
+            .map(|metric| {
+                assert_eq!(metric.namespace(), Some(NAMESPACE));
+                assert_eq!(metric.tag_value("probe").unwrap(), target.probe.name());
+                assert_eq!(metric.tag_value("target").unwrap(), target.name);
+                match metric.value() {
+                    MetricValue::Gauge { value } => (metric.name().to_owned(), *value),
+                    value => panic!("unexpected metric value {:?}", value),
+                }
+            })
+            .collect()
+    }
+
+    fn serve_status(address: SocketAddr, status: StatusCode) {
+        let service = make_service_fn(move |_| async move {
+            Ok::<_, Infallible>(service_fn(move |_| async move {
+                Ok::<_, Infallible>(
+                    Response::builder()
+                        .status(status)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+            }))
+        });
+        tokio::spawn(Server::bind(&address).serve(service));
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ProbeConfig>();
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_config() {
+        for (config, expected) in [
+            (
+                "interval_secs = 0\ntargets = []",
+                "`interval_secs` must be greater than 0",
+            ),
+            (
+                "timeout_secs = 0\ntargets = []",
+                "`timeout_secs` must be greater than 0",
+            ),
+            (
+                "targets = [{ type = \"http\", url = \"http://[::1\" }]",
+                "Invalid URL \"http://[::1\"",
+            ),
+        ] {
+            let config: ProbeConfig = toml::from_str(config).unwrap();
+            let (out, _) = SourceSender::new_test();
+            let error = config
+                .build(SourceContext::new_test(out, None))
+                .await
+                .err()
+                .unwrap();
+            assert!(error.to_string().starts_with(expected), "{}", error);
+        }
+    }
+
+    #[tokio::test]
+    async fn tcp_probe() {
+        let address = next_addr();
+        let listener = TcpListener::bind(address).await.unwrap();
+        let metrics = probe(ProbeTargetConfig::Tcp {
+            address: address.to_string(),
+        })
+        .await;
+        assert_eq!(metrics["success"], 1.0);
+        assert!(metrics["duration_seconds"] < TIMEOUT.as_secs_f64());
+        assert!(!metrics.contains_key("http_status_code"));
+
+        drop(listener);
+        let metrics = probe(ProbeTargetConfig::Tcp {
+            address: address.to_string(),
+        })
+        .await;
+        assert_eq!(metrics["success"], 0.0);
+    }
+
+    #[tokio::test]
+    async fn http_probe() {
+        let ok = next_addr();
+        let unavailable = next_addr();
+        serve_status(ok, StatusCode::OK);
+        serve_status(unavailable, StatusCode::SERVICE_UNAVAILABLE);
+        crate::test_util::wait_for_tcp(ok).await;
+        crate::test_util::wait_for_tcp(unavailable).await;
+
+        let metrics = probe(ProbeTargetConfig::Http {
+            url: format!("http://{}/health", ok),
+        })
+        .await;
+        assert_eq!(metrics["success"], 1.0);
+        assert_eq!(metrics["http_status_code"], 200.0);
+
+        let metrics = probe(ProbeTargetConfig::Http {
+            url: format!("http://{}/health", unavailable),
+        })
+        .await;
+        assert_eq!(metrics["success"], 0.0);
+        assert_eq!(metrics["http_status_code"], 503.0);
+
+        // Without a response, there's no status code.
+        let metrics = probe(ProbeTargetConfig::Http {
+            url: format!("http://{}/health", next_addr()),
+        })
+        .await;
+        assert_eq!(metrics["success"], 0.0);
+        assert!(!metrics.contains_key("http_status_code"));
+    }
+
+    #[tokio::test]
+    async fn http_probe_times_out() {
+        let address = next_addr();
+        // Connections are accepted, but never answered.
+        let _listener = TcpListener::bind(address).await.unwrap();
+
+        let metrics = probe(ProbeTargetConfig::Http {
+            url: format!("http://{}/", address),
+        })
+        .await;
+        assert_eq!(metrics["success"], 0.0);
+        assert!(metrics["duration_seconds"] >= TIMEOUT.as_secs_f64());
+    }
+
+    #[tokio::test]
+    async fn icmp_probe() {
+        // ICMP sockets may not be permitted in the test environment.
+        if let Err(IcmpError::PermissionDenied) = IcmpSocket::open(Ipv4Addr::LOCALHOST.into()) {
+            return;
+        }
+
+        let metrics = probe(ProbeTargetConfig::Icmp {
+            host: "127.0.0.1".to_owned(),
+        })
+        .await;
+        assert_eq!(metrics["success"], 1.0);
+    }
+
+    #[tokio::test]
+    async fn probes_targets_concurrently() {
+        let up = next_addr();
+        let _listener = TcpListener::bind(up).await.unwrap();
+        let down = next_addr();
+
+        let config = ProbeConfig {
+            targets: vec![
+                ProbeTargetConfig::Tcp {
+                    address: up.to_string(),
+                },
+                ProbeTargetConfig::Tcp {
+                    address: down.to_string(),
+                },
+            ],
+            interval_secs: 1,
+            timeout_secs: 1,
+            tls: None,
+        };
+        let (out, rx) = SourceSender::new_test();
+        let (cx, mut shutdown) = SourceContext::new_shutdown(&"probe".into(), out);
+        let source = tokio::spawn(config.build(cx).await.unwrap());
+
+        // Each target is probed once within the first interval.
+        time::sleep(Duration::from_millis(1500)).await;
+        shutdown
+            .shutdown_source(&"probe".into(), Instant::now())
+            .await;
+        source.await.unwrap().unwrap();
+
+        let events = collect_ready(rx).await;
+        let successes = events
+            .iter()
+            .map(Event::as_metric)
+            .filter(|metric| metric.name() == "success")
+            .map(|metric| {
+                let value = match metric.value() {
+                    MetricValue::Gauge { value } => *value,
+                    value => panic!("unexpected metric value {:?}", value),
+                };
+                (metric.tag_value("target").unwrap(), value)
+            })
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(successes.len(), 2);
+        assert_eq!(successes[&up.to_string()], 1.0);
+        assert_eq!(successes[&down.to_string()], 0.0);
+    }
+}
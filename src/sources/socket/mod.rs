@@ -138,6 +138,7 @@ impl SourceConfig for SocketConfig {
                 let tls = MaybeTlsSettings::from_config(&tls_config, true)?;
                 tcp.run(
                     config.address(),
+                    config.v6_only(),
                     config.keepalive(),
                     config.shutdown_timeout_secs(),
                     tls,
@@ -146,6 +147,7 @@ impl SourceConfig for SocketConfig {
                     cx,
                     false.into(),
                     config.connection_limit,
+                    config.max_events_per_second,
                 )
             }
             Mode::Udp(config) => {
@@ -235,7 +237,7 @@ impl SourceConfig for SocketConfig {
     fn resources(&self) -> Vec<Resource> {
         match self.mode.clone() {
             Mode::Tcp(tcp) => vec![tcp.address().into()],
-            Mode::Udp(udp) => vec![Resource::udp(udp.address())],
+            Mode::Udp(udp) => vec![udp.address().into()],
             #[cfg(unix)]
             Mode::UnixDatagram(_) => vec![],
             #[cfg(unix)]
@@ -253,6 +255,7 @@ mod test {
     use std::{
         collections::{BTreeMap, HashMap},
         net::{SocketAddr, UdpSocket},
+        num::NonZeroU32,
         sync::{
             atomic::{AtomicBool, Ordering},
             Arc,
@@ -266,8 +269,10 @@ mod test {
     use codecs::{decoding::CharacterDelimitedDecoderOptions, CharacterDelimitedDecoderConfig};
     use futures::{stream, StreamExt};
     use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt as _},
+        net::TcpStream,
         task::JoinHandle,
-        time::{timeout, Duration, Instant},
+        time::{sleep, timeout, Duration, Instant},
     };
     use vector_common::btreemap;
     use vector_core::event::EventContainer;
@@ -293,8 +298,9 @@ mod test {
         event::{Event, LogEvent},
         shutdown::{ShutdownSignal, SourceShutdownCoordinator},
         sinks::util::tcp::TcpSinkConfig,
+        sources::util::UdpListenAddr,
         test_util::{
-            collect_n, collect_n_limited,
+            collect_n, collect_n_limited, collect_ready,
             components::{assert_source_compliance, SOCKET_HIGH_CARDINALITY_PUSH_SOURCE_TAGS},
             next_addr, random_string, send_lines, send_lines_tls, wait_for_tcp,
         },
@@ -487,6 +493,118 @@ mod test {
         .await;
     }
 
+    #[tokio::test]
+    async fn tcp_connection_limit_released_after_tls_handshake_failure() {
+        let (tx, mut rx) = SourceSender::new_test();
+        let addr = next_addr();
+
+        let mut config = TcpConfig::from_address(addr.into());
+        config.connection_limit = Some(1);
+        config.set_tls(Some(TlsSourceConfig {
+            tls_config: TlsEnableableConfig {
+                enabled: Some(true),
+                options: TlsConfig {
+                    crt_file: Some(tls::TEST_PEM_CRT_PATH.into()),
+                    key_file: Some(tls::TEST_PEM_KEY_PATH.into()),
+                    ..Default::default()
+                },
+            },
+            client_metadata_key: None,
+        }));
+
+        let server = SocketConfig::from(config)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+
+        wait_for_tcp(addr).await;
+
+        // Each failed handshake must give back its connection slot, or the source would stop
+        // accepting connections after the first one.
+        for _ in 0..3 {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream.write_all(b"not a TLS handshake\n").await.unwrap();
+            let mut buf = Vec::new();
+            let _ = timeout(Duration::from_secs(5), stream.read_to_end(&mut buf))
+                .await
+                .expect("connection should be closed after the failed handshake");
+        }
+
+        send_lines_tls(
+            addr,
+            "localhost".into(),
+            vec!["one line".to_owned()].into_iter(),
+            std::path::Path::new(tls::TEST_PEM_CA_PATH),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let event = timeout(Duration::from_secs(5), rx.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            event.as_log()[log_schema().message_key()],
+            "one line".into()
+        );
+    }
+
+    #[tokio::test]
+    async fn tcp_throttles_noisy_connection() {
+        let (tx, rx) = SourceSender::new_test();
+        let addr = next_addr();
+
+        let mut config = TcpConfig::from_address(addr.into());
+        config.max_events_per_second = NonZeroU32::new(10);
+
+        let server = SocketConfig::from(config)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+
+        wait_for_tcp(addr).await;
+
+        // One noisy client keeps sending far more than its limit...
+        let noisy = tokio::spawn(async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            for i in 0..1000 {
+                let line = format!("noisy {}\n", i);
+                if stream.write_all(line.as_bytes()).await.is_err() {
+                    break;
+                }
+                sleep(Duration::from_millis(1)).await;
+            }
+        });
+
+        // ...while many quiet clients each send a single line, and must not be held back by it.
+        for i in 0..50 {
+            send_lines(addr, vec![format!("quiet {}", i)].into_iter())
+                .await
+                .unwrap();
+        }
+
+        sleep(Duration::from_secs(1)).await;
+        let events = collect_ready(rx).await;
+        noisy.abort();
+
+        let (quiet, noisy): (Vec<_>, Vec<_>) = events.iter().partition(|event| {
+            event.as_log()[log_schema().message_key()]
+                .to_string_lossy()
+                .starts_with("quiet")
+        });
+        assert_eq!(quiet.len(), 50);
+        assert!(!noisy.is_empty());
+        assert!(
+            noisy.len() < 300,
+            "noisy connection was not throttled, read {} events",
+            noisy.len()
+        );
+    }
+
     #[tokio::test]
     async fn tcp_shutdown_simple() {
         assert_source_compliance(&SOCKET_HIGH_CARDINALITY_PUSH_SOURCE_TAGS, async {
@@ -673,7 +791,10 @@ mod test {
         config: Option<UdpConfig>,
     ) -> (SocketAddr, JoinHandle<Result<(), ()>>) {
         let (address, config) = match config {
-            Some(config) => (config.address(), config),
+            Some(config) => match config.address() {
+                UdpListenAddr::SocketAddr(address) => (address, config),
+                UdpListenAddr::Host(_) => panic!("tests listen on socket addresses"),
+            },
             None => {
                 let address = next_addr();
                 (address, UdpConfig::from_address(address))
@@ -1,3 +1,5 @@
+use std::num::NonZeroU32;
+
 use bytes::Bytes;
 use chrono::Utc;
 use codecs::decoding::{DeserializerConfig, FramingConfig};
@@ -21,6 +23,12 @@ pub struct TcpConfig {
     /// The address to listen for connections on.
     address: SocketListenAddr,
 
+    /// Whether an IPv6 listening socket only accepts IPv6 connections, by setting `IPV6_V6ONLY`.
+    ///
+    /// When disabled, a socket listening on `[::]` also accepts IPv4 connections, allowing a single dual-stack
+    /// listener where the platform supports it. By default, the platform's default is used.
+    v6_only: Option<bool>,
+
     #[configurable(derived)]
     keepalive: Option<TcpKeepaliveConfig>,
 
@@ -60,6 +68,14 @@ pub struct TcpConfig {
     /// The maximum number of TCP connections that will be allowed at any given time.
     pub connection_limit: Option<u32>,
 
+    /// The maximum number of events per second that will be read from a single connection.
+    ///
+    /// Once a connection exceeds this rate by more than a second's worth of events, reads from it are paused until
+    /// it's back within the rate, without affecting other connections.
+    ///
+    /// By default, there is no limit.
+    pub max_events_per_second: Option<NonZeroU32>,
+
     #[configurable(derived)]
     framing: Option<FramingConfig>,
 
@@ -76,6 +92,7 @@ impl TcpConfig {
     pub fn from_address(address: SocketListenAddr) -> Self {
         Self {
             address,
+            v6_only: None,
             keepalive: None,
             max_length: Some(crate::serde::default_max_length()),
             shutdown_timeout_secs: default_shutdown_timeout_secs(),
@@ -86,6 +103,7 @@ impl TcpConfig {
             framing: None,
             decoding: default_decoding(),
             connection_limit: None,
+            max_events_per_second: None,
         }
     }
 
@@ -105,8 +123,12 @@ impl TcpConfig {
         &self.decoding
     }
 
-    pub const fn address(&self) -> SocketListenAddr {
-        self.address
+    pub fn address(&self) -> SocketListenAddr {
+        self.address.clone()
+    }
+
+    pub const fn v6_only(&self) -> Option<bool> {
+        self.v6_only
     }
 
     pub const fn keepalive(&self) -> Option<TcpKeepaliveConfig> {
@@ -7,7 +7,6 @@ use codecs::{
     StreamDecodingError,
 };
 use futures::StreamExt;
-use tokio::net::UdpSocket;
 use tokio_util::codec::FramedRead;
 use vector_config::configurable_component;
 use vector_core::ByteSizeOf;
@@ -21,7 +20,7 @@ use crate::{
     },
     serde::{default_decoding, default_framing_message_based},
     shutdown::ShutdownSignal,
-    sources::Source,
+    sources::{util::UdpListenAddr, Source},
     udp, SourceSender,
 };
 
@@ -31,7 +30,13 @@ use crate::{
 #[serde(deny_unknown_fields)]
 pub struct UdpConfig {
     /// The address to listen for messages on.
-    address: SocketAddr,
+    address: UdpListenAddr,
+
+    /// Whether an IPv6 listening socket only receives IPv6 datagrams, by setting `IPV6_V6ONLY`.
+    ///
+    /// When disabled, a socket listening on `[::]` also receives IPv4 datagrams, allowing a single dual-stack
+    /// listener where the platform supports it. By default, the platform's default is used.
+    v6_only: Option<bool>,
 
     /// The maximum buffer size, in bytes, of incoming messages.
     ///
@@ -82,13 +87,14 @@ impl UdpConfig {
         &self.decoding
     }
 
-    pub(super) const fn address(&self) -> SocketAddr {
-        self.address
+    pub(super) fn address(&self) -> UdpListenAddr {
+        self.address.clone()
     }
 
     pub fn from_address(address: SocketAddr) -> Self {
         Self {
-            address,
+            address: address.into(),
+            v6_only: None,
             max_length: crate::serde::default_max_length(),
             host_key: None,
             port_key: Some(String::from("port")),
@@ -107,7 +113,9 @@ pub(super) fn udp(
     mut out: SourceSender,
 ) -> Source {
     Box::pin(async move {
-        let socket = UdpSocket::bind(&config.address)
+        let socket = config
+            .address
+            .bind(config.v6_only)
             .await
             .expect("Failed to bind to udp listener socket");
 
@@ -5,15 +5,14 @@ use codecs::{
     decoding::{self, Deserializer, Framer},
     NewlineDelimitedDecoder,
 };
-use futures::{StreamExt, TryFutureExt};
+use futures::StreamExt;
 use smallvec::{smallvec, SmallVec};
-use tokio::net::UdpSocket;
 use tokio_util::udp::UdpFramed;
 use vector_config::configurable_component;
 use vector_core::ByteSizeOf;
 
 use self::parser::ParseError;
-use super::util::{SocketListenAddr, TcpNullAcker, TcpSource};
+use super::util::{SocketListenAddr, TcpNullAcker, TcpSource, UdpListenAddr};
 use crate::{
     codecs::Decoder,
     config::{
@@ -60,7 +59,13 @@ pub enum StatsdConfig {
 #[derive(Clone, Debug)]
 pub struct UdpConfig {
     /// The address to listen for messages on.
-    address: SocketAddr,
+    address: UdpListenAddr,
+
+    /// Whether an IPv6 listening socket only receives IPv6 datagrams, by setting `IPV6_V6ONLY`.
+    ///
+    /// When disabled, a socket listening on `[::]` also receives IPv4 datagrams, allowing a single dual-stack
+    /// listener where the platform supports it. By default, the platform's default is used.
+    v6_only: Option<bool>,
 
     /// The size, in bytes, of the receive buffer used for each connection.
     ///
@@ -71,7 +76,8 @@ pub struct UdpConfig {
 impl UdpConfig {
     pub const fn from_address(address: SocketAddr) -> Self {
         Self {
-            address,
+            address: UdpListenAddr::SocketAddr(address),
+            v6_only: None,
             receive_buffer_bytes: None,
         }
     }
@@ -84,6 +90,12 @@ pub struct TcpConfig {
     /// The address to listen for connections on.
     address: SocketListenAddr,
 
+    /// Whether an IPv6 listening socket only accepts IPv6 connections, by setting `IPV6_V6ONLY`.
+    ///
+    /// When disabled, a socket listening on `[::]` also accepts IPv4 connections, allowing a single dual-stack
+    /// listener where the platform supports it. By default, the platform's default is used.
+    v6_only: Option<bool>,
+
     #[configurable(derived)]
     keepalive: Option<TcpKeepaliveConfig>,
 
@@ -110,6 +122,7 @@ impl TcpConfig {
     pub fn from_address(address: SocketListenAddr) -> Self {
         Self {
             address,
+            v6_only: None,
             keepalive: None,
             tls: None,
             shutdown_timeout_secs: default_shutdown_timeout_secs(),
@@ -152,7 +165,8 @@ impl SourceConfig for StatsdConfig {
                     .and_then(|tls| tls.client_metadata_key.clone());
                 let tls = MaybeTlsSettings::from_config(&tls_config, true)?;
                 StatsdTcpSource.run(
-                    config.address,
+                    config.address.clone(),
+                    config.v6_only,
                     config.keepalive,
                     config.shutdown_timeout_secs,
                     tls,
@@ -161,6 +175,7 @@ impl SourceConfig for StatsdConfig {
                     cx,
                     false.into(),
                     config.connection_limit,
+                    None,
                 )
             }
             #[cfg(unix)]
@@ -179,7 +194,7 @@ impl SourceConfig for StatsdConfig {
     fn resources(&self) -> Vec<Resource> {
         match self.clone() {
             Self::Tcp(tcp) => vec![tcp.address.into()],
-            Self::Udp(udp) => vec![Resource::udp(udp.address)],
+            Self::Udp(udp) => vec![udp.address.into()],
             #[cfg(unix)]
             Self::Unix(_) => vec![],
         }
@@ -253,9 +268,11 @@ async fn statsd_udp(
 ) -> Result<(), ()> {
     // TODO: This should probably be based off of the `socket` source in UDP mode. If it's missing features needed, we
     // should add them. Reduce, reuse, recycle.
-    let socket = UdpSocket::bind(&config.address)
-        .map_err(|error| emit!(StatsdSocketError::bind(error)))
-        .await?;
+    let socket = config
+        .address
+        .bind(config.v6_only)
+        .await
+        .map_err(|error| emit!(StatsdSocketError::bind(error)))?;
 
     if let Some(receive_buffer_bytes) = config.receive_buffer_bytes {
         if let Err(error) = udp::set_receive_buffer_size(&socket, receive_buffer_bytes) {
@@ -318,6 +335,7 @@ mod test {
     use futures_util::SinkExt;
     use tokio::{
         io::AsyncWriteExt,
+        net::UdpSocket,
         time::{sleep, Duration, Instant},
     };
     use vector_core::{config::ComponentKey, event::EventContainer};
@@ -1,6 +1,6 @@
-use std::net::SocketAddr;
 #[cfg(unix)]
 use std::path::PathBuf;
+use std::{net::SocketAddr, num::NonZeroU32};
 
 use bytes::Bytes;
 use chrono::Utc;
@@ -10,7 +10,6 @@ use codecs::{
 };
 use futures::StreamExt;
 use smallvec::SmallVec;
-use tokio::net::UdpSocket;
 use tokio_util::udp::UdpFramed;
 use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
@@ -26,7 +25,7 @@ use crate::{
     event::Event,
     internal_events::SyslogUdpReadError,
     shutdown::ShutdownSignal,
-    sources::util::{SocketListenAddr, TcpNullAcker, TcpSource},
+    sources::util::{SocketListenAddr, TcpNullAcker, TcpSource, UdpListenAddr},
     tcp::TcpKeepaliveConfig,
     tls::{MaybeTlsSettings, TlsSourceConfig},
     udp, SourceSender,
@@ -66,6 +65,12 @@ pub enum Mode {
         /// The address to listen for connections on.
         address: SocketListenAddr,
 
+        /// Whether an IPv6 listening socket only accepts IPv6 connections, by setting `IPV6_V6ONLY`.
+        ///
+        /// When disabled, a socket listening on `[::]` also accepts IPv4 connections, allowing a single dual-stack
+        /// listener where the platform supports it. By default, the platform's default is used.
+        v6_only: Option<bool>,
+
         #[configurable(derived)]
         keepalive: Option<TcpKeepaliveConfig>,
 
@@ -79,12 +84,26 @@ pub enum Mode {
 
         /// The maximum number of TCP connections that will be allowed at any given time.
         connection_limit: Option<u32>,
+
+        /// The maximum number of events per second that will be read from a single connection.
+        ///
+        /// Once a connection exceeds this rate by more than a second's worth of events, reads from it are paused
+        /// until it's back within the rate, without affecting other connections.
+        ///
+        /// By default, there is no limit.
+        max_events_per_second: Option<NonZeroU32>,
     },
 
     /// Listen on UDP.
     Udp {
         /// The address to listen for messages on.
-        address: SocketAddr,
+        address: UdpListenAddr,
+
+        /// Whether an IPv6 listening socket only receives IPv6 datagrams, by setting `IPV6_V6ONLY`.
+        ///
+        /// When disabled, a socket listening on `[::]` also receives IPv4 datagrams, allowing a single dual-stack
+        /// listener where the platform supports it. By default, the platform's default is used.
+        v6_only: Option<bool>,
 
         /// The size, in bytes, of the receive buffer used for the listening socket.
         ///
@@ -127,10 +146,12 @@ impl GenerateConfig for SyslogConfig {
         toml::Value::try_from(Self {
             mode: Mode::Tcp {
                 address: SocketListenAddr::SocketAddr("0.0.0.0:514".parse().unwrap()),
+                v6_only: None,
                 keepalive: None,
                 tls: None,
                 receive_buffer_bytes: None,
                 connection_limit: None,
+                max_events_per_second: None,
             },
             host_key: None,
             max_length: crate::serde::default_max_length(),
@@ -151,10 +172,12 @@ impl SourceConfig for SyslogConfig {
         match self.mode.clone() {
             Mode::Tcp {
                 address,
+                v6_only,
                 keepalive,
                 tls,
                 receive_buffer_bytes,
                 connection_limit,
+                max_events_per_second,
             } => {
                 let source = SyslogTcpSource {
                     max_length: self.max_length,
@@ -167,6 +190,7 @@ impl SourceConfig for SyslogConfig {
                 let tls = MaybeTlsSettings::from_config(&tls_config, true)?;
                 source.run(
                     address,
+                    v6_only,
                     keepalive,
                     shutdown_secs,
                     tls,
@@ -175,13 +199,16 @@ impl SourceConfig for SyslogConfig {
                     cx,
                     false.into(),
                     connection_limit,
+                    max_events_per_second,
                 )
             }
             Mode::Udp {
                 address,
+                v6_only,
                 receive_buffer_bytes,
             } => Ok(udp(
                 address,
+                v6_only,
                 self.max_length,
                 host_key,
                 receive_buffer_bytes,
@@ -223,7 +250,7 @@ impl SourceConfig for SyslogConfig {
     fn resources(&self) -> Vec<Resource> {
         match self.mode.clone() {
             Mode::Tcp { address, .. } => vec![address.into()],
-            Mode::Udp { address, .. } => vec![Resource::udp(address)],
+            Mode::Udp { address, .. } => vec![address.into()],
             #[cfg(unix)]
             Mode::Unix { .. } => vec![],
         }
@@ -263,7 +290,8 @@ impl TcpSource for SyslogTcpSource {
 }
 
 pub fn udp(
-    addr: SocketAddr,
+    addr: UdpListenAddr,
+    v6_only: Option<bool>,
     _max_length: usize,
     host_key: String,
     receive_buffer_bytes: Option<usize>,
@@ -271,7 +299,8 @@ pub fn udp(
     mut out: SourceSender,
 ) -> super::Source {
     Box::pin(async move {
-        let socket = UdpSocket::bind(&addr)
+        let socket = addr
+            .bind(v6_only)
             .await
             .expect("Failed to bind to UDP listener socket");
 
@@ -801,10 +830,12 @@ mod test {
             // Create and spawn the source.
             let config = SyslogConfig::from_mode(Mode::Tcp {
                 address: in_addr.into(),
+                v6_only: None,
                 keepalive: None,
                 tls: None,
                 receive_buffer_bytes: None,
                 connection_limit: None,
+                max_events_per_second: None,
             });
 
             let key = ComponentKey::from("in");
@@ -944,10 +975,12 @@ mod test {
             // Create and spawn the source.
             let config = SyslogConfig::from_mode(Mode::Tcp {
                 address: in_addr.into(),
+                v6_only: None,
                 keepalive: None,
                 tls: None,
                 receive_buffer_bytes: None,
                 connection_limit: None,
+                max_events_per_second: None,
             });
 
             let key = ComponentKey::from("in");
@@ -4,6 +4,7 @@ use std::{
     convert::TryInto,
     fs,
     marker::{Send, Sync},
+    net::SocketAddr,
     path::PathBuf,
     sync::{
         atomic::{AtomicU32, Ordering},
@@ -20,15 +21,21 @@ use futures::{
     sink::{Sink, SinkExt},
     stream::{self, StreamExt, TryStreamExt},
 };
-use tokio::{self, net::UnixListener, task::JoinHandle};
-use tokio_stream::wrappers::UnixListenerStream;
+use tokio::{
+    self,
+    io::{AsyncRead, AsyncWrite},
+    net::{TcpListener, UnixListener},
+    task::JoinHandle,
+};
+use tokio_stream::wrappers::{TcpListenerStream, UnixListenerStream};
 use tokio_util::codec::{length_delimited, Framed};
 use tracing::{field, Instrument};
 
 use crate::{
     event::Event,
     internal_events::{
-        SocketEventsReceived, SocketMode, UnixSocketError, UnixSocketFileDeleteError,
+        SocketEventsReceived, SocketMode, TcpSocketConnectionEstablished, TcpSocketError,
+        UnixSocketError, UnixSocketFileDeleteError,
     },
     shutdown::ShutdownSignal,
     sources::Source,
@@ -44,6 +51,7 @@ struct FrameStreamReader {
     response_sink: Mutex<FrameStreamSink>,
     expected_content_type: String,
     state: FrameStreamState,
+    mode: SocketMode,
 }
 
 struct FrameStreamState {
@@ -136,11 +144,16 @@ fn advance_u32(b: &mut Bytes) -> Result<u32, ()> {
 }
 
 impl FrameStreamReader {
-    pub fn new(response_sink: FrameStreamSink, expected_content_type: String) -> Self {
+    pub fn new(
+        response_sink: FrameStreamSink,
+        expected_content_type: String,
+        mode: SocketMode,
+    ) -> Self {
         FrameStreamReader {
             response_sink: Mutex::new(response_sink),
             expected_content_type,
             state: FrameStreamState::new(),
+            mode,
         }
     }
 
@@ -157,7 +170,7 @@ impl FrameStreamReader {
             //data frame
             if self.state.control_state == ControlState::ReadingData {
                 emit!(SocketEventsReceived {
-                    mode: SocketMode::Unix,
+                    mode: self.mode,
                     byte_size: frame.len(),
                     count: 1
                 });
@@ -352,6 +365,8 @@ pub trait FrameHandler {
     fn multithreaded(&self) -> bool;
     fn max_frame_handling_tasks(&self) -> u32;
     fn socket_file_mode(&self) -> Option<u32>;
+    fn socket_file_owner(&self) -> Option<u32>;
+    fn socket_file_group(&self) -> Option<u32>;
     fn socket_receive_buffer_size(&self) -> Option<usize>;
     fn socket_send_buffer_size(&self) -> Option<usize>;
     fn host_key(&self) -> String;
@@ -439,6 +454,27 @@ pub fn build_framestream_unix_source(
         };
     };
 
+    let owner = frame_handler.socket_file_owner();
+    let group = frame_handler.socket_file_group();
+    if owner.is_some() || group.is_some() {
+        match nix::unistd::chown(
+            &path,
+            owner.map(nix::unistd::Uid::from_raw),
+            group.map(nix::unistd::Gid::from_raw),
+        ) {
+            Ok(_) => {
+                info!("Socket ownership updated to {:?}:{:?}.", owner, group);
+            }
+            Err(e) => {
+                error!(
+                    "Failed to update listener socket ownership; error = {:?}.",
+                    e
+                );
+                return Err(Box::new(e));
+            }
+        }
+    }
+
     let fut = async move {
         let active_parsing_task_nums = Arc::new(AtomicU32::new(0));
 
@@ -454,10 +490,7 @@ pub fn build_framestream_unix_source(
                 Ok(s) => s,
             };
             let peer_addr = socket.peer_addr().ok();
-            let content_type = frame_handler.content_type();
             let listen_path = path.clone();
-            let mut event_sink = out.clone();
-            let active_task_nums_ = Arc::clone(&active_parsing_task_nums);
 
             let span = info_span!("connection");
             let path = if let Some(addr) = peer_addr {
@@ -473,69 +506,22 @@ pub fn build_framestream_unix_source(
             let received_from: Option<Bytes> =
                 path.map(|p| p.to_string_lossy().into_owned().into());
 
-            let (sock_sink, sock_stream) = Framed::new(
+            let handler = handle_stream(
+                frame_handler.clone(),
+                shutdown.clone(),
                 socket,
-                length_delimited::Builder::new()
-                    .max_frame_length(frame_handler.max_frame_length())
-                    .new_codec(),
-            )
-            .split();
-            let mut fs_reader = FrameStreamReader::new(Box::new(sock_sink), content_type);
-            let frame_handler_copy = frame_handler.clone();
-            let frames = sock_stream
-                .take_until(shutdown.clone())
-                .map_err(move |error| {
+                SocketMode::Unix,
+                received_from,
+                out.clone(),
+                Arc::clone(&active_parsing_task_nums),
+                move |error| {
                     emit!(UnixSocketError {
                         error: &error,
                         path: &listen_path,
                     });
-                })
-                .filter_map(move |frame| {
-                    future::ready(match frame {
-                        Ok(f) => fs_reader.handle_frame(Bytes::from(f)),
-                        Err(_) => None,
-                    })
-                });
-            if !frame_handler.multithreaded() {
-                let mut events = frames.filter_map(move |f| {
-                    future::ready(frame_handler_copy.handle_event(received_from.clone(), f))
-                });
-
-                let handler = async move {
-                    if let Err(e) = event_sink.send_event_stream(&mut events).await {
-                        error!("Error sending event: {:?}.", e);
-                    }
-
-                    info!("Finished sending.");
-                };
-                tokio::spawn(handler.instrument(span.or_current()));
-            } else {
-                let handler = async move {
-                    frames
-                        .for_each(move |f| {
-                            future::ready({
-                                let max_frame_handling_tasks =
-                                    frame_handler_copy.max_frame_handling_tasks();
-                                let f_handler = frame_handler_copy.clone();
-                                let received_from_copy = received_from.clone();
-                                let event_sink_copy = event_sink.clone();
-                                let active_task_nums_copy = Arc::clone(&active_task_nums_);
-
-                                spawn_event_handling_tasks(
-                                    f,
-                                    f_handler,
-                                    event_sink_copy,
-                                    received_from_copy,
-                                    active_task_nums_copy,
-                                    max_frame_handling_tasks,
-                                );
-                            })
-                        })
-                        .await;
-                    info!("Finished sending.");
-                };
-                tokio::spawn(handler.instrument(span.or_current()));
-            }
+                },
+            );
+            tokio::spawn(handler.instrument(span.or_current()));
         }
 
         // Cleanup
@@ -552,6 +538,125 @@ pub fn build_framestream_unix_source(
     Ok(Box::pin(fut))
 }
 
+/**
+ * Functions similarly to build_framestream_unix_source, but listens for
+ * framestream connections on a TCP address.
+ **/
+pub fn build_framestream_tcp_source(
+    frame_handler: impl FrameHandler + Send + Sync + Clone + 'static,
+    address: SocketAddr,
+    shutdown: ShutdownSignal,
+    out: SourceSender,
+) -> crate::Result<Source> {
+    let fut = async move {
+        let listener = TcpListener::bind(address).await.map_err(|error| {
+            error!(message = "Failed to bind to listener socket.", %error);
+        })?;
+        let active_parsing_task_nums = Arc::new(AtomicU32::new(0));
+
+        info!(message = "Listening...", %address, r#type = "tcp");
+
+        let mut stream = TcpListenerStream::new(listener).take_until(shutdown.clone());
+        while let Some(socket) = stream.next().await {
+            let socket = match socket {
+                Err(e) => {
+                    error!("Failed to accept socket; error = {:?}.", e);
+                    continue;
+                }
+                Ok(s) => s,
+            };
+            let peer_addr = socket.peer_addr().ok();
+            emit!(TcpSocketConnectionEstablished { peer_addr });
+
+            let span = info_span!("connection", peer_addr = field::Empty);
+            if let Some(addr) = peer_addr {
+                span.record("peer_addr", &field::display(&addr));
+            }
+            let received_from: Option<Bytes> = peer_addr.map(|addr| addr.ip().to_string().into());
+
+            let handler = handle_stream(
+                frame_handler.clone(),
+                shutdown.clone(),
+                socket,
+                SocketMode::Tcp,
+                received_from,
+                out.clone(),
+                Arc::clone(&active_parsing_task_nums),
+                |error| emit!(TcpSocketError { error }),
+            );
+            tokio::spawn(handler.instrument(span.or_current()));
+        }
+
+        Ok(())
+    };
+
+    Ok(Box::pin(fut))
+}
+
+/// Reads the frames of a framestream connection, replying to its control frames and handling its data frames.
+#[allow(clippy::too_many_arguments)]
+async fn handle_stream(
+    frame_handler: impl FrameHandler + Send + Sync + Clone + 'static,
+    shutdown: ShutdownSignal,
+    socket: impl AsyncRead + AsyncWrite + Send + Unpin + 'static,
+    mode: SocketMode,
+    received_from: Option<Bytes>,
+    mut event_sink: SourceSender,
+    active_task_nums: Arc<AtomicU32>,
+    emit_error: impl Fn(std::io::Error) + Send + 'static,
+) {
+    let content_type = frame_handler.content_type();
+    let (sock_sink, sock_stream) = Framed::new(
+        socket,
+        length_delimited::Builder::new()
+            .max_frame_length(frame_handler.max_frame_length())
+            .new_codec(),
+    )
+    .split();
+    let mut fs_reader = FrameStreamReader::new(Box::new(sock_sink), content_type, mode);
+    let frames = sock_stream
+        .take_until(shutdown)
+        .map_err(emit_error)
+        .filter_map(move |frame| {
+            future::ready(match frame {
+                Ok(f) => fs_reader.handle_frame(Bytes::from(f)),
+                Err(_) => None,
+            })
+        });
+    if !frame_handler.multithreaded() {
+        let mut events = frames.filter_map(move |f| {
+            future::ready(frame_handler.handle_event(received_from.clone(), f))
+        });
+
+        if let Err(e) = event_sink.send_event_stream(&mut events).await {
+            error!("Error sending event: {:?}.", e);
+        }
+    } else {
+        frames
+            .for_each(move |f| {
+                future::ready({
+                    let max_frame_handling_tasks = frame_handler.max_frame_handling_tasks();
+                    let f_handler = frame_handler.clone();
+                    let received_from_copy = received_from.clone();
+                    let event_sink_copy = event_sink.clone();
+                    let active_task_nums_copy = Arc::clone(&active_task_nums);
+
+                    spawn_event_handling_tasks(
+                        f,
+                        f_handler,
+                        event_sink_copy,
+                        received_from_copy,
+                        active_task_nums_copy,
+                        max_frame_handling_tasks,
+                    );
+                })
+            })
+            .await;
+    }
+
+    info!("Finished sending.");
+}
+
 fn spawn_event_handling_tasks(
     event_data: Bytes,
     event_handler: impl FrameHandler + Send + Sync + 'static,
@@ -586,6 +691,7 @@ fn wait_for_task_quota(active_task_nums: &Arc<AtomicU32>, max_tasks: u32) {
 mod test {
     #[cfg(unix)]
     use std::{
+        net::SocketAddr,
         path::PathBuf,
         sync::{
             atomic::{AtomicU32, Ordering},
@@ -602,21 +708,21 @@ mod test {
     };
     use tokio::{
         self,
-        net::UnixStream,
+        net::{TcpStream, UnixStream},
         task::JoinHandle,
         time::{Duration, Instant},
     };
     use tokio_util::codec::{length_delimited, Framed};
 
     use super::{
-        build_framestream_unix_source, spawn_event_handling_tasks, ControlField, ControlHeader,
-        FrameHandler,
+        build_framestream_tcp_source, build_framestream_unix_source, spawn_event_handling_tasks,
+        ControlField, ControlHeader, FrameHandler,
     };
     use crate::{
         config::{log_schema, ComponentKey},
         event::{Event, LogEvent},
         shutdown::SourceShutdownCoordinator,
-        test_util::{collect_n, collect_n_stream},
+        test_util::{collect_n, collect_n_stream, next_addr, wait_for_tcp},
         SourceSender,
     };
 
@@ -628,6 +734,8 @@ mod test {
         multithreaded: bool,
         max_frame_handling_tasks: u32,
         socket_file_mode: Option<u32>,
+        socket_file_owner: Option<u32>,
+        socket_file_group: Option<u32>,
         socket_receive_buffer_size: Option<usize>,
         socket_send_buffer_size: Option<usize>,
         extra_task_handling_routine: F,
@@ -644,6 +752,8 @@ mod test {
                 multithreaded,
                 max_frame_handling_tasks: 0,
                 socket_file_mode: None,
+                socket_file_owner: None,
+                socket_file_group: None,
                 socket_receive_buffer_size: None,
                 socket_send_buffer_size: None,
                 extra_task_handling_routine: extra_routine,
@@ -687,6 +797,14 @@ mod test {
             self.socket_file_mode
         }
 
+        fn socket_file_owner(&self) -> Option<u32> {
+            self.socket_file_owner
+        }
+
+        fn socket_file_group(&self) -> Option<u32> {
+            self.socket_file_group
+        }
+
         fn socket_receive_buffer_size(&self) -> Option<usize> {
             self.socket_receive_buffer_size
         }
@@ -737,6 +855,29 @@ mod test {
         Framed::new(socket, length_delimited::Builder::new().new_codec())
     }
 
+    async fn init_framestream_tcp(
+        source_id: &str,
+        frame_handler: impl FrameHandler + Send + Sync + Clone + 'static,
+        pipeline: SourceSender,
+    ) -> (
+        SocketAddr,
+        JoinHandle<Result<(), ()>>,
+        SourceShutdownCoordinator,
+    ) {
+        let source_id = ComponentKey::from(source_id);
+        let address = next_addr();
+        let mut shutdown = SourceShutdownCoordinator::default();
+        let (shutdown_signal, _) = shutdown.register_source(&source_id);
+        let server =
+            build_framestream_tcp_source(frame_handler, address, shutdown_signal, pipeline)
+                .expect("Failed to build framestream tcp source.");
+
+        let join_handle = tokio::spawn(server);
+        wait_for_tcp(address).await;
+
+        (address, join_handle, shutdown)
+    }
+
     async fn send_data_frames<S: Sink<Bytes, Error = std::io::Error> + Unpin>(
         sock_sink: &mut S,
         frames: Vec<Result<Bytes, std::io::Error>>,
@@ -902,6 +1043,49 @@ mod test {
         let _ = source_handle.await.unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn normal_framestream_tcp() {
+        let source_name = "test_source";
+        let (tx, rx) = SourceSender::new_test();
+        let (address, source_handle, mut shutdown) =
+            init_framestream_tcp(source_name, create_frame_handler(false), tx).await;
+        let socket = TcpStream::connect(address).await.unwrap();
+        let (mut sock_sink, mut sock_stream) =
+            Framed::new(socket, length_delimited::Builder::new().new_codec()).split();
+
+        //1 - send READY frame (with content_type)
+        let content_type = Bytes::from(&b"test_content"[..]);
+        let ready_msg =
+            create_control_frame_with_content(ControlHeader::Ready, vec![content_type.clone()]);
+        send_control_frame(&mut sock_sink, ready_msg).await;
+
+        //2 - wait for ACCEPT frame
+        let mut frame_vec = collect_n_stream(&mut sock_stream, 2).await;
+        assert_eq!(frame_vec[0].as_ref().unwrap().len(), 0);
+        assert_accept_frame(frame_vec[1].as_mut().unwrap(), content_type);
+
+        //3 - send START frame
+        send_control_frame(&mut sock_sink, create_control_frame(ControlHeader::Start)).await;
+
+        //4 - send data
+        send_data_frames(&mut sock_sink, vec![Ok(Bytes::from("hello"))]).await;
+        let events = collect_n(rx, 1).await;
+
+        //5 - send STOP frame
+        send_control_frame(&mut sock_sink, create_control_frame(ControlHeader::Stop)).await;
+
+        assert_eq!(
+            events[0].as_log()[&log_schema().message_key()],
+            "hello".into(),
+        );
+        assert_eq!(events[0].as_log()["test_framestream"], "127.0.0.1".into());
+
+        std::mem::drop(sock_stream);
+
+        signal_shutdown(source_name, &mut shutdown).await;
+        let _ = source_handle.await.unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn multiple_content_types() {
         let source_name = "test_source";
@@ -0,0 +1,100 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::FutureExt;
+use pin_project::pin_project;
+use stream_cancel::Tripwire;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tonic::transport::server::Connected;
+
+/// A connection that fails all reads and writes once its tripwire is triggered.
+///
+/// The server shuts down gracefully by waiting for its open connections to complete their
+/// in-flight requests. Failing the I/O of a connection makes the server close it right away
+/// instead, abandoning its in-flight requests.
+#[pin_project]
+pub struct AbortOnShutdown<IO> {
+    #[pin]
+    io: IO,
+    /// Aborts the connection when triggered, or never if `None`.
+    tripwire: Option<Tripwire>,
+    aborted: bool,
+}
+
+impl<IO> AbortOnShutdown<IO> {
+    pub const fn new(io: IO, tripwire: Option<Tripwire>) -> Self {
+        Self {
+            io,
+            tripwire,
+            aborted: false,
+        }
+    }
+}
+
+/// Checks whether the connection has been aborted, registering for wakeup otherwise.
+fn poll_aborted(tripwire: &mut Option<Tripwire>, aborted: &mut bool, cx: &mut Context<'_>) -> bool {
+    if !*aborted {
+        if let Some(tripwire) = tripwire {
+            *aborted = tripwire.poll_unpin(cx).is_ready();
+        }
+    }
+    *aborted
+}
+
+fn aborted_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::ConnectionAborted,
+        "connection closed on shutdown",
+    )
+}
+
+impl<IO: AsyncRead> AsyncRead for AbortOnShutdown<IO> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.project();
+        if poll_aborted(this.tripwire, this.aborted, cx) {
+            return Poll::Ready(Err(aborted_error()));
+        }
+        this.io.poll_read(cx, buf)
+    }
+}
+
+impl<IO: AsyncWrite> AsyncWrite for AbortOnShutdown<IO> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        if poll_aborted(this.tripwire, this.aborted, cx) {
+            return Poll::Ready(Err(aborted_error()));
+        }
+        this.io.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        if poll_aborted(this.tripwire, this.aborted, cx) {
+            return Poll::Ready(Err(aborted_error()));
+        }
+        this.io.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().io.poll_shutdown(cx)
+    }
+}
+
+impl<IO: Connected> Connected for AbortOnShutdown<IO> {
+    type ConnectInfo = IO::ConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.io.connect_info()
+    }
+}
@@ -19,7 +19,7 @@ use tokio::{pin, select};
 use tonic::{body::BoxBody, metadata::AsciiMetadataValue, Status};
 use tower::{Layer, Service};
 
-use crate::internal_events::BytesReceived;
+use crate::internal_events::{BytesReceived, GrpcTruncatedMessageError};
 
 // Every gRPC message has a five byte header:
 // - a compressed flag (u8, 0/1 for compressed/decompressed)
@@ -86,9 +86,33 @@ fn new_decompressor() -> GzDecoder<Vec<u8>> {
     GzDecoder::new(buf)
 }
 
+/// Reports the message that is still incomplete once the body ended, if any.
+///
+/// Such a message, for example cut short by the client closing the connection, can't be decoded, so it's dropped. The
+/// complete messages before it have already been forwarded.
+fn report_partial_message(state: &State, buf: &BytesMut) {
+    if !buf.is_empty() || !matches!(state, State::WaitingForHeader) {
+        emit!(GrpcTruncatedMessageError {
+            byte_size: buf.len()
+        });
+    }
+}
+
+/// Rejects messages larger than `max_message_size`, if set, before they are buffered.
+fn check_message_size(message_len: usize, max_message_size: Option<usize>) -> Result<(), Status> {
+    match max_message_size {
+        Some(max) if message_len > max => Err(Status::resource_exhausted(format!(
+            "message of {} bytes is larger than the maximum of {} bytes",
+            message_len, max
+        ))),
+        _ => Ok(()),
+    }
+}
+
 async fn drive_body_decompression(
     mut source: Body,
     mut destination: Sender,
+    max_message_size: Option<usize>,
 ) -> Result<usize, Status> {
     let mut state = State::default();
     let mut buf = BytesMut::new();
@@ -97,7 +121,10 @@ async fn drive_body_decompression(
 
     // Drain all message chunks from the body first.
     while let Some(result) = source.data().await {
-        let chunk = result.map_err(|_| Status::internal("failed to read from underlying body"))?;
+        let chunk = result.map_err(|_| {
+            report_partial_message(&state, &buf);
+            Status::internal("failed to read from underlying body")
+        })?;
         buf.put(chunk);
 
         let maybe_message = loop {
@@ -122,6 +149,7 @@ async fn drive_body_decompression(
 
                         (header[0] == 1, message_len)
                     };
+                    check_message_size(message_len, max_message_size)?;
 
                     // Now, if the message is not compressed, then put ourselves into forward mode, where we'll wait for
                     // the rest of the message to come in -- decoding isn't streaming so there's no benefit there --
@@ -174,6 +202,11 @@ async fn drive_body_decompression(
                             if decompressor.write_all(&buf[..to_take]).is_err() {
                                 return Err(Status::internal("failed to write to decompressor"));
                             }
+                            // A small compressed message can decompress into a very large one.
+                            check_message_size(
+                                decompressor.get_ref().len() - GRPC_MESSAGE_HEADER_LEN,
+                                max_message_size,
+                            )?;
 
                             *remaining -= to_take;
                             buf.advance(to_take);
@@ -225,6 +258,8 @@ async fn drive_body_decompression(
         }
     }
 
+    report_partial_message(&state, &buf);
+
     // When we've exhausted all the message chunks, we try sending any trailers that came in on the underlying body.
     let result = source.trailers().await;
     let maybe_trailers =
@@ -242,11 +277,12 @@ async fn drive_request<F, E>(
     source: Body,
     destination: Sender,
     inner: F,
+    max_message_size: Option<usize>,
 ) -> Result<Response<BoxBody>, E>
 where
     F: Future<Output = Result<Response<BoxBody>, E>>,
 {
-    let body_decompression = drive_body_decompression(source, destination);
+    let body_decompression = drive_body_decompression(source, destination, max_message_size);
 
     pin!(inner);
     pin!(body_decompression);
@@ -292,6 +328,7 @@ where
 #[derive(Clone)]
 pub struct DecompressionAndMetrics<S> {
     inner: S,
+    max_message_size: Option<usize>,
 }
 
 impl<S> Service<Request<Body>> for DecompressionAndMetrics<S>
@@ -324,7 +361,7 @@ where
 
                 let inner = self.inner.call(mapped_req);
 
-                drive_request(req_body, destination, inner).boxed()
+                drive_request(req_body, destination, inner, self.max_message_size).boxed()
             }
         }
     }
@@ -350,13 +387,27 @@ where
 /// received _and_ processed correctly.
 ///
 /// The only supported compression scheme is gzip, which is also the only supported compression scheme in `tonic` itself.
+///
+/// As `tonic` doesn't limit the size of the messages it decodes, this layer also rejects the messages larger than
+/// `max_message_size`, after decompression, with `RESOURCE_EXHAUSTED`, before they are buffered in full.
 #[derive(Clone, Default)]
-pub struct DecompressionAndMetricsLayer;
+pub struct DecompressionAndMetricsLayer {
+    max_message_size: Option<usize>,
+}
+
+impl DecompressionAndMetricsLayer {
+    pub const fn new(max_message_size: Option<usize>) -> Self {
+        Self { max_message_size }
+    }
+}
 
 impl<S> Layer<S> for DecompressionAndMetricsLayer {
     type Service = DecompressionAndMetrics<S>;
 
     fn layer(&self, inner: S) -> Self::Service {
-        DecompressionAndMetrics { inner }
+        DecompressionAndMetrics {
+            inner,
+            max_message_size: self.max_message_size,
+        }
     }
 }
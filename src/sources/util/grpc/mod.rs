@@ -1,26 +1,60 @@
 use crate::{
     shutdown::{ShutdownSignal, ShutdownSignalToken},
-    tls::MaybeTlsSettings,
+    tls::{MaybeTlsListener, MaybeTlsSettings, TlsError},
 };
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt};
 use http::{Request, Response};
 use hyper::Body;
-use std::{convert::Infallible, net::SocketAddr};
+use std::{convert::Infallible, io, net::SocketAddr, time::Duration};
+use stream_cancel::Tripwire;
 use tonic::{
     body::BoxBody,
-    transport::server::{NamedService, Server},
+    transport::server::{NamedService, Router, Server},
+};
+use tower::{
+    layer::util::{Identity, Stack},
+    Service,
 };
-use tower::Service;
 use tracing::{Instrument, Span};
 
+mod abort;
 mod decompression;
+mod tls_peer;
+use self::abort::AbortOnShutdown;
 pub use self::decompression::{DecompressionAndMetrics, DecompressionAndMetricsLayer};
+pub use self::tls_peer::{RecordTlsPeer, TlsPeer};
+
+/// The settings of the connections and messages of a gRPC server.
+///
+/// The default settings match those of `tonic`, with messages of any size.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GrpcServerSettings {
+    /// The interval of the HTTP/2 pings sent to check that connections are alive, if any.
+    pub keepalive_interval: Option<Duration>,
+    /// The time to wait for the acknowledgement of a ping before closing the connection.
+    pub keepalive_timeout: Option<Duration>,
+    /// The maximum size of the HTTP/2 frames received.
+    pub max_frame_size: Option<u32>,
+    /// The maximum number of concurrent HTTP/2 streams, or requests, of each connection.
+    pub max_concurrent_streams: Option<u32>,
+    /// The maximum size of a message, after decompression.
+    pub max_message_size: Option<usize>,
+}
+
+/// The layers the gRPC servers apply to their services.
+pub type GrpcServerLayer = Stack<DecompressionAndMetricsLayer, Identity>;
 
+/// Runs a gRPC server until shutdown is signaled.
+///
+/// On shutdown, the server stops accepting connections. If `drain_connections` is `true`, it then
+/// waits for the open connections to complete their in-flight requests, otherwise it closes them
+/// right away.
 pub async fn run_grpc_server<S>(
     address: SocketAddr,
     tls_settings: MaybeTlsSettings,
     service: S,
     shutdown: ShutdownSignal,
+    drain_connections: bool,
 ) -> crate::Result<()>
 where
     S: Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible>
@@ -29,16 +63,53 @@ where
         + Send
         + 'static,
     S::Future: Send + 'static,
+{
+    run_grpc_server_with_routes(
+        address,
+        tls_settings,
+        |server| server.add_service(service),
+        shutdown,
+        drain_connections,
+        None,
+        GrpcServerSettings::default(),
+    )
+    .await
+}
+
+/// Runs a gRPC server serving the services `routes` adds to it until shutdown is signaled.
+///
+/// See [`run_grpc_server`] for the shutdown behavior. If `bind_retry_timeout` is set, binding the
+/// address is retried while it is in use, until the timeout elapses. The connections and messages
+/// of the server follow `settings`.
+pub async fn run_grpc_server_with_routes<F>(
+    address: SocketAddr,
+    tls_settings: MaybeTlsSettings,
+    routes: F,
+    shutdown: ShutdownSignal,
+    drain_connections: bool,
+    bind_retry_timeout: Option<Duration>,
+    settings: GrpcServerSettings,
+) -> crate::Result<()>
+where
+    F: FnOnce(&mut Server<GrpcServerLayer>) -> Router<GrpcServerLayer>,
 {
     let span = Span::current();
     let (tx, rx) = tokio::sync::oneshot::channel::<ShutdownSignalToken>();
-    let listener = tls_settings.bind(&address).await?;
-    let stream = listener.accept_stream();
+    let listener = bind(&tls_settings, &address, bind_retry_timeout).await?;
+    let (abort, tripwire) = Tripwire::new();
+    let tripwire = (!drain_connections).then(|| tripwire);
+    let stream = listener.accept_stream().map(move |connection| {
+        connection.map(|io| AbortOnShutdown::new(RecordTlsPeer::new(io), tripwire.clone()))
+    });
 
     info!(message = "Building gRPC server.", address = %address);
 
-    Server::builder()
+    let mut server = Server::builder()
         .trace_fn(move |_| span.clone())
+        .http2_keepalive_interval(settings.keepalive_interval)
+        .http2_keepalive_timeout(settings.keepalive_timeout)
+        .max_frame_size(settings.max_frame_size)
+        .max_concurrent_streams(settings.max_concurrent_streams)
         // This layer explicitly decompresses payloads, if compressed, and reports the number of message bytes we've
         // received if the message is processed successfully, aka `BytesReceived`. We do this because otherwise the only
         // access we have is either the event-specific bytes (the in-memory representation) or the raw bytes over the
@@ -48,9 +119,16 @@ where
         // use independent `tower` layers when the request body itself (the body type, not the actual bytes) must be
         // modified or wrapped.. so instead of a cleaner design, we're opting here to bake it all together until the
         // crates are sufficiently flexible for us to craft a better design.
-        .layer(DecompressionAndMetricsLayer::default())
-        .add_service(service)
-        .serve_with_incoming_shutdown(stream, shutdown.map(|token| tx.send(token).unwrap()))
+        .layer(DecompressionAndMetricsLayer::new(settings.max_message_size));
+
+    routes(&mut server)
+        .serve_with_incoming_shutdown(
+            stream,
+            shutdown.map(|token| {
+                abort.cancel();
+                tx.send(token).unwrap()
+            }),
+        )
         .in_current_span()
         .await?;
 
@@ -58,3 +136,32 @@ where
 
     Ok(())
 }
+
+/// Binds the address, retrying every 100 milliseconds while it is in use until `retry_timeout`
+/// elapses, such as while another instance is shutting down during a rolling deploy.
+async fn bind(
+    tls_settings: &MaybeTlsSettings,
+    address: &SocketAddr,
+    retry_timeout: Option<Duration>,
+) -> crate::tls::Result<MaybeTlsListener> {
+    let retry_timeout = match retry_timeout {
+        Some(retry_timeout) => retry_timeout,
+        None => return tls_settings.bind(address).await,
+    };
+    let retry = async {
+        loop {
+            match tls_settings.bind(address).await {
+                Err(TlsError::TcpBind { source }) if source.kind() == io::ErrorKind::AddrInUse => {
+                    debug!(message = "Address in use, retrying bind.", address = %address);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+                result => return result,
+            }
+        }
+    };
+    match tokio::time::timeout(retry_timeout, retry).await {
+        Ok(result) => result,
+        // Reports the error of a last attempt.
+        Err(_) => tls_settings.bind(address).await,
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    io,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use once_cell::sync::OnceCell;
+use pin_project::pin_project;
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    net::TcpStream,
+};
+use tonic::transport::server::Connected;
+
+use crate::tls::{CertificateMetadata, MaybeTlsIncomingStream};
+
+/// The certificate presented by the client of a connection, if any.
+///
+/// The TLS handshake of a connection only completes on its first read, after the connection has
+/// been handed to the server, so the certificate is filled in by [`RecordTlsPeer`] once known and
+/// shared with every request of the connection.
+#[derive(Clone, Debug, Default)]
+pub struct TlsPeer(Arc<OnceCell<CertificateMetadata>>);
+
+impl TlsPeer {
+    /// The metadata of the client certificate, or `None` if the client did not present one or the
+    /// handshake has not completed.
+    pub fn certificate_metadata(&self) -> Option<&CertificateMetadata> {
+        self.0.get()
+    }
+}
+
+/// A connection recording the certificate of its client into a [`TlsPeer`] once the TLS
+/// handshake has completed.
+#[pin_project]
+pub struct RecordTlsPeer {
+    #[pin]
+    io: MaybeTlsIncomingStream<TcpStream>,
+    peer: TlsPeer,
+    recorded: bool,
+}
+
+impl RecordTlsPeer {
+    pub fn new(io: MaybeTlsIncomingStream<TcpStream>) -> Self {
+        Self {
+            io,
+            peer: TlsPeer::default(),
+            recorded: false,
+        }
+    }
+
+    pub const fn peer(&self) -> &TlsPeer {
+        &self.peer
+    }
+}
+
+impl AsyncRead for RecordTlsPeer {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.project();
+        let result = this.io.poll_read(cx, buf);
+        if !*this.recorded {
+            if let Some(stream) = this.io.ssl_stream() {
+                if let Some(certificate) = stream.ssl().peer_certificate() {
+                    drop(this.peer.0.set(CertificateMetadata::from_x509(certificate)));
+                }
+                *this.recorded = true;
+            }
+        }
+        result
+    }
+}
+
+impl AsyncWrite for RecordTlsPeer {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.project().io.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().io.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().io.poll_shutdown(cx)
+    }
+}
+
+impl Connected for RecordTlsPeer {
+    type ConnectInfo = TlsPeer;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.peer.clone()
+    }
+}
@@ -2,35 +2,47 @@ use std::io::Read;
 
 use bytes::{Buf, Bytes};
 use flate2::read::{MultiGzDecoder, ZlibDecoder};
-use snap::raw::Decoder as SnappyDecoder;
+use snap::raw::{decompress_len, Decoder as SnappyDecoder};
 use warp::http::StatusCode;
+use zstd::stream::read::Decoder as ZstdDecoder;
 
 use super::error::ErrorMessage;
 use crate::internal_events::HttpDecompressError;
 
-pub fn decode(header: &Option<String>, mut body: Bytes) -> Result<Bytes, ErrorMessage> {
+pub fn decode(header: &Option<String>, body: Bytes) -> Result<Bytes, ErrorMessage> {
+    decode_with_limit(header, body, None)
+}
+
+/// Decompresses the body like [`decode`], rejecting bodies that decompress to more than `max_size`
+/// bytes with `413 Payload Too Large`.
+///
+/// Bodies are only decompressed up to the limit, so that small payloads expanding to huge ones
+/// aren't buffered whole.
+pub fn decode_with_limit(
+    header: &Option<String>,
+    mut body: Bytes,
+    max_size: Option<usize>,
+) -> Result<Bytes, ErrorMessage> {
     if let Some(encodings) = header {
         for encoding in encodings.rsplit(',').map(str::trim) {
             body = match encoding {
                 "identity" => body,
-                "gzip" => {
-                    let mut decoded = Vec::new();
-                    MultiGzDecoder::new(body.reader())
-                        .read_to_end(&mut decoded)
+                "gzip" => read_limited(MultiGzDecoder::new(body.reader()), encoding, max_size)?,
+                "deflate" => read_limited(ZlibDecoder::new(body.reader()), encoding, max_size)?,
+                "snappy" => {
+                    let size = decompress_len(&body)
                         .map_err(|error| handle_decode_error(encoding, error))?;
-                    decoded.into()
+                    check_size(size, max_size)?;
+                    SnappyDecoder::new()
+                        .decompress_vec(&body)
+                        .map_err(|error| handle_decode_error(encoding, error))?
+                        .into()
                 }
-                "deflate" => {
-                    let mut decoded = Vec::new();
-                    ZlibDecoder::new(body.reader())
-                        .read_to_end(&mut decoded)
+                "zstd" => {
+                    let decoder = ZstdDecoder::new(body.reader())
                         .map_err(|error| handle_decode_error(encoding, error))?;
-                    decoded.into()
+                    read_limited(decoder, encoding, max_size)?
                 }
-                "snappy" => SnappyDecoder::new()
-                    .decompress_vec(&body)
-                    .map_err(|error| handle_decode_error(encoding, error))?
-                    .into(),
                 encoding => {
                     return Err(ErrorMessage::new(
                         StatusCode::UNSUPPORTED_MEDIA_TYPE,
@@ -44,6 +56,35 @@ pub fn decode(header: &Option<String>, mut body: Bytes) -> Result<Bytes, ErrorMe
     Ok(body)
 }
 
+fn read_limited(
+    reader: impl Read,
+    encoding: &str,
+    max_size: Option<usize>,
+) -> Result<Bytes, ErrorMessage> {
+    // Reading a byte past the limit tells whether the body is larger than it.
+    let limit = max_size.map_or(u64::MAX, |max_size| max_size as u64 + 1);
+    let mut decoded = Vec::new();
+    reader
+        .take(limit)
+        .read_to_end(&mut decoded)
+        .map_err(|error| handle_decode_error(encoding, error))?;
+    check_size(decoded.len(), max_size)?;
+    Ok(decoded.into())
+}
+
+fn check_size(size: usize, max_size: Option<usize>) -> Result<(), ErrorMessage> {
+    match max_size {
+        Some(max_size) if size > max_size => Err(ErrorMessage::new(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Decompressed request body is larger than the maximum of {} bytes",
+                max_size
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
 fn handle_decode_error(encoding: &str, error: impl std::error::Error) -> ErrorMessage {
     emit!(HttpDecompressError {
         encoding,
@@ -16,7 +16,7 @@ mod query;
 #[cfg(feature = "sources-utils-http-auth")]
 pub use auth::{HttpSourceAuth, HttpSourceAuthConfig};
 #[cfg(feature = "sources-utils-http-encoding")]
-pub use encoding::decode;
+pub use encoding::{decode, decode_with_limit};
 #[cfg(feature = "sources-utils-http-error")]
 pub use error::ErrorMessage;
 #[cfg(feature = "sources-utils-http-prelude")]
@@ -13,10 +13,16 @@ pub mod grpc;
     feature = "sources-utils-http-query"
 ))]
 mod http;
-#[cfg(any(feature = "sources-aws_sqs", feature = "sources-gcp_pubsub"))]
+#[cfg(any(
+    feature = "sources-aws_cloudwatch_logs",
+    feature = "sources-aws_sqs",
+    feature = "sources-gcp_pubsub"
+))]
 mod message_decoding;
 pub mod multiline_config;
 #[cfg(all(feature = "sources-utils-tls", feature = "listenfd"))]
+mod net;
+#[cfg(all(feature = "sources-utils-tls", feature = "listenfd"))]
 mod tcp;
 #[cfg(all(unix, any(feature = "sources-socket", feature = "sources-utils-unix",)))]
 mod unix;
@@ -35,6 +41,8 @@ mod wrappers;
 pub use encoding_config::EncodingConfig;
 pub use multiline_config::MultilineConfig;
 #[cfg(all(feature = "sources-utils-tls", feature = "listenfd"))]
+pub use net::UdpListenAddr;
+#[cfg(all(feature = "sources-utils-tls", feature = "listenfd"))]
 pub use tcp::{SocketListenAddr, TcpNullAcker, TcpSource, TcpSourceAck, TcpSourceAcker};
 #[cfg(all(unix, any(feature = "sources-socket", feature = "sources-utils-unix",)))]
 pub use unix::change_socket_permissions;
@@ -58,11 +66,17 @@ pub use self::http::add_query_parameters;
     feature = "sources-utils-http-encoding"
 ))]
 pub use self::http::decode;
+#[cfg(feature = "sources-utils-http-encoding")]
+pub use self::http::decode_with_limit;
 #[cfg(feature = "sources-utils-http-error")]
 pub use self::http::ErrorMessage;
 #[cfg(feature = "sources-utils-http-prelude")]
 pub use self::http::HttpSource;
 #[cfg(feature = "sources-utils-http-auth")]
 pub use self::http::HttpSourceAuthConfig;
-#[cfg(any(feature = "sources-aws_sqs", feature = "sources-gcp_pubsub"))]
+#[cfg(any(
+    feature = "sources-aws_cloudwatch_logs",
+    feature = "sources-aws_sqs",
+    feature = "sources-gcp_pubsub"
+))]
 pub use self::message_decoding::decode_message;
@@ -0,0 +1,181 @@
+use std::{fmt, future::Future, io, net::SocketAddr};
+
+use serde::{de, Deserialize, Deserializer};
+use snafu::{ResultExt, Snafu};
+use tokio::net::UdpSocket;
+use vector_config::configurable_component;
+
+use crate::{
+    config::{Protocol, Resource},
+    udp,
+};
+
+#[derive(Debug, Snafu)]
+pub enum BindHostError {
+    #[snafu(display("Failed to resolve {:?}: {}", host, source))]
+    Resolve { host: String, source: io::Error },
+    #[snafu(display("{:?} did not resolve to any address", host))]
+    NoAddresses { host: String },
+    #[snafu(display(
+        "Failed to bind to any address {:?} resolves to ({}): {}",
+        host,
+        candidates,
+        source
+    ))]
+    BindCandidates {
+        host: String,
+        candidates: String,
+        source: crate::Error,
+    },
+}
+
+/// Binds to the first address the `host:port` resolves to that `bind` succeeds for.
+pub(crate) async fn bind_host<T, E, F, Fut>(host: &str, mut bind: F) -> Result<T, BindHostError>
+where
+    F: FnMut(SocketAddr) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Into<crate::Error>,
+{
+    let candidates = tokio::net::lookup_host(host)
+        .await
+        .context(ResolveSnafu { host })?
+        .collect::<Vec<_>>();
+
+    let mut last_error = None;
+    for candidate in &candidates {
+        match bind(*candidate).await {
+            Ok(bound) => return Ok(bound),
+            Err(error) => {
+                let error = error.into();
+                debug!(message = "Failed to bind to resolved address.", address = %candidate, %error);
+                last_error = Some(error);
+            }
+        }
+    }
+
+    match last_error {
+        None => NoAddressesSnafu { host }.fail(),
+        Some(source) => Err(BindHostError::BindCandidates {
+            host: host.to_owned(),
+            candidates: candidates
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            source,
+        }),
+    }
+}
+
+pub(super) fn parse_host<'de, D>(des: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: String = Deserialize::deserialize(des)?;
+    match s.rsplit_once(':') {
+        Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => Ok(s),
+        _ => Err(de::Error::custom(
+            "must be a hostname and port, such as \"localhost:9000\"",
+        )),
+    }
+}
+
+/// A listening address of UDP sockets, given directly or as a hostname.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum UdpListenAddr {
+    /// An IPv4/IPv6 address and port.
+    SocketAddr(#[configurable(derived)] SocketAddr),
+
+    /// A hostname and port, such as `localhost:514`, resolved when binding.
+    ///
+    /// The first of the resolved addresses that can be bound to is listened on.
+    #[serde(deserialize_with = "parse_host")]
+    Host(#[configurable(transparent)] String),
+}
+
+impl UdpListenAddr {
+    /// Binds a UDP socket to the address, setting `IPV6_V6ONLY` on IPv6 sockets when `v6_only` is given.
+    pub async fn bind(&self, v6_only: Option<bool>) -> crate::Result<UdpSocket> {
+        match self {
+            Self::SocketAddr(addr) => Ok(udp::bind(*addr, v6_only)?),
+            Self::Host(host) => {
+                let bind = |candidate| async move { udp::bind(candidate, v6_only) };
+                Ok(bind_host(host, bind).await?)
+            }
+        }
+    }
+}
+
+impl fmt::Display for UdpListenAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SocketAddr(addr) => addr.fmt(f),
+            Self::Host(host) => host.fmt(f),
+        }
+    }
+}
+
+impl From<SocketAddr> for UdpListenAddr {
+    fn from(addr: SocketAddr) -> Self {
+        Self::SocketAddr(addr)
+    }
+}
+
+impl From<UdpListenAddr> for Resource {
+    fn from(addr: UdpListenAddr) -> Resource {
+        match addr {
+            UdpListenAddr::SocketAddr(addr) => Resource::udp(addr),
+            UdpListenAddr::Host(host) => Resource::Host(host, Protocol::Udp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        addr: UdpListenAddr,
+    }
+
+    #[test]
+    fn parse_udp_listen_addr() {
+        let test: Config = toml::from_str(r#"addr="127.0.0.1:1234""#).unwrap();
+        assert_eq!(
+            test.addr,
+            UdpListenAddr::SocketAddr("127.0.0.1:1234".parse().unwrap())
+        );
+        let test: Config = toml::from_str(r#"addr="localhost:1234""#).unwrap();
+        assert_eq!(test.addr, UdpListenAddr::Host("localhost:1234".into()));
+        assert!(toml::from_str::<Config>(r#"addr="localhost""#).is_err());
+    }
+
+    #[tokio::test]
+    async fn binds_udp_hostname() {
+        let socket = UdpListenAddr::Host("localhost:0".into())
+            .bind(None)
+            .await
+            .expect("localhost should resolve to a bindable address");
+        assert!(socket.local_addr().unwrap().ip().is_loopback());
+    }
+
+    #[tokio::test]
+    async fn lists_candidates_of_unbindable_hostname() {
+        // Ports of addresses already bound to can't be bound to again.
+        let bound = crate::tcp::bind_listener("127.0.0.1:0".parse().unwrap(), None).unwrap();
+        let host = format!("127.0.0.1:{}", bound.local_addr().unwrap().port());
+
+        let error = bind_host(&host, |candidate| async move {
+            crate::tcp::bind_listener(candidate, None)
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(error, BindHostError::BindCandidates { .. }));
+        assert!(error.to_string().contains(&host), "{}", error);
+    }
+}
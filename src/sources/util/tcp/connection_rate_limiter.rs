@@ -0,0 +1,72 @@
+use std::{num::NonZeroU32, time::Duration};
+
+use tokio::time::Instant;
+
+/// Limits the rate of events read from a single connection.
+///
+/// Reads from the connection are paused once it has sent more than a second's worth of events ahead
+/// of its limit, which applies backpressure to just that client instead of the whole listener.
+pub struct ConnectionRateLimiter {
+    /// The time it takes for a single event to be allowed.
+    per_event: Duration,
+    /// The time by which all of the events read so far are allowed.
+    allowed_until: Option<Instant>,
+}
+
+impl ConnectionRateLimiter {
+    pub fn new(max_events_per_second: NonZeroU32) -> Self {
+        Self {
+            per_event: Duration::from_secs(1) / max_events_per_second.get(),
+            allowed_until: None,
+        }
+    }
+
+    /// Records `count` events read from the connection, returning the time until which reads must be
+    /// paused, if they must.
+    pub fn record(&mut self, count: usize, now: Instant) -> Option<Instant> {
+        let start = self.allowed_until.map_or(now, |allowed| allowed.max(now));
+        let allowed_until = start + self.per_event * u32::try_from(count).unwrap_or(u32::MAX);
+        self.allowed_until = Some(allowed_until);
+
+        allowed_until
+            .checked_sub(Duration::from_secs(1))
+            .filter(|resume| *resume > now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_one_second_burst() {
+        let mut limiter = ConnectionRateLimiter::new(NonZeroU32::new(10).unwrap());
+        let now = Instant::now();
+
+        assert_eq!(limiter.record(5, now), None);
+        assert_eq!(limiter.record(5, now), None);
+        assert_eq!(
+            limiter.record(5, now),
+            Some(now + Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn recovers_over_time() {
+        let mut limiter = ConnectionRateLimiter::new(NonZeroU32::new(10).unwrap());
+        let now = Instant::now();
+
+        assert_eq!(limiter.record(20, now), Some(now + Duration::from_secs(1)));
+
+        // Once the pause is over, events are allowed at the configured rate.
+        let later = now + Duration::from_secs(1);
+        assert_eq!(
+            limiter.record(1, later),
+            Some(later + Duration::from_millis(100))
+        );
+
+        // After being idle, the full burst is allowed again.
+        let idle = later + Duration::from_secs(10);
+        assert_eq!(limiter.record(10, idle), None);
+    }
+}
@@ -1,8 +1,9 @@
+mod connection_rate_limiter;
 mod request_limiter;
 
 use std::collections::BTreeMap;
 use std::net::SocketAddr;
-use std::{fmt, io, mem::drop, time::Duration};
+use std::{fmt, io, mem::drop, num::NonZeroU32, time::Duration};
 
 use bytes::Bytes;
 use codecs::StreamDecodingError;
@@ -22,15 +23,20 @@ use vector_common::finalization::AddBatchNotifier;
 use vector_config::configurable_component;
 use vector_core::ByteSizeOf;
 
-use super::AfterReadExt as _;
-use crate::sources::util::tcp::request_limiter::RequestLimiter;
+use super::{
+    net::{bind_host, parse_host},
+    AfterReadExt as _,
+};
+use crate::sources::util::tcp::{
+    connection_rate_limiter::ConnectionRateLimiter, request_limiter::RequestLimiter,
+};
 use crate::{
     codecs::ReadyFrames,
-    config::{AcknowledgementsConfig, Resource, SourceContext},
+    config::{AcknowledgementsConfig, Protocol, Resource, SourceContext},
     event::{BatchNotifier, BatchStatus, Event},
     internal_events::{
         ConnectionOpen, OpenGauge, SocketEventsReceived, SocketMode, StreamClosedError,
-        TcpBytesReceived, TcpSendAckError, TcpSocketTlsConnectionError,
+        TcpBytesReceived, TcpConnectionThrottled, TcpSendAckError, TcpSocketTlsConnectionError,
     },
     shutdown::ShutdownSignal,
     tcp::TcpKeepaliveConfig,
@@ -41,12 +47,24 @@ use crate::{
 const MAX_IN_FLIGHT_EVENTS_TARGET: usize = 100_000;
 
 async fn make_listener(
-    addr: SocketListenAddr,
+    addr: &SocketListenAddr,
+    v6_only: Option<bool>,
     mut listenfd: ListenFd,
     tls: &MaybeTlsSettings,
 ) -> Option<MaybeTlsListener> {
     match addr {
-        SocketListenAddr::SocketAddr(addr) => match tls.bind(&addr).await {
+        SocketListenAddr::SocketAddr(addr) => match tls.bind_with_v6_only(addr, v6_only).await {
+            Ok(listener) => Some(listener),
+            Err(error) => {
+                error!(message = "Failed to bind to listener socket.", %error);
+                None
+            }
+        },
+        SocketListenAddr::Host(host) => match bind_host(host, |candidate| async move {
+            tls.bind_with_v6_only(&candidate, v6_only).await
+        })
+        .await
+        {
             Ok(listener) => Some(listener),
             Err(error) => {
                 error!(message = "Failed to bind to listener socket.", %error);
@@ -120,6 +138,7 @@ where
     fn run(
         self,
         addr: SocketListenAddr,
+        v6_only: Option<bool>,
         keepalive: Option<TcpKeepaliveConfig>,
         shutdown_timeout_secs: u64,
         tls: MaybeTlsSettings,
@@ -128,13 +147,14 @@ where
         cx: SourceContext,
         acknowledgements: AcknowledgementsConfig,
         max_connections: Option<u32>,
+        max_events_per_second: Option<NonZeroU32>,
     ) -> crate::Result<crate::sources::Source> {
         let acknowledgements = cx.do_acknowledgements(&acknowledgements);
 
         let listenfd = ListenFd::from_env();
 
         Ok(Box::pin(async move {
-            let listener = match make_listener(addr, listenfd, &tls).await {
+            let listener = match make_listener(&addr, v6_only, listenfd, &tls).await {
                 None => return Err(()),
                 Some(listener) => listener,
             };
@@ -214,6 +234,7 @@ where
                                 acknowledgements,
                                 request_limiter,
                                 tls_client_metadata_key.clone(),
+                                max_events_per_second,
                             );
 
                             tokio::spawn(
@@ -245,6 +266,7 @@ async fn handle_stream<T>(
     acknowledgements: bool,
     request_limiter: RequestLimiter,
     tls_client_metadata_key: Option<String>,
+    max_events_per_second: Option<NonZeroU32>,
 ) where
     <<T as TcpSource>::Decoder as tokio_util::codec::Decoder>::Item: std::marker::Send,
     T: TcpSource,
@@ -289,6 +311,8 @@ async fn handle_stream<T>(
     let reader = FramedRead::new(socket, source.decoder());
     let mut reader = ReadyFrames::new(reader);
 
+    let mut rate_limiter = max_events_per_second.map(ConnectionRateLimiter::new);
+
     loop {
         let mut permit = tokio::select! {
             _ = &mut tripwire => break,
@@ -307,6 +331,8 @@ async fn handle_stream<T>(
         let timeout = tokio::time::sleep(Duration::from_millis(10));
         tokio::pin!(timeout);
 
+        let mut paused_until = None;
+
         tokio::select! {
             _ = &mut tripwire => break,
             _ = &mut shutdown_signal => {
@@ -336,6 +362,10 @@ async fn handle_stream<T>(
                             count,
                         });
 
+                        paused_until = rate_limiter
+                            .as_mut()
+                            .and_then(|rate_limiter| rate_limiter.record(count, tokio::time::Instant::now()));
+
                         if let Some(permit) = &mut permit {
                             // Note that this is intentionally not the "number of events in a single request", but rather
                             // the "number of events currently available". This may contain events from multiple events,
@@ -413,6 +443,24 @@ async fn handle_stream<T>(
         }
 
         drop(permit);
+
+        // Reads are paused without holding a permit, so that other connections can be read from.
+        if let Some(paused_until) = paused_until {
+            emit!(TcpConnectionThrottled {
+                peer_addr,
+                delay: paused_until.saturating_duration_since(tokio::time::Instant::now()),
+            });
+
+            tokio::select! {
+                _ = &mut tripwire => break,
+                _ = &mut shutdown_signal => {
+                    if close_socket(reader.get_ref().get_ref().get_ref()) {
+                        break;
+                    }
+                },
+                _ = tokio::time::sleep_until(paused_until) => {},
+            }
+        }
     }
 }
 
@@ -435,7 +483,7 @@ fn close_socket(socket: &MaybeTlsIncomingStream<TcpStream>) -> bool {
 
 /// A listening address that can be given directly or be managed via `systemd` socket activation.
 #[configurable_component]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[serde(untagged)]
 pub enum SocketListenAddr {
     /// An IPv4/IPv6 address and port.
@@ -444,6 +492,12 @@ pub enum SocketListenAddr {
     /// A file descriptor identifier that is given from, and managed by, the socket activation feature of `systemd`.
     #[serde(deserialize_with = "parse_systemd_fd")]
     SystemdFd(#[configurable(transparent)] usize),
+
+    /// A hostname and port, such as `localhost:9000`, resolved when binding.
+    ///
+    /// The first of the resolved addresses that can be bound to is listened on.
+    #[serde(deserialize_with = "parse_host")]
+    Host(#[configurable(transparent)] String),
 }
 
 impl fmt::Display for SocketListenAddr {
@@ -451,6 +505,7 @@ impl fmt::Display for SocketListenAddr {
         match self {
             Self::SocketAddr(ref addr) => addr.fmt(f),
             Self::SystemdFd(offset) => write!(f, "systemd socket #{}", offset),
+            Self::Host(host) => host.fmt(f),
         }
     }
 }
@@ -466,6 +521,7 @@ impl From<SocketListenAddr> for Resource {
         match addr {
             SocketListenAddr::SocketAddr(addr) => Resource::tcp(addr),
             SocketListenAddr::SystemdFd(offset) => Self::SystemFdOffset(offset),
+            SocketListenAddr::Host(host) => Resource::Host(host, Protocol::Tcp),
         }
     }
 }
@@ -488,7 +544,7 @@ where
 
 #[cfg(test)]
 mod test {
-    use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 
     use serde::Deserialize;
 
@@ -513,5 +569,31 @@ mod test {
         assert_eq!(test.addr, SocketListenAddr::SystemdFd(0));
         let test: Config = toml::from_str(r#"addr="systemd#3""#).unwrap();
         assert_eq!(test.addr, SocketListenAddr::SystemdFd(2));
+        let test: Config = toml::from_str(r#"addr="[::1]:1234""#).unwrap();
+        assert_eq!(
+            test.addr,
+            SocketListenAddr::SocketAddr(SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::LOCALHOST,
+                1234,
+                0,
+                0
+            )))
+        );
+        let test: Config = toml::from_str(r#"addr="localhost:1234""#).unwrap();
+        assert_eq!(test.addr, SocketListenAddr::Host("localhost:1234".into()));
+        assert!(toml::from_str::<Config>(r#"addr="localhost""#).is_err());
+    }
+
+    #[tokio::test]
+    async fn binds_hostname() {
+        let listener = make_listener(
+            &SocketListenAddr::Host("localhost:0".into()),
+            None,
+            ListenFd::empty(),
+            &MaybeTlsSettings::Raw(()),
+        )
+        .await
+        .expect("localhost should resolve to a bindable address");
+        assert!(listener.local_addr().unwrap().ip().is_loopback());
     }
 }
@@ -91,7 +91,8 @@ impl VectorConfig {
 
         let tls = MaybeTlsSettings::from_config(&tls_config, true)?;
         vector.run(
-            self.address,
+            self.address.clone(),
+            None,
             self.keepalive,
             self.shutdown_timeout_secs,
             tls,
@@ -100,6 +101,7 @@ impl VectorConfig {
             cx,
             false.into(),
             None,
+            None,
         )
     }
 
@@ -112,7 +114,7 @@ impl VectorConfig {
     }
 
     pub(super) fn resources(&self) -> Vec<Resource> {
-        vec![self.address.into()]
+        vec![self.address.clone().into()]
     }
 }
 
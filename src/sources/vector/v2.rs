@@ -137,8 +137,8 @@ impl VectorConfig {
         })
         .accept_gzip();
 
-        let source =
-            run_grpc_server(self.address, tls_settings, service, cx.shutdown).map_err(|error| {
+        let source = run_grpc_server(self.address, tls_settings, service, cx.shutdown, true)
+            .map_err(|error| {
                 error!(message = "Source future failed.", %error);
             });
 
@@ -1,13 +1,15 @@
 use std::{borrow::Cow, collections::BTreeMap, time::Duration};
 
+use chrono::{DateTime, Utc};
 use colored::{ColoredString, Colorize};
 use tokio_stream::StreamExt;
 use url::Url;
 use vector_api_client::{
     connect_subscription_client,
     gql::{
+        component_errors_query::ComponentErrorsQueryComponentErrorsEvent,
         output_events_by_component_id_patterns_subscription::OutputEventsByComponentIdPatternsSubscriptionOutputEventsByComponentIdPatterns,
-        TapEncodingFormat, TapSubscriptionExt,
+        ComponentErrorsQueryExt, TapEncodingFormat, TapSubscriptionExt,
     },
     Client,
 };
@@ -34,8 +36,16 @@ pub(crate) async fn cmd(opts: &super::Opts, mut signal_rx: SignalRx) -> exitcode
 
     // Return early with instructions for enabling the API if the endpoint isn't reachable
     // via a healthcheck.
-    if Client::new_with_healthcheck(url.clone()).await.is_none() {
-        return exitcode::UNAVAILABLE;
+    let client = match Client::new_with_healthcheck(url.clone()).await {
+        Some(client) => client,
+        None => return exitcode::UNAVAILABLE,
+    };
+
+    let formatter = EventFormatter::new(opts.meta, opts.format);
+
+    // Dropped events are retained by the component, so they're polled over HTTP.
+    if let Some(component_id) = &opts.errors_of {
+        return errors(client, component_id, opts, formatter, signal_rx).await;
     }
 
     // Change the HTTP schema to WebSockets.
@@ -59,8 +69,6 @@ pub(crate) async fn cmd(opts: &super::Opts, mut signal_rx: SignalRx) -> exitcode
             .collect()
     };
 
-    let formatter = EventFormatter::new(opts.meta, opts.format);
-
     loop {
         tokio::select! {
             biased;
@@ -138,6 +146,92 @@ async fn run(
     }
 }
 
+/// Prints the events dropped by a component every `interval`, skipping those already printed.
+async fn errors(
+    client: Client,
+    component_id: &str,
+    opts: &super::Opts,
+    formatter: EventFormatter,
+    mut signal_rx: SignalRx,
+) -> exitcode::ExitCode {
+    let mut interval = tokio::time::interval(Duration::from_millis(opts.interval.into()));
+    let mut last_printed: Option<DateTime<Utc>> = None;
+
+    #[allow(clippy::print_stdout)]
+    #[allow(clippy::print_stderr)]
+    loop {
+        tokio::select! {
+            biased;
+            Ok(SignalTo::Shutdown | SignalTo::Quit) = signal_rx.recv() => break,
+            _ = interval.tick() => {},
+        }
+
+        let dropped = match client
+            .component_errors_query(component_id.to_owned(), opts.format, opts.limit.into())
+            .await
+        {
+            Ok(res) => res.data.map(|d| d.component_errors).unwrap_or_default(),
+            Err(e) => {
+                eprintln!("[tap] Couldn't query dropped events: {}", e);
+                return exitcode::UNAVAILABLE;
+            }
+        };
+
+        // Dropped events are returned newest first.
+        for dropped in dropped
+            .iter()
+            .rev()
+            .filter(|dropped| last_printed.map_or(true, |last| dropped.timestamp > last))
+        {
+            if !opts.quiet {
+                eprintln!("[tap] Dropped at {}: {}", dropped.timestamp, dropped.reason);
+            }
+            match &dropped.event {
+                ComponentErrorsQueryComponentErrorsEvent::Log(ev) => {
+                    println!(
+                        "{}",
+                        formatter.format(
+                            ev.component_id.as_ref(),
+                            ev.component_kind.as_ref(),
+                            ev.component_type.as_ref(),
+                            ev.string.as_ref()
+                        )
+                    );
+                }
+                ComponentErrorsQueryComponentErrorsEvent::Metric(ev) => {
+                    println!(
+                        "{}",
+                        formatter.format(
+                            ev.component_id.as_ref(),
+                            ev.component_kind.as_ref(),
+                            ev.component_type.as_ref(),
+                            ev.string.as_ref()
+                        )
+                    );
+                }
+                ComponentErrorsQueryComponentErrorsEvent::Trace(ev) => {
+                    println!(
+                        "{}",
+                        formatter.format(
+                            ev.component_id.as_ref(),
+                            ev.component_kind.as_ref(),
+                            ev.component_type.as_ref(),
+                            ev.string.as_ref()
+                        )
+                    );
+                }
+                ComponentErrorsQueryComponentErrorsEvent::EventNotification(_) => {}
+            }
+        }
+
+        if let Some(newest) = dropped.first() {
+            last_printed = Some(newest.timestamp);
+        }
+    }
+
+    exitcode::OK
+}
+
 #[derive(Clone)]
 struct EventFormatter {
     meta: bool,
@@ -36,6 +36,10 @@ pub struct Opts {
     #[clap(use_value_delimiter(true), long)]
     inputs_of: Vec<String>,
 
+    /// Component ID whose most recently dropped events to observe, if it's configured with `dropped_event_samples`
+    #[clap(long)]
+    errors_of: Option<String>,
+
     /// Quiet output includes only events
     #[clap(short, long)]
     quiet: bool,
@@ -1,5 +1,7 @@
-use socket2::SockRef;
-use tokio::net::TcpStream;
+use std::net::SocketAddr;
+
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+use tokio::net::{TcpListener, TcpStream};
 use vector_config::configurable_component;
 
 /// TCP keepalive settings for socket-based components.
@@ -31,3 +33,61 @@ pub(crate) fn set_receive_buffer_size(socket: &TcpStream, size: usize) -> std::i
 pub(crate) fn set_send_buffer_size(socket: &TcpStream, size: usize) -> std::io::Result<()> {
     SockRef::from(socket).set_send_buffer_size(size)
 }
+
+/// Binds a TCP listener to the address, setting `IPV6_V6ONLY` on IPv6 sockets when `v6_only` is given.
+///
+/// Otherwise the platform default applies: IPv6 sockets bound to `[::]` also accept IPv4 connections on Linux,
+/// unless disabled system-wide, but not on Windows or the BSDs.
+pub(crate) fn bind_listener(
+    addr: SocketAddr,
+    v6_only: Option<bool>,
+) -> std::io::Result<TcpListener> {
+    let socket = Socket::new(Domain::for_address(addr), Type::STREAM, Some(Protocol::TCP))?;
+    // Like `TcpListener::bind`, this allows binding to addresses of connections in `TIME_WAIT`.
+    #[cfg(unix)]
+    socket.set_reuse_address(true)?;
+    if let (SocketAddr::V6(_), Some(v6_only)) = (addr, v6_only) {
+        socket.set_only_v6(v6_only)?;
+    }
+    socket.bind(&addr.into())?;
+    socket.listen(1024)?;
+    socket.set_nonblocking(true)?;
+    TcpListener::from_std(socket.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    use super::*;
+
+    /// Binds to the IPv6 loopback address, or returns `None` if IPv6 isn't available, as in some CI environments.
+    fn bind_v6_loopback(v6_only: Option<bool>) -> Option<TcpListener> {
+        let addr = SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0));
+        match bind_listener(addr, v6_only) {
+            Ok(listener) => Some(listener),
+            Err(error) => {
+                eprintln!("Skipping test, IPv6 is unavailable: {}", error);
+                None
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn binds_v6_loopback() {
+        if let Some(listener) = bind_v6_loopback(None) {
+            let addr = listener.local_addr().unwrap();
+            assert!(addr.is_ipv6());
+            TcpStream::connect(addr).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn sets_v6_only() {
+        for v6_only in [true, false] {
+            if let Some(listener) = bind_v6_loopback(Some(v6_only)) {
+                assert_eq!(SockRef::from(&listener).only_v6().unwrap(), v6_only);
+            }
+        }
+    }
+}
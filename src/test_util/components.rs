@@ -110,6 +110,23 @@ pub static SINK_TESTS: Lazy<ComponentTests> = Lazy::new(|| {
     }
 });
 
+/// The component test specification for push-based sinks built on the common sink driver, which
+/// additionally reports the state of the requests it dispatches.
+pub static DRIVER_SINK_TESTS: Lazy<ComponentTests> = Lazy::new(|| ComponentTests {
+    events: &[
+        "BytesSent",
+        "EventsSent",
+        "SinkRequestsInFlight",
+        "SinkRequestQueueDepth",
+        "SinkRequestDuration",
+    ],
+    tagged_counters: &["component_sent_bytes_total"],
+    untagged_counters: &[
+        "component_sent_events_total",
+        "component_sent_event_bytes_total",
+    ],
+});
+
 /// The component test specification for sinks which simply expose data, or do not otherwise "send" it anywhere.
 pub static NONSENDING_SINK_TESTS: Lazy<ComponentTests> = Lazy::new(|| ComponentTests {
     events: &["EventsSent"],
@@ -376,6 +393,31 @@ where
     .await;
 }
 
+/// Convenience wrapper for running tests of sinks built on the common sink driver
+#[track_caller]
+pub async fn assert_driver_sink_compliance<T>(tags: &[&str], f: impl Future<Output = T>) -> T {
+    init_test();
+
+    let result = f.await;
+
+    DRIVER_SINK_TESTS.assert(tags);
+
+    result
+}
+
+#[track_caller]
+pub async fn run_and_assert_driver_sink_compliance<S, I>(sink: VectorSink, events: S, tags: &[&str])
+where
+    S: Stream<Item = I> + Send,
+    I: Into<EventArray>,
+{
+    assert_driver_sink_compliance(tags, async move {
+        let events = events.map(Into::into);
+        sink.run(events).await.expect("Running sink failed")
+    })
+    .await;
+}
+
 #[track_caller]
 pub async fn assert_nonsending_sink_compliance<T>(tags: &[&str], f: impl Future<Output = T>) -> T {
     init_test();
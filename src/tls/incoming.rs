@@ -20,6 +20,7 @@ use super::{
     CreateAcceptorSnafu, HandshakeSnafu, IncomingListenerSnafu, MaybeTlsSettings, MaybeTlsStream,
     SslBuildSnafu, TcpBindSnafu, TlsError, TlsSettings,
 };
+use crate::internal_events::TlsHandshakeError;
 #[cfg(feature = "sources-utils-tcp-keepalive")]
 use crate::tcp::TcpKeepaliveConfig;
 #[cfg(feature = "sources-utils-tcp-socket")]
@@ -40,8 +41,20 @@ impl TlsSettings {
 }
 
 impl MaybeTlsSettings {
+    // TODO: The HTTP-based sources, the gRPC servers of `vector` and `opentelemetry`, and the OTLP/HTTP
+    // server bind through here with a `SocketAddr`. They should accept hostnames like the TCP and UDP
+    // listeners of `sources::util`, resolving them with `bind_host`.
     pub(crate) async fn bind(&self, addr: &SocketAddr) -> crate::tls::Result<MaybeTlsListener> {
-        let listener = TcpListener::bind(addr).await.context(TcpBindSnafu)?;
+        self.bind_with_v6_only(addr, None).await
+    }
+
+    /// Binds to the address like `bind`, setting `IPV6_V6ONLY` on IPv6 sockets when `v6_only` is given.
+    pub(crate) async fn bind_with_v6_only(
+        &self,
+        addr: &SocketAddr,
+        v6_only: Option<bool>,
+    ) -> crate::tls::Result<MaybeTlsListener> {
+        let listener = crate::tcp::bind_listener(*addr, v6_only).context(TcpBindSnafu)?;
 
         let acceptor = match self {
             Self::Tls(tls) => Some(tls.acceptor()?),
@@ -296,6 +309,10 @@ impl MaybeTlsIncomingStream<TcpStream> {
                         continue;
                     }
                     Err(error) => {
+                        emit!(TlsHandshakeError {
+                            error: &error,
+                            peer_addr: this.peer_addr,
+                        });
                         let error = io::Error::new(io::ErrorKind::Other, error);
                         this.state = StreamState::AcceptError(error.to_string());
                         Poll::Ready(Err(error))
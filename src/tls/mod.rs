@@ -22,11 +22,15 @@ pub(crate) use maybe_tls::MaybeTls;
 #[cfg(all(test, feature = "kafka-integration-tests"))]
 pub use settings::TEST_PEM_INTERMEDIATE_CA_PATH;
 pub use settings::{
-    MaybeTlsSettings, TlsConfig, TlsEnableableConfig, TlsSettings, TlsSourceConfig,
+    MaybeTlsSettings, TlsClientAuthConfig, TlsConfig, TlsEnableableConfig, TlsSettings,
+    TlsSourceConfig, TlsVersion,
 };
 #[cfg(test)]
 pub use settings::{TEST_PEM_CA_PATH, TEST_PEM_CRT_PATH, TEST_PEM_KEY_PATH};
-#[cfg(all(test, feature = "sources-socket"))]
+#[cfg(all(
+    test,
+    any(feature = "sources-socket", feature = "sources-opentelemetry")
+))]
 pub use settings::{TEST_PEM_CLIENT_CRT_PATH, TEST_PEM_CLIENT_KEY_PATH};
 
 pub type Result<T> = std::result::Result<T, TlsError>;
@@ -103,6 +107,21 @@ pub enum TlsError {
     AddCertToStore { source: ErrorStack },
     #[snafu(display("Error setting up the verification certificate: {}", source))]
     SetVerifyCert { source: ErrorStack },
+    #[snafu(display("Error setting the minimum TLS version: {}", source))]
+    SetMinProtoVersion { source: ErrorStack },
+    #[snafu(display(
+        "Could not load certificate revocation list {:?}: {}",
+        filename,
+        source
+    ))]
+    CrlLoad {
+        filename: PathBuf,
+        source: ErrorStack,
+    },
+    #[snafu(display("Error enabling certificate revocation checks: {}", source))]
+    SetCrlCheck { source: ErrorStack },
+    #[snafu(display("TLS client authentication requires TLS to be enabled"))]
+    ClientAuthWithoutTls,
     #[snafu(display("PKCS#12 parse failed: {}", source))]
     ParsePkcs12 { source: ErrorStack },
     #[snafu(display("TCP bind failed: {}", source))]
@@ -8,18 +8,23 @@ use std::{
 use openssl::{
     pkcs12::{ParsedPkcs12, Pkcs12},
     pkey::{PKey, Private},
-    ssl::{ConnectConfiguration, SslContextBuilder, SslVerifyMode},
+    ssl::{ConnectConfiguration, SslContextBuilder, SslFiletype, SslVerifyMode, SslVersion},
     stack::Stack,
-    x509::{store::X509StoreBuilder, X509},
+    x509::{
+        store::{X509Lookup, X509StoreBuilder},
+        verify::X509VerifyFlags,
+        X509,
+    },
 };
 use snafu::ResultExt;
 use vector_config::configurable_component;
 
 use super::{
-    AddCertToStoreSnafu, AddExtraChainCertSnafu, CaStackPushSnafu, DerExportSnafu,
+    AddCertToStoreSnafu, AddExtraChainCertSnafu, CaStackPushSnafu, CrlLoadSnafu, DerExportSnafu,
     FileOpenFailedSnafu, FileReadFailedSnafu, MaybeTls, NewCaStackSnafu, NewStoreBuilderSnafu,
     ParsePkcs12Snafu, Pkcs12Snafu, PrivateKeyParseSnafu, Result, SetCertificateSnafu,
-    SetPrivateKeySnafu, SetVerifyCertSnafu, TlsError, TlsIdentitySnafu, X509ParseSnafu,
+    SetCrlCheckSnafu, SetMinProtoVersionSnafu, SetPrivateKeySnafu, SetVerifyCertSnafu, TlsError,
+    TlsIdentitySnafu, X509ParseSnafu,
 };
 
 const PEM_START_MARKER: &str = "-----BEGIN ";
@@ -34,10 +39,16 @@ pub const TEST_PEM_CRT_PATH: &str =
     "tests/data/ca/intermediate_server/certs/localhost-chain.cert.pem";
 #[cfg(test)]
 pub const TEST_PEM_KEY_PATH: &str = "tests/data/ca/intermediate_server/private/localhost.key.pem";
-#[cfg(all(test, feature = "sources-socket"))]
+#[cfg(all(
+    test,
+    any(feature = "sources-socket", feature = "sources-opentelemetry")
+))]
 pub const TEST_PEM_CLIENT_CRT_PATH: &str =
     "tests/data/ca/intermediate_client/certs/localhost-chain.cert.pem";
-#[cfg(all(test, feature = "sources-socket"))]
+#[cfg(all(
+    test,
+    any(feature = "sources-socket", feature = "sources-opentelemetry")
+))]
 pub const TEST_PEM_CLIENT_KEY_PATH: &str =
     "tests/data/ca/intermediate_client/private/localhost.key.pem";
 
@@ -133,6 +144,67 @@ pub struct TlsConfig {
     ///
     /// This has no effect unless `key_file` is set.
     pub key_pass: Option<String>,
+
+    /// The minimum version of the TLS protocol to negotiate.
+    ///
+    /// Handshakes with peers that only support older versions are rejected. By default, the minimum version of the
+    /// TLS library is used.
+    ///
+    /// Relevant for both incoming and outgoing connections.
+    pub min_tls_version: Option<TlsVersion>,
+}
+
+/// Client certificate authentication of incoming TLS connections.
+///
+/// Clients must present a certificate issued by the given CA during the TLS handshake, and connections of clients
+/// that don't are rejected.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TlsClientAuthConfig {
+    /// Absolute path to the CA certificate file that client certificates must be issued by.
+    ///
+    /// The CAs of `tls.ca_file` are trusted as well.
+    ///
+    /// The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+    pub ca_file: PathBuf,
+
+    /// Absolute path to a certificate revocation list file, in PEM format.
+    ///
+    /// Clients presenting a certificate revoked by the list are rejected.
+    pub crl_file: Option<PathBuf>,
+}
+
+/// A version of the TLS protocol.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TlsVersion {
+    /// TLS 1.0.
+    #[serde(rename = "TLSv1.0")]
+    Tls10,
+
+    /// TLS 1.1.
+    #[serde(rename = "TLSv1.1")]
+    Tls11,
+
+    /// TLS 1.2.
+    #[serde(rename = "TLSv1.2")]
+    Tls12,
+
+    /// TLS 1.3.
+    #[serde(rename = "TLSv1.3")]
+    Tls13,
+}
+
+impl From<TlsVersion> for SslVersion {
+    fn from(version: TlsVersion) -> Self {
+        match version {
+            TlsVersion::Tls10 => SslVersion::TLS1,
+            TlsVersion::Tls11 => SslVersion::TLS1_1,
+            TlsVersion::Tls12 => SslVersion::TLS1_2,
+            TlsVersion::Tls13 => SslVersion::TLS1_3,
+        }
+    }
 }
 
 impl TlsConfig {
@@ -154,6 +226,8 @@ pub struct TlsSettings {
     pub(super) verify_hostname: bool,
     authorities: Vec<X509>,
     pub(super) identity: Option<IdentityStore>, // openssl::pkcs12::ParsedPkcs12 doesn't impl Clone yet
+    min_tls_version: Option<TlsVersion>,
+    crl_file: Option<PathBuf>,
 }
 
 #[derive(Clone)]
@@ -187,9 +261,36 @@ impl TlsSettings {
             verify_hostname: options.verify_hostname.unwrap_or(!for_server),
             authorities: options.load_authorities()?,
             identity: options.load_identity()?,
+            min_tls_version: options.min_tls_version,
+            crl_file: None,
         })
     }
 
+    /// Requires the clients of incoming connections to present a certificate issued by the CA of
+    /// `client_auth`, and not revoked by its revocation list.
+    ///
+    /// The CAs of `ca_file` remain trusted along with the CA of `client_auth`.
+    pub fn with_client_auth(mut self, client_auth: &TlsClientAuthConfig) -> Result<Self> {
+        if let Some(crl_file) = &client_auth.crl_file {
+            // The list is loaded by the certificate store, but missing files are reported early.
+            open_read(crl_file, "certificate revocation list")?;
+        }
+        self.verify_certificate = true;
+        for authority in load_authorities(&client_auth.ca_file)? {
+            // Certificate stores may reject certificates they already hold.
+            let der = authority.to_der().ok();
+            if !self
+                .authorities
+                .iter()
+                .any(|existing| existing.to_der().ok() == der)
+            {
+                self.authorities.push(authority);
+            }
+        }
+        self.crl_file = client_auth.crl_file.clone();
+        Ok(self)
+    }
+
     fn identity(&self) -> Option<ParsedPkcs12> {
         // This data was test-built previously, so we can just use it
         // here and expect the results will not fail. This can all be
@@ -239,6 +340,11 @@ impl TlsSettings {
         } else {
             SslVerifyMode::NONE
         });
+        if let Some(version) = self.min_tls_version {
+            context
+                .set_min_proto_version(Some(version.into()))
+                .context(SetMinProtoVersionSnafu)?;
+        }
         if let Some(identity) = self.identity() {
             context
                 .set_certificate(&identity.cert)
@@ -261,6 +367,15 @@ impl TlsSettings {
                     .add_cert(authority.clone())
                     .context(AddCertToStoreSnafu)?;
             }
+            if let Some(crl_file) = &self.crl_file {
+                store
+                    .add_lookup(X509Lookup::file())
+                    .and_then(|lookup| lookup.load_crl_file(crl_file, SslFiletype::PEM))
+                    .with_context(|_| CrlLoadSnafu { filename: crl_file })?;
+                store
+                    .set_flags(X509VerifyFlags::CRL_CHECK)
+                    .context(SetCrlCheckSnafu)?;
+            }
             context
                 .set_verify_cert_store(store.build())
                 .context(SetVerifyCertSnafu)?;
@@ -286,19 +401,7 @@ impl TlsConfig {
     fn load_authorities(&self) -> Result<Vec<X509>> {
         match &self.ca_file {
             None => Ok(vec![]),
-            Some(filename) => {
-                let (data, filename) = open_read(filename, "certificate")?;
-                der_or_pem(
-                    data,
-                    |der| X509::from_der(&der).map(|x509| vec![x509]),
-                    |pem| {
-                        pem.match_indices(PEM_START_MARKER)
-                            .map(|(start, _)| X509::from_pem(pem[start..].as_bytes()))
-                            .collect()
-                    },
-                )
-                .with_context(|_| X509ParseSnafu { filename })
-            }
+            Some(filename) => load_authorities(filename),
         }
     }
 
@@ -451,6 +554,7 @@ impl fmt::Debug for TlsSettings {
         f.debug_struct("TlsSettings")
             .field("verify_certificate", &self.verify_certificate)
             .field("verify_hostname", &self.verify_hostname)
+            .field("min_tls_version", &self.min_tls_version)
             .finish()
     }
 }
@@ -506,6 +610,21 @@ impl From<TlsSettings> for MaybeTlsSettings {
     }
 }
 
+/// Load the CA certificates of a named file
+fn load_authorities(filename: &Path) -> Result<Vec<X509>> {
+    let (data, filename) = open_read(filename, "certificate")?;
+    der_or_pem(
+        data,
+        |der| X509::from_der(&der).map(|x509| vec![x509]),
+        |pem| {
+            pem.match_indices(PEM_START_MARKER)
+                .map(|(start, _)| X509::from_pem(pem[start..].as_bytes()))
+                .collect()
+        },
+    )
+    .with_context(|_| X509ParseSnafu { filename })
+}
+
 /// Load a private key from a named file
 fn load_key(filename: &Path, pass_phrase: &Option<String>) -> Result<PKey<Private>> {
     let (data, filename) = open_read(filename, "key")?;
@@ -662,6 +781,23 @@ mod test {
         assert_eq!(settings.authorities.len(), 2);
     }
 
+    #[test]
+    fn client_auth_merges_authorities() {
+        let options = TlsConfig {
+            ca_file: Some(TEST_PEM_CA_PATH.into()),
+            ..Default::default()
+        };
+        let client_auth = TlsClientAuthConfig {
+            ca_file: "tests/data/ca/intermediate_server/certs/ca-chain.cert.pem".into(),
+            crl_file: None,
+        };
+        let settings = TlsSettings::from_options(&Some(options))
+            .and_then(|settings| settings.with_client_auth(&client_auth))
+            .expect("Failed to load authority certificates");
+        // The root CA is in both files, and is only kept once.
+        assert_eq!(settings.authorities.len(), 2);
+    }
+
     #[test]
     fn from_options_none() {
         let settings = TlsSettings::from_options(&None).expect("Failed to generate null settings");
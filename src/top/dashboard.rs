@@ -104,7 +104,7 @@ fn format_metric(total: i64, throughput: i64, human_metrics: bool) -> String {
     }
 }
 
-const NUM_COLUMNS: usize = 8;
+const NUM_COLUMNS: usize = 9;
 static HEADER: [&str; NUM_COLUMNS] = [
     "ID",
     "Output",
@@ -113,6 +113,7 @@ static HEADER: [&str; NUM_COLUMNS] = [
     "Events In",
     "Events Out",
     "Bytes",
+    "In Flight",
     "Errors",
 ];
 
@@ -204,6 +205,7 @@ impl<'a> Widgets<'a> {
                     r.processed_bytes_throughput_sec,
                     self.opts.human_metrics,
                 ),
+                r.requests_in_flight.thousands_format(),
                 if self.opts.human_metrics {
                     r.errors.human_format()
                 } else {
@@ -239,12 +241,13 @@ impl<'a> Widgets<'a> {
             .column_spacing(2)
             .widths(&[
                 Constraint::Percentage(15), // ID
-                Constraint::Percentage(15), // Output
+                Constraint::Percentage(10), // Output
                 Constraint::Percentage(10), // Kind
                 Constraint::Percentage(10), // Type
                 Constraint::Percentage(10), // Events In
                 Constraint::Percentage(10), // Events Out
                 Constraint::Percentage(10), // Bytes
+                Constraint::Percentage(10), // In Flight
                 Constraint::Percentage(10), // Errors
             ]);
 
@@ -35,6 +35,7 @@ async fn component_added(client: Arc<SubscriptionClient>, tx: state::EventTx) {
                     sent_events_throughput_sec: 0,
                     processed_bytes_total: 0,
                     processed_bytes_throughput_sec: 0,
+                    requests_in_flight: 0,
                     errors: 0,
                 }))
                 .await;
@@ -236,6 +237,30 @@ async fn errors_totals(client: Arc<SubscriptionClient>, tx: state::EventTx, inte
     }
 }
 
+async fn requests_in_flight(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_requests_in_flight_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_requests_in_flight;
+            let _ = tx
+                .send(state::EventType::RequestsInFlight(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.metric.requests_in_flight as i64,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
 /// Subscribe to each metrics channel through a separate client. This is a temporary workaround
 /// until client multiplexing is fixed. In future, we should be able to use a single client
 pub fn subscribe(
@@ -278,6 +303,11 @@ pub fn subscribe(
             tx.clone(),
             interval,
         )),
+        tokio::spawn(requests_in_flight(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
         tokio::spawn(errors_totals(Arc::clone(&client), tx, interval)),
     ]
 }
@@ -321,6 +351,7 @@ pub async fn init_components(client: &Client) -> Result<state::State, ()> {
                         sent_events_throughput_sec: 0,
                         processed_bytes_total: d.on.processed_bytes_total(),
                         processed_bytes_throughput_sec: 0,
+                        requests_in_flight: 0,
                         errors: 0,
                     },
                 ))
@@ -32,6 +32,7 @@ pub enum EventType {
     /// Interval + identified metric
     ProcessedBytesThroughputs(i64, Vec<IdentifiedMetric>),
     ErrorsTotals(Vec<IdentifiedMetric>),
+    RequestsInFlight(Vec<IdentifiedMetric>),
     ComponentAdded(ComponentRow),
     ComponentRemoved(ComponentKey),
     ConnectionUpdated(ConnectionStatus),
@@ -118,6 +119,7 @@ pub struct ComponentRow {
     pub received_events_throughput_sec: i64,
     pub sent_events_total: i64,
     pub sent_events_throughput_sec: i64,
+    pub requests_in_flight: i64,
     pub errors: i64,
 }
 
@@ -208,6 +210,13 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         }
                     }
                 }
+                EventType::RequestsInFlight(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.requests_in_flight = v;
+                        }
+                    }
+                }
                 EventType::ComponentAdded(c) => {
                     let _ = state.components.insert(c.key.clone(), c);
                 }
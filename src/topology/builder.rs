@@ -6,7 +6,7 @@ use std::{
     time::Instant,
 };
 
-use futures::{stream::FuturesOrdered, FutureExt, StreamExt};
+use futures::{future::Either, stream::FuturesOrdered, FutureExt, StreamExt};
 use once_cell::sync::Lazy;
 use stream_cancel::{StreamExt as StreamCancelExt, Trigger, Tripwire};
 use tokio::{
@@ -28,6 +28,8 @@ use vector_core::{
 };
 
 use super::{
+    dropped_samples,
+    fallback::{FallbackRouter, FallbackSender},
     fanout::{self, Fanout},
     schema,
     task::{Task, TaskOutput},
@@ -128,6 +130,7 @@ pub struct Pieces {
     pub(super) healthchecks: HashMap<ComponentKey, Task>,
     pub(crate) shutdown_coordinator: SourceShutdownCoordinator,
     pub(crate) detach_triggers: HashMap<ComponentKey, Trigger>,
+    pub(super) fallbacks: HashMap<ComponentKey, FallbackSender>,
 }
 
 /// Builds only the new pieces, and doesn't check their topology.
@@ -143,6 +146,7 @@ pub async fn build_pieces(
     let mut healthchecks = HashMap::new();
     let mut shutdown_coordinator = SourceShutdownCoordinator::default();
     let mut detach_triggers = HashMap::new();
+    let mut fallbacks = HashMap::new();
 
     let mut errors = vec![];
 
@@ -232,6 +236,8 @@ pub async fn build_pieces(
             schema_definitions,
             schema: config.schema,
         };
+
+        dropped_samples::register(key, source.dropped_event_samples.as_ref());
         let server = match source.inner.build(context).await {
             Err(error) => {
                 errors.push(format!("Source \"{}\": {}", key, error));
@@ -302,6 +308,8 @@ pub async fn build_pieces(
 
         let node = TransformNode::from_parts(key.clone(), transform, &merged_definition);
 
+        dropped_samples::register(key, transform.dropped_event_samples.as_ref());
+
         let transform = match transform.inner.build(&context).await {
             Err(error) => {
                 errors.push(format!("Transform \"{}\": {}", key, error));
@@ -379,6 +387,8 @@ pub async fn build_pieces(
             schema: config.schema,
         };
 
+        let fallback = sink.fallback.clone();
+        dropped_samples::register(key, sink.dropped_event_samples.as_ref());
         let (sink, healthcheck) = match sink.inner.build(cx).await {
             Err(error) => {
                 errors.push(format!("Sink \"{}\": {}", key, error));
@@ -387,6 +397,13 @@ pub async fn build_pieces(
             Ok(built) => built,
         };
 
+        // The fallback sink is connected once it's part of the running topology.
+        let router = fallback.as_ref().map(|fallback| {
+            let sender = FallbackSender::new(fallback.sink.clone());
+            fallbacks.insert(key.clone(), sender.clone());
+            FallbackRouter::new(fallback, sender)
+        });
+
         let (trigger, tripwire) = Tripwire::new();
 
         let sink = async move {
@@ -413,6 +430,10 @@ pub async fn build_pieces(
                             byte_size: events.size_of(),
                         })
                     })
+                    .filter_map(move |events| match &router {
+                        Some(router) => Either::Left(Arc::clone(router).route(events)),
+                        None => Either::Right(ready(Some(events))),
+                    })
                     .take_until_if(tripwire),
             )
             .await
@@ -494,6 +515,7 @@ pub async fn build_pieces(
             healthchecks,
             shutdown_coordinator,
             detach_triggers,
+            fallbacks,
         };
 
         Ok(pieces)
@@ -691,12 +713,12 @@ impl Runner {
 
                             let mut t = self.transform.clone();
                             let mut outputs_buf = self.outputs.new_buf_with_capacity(len);
-                            let task = tokio::spawn(async move {
+                            let task = tokio::spawn(dropped_samples::in_current_scope(async move {
                                 for events in input_arrays {
                                     t.transform_all(events, &mut outputs_buf);
                                 }
                                 outputs_buf
-                            }.in_current_span());
+                            }.in_current_span()));
                             in_flight.push(task);
                         }
                         None => {
@@ -0,0 +1,286 @@
+//! Samples of the events dropped by components.
+//!
+//! Components configured with `dropped_event_samples` retain the most recent events they dropped,
+//! along with the reason they were dropped, so they can be inspected through the API when the
+//! `component_discarded_events_total` metric alone doesn't explain what went wrong.
+//!
+//! Samples are recorded by the [`ComponentEventsDropped`] internal event, into the sampler of the
+//! component whose task emits it. Components emit it with the events they drop, whenever they still
+//! hold them.
+//!
+//! [`ComponentEventsDropped`]: crate::internal_events::ComponentEventsDropped
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use pin_project::pin_project;
+use vector_config::configurable_component;
+
+use crate::{config::ComponentKey, event::Event};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Retains the most recent events dropped by a component, so they can be inspected through the API.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DroppedEventSamplesConfig {
+    /// The number of dropped events retained.
+    pub size: usize,
+
+    /// The fields whose values are redacted from the retained events.
+    #[serde(default)]
+    pub redact_fields: Vec<String>,
+}
+
+static SAMPLERS: Lazy<Mutex<HashMap<ComponentKey, DroppedEventSampler>>> =
+    Lazy::new(Default::default);
+
+thread_local! {
+    /// The sampler of the component being polled on this thread.
+    static CURRENT: RefCell<Option<DroppedEventSampler>> = RefCell::new(None);
+}
+
+/// An event dropped by a component.
+#[derive(Clone, Debug)]
+pub struct DroppedEventSample {
+    pub event: Event,
+    pub reason: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct Samples {
+    size: usize,
+    redact_fields: Vec<String>,
+    /// The retained samples, newest last.
+    samples: VecDeque<DroppedEventSample>,
+}
+
+/// Retains the most recent events dropped by a component.
+#[derive(Clone, Debug)]
+pub struct DroppedEventSampler(Arc<Mutex<Samples>>);
+
+impl DroppedEventSampler {
+    fn new(config: &DroppedEventSamplesConfig) -> Self {
+        Self(Arc::new(Mutex::new(Samples {
+            size: config.size,
+            redact_fields: config.redact_fields.clone(),
+            samples: VecDeque::with_capacity(config.size),
+        })))
+    }
+
+    /// Records a dropped event, evicting the oldest sample if the sampler is full.
+    pub fn record(&self, event: &Event, reason: impl Into<String>) {
+        let mut inner = self.0.lock().expect("poisoned lock");
+        let mut event = event.clone();
+        redact(&mut event, &inner.redact_fields);
+
+        if inner.samples.len() == inner.size {
+            inner.samples.pop_front();
+        }
+        inner.samples.push_back(DroppedEventSample {
+            event,
+            reason: reason.into(),
+            timestamp: Utc::now(),
+        });
+    }
+
+    fn latest(&self, limit: usize) -> Vec<DroppedEventSample> {
+        let inner = self.0.lock().expect("poisoned lock");
+        inner.samples.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+fn redact(event: &mut Event, fields: &[String]) {
+    for field in fields {
+        match event {
+            Event::Log(log) => {
+                if log.contains(field.as_str()) {
+                    log.insert(field.as_str(), REDACTED);
+                }
+            }
+            Event::Metric(metric) => {
+                if metric.tag_value(field).is_some() {
+                    metric.insert_tag(field.clone(), REDACTED.into());
+                }
+            }
+            Event::Trace(trace) => {
+                if trace.contains(field) {
+                    trace.insert(field, REDACTED);
+                }
+            }
+        }
+    }
+}
+
+/// Runs `f` with `sampler` as the sampler of the current component.
+pub(crate) fn scope<R>(sampler: Option<&DroppedEventSampler>, f: impl FnOnce() -> R) -> R {
+    /// Restores the sampler of the enclosing component, even if `f` panics.
+    struct Restore(Option<DroppedEventSampler>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|current| current.replace(sampler.cloned())));
+    f()
+}
+
+/// Whether the current component retains the events it drops, so that it should hold on to them.
+pub(crate) fn is_sampling() -> bool {
+    CURRENT.with(|current| current.borrow().is_some())
+}
+
+/// Records events dropped by the current component, if it retains the events it drops.
+pub(crate) fn record(events: &[Event], reason: &str) {
+    CURRENT.with(|current| {
+        if let Some(sampler) = &*current.borrow() {
+            for event in events {
+                sampler.record(event, reason);
+            }
+        }
+    });
+}
+
+/// Polls a future with the sampler of the current component, like `in_current_span` does with the
+/// tracing span, for tasks spawned by a component.
+pub(crate) fn in_current_scope<F: Future>(future: F) -> Scoped<F> {
+    Scoped {
+        sampler: CURRENT.with(|current| current.borrow().clone()),
+        inner: future,
+    }
+}
+
+/// A future polled with the sampler of a component.
+#[pin_project]
+pub(crate) struct Scoped<F> {
+    sampler: Option<DroppedEventSampler>,
+    #[pin]
+    inner: F,
+}
+
+impl<F> Scoped<F> {
+    pub(crate) fn new(key: &ComponentKey, future: F) -> Self {
+        Self {
+            sampler: sampler(key),
+            inner: future,
+        }
+    }
+}
+
+impl<F: Future> Future for Scoped<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let inner = this.inner;
+        scope(this.sampler.as_ref(), || inner.poll(cx))
+    }
+}
+
+/// Sets up the sampler of a component that is about to be built, replacing any previous one.
+pub(crate) fn register(key: &ComponentKey, config: Option<&DroppedEventSamplesConfig>) {
+    let mut samplers = SAMPLERS.lock().expect("poisoned lock");
+    match config.filter(|config| config.size > 0) {
+        Some(config) => samplers.insert(key.clone(), DroppedEventSampler::new(config)),
+        None => samplers.remove(key),
+    };
+}
+
+/// Removes the sampler of a component that was removed from the topology.
+pub(crate) fn unregister(key: &ComponentKey) {
+    SAMPLERS.lock().expect("poisoned lock").remove(key);
+}
+
+/// Gets the sampler of a component, if it retains the events it drops.
+pub fn sampler(key: &ComponentKey) -> Option<DroppedEventSampler> {
+    SAMPLERS.lock().expect("poisoned lock").get(key).cloned()
+}
+
+/// Gets up to `limit` of the events most recently dropped by a component, newest first.
+pub fn latest(key: &ComponentKey, limit: usize) -> Vec<DroppedEventSample> {
+    sampler(key)
+        .map(|sampler| sampler.latest(limit))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::LogEvent;
+
+    fn config(size: usize, redact_fields: &[&str]) -> DroppedEventSamplesConfig {
+        DroppedEventSamplesConfig {
+            size,
+            redact_fields: redact_fields
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+        }
+    }
+
+    fn message(sample: &DroppedEventSample) -> String {
+        sample.event.as_log()["message"].to_string_lossy()
+    }
+
+    #[test]
+    fn retains_most_recent_samples() {
+        let key = ComponentKey::from("retains_most_recent_samples");
+        register(&key, Some(&config(2, &[])));
+
+        let sampler = sampler(&key).unwrap();
+        for message in ["one", "two", "three"] {
+            sampler.record(&LogEvent::from(message).into(), "error");
+        }
+
+        let samples = latest(&key, 10);
+        assert_eq!(
+            samples.iter().map(message).collect::<Vec<_>>(),
+            vec!["three", "two"]
+        );
+        assert_eq!(latest(&key, 1).len(), 1);
+
+        unregister(&key);
+        assert!(latest(&key, 10).is_empty());
+    }
+
+    #[test]
+    fn redacts_fields() {
+        let key = ComponentKey::from("redacts_fields");
+        register(&key, Some(&config(1, &["user.password", "token"])));
+
+        let mut log = LogEvent::from("login");
+        log.insert("user.password", "hunter2");
+        log.insert("user.name", "jane");
+        sampler(&key).unwrap().record(&log.into(), "error");
+
+        let sample = latest(&key, 1).remove(0);
+        let log = sample.event.as_log();
+        assert_eq!(log["user.password"], REDACTED.into());
+        assert_eq!(log["user.name"], "jane".into());
+        assert!(!log.contains("token"));
+        unregister(&key);
+    }
+
+    #[test]
+    fn disabled_without_size() {
+        let key = ComponentKey::from("disabled_without_size");
+        register(&key, Some(&config(0, &[])));
+        assert!(sampler(&key).is_none());
+
+        register(&key, None);
+        assert!(sampler(&key).is_none());
+    }
+}
@@ -0,0 +1,350 @@
+//! Routing of the events of a failing sink to its fallback sink.
+//!
+//! The delivery status of every batch sent to a sink with a fallback is tracked by a
+//! [`CircuitBreaker`]. While the breaker is open, new batches are sent to the input buffer of the
+//! fallback sink instead, so acknowledgements follow whichever sink actually delivered the events.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use futures::{stream::FuturesUnordered, FutureExt, StreamExt};
+use tokio::sync::mpsc;
+use vector_buffers::topology::channel::BufferSender;
+use vector_common::finalization::AddBatchNotifier;
+use vector_core::event::{
+    BatchNotifier, BatchStatus, BatchStatusReceiver, EventArray, EventContainer,
+};
+
+use crate::{
+    config::{ComponentKey, SinkFallbackConfig},
+    internal_events::{SinkCircuitBreakerClosed, SinkCircuitBreakerOpened, SinkFallbackSendError},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+    /// Batches are sent to the sink.
+    Closed,
+    /// Batches are sent to the fallback sink until the next probe is due.
+    Open { next_probe: Instant },
+    /// A probe batch was sent to the sink, and all other batches are sent to the fallback sink until
+    /// its outcome is known.
+    HalfOpen,
+}
+
+/// Where a batch should be sent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Route {
+    Primary { probe: bool },
+    Fallback,
+}
+
+/// Circuit breaker tracking the delivery status of the batches sent to a sink.
+#[derive(Debug)]
+struct CircuitBreaker {
+    consecutive_failures_threshold: usize,
+    error_rate_threshold: Option<f64>,
+    error_rate_window: usize,
+    probe_interval: Duration,
+    state: State,
+    consecutive_failures: usize,
+    /// Whether each of the most recent batches failed, newest last.
+    outcomes: VecDeque<bool>,
+}
+
+impl CircuitBreaker {
+    fn new(config: &SinkFallbackConfig) -> Self {
+        Self {
+            consecutive_failures_threshold: config.consecutive_failures,
+            error_rate_threshold: config.error_rate,
+            error_rate_window: config.error_rate_window,
+            probe_interval: Duration::from_secs(config.probe_interval_secs),
+            state: State::Closed,
+            consecutive_failures: 0,
+            outcomes: VecDeque::new(),
+        }
+    }
+
+    fn route(&mut self, now: Instant) -> Route {
+        match self.state {
+            State::Closed => Route::Primary { probe: false },
+            State::Open { next_probe } if now >= next_probe => {
+                self.state = State::HalfOpen;
+                Route::Primary { probe: true }
+            }
+            State::Open { .. } | State::HalfOpen => Route::Fallback,
+        }
+    }
+
+    /// Records the outcome of a batch sent to the sink.
+    ///
+    /// Returns the new state if the outcome changed it.
+    fn record(&mut self, delivered: bool, probe: bool, now: Instant) -> Option<State> {
+        match self.state {
+            State::Closed => {
+                if delivered {
+                    self.consecutive_failures = 0;
+                } else {
+                    self.consecutive_failures += 1;
+                }
+                if self.error_rate_threshold.is_some() {
+                    self.outcomes.push_back(!delivered);
+                    if self.outcomes.len() > self.error_rate_window {
+                        self.outcomes.pop_front();
+                    }
+                }
+
+                (self.consecutive_failures >= self.consecutive_failures_threshold
+                    || self.error_rate_exceeded())
+                .then(|| self.open(now))
+            }
+            State::HalfOpen if probe => Some(if delivered {
+                self.close()
+            } else {
+                self.open(now)
+            }),
+            // Outcomes of batches sent before the breaker opened don't tell anything about the
+            // current health of the sink.
+            State::HalfOpen | State::Open { .. } => None,
+        }
+    }
+
+    fn error_rate_exceeded(&self) -> bool {
+        match self.error_rate_threshold {
+            // Don't judge the error rate until the window is full.
+            Some(threshold) if self.outcomes.len() >= self.error_rate_window => {
+                let failures = self.outcomes.iter().filter(|failed| **failed).count();
+                failures as f64 / self.outcomes.len() as f64 >= threshold
+            }
+            _ => false,
+        }
+    }
+
+    fn open(&mut self, now: Instant) -> State {
+        self.state = State::Open {
+            next_probe: now + self.probe_interval,
+        };
+        self.state
+    }
+
+    fn close(&mut self) -> State {
+        self.consecutive_failures = 0;
+        self.outcomes.clear();
+        self.state = State::Closed;
+        self.state
+    }
+}
+
+/// The input buffer of a fallback sink, connected once the fallback sink is part of the running
+/// topology.
+#[derive(Clone, Debug)]
+pub(super) struct FallbackSender {
+    pub(super) fallback: ComponentKey,
+    sender: Arc<Mutex<Option<BufferSender<EventArray>>>>,
+}
+
+impl FallbackSender {
+    pub(super) fn new(fallback: ComponentKey) -> Self {
+        Self {
+            fallback,
+            sender: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub(super) fn connect(&self, sender: Option<BufferSender<EventArray>>) {
+        *self.sender.lock().unwrap() = sender;
+    }
+
+    /// Disconnects from the fallback sink, so that it can shut down.
+    pub(super) fn disconnect(&self) {
+        self.connect(None);
+    }
+
+    fn get(&self) -> Option<BufferSender<EventArray>> {
+        self.sender.lock().unwrap().clone()
+    }
+}
+
+/// Routes the batches of a sink either to the sink itself or its fallback sink.
+pub(super) struct FallbackRouter {
+    breaker: Arc<Mutex<CircuitBreaker>>,
+    sender: FallbackSender,
+    outcomes: mpsc::UnboundedSender<(BatchStatusReceiver, bool)>,
+}
+
+impl FallbackRouter {
+    pub(super) fn new(config: &SinkFallbackConfig, sender: FallbackSender) -> Arc<Self> {
+        let breaker = Arc::new(Mutex::new(CircuitBreaker::new(config)));
+        let (outcomes, receivers) = mpsc::unbounded_channel();
+        tokio::spawn(track_outcomes(
+            Arc::clone(&breaker),
+            sender.fallback.clone(),
+            receivers,
+        ));
+        Arc::new(Self {
+            breaker,
+            sender,
+            outcomes,
+        })
+    }
+
+    /// Returns the batch if it should be sent to the sink, or otherwise sends it to the fallback sink.
+    pub(super) async fn route(self: Arc<Self>, mut events: EventArray) -> Option<EventArray> {
+        let route = self.breaker.lock().unwrap().route(Instant::now());
+        match (route, self.sender.get()) {
+            (Route::Fallback, Some(mut sender)) => {
+                let count = events.len();
+                if sender.send(events).await.is_err() {
+                    emit!(SinkFallbackSendError {
+                        fallback: &self.sender.fallback,
+                        count,
+                    });
+                }
+                None
+            }
+            // Without a connected fallback sink, there's nowhere else to send the batch to.
+            (Route::Fallback, None) => Some(events),
+            (Route::Primary { probe }, _) => {
+                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                events.add_batch_notifier(batch);
+                // The tracking task only stops once the router is dropped.
+                let _ = self.outcomes.send((receiver, probe));
+                Some(events)
+            }
+        }
+    }
+}
+
+/// Records the delivery status of the batches sent to the sink in its circuit breaker, until the
+/// router is dropped and all pending batches are finalized.
+async fn track_outcomes(
+    breaker: Arc<Mutex<CircuitBreaker>>,
+    fallback: ComponentKey,
+    mut receivers: mpsc::UnboundedReceiver<(BatchStatusReceiver, bool)>,
+) {
+    let mut pending = FuturesUnordered::new();
+    loop {
+        tokio::select! {
+            Some((receiver, probe)) = receivers.recv() => {
+                pending.push(receiver.map(move |status| (status, probe)));
+            }
+            Some((status, probe)) = pending.next() => {
+                let delivered = status == BatchStatus::Delivered;
+                let transition = breaker
+                    .lock()
+                    .unwrap()
+                    .record(delivered, probe, Instant::now());
+                match transition {
+                    Some(State::Open { .. }) if !probe => {
+                        emit!(SinkCircuitBreakerOpened {
+                            fallback: &fallback
+                        })
+                    }
+                    Some(State::Closed) => emit!(SinkCircuitBreakerClosed),
+                    _ => {}
+                }
+            }
+            else => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(error_rate: Option<f64>) -> SinkFallbackConfig {
+        SinkFallbackConfig {
+            sink: ComponentKey::from("fallback"),
+            consecutive_failures: 3,
+            error_rate,
+            error_rate_window: 4,
+            probe_interval_secs: 10,
+        }
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let mut breaker = CircuitBreaker::new(&config(None));
+        let now = Instant::now();
+
+        assert_eq!(breaker.record(false, false, now), None);
+        assert_eq!(breaker.record(false, false, now), None);
+        // A delivered batch resets the consecutive failures.
+        assert_eq!(breaker.record(true, false, now), None);
+        assert_eq!(breaker.record(false, false, now), None);
+        assert_eq!(breaker.record(false, false, now), None);
+        assert_eq!(breaker.route(now), Route::Primary { probe: false });
+
+        assert_eq!(
+            breaker.record(false, false, now),
+            Some(State::Open {
+                next_probe: now + Duration::from_secs(10)
+            })
+        );
+        assert_eq!(breaker.route(now), Route::Fallback);
+    }
+
+    #[test]
+    fn opens_after_error_rate() {
+        let mut breaker = CircuitBreaker::new(&config(Some(0.5)));
+        let now = Instant::now();
+
+        assert_eq!(breaker.record(false, false, now), None);
+        assert_eq!(breaker.record(true, false, now), None);
+        assert_eq!(breaker.record(false, false, now), None);
+        assert!(matches!(
+            breaker.record(true, false, now),
+            Some(State::Open { .. })
+        ));
+    }
+
+    #[test]
+    fn probes_while_open() {
+        let mut breaker = CircuitBreaker::new(&config(None));
+        let now = Instant::now();
+        breaker.open(now);
+
+        assert_eq!(breaker.route(now + Duration::from_secs(5)), Route::Fallback);
+
+        // Once the probe interval passed, a single batch probes the sink.
+        let later = now + Duration::from_secs(10);
+        assert_eq!(breaker.route(later), Route::Primary { probe: true });
+        assert_eq!(breaker.state, State::HalfOpen);
+        assert_eq!(breaker.route(later), Route::Fallback);
+
+        // Outcomes of batches sent before the breaker opened are ignored.
+        assert_eq!(breaker.record(true, false, later), None);
+        assert_eq!(breaker.state, State::HalfOpen);
+
+        // A failed probe opens the breaker again.
+        assert_eq!(
+            breaker.record(false, true, later),
+            Some(State::Open {
+                next_probe: later + Duration::from_secs(10)
+            })
+        );
+        assert_eq!(breaker.route(later), Route::Fallback);
+    }
+
+    #[test]
+    fn closes_after_successful_probe() {
+        let mut breaker = CircuitBreaker::new(&config(Some(0.5)));
+        let now = Instant::now();
+        for _ in 0..3 {
+            breaker.record(false, false, now);
+        }
+        assert!(matches!(breaker.state, State::Open { .. }));
+
+        let later = now + Duration::from_secs(10);
+        assert_eq!(breaker.route(later), Route::Primary { probe: true });
+        assert_eq!(breaker.record(true, true, later), Some(State::Closed));
+        assert_eq!(breaker.route(later), Route::Primary { probe: false });
+
+        // The failures before the breaker closed are forgotten.
+        assert_eq!(breaker.record(false, false, later), None);
+        assert_eq!(breaker.record(false, false, later), None);
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+
+use metrics::{Key, Label};
+
+use crate::{
+    config::Config,
+    internal_events::{VectorBuildInfo, VectorComponentInfo, VectorConfigHashInfo},
+    metrics::Controller,
+};
+
+const BUILD_INFO: &str = "build_info";
+const CONFIG_HASH_INFO: &str = "config_hash_info";
+const COMPONENT_INFO: &str = "component_info";
+
+/// Reports the build and the running `config` as info gauges, so that fleets can be checked for
+/// drift.
+///
+/// The series of a previous configuration, such as the components removed by a reload, are
+/// retracted. As they're only set here, they're exempt from `expire_metrics_secs`.
+pub(super) fn report_config_info(config: &Config) {
+    let components = config
+        .sources()
+        .map(|(key, source)| (key.id(), "source", source.inner.source_type()))
+        .chain(
+            config
+                .transforms()
+                .map(|(key, transform)| (key.id(), "transform", transform.inner.transform_type())),
+        )
+        .chain(
+            config
+                .sinks()
+                .map(|(key, sink)| (key.id(), "sink", sink.inner.sink_type())),
+        )
+        .collect::<HashSet<_>>();
+
+    if let Ok(controller) = Controller::get() {
+        for name in [BUILD_INFO, CONFIG_HASH_INFO, COMPONENT_INFO] {
+            controller.exempt_gauges_from_expiry(name);
+        }
+        controller.remove_gauges(CONFIG_HASH_INFO, |key| {
+            label(key, "hash") != config.version.as_deref()
+        });
+        controller.remove_gauges(COMPONENT_INFO, |key| {
+            match (
+                label(key, "component_id"),
+                label(key, "kind"),
+                label(key, "type"),
+            ) {
+                (Some(component_id), Some(kind), Some(component_type)) => !components
+                    .iter()
+                    .any(|component| *component == (component_id, kind, component_type)),
+                _ => true,
+            }
+        });
+    }
+
+    emit!(VectorBuildInfo);
+    if let Some(hash) = &config.version {
+        emit!(VectorConfigHashInfo { hash });
+    }
+    for (component_id, kind, component_type) in components {
+        emit!(VectorComponentInfo {
+            component_id,
+            kind,
+            component_type,
+        });
+    }
+}
+
+fn label<'a>(key: &'a Key, name: &str) -> Option<&'a str> {
+    key.labels()
+        .find(|label| label.key() == name)
+        .map(Label::value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::test_util::mock::{basic_sink, basic_source, basic_transform};
+
+    fn info_series(name: &str) -> BTreeSet<Vec<(String, String)>> {
+        Controller::get()
+            .unwrap()
+            .capture_metrics()
+            .into_iter()
+            .filter(|metric| metric.name() == name)
+            .map(|metric| {
+                metric
+                    .tags()
+                    .map(|tags| tags.clone().into_iter().collect())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    fn component(id: &str, kind: &str, component_type: &str) -> Vec<(String, String)> {
+        vec![
+            ("component_id".to_owned(), id.to_owned()),
+            ("kind".to_owned(), kind.to_owned()),
+            ("type".to_owned(), component_type.to_owned()),
+        ]
+    }
+
+    #[test]
+    fn retracts_stale_series_on_reload() {
+        let _ = crate::metrics::init_test();
+
+        let mut builder = Config::builder();
+        builder.add_source("in", basic_source().1);
+        builder.add_transform("parse", &["in"], basic_transform("", 0.0));
+        builder.add_sink("out", &["parse"], basic_sink(10).1);
+        let config = builder.build().unwrap();
+        report_config_info(&config);
+
+        let hash = config.version.clone().unwrap();
+        assert_eq!(
+            info_series(CONFIG_HASH_INFO),
+            BTreeSet::from([vec![("hash".to_owned(), hash.clone())]])
+        );
+        assert_eq!(
+            info_series(COMPONENT_INFO),
+            BTreeSet::from([
+                component("in", "source", "basic_source"),
+                component("parse", "transform", "basic_transform"),
+                component("out", "sink", "basic_sink"),
+            ])
+        );
+        assert_eq!(info_series(BUILD_INFO).len(), 1);
+
+        let mut builder = Config::builder();
+        builder.add_source("in", basic_source().1);
+        builder.add_sink("out", &["in"], basic_sink(10).1);
+        let reloaded = builder.build().unwrap();
+        report_config_info(&reloaded);
+
+        let reloaded_hash = reloaded.version.clone().unwrap();
+        assert_ne!(hash, reloaded_hash);
+        assert_eq!(
+            info_series(CONFIG_HASH_INFO),
+            BTreeSet::from([vec![("hash".to_owned(), reloaded_hash)]])
+        );
+        assert_eq!(
+            info_series(COMPONENT_INFO),
+            BTreeSet::from([
+                component("in", "source", "basic_source"),
+                component("out", "sink", "basic_sink"),
+            ])
+        );
+        assert_eq!(info_series(BUILD_INFO).len(), 1);
+    }
+}
@@ -10,6 +10,9 @@ pub(super) use vector_core::fanout;
 pub mod schema;
 
 pub mod builder;
+pub mod dropped_samples;
+mod fallback;
+mod info;
 mod ready_arrays;
 mod running;
 mod task;
@@ -79,9 +82,9 @@ pub async fn start_validated(
 ) -> Option<(RunningTopology, mpsc::UnboundedReceiver<()>)> {
     let (abort_tx, abort_rx) = mpsc::unbounded_channel();
 
-    crate::metrics::Controller::get()
-        .expect("Metrics must be initialized")
-        .set_expiry(config.global.expire_metrics);
+    let controller = crate::metrics::Controller::get().expect("Metrics must be initialized");
+    controller.set_expiry(config.global.expire_metrics);
+    controller.set_telemetry(&config.global.telemetry);
 
     let mut running_topology = RunningTopology::new(config, abort_tx);
 
@@ -93,6 +96,7 @@ pub async fn start_validated(
     }
     running_topology.connect_diff(&diff, &mut pieces).await;
     running_topology.spawn_diff(&diff, pieces);
+    info::report_config_info(&running_topology.config);
 
     Some((running_topology, abort_rx))
 }
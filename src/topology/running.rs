@@ -17,15 +17,17 @@ use vector_common::trigger::DisabledTrigger;
 
 use super::{TapOutput, TapResource};
 use crate::{
-    config::{ComponentKey, Config, ConfigDiff, HealthcheckOptions, OutputId, Resource},
+    config::{schema, ComponentKey, Config, ConfigDiff, HealthcheckOptions, OutputId, Resource},
     event::EventArray,
     shutdown::SourceShutdownCoordinator,
     spawn_named,
     topology::{
         build_or_log_errors, builder,
         builder::Pieces,
+        dropped_samples,
+        fallback::FallbackSender,
         fanout::{ControlChannel, ControlMessage},
-        handle_errors, retain, take_healthchecks,
+        handle_errors, info, retain, take_healthchecks,
         task::TaskOutput,
         BuiltBuffer, TaskHandle, WatchRx, WatchTx,
     },
@@ -39,6 +41,7 @@ pub struct RunningTopology {
     tasks: HashMap<ComponentKey, TaskHandle>,
     shutdown_coordinator: SourceShutdownCoordinator,
     detach_triggers: HashMap<ComponentKey, DisabledTrigger>,
+    fallbacks: HashMap<ComponentKey, FallbackSender>,
     pub(crate) config: Config,
     abort_tx: mpsc::UnboundedSender<()>,
     watch: (WatchTx, WatchRx),
@@ -53,6 +56,7 @@ impl RunningTopology {
             config,
             shutdown_coordinator: SourceShutdownCoordinator::default(),
             detach_triggers: HashMap::new(),
+            fallbacks: HashMap::new(),
             source_tasks: HashMap::new(),
             tasks: HashMap::new(),
             abort_tx,
@@ -214,6 +218,13 @@ impl RunningTopology {
             return Ok(false);
         }
 
+        if let Err(errors) = schema::validate_reload(&self.config.schema, &new_config.schema) {
+            for error in errors {
+                error!(message = "Schema options can't be reloaded; reload aborted.", %error);
+            }
+            return Ok(false);
+        }
+
         // Calculate the change between the current configuration and the new configuration, and
         // shutdown any components that are changing so that we can reclaim their buffers before
         // spawning the new version of the component.
@@ -245,6 +256,7 @@ impl RunningTopology {
                 self.connect_diff(&diff, &mut new_pieces).await;
                 self.spawn_diff(&diff, new_pieces);
                 self.config = new_config;
+                info::report_config_info(&self.config);
 
                 info!("New configuration loaded successfully.");
 
@@ -331,6 +343,7 @@ impl RunningTopology {
                 drop(previous); // detach and forget
 
                 self.remove_outputs(key);
+                dropped_samples::unregister(key);
                 source_shutdown_handles
                     .push(self.shutdown_coordinator.shutdown_source(key, deadline));
             }
@@ -372,6 +385,7 @@ impl RunningTopology {
 
             self.remove_inputs(key, diff, new_config).await;
             self.remove_outputs(key);
+            dropped_samples::unregister(key);
         }
 
         for key in &diff.transforms.to_change {
@@ -432,10 +446,19 @@ impl RunningTopology {
             .chain(reuse_buffers.iter().cloned())
             .collect::<HashSet<_>>();
 
+        // Sinks falling back to a changed/removed sink hold on to its input, so we disconnect them
+        // to let it shut down. They're connected again once all sinks are wired up.
+        for fallback in self.fallbacks.values() {
+            if diff.sinks.contains(&fallback.fallback) {
+                fallback.disconnect();
+            }
+        }
+
         // First, we remove any inputs to removed sinks so they can naturally shut down.
         for key in &diff.sinks.to_remove {
             debug!(component = %key, "Removing sink.");
             self.remove_inputs(key, diff, new_config).await;
+            dropped_samples::unregister(key);
         }
 
         // After that, for any changed sinks, we temporarily detach their inputs (not remove) so
@@ -560,6 +583,9 @@ impl RunningTopology {
             self.setup_inputs(key, diff, new_pieces).await;
         }
 
+        // With the inputs of all sinks available, we can connect sinks to their fallback sinks.
+        self.setup_fallbacks(diff, new_pieces);
+
         // We do a final pass here to reconnect unchanged components.
         //
         // Why would we reconnect unchanged components?  Well, as sources and transforms will
@@ -632,6 +658,20 @@ impl RunningTopology {
         }
     }
 
+    fn setup_fallbacks(&mut self, diff: &ConfigDiff, new_pieces: &mut builder::Pieces) {
+        for key in diff.sinks.removed_and_changed() {
+            if let Some(fallback) = self.fallbacks.remove(key) {
+                fallback.disconnect();
+            }
+        }
+        self.fallbacks.extend(new_pieces.fallbacks.drain());
+
+        for fallback in self.fallbacks.values() {
+            debug!(component = %fallback.fallback, "Connecting fallback sink.");
+            fallback.connect(self.inputs.get(&fallback.fallback).cloned());
+        }
+    }
+
     async fn setup_inputs(
         &mut self,
         key: &ComponentKey,
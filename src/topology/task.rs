@@ -10,6 +10,7 @@ use pin_project::pin_project;
 use vector_buffers::topology::channel::BufferReceiverStream;
 use vector_core::event::EventArray;
 
+use super::dropped_samples;
 use crate::{config::ComponentKey, utilization::Utilization};
 
 #[allow(clippy::large_enum_variant)]
@@ -37,7 +38,8 @@ impl Task {
         Fut: Future<Output = Result<TaskOutput, ()>> + Send + 'static,
     {
         Self {
-            inner: inner.boxed(),
+            // Events dropped by the component are sampled while its task is polled.
+            inner: dropped_samples::Scoped::new(&key, inner).boxed(),
             key,
             typetag: typetag.into(),
         }
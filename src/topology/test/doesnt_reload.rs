@@ -41,3 +41,32 @@ async fn topology_doesnt_reload_new_data_dir() {
         Some(Path::new("/asdf").to_path_buf())
     );
 }
+
+#[tokio::test]
+async fn topology_doesnt_reload_new_log_namespace() {
+    trace_init();
+
+    let mut old_config = Config::builder();
+    old_config.add_source("in", SocketConfig::make_basic_tcp_config(next_addr()));
+    old_config.add_sink(
+        "out",
+        &["in"],
+        ConsoleSinkConfig {
+            target: Target::Stdout,
+            encoding: (None::<FramingConfig>, TextSerializerConfig::new()).into(),
+            acknowledgements: Default::default(),
+        },
+    );
+    let mut new_config = old_config.clone();
+
+    let (mut topology, _crash) = start_topology(old_config.build().unwrap(), false).await;
+
+    new_config.schema.log_namespace = Some(true);
+
+    assert!(!topology
+        .reload_config_and_respawn(new_config.build().unwrap())
+        .await
+        .unwrap());
+
+    assert_eq!(topology.config.schema.log_namespace, None);
+}
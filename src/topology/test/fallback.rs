@@ -0,0 +1,112 @@
+use futures::StreamExt;
+use tokio::time::{sleep, timeout, Duration};
+
+use super::into_message_stream;
+use crate::{
+    config::{ComponentKey, Config, SinkFallbackConfig},
+    event::{Event, LogEvent},
+    test_util::{
+        mock::{basic_sink, basic_source},
+        start_topology, trace_init,
+    },
+};
+
+/// Sends the events of a sink that rejects everything to its fallback sink once the circuit
+/// breaker opens.
+#[tokio::test]
+async fn routes_to_fallback_sink() {
+    trace_init();
+
+    let (mut in1, source1) = basic_source();
+    let (_in2, source2) = basic_source();
+    let (mut out, fallback_sink) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.global.acknowledgements = true.into();
+    config.add_source("in1", source1);
+    config.add_source("in2", source2);
+    config.add_sink("primary", &["in1"], test_sink::RejectingSinkConfig {});
+    config.add_sink("fallback", &["in2"], fallback_sink);
+    config
+        .sinks
+        .get_mut(&ComponentKey::from("primary"))
+        .unwrap()
+        .fallback = Some(SinkFallbackConfig {
+        sink: "fallback".into(),
+        consecutive_failures: 1,
+        error_rate: None,
+        error_rate_window: 100,
+        probe_interval_secs: 60,
+    });
+
+    let (_topology, _crash) = start_topology(config.build().unwrap(), false).await;
+
+    // The first batch is rejected by the primary sink, which opens the circuit breaker.
+    in1.send_event(Event::Log(LogEvent::from("rejected")))
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(100)).await;
+
+    in1.send_event(Event::Log(LogEvent::from("rerouted")))
+        .await
+        .unwrap();
+    let events = timeout(Duration::from_secs(5), out.next())
+        .await
+        .expect("Timed out waiting for the fallback sink.")
+        .unwrap();
+    let messages = into_message_stream(events).collect::<Vec<_>>().await;
+    assert_eq!(messages, vec!["rerouted".to_owned()]);
+}
+
+mod test_sink {
+    use async_trait::async_trait;
+    use futures::stream::BoxStream;
+    use futures::{FutureExt, StreamExt};
+    use serde::{Deserialize, Serialize};
+    use vector_common::finalization::Finalizable;
+
+    use crate::config::{AcknowledgementsConfig, Input, SinkConfig, SinkContext};
+    use crate::event::{Event, EventStatus};
+    use crate::sinks::util::StreamSink;
+    use crate::sinks::{Healthcheck, VectorSink};
+
+    /// A sink that rejects every event.
+    struct RejectingSink;
+
+    #[async_trait]
+    impl StreamSink<Event> for RejectingSink {
+        async fn run(self: Box<Self>, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
+            while let Some(mut event) = input.next().await {
+                event.take_finalizers().update_status(EventStatus::Rejected);
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub(super) struct RejectingSinkConfig {}
+
+    #[async_trait]
+    #[typetag::serde(name = "test-rejecting-sink")]
+    impl SinkConfig for RejectingSinkConfig {
+        async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+            let healthcheck = futures::future::ok(()).boxed();
+            Ok((
+                VectorSink::from_event_streamsink(RejectingSink),
+                healthcheck,
+            ))
+        }
+
+        fn input(&self) -> Input {
+            Input::all()
+        }
+
+        fn sink_type(&self) -> &'static str {
+            "test-rejecting-sink"
+        }
+
+        fn acknowledgements(&self) -> &AcknowledgementsConfig {
+            &AcknowledgementsConfig::DEFAULT
+        }
+    }
+}
@@ -51,6 +51,7 @@ mod doesnt_reload;
 
 mod backpressure;
 mod compliance;
+mod fallback;
 
 fn basic_config() -> Config {
     trace_init();
@@ -84,6 +84,9 @@ async fn closed_source() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -93,6 +96,9 @@ async fn closed_source() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -111,6 +117,9 @@ async fn closed_source() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -144,6 +153,9 @@ async fn remove_sink() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -153,6 +165,9 @@ async fn remove_sink() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -170,6 +185,9 @@ async fn remove_sink() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -203,6 +221,9 @@ async fn remove_transform() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -212,6 +233,9 @@ async fn remove_transform() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -229,6 +253,9 @@ async fn remove_transform() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -258,6 +285,9 @@ async fn replace_transform() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
@@ -277,6 +307,9 @@ async fn replace_transform() {
         BlackholeConfig {
             print_interval_secs: 10,
             rate: None,
+            smoothing: None,
+            metrics_flush_interval_secs: None,
+            openmetrics_address: None,
             acknowledgements: Default::default(),
         },
     );
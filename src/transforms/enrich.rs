@@ -0,0 +1,563 @@
+use std::collections::BTreeMap;
+
+use enrichment::{Case, Condition, IndexHandle, TableRegistry, TableSearch};
+use indexmap::IndexMap;
+use lookup::path;
+use snafu::Snafu;
+use vector_config::configurable_component;
+use vector_core::transform::SyncTransform;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, Output, TransformConfig, TransformContext,
+        TransformDescription,
+    },
+    event::{Event, LogEvent, Value},
+    internal_events::{EnrichEventDiscarded, EnrichLookupError},
+    schema,
+    transforms::{Transform, TransformOutputsBuf},
+};
+
+/// The name of the output events that don't match any row are sent to, when `on_miss` is `route`.
+pub(crate) const MISSED_OUTPUT: &str = "missed";
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("`key` must map at least one column"))]
+    EmptyKey,
+    #[snafu(display("Enrichment table {:?}: {}", table, message))]
+    Table { table: String, message: String },
+    #[snafu(display("Column {:?} is not in enrichment table {:?}", column, table))]
+    UnknownColumn { table: String, column: String },
+}
+
+/// What to do with events that don't match any row of the table.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub enum OnMiss {
+    /// Pass the event through unchanged.
+    #[derivative(Default)]
+    Ignore,
+
+    /// Drop the event.
+    Drop,
+
+    /// Send the event, unchanged, to the `missed` output.
+    Route,
+}
+
+/// What to do when an event matches several rows of the table.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub enum OnMultiple {
+    /// Report an error, and handle the event as if it didn't match any row.
+    #[derivative(Default)]
+    Error,
+
+    /// Enrich the event with the first matching row.
+    First,
+}
+
+/// Configuration for the `enrich` transform.
+#[configurable_component(transform)]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct EnrichConfig {
+    /// The name of the enrichment table to look up rows in.
+    table: String,
+
+    /// The columns of the table to match, mapped to the paths of the event fields holding their values.
+    ///
+    /// Rows match when all of their columns are equal to the values of the fields. Events missing any of the fields
+    /// don't match any row.
+    #[serde(default)]
+    key: IndexMap<String, String>,
+
+    /// The path of the field to insert the columns of the matched row into, as an object.
+    ///
+    /// By default, the columns are inserted at the root of the event.
+    #[serde(default)]
+    target: Option<String>,
+
+    /// The columns of the matched row to insert.
+    ///
+    /// By default, all of its columns are inserted.
+    #[serde(default)]
+    select: Option<Vec<String>>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    on_miss: OnMiss,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    on_multiple: OnMultiple,
+}
+
+inventory::submit! {
+    TransformDescription::new::<EnrichConfig>("enrich")
+}
+
+impl GenerateConfig for EnrichConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"table = "users"
+            key.id = "user_id"
+            target = "user""#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "enrich")]
+impl TransformConfig for EnrichConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let enrich = Enrich::new(self, context.enrichment_tables.clone())?;
+        Ok(Transform::synchronous(enrich))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        let mut outputs = vec![Output::default(DataType::Log)];
+        if self.on_miss == OnMiss::Route {
+            outputs.push(Output::default(DataType::Log).with_port(MISSED_OUTPUT));
+        }
+        outputs
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+
+    fn transform_type(&self) -> &'static str {
+        "enrich"
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Enrich {
+    table: String,
+    key: IndexMap<String, String>,
+    target: Option<String>,
+    select: Option<Vec<String>>,
+    on_miss: OnMiss,
+    on_multiple: OnMultiple,
+    index: IndexHandle,
+    tables: TableSearch,
+}
+
+impl Enrich {
+    /// Validates the columns of the configuration against the loaded table, and indexes the table
+    /// on the columns of the key.
+    ///
+    /// This must be called while the enrichment tables are loading, before they can be searched.
+    pub fn new(config: &EnrichConfig, mut tables: TableRegistry) -> crate::Result<Self> {
+        if config.key.is_empty() {
+            return Err(BuildError::EmptyKey.into());
+        }
+
+        let table = config.table.clone();
+        let columns = tables
+            .columns(&table)
+            .map_err(|message| BuildError::Table {
+                table: table.clone(),
+                message,
+            })?;
+        if let Some(columns) = columns {
+            let unknown = config
+                .key
+                .keys()
+                .chain(config.select.iter().flatten())
+                .find(|column| !columns.contains(*column));
+            if let Some(column) = unknown {
+                return Err(BuildError::UnknownColumn {
+                    table,
+                    column: column.clone(),
+                }
+                .into());
+            }
+        }
+
+        let fields = config.key.keys().map(String::as_str).collect::<Vec<_>>();
+        let index = tables
+            .add_index(&table, Case::Sensitive, &fields)
+            .map_err(|message| BuildError::Table {
+                table: table.clone(),
+                message,
+            })?;
+
+        Ok(Self {
+            table,
+            key: config.key.clone(),
+            target: config.target.clone(),
+            select: config.select.clone(),
+            on_miss: config.on_miss,
+            on_multiple: config.on_multiple,
+            index,
+            tables: tables.as_readonly(),
+        })
+    }
+
+    /// Finds the row matching the key fields of the event, if any.
+    fn find_row(&self, log: &LogEvent) -> Result<Option<BTreeMap<String, Value>>, String> {
+        let mut condition = Vec::with_capacity(self.key.len());
+        for (column, field) in &self.key {
+            match log.get(field.as_str()) {
+                Some(value) => condition.push(Condition::Equals {
+                    field: column.as_str(),
+                    value: value.clone(),
+                }),
+                None => return Ok(None),
+            }
+        }
+
+        let rows = self.tables.find_table_rows(
+            &self.table,
+            Case::Sensitive,
+            &condition,
+            self.select.as_deref(),
+            Some(self.index),
+        )?;
+        match (rows.len(), self.on_multiple) {
+            (0 | 1, _) | (_, OnMultiple::First) => Ok(rows.into_iter().next()),
+            (count, OnMultiple::Error) => Err(format!("{} rows match the key", count)),
+        }
+    }
+
+    fn insert_row(&self, log: &mut LogEvent, row: BTreeMap<String, Value>) {
+        match &self.target {
+            Some(target) => {
+                log.insert(target.as_str(), Value::Object(row));
+            }
+            None => {
+                for (column, value) in row {
+                    log.insert(path!(column.as_str()), value);
+                }
+            }
+        }
+    }
+}
+
+impl SyncTransform for Enrich {
+    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+        let mut log = event.into_log();
+        let row = self.find_row(&log).unwrap_or_else(|error| {
+            emit!(EnrichLookupError {
+                table: &self.table,
+                error,
+            });
+            None
+        });
+
+        match (row, self.on_miss) {
+            (Some(row), _) => {
+                self.insert_row(&mut log, row);
+                output.push(log.into());
+            }
+            (None, OnMiss::Ignore) => output.push(log.into()),
+            (None, OnMiss::Drop) => emit!(EnrichEventDiscarded),
+            (None, OnMiss::Route) => output.push_named(MISSED_OUTPUT, log.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use enrichment::Table;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct TestTable {
+        columns: Vec<String>,
+        rows: Vec<BTreeMap<String, Value>>,
+    }
+
+    impl Table for TestTable {
+        fn find_table_row(
+            &self,
+            case: Case,
+            condition: &[Condition],
+            select: Option<&[String]>,
+            index: Option<IndexHandle>,
+        ) -> Result<BTreeMap<String, Value>, String> {
+            let mut rows = self.find_table_rows(case, condition, select, index)?;
+            match rows.len() {
+                1 => Ok(rows.remove(0)),
+                count => Err(format!("{} rows found", count)),
+            }
+        }
+
+        fn find_table_rows(
+            &self,
+            _case: Case,
+            condition: &[Condition],
+            select: Option<&[String]>,
+            _index: Option<IndexHandle>,
+        ) -> Result<Vec<BTreeMap<String, Value>>, String> {
+            Ok(self
+                .rows
+                .iter()
+                .filter(|row| {
+                    condition.iter().all(|condition| match condition {
+                        Condition::Equals { field, value } => row.get(*field) == Some(value),
+                        Condition::BetweenDates { .. } => false,
+                    })
+                })
+                .map(|row| {
+                    row.iter()
+                        .filter(|(column, _)| {
+                            select.map_or(true, |select| select.contains(*column))
+                        })
+                        .map(|(column, value)| (column.clone(), value.clone()))
+                        .collect()
+                })
+                .collect())
+        }
+
+        fn add_index(&mut self, _case: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+            match fields
+                .iter()
+                .find(|field| !self.columns.iter().any(|column| column == **field))
+            {
+                Some(field) => Err(format!("field {} not in table", field)),
+                None => Ok(IndexHandle(0)),
+            }
+        }
+
+        fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+            Vec::new()
+        }
+
+        fn needs_reload(&self) -> bool {
+            false
+        }
+
+        fn columns(&self) -> Option<Vec<String>> {
+            Some(self.columns.clone())
+        }
+    }
+
+    fn users() -> TableRegistry {
+        let row = |id: &str, name: &str, team: &str| {
+            BTreeMap::from([
+                ("id".to_owned(), Value::from(id)),
+                ("name".to_owned(), Value::from(name)),
+                ("team".to_owned(), Value::from(team)),
+            ])
+        };
+        let table = TestTable {
+            columns: vec!["id".to_owned(), "name".to_owned(), "team".to_owned()],
+            rows: vec![
+                row("1", "alice", "core"),
+                row("2", "bob", "core"),
+                row("3", "carol", "edge"),
+            ],
+        };
+        let registry = TableRegistry::default();
+        registry.load(HashMap::from([(
+            "users".to_owned(),
+            Box::new(table) as Box<dyn Table + Send + Sync>,
+        )]));
+        registry
+    }
+
+    fn build(config: &str) -> crate::Result<Enrich> {
+        let config = toml::from_str::<EnrichConfig>(config).unwrap();
+        let registry = users();
+        let enrich = Enrich::new(&config, registry.clone());
+        registry.finish_load();
+        enrich
+    }
+
+    /// Transforms a log event with the given fields, returning the events sent to the default and
+    /// `missed` outputs.
+    fn transform(enrich: &mut Enrich, fields: serde_json::Value) -> (Vec<Event>, Vec<Event>) {
+        let mut outputs = TransformOutputsBuf::new_with_capacity(
+            vec![
+                Output::default(DataType::Log),
+                Output::default(DataType::Log).with_port(MISSED_OUTPUT),
+            ],
+            1,
+        );
+        enrich.transform(Event::try_from(fields).unwrap(), &mut outputs);
+        (
+            outputs.drain().collect(),
+            outputs.drain_named(MISSED_OUTPUT).collect(),
+        )
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<EnrichConfig>();
+    }
+
+    #[test]
+    fn enriches_matching_event() {
+        let mut enrich = build(
+            r#"
+            table = "users"
+            key.id = "user.id"
+            target = "user.details"
+            select = ["name", "team"]
+            "#,
+        )
+        .unwrap();
+
+        let (output, _) = transform(&mut enrich, serde_json::json!({"user": {"id": "2"}}));
+        assert_eq!(output.len(), 1);
+        let log = output[0].as_log();
+        assert_eq!(log.get("user.id"), Some(&Value::from("2")));
+        assert_eq!(log.get("user.details.name"), Some(&Value::from("bob")));
+        assert_eq!(log.get("user.details.team"), Some(&Value::from("core")));
+        assert_eq!(log.get("user.details.id"), None);
+    }
+
+    #[test]
+    fn enriches_root_without_target() {
+        let mut enrich = build(
+            r#"
+            table = "users"
+            key.id = "user_id"
+            key.team = "team"
+            "#,
+        )
+        .unwrap();
+
+        let (output, _) = transform(
+            &mut enrich,
+            serde_json::json!({"user_id": "3", "team": "edge"}),
+        );
+        let log = output[0].as_log();
+        assert_eq!(log.get("name"), Some(&Value::from("carol")));
+        assert_eq!(log.get("id"), Some(&Value::from("3")));
+    }
+
+    #[test]
+    fn handles_misses() {
+        let config = |on_miss: &str| {
+            format!(
+                r#"
+                table = "users"
+                key.id = "user_id"
+                on_miss = "{}"
+                "#,
+                on_miss
+            )
+        };
+
+        // Neither an unknown key nor a missing key field match any row.
+        for fields in [
+            serde_json::json!({"user_id": "4"}),
+            serde_json::json!({"message": "no key"}),
+        ] {
+            let mut enrich = build(&config("ignore")).unwrap();
+            let (output, missed) = transform(&mut enrich, fields.clone());
+            assert_eq!(output, vec![Event::try_from(fields.clone()).unwrap()]);
+            assert!(missed.is_empty());
+
+            let mut enrich = build(&config("drop")).unwrap();
+            let (output, missed) = transform(&mut enrich, fields.clone());
+            assert!(output.is_empty());
+            assert!(missed.is_empty());
+
+            let mut enrich = build(&config("route")).unwrap();
+            let (output, missed) = transform(&mut enrich, fields.clone());
+            assert!(output.is_empty());
+            assert_eq!(missed, vec![Event::try_from(fields).unwrap()]);
+        }
+    }
+
+    #[test]
+    fn handles_multiple_rows() {
+        let mut enrich = build(
+            r#"
+            table = "users"
+            key.team = "team"
+            target = "user"
+            on_multiple = "first"
+            "#,
+        )
+        .unwrap();
+        let (output, _) = transform(&mut enrich, serde_json::json!({"team": "core"}));
+        assert_eq!(
+            output[0].as_log().get("user.name"),
+            Some(&Value::from("alice"))
+        );
+
+        // An error is handled as a miss.
+        let mut enrich = build(
+            r#"
+            table = "users"
+            key.team = "team"
+            target = "user"
+            on_miss = "route"
+            "#,
+        )
+        .unwrap();
+        let (output, missed) = transform(&mut enrich, serde_json::json!({"team": "core"}));
+        assert!(output.is_empty());
+        assert_eq!(missed[0].as_log().get("user"), None);
+    }
+
+    #[test]
+    fn validates_columns() {
+        let error = build(
+            r#"
+            table = "users"
+            key.email = "email"
+            "#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            r#"Column "email" is not in enrichment table "users""#
+        );
+
+        let error = build(
+            r#"
+            table = "users"
+            key.id = "user_id"
+            select = ["name", "role"]
+            "#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            r#"Column "role" is not in enrichment table "users""#
+        );
+
+        let error = build(
+            r#"
+            table = "groups"
+            key.id = "group_id"
+            "#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            r#"Enrichment table "groups": table 'groups' not loaded"#
+        );
+
+        let error = build(
+            r#"table = "users"
+            key = {}"#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.to_string(), "`key` must map at least one column");
+    }
+}
@@ -327,6 +327,7 @@ mod tests {
         let config: PipelinesConfig = config.try_into().unwrap();
         let outer = TransformOuter {
             inputs: vec!["source".to_string()],
+            dropped_event_samples: None,
             inner: Box::new(config),
         };
         let name = ComponentKey::from("foo");
@@ -30,8 +30,12 @@ use crate::{
         TransformDescription,
     },
     event::{Event, TargetEvents, VrlTarget},
-    internal_events::{RemapMappingAbort, RemapMappingError},
+    internal_events::{
+        prelude::{error_stage, error_type},
+        ComponentEventsDropped, RemapMappingAbort, RemapMappingError,
+    },
     schema,
+    topology::dropped_samples,
     transforms::{SyncTransform, Transform, TransformOutputsBuf},
     Result,
 };
@@ -208,7 +212,7 @@ impl TransformConfig for RemapConfig {
                     .0;
 
                 let mut new_type_def = Definition::new_with_default_metadata(
-                    state.target_kind().clone(),
+                    event_kind(state.target_kind().clone()),
                     input_definition.log_namespaces().clone(),
                 );
                 for (id, path) in meaning {
@@ -294,6 +298,8 @@ where
 {
     component_key: Option<ComponentKey>,
     program: Program,
+    /// Whether the program may set the event to an array, splitting it into one event per element.
+    splits_events: bool,
     timezone: TimeZone,
     drop_on_error: bool,
     drop_on_abort: bool,
@@ -343,15 +349,16 @@ impl Remap<AstRunner> {
         config: RemapConfig,
         context: &TransformContext,
     ) -> crate::Result<(Self, String)> {
-        let (program, warnings, _, _) = config.compile_vrl_program(
+        let (program, warnings, _, state) = config.compile_vrl_program(
             context.enrichment_tables.clone(),
             context.merged_schema_definition.clone(),
         )?;
+        let splits_events = state.target_kind().as_array().is_some();
 
         let runtime = Runtime::default();
         let runner = AstRunner { runtime };
 
-        Self::new(config, context, program, runner).map(|remap| (remap, warnings))
+        Self::new(config, context, program, splits_events, runner).map(|remap| (remap, warnings))
     }
 }
 
@@ -363,6 +370,7 @@ where
         config: RemapConfig,
         context: &TransformContext,
         program: Program,
+        splits_events: bool,
         runner: Runner,
     ) -> crate::Result<Self> {
         let default_schema_definition = context
@@ -381,6 +389,7 @@ where
         Ok(Remap {
             component_key: context.key.clone(),
             program,
+            splits_events,
             timezone: config.timezone,
             drop_on_error: config.drop_on_error,
             drop_on_abort: config.drop_on_abort,
@@ -397,16 +406,9 @@ where
     }
 
     fn dropped_data(&self, reason: &str, error: ExpressionError) -> serde_json::Value {
-        let message = error
-            .notes()
-            .iter()
-            .filter(|note| matches!(note, Note::UserErrorMessage(_)))
-            .last()
-            .map(|note| note.to_string())
-            .unwrap_or_else(|| error.to_string());
         serde_json::json!({
                 "reason": reason,
-                "message": message,
+                "message": dropped_message(&error),
                 "component_id": self.component_key,
                 "component_type": "remap",
                 "component_kind": "transform",
@@ -469,10 +471,13 @@ where
         // main output if they're failed or aborted, in which case we can skip the cloning, since
         // any mutations made by VRL will be ignored regardless. If they hav configured
         // `reroute_dropped`, however, we still need to do the clone to ensure that we can forward
-        // the event to the `dropped` output.
-        let forward_on_error = !self.drop_on_error || self.reroute_dropped;
-        let forward_on_abort = !self.drop_on_abort || self.reroute_dropped;
-        let original_event = if (self.program.info().fallible && forward_on_error)
+        // the event to the `dropped` output, or to retain samples of the dropped events.
+        let keep_dropped = self.reroute_dropped || dropped_samples::is_sampling();
+        let forward_on_error = !self.drop_on_error || keep_dropped;
+        let forward_on_abort = !self.drop_on_abort || keep_dropped;
+        // Splitting an event fails at runtime if it can't be split.
+        let fallible = self.program.info().fallible || self.splits_events;
+        let original_event = if (fallible && forward_on_error)
             || (self.program.info().abortable && forward_on_abort)
         {
             Some(event.clone())
@@ -481,7 +486,11 @@ where
         };
 
         let mut target = VrlTarget::new(event, self.program.info());
-        let result = self.run_vrl(&mut target);
+        let result = self.run_vrl(&mut target).and_then(|value| {
+            check_split(&target)
+                .map(|_| value)
+                .map_err(Terminate::Error)
+        });
 
         match result {
             Ok(_) => match target.into_events() {
@@ -516,17 +525,79 @@ where
                     let event = original_event.expect("event will be set");
 
                     push_default(event, output, &self.default_schema_definition);
-                } else if self.reroute_dropped {
-                    let mut event = original_event.expect("event will be set");
-
-                    self.annotate_dropped(&mut event, reason, error);
-                    push_dropped(event, output, &self.dropped_schema_definition);
+                } else {
+                    emit!(ComponentEventsDropped {
+                        count: 1,
+                        events: original_event
+                            .as_ref()
+                            .map(std::slice::from_ref)
+                            .unwrap_or_default(),
+                        reason: &format!("{}: {}", reason, dropped_message(&error)),
+                        error_type: error_type::CONVERSION_FAILED,
+                        stage: error_stage::PROCESSING,
+                    });
+
+                    if self.reroute_dropped {
+                        let mut event = original_event.expect("event will be set");
+
+                        self.annotate_dropped(&mut event, reason, error);
+                        push_dropped(event, output, &self.dropped_schema_definition);
+                    }
                 }
             }
         }
     }
 }
 
+/// Checks that a target set to an array can be split into one event per element.
+///
+/// Elements can't be arrays themselves, and only log events can be split. Metric events can't be
+/// set to an array in the first place.
+fn check_split(target: &VrlTarget) -> std::result::Result<(), ExpressionError> {
+    match target {
+        VrlTarget::LogEvent(value::Value::Array(values), _) => {
+            if values
+                .iter()
+                .any(|value| matches!(value, value::Value::Array(_)))
+            {
+                Err("can't split event into nested arrays".into())
+            } else {
+                Ok(())
+            }
+        }
+        VrlTarget::Trace(value::Value::Array(_), _) => {
+            Err("can't split trace event into multiple events".into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The kind of the events a program turns its target into.
+///
+/// Setting the target to an array splits it into one event per element, so the array is replaced
+/// by the union of the kinds of its elements, which may be `any` if they're unknown. Nested arrays
+/// are rejected at runtime.
+fn event_kind(mut kind: Kind) -> Kind {
+    match kind.as_array().map(|elements| elements.reduced_kind()) {
+        Some(mut elements) => {
+            elements.remove_array();
+            kind.remove_array();
+            kind.union(elements)
+        }
+        None => kind,
+    }
+}
+
+fn dropped_message(error: &ExpressionError) -> String {
+    error
+        .notes()
+        .iter()
+        .filter(|note| matches!(note, Note::UserErrorMessage(_)))
+        .last()
+        .map(|note| note.to_string())
+        .unwrap_or_else(|| error.to_string())
+}
+
 #[inline]
 fn push_default(
     mut event: Event,
@@ -577,10 +648,11 @@ mod tests {
         config::{build_unit_tests, ConfigBuilder},
         event::{
             metric::{MetricKind, MetricValue},
-            LogEvent, Metric, Value,
+            BatchNotifier, BatchStatus, LogEvent, Metric, TraceEvent, Value,
         },
         schema,
         test_util::components::{init_test, COMPONENT_MULTIPLE_OUTPUTS_TESTS},
+        topology::dropped_samples::DroppedEventSamplesConfig,
         transforms::OutputBuffer,
     };
 
@@ -726,6 +798,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_remap_samples_dropped_events() {
+        let key = ComponentKey::from("check_remap_samples_dropped_events");
+        dropped_samples::register(
+            &key,
+            Some(&DroppedEventSamplesConfig {
+                size: 10,
+                redact_fields: vec!["secret".to_owned()],
+            }),
+        );
+        let conf = RemapConfig {
+            source: Some(r#"if .secret != null { abort "secret found" }"#.to_owned()),
+            drop_on_abort: true,
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        // Samples are recorded while the task of the transform is polled.
+        let sampler = dropped_samples::sampler(&key);
+        dropped_samples::scope(sampler.as_ref(), || {
+            let mut event = LogEvent::from("dropped");
+            event.insert("secret", "hunter2");
+            assert!(transform_one(&mut tform, event.into()).is_none());
+            assert!(transform_one(&mut tform, LogEvent::from("kept").into()).is_some());
+        });
+
+        let samples = dropped_samples::latest(&key, 10);
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].reason, "abort: secret found");
+        assert_eq!(samples[0].event.as_log()["message"], "dropped".into());
+        assert_eq!(samples[0].event.as_log()["secret"], "[REDACTED]".into());
+
+        dropped_samples::unregister(&key);
+    }
+
     #[test]
     fn check_remap_emits_multiple() {
         let event = {
@@ -771,6 +878,101 @@ mod tests {
         );
     }
 
+    fn split_remap(source: &str) -> Remap<AstRunner> {
+        remap(RemapConfig {
+            source: Some(source.to_owned()),
+            drop_on_error: true,
+            reroute_dropped: true,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn check_remap_split_empty_array_drops_event() {
+        let mut tform = split_remap(". = []");
+
+        let out = collect_outputs(&mut tform, LogEvent::from("split me").into());
+        assert!(out.primary.is_empty());
+        assert!(out.named[DROPPED].is_empty());
+    }
+
+    #[test]
+    fn check_remap_split_nested_arrays_error() {
+        let mut tform = split_remap(r#". = [{"message": "foo"}, [1, 2]]"#);
+
+        let dropped =
+            transform_one_fallible(&mut tform, LogEvent::from("split me").into()).unwrap_err();
+        let log = dropped.as_log();
+        assert_eq!(log["message"], "split me".into());
+        assert_eq!(log["metadata.dropped.reason"], "error".into());
+        assert_eq!(
+            log["metadata.dropped.message"],
+            "can't split event into nested arrays".into()
+        );
+    }
+
+    #[test]
+    fn check_remap_split_rejects_metrics_and_traces() {
+        let mut tform = split_remap(r#". = [{"message": "foo"}, {"message": "bar"}]"#);
+
+        let metric = Metric::new(
+            "counter",
+            MetricKind::Absolute,
+            MetricValue::Counter { value: 1.0 },
+        );
+        let dropped = transform_one_fallible(&mut tform, metric.clone().into()).unwrap_err();
+        assert_eq!(dropped.as_metric().name(), metric.name());
+
+        let trace = TraceEvent::from(LogEvent::from("split me"));
+        let dropped = transform_one_fallible(&mut tform, trace.into()).unwrap_err();
+        let trace = dropped.as_trace();
+        assert_eq!(trace.get("message"), Some(&"split me".into()));
+        assert_eq!(
+            trace.get("metadata.dropped.message"),
+            Some(&"can't split trace event into multiple events".into())
+        );
+    }
+
+    #[test]
+    fn check_remap_split_events_share_finalizers() {
+        let mut tform = split_remap(r#". = [{"message": "foo"}, {"message": "bar"}]"#);
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let event = LogEvent::from("split me").with_batch_notifier(&batch);
+        drop(batch);
+
+        let out = collect_outputs(&mut tform, event.into());
+        let mut events = out.primary.into_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        assert_eq!(get_field_string(&events[0], "message"), "foo");
+        assert_eq!(get_field_string(&events[1], "message"), "bar");
+
+        // The original event is acknowledged once all of the events it was split into are.
+        drop(events.pop());
+        assert!(receiver.try_recv().is_err());
+        drop(events);
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+    }
+
+    #[test]
+    fn check_remap_split_schema_definition() {
+        let conf = RemapConfig {
+            source: Some(r#". = [{"foo": 1}, {"foo": 2}]"#.to_owned()),
+            ..Default::default()
+        };
+
+        let outputs = conf.outputs(&schema::Definition::new_with_default_metadata(
+            Kind::any_object(),
+            [LogNamespace::Legacy],
+        ));
+        let definition = outputs[0].log_schema_definition.as_ref().unwrap();
+        assert!(definition.event_kind().as_array().is_none());
+        assert_eq!(
+            definition.event_kind(),
+            &Kind::object(BTreeMap::from([("foo".into(), Kind::integer())]))
+        );
+    }
+
     #[test]
     fn check_remap_error() {
         let event = {
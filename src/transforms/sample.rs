@@ -1,4 +1,10 @@
+use std::{collections::HashMap, pin::Pin, time::Duration};
+
+use async_stream::stream;
+use futures::{stream, Stream, StreamExt};
+use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
+use snafu::Snafu;
 use vector_config::configurable_component;
 
 use crate::{
@@ -8,11 +14,15 @@ use crate::{
         TransformDescription,
     },
     event::Event,
-    internal_events::SampleEventDiscarded,
+    internal_events::{SampleEventDiscarded, TemplateRenderingError},
     schema,
-    transforms::{FunctionTransform, OutputBuffer, Transform},
+    template::Template,
+    transforms::{FunctionTransform, OutputBuffer, TaskTransform, Transform},
 };
 
+/// The key of the events of groups beyond `max_groups`, which are sampled together.
+const OVERFLOW_GROUP: &str = "_other";
+
 /// Configuration for the `sample` transform.
 #[configurable_component(transform)]
 #[derive(Clone, Debug)]
@@ -21,7 +31,9 @@ pub struct SampleConfig {
     /// The rate at which events will be forwarded, expressed as `1/N`.
     ///
     /// For example, `rate = 10` means 1 out of every 10 events will be forwarded and the rest will be dropped.
-    pub rate: u64,
+    ///
+    /// Either `rate` or `rate_limit` must be set.
+    pub rate: Option<u64>,
 
     /// The name of the log field whose value will be hashed to determine if the event should be passed.
     ///
@@ -32,6 +44,55 @@ pub struct SampleConfig {
 
     /// A logical condition used to exclude events from sampling.
     pub exclude: Option<AnyCondition>,
+
+    #[configurable(derived)]
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+/// Samples at most a fixed number of events per interval, instead of a fixed ratio of them.
+///
+/// The events of each interval are held back until its end, and `events` of them are chosen uniformly with reservoir
+/// sampling, regardless of the input rate. The chosen events are forwarded in their original order, with the ratio of
+/// the events seen to the events forwarded in the interval as their `sample_rate` field.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitConfig {
+    /// The maximum number of events forwarded per interval, for each group.
+    pub events: usize,
+
+    /// The length of the intervals, in seconds.
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: f64,
+
+    /// A template rendering the group of events, each of which has its own budget of `events` per interval.
+    ///
+    /// Events for which the template fails to render are grouped together.
+    #[configurable(metadata(templatable))]
+    pub group_by: Option<Template>,
+
+    /// The maximum number of groups per interval.
+    ///
+    /// Once reached, the events of any further group share the budget of a single `_other` group for the rest of the
+    /// interval, which bounds the memory used by the transform.
+    #[serde(default = "default_max_groups")]
+    pub max_groups: usize,
+}
+
+const fn default_interval_secs() -> f64 {
+    60.0
+}
+
+const fn default_max_groups() -> usize {
+    1000
+}
+
+#[derive(Debug, Snafu)]
+pub enum ConfigError {
+    #[snafu(display("Exactly one of `rate` and `rate_limit` must be set"))]
+    RateOrRateLimit,
+    #[snafu(display("`rate_limit.events`, `rate_limit.interval_secs` and `rate_limit.max_groups` must be positive"))]
+    RateLimitNonPositive,
 }
 
 // TODO: Deprecate the name `sampler`
@@ -46,9 +107,10 @@ inventory::submit! {
 impl GenerateConfig for SampleConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
-            rate: 10,
+            rate: Some(10),
             key_field: None,
             exclude: None::<AnyCondition>,
+            rate_limit: None,
         })
         .unwrap()
     }
@@ -58,14 +120,23 @@ impl GenerateConfig for SampleConfig {
 #[typetag::serde(name = "sample")]
 impl TransformConfig for SampleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::function(Sample::new(
-            self.rate,
-            self.key_field.clone(),
-            self.exclude
-                .as_ref()
-                .map(|condition| condition.build(&context.enrichment_tables))
-                .transpose()?,
-        )))
+        let exclude = self
+            .exclude
+            .as_ref()
+            .map(|condition| condition.build(&context.enrichment_tables))
+            .transpose()?;
+
+        match (self.rate, &self.rate_limit) {
+            (Some(rate), None) => Ok(Transform::function(Sample::new(
+                rate,
+                self.key_field.clone(),
+                exclude,
+            ))),
+            (None, Some(rate_limit)) => {
+                ReservoirSample::new(rate_limit, exclude).map(Transform::event_task)
+            }
+            _ => Err(Box::new(ConfigError::RateOrRateLimit)),
+        }
     }
 
     fn input(&self) -> Input {
@@ -171,9 +242,188 @@ impl FunctionTransform for Sample {
     }
 }
 
+/// Samples a fixed number of events per interval and group with reservoir sampling.
+pub struct ReservoirSample {
+    events: usize,
+    interval: Duration,
+    group_by: Option<Template>,
+    max_groups: usize,
+    exclude: Option<Condition>,
+    /// The reservoirs of the current interval, keyed by group.
+    reservoirs: HashMap<Option<String>, Reservoir>,
+    /// The number of events seen in the current interval, which orders the sampled events.
+    seen: usize,
+}
+
+impl ReservoirSample {
+    pub fn new(config: &RateLimitConfig, exclude: Option<Condition>) -> crate::Result<Self> {
+        if config.events == 0
+            || config.max_groups == 0
+            || !config.interval_secs.is_finite()
+            || config.interval_secs <= 0.0
+        {
+            return Err(Box::new(ConfigError::RateLimitNonPositive));
+        }
+
+        Ok(Self {
+            events: config.events,
+            interval: Duration::from_secs_f64(config.interval_secs),
+            group_by: config.group_by.clone(),
+            max_groups: config.max_groups,
+            exclude,
+            reservoirs: HashMap::new(),
+            seen: 0,
+        })
+    }
+
+    fn sample(&mut self, event: Event) {
+        let group = self.group_by.as_ref().and_then(|template| {
+            template
+                .render_string(&event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("group_by"),
+                        drop_event: false,
+                    })
+                })
+                .ok()
+        });
+        let group =
+            if self.reservoirs.contains_key(&group) || self.reservoirs.len() < self.max_groups {
+                group
+            } else {
+                Some(OVERFLOW_GROUP.to_owned())
+            };
+
+        let capacity = self.events;
+        let reservoir = self
+            .reservoirs
+            .entry(group)
+            .or_insert_with(|| Reservoir::new(capacity));
+        if reservoir.observe(self.seen, event) {
+            emit!(SampleEventDiscarded);
+        }
+        self.seen += 1;
+    }
+
+    /// Ends the current interval, returning the sampled events in the order they were seen.
+    fn flush(&mut self) -> Vec<Event> {
+        let mut sampled = self
+            .reservoirs
+            .drain()
+            .flat_map(|(_, reservoir)| reservoir.into_events())
+            .collect::<Vec<_>>();
+        sampled.sort_unstable_by_key(|(index, _)| *index);
+        self.seen = 0;
+        sampled.into_iter().map(|(_, event)| event).collect()
+    }
+}
+
+impl TaskTransform<Event> for ReservoirSample {
+    fn transform(
+        mut self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut interval =
+            tokio::time::interval_at(tokio::time::Instant::now() + self.interval, self.interval);
+
+        Box::pin(
+            stream! {
+                loop {
+                    let mut output = Vec::new();
+                    let done = tokio::select! {
+                        maybe_event = input_rx.next() => match maybe_event {
+                            None => {
+                                output = self.flush();
+                                true
+                            }
+                            Some(event) => {
+                                let (excluded, event) = match self.exclude.as_ref() {
+                                    Some(condition) => condition.check(event),
+                                    None => (false, event),
+                                };
+                                if excluded {
+                                    output.push(event);
+                                } else {
+                                    self.sample(event);
+                                }
+                                false
+                            }
+                        },
+                        _ = interval.tick() => {
+                            output = self.flush();
+                            false
+                        }
+                    };
+                    yield stream::iter(output.into_iter());
+                    if done {
+                        break;
+                    }
+                }
+            }
+            .flatten(),
+        )
+    }
+}
+
+/// A uniform sample of at most `capacity` events, as described by Algorithm R.
+struct Reservoir {
+    capacity: usize,
+    /// The number of events observed.
+    count: usize,
+    /// The sampled events, along with the order in which they were seen.
+    events: Vec<(usize, Event)>,
+}
+
+impl Reservoir {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            count: 0,
+            events: Vec::new(),
+        }
+    }
+
+    /// Observes an event, returning whether an event was discarded to keep the sample uniform.
+    fn observe(&mut self, index: usize, event: Event) -> bool {
+        self.count += 1;
+        if self.events.len() < self.capacity {
+            self.events.push((index, event));
+            return false;
+        }
+
+        let position = thread_rng().gen_range(0..self.count);
+        if let Some(slot) = self.events.get_mut(position) {
+            *slot = (index, event);
+        }
+        true
+    }
+
+    /// The sampled events, with the ratio of the observed events to the sampled events as their
+    /// `sample_rate` field.
+    fn into_events(self) -> impl Iterator<Item = (usize, Event)> {
+        let sample_rate = (self.count as f64 / self.events.len() as f64).to_string();
+        self.events.into_iter().map(move |(index, mut event)| {
+            match event {
+                Event::Log(ref mut event) => event.insert("sample_rate", sample_rate.clone()),
+                Event::Trace(ref mut event) => event.insert("sample_rate", sample_rate.clone()),
+                Event::Metric(_) => panic!("component can never receive metric events"),
+            };
+            (index, event)
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::task::Poll;
+
     use approx::assert_relative_eq;
+    use futures::SinkExt;
 
     use super::*;
     use crate::{
@@ -362,6 +612,125 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn requires_rate_or_rate_limit() {
+        for config in ["", "rate = 10\nrate_limit.events = 10"] {
+            let config = toml::from_str::<SampleConfig>(config).unwrap();
+            let error = config.build(&TransformContext::default()).await.err();
+            assert_eq!(
+                error.unwrap().to_string(),
+                "Exactly one of `rate` and `rate_limit` must be set"
+            );
+        }
+
+        let config = toml::from_str::<SampleConfig>("rate_limit.events = 0").unwrap();
+        assert!(config.build(&TransformContext::default()).await.is_err());
+    }
+
+    fn rate_limited(config: &str) -> Box<dyn TaskTransform<vector_core::event::EventArray>> {
+        let config = toml::from_str::<SampleConfig>(config).unwrap();
+        let rate_limit = config.rate_limit.unwrap();
+        Transform::event_task(ReservoirSample::new(&rate_limit, None).unwrap()).into_task()
+    }
+
+    fn indexed_event(index: usize, group: &str) -> Event {
+        let mut log = LogEvent::from(index.to_string());
+        log.insert("group", group);
+        log.into()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limit_samples_budget_per_interval() {
+        let sampler = rate_limited(indoc::indoc! {r#"
+            rate_limit.events = 10
+            rate_limit.interval_secs = 60
+        "#});
+        let (mut tx, rx) = futures::channel::mpsc::channel(100);
+        let mut out_stream = sampler.transform_events(Box::pin(rx));
+
+        for index in 0..100 {
+            tx.send(indexed_event(index, "a")).await.unwrap();
+        }
+        // The events are held back until the end of the interval.
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        tokio::time::advance(Duration::from_secs(60)).await;
+
+        let mut indexes = Vec::new();
+        for _ in 0..10 {
+            let event = out_stream.next().await.unwrap();
+            assert_eq!(event.as_log()["sample_rate"], "10".into());
+            indexes.push(
+                event.as_log()["message"]
+                    .to_string_lossy()
+                    .parse::<usize>()
+                    .unwrap(),
+            );
+        }
+        assert!(indexes.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        // The remaining events are flushed when the input ends, all of them fitting the budget.
+        for index in 0..4 {
+            tx.send(indexed_event(index, "a")).await.unwrap();
+        }
+        drop(tx);
+        let events = out_stream.collect::<Vec<_>>().await;
+        assert_eq!(events.len(), 4);
+        assert!(events
+            .iter()
+            .all(|event| event.as_log()["sample_rate"] == "1".into()));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_groups_with_bounded_cardinality() {
+        let sampler = rate_limited(indoc::indoc! {r#"
+            rate_limit.events = 5
+            rate_limit.group_by = "{{ group }}"
+            rate_limit.max_groups = 2
+        "#});
+        let events = [("a", 20), ("b", 5), ("c", 10), ("d", 10)]
+            .into_iter()
+            .flat_map(|(group, count)| (0..count).map(move |index| indexed_event(index, group)));
+        let events = sampler
+            .transform_events(Box::pin(futures::stream::iter(events)))
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut groups = HashMap::<String, (usize, String)>::new();
+        for event in &events {
+            let log = event.as_log();
+            let group = match log["group"].to_string_lossy().as_str() {
+                "c" | "d" => OVERFLOW_GROUP.to_owned(),
+                group => group.to_owned(),
+            };
+            let entry = groups
+                .entry(group)
+                .or_insert_with(|| (0, log["sample_rate"].to_string_lossy()));
+            entry.0 += 1;
+            assert_eq!(entry.1, log["sample_rate"].to_string_lossy());
+        }
+        assert_eq!(groups["a"], (5, "4".to_owned()));
+        assert_eq!(groups["b"], (5, "1".to_owned()));
+        assert_eq!(groups[OVERFLOW_GROUP], (5, "4".to_owned()));
+    }
+
+    #[test]
+    fn reservoir_samples_uniformly() {
+        let mut total = 0;
+        for _ in 0..200 {
+            let mut reservoir = Reservoir::new(10);
+            for index in 0..1000 {
+                reservoir.observe(index, LogEvent::default().into());
+            }
+            total += reservoir
+                .into_events()
+                .map(|(index, _)| index)
+                .sum::<usize>();
+        }
+        let mean = total as f64 / 2000.0;
+        assert!((450.0..550.0).contains(&mean), "mean index {}", mean);
+    }
+
     #[test]
     fn handles_trace_event() {
         let event: TraceEvent = LogEvent::from("trace").into();
@@ -1,4 +1,9 @@
-use std::{num::NonZeroU32, pin::Pin, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroU32,
+    pin::Pin,
+    time::Duration,
+};
 
 use async_stream::stream;
 use futures::{stream, Stream, StreamExt};
@@ -10,7 +15,10 @@ use crate::{
     conditions::{AnyCondition, Condition},
     config::{DataType, Input, Output, TransformConfig, TransformContext, TransformDescription},
     event::Event,
-    internal_events::{TemplateRenderingError, ThrottleEventDiscarded},
+    internal_events::{
+        TemplateRenderingError, ThrottleEventDiscarded, ThrottleKeySeen, ThrottleKeyUntracked,
+        ThrottleKeyUtilization,
+    },
     schema,
     template::Template,
     transforms::{TaskTransform, Transform},
@@ -35,8 +43,23 @@ pub struct ThrottleConfig {
     #[configurable(metadata(templatable))]
     key_field: Option<Template>,
 
-    /// A logical condition used to exclude events from sampling.
+    /// A logical condition used to exclude events from throttling.
+    ///
+    /// Excluded events are always passed through, and don't count towards the `threshold` of their key.
     exclude: Option<AnyCondition>,
+
+    /// The number of keys to report the utilization of.
+    ///
+    /// When set, the utilization of the busiest keys, being the number of events seen for a key in the
+    /// last window divided by `threshold`, is reported every `window_secs` as the
+    /// `throttle_key_utilization` gauge, and the keys seen are counted by the `throttle_unique_keys_total`
+    /// counter. The busiest keys are estimated with a bounded amount of memory, so the reported
+    /// utilization of keys seen rarely may be overestimated. Up to 10000 keys are remembered per
+    /// window to count the unique keys, and the events with other keys are counted by the
+    /// `throttle_untracked_keys_total` counter instead.
+    ///
+    /// By default, no utilization is reported.
+    utilization_top_keys: Option<usize>,
 }
 
 inventory::submit! {
@@ -71,6 +94,7 @@ pub struct Throttle<C: clock::Clock<Instant = I>, I: clock::Reference> {
     flush_keys_interval: Duration,
     key_field: Option<Template>,
     exclude: Option<Condition>,
+    utilization: Option<KeyUtilization>,
     clock: C,
 }
 
@@ -102,6 +126,11 @@ where
             .as_ref()
             .map(|condition| condition.build(&context.enrichment_tables))
             .transpose()?;
+        let utilization = match config.utilization_top_keys {
+            Some(0) => return Err(Box::new(ConfigError::ZeroUtilizationTopKeys)),
+            Some(top_keys) => Some(KeyUtilization::new(top_keys, threshold)),
+            None => None,
+        };
 
         Ok(Self {
             quota,
@@ -109,6 +138,7 @@ where
             flush_keys_interval,
             key_field: config.key_field.clone(),
             exclude,
+            utilization,
         })
     }
 }
@@ -119,7 +149,7 @@ where
     I: clock::Reference + Send + 'static,
 {
     fn transform(
-        self: Box<Self>,
+        mut self: Box<Self>,
         mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
     ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
     where
@@ -129,6 +159,8 @@ where
 
         let mut flush_stream = tokio::time::interval(Duration::from_millis(1000));
 
+        let mut report_utilization = tokio::time::interval(self.flush_keys_interval);
+
         let limiter = RateLimiter::dashmap_with_clock(self.quota, &self.clock);
 
         Box::pin(
@@ -162,6 +194,10 @@ where
                                                 .ok()
                                         });
 
+                                        if let Some(utilization) = self.utilization.as_mut() {
+                                            utilization.observe(key.as_deref().unwrap_or("None"));
+                                        }
+
                                         match limiter.check_key(&key) {
                                             Ok(()) => {
                                                 output.push(event);
@@ -188,6 +224,12 @@ where
                     _ = flush_stream.tick() => {
                         false
                     }
+                    _ = report_utilization.tick(), if self.utilization.is_some() => {
+                        if let Some(utilization) = self.utilization.as_mut() {
+                            utilization.report();
+                        }
+                        false
+                    }
                 };
                 yield stream::iter(output.into_iter());
                 if done { break }
@@ -202,6 +244,126 @@ where
 pub enum ConfigError {
     #[snafu(display("`threshold`, and `window_secs` must be non-zero"))]
     NonZero,
+    #[snafu(display("`utilization_top_keys` must be non-zero"))]
+    ZeroUtilizationTopKeys,
+}
+
+/// The number of keys tracked by the utilization sketch per reported key.
+const SKETCH_KEYS_PER_TOP_KEY: usize = 10;
+
+/// The number of keys remembered per window to count the unique keys seen.
+const MAX_SEEN_KEYS: usize = 10_000;
+
+/// Tracks how close the busiest keys are to their threshold within a window.
+#[derive(Clone, Debug)]
+struct KeyUtilization {
+    top_keys: usize,
+    threshold: f64,
+    window: SpaceSaving,
+    /// Keys seen since the last report, and in the window before that.
+    ///
+    /// Keys that aren't seen for two windows are forgotten, and counted again as unique keys when
+    /// they reappear, just like their rate limiter state is forgotten. At most `max_seen_keys` are
+    /// remembered per window, and new keys seen once it's full are counted as untracked instead.
+    seen: HashSet<String>,
+    previously_seen: HashSet<String>,
+    max_seen_keys: usize,
+}
+
+impl KeyUtilization {
+    fn new(top_keys: usize, threshold: NonZeroU32) -> Self {
+        Self {
+            top_keys,
+            threshold: threshold.get() as f64,
+            window: SpaceSaving::new(top_keys.saturating_mul(SKETCH_KEYS_PER_TOP_KEY)),
+            seen: HashSet::new(),
+            previously_seen: HashSet::new(),
+            max_seen_keys: MAX_SEEN_KEYS,
+        }
+    }
+
+    fn observe(&mut self, key: &str) {
+        if !self.seen.contains(key) {
+            let known = self.previously_seen.contains(key);
+            if self.seen.len() < self.max_seen_keys {
+                if !known {
+                    emit!(ThrottleKeySeen);
+                }
+                self.seen.insert(key.to_owned());
+            } else if !known {
+                emit!(ThrottleKeyUntracked);
+            }
+        }
+        self.window.observe(key);
+    }
+
+    /// Reports the utilization of the busiest keys of the window, and starts a new window.
+    fn report(&mut self) {
+        for (key, count) in self.window.top(self.top_keys) {
+            emit!(ThrottleKeyUtilization {
+                key,
+                utilization: count as f64 / self.threshold,
+            });
+        }
+        self.window.clear();
+        self.previously_seen = std::mem::take(&mut self.seen);
+    }
+}
+
+/// Estimates the most frequent keys of a stream with the space-saving algorithm, which tracks the
+/// counts of at most `capacity` keys.
+///
+/// Any key seen more than `1 / capacity` of the time is guaranteed to be tracked, and its count is
+/// overestimated by at most the count of the least frequent tracked key.
+#[derive(Clone, Debug)]
+struct SpaceSaving {
+    capacity: usize,
+    counts: Vec<(String, u64)>,
+    index: HashMap<String, usize>,
+}
+
+impl SpaceSaving {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            counts: Vec::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+        }
+    }
+
+    fn observe(&mut self, key: &str) {
+        if let Some(&position) = self.index.get(key) {
+            self.counts[position].1 += 1;
+        } else if self.counts.len() < self.capacity {
+            self.index.insert(key.to_owned(), self.counts.len());
+            self.counts.push((key.to_owned(), 1));
+        } else if let Some((position, _)) = self
+            .counts
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (_, count))| *count)
+        {
+            // The new key takes over the least frequent key, inheriting its count as the error.
+            let (evicted, count) = &mut self.counts[position];
+            self.index.remove(evicted.as_str());
+            *evicted = key.to_owned();
+            *count += 1;
+            self.index.insert(key.to_owned(), position);
+        }
+    }
+
+    /// Returns the `n` keys with the highest counts, highest first.
+    fn top(&self, n: usize) -> Vec<(String, u64)> {
+        let mut counts = self.counts.clone();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts.truncate(n);
+        counts
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+        self.index.clear();
+    }
 }
 
 #[cfg(test)]
@@ -211,7 +373,10 @@ mod tests {
     use futures::SinkExt;
 
     use super::*;
-    use crate::event::LogEvent;
+    use crate::{
+        event::{LogEvent, MetricValue},
+        metrics::{self, Controller},
+    };
 
     #[test]
     fn generate_config() {
@@ -406,4 +571,187 @@ key_field = "{{ bucket }}"
         // And still nothing there
         assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
     }
+
+    #[test]
+    fn space_saving_finds_top_keys_under_skew() {
+        let mut sketch = SpaceSaving::new(10);
+        for i in 0..1000 {
+            match i % 10 {
+                0..=4 => sketch.observe("hot"),
+                5 | 6 => sketch.observe("warm"),
+                _ => sketch.observe(&format!("cold{}", i)),
+            }
+        }
+        assert_eq!(sketch.counts.len(), 10);
+
+        // Counts are overestimated by at most the number of events over the capacity.
+        let top = sketch.top(2);
+        assert_eq!(top[0].0, "hot");
+        assert!((500..=600).contains(&top[0].1));
+        assert_eq!(top[1].0, "warm");
+        assert!((200..=300).contains(&top[1].1));
+    }
+
+    #[test]
+    fn space_saving_counts_exactly_within_capacity() {
+        let mut sketch = SpaceSaving::new(3);
+        for key in ["a", "b", "a", "c", "a", "b"] {
+            sketch.observe(key);
+        }
+        assert_eq!(
+            sketch.top(3),
+            vec![("a".into(), 3), ("b".into(), 2), ("c".into(), 1)]
+        );
+
+        sketch.clear();
+        assert!(sketch.top(3).is_empty());
+    }
+
+    #[test]
+    fn rejects_zero_utilization_top_keys() {
+        let config = toml::from_str::<ThrottleConfig>(
+            r#"
+threshold = 2
+window_secs = 5
+utilization_top_keys = 0
+"#,
+        )
+        .unwrap();
+
+        let error = Throttle::new(
+            &config,
+            &TransformContext::default(),
+            clock::FakeRelativeClock::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.to_string(), "`utilization_top_keys` must be non-zero");
+    }
+
+    fn capture_utilization() -> Vec<(String, f64)> {
+        Controller::get()
+            .unwrap()
+            .capture_metrics()
+            .into_iter()
+            .filter(|metric| metric.name() == "throttle_key_utilization")
+            .map(|metric| match metric.value() {
+                MetricValue::Gauge { value } => (metric.tag_value("key").unwrap(), *value),
+                value => panic!("Unexpected metric value {:?}", value),
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn throttle_utilization_skips_excluded_events() {
+        let _ = metrics::init_test();
+        let clock = clock::FakeRelativeClock::default();
+        let config = toml::from_str::<ThrottleConfig>(
+            r#"
+threshold = 4
+window_secs = 1
+key_field = "{{ bucket }}"
+utilization_top_keys = 1
+exclude = """
+.level == "error"
+"""
+"#,
+        )
+        .unwrap();
+
+        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
+            .map(Transform::event_task)
+            .unwrap();
+
+        let throttle = throttle.into_task();
+
+        let (mut tx, rx) = futures::channel::mpsc::channel(10);
+        let mut out_stream = throttle.transform_events(Box::pin(rx));
+
+        // tokio interval is always immediately ready, so we poll once to make sure
+        // we trip it/set the interval in the future
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        // Errors bypass throttling, so they don't count towards the utilization of their key.
+        for _ in 0..3 {
+            let mut log = LogEvent::from("error");
+            log.insert("bucket", "a");
+            log.insert("level", "error");
+            tx.send(log.into()).await.unwrap();
+        }
+        let mut log = LogEvent::from("info");
+        log.insert("bucket", "b");
+        log.insert("level", "info");
+        tx.send(log.into()).await.unwrap();
+
+        for _ in 0..4 {
+            assert!(out_stream.next().await.is_some());
+        }
+        assert!(capture_utilization().is_empty());
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        assert_eq!(capture_utilization(), vec![("b".to_owned(), 0.25)]);
+        assert_eq!(capture_unique_keys(), 1.0);
+    }
+
+    fn capture_unique_keys() -> f64 {
+        capture_counter("throttle_unique_keys_total")
+    }
+
+    fn capture_counter(name: &str) -> f64 {
+        Controller::get()
+            .unwrap()
+            .capture_metrics()
+            .into_iter()
+            .find(|metric| metric.name() == name)
+            .map_or(0.0, |metric| match metric.value() {
+                MetricValue::Counter { value } => *value,
+                value => panic!("Unexpected metric value {:?}", value),
+            })
+    }
+
+    #[test]
+    fn utilization_counts_keys_again_once_forgotten() {
+        let _ = metrics::init_test();
+        let mut utilization = KeyUtilization::new(1, NonZeroU32::new(1).unwrap());
+
+        utilization.observe("a");
+        utilization.observe("a");
+        assert_eq!(capture_unique_keys(), 1.0);
+
+        // Keys seen in the previous window are still known.
+        utilization.report();
+        utilization.observe("a");
+        utilization.observe("b");
+        assert_eq!(capture_unique_keys(), 2.0);
+
+        // Keys not seen for two windows are forgotten.
+        utilization.report();
+        utilization.report();
+        utilization.observe("a");
+        assert_eq!(capture_unique_keys(), 3.0);
+    }
+
+    #[test]
+    fn utilization_bounds_keys_seen() {
+        let _ = metrics::init_test();
+        let mut utilization = KeyUtilization::new(1, NonZeroU32::new(1).unwrap());
+        utilization.max_seen_keys = 2;
+
+        for key in ["a", "b", "c", "d", "a", "c"] {
+            utilization.observe(key);
+        }
+        assert_eq!(utilization.seen.len(), 2);
+        assert_eq!(capture_unique_keys(), 2.0);
+        assert_eq!(capture_counter("throttle_untracked_keys_total"), 3.0);
+
+        // Keys remembered from the previous window aren't untracked, even once the window is full.
+        utilization.report();
+        for key in ["c", "d", "a"] {
+            utilization.observe(key);
+        }
+        assert_eq!(capture_unique_keys(), 4.0);
+        assert_eq!(capture_counter("throttle_untracked_keys_total"), 3.0);
+    }
 }
@@ -1,6 +1,22 @@
-use socket2::SockRef;
+use std::net::SocketAddr;
+
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
 use tokio::net::UdpSocket;
 
+/// Binds a UDP socket to the address, setting `IPV6_V6ONLY` on IPv6 sockets when `v6_only` is given.
+///
+/// Otherwise the platform default applies: IPv6 sockets bound to `[::]` also receive IPv4 datagrams on Linux,
+/// unless disabled system-wide, but not on Windows or the BSDs.
+pub fn bind(addr: SocketAddr, v6_only: Option<bool>) -> std::io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::for_address(addr), Type::DGRAM, Some(Protocol::UDP))?;
+    if let (SocketAddr::V6(_), Some(v6_only)) = (addr, v6_only) {
+        socket.set_only_v6(v6_only)?;
+    }
+    socket.bind(&addr.into())?;
+    socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into())
+}
+
 // This function will be obsolete after tokio/mio internally use `socket2` and expose the methods to
 // apply options to a socket.
 pub fn set_receive_buffer_size(socket: &UdpSocket, size: usize) -> std::io::Result<()> {
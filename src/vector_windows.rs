@@ -397,14 +397,17 @@ fn run_service(_arguments: Vec<OsString>) -> Result<()> {
                 process_id: None,
             })?;
 
-            app.run();
+            let exit_code = match app.run() {
+                exitcode::OK => ServiceExitCode::Win32(NO_ERROR),
+                code => ServiceExitCode::ServiceSpecific(code as u32),
+            };
 
             // Tell the system that service has stopped.
             status_handle.set_service_status(ServiceStatus {
                 service_type: SERVICE_TYPE,
                 current_state: ServiceState::Stopped,
                 controls_accepted: ServiceControlAccept::empty(),
-                exit_code: ServiceExitCode::Win32(NO_ERROR),
+                exit_code,
                 checkpoint: 0,
                 wait_hint: Duration::default(),
                 process_id: None,
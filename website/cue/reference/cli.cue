@@ -96,6 +96,10 @@ cli: {
 			description: env_vars.VECTOR_REQUIRE_HEALTHY.description
 			env_var:     "VECTOR_REQUIRE_HEALTHY"
 		}
+		"strict-env-vars": {
+			description: env_vars.VECTOR_STRICT_ENV_VARS.description
+			env_var:     "VECTOR_STRICT_ENV_VARS"
+		}
 		"verbose": {
 			_short:      "v"
 			description: "Enable more detailed logging. Repeat to reduce further. Overrides `--verbose`."
@@ -164,7 +168,14 @@ cli: {
 		}
 	}
 
-	options: _core_options
+	options: _core_options & {
+		"deny": {
+			description: env_vars.VECTOR_DENY.description
+			type:        "list"
+			enum:        env_vars.VECTOR_DENY.type.string.enum
+			env_var:     "VECTOR_DENY"
+		}
+	}
 
 	commands: {
 		"graph": {
@@ -313,6 +324,10 @@ cli: {
 						logfmt: "Output events as logfmt"
 					}
 				}
+				"errors-of": {
+					description: "Component whose most recently dropped events to observe. Only available for transforms configured with `dropped_event_samples`."
+					type:        "string"
+				}
 				"inputs-of": {
 					description: "Components (transforms, sinks) to observe for their inputs (comma-separated; accepts glob patterns)"
 					type:        "list"
@@ -548,6 +563,19 @@ cli: {
 				"""
 			type: string: default: null
 		}
+		VECTOR_DENY: {
+			description: "Turn the given kinds of config warnings into errors."
+			type: string: {
+				default: null
+				enum: {
+					dangling: """
+						Fail configs with components whose outputs don't reach any sink. When the API is
+						enabled, components without consumers aren't considered dangling, as they can be
+						observed with `vector tap`.
+						"""
+				}
+			}
+		}
 		VECTOR_LOG: {
 			description: "Vector's log level. Each log level includes messages from higher priority levels."
 			type: string: {
@@ -576,6 +604,13 @@ cli: {
 			description: "Exit on startup if any sinks fail healthchecks."
 			type: bool: default: false
 		}
+		VECTOR_STRICT_ENV_VARS: {
+			description: """
+				Fail to load configs referencing environment variables that aren't set and have no default,
+				instead of interpolating them as empty strings.
+				"""
+			type: bool: default: false
+		}
 		VECTOR_THREADS: {
 			description: """
 				The number of threads to use for processing. The default is the number of available cores.
@@ -338,6 +338,9 @@ components: {
 				max_bytes?:    uint | null
 				max_events?:   uint | null
 				timeout_secs?: float | null
+				// `overrides` is set for components whose batch settings can be
+				// overridden per partition with `batch.overrides`.
+				overrides?: bool
 			}
 		}
 
@@ -660,6 +663,20 @@ components: {
 							examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
 						}
 					}
+					min_tls_version: {
+						common:      false
+						description: "The minimum version of the TLS protocol to negotiate. Handshakes with peers that only support older versions are rejected. By default, the minimum version of the TLS library is used."
+						required:    false
+						type: string: {
+							default: null
+							enum: {
+								"TLSv1.0": "TLS 1.0."
+								"TLSv1.1": "TLS 1.1."
+								"TLSv1.2": "TLS 1.2."
+								"TLSv1.3": "TLS 1.3."
+							}
+						}
+					}
 
 					if Args.can_verify_certificate {
 						verify_certificate: {
@@ -727,6 +744,20 @@ components: {
 							examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
 						}
 					}
+					min_tls_version: {
+						common:      false
+						description: "The minimum version of the TLS protocol to negotiate. Handshakes with peers that only support older versions are rejected. By default, the minimum version of the TLS library is used."
+						required:    false
+						type: string: {
+							default: null
+							enum: {
+								"TLSv1.0": "TLS 1.0."
+								"TLSv1.1": "TLS 1.1."
+								"TLSv1.2": "TLS 1.2."
+								"TLSv1.3": "TLS 1.3."
+							}
+						}
+					}
 
 					if Args.can_verify_certificate {
 						verify_certificate: {
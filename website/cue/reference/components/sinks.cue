@@ -6,6 +6,34 @@ components: sinks: [Name=string]: {
 	features: _
 
 	configuration: {
+		dropped_event_samples: {
+			common:      false
+			description: "Retains the most recent events dropped by this component, so they can be inspected through the `componentErrors` field of the GraphQL API or with `vector tap --errors-of`."
+			required:    false
+			type: object: {
+				examples: []
+				options: {
+					size: {
+						description: "The number of dropped events retained."
+						required:    true
+						type: uint: {
+							examples: [10]
+							unit: "events"
+						}
+					}
+					redact_fields: {
+						common:      false
+						description: "The fields whose values are replaced by `[REDACTED]` in the retained events."
+						required:    false
+						type: array: {
+							default: []
+							items: type: string: examples: ["password", "user.token"]
+						}
+					}
+				}
+			}
+		}
+
 		if features.acknowledgements {
 			acknowledgements: {
 				common: true
@@ -61,6 +89,57 @@ components: sinks: [Name=string]: {
 									unit:    "seconds"
 								}
 							}
+							if features.send.batch.overrides != _|_ {
+								if features.send.batch.overrides {
+									overrides: {
+										common:      false
+										description: """
+											Batch settings overriding the settings above for the partitions whose rendered key
+											matches `key` exactly. Settings not set in an override fall back to the settings above.
+											"""
+										required:    false
+										type: array: {
+											default: []
+											items: type: object: options: {
+												key: {
+													description: "The rendered partition key the settings apply to."
+													required:    true
+													type: string: {
+														examples: ["premium"]
+													}
+												}
+												max_bytes: {
+													description: "The maximum size of a batch of the partition, in bytes, before it is flushed."
+													required:    false
+													common:      true
+													type: uint: {
+														default: null
+														unit:    "bytes"
+													}
+												}
+												max_events: {
+													description: "The maximum size of a batch of the partition, in events, before it is flushed."
+													required:    false
+													common:      true
+													type: uint: {
+														default: null
+														unit:    "events"
+													}
+												}
+												timeout_secs: {
+													description: "The maximum age of a batch of the partition before it is flushed."
+													required:    false
+													common:      true
+													type: float: {
+														default: null
+														unit:    "seconds"
+													}
+												}
+											}
+										}
+									}
+								}
+							}
 						}
 					}
 				}
@@ -129,6 +208,58 @@ components: sinks: [Name=string]: {
 			}
 		}
 
+		fallback: {
+			common:      false
+			description: "Sends events to another sink while this sink is failing. The delivery status of every batch is tracked by a circuit breaker; once it opens, new batches are sent to the fallback sink, and a single batch is periodically sent to this sink to probe whether it has recovered. The sink must have acknowledgements enabled and, if it makes requests, a bounded `request.retry_attempts`, so that failed batches are reported."
+			required:    false
+			type: object: {
+				examples: []
+				options: {
+					sink: {
+						description: "The ID of the sink to send events to while the circuit breaker is open. The fallback sink cannot have a fallback itself."
+						required:    true
+						type: string: examples: ["archive"]
+					}
+					consecutive_failures: {
+						common:      false
+						description: "The number of consecutive failed batches that opens the circuit breaker."
+						required:    false
+						type: uint: {
+							default: 5
+							unit:    null
+						}
+					}
+					error_rate: {
+						common:      false
+						description: "The ratio of failed batches, between `0` and `1`, within the last `error_rate_window` batches that opens the circuit breaker."
+						required:    false
+						type: float: {
+							default: null
+							examples: [0.5]
+						}
+					}
+					error_rate_window: {
+						common:      false
+						description: "The number of most recent batches the error rate is calculated over."
+						required:    false
+						type: uint: {
+							default: 100
+							unit:    null
+						}
+					}
+					probe_interval_secs: {
+						common:      false
+						description: "The number of seconds between probes of this sink while the circuit breaker is open."
+						required:    false
+						type: uint: {
+							default: 30
+							unit:    "seconds"
+						}
+					}
+				}
+			}
+		}
+
 		if features.send != _|_ {
 			if features.send.compression.enabled {
 				compression: {
@@ -196,6 +327,9 @@ components: sinks: [Name=string]: {
 											if codec == "avro" {
 												avro: "Avro encoded event with a given schema."
 											}
+											if codec == "gelf" {
+												gelf: "[GELF](\(urls.graylog_gelf)) encoded event."
+											}
 										}
 									}
 								}
@@ -204,6 +338,50 @@ components: sinks: [Name=string]: {
 						options: {
 							if features.send.encoding.codec.enabled {
 								for codec in features.send.encoding.codec.enum {
+									if codec == "gelf" {
+										gelf: {
+											description:   "Options for the `gelf` codec."
+											required:      false
+											relevant_when: "codec = `gelf`"
+											type: object: options: {
+												flatten_separator: {
+													description: "The separator joining the path of a nested field into the name of the additional field it is flattened into."
+													required:    false
+													type: string: default: "_"
+												}
+												invalid_field_names: {
+													description: "How additional fields whose names aren't valid GELF field names are handled."
+													required:    false
+													type: string: {
+														default: "error"
+														enum: {
+															error:    "The event is rejected."
+															sanitize: "Invalid characters are replaced with `_`, and the reserved `_id` field is renamed to `__id`."
+														}
+													}
+												}
+												chunk_size: {
+													description: "The maximum size of the datagrams sent by the `udp` mode. Larger messages are split into GELF chunks of at most this size, headers included."
+													required:    false
+													type: uint: {
+														default: 8192
+														unit:    "bytes"
+													}
+												}
+												compression: {
+													description: "The compression applied to messages before they are sent by the `udp` mode."
+													required:    false
+													type: string: {
+														default: "none"
+														enum: {
+															none: "Messages aren't compressed."
+															zlib: "Messages are compressed with zlib."
+														}
+													}
+												}
+											}
+										}
+									}
 									if codec == "avro" {
 										avro: {
 											description:   "Options for the `avro` codec."
@@ -72,15 +72,41 @@ components: sinks: aws_kinesis_firehose: components._aws & {
 	}
 
 	configuration: {
+		aggregate_records: {
+			common:      false
+			description: "Whether or not to join encoded events, separated by newlines, into aggregated records of up to 1,000 KiB, reducing the number of records billed. Can't be combined with `compression`."
+			required:    false
+			type: bool: default: false
+		}
+		max_open_streams: {
+			common:      false
+			description: "The maximum number of delivery streams batched at once. When an event for another delivery stream arrives while this many batches are open, the largest open batch is sent early."
+			required:    false
+			type: uint: {
+				default: 100
+				unit:    null
+			}
+		}
 		stream_name: {
-			description: "The [stream name](\(urls.aws_cloudwatch_logs_stream_name)) of the target Kinesis Firehose delivery stream."
+			description: "The [stream name](\(urls.aws_cloudwatch_logs_stream_name)) of the target Kinesis Firehose delivery stream. Events are batched separately for every rendered stream name."
 			required:    true
 			type: string: {
-				examples: ["my-stream"]
+				examples: ["my-stream", "{{ tenant }}-stream"]
+				syntax: "template"
 			}
 		}
 	}
 
+	how_it_works: {
+		partial_failures: {
+			title: "Partial failures"
+			body: """
+				When Kinesis Firehose rejects some of the records of a `PutRecordBatch` request, only the
+				rejected records are sent again, up to five attempts in total with an exponential backoff.
+				"""
+		}
+	}
+
 	input: {
 		logs:    true
 		metrics: null
@@ -21,6 +21,7 @@ components: sinks: aws_s3: components._aws & {
 				common:       true
 				max_bytes:    10000000
 				timeout_secs: 300.0
+				overrides:    true
 			}
 			compression: {
 				enabled: true
@@ -30,9 +30,60 @@ components: sinks: blackhole: {
 	}
 
 	configuration: {
+		ack_delay_secs: {
+			common:      false
+			description: "The number of seconds to delay acknowledging the events consumed. Events are consumed right away, but their finalizers are only completed after the delay, which is useful to exercise how sources handle slow acknowledgements. By default, events are acknowledged once consumed."
+			required:    false
+			type: float: {
+				default: null
+				examples: [0.5]
+				unit: "seconds"
+			}
+		}
+		burst: {
+			common:      false
+			description: "The number of events that the sink is allowed to consume at once, before being limited to `rate`. The sink is rate limited with a token bucket holding up to `burst` events, refilled at `rate` events per second, so that spiky traffic can be simulated. Requires `rate` to be set. By default, events are consumed at a steady `rate`."
+			required:    false
+			type: uint: {
+				default: null
+				examples: [100]
+				unit: null
+			}
+		}
+		exit_on_rejected: {
+			common:      false
+			description: "Whether to shut down Vector with a failure exit code once an event consumed is rejected by another component. Events are shared with the other sinks they're sent to, so this is useful to fail fast, such as in CI pipelines, when another sink rejects them. Whether an event was rejected is known once every sink it was sent to has finalized it, which requires the source to have acknowledgements enabled."
+			required:    false
+			type: bool: default: false
+		}
+		metrics_flush_interval_secs: {
+			common:      false
+			description: "The number of seconds between flushing the internal metrics of the events consumed. By default, internal metrics are updated as soon as events are consumed. When set, they are accumulated and flushed on this interval instead, independently of `print_interval_secs`."
+			required:    false
+			type: uint: {
+				default: null
+				examples: [10]
+				unit: "seconds"
+			}
+		}
+		openmetrics_address: {
+			common:      false
+			description: "The address to expose the counters of the sink on, in the [OpenMetrics](https://openmetrics.io) text format. The total number of events and raw bytes consumed are exposed as the `blackhole_events` and `blackhole_raw_bytes` counters, along with the time they were created. The sink fails to build if the address can't be bound."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["0.0.0.0:9599"]
+			}
+		}
+		per_type_metrics: {
+			common:      false
+			description: "Whether to count the events consumed separately for each type of event. When enabled, `component_received_events_total` is also emitted with an `event_type` tag of `log`, `metric`, or `trace`."
+			required:    false
+			type: bool: default: false
+		}
 		print_interval_secs: {
 			common:      false
-			description: "The number of seconds between reporting a summary of activity. Set to `0` to disable reporting."
+			description: "The number of seconds between reporting a summary of activity. The summary includes the total number of events and raw bytes consumed, and the rates of events and bytes consumed per second. Set to `0` to disable reporting."
 			required:    false
 			type: uint: {
 				default: 1
@@ -50,6 +101,65 @@ components: sinks: blackhole: {
 				unit: null
 			}
 		}
+		report_ack_latency: {
+			common:      false
+			description: "Whether to report the latency of acknowledging the events consumed. The time from receiving events to completing their finalizers is recorded in the `blackhole_ack_latency_seconds` histogram, and its percentiles are logged along with the activity summary."
+			required:    false
+			type: bool: default: false
+		}
+		require_finalizers: {
+			common:      false
+			description: "Whether to report events received without finalizers as errors. Sources attach finalizers to events when acknowledgements are enabled, so this is useful to validate that a source wires them up. Events without finalizers have no status to reject, so they're counted as errors and still consumed."
+			required:    false
+			type: bool: default: false
+		}
+		shutdown_after_idle_secs: {
+			common:      false
+			description: "The number of seconds without receiving events after which to shut Vector down. This is useful to exit once one-shot jobs, such as imports, are done. The whole topology is shut down gracefully, as when Vector receives `SIGTERM`, and the events still in flight are consumed. By default, the sink consumes events until the topology is shut down."
+			required:    false
+			type: uint: {
+				default: null
+				examples: [30]
+				unit: "seconds"
+			}
+		}
+		smoothing: {
+			common:      false
+			description: "Reports the rates of events and bytes consumed per second in the activity summary as exponential moving averages, rather than the rates of the latest reporting interval alone."
+			required:    false
+			type: object: options: {
+				alpha: {
+					description: "The weight given to the rate of the latest reporting interval. Must be greater than `0` and at most `1`. Lower values give a smoother, but slower to react, average."
+					required:    true
+					type: float: examples: [0.2]
+				}
+			}
+		}
+		snapshot_interval_secs: {
+			common:      false
+			description: "The number of seconds between snapshots written to `snapshot_to`."
+			required:    false
+			type: uint: {
+				default: 10
+				examples: [60]
+				unit: "seconds"
+			}
+		}
+		snapshot_to: {
+			common:      false
+			description: "The path of a CSV file to periodically append snapshots of the counters of the sink to. Each row contains the time of the snapshot, the total number of events and raw bytes consumed, and the rate of events consumed per second since the previous snapshot, under a `timestamp,events,bytes,rate` header. Failures to write to the file are logged, and don't stop the sink."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["/var/lib/vector/blackhole.csv"]
+			}
+		}
+		write_to_temp_file: {
+			common:      false
+			description: "Whether to write the events consumed to a temporary file, for inspection. Each event is written as a line of JSON. The path of the file is logged at startup, and the file is deleted when the sink shuts down."
+			required:    false
+			type: bool: default: false
+		}
 	}
 
 	input: {
@@ -66,7 +176,10 @@ components: sinks: blackhole: {
 	}
 
 	telemetry: metrics: {
-		processed_bytes_total:  components.sources.internal_metrics.output.metrics.processed_bytes_total
-		processed_events_total: components.sources.internal_metrics.output.metrics.processed_events_total
+		blackhole_ack_latency_seconds:   components.sources.internal_metrics.output.metrics.blackhole_ack_latency_seconds
+		component_errors_total:          components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_events_total: components.sources.internal_metrics.output.metrics.component_received_events_total
+		processed_bytes_total:           components.sources.internal_metrics.output.metrics.processed_bytes_total
+		processed_events_total:          components.sources.internal_metrics.output.metrics.processed_events_total
 	}
 }
@@ -115,6 +115,16 @@ components: sinks: kafka: {
 			}
 		}
 		socket_timeout_ms: components._kafka.configuration.socket_timeout_ms
+		statistics_interval_ms: {
+			common:      false
+			description: "The interval at which `librdkafka` reports the statistics translated into internal metrics. Set to `0` to disable the statistics."
+			required:    false
+			type: uint: {
+				default: 1000
+				examples: [0, 5000]
+				unit: "milliseconds"
+			}
+		}
 		topic: {
 			description: "The Kafka topic name to write events to."
 			required:    true
@@ -165,5 +175,7 @@ components: sinks: kafka: {
 		kafka_produced_messages_bytes_total: components.sources.internal_metrics.output.metrics.kafka_produced_messages_bytes_total
 		kafka_consumed_messages_total:       components.sources.internal_metrics.output.metrics.kafka_consumed_messages_total
 		kafka_consumed_messages_bytes_total: components.sources.internal_metrics.output.metrics.kafka_consumed_messages_bytes_total
+		kafka_delivery_errors_total:         components.sources.internal_metrics.output.metrics.kafka_delivery_errors_total
+		kafka_request_latency_avg:           components.sources.internal_metrics.output.metrics.kafka_request_latency_avg
 	}
 }
@@ -22,6 +22,7 @@ components: sinks: loki: {
 				max_bytes:    1_000_000
 				max_events:   100_000
 				timeout_secs: 1.0
+				overrides:    true
 			}
 			compression: {
 				enabled: true
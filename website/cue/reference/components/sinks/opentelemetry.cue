@@ -0,0 +1,132 @@
+package metadata
+
+components: sinks: opentelemetry: {
+	title: "OpenTelemetry"
+
+	description: """
+		Exports logs to an OpenTelemetry collector, or any other OTLP-compatible receiver, over gRPC.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: []
+		stateful: false
+	}
+
+	features: {
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    10_000_000
+				timeout_secs: 1.0
+			}
+			compression: enabled: false
+			encoding: enabled:    false
+			proxy: enabled:       true
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+			}
+			to: {
+				service: services.opentelemetry
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: {
+		endpoint: {
+			description: "The endpoint of the OTLP/gRPC receiver to export logs to. The endpoint should include the port. Use the `https` scheme to connect with TLS."
+			required:    true
+			type: string: {
+				examples: ["http://localhost:4317", "https://otlp.example.com:4317"]
+			}
+		}
+		compression: {
+			description: "Enable gRPC compression with gzip."
+			common:      true
+			required:    false
+			type: bool: default: false
+		}
+		headers: {
+			common:      false
+			description: "Custom headers to add to each export request, sent as gRPC metadata. This is commonly used to authenticate against OTLP backends."
+			required:    false
+			type: object: {
+				examples: [
+					{
+						"x-api-key": "${OTLP_API_KEY}"
+					},
+				]
+				options: {}
+			}
+		}
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		mapping: {
+			title: "Log record mapping"
+			body: """
+				Events are converted into OTLP log records with the reverse of the mapping used by the
+				`opentelemetry` source. The message becomes the body of the record, the `attributes` and
+				`resources` fields become the attributes of the record and of its resource, the `scope`
+				field becomes its instrumentation scope, and the hex-encoded `trace_id` and `span_id`
+				fields are decoded back into bytes. The `timestamp`, `observed_timestamp`,
+				`severity_text`, `severity_number`, `flags` and `dropped_attributes_count` fields are
+				carried over as they are, and any other field becomes an attribute of the record.
+
+				Events of the Vector log namespace are the body of their record, and the other fields are
+				read from the `opentelemetry` source metadata instead.
+
+				Each batch of events is sent as a single export request, holding the records of the same
+				resource and instrumentation scope together.
+				"""
+		}
+		acknowledgements: {
+			title: "Acknowledgements"
+			body: """
+				Events are acknowledged once the export request holding them succeeds. Export requests
+				failing with a retryable gRPC status, such as `UNAVAILABLE` or `RESOURCE_EXHAUSTED`, are
+				retried according to the `request` options, and the events of requests failing with any
+				other status are rejected.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+	}
+}
@@ -0,0 +1,163 @@
+package metadata
+
+components: sinks: victorialogs: {
+	title: "VictoriaLogs"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: ["VictoriaMetrics"]
+		stateful: false
+	}
+
+	features: {
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    10_000_000
+				timeout_secs: 1.0
+			}
+			compression: {
+				enabled: true
+				default: "gzip"
+				algorithms: ["none", "gzip"]
+				levels: ["none", "fast", "default", "best", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+			}
+			encoding: {
+				enabled: true
+				codec: enabled: false
+			}
+			proxy: enabled: true
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+			}
+			to: {
+				service: services.victorialogs
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: {
+		endpoint: {
+			description: "The base URL of the VictoriaLogs instance. Vector will append `/insert/jsonline` to this."
+			required:    true
+			type: string: {
+				examples: ["http://localhost:9428"]
+			}
+		}
+		auth: configuration._http_auth & {_args: {
+			password_example: "${VICTORIALOGS_PASSWORD}"
+			username_example: "${VICTORIALOGS_USERNAME}"
+		}}
+		stream_fields: {
+			common: true
+			description: """
+				The event fields that identify the log stream an event belongs to. These are sent to VictoriaLogs as
+				the `_stream_fields` query argument.
+
+				Note: Stream fields should have low cardinality. You can read more about stream fields
+				[here](\(urls.victorialogs_stream_fields)).
+				"""
+			required: false
+			type: array: {
+				default: []
+				items: type: string: {
+					examples: ["host", "kubernetes.pod_name"]
+				}
+			}
+		}
+		message_field: {
+			common:      false
+			description: "The event field that is sent to VictoriaLogs as the log message (`_msg`). Defaults to the global `log_schema.message_key` option."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["message", "log"]
+			}
+		}
+		timestamp_field: {
+			common:      false
+			description: "The event field that is sent to VictoriaLogs as the log timestamp (`_time`). Defaults to the global `log_schema.timestamp_key` option."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["timestamp", "ts"]
+			}
+		}
+		account_id: {
+			common: false
+			description: """
+				The tenant account ID, sent as the `AccountID` header with every request.
+
+				You can read more about multitenancy [here](\(urls.victorialogs_multitenancy)).
+				"""
+			required: false
+			type: uint: {
+				default: null
+				examples: [12]
+				unit: null
+			}
+		}
+		project_id: {
+			common:      false
+			description: "The tenant project ID, sent as the `ProjectID` header with every request."
+			required:    false
+			type: uint: {
+				default: null
+				examples: [34]
+				unit: null
+			}
+		}
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		stream_fields: {
+			title: "Stream Fields"
+			body: """
+				VictoriaLogs groups logs into streams using the fields listed in `stream_fields`. The remaining
+				fields of each event are stored as regular log fields that can be searched, so only fields
+				that identify where a log originated, such as the host or application name, belong in
+				`stream_fields`.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		events_discarded_total:           components.sources.internal_metrics.output.metrics.events_discarded_total
+	}
+}
@@ -65,14 +65,67 @@ components: sinks: websocket: {
 			password_example: "${HTTP_PASSWORD}"
 			username_example: "${HTTP_USERNAME}"
 		}}
+		address: {
+			description: """
+				The address to listen for connections on, in server mode. It _must_ include a port.
+				"""
+			relevant_when: "mode = \"server\""
+			required:      false
+			common:        false
+			warnings: []
+			type: string: {
+				default: null
+				examples: ["0.0.0.0:9000"]
+			}
+		}
+		client_buffer_size: {
+			description: """
+				The maximum number of messages buffered for each client, in server mode. When a client
+				doesn't keep up with the events, the oldest messages buffered for it are dropped, so that a
+				slow client never holds up the sink or the other clients.
+				"""
+			relevant_when: "mode = \"server\""
+			required:      false
+			common:        false
+			warnings: []
+			type: uint: {
+				default: 1000
+				unit:    "events"
+			}
+		}
+		mode: {
+			description: "The mode of the sink."
+			required:    false
+			common:      false
+			warnings: []
+			type: string: {
+				default: "client"
+				enum: {
+					client: "Connects to the WebSocket server at `uri`, and sends it all events."
+					server: """
+						Listens on `address` for WebSocket clients, and streams events to all connected
+						clients. When `auth` is set, clients must send the matching `Authorization` header.
+						With bearer authentication, the token can also be passed as the `token` query
+						parameter, for clients such as browsers that can't set headers. Clients can pass a
+						VRL condition as the `filter` query parameter to only receive the events matching it,
+						for example `ws://vector:9000/?filter=.status%20%3D%3D%20500`. Filters can only use
+						VRL functions that inspect the event, such as `contains`, `exists`, `match`, and
+						`starts_with`.
+						"""
+				}
+			}
+		}
 		uri: {
 			description: """
-				The WebSocket URI to connect to. This should include the protocol and host,
+				The WebSocket URI to connect to, in client mode. This should include the protocol and host,
 				but can also include the port, path, and any other valid part of a URI.
 				"""
-			required: true
+			relevant_when: "mode = \"client\""
+			required:      false
+			common:        true
 			warnings: []
 			type: string: {
+				default: null
 				examples: ["ws://127.0.0.1:9000/endpoint"]
 				syntax: "literal"
 			}
@@ -118,5 +171,6 @@ components: sinks: websocket: {
 		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
 		events_out_total:                 components.sources.internal_metrics.output.metrics.events_out_total
 		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		websocket_messages_dropped_total: components.sources.internal_metrics.output.metrics.websocket_messages_dropped_total
 	}
 }
@@ -0,0 +1,205 @@
+package metadata
+
+components: sources: aws_cloudwatch_logs: components._aws & {
+	title: "AWS Cloudwatch Logs"
+
+	features: {
+		acknowledgements: false
+		collect: {
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+			}
+			checkpoint: enabled: true
+			proxy: enabled:      true
+			from: service:       services.aws_cloudwatch_logs
+		}
+		multiline: enabled: false
+		codecs: {
+			enabled:         true
+			default_framing: "bytes"
+		}
+	}
+
+	classes: {
+		commonly_used: false
+		deployment_roles: ["aggregator"]
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	support: {
+		targets: {
+			"aarch64-unknown-linux-gnu":      true
+			"aarch64-unknown-linux-musl":     true
+			"armv7-unknown-linux-gnueabihf":  true
+			"armv7-unknown-linux-musleabihf": true
+			"x86_64-apple-darwin":            true
+			"x86_64-pc-windows-msv":          true
+			"x86_64-unknown-linux-gnu":       true
+			"x86_64-unknown-linux-musl":      true
+		}
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: {
+		data_dir: {
+			common:      false
+			description: "The directory used to persist checkpoints of the events read from each log group. By default, the [global `data_dir` option](\(urls.vector_configuration)/global-options#data_dir) is used. Please make sure the Vector project has write permissions to this dir."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["/var/local/lib/vector/"]
+			}
+		}
+		filter_pattern: {
+			common:      false
+			description: "A CloudWatch Logs [filter pattern](https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/FilterAndPatternSyntax.html) that events must match to be read."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["ERROR"]
+			}
+		}
+		group_names: {
+			common:      true
+			description: "The names of the log groups to read events from. At least one of `group_names` or `group_name_prefixes` must be set."
+			required:    false
+			type: array: {
+				default: []
+				items: type: string: examples: ["/aws/lambda/my-function"]
+			}
+		}
+		group_name_prefixes: {
+			common:      true
+			description: "Prefixes of the names of the log groups to read events from. Matching log groups are discovered every `poll_secs`."
+			required:    false
+			type: array: {
+				default: []
+				items: type: string: examples: ["/aws/lambda/"]
+			}
+		}
+		ignore_older_secs: {
+			common:      false
+			description: "Ignore events older than this many seconds when a log group is read for the first time. By default, all events in a log group are read."
+			required:    false
+			type: uint: {
+				default: null
+				unit:    "seconds"
+				examples: [600]
+			}
+		}
+		poll_secs: {
+			common:      true
+			description: "How long to wait between polling a log group for new events."
+			required:    false
+			type: uint: {
+				default: 15
+				unit:    "seconds"
+			}
+		}
+		stream_names: {
+			common:      false
+			description: "The names of the log streams to read events from. If empty, events from all log streams are read. Cannot be used together with `stream_name_prefix`."
+			required:    false
+			type: array: {
+				default: []
+				items: type: string: examples: ["my-stream"]
+			}
+		}
+		stream_name_prefix: {
+			common:      false
+			description: "A prefix of the names of the log streams to read events from. Cannot be used together with `stream_names`."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["2022/08/"]
+			}
+		}
+	}
+
+	output: logs: record: {
+		description: "An individual CloudWatch Logs event"
+		fields: {
+			message: {
+				description: "The raw message of the log event."
+				required:    true
+				type: string: {
+					examples: ["START RequestId: 6a6aca43-2d1b-4b4f-a8f3-1b1a6e2b6b4c Version: $LATEST"]
+					syntax: "literal"
+				}
+			}
+			group_name: {
+				description: "The name of the log group the event was read from."
+				required:    true
+				type: string: {
+					examples: ["/aws/lambda/my-function"]
+				}
+			}
+			stream_name: {
+				description: "The name of the log stream the event was read from."
+				required:    true
+				type: string: {
+					examples: ["2022/08/10/[$LATEST]4b4bbd1bb4d8470ba0b1fcc0d6d3b3c1"]
+				}
+			}
+			ingestion_timestamp: {
+				description: "The time the event was ingested by CloudWatch Logs."
+				required:    true
+				type: timestamp: {}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["aws_cloudwatch_logs"]
+				}
+			}
+			timestamp: fields._current_timestamp & {
+				description: "The time of the log event."
+			}
+		}
+	}
+
+	telemetry: metrics: {
+		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
+	}
+
+	how_it_works: {
+		aws_cloudwatch_logs: {
+			title: "AWS Cloudwatch Logs"
+			body: """
+				The `aws_cloudwatch_logs` source polls log groups with the
+				[FilterLogEvents](https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_FilterLogEvents.html)
+				API. Each log group is polled independently, so throttling of one log group backs off
+				only the requests for that log group. Log groups that are deleted stop being polled, and
+				are polled again only if they are discovered again.
+
+				The [StartLiveTail](https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_StartLiveTail.html)
+				streaming API is not supported, since the AWS SDK Vector is built with doesn't provide it.
+				Log groups are always polled, and events are received up to `poll_secs` seconds after they
+				are ingested.
+				"""
+		}
+		checkpointing: {
+			title: "Checkpointing"
+			body: """
+				The position within each log group is checkpointed in the `data_dir` after every page of
+				events, so Vector resumes reading where it left off after a restart.
+				"""
+		}
+	}
+}
@@ -86,6 +86,37 @@ components: sources: datadog_agent: {
 			required:    false
 			type: bool: default: false
 		}
+		passthrough: {
+			common: false
+			description: """
+				How requests to the intake endpoints not supported by the source are handled. The Agent also sends
+				the payloads of other products, such as processes, orchestrator resources and host metadata, to its
+				intake endpoints, and retries them when they fail.
+				"""
+			required: false
+			type: object: options: {
+				mode: {
+					description: "How the requests are handled."
+					required:    true
+					type: string: {
+						default: "reject"
+						enum: {
+							reject: "Requests are rejected with `404 Not Found`."
+							accept: "Requests are acknowledged with `202 Accepted`, and their payloads are discarded."
+							proxy:  "Requests are proxied verbatim, with their headers and body, to `endpoint`, and its responses are returned to the Agent."
+						}
+					}
+				}
+				endpoint: {
+					description: "The base URL of the upstream endpoint, to which the path and query of requests are appended."
+					relevant_when: "mode = \"proxy\""
+					required:      true
+					type: string: {
+						examples: ["https://app.datadoghq.com"]
+					}
+				}
+			}
+		}
 		store_api_key: {
 			common:      false
 			description: "When incoming events contain a Datadog API key, if this setting is set to `true` the key will kept in the event metadata and will be used if the event is sent to a Datadog sink."
@@ -251,5 +282,6 @@ components: sources: datadog_agent: {
 		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
 		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
 		events_in_total:                      components.sources.internal_metrics.output.metrics.events_in_total
+		passthrough_requests_total:           components.sources.internal_metrics.output.metrics.passthrough_requests_total
 	}
 }
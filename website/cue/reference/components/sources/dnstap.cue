@@ -25,7 +25,7 @@ components: sources: dnstap: {
 					}
 					direction: "incoming"
 					port:      0
-					protocols: ["unix"]
+					protocols: ["tcp", "unix"]
 					socket: "/run/bind/dnstap.sock"
 					ssl:    "disabled"
 				}
@@ -54,14 +54,37 @@ components: sources: dnstap: {
 				unit:    "bytes"
 			}
 		}
+		mode: {
+			common:      true
+			description: "The type of socket to listen on for dnstap data."
+			required:    false
+			type: string: {
+				default: "unix"
+				enum: {
+					tcp:  "Listen on a TCP socket, at `address`."
+					unix: "Listen on a Unix socket, at `socket_path`."
+				}
+			}
+		}
+		address: {
+			description:   "The address to listen for dnstap connections on."
+			relevant_when: "mode = `tcp`"
+			required:      true
+			type: string: {
+				examples: ["0.0.0.0:6000", "127.0.0.1:6000"]
+			}
+		}
 		socket_path: {
+			common: true
 			description: """
 				Absolute path of server socket file to which the DNS server is
 				configured to send dnstap data. The socket file will be created
 				by dnstap source component automatically upon startup.
 				"""
-			required: true
+			relevant_when: "mode = `unix`"
+			required:      false
 			type: string: {
+				default: "/run/bind/dnstap.sock"
 				examples: ["/run/bind/dnstap.sock"]
 				syntax: "file_system_path"
 			}
@@ -75,13 +98,36 @@ components: sources: dnstap: {
 				supported by TOML, but it'd be more intuitive to use an octal number.
 				Also note that the value specified must be between `0o700` and `0o777`.
 				"""
-			required: false
+			relevant_when: "mode = `unix`"
+			required:      false
 			type: uint: {
 				default: null
 				unit:    null
 				examples: [0o777, 0o754, 508]
 			}
 		}
+		socket_file_owner: {
+			common:        false
+			description:   "The user ID to set as the owner of the server socket file."
+			relevant_when: "mode = `unix`"
+			required:      false
+			type: uint: {
+				default: null
+				unit:    null
+				examples: [1000]
+			}
+		}
+		socket_file_group: {
+			common:        false
+			description:   "The group ID to set as the group of the server socket file."
+			relevant_when: "mode = `unix`"
+			required:      false
+			type: uint: {
+				default: null
+				unit:    null
+				examples: [1000]
+			}
+		}
 		socket_receive_buffer_size: {
 			common: false
 			description: """
@@ -129,6 +175,18 @@ components: sources: dnstap: {
 			required: false
 			type: bool: default: false
 		}
+		envelope_only: {
+			common: false
+			description: """
+				Whether or not to skip parsing the DNS messages carried by dnstap
+				frames. Only the dnstap envelope is decoded, and the DNS query and
+				response messages are written out as raw bytes, in the `rawData`
+				field of `requestData` and `responseData` respectively. Has no
+				effect if `raw_data_only` is enabled.
+				"""
+			required: false
+			type: bool: default: false
+		}
 	}
 
 	output: logs: event: {
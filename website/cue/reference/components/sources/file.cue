@@ -146,7 +146,7 @@ components: sources: file: {
 		}
 		ignore_older_secs: {
 			common:      true
-			description: "Ignore files with a data modification date older than the specified number of seconds."
+			description: "Ignore files with a data modification date older than the specified number of seconds. This also applies to files that go idle while being watched: once they have been read to their end, they are not read again until they are modified."
 			required:    false
 			type: uint: {
 				default: null
@@ -190,10 +190,21 @@ components: sources: file: {
 				unit: "bytes"
 			}
 		}
+		max_read_bytes_per_second: {
+			category:    "Reading"
+			common:      false
+			description: "An approximate limit on the amount of data read from a single file per second. Files which reach this limit are skipped for the rest of the second, so that replaying a large file doesn't delay reading other files, even with `oldest_first` enabled. By default, reads are not throttled."
+			required:    false
+			type: uint: {
+				default: null
+				examples: [1_048_576]
+				unit: "bytes"
+			}
+		}
 		oldest_first: {
 			category:    "Reading"
 			common:      false
-			description: "Instead of balancing read capacity fairly across all watched files, prioritize draining the oldest files before moving on to read data from younger files."
+			description: "Instead of balancing read capacity fairly across all watched files, prioritize draining the oldest files before moving on to read data from younger files. A file that is behind still lets the younger files be read once a second, so that they aren't starved."
 			required:    false
 			type: bool: default: false
 		}
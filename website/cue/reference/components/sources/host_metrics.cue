@@ -72,7 +72,7 @@ components: sources: host_metrics: {
 			common:      true
 			required:    false
 			type: array: {
-				default: ["cgroups", "cpu", "disk", "filesystem", "load", "host", "memory", "network"]
+				default: ["cgroups", "cpu", "disk", "filesystem", "load", "host", "memory", "network", "tcp"]
 				items: type: string: {
 					enum: {
 						cgroups:    "Metrics related to Linux control groups."
@@ -83,6 +83,7 @@ components: sources: host_metrics: {
 						host:       "Metrics related to host"
 						memory:     "Metrics related to memory utilization."
 						network:    "Metrics related to network utilization."
+						tcp:        "Metrics related to TCP connections and UDP sockets (Linux only)."
 					}
 				}
 			}
@@ -401,6 +402,34 @@ components: sources: host_metrics: {
 				}
 			}
 		}
+		tcp: {
+			common: false
+			description: #"""
+				Options for the "tcp" metrics collector.
+
+				Note: this collector is only available on Linux systems.
+				"""#
+			required: false
+			type: object: options: {
+				ports: {
+					common:   false
+					required: false
+					description: """
+						The local ports to additionally report the sockets of, as the `tcp_port_connections` and
+						`udp_port_sockets` metrics tagged with the `port` tag.
+
+						By default, sockets are only reported by protocol and state, which keeps the number of
+						metrics bounded regardless of the ports in use.
+						"""
+					type: array: {
+						default: []
+						items: type: uint: {
+							examples: [22, 443]
+						}
+					}
+				}
+			}
+		}
 	}
 
 	output: metrics: {
@@ -494,6 +523,23 @@ components: sources: host_metrics: {
 		network_transmit_packets_drop_total: _host & _network_nomac & {description: "The number of packets dropped during transmits on this interface."}
 		network_transmit_packets_total:      _host & _network_nomac & {description: "The number of packets transmitted on this interface."}
 
+		// Host TCP and UDP sockets
+		tcp_connections: _host & _tcp_connections & {description: "The number of TCP connections in the given state."}
+		tcp_port_connections: _host & _tcp_connections & {
+			description: "The number of TCP connections in the given state on a local port of `tcp.ports`."
+			tags: port: _socket_port
+		}
+		tcp_listen_overflows_total: _host & _socket_counter & {description: "The number of times the accept queue of a listening socket overflowed."}
+		tcp_listen_drops_total:     _host & _socket_counter & {description: "The number of connection requests dropped by listening sockets."}
+		udp_sockets:                _host & _udp_sockets & {description: "The number of UDP sockets."}
+		udp_port_sockets: _host & _udp_sockets & {
+			description: "The number of UDP sockets on a local port of `tcp.ports`."
+			tags: port: _socket_port
+		}
+		udp_receive_errors_total:        _host & _socket_counter & {description: "The number of UDP datagrams that could not be delivered."}
+		udp_receive_buffer_errors_total: _host & _socket_counter & {description: "The number of UDP datagrams dropped because the receive buffer was full."}
+		udp_send_buffer_errors_total:    _host & _socket_counter & {description: "The number of UDP datagrams dropped because the send buffer was full."}
+
 		// Helpers
 		_host: {
 			default_namespace: "host"
@@ -583,6 +629,47 @@ components: sources: host_metrics: {
 			}
 		}
 		_network_nomac: _network_gauge & {relevant_when: "OS is not macOS"}
+		_socket_port: {
+			description: "The local port of the sockets."
+			required:    true
+			examples: ["22"]
+		}
+		_tcp_connections: {
+			type: "gauge"
+			tags: _host_metrics_tags & {
+				collector: examples: ["tcp"]
+				protocol: {
+					description: "The protocol of the sockets."
+					required:    true
+					examples: ["tcp", "tcp6"]
+				}
+				state: {
+					description: "The state of the connections."
+					required:    true
+					examples: ["established", "time_wait", "listen"]
+				}
+			}
+			relevant_when: "OS is Linux"
+		}
+		_socket_counter: {
+			type: "counter"
+			tags: _host_metrics_tags & {
+				collector: examples: ["tcp"]
+			}
+			relevant_when: "OS is Linux"
+		}
+		_udp_sockets: {
+			type: "gauge"
+			tags: _host_metrics_tags & {
+				collector: examples: ["tcp"]
+				protocol: {
+					description: "The protocol of the sockets."
+					required:    true
+					examples: ["udp", "udp6"]
+				}
+			}
+			relevant_when: "OS is Linux"
+		}
 	}
 
 	telemetry: metrics: {
@@ -281,7 +281,7 @@ components: sources: http: {
 			title: "Decompression"
 			body: """
 				Received body is decompressed according to `Content-Encoding` header.
-				Supported algorithms are `gzip`, `deflate`, and `snappy`.
+				Supported algorithms are `gzip`, `deflate`, `snappy`, and `zstd`.
 				"""
 		}
 	}
@@ -129,6 +129,50 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _internal_metrics_tags
 		}
+		build_info: {
+			description:       "Has a constant value of 1, and is tagged with the build of the Vector instance."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags: _internal_metrics_tags & {
+				version: {
+					description: "The version of Vector."
+					required:    true
+					examples: ["0.24.0"]
+				}
+				arch: {
+					description: "The architecture Vector was built for."
+					required:    true
+					examples: ["x86_64"]
+				}
+				revision: {
+					description: "The revision Vector was built from, or `none` when it's unknown."
+					required:    true
+					examples: ["d2b8a2b"]
+				}
+			}
+		}
+		component_info: {
+			description:       "Has a constant value of 1 for each component of the running configuration. The series of components removed by a reload are retracted."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags: _internal_metrics_tags & {
+				component_id: _component_id
+				kind:         _component_kind
+				type:         _component_type
+			}
+		}
+		config_hash_info: {
+			description:       "Has a constant value of 1, and is tagged with the hash of the running configuration. The series of the previous configuration is retracted on reload."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags: _internal_metrics_tags & {
+				hash: {
+					description: "The SHA256 hash of the running configuration."
+					required:    true
+					examples: ["bc0825487e137ee1d1fc76c616795d041c4825b4ca5a7236455ea4515238885c"]
+				}
+			}
+		}
 		config_load_errors_total: {
 			description:       "The total number of errors loading the Vector configuration."
 			type:              "counter"
@@ -171,6 +215,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _internal_metrics_tags
 		}
+		connection_throttled_total: {
+			description:       "The total number of times reads from a connection were paused for exceeding `max_events_per_second`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
 		quit_total: {
 			description:       "The total number of times the Vector instance has quit."
 			type:              "counter"
@@ -234,6 +284,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _internal_metrics_tags
 		}
+		blackhole_ack_latency_seconds: {
+			description:       "The time from the `blackhole` sink receiving events to acknowledging them."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
+		body_pattern_misses_total: {
+			description:       "The total number of log record bodies that did not match the configured `body_pattern`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		checkpoint_write_errors_total: {
 			description:       "The total number of errors writing checkpoints. This metric is deprecated in favor of `component_errors_total`."
 			type:              "counter"
@@ -334,6 +396,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		duplicate_requests_total: {
+			description:       "The total number of export requests acknowledged without emitting their records, because a request with the same idempotency key was already processed."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		k8s_format_picker_edge_cases_total: {
 			description:       "The total number of edge cases encountered while picking format of the Kubernetes log message."
 			type:              "counter"
@@ -511,6 +579,21 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		component_events_failed_total: {
+			description:       """
+				The number of events that could not be emitted by this component because of an unexpected error,
+				such as its output stream being closed. Unlike `component_discarded_events_total`, this doesn't
+				count events dropped intentionally.
+				"""
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				output: {
+					description: "The output the events were sent to."
+					required:    true
+				}
+			}
+		}
 		component_errors_total: {
 			description:       "The total number of errors encountered by this component."
 			type:              "counter"
@@ -520,6 +603,12 @@ components: sources: internal_metrics: {
 				stage:      _stage
 			}
 		}
+		component_output_event_bytes_histogram: {
+			description:       "The size in bytes of each event output by this component, as serialized to JSON."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		component_received_bytes_total: {
 			description:       string | *"The number of raw bytes accepted by this component from source origins."
 			type:              "counter"
@@ -558,6 +647,10 @@ components: sources: internal_metrics: {
 					description: "The pathname from which the data originated."
 					required:    false
 				}
+				event_type: {
+					description: "The type of the events received, either `log`, `metric`, or `trace`."
+					required:    false
+				}
 				mode: _mode
 			}
 		}
@@ -606,6 +699,20 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              component_received_events_total.tags
 		}
+		component_received_records_total: {
+			description: """
+				The number of log records received in OpenTelemetry export requests, optionally tagged with
+				a resource attribute.
+				"""
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				"*": {
+					description: "The value of the resource attribute selected by `metric_tag_from_attribute`, `_none` if missing, or `_other` once the maximum number of distinct values is reached."
+					required:    false
+				}
+			}
+		}
 		component_sent_bytes_total: {
 			description:       "The number of raw bytes sent by this component to destination sinks."
 			type:              "counter"
@@ -665,6 +772,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              internal_metrics_cardinality.tags
 		}
+		json_body_misses_total: {
+			description:       "The total number of log record bodies that were not a JSON object when parsing them with `parse_json_body`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		kafka_queue_messages: {
 			description:       "Current number of messages in producer queues."
 			type:              "gauge"
@@ -725,6 +838,49 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		kafka_consumer_lag: {
+			description:       "The number of messages between the high watermark and the committed offset of the consumed partitions, summed per topic unless `statistics_partition_tags` is enabled."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				topic: {
+					description: "The Kafka topic."
+					required:    true
+				}
+				partition: {
+					description: "The Kafka partition. Only present if `statistics_partition_tags` is enabled."
+					required:    false
+				}
+			}
+		}
+		kafka_delivery_errors_total: {
+			description:       "The total number of messages that couldn't be delivered to Kafka."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				topic: {
+					description: "The Kafka topic."
+					required:    true
+				}
+			}
+		}
+		kafka_request_latency_avg: {
+			description:       "The average round-trip time, in seconds, of the requests sent to a Kafka broker during the last statistics interval."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				broker: {
+					description: "The name of the Kafka broker."
+					required:    true
+				}
+			}
+		}
+		filtered_records_total: {
+			description:       "The total number of log records dropped because their resource didn't match the `resource_filter`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		file_delete_errors_total: {
 			description:       "The total number of failures to delete a file. This metric is deprecated in favor of `component_errors_total`."
 			type:              "counter"
@@ -886,6 +1042,21 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _internal_metrics_tags
 		}
+		passthrough_requests_total: {
+			description:       "The total number of requests to intake endpoints not supported by the `datadog_agent` source that were passed through."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				mode: {
+					description: "How the request was passed through."
+					required:    true
+					enum: {
+						accept: "The request was acknowledged and its payload discarded."
+						proxy:  "The request was proxied to the upstream endpoint."
+					}
+				}
+			}
+		}
 		parse_errors_total: {
 			description:       "The total number of errors parsing metrics for this component."
 			type:              "counter"
@@ -980,6 +1151,24 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		sink_request_duration_seconds: {
+			description:       "The time taken by the sink's service to complete a single request, successful or not."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		sink_request_queue_depth: {
+			description:       "The number of events the sink has batched into requests but not yet dispatched, typically because it is at its concurrency limit."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		sink_requests_in_flight: {
+			description:       "The number of requests the sink has dispatched and not yet seen complete."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		splunk_pending_acks: {
 			description:       "The number of outstanding Splunk HEC indexer acknowledgement acks."
 			type:              "gauge"
@@ -1070,6 +1259,44 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		tenant_requests_throttled_total: {
+			description:       "The total number of export requests rejected because their tenant exceeded its rate limit."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		throttle_key_utilization: {
+			description: """
+				The number of events seen for the key in the last window of the throttle transform, divided by
+				its `threshold`. Only reported for the busiest keys, per `utilization_top_keys`.
+				"""
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				key: {
+					description: "The throttled key."
+					required:    true
+				}
+			}
+		}
+		throttle_unique_keys_total: {
+			description: """
+				The total number of unique keys seen by the throttle transform. Keys that aren't seen for two
+				windows are counted again when they reappear.
+				"""
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		throttle_untracked_keys_total: {
+			description: """
+				The total number of events whose key the throttle transform couldn't remember to count the
+				unique keys seen, because it already remembers as many keys as it can for the window.
+				"""
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		timestamp_parse_errors_total: {
 			description:       "The total number of errors encountered parsing [RFC 3339](\(urls.rfc_3339)) timestamps."
 			type:              "counter"
@@ -1111,6 +1338,15 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		websocket_messages_dropped_total: {
+			description: """
+				The total number of messages dropped by the `websocket` sink in server mode, because
+				a client didn't keep up with the events.
+				"""
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 
 		// Windows metrics
 		windows_service_does_not_exist_total: {
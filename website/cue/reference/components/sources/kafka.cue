@@ -180,6 +180,22 @@ components: sources: kafka: {
 			}
 		}
 		socket_timeout_ms: components._kafka.configuration.socket_timeout_ms
+		statistics_interval_ms: {
+			common:      false
+			description: "The interval at which `librdkafka` reports the statistics translated into internal metrics. Set to `0` to disable the statistics."
+			required:    false
+			type: uint: {
+				default: 1000
+				examples: [0, 5000]
+				unit: "milliseconds"
+			}
+		}
+		statistics_partition_tags: {
+			common:      false
+			description: "Whether or not to tag the `kafka_consumer_lag` internal metric with the partition. By default, the consumer lag is summed per topic, as tagging it with the partition produces one metric per partition."
+			required:    false
+			type: bool: default: false
+		}
 		topics: {
 			description: "The Kafka topics names to read events from. Regex is supported if the topic begins with `^`."
 			required:    true
@@ -248,6 +264,8 @@ components: sources: kafka: {
 		kafka_produced_messages_bytes_total:  components.sources.internal_metrics.output.metrics.kafka_produced_messages_bytes_total
 		kafka_consumed_messages_total:        components.sources.internal_metrics.output.metrics.kafka_consumed_messages_total
 		kafka_consumed_messages_bytes_total:  components.sources.internal_metrics.output.metrics.kafka_consumed_messages_bytes_total
+		kafka_consumer_lag:                   components.sources.internal_metrics.output.metrics.kafka_consumer_lag
+		kafka_request_latency_avg:            components.sources.internal_metrics.output.metrics.kafka_request_latency_avg
 		processed_bytes_total:                components.sources.internal_metrics.output.metrics.processed_bytes_total
 		processed_events_total:               components.sources.internal_metrics.output.metrics.processed_events_total
 		component_discarded_events_total:     components.sources.internal_metrics.output.metrics.component_discarded_events_total
@@ -7,7 +7,7 @@ components: sources: opentelemetry: {
 	title: "OpenTelemetry"
 
 	description: """
-		Collect OpenTelemetry data over gRPC and HTTP (currently, only logs are supported).
+		Collect OpenTelemetry data over gRPC and HTTP (currently, logs, traces and metrics are supported).
 		"""
 
 	classes: {
@@ -52,9 +52,276 @@ components: sources: opentelemetry: {
 
 	configuration: {
 		acknowledgements: configuration._source_acknowledgements
+		attributes_key: {
+			common: false
+			description: """
+				The name of the field holding the attributes of log records. Paths such as `otel.attributes`
+				relocate the attributes, keeping them apart from fields of events from other sources. When
+				empty, the attributes are merged into the root of the event instead, without replacing the
+				fields of the log record itself. Can't be combined with `field_mapping.attributes`.
+				"""
+			required: false
+			type: string: {
+				default: "attributes"
+				examples: ["otel.attributes", ""]
+			}
+		}
+		body_pattern: {
+			common: false
+			description: """
+				A regular expression with named capture groups, applied to log records with a string body.
+				When the body matches, each named capture is inserted into the event as a field of the same
+				name, replacing any existing field. Bodies that don't match are passed through unchanged.
+				"""
+			required: false
+			type: string: {
+				default: null
+				examples: ["^(?P<client>\\S+) \\S+ (?P<user>\\S+) \\[(?P<time>[^\\]]+)\\] \"(?P<method>\\S+) (?P<path>\\S+) \\S+\" (?P<status>\\d{3}) (?P<size>\\d+|-)$"]
+			}
+		}
+		bool_false_strings: {
+			common: false
+			description: """
+				The strings coerced to `false` when `coerce_bool_strings` is enabled. Matching is exact and
+				case-sensitive.
+				"""
+			required: false
+			type: array: {
+				default: ["false"]
+				items: type: string: examples: ["false", "no", "0"]
+			}
+		}
+		bool_true_strings: {
+			common: false
+			description: """
+				The strings coerced to `true` when `coerce_bool_strings` is enabled. Matching is exact and
+				case-sensitive, and a string can't be in both `bool_true_strings` and `bool_false_strings`.
+				"""
+			required: false
+			type: array: {
+				default: ["true"]
+				items: type: string: examples: ["true", "yes", "1"]
+			}
+		}
+		coerce_bool_strings: {
+			common: false
+			description: """
+				Coerces the string values of log record and resource attributes that match
+				`bool_true_strings` or `bool_false_strings` into booleans, including nested values.
+				Other strings are left unchanged.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		emit_exemplars: {
+			common: false
+			description: """
+				Emits the exemplars of the data points of OTLP metrics as log events, on the `exemplars`
+				output. Each event holds the name of the metric as `metric`, the value of the exemplar as
+				`value`, its hex-encoded `trace_id` and `span_id`, its filtered attributes as `attributes`,
+				and the tags of the metric event of its data point as `tags`, so that traces can be
+				correlated with metrics. Only relevant when `enable_metrics` is `true`.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		emit_resource: {
+			common: false
+			description: """
+				How the resource of log records is emitted.
+				"""
+			required: false
+			type: string: {
+				default: "inline"
+				enum: {
+					inline:    "The resource attributes are inserted into every log record as the `resources` field."
+					reference: """
+						Each distinct resource of an export request is emitted once, as an event on the
+						`resources` output with the resource attributes as the `resources` field and an ID
+						derived from them as the `resource_id` field. Log records contain only the
+						`resource_id` field of their resource, which saves memory when an export request
+						contains many records of the same resource.
+						"""
+				}
+			}
+		}
+		enable_metrics: {
+			common: false
+			description: """
+				Accepts OTLP metrics, in addition to log records and spans. Export requests of metrics are
+				accepted by the gRPC server, and on the `/v1/metrics` path of the HTTP server. Their gauges,
+				sums, histograms, exponential histograms and summaries are converted into metric events sent
+				to the `metrics` output, with the attributes of the resource, of the instrumentation scope and
+				of the data points as tags. Delta data points are incremental metrics, with the window since
+				their start time as interval, and cumulative ones absolute. Exemplars are dropped, unless
+				`emit_exemplars` is `true`.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		error_flag: {
+			common: false
+			description: """
+				Flags log records indicating an error with an `is_error` field set to `true`, so they can be
+				filtered on without interpreting OTLP severities. Records are flagged when their severity number is
+				at least `min_severity_number`, or when they carry any of `attributes`. The field is absent from
+				other records.
+				"""
+			required: false
+			type: object: {
+				examples: []
+				options: {
+					min_severity_number: {
+						common:      false
+						description: "The lowest severity number of the records flagged as errors. By default, records of the `ERROR` and `FATAL` severity levels are flagged."
+						required:    false
+						type: uint: default: 17
+					}
+					attributes: {
+						common:      false
+						description: "The names of the attributes flagging the records carrying them as errors. Attributes set to `false` don't flag records."
+						required:    false
+						type: array: {
+							default: ["exception.type"]
+							items: type: string: examples: ["exception.type", "error"]
+						}
+					}
+				}
+			}
+		}
+		error_output: {
+			common: false
+			description: """
+				Emits log records that fail conversion as error events on a dedicated `errors` output,
+				instead of dropping them. Error events contain the error message, the index of the
+				record within the export request, and the original record as base64-encoded protobuf bytes.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		field_mapping: {
+			common: false
+			description: """
+				Overrides the names of the fields log records are converted into. Fields without an override keep
+				their default name. The fields are renamed once log records are fully converted, so the other
+				options of the source, such as `partition_fields` or `body_pattern`, refer to the default names.
+				With the `Vector` log namespace, the fields aren't renamed, as the body becomes the event and the
+				other fields are placed in the source metadata under their default names.
+				"""
+			required: false
+			type: object: {
+				examples: []
+				options: {
+					message: {
+						common:      false
+						description: "The name of the field holding the body of log records. Defaults to the [global `log_schema.message_key` option](\(urls.vector_configuration)/global-options#log_schema.message_key)."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["body"]
+						}
+					}
+					timestamp: {
+						common:      false
+						description: "The name of the field holding the timestamp of log records. Defaults to the [global `log_schema.timestamp_key` option](\(urls.vector_configuration)/global-options#log_schema.timestamp_key)."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["time"]
+						}
+					}
+					observed_timestamp: {
+						common:      false
+						description: "The name of the field holding the observed timestamp of log records. Defaults to `observed_timestamp`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["observed_time"]
+						}
+					}
+					severity_number: {
+						common:      false
+						description: "The name of the field holding the severity number of log records. Defaults to `severity_number`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["severityNumber"]
+						}
+					}
+					severity_text: {
+						common:      false
+						description: "The name of the field holding the severity text of log records. Defaults to `severity_text`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["severityText"]
+						}
+					}
+					trace_id: {
+						common:      false
+						description: "The name of the field holding the hex encoded trace ID of log records. Defaults to `trace_id`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["traceId"]
+						}
+					}
+					span_id: {
+						common:      false
+						description: "The name of the field holding the hex encoded span ID of log records. Defaults to `span_id`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["spanId"]
+						}
+					}
+					flags: {
+						common:      false
+						description: "The name of the field holding the flags of log records. Defaults to `flags`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["trace_flags"]
+						}
+					}
+					dropped_attributes_count: {
+						common:      false
+						description: "The name of the field holding the number of dropped attributes of log records. Defaults to `dropped_attributes_count`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["droppedAttributesCount"]
+						}
+					}
+					attributes: {
+						common:      false
+						description: "The name of the field holding the attributes of log records. Defaults to `attributes`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["attrs"]
+						}
+					}
+					resources: {
+						common:      false
+						description: "The name of the field holding the attributes of the resource of log records. Defaults to `resources`."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["resource"]
+						}
+					}
+				}
+			}
+		}
 		grpc: {
-			description: "Configuration options for the gRPC server."
-			required:    true
+			common: true
+			description: """
+				Configuration options for the gRPC server. By default, the gRPC server listens on
+				`127.0.0.1:\(_grpc_port)`, the OTLP/gRPC port recommended by the OTLP specification and
+				registered with IANA.
+				"""
+			required: false
 			type: object: {
 				examples: [{address: "0.0.0.0:\(_grpc_port)"}]
 				options: {
@@ -67,16 +334,132 @@ components: sources: opentelemetry: {
 							examples: ["0.0.0.0:\(_grpc_port)"]
 						}
 					}
+					bind_retry_timeout_secs: {
+						common: false
+						description: """
+							The maximum amount of time to retry binding the address while it is in use, which lets
+							the source start while another instance releases the address, such as during a rolling
+							deploy. By default, the source fails right away.
+							"""
+						required: false
+						type: uint: {
+							default: null
+							unit:    "seconds"
+						}
+					}
+					http2_keepalive_interval_secs: {
+						common: false
+						description: """
+							The interval of the HTTP/2 pings sent to check that connections are alive. Connections of
+							clients that don't acknowledge pings in time, such as clients that went away without
+							closing them, are closed. By default, no pings are sent.
+							"""
+						required: false
+						type: uint: {
+							default: null
+							examples: [30]
+							unit: "seconds"
+						}
+					}
+					http2_keepalive_timeout_secs: {
+						common:      false
+						description: "The amount of time to wait for the acknowledgement of a ping before closing the connection. Only used with `http2_keepalive_interval_secs`."
+						required:    false
+						type: uint: {
+							default: 20
+							unit:    "seconds"
+						}
+					}
+					max_frame_size_bytes: {
+						common:      false
+						description: "The maximum size of the HTTP/2 frames received by the gRPC server. It must be between 16384 (16 KiB) and 16777215 (16 MiB). Larger frames let clients send large export requests in fewer frames."
+						required:    false
+						type: uint: {
+							default: 16384
+							unit:    "bytes"
+						}
+					}
+					max_concurrent_streams: {
+						common:      false
+						description: "The maximum number of concurrent requests of each connection, as HTTP/2 streams. By default, the number of concurrent requests isn't limited."
+						required:    false
+						type: uint: {
+							default: null
+							examples: [100]
+							unit: null
+						}
+					}
+					max_recv_message_size: {
+						common: false
+						description: """
+							The maximum size of a message received by the gRPC server, after decompression. Larger
+							messages, such as oversized export requests, are rejected with `RESOURCE_EXHAUSTED`
+							before they are buffered in full. By default, messages of any size are accepted.
+							"""
+						required: false
+						type: uint: {
+							default: null
+							examples: [4194304]
+							unit: "bytes"
+						}
+					}
+					client_auth: {
+						common: false
+						description: """
+							Requires the clients of the gRPC server to authenticate with a TLS client certificate.
+							TLS must be enabled with `tls`. Connections of clients without a certificate issued by
+							`ca_file`, or with a certificate revoked by `crl_file`, are rejected during the TLS
+							handshake and reported as component errors.
+							"""
+						required: false
+						type: object: {
+							examples: [{ca_file: "/etc/certs/clients-ca.crt"}]
+							options: {
+								ca_file: {
+									description: "Absolute path to the CA certificate file, in DER or PEM format, that client certificates must be issued by. The CAs of `tls.ca_file` are trusted as well."
+									required:    true
+									type: string: examples: ["/etc/certs/clients-ca.crt"]
+								}
+								crl_file: {
+									common:      false
+									description: "Absolute path to a certificate revocation list file, in PEM format. Clients presenting a certificate revoked by the list are rejected."
+									required:    false
+									type: string: {
+										default: null
+										examples: ["/etc/certs/clients.crl.pem"]
+									}
+								}
+							}
+						}
+					}
+					drain_connections_on_restart: {
+						common: false
+						description: """
+							Whether or not to wait for the in-flight requests of open connections to complete when
+							the source shuts down, which is the case when Vector stops and when the source is
+							restarted on a configuration reload. Otherwise, open connections are closed right away
+							and clients must retry their in-flight requests, which makes the source shut down faster
+							while its pipeline is backed up.
+							"""
+						required: false
+						type: bool: default: true
+					}
 					tls: configuration._tls_accept & {_args: {
-						can_verify_certificate: true
-						enabled_default:        false
+						can_add_client_metadata: true
+						can_verify_certificate:  true
+						enabled_default:         false
 					}}
 				}
 			}
 		}
 		http: {
-			description: "Configuration options for the HTTP server."
-			required:    true
+			common: true
+			description: """
+				Configuration options for the HTTP server. By default, the HTTP server listens on
+				`127.0.0.1:\(_http_port)`, the OTLP/HTTP port recommended by the OTLP specification and
+				registered with IANA.
+				"""
+			required: false
 			type: object: {
 				examples: [{address: "0.0.0.0:\(_http_port)"}]
 				options: {
@@ -89,13 +472,446 @@ components: sources: opentelemetry: {
 							examples: ["0.0.0.0:\(_http_port)"]
 						}
 					}
+					auth: {
+						common: false
+						description: """
+							Authentication of the requests to the HTTP server. Requests without the configured
+							credentials are rejected with `401 Unauthorized`. As the credentials are sent in plain
+							text, this should almost always be used along with TLS.
+							"""
+						required: false
+						type: object: options: {
+							strategy: {
+								description: "The authentication strategy."
+								required:    true
+								type: string: enum: {
+									bearer:  "Requests must carry the token in their `Authorization` header, as `Bearer <token>`."
+									api_key: "Requests must carry the key as the value of the `header` header."
+								}
+							}
+							header: {
+								description:   "The name of the header carrying the key."
+								required:      true
+								relevant_when: "strategy = \"api_key\""
+								type: string: examples: ["x-api-key"]
+							}
+							key: {
+								description:   "The key requests must carry."
+								required:      true
+								relevant_when: "strategy = \"api_key\""
+								type: string: examples: ["${OTEL_API_KEY}"]
+							}
+							token: {
+								description:   "The token requests must carry."
+								required:      true
+								relevant_when: "strategy = \"bearer\""
+								type: string: examples: ["${OTEL_TOKEN}"]
+							}
+						}
+					}
+					compression: {
+						common: false
+						description: """
+							The compression algorithms request bodies may be compressed with, as named by their
+							`Content-Encoding` header. Requests compressed with any other algorithm are rejected with
+							`415 Unsupported Media Type`. Uncompressed requests are always accepted. By default, only
+							GZIP, which the OTLP specification requires, is accepted.
+							"""
+						required: false
+						type: array: {
+							default: ["gzip"]
+							items: type: string: enum: {
+								gzip:    "GZIP."
+								deflate: "DEFLATE, in the zlib format."
+								snappy:  "Snappy, in the raw format."
+								zstd:    "ZSTD."
+							}
+						}
+					}
+					max_connections: {
+						common: false
+						description: """
+							The maximum number of connections open at once. Requests of connections opened while the
+							limit is reached are rejected with `503 Service Unavailable`, and the connections closed. By
+							default, the number of connections is unlimited.
+							"""
+						required: false
+						type: uint: {
+							default: null
+							examples: [1000]
+							unit: null
+						}
+					}
+					max_request_size_bytes: {
+						common: false
+						description: """
+							The maximum size of the body of requests. Requests with a larger body, either before or after
+							decompression, are rejected with `413 Payload Too Large`. By default, the size of requests is
+							unlimited.
+							"""
+						required: false
+						type: uint: {
+							default: null
+							examples: [4194304]
+							unit: "bytes"
+						}
+					}
+					success_response_body: {
+						common: false
+						description: """
+							The body of the responses to successful JSON export requests, sent with the
+							`application/json` content type. Can be overridden for compatibility with clients expecting
+							a different body, including an empty one. Protobuf export requests are always replied to
+							with an encoded export response, and log export requests with records that could not be
+							converted with a partial success, in the encoding of the request.
+							"""
+						required: false
+						type: string: {
+							default: "{}"
+							examples: ["{\"partialSuccess\":{}}", ""]
+						}
+					}
 					tls: configuration._tls_accept & {_args: {
-						can_verify_certificate: true
-						enabled_default:        false
+						can_add_client_metadata: true
+						can_verify_certificate:  true
+						enabled_default:         false
 					}}
 				}
 			}
 		}
+		idempotency_header: {
+			common: false
+			description: """
+				The name of the header holding the idempotency key of export requests. Requests with the key
+				of a request processed successfully within `idempotency_ttl_secs` are acknowledged without
+				emitting their log records again. For gRPC requests, this is the name of the metadata entry.
+				Requests without the header are always processed.
+				"""
+			required: false
+			type: string: {
+				default: null
+				examples: ["idempotency-key"]
+			}
+		}
+		idempotency_ttl_secs: {
+			common:      false
+			description: "How long the idempotency keys of processed requests are remembered."
+			required:    false
+			type: uint: {
+				default: 300
+				unit:    "seconds"
+			}
+		}
+		include_proto_version: {
+			common: false
+			description: """
+				Adds the OTLP version the log records were sent with as the `proto_version` field. The
+				version is taken from the `schema_url` of the records' resource, or otherwise from the
+				`User-Agent` header of the request.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		log_namespace: {
+			common: false
+			description: """
+				The namespace to use for logs, overriding the global `schema.log_namespace` setting. With
+				`true`, the body of log records becomes the event, and their other fields, such as
+				`attributes`, `resources`, and `severity_text`, are placed in the `opentelemetry` source
+				metadata, for example `%opentelemetry.attributes`. The `source_type` and ingest timestamp
+				are placed in the `vector` metadata.
+				"""
+			required: false
+			type: bool: default: null
+		}
+		metric_tag_from_attribute: {
+			common: false
+			description: """
+				The name of a resource attribute to tag the `component_received_records_total` internal metric
+				with. The tag has the same name as the attribute, and the attribute's value for the records of
+				each resource as its value, or `_none` for resources without the attribute. The attribute
+				should have a low cardinality, such as `service.name`.
+				"""
+			required: false
+			type: string: {
+				default: null
+				examples: ["service.name", "deployment.environment"]
+			}
+		}
+		metric_tag_max_values: {
+			common: false
+			description: """
+				The maximum number of distinct values of `metric_tag_from_attribute` to tag the metric with.
+				The records of resources with any other value are tagged with `_other`.
+				"""
+			required: false
+			type: uint: default: 100
+		}
+		namespace_outputs: {
+			common: false
+			description: """
+				Routes log records into dedicated outputs by the `service.namespace` attribute of their
+				resource. The log records of each listed namespace are sent to the `logs_<namespace>` output,
+				and those of other namespaces, or of resources without the attribute, to the `logs` output.
+				"""
+			required: false
+			type: object: {
+				examples: []
+				options: {
+					namespaces: {
+						description: "The namespaces whose log records are sent to a dedicated output."
+						required:    true
+						type: array: items: type: string: examples: ["payments", "search"]
+					}
+					max_outputs: {
+						common:      false
+						description: "The maximum number of namespaces, as a safety cap on the number of outputs of the source. Vector fails to start if `namespaces` has more."
+						required:    false
+						type: uint: default: 32
+					}
+				}
+			}
+		}
+		normalize_severity: {
+			common: false
+			description: """
+				Adds a `level` field to log records, holding the canonical OTLP severity level of their
+				`severity_number`: `TRACE` for 1 to 4, `DEBUG` for 5 to 8, `INFO` for 9 to 12, `WARN` for 13 to
+				16, `ERROR` for 17 to 20 and `FATAL` for 21 to 24. The `severity_number` and `severity_text`
+				fields are kept as they are, and the field is absent for records without a valid severity number.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		normalize_severity_text: {
+			common: false
+			description: """
+				Normalizes the `severity_text` of log records into the canonical uppercase OTLP severity labels,
+				such as `INFO` for `Info` or `info`. Texts that aren't a label regardless of case are replaced by
+				the label of the record's `severity_number`, and left unchanged if it has none.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		parse_json_body: {
+			common: false
+			description: """
+				Parses string bodies of log records holding a JSON object into structured fields. Bodies that
+				aren't a JSON object are passed through unchanged, and counted in `json_body_misses_total`.
+				Parsing happens before `structured_body` renders the message.
+				"""
+			required: false
+			type: object: {
+				examples: []
+				options: {
+					mode: {
+						common:      false
+						description: "Where the fields of a parsed JSON body are placed."
+						required:    false
+						type: string: {
+							default: "nest"
+							enum: {
+								nest:  "The message is replaced by the parsed object."
+								merge: "The fields of the parsed object are inserted into the root of the event, replacing any existing field, and the message is kept."
+							}
+						}
+					}
+				}
+			}
+		}
+		partition_fields: {
+			common: false
+			description: """
+				Adds `year`, `month`, `day` and `hour` fields to each log record, computed from its timestamp.
+				The timestamp is the record's `time_unix_nano`, or its `observed_time_unix_nano` if unset. The
+				fields are zero-padded strings, so that they can be used directly in the key prefix of object
+				store sinks to partition the records by date.
+				"""
+			required: false
+			type: bool: default: false
+		}
+		partition_timezone: {
+			common:      false
+			description: "The time zone of the fields added by `partition_fields`."
+			required:    false
+			type: string: {
+				default: "UTC"
+				examples: ["local", "America/New_York", "EST5EDT"]
+			}
+		}
+		repeat_window_ms: {
+			common: false
+			description: """
+				Collapses runs of consecutive log records with identical bodies into a single event. Records
+				of the same resource within an export request are collapsed while their timestamps are within
+				this many milliseconds of the first record of the run. Only the event of the first record is
+				emitted, with the number of records it stands for as the `repeat_count` field.
+				"""
+			required: false
+			type: uint: {
+				default: null
+				examples: [1000]
+				unit: "milliseconds"
+			}
+		}
+		resource_filter: {
+			common: false
+			description: """
+				Regular expressions that resource attributes must match for the log records of the resource
+				to be emitted, by attribute name. String, integer and boolean attributes are supported. The
+				log records of resources missing any of the attributes, or with a value that doesn't match
+				its pattern, are dropped.
+				"""
+			required: false
+			type: object: {
+				examples: [{"service.name": "^(checkout|payments)$"}]
+				options: {
+					"*": {
+						common:      false
+						description: "A regular expression the resource attribute must match."
+						required:    true
+						type: string: {}
+					}
+				}
+			}
+		}
+		resource_id_field: {
+			common: false
+			description: """
+				The name of a field to add to each log record, holding a digest of its resource attributes. The digest
+				is a hash of the attributes sorted by name, so log records of identical resources get the same value,
+				regardless of the order their attributes were sent in. The field is absent for log records without
+				resource attributes.
+				"""
+			required: false
+			type: string: {
+				default: null
+				examples: ["resource_id"]
+			}
+		}
+		resources_key: {
+			common: false
+			description: """
+				The name of the field holding the attributes of the resource of log records. Paths such as
+				`otel.resources` relocate the resource attributes, keeping them apart from fields of events
+				from other sources. When empty, the resource attributes are merged into the root of the
+				event instead, without replacing the fields of the log record itself. Can't be combined with
+				`field_mapping.resources`.
+				"""
+			required: false
+			type: string: {
+				default: "resources"
+				examples: ["otel.resources", ""]
+			}
+		}
+		structured_body: {
+			common: false
+			description: """
+				Renders the structured bodies of log records into a string message, so that sinks expecting a
+				string message can render them. The body of log records whose body is a map or an array is moved
+				to `body_key`, and the message is replaced by its rendering. String bodies are left untouched.
+				"""
+			required: false
+			type: object: {
+				examples: []
+				options: {
+					body_key: {
+						common:      false
+						description: "The name of the field the structured body is moved to."
+						required:    false
+						type: string: default: "body"
+					}
+					message_template: {
+						common:      false
+						description: "A template rendering the message of log records with a structured body. The template is rendered once the body is moved to `body_key`, so it refers to the body through that field. By default, or when the template fails to render, the message is the body encoded as JSON."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["{{ body.user }} {{ body.event }}"]
+							syntax: "template"
+						}
+					}
+				}
+			}
+		}
+		tenant_rate_limits: {
+			common: false
+			description: """
+				Per-tenant rate limits of the log records accepted by the source. Each tenant has an
+				independent token bucket, refilled at its rate and holding up to one second's worth of log
+				records. Export requests of a tenant over its quota are rejected with `429 Too Many Requests`
+				(HTTP) or `RESOURCE_EXHAUSTED` (gRPC), without affecting other tenants.
+				"""
+			required: false
+			type: object: {
+				examples: []
+				options: {
+					header: {
+						description: "The name of the header identifying the tenant of an export request. For gRPC requests, this is the name of the metadata entry."
+						required:    true
+						type: string: examples: ["X-Scope-OrgID"]
+					}
+					limits: {
+						common:      true
+						description: "The maximum number of log records per second accepted from each tenant, keyed by the value of `header`."
+						required:    false
+						type: object: {
+							examples: [{"tenant-a": 1000, "tenant-b": 5000}]
+							options: {}
+						}
+					}
+					default_limit: {
+						common:      false
+						description: "The maximum number of log records per second accepted from each tenant not listed in `limits`. Requests without the header count as a single tenant. By default, these tenants are not limited."
+						required:    false
+						type: uint: {
+							default: null
+							examples: [100]
+							unit: "events"
+						}
+					}
+				}
+			}
+		}
+		enrichment_table: {
+			common: false
+			description: """
+				A static table of fields added to log records, keyed by the value of a resource attribute.
+				Each log record of a resource whose attribute value has an entry in the table is enriched
+				with the fields of the entry, such as the team or owner of a `service.name`. Fields already
+				present in the log record are left as they are, and the log records of resources without an
+				entry aren't enriched.
+				"""
+			required: false
+			type: object: {
+				examples: []
+				options: {
+					attribute: {
+						description: "The name of the resource attribute whose value is looked up in the table."
+						required:    true
+						type: string: examples: ["service.name"]
+					}
+					entries: {
+						common:      true
+						description: "The entries of the table, keyed by attribute value. Each entry maps the names of the fields added to log records to their values. Exactly one of `entries` and `path` must be set."
+						required:    false
+						type: object: {
+							examples: [{"checkout": {"team": "payments", "owner": "alice"}}]
+							options: {}
+						}
+					}
+					path: {
+						common:      false
+						description: "The path of a JSON file holding the entries of the table, in the same shape as `entries`. The file is read when the source is built."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["/etc/vector/services.json"]
+						}
+					}
+				}
+			}
+		}
 	}
 
 	outputs: [
@@ -105,6 +921,42 @@ components: sources: opentelemetry: {
 				Received log events will go to this output stream. Use `<component_id>.logs` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "traces"
+			description: """
+				Received trace spans will go to this output stream. Use `<component_id>.traces` as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "metrics"
+			description: """
+				Received metrics will go to this output stream when `enable_metrics` is `true`. Use `<component_id>.metrics` as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "exemplars"
+			description: """
+				Exemplars of the received metrics will go to this output stream when `enable_metrics` and `emit_exemplars` are `true`. Use `<component_id>.exemplars` as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "errors"
+			description: """
+				Log records that fail conversion will go to this output stream when `error_output` is enabled. Use `<component_id>.errors` as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "resources"
+			description: """
+				Resources of the received log records will go to this output stream when `emit_resource` is set to `reference`. Use `<component_id>.resources` as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "logs_<namespace>"
+			description: """
+				Received log records of the resources in each namespace of `namespace_outputs` will go to this output stream. Use `<component_id>.logs_<namespace>` as an input to downstream transforms and sinks.
+				"""
+		},
 	]
 
 	output: {
@@ -127,8 +979,79 @@ components: sources: opentelemetry: {
 						examples: [{"k1": "v1"}]
 					}
 				}
+				scope: {
+					description: """
+						The instrumentation scope that produced the log record, with its `name`, `version` and
+						`attributes`, and the `schema_url` of the scope's semantic conventions. Only the fields
+						that are set are included, and the field is absent for records without a scope.
+						"""
+					required: false
+					common:   false
+					type: object: {
+						examples: [{"name": "io.opentelemetry.example", "version": "1.2.3", "attributes": {"library.language": "java"}, "schema_url": "https://opentelemetry.io/schemas/1.9.0"}]
+					}
+				}
+				year: {
+					description: "The year of the log record's timestamp, when `partition_fields` is enabled."
+					required:    false
+					common:      false
+					type: string: {
+						examples: ["2022"]
+					}
+				}
+				month: {
+					description: "The month of the log record's timestamp, from `01` to `12`, when `partition_fields` is enabled."
+					required:    false
+					common:      false
+					type: string: {
+						examples: ["08"]
+					}
+				}
+				day: {
+					description: "The day of the month of the log record's timestamp, from `01` to `31`, when `partition_fields` is enabled."
+					required:    false
+					common:      false
+					type: string: {
+						examples: ["05"]
+					}
+				}
+				hour: {
+					description: "The hour of the log record's timestamp, from `00` to `23`, when `partition_fields` is enabled."
+					required:    false
+					common:      false
+					type: string: {
+						examples: ["09"]
+					}
+				}
+				proto_version: {
+					description: "The OTLP version the log record was sent with, when `include_proto_version` is enabled and the version is known."
+					required:    false
+					common:      false
+					type: string: {
+						examples: ["v0.20.0"]
+					}
+				}
+				repeat_count: {
+					description: "The number of consecutive log records with the same body that the event stands for, when `repeat_window_ms` is set and the record was repeated."
+					required:    false
+					common:      false
+					type: uint: {
+						default: null
+						examples: [5]
+						unit: null
+					}
+				}
+				resource_id: {
+					description: "The ID of the resource of the log record, when `emit_resource` is set to `reference`."
+					required:    false
+					common:      false
+					type: string: {
+						default: null
+						examples: ["5b1d6e0c8a3f4e21"]
+					}
+				}
 				message: {
-					description: "Contains the body of the log record."
+					description: "Contains the body of the log record. Map and array bodies are converted into objects and arrays, and bytes bodies into base64 encoded strings."
 					required:    false
 					common:      true
 					type: string: {
@@ -207,17 +1130,182 @@ components: sources: opentelemetry: {
 		}
 	}
 
+	output: traces: event: {
+		description: "An individual span from a batch of spans received through a gRPC or HTTP request sent by OpenTelemetry SDK"
+		fields: {
+			trace_id: {
+				description: "The hex-encoded ID of the trace the span belongs to."
+				required:    true
+				type: string: {
+					examples: ["66346462666332643762373238376133"]
+				}
+			}
+			span_id: {
+				description: "The hex-encoded ID of the span."
+				required:    true
+				type: string: {
+					examples: ["3763656366613637"]
+				}
+			}
+			parent_span_id: {
+				description: "The hex-encoded ID of the span's parent, absent for root spans."
+				required:    false
+				common:      true
+				type: string: {
+					examples: ["3431363336356134"]
+				}
+			}
+			trace_state: {
+				description: "The W3C trace state of the span."
+				required:    false
+				common:      false
+				type: string: {
+					examples: ["vendor=value"]
+				}
+			}
+			name: {
+				description: "The name of the span."
+				required:    true
+				type: string: {
+					examples: ["GET /index"]
+				}
+			}
+			kind: {
+				description: "The numeric span kind, for example `2` for a server span."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+			start_time_unix_nano: {
+				description: "The time the span started."
+				required:    true
+				type: timestamp: {}
+			}
+			end_time_unix_nano: {
+				description: "The time the span ended."
+				required:    true
+				type: timestamp: {}
+			}
+			status: {
+				description: "The status of the span, with its numeric `code` and `message`."
+				required:    false
+				common:      true
+				type: object: {
+					examples: [{"code": 1, "message": "OK"}]
+				}
+			}
+			attributes: {
+				description: "Attributes that describe the span."
+				required:    false
+				common:      true
+				type: object: {
+					examples: [{"k1": "v1"}]
+				}
+			}
+			resources: {
+				description: "Set of attributes that describe the resource."
+				required:    false
+				common:      true
+				type: object: {
+					examples: [{"k1": "v1"}]
+				}
+			}
+			scope: {
+				description: "The instrumentation scope that produced the span, as for log events."
+				required:    false
+				common:      false
+				type: object: {
+					examples: [{"name": "io.opentelemetry.example", "version": "1.2.3"}]
+				}
+			}
+			events: {
+				description: "Timestamped events recorded during the span, each with a `name`, `timestamp`, `attributes` and `dropped_attributes_count`."
+				required:    false
+				common:      true
+				type: array: items: type: object: {}
+			}
+			links: {
+				description: "Links to other spans, each with a `trace_id`, `span_id`, `trace_state`, `attributes` and `dropped_attributes_count`."
+				required:    false
+				common:      false
+				type: array: items: type: object: {}
+			}
+			dropped_attributes_count: {
+				description: "Counts for attributes dropped due to collection limits."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+			dropped_events_count: {
+				description: "Counts for span events dropped due to collection limits."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+			dropped_links_count: {
+				description: "Counts for span links dropped due to collection limits."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+		}
+	}
+
 	telemetry: metrics: {
-		component_discarded_events_total:     components.sources.internal_metrics.output.metrics.component_discarded_events_total
-		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
-		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
-		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
-		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
-		events_in_total:                      components.sources.internal_metrics.output.metrics.events_in_total
-		protobuf_decode_errors_total:         components.sources.internal_metrics.output.metrics.protobuf_decode_errors_total
+		body_pattern_misses_total:              components.sources.internal_metrics.output.metrics.body_pattern_misses_total
+		component_discarded_events_total:       components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		component_errors_total:                 components.sources.internal_metrics.output.metrics.component_errors_total
+		component_events_failed_total:          components.sources.internal_metrics.output.metrics.component_events_failed_total
+		component_output_event_bytes_histogram: components.sources.internal_metrics.output.metrics.component_output_event_bytes_histogram
+		component_received_bytes_total:         components.sources.internal_metrics.output.metrics.component_received_bytes_total
+		component_received_events_total:        components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total:   components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+		component_received_records_total:       components.sources.internal_metrics.output.metrics.component_received_records_total
+		duplicate_requests_total:               components.sources.internal_metrics.output.metrics.duplicate_requests_total
+		events_in_total:                        components.sources.internal_metrics.output.metrics.events_in_total
+		filtered_records_total:                 components.sources.internal_metrics.output.metrics.filtered_records_total
+		json_body_misses_total:                 components.sources.internal_metrics.output.metrics.json_body_misses_total
+		protobuf_decode_errors_total:           components.sources.internal_metrics.output.metrics.protobuf_decode_errors_total
+		tenant_requests_throttled_total:        components.sources.internal_metrics.output.metrics.tenant_requests_throttled_total
 	}
 
 	how_it_works: {
+		http_encodings: {
+			title: "OTLP/HTTP encodings"
+			body:  """
+				  The HTTP server accepts log export requests encoded as either Protobuf, with the
+				  `application/x-protobuf` content type, or JSON, with the `application/json` content type.
+				  JSON requests follow the [OTLP JSON encoding](\(urls.opentelemetry_otlp_http_json)): field
+				  names are in lowerCamelCase, and trace and span IDs are hex encoded. Errors are replied to
+				  with a `Status` message in the encoding of the request, and partial successes are replied
+				  to in the encoding of the request as well. Trace and metric export requests must be
+				  encoded as Protobuf.
+				  """
+		}
+		partial_success: {
+			title: "Partial success"
+			body:  """
+				  Log export requests received by either server are replied to with a partial success when
+				  some of their records could not be converted into events or, with acknowledgements
+				  enabled, were not delivered to their sinks. The reply reports the number of rejected
+				  records and why they were rejected, so that clients don't assume every record was
+				  accepted. The records that could be converted and delivered are still accepted.
+				  """
+		}
+		compression: {
+			title: "Compression"
+			body:  """
+				  Compressed export requests are decompressed according to what the client declares. The
+				  gRPC server accepts gzip compressed messages, and the HTTP server accepts bodies compressed
+				  with the `gzip` algorithm named by the `Content-Encoding` header, or with the `deflate`,
+				  `snappy` or `zstd` ones once enabled with `http.compression`. HTTP requests whose bodies fail to decompress are rejected with a `400` status, and
+				  gRPC ones with an error status, without emitting any event.
+				  """
+		}
 		tls: {
 			title: "Transport Layer Security (TLS)"
 			body:  """
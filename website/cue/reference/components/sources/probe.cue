@@ -0,0 +1,145 @@
+package metadata
+
+components: sources: probe: {
+	title: "Probe"
+
+	description: """
+		Probes the availability of hosts, TCP services, and HTTP endpoints, and generates metrics
+		describing whether they're reachable and how long they take to respond.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["daemon", "sidecar", "aggregator"]
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: false
+		multiline: enabled: false
+		generate: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: {
+		interval_secs: {
+			description: "The interval between probes of each target. The first probe of each target happens at a random offset within the interval, so that the targets aren't all probed at once."
+			common:      true
+			required:    false
+			type: uint: {
+				default: 15
+				unit:    "seconds"
+			}
+		}
+		targets: {
+			description: "The targets to probe."
+			required:    true
+			type: array: items: type: object: options: {
+				type: {
+					description: "The type of probe."
+					required:    true
+					type: string: enum: {
+						icmp: "Sends an ICMP echo request, succeeding when the host replies."
+						tcp:  "Opens a TCP connection, succeeding once it's established."
+						http: "Sends an HTTP `GET` request, succeeding when the response has a `2xx` status code."
+					}
+				}
+				host: {
+					description:   "The hostname or IP address of the host."
+					relevant_when: "type = \"icmp\""
+					required:      true
+					type: string: examples: ["10.0.0.1", "example.com"]
+				}
+				address: {
+					description:   "The address to connect to, as `host:port`."
+					relevant_when: "type = \"tcp\""
+					required:      true
+					type: string: examples: ["postgres:5432"]
+				}
+				url: {
+					description:   "The URL to request."
+					relevant_when: "type = \"http\""
+					required:      true
+					type: string: examples: ["https://example.com/health"]
+				}
+			}
+		}
+		timeout_secs: {
+			description: "The time to wait for a probe to succeed before considering it failed."
+			common:      false
+			required:    false
+			type: uint: {
+				default: 5
+				unit:    "seconds"
+			}
+		}
+		tls: configuration._tls_connect & {_args: {
+			can_verify_certificate: true
+			can_verify_hostname:    true
+			enabled_default:        false
+		}}
+	}
+
+	how_it_works: {
+		icmp_permissions: {
+			title: "ICMP permissions"
+			body:  """
+				ICMP probes use raw sockets when Vector has the `CAP_NET_RAW` capability, and fall back
+				to unprivileged ICMP sockets otherwise. On Linux, unprivileged ICMP sockets are only
+				available to the groups in the `net.ipv4.ping_group_range` sysctl. Vector fails to
+				start if neither is permitted.
+				"""
+		}
+	}
+
+	output: metrics: {
+		_probe_tags: {
+			target: {
+				description: "The probed host, address, or URL."
+				required:    true
+				examples: ["postgres:5432"]
+			}
+			probe: {
+				description: "The type of probe."
+				required:    true
+				examples: ["icmp", "tcp", "http"]
+			}
+		}
+
+		success: {
+			description:       "Whether the probe succeeded, as `1`, or failed, as `0`."
+			type:              "gauge"
+			default_namespace: "probe"
+			tags:              _probe_tags
+		}
+		duration_seconds: {
+			description:       "The time the probe took to succeed or fail."
+			type:              "gauge"
+			default_namespace: "probe"
+			tags:              _probe_tags
+		}
+		http_status_code: {
+			description:       "The status code of the response to HTTP probes."
+			type:              "gauge"
+			default_namespace: "probe"
+			tags:              _probe_tags
+		}
+	}
+
+	telemetry: metrics: {
+		events_in_total:                 components.sources.internal_metrics.output.metrics.events_in_total
+		component_received_events_total: components.sources.internal_metrics.output.metrics.component_received_events_total
+	}
+}
@@ -56,13 +56,24 @@ components: sources: statsd: {
 
 	configuration: {
 		address: {
-			description:   "The address to listen for connections on, or `systemd#N` to use the Nth socket passed by systemd socket activation. If an address is used it _must_ include a port."
+			description:   "The address to listen for connections on, or `systemd#N` to use the Nth socket passed by systemd socket activation. If an address is used it _must_ include a port. The address can be a hostname, resolved when binding to the first of its addresses that can be bound to, and `systemd#N` is only supported in `tcp` mode."
 			relevant_when: "mode = `tcp` or `udp`"
 			required:      true
 			type: string: {
-				examples: ["0.0.0.0:\(_port)", "systemd", "systemd#3"]
+				examples: ["0.0.0.0:\(_port)", "[::]:\(_port)", "localhost:\(_port)", "systemd", "systemd#3"]
 			}
 		}
+		v6_only: {
+			common:        false
+			description:   """
+				Whether an IPv6 listening socket only accepts IPv6 traffic, by setting `IPV6_V6ONLY`. When
+				disabled, a socket listening on `[::]` also accepts IPv4 traffic, allowing a single dual-stack
+				listener where the platform supports it. By default, the platform's default is used.
+				"""
+			relevant_when: "mode = `tcp` or `udp`"
+			required:      false
+			type: bool: default: null
+		}
 		mode: {
 			description: "The type of socket to use."
 			required:    true
@@ -50,13 +50,24 @@ components: sources: syslog: {
 
 	configuration: {
 		address: {
-			description:   "The address to listen for connections on, or `systemd#N` to use the Nth socket passed by systemd socket activation. If an address is used it _must_ include a port."
+			description:   "The address to listen for connections on, or `systemd#N` to use the Nth socket passed by systemd socket activation. If an address is used it _must_ include a port. The address can be a hostname, resolved when binding to the first of its addresses that can be bound to, and `systemd#N` is only supported in `tcp` mode."
 			relevant_when: "mode = `tcp` or `udp`"
 			required:      true
 			type: string: {
-				examples: ["0.0.0.0:\(_port)", "systemd", "systemd#3"]
+				examples: ["0.0.0.0:\(_port)", "[::]:\(_port)", "localhost:\(_port)", "systemd", "systemd#3"]
 			}
 		}
+		v6_only: {
+			common:        false
+			description:   """
+				Whether an IPv6 listening socket only accepts IPv6 traffic, by setting `IPV6_V6ONLY`. When
+				disabled, a socket listening on `[::]` also accepts IPv4 traffic, allowing a single dual-stack
+				listener where the platform supports it. By default, the platform's default is used.
+				"""
+			relevant_when: "mode = `tcp` or `udp`"
+			required:      false
+			type: bool: default: null
+		}
 		host_key: {
 			category:    "Context"
 			common:      false
@@ -108,6 +119,16 @@ components: sources: syslog: {
 				unit:    "concurrency"
 			}
 		}
+		max_events_per_second: {
+			common:        false
+			description:   "The maximum number of events per second that will be read from a single connection. Once a connection exceeds this rate by more than a second's worth of events, reads from it are paused until it's back within the rate, without affecting other connections."
+			relevant_when: "mode = `tcp`"
+			required:      false
+			type: uint: {
+				default: null
+				unit:    "events"
+			}
+		}
 	}
 
 	output: logs: line: {
@@ -268,6 +289,8 @@ components: sources: syslog: {
 	telemetry: metrics: {
 		events_in_total:                 components.sources.internal_metrics.output.metrics.events_in_total
 		connection_read_errors_total:    components.sources.internal_metrics.output.metrics.connection_read_errors_total
+		connection_throttled_total:      components.sources.internal_metrics.output.metrics.connection_throttled_total
+		open_connections:                components.sources.internal_metrics.output.metrics.open_connections
 		processed_bytes_total:           components.sources.internal_metrics.output.metrics.processed_bytes_total
 		processed_events_total:          components.sources.internal_metrics.output.metrics.processed_events_total
 		component_received_bytes_total:  components.sources.internal_metrics.output.metrics.component_received_bytes_total
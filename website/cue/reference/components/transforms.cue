@@ -10,6 +10,36 @@ components: transforms: [Name=string]: {
 
 	kind: "transform"
 
+	configuration: {
+		dropped_event_samples: {
+			common:      false
+			description: "Retains the most recent events dropped by this component, so they can be inspected through the `componentErrors` field of the GraphQL API or with `vector tap --errors-of`."
+			required:    false
+			type: object: {
+				examples: []
+				options: {
+					size: {
+						description: "The number of dropped events retained."
+						required:    true
+						type: uint: {
+							examples: [10]
+							unit: "events"
+						}
+					}
+					redact_fields: {
+						common:      false
+						description: "The fields whose values are replaced by `[REDACTED]` in the retained events."
+						required:    false
+						type: array: {
+							default: []
+							items: type: string: examples: ["password", "user.token"]
+						}
+					}
+				}
+			}
+		}
+	}
+
 	telemetry: metrics: {
 		events_in_total:                      components.sources.internal_metrics.output.metrics.events_in_total
 		events_out_total:                     components.sources.internal_metrics.output.metrics.events_out_total
@@ -0,0 +1,148 @@
+package metadata
+
+components: transforms: enrich: {
+	title: "Enrich"
+
+	description: """
+		Enriches log events with the columns of the matching row of an enrichment table, without writing
+		VRL. The columns of the configuration are validated against the loaded table when Vector starts.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		enrich: {
+			from: service: {
+				name:     "Enrichment tables"
+				url:      urls.enrichment_tables_concept
+				versions: null
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: {
+		table: {
+			description: "The name of the enrichment table to look up rows in."
+			required:    true
+			type: string: {
+				examples: ["users"]
+			}
+		}
+		key: {
+			description: """
+				The columns of the table to match, mapped to the paths of the event fields holding their
+				values. Rows match when all of their columns are equal to the values of the fields. Events
+				missing any of the fields don't match any row.
+				"""
+			required: true
+			type: object: {
+				examples: [{"id": "user_id"}]
+				options: {
+					"*": {
+						description: "The path of the event field holding the value of the column."
+						required:    true
+						type: string: {}
+					}
+				}
+			}
+		}
+		target: {
+			common:      true
+			description: "The path of the field to insert the columns of the matched row into, as an object. By default, the columns are inserted at the root of the event."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["user"]
+			}
+		}
+		select: {
+			common:      true
+			description: "The columns of the matched row to insert. By default, all of its columns are inserted."
+			required:    false
+			type: array: {
+				default: null
+				items: type: string: {
+					examples: ["name", "team"]
+				}
+			}
+		}
+		on_miss: {
+			common:      true
+			description: "What to do with events that don't match any row of the table."
+			required:    false
+			type: string: {
+				default: "ignore"
+				enum: {
+					ignore: "Pass the event through unchanged."
+					drop:   "Drop the event."
+					route:  "Send the event, unchanged, to the `missed` output."
+				}
+			}
+		}
+		on_multiple: {
+			common:      false
+			description: "What to do when an event matches several rows of the table."
+			required:    false
+			type: string: {
+				default: "error"
+				enum: {
+					error: "Report an error, and handle the event as if it didn't match any row."
+					first: "Enrich the event with the first matching row."
+				}
+			}
+		}
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	outputs: [
+		{
+			name:        "missed"
+			description: "Events that don't match any row of the table, when `on_miss` is `route`. Use `<transform_name>.missed` as an input to downstream transforms and sinks."
+		},
+	]
+
+	examples: [
+		{
+			title: "Enrich logs with user details"
+			configuration: {
+				table:  "users"
+				key: id: "user_id"
+				target: "user"
+				select: ["name", "team"]
+			}
+			input: log: {
+				user_id: "2"
+				message: "Logged in"
+			}
+			output: log: {
+				user_id: "2"
+				message: "Logged in"
+				user: {
+					name: "bob"
+					team: "core"
+				}
+			}
+		},
+	]
+
+	telemetry: metrics: {
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		component_errors_total:           components.sources.internal_metrics.output.metrics.component_errors_total
+	}
+}
@@ -204,6 +204,11 @@ components: transforms: "remap": {
 				  "message": 123
 				}
 				```
+
+				The emitted events share the finalizers of the original event, so it's acknowledged once
+				all of them are. Assigning an empty array drops the event. Arrays nested in the array, as
+				well as arrays assigned to metric or trace events, are runtime errors, and the original
+				event is handled according to `drop_on_error` and `reroute_dropped`.
 				"""#
 		}
 	}
@@ -49,14 +49,73 @@ components: transforms: sample: {
 			type: condition: {}
 		}
 		rate: {
+			common: true
 			description: """
 				The rate at which events will be forwarded, expressed as 1/N. For example,
 				`rate = 10` means 1 out of every 10 events will be forwarded and the rest will be dropped.
+				Either `rate` or `rate_limit` must be set.
 				"""
-			required: true
+			required: false
 			type: uint: {
+				default: null
 				examples: [10]
-				unit: null
+				unit:    null
+			}
+		}
+		rate_limit: {
+			common: false
+			description: """
+				Samples at most a fixed number of events per interval, instead of a fixed ratio of them.
+				The events of each interval are held back until its end, and `events` of them are chosen
+				uniformly, regardless of the input rate. The chosen events are forwarded in their original
+				order, with the ratio of the events seen to the events forwarded in the interval as their
+				`sample_rate` field.
+				"""
+			required: false
+			type: object: options: {
+				events: {
+					description: "The maximum number of events forwarded per interval, for each group."
+					required:    true
+					type: uint: {
+						examples: [100]
+						unit: "events"
+					}
+				}
+				interval_secs: {
+					common:      false
+					description: "The length of the intervals, in seconds."
+					required:    false
+					type: float: {
+						default: 60.0
+						unit:    "seconds"
+					}
+				}
+				group_by: {
+					common: false
+					description: """
+						A [template string](/docs/reference/configuration/template-syntax/) rendering the group
+						of events, each of which has its own budget of `events` per interval. Events for which
+						the template fails to render are grouped together.
+						"""
+					required: false
+					type: string: {
+						default: null
+						examples: ["{{ service }}"]
+						syntax: "template"
+					}
+				}
+				max_groups: {
+					common: false
+					description: """
+						The maximum number of groups per interval. Once reached, the events of any further group
+						share the budget of a single `_other` group for the rest of the interval.
+						"""
+					required: false
+					type: uint: {
+						default: 1000
+						unit:    null
+					}
+				}
 			}
 		}
 	}
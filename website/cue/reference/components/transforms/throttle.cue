@@ -29,6 +29,8 @@ components: transforms: throttle: {
 			common: true
 			description: """
 				The set of logical conditions to exclude events from rate limiting.
+
+				Excluded events are always passed through, and don't count towards the `threshold` of their key.
 				"""
 			required: false
 			type: condition: {}
@@ -62,6 +64,25 @@ components: transforms: throttle: {
 				unit: null
 			}
 		}
+		utilization_top_keys: {
+			common: false
+			description: """
+				The number of keys to report the utilization of.
+
+				When set, the utilization of the busiest keys, being the number of events seen for a key in the last
+				window divided by `threshold`, is reported every `window_secs` as the `throttle_key_utilization`
+				gauge, and the keys seen are counted by the `throttle_unique_keys_total` counter. The busiest keys
+				are estimated with a bounded amount of memory, so the reported utilization of keys seen rarely may
+				be overestimated. Up to 10000 keys are remembered per window to count the unique keys, and the
+				events with other keys are counted by the `throttle_untracked_keys_total` counter instead.
+				"""
+			required: false
+			type: uint: {
+				default: null
+				examples: [10]
+				unit: null
+			}
+		}
 		window_secs: {
 			description: """
 				The time frame in which the configured `threshold` is applied.
@@ -81,7 +102,10 @@ components: transforms: throttle: {
 	}
 
 	telemetry: metrics: {
-		events_discarded_total: components.sources.internal_metrics.output.metrics.events_discarded_total
+		events_discarded_total:        components.sources.internal_metrics.output.metrics.events_discarded_total
+		throttle_key_utilization:      components.sources.internal_metrics.output.metrics.throttle_key_utilization
+		throttle_unique_keys_total:    components.sources.internal_metrics.output.metrics.throttle_unique_keys_total
+		throttle_untracked_keys_total: components.sources.internal_metrics.output.metrics.throttle_untracked_keys_total
 	}
 
 	examples: [
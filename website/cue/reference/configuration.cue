@@ -42,6 +42,7 @@ configuration: {
 			description: """
 				If set, Vector will configure the internal metrics system to automatically
 				remove all metrics that have not been updated in the given number of seconds.
+				The `build_info`, `component_info`, and `config_hash_info` gauges are never removed.
 				"""
 			required: false
 			type: uint: {
@@ -198,6 +199,58 @@ configuration: {
 								examples: ["de", "en", "es", "fr", "ja", "pt-BR", "ru", "zh-CN"]
 							}
 						}
+						download: {
+							description: """
+								Downloads the database to `path` when Vector starts, and then periodically
+								refreshes it. The refreshed database replaces the database in use without a
+								restart. If a download fails, Vector keeps using the database downloaded last.
+
+								Proxy settings are taken from the global `proxy` option.
+								"""
+							required: false
+							common:   false
+							type: object: options: {
+								url: {
+									description: "The URL of the database file, in the MaxMind DB file format."
+									required:    true
+									type: string: {
+										examples: ["https://example.com/GeoLite2-City.mmdb"]
+									}
+								}
+								sha256_url: {
+									description: """
+										The URL of a file containing the hex encoded SHA-256 digest of the database
+										file, in the format output by `sha256sum`. When set, a downloaded database is
+										only used if its digest matches.
+										"""
+									required: false
+									common:   true
+									type: string: {
+										default: null
+										examples: ["https://example.com/GeoLite2-City.mmdb.sha256"]
+									}
+								}
+								interval_secs: {
+									description: "The interval between downloads of the database."
+									required:    false
+									common:      false
+									type: uint: {
+										default: 86400
+										unit:    "seconds"
+									}
+								}
+								tls: {
+									description: """
+										TLS options used when downloading the database, such as `ca_file`,
+										`verify_certificate` and `verify_hostname`. These are the same options as
+										the `tls` option of HTTP-based sinks.
+										"""
+									required: false
+									common:   false
+									type: object: options: {}
+								}
+							}
+						}
 					}
 				}
 			}
@@ -378,6 +431,65 @@ configuration: {
 			}
 		}
 
+		telemetry: {
+			common:      false
+			description: "Configures the internal metrics of Vector."
+			required:    false
+			type: object: options: {
+				histogram_buckets: {
+					common: false
+					description: """
+						The upper limits of the buckets of internal histograms, replacing the default powers
+						of two. A bucket for values larger than the last limit is always added. Only applies
+						to histograms registered after the configuration is loaded.
+						"""
+					required: false
+					type: array: {
+						default: null
+						items: type: float: examples: [0.005, 0.01, 0.05, 0.1, 0.5, 1.0]
+					}
+				}
+				histogram_buckets_by_name: {
+					common:      false
+					description: "The upper limits of the buckets of specific internal histograms, by metric name. These take precedence over `histogram_buckets`."
+					required:    false
+					type: object: {
+						examples: [{"http_client_rtt_seconds": [0.01, 0.1, 1.0]}]
+						options: {
+							"*": {
+								common:      false
+								description: "The upper limits of the buckets of the histogram."
+								required:    true
+								type: array: items: type: float: examples: [0.01, 0.1, 1.0]
+							}
+						}
+					}
+				}
+				summary_histograms: {
+					common: false
+					description: """
+						The names of the internal histograms emitted as summaries of the `summary_quantiles`,
+						estimated from their buckets, instead of the buckets themselves. Useful for
+						destinations such as StatsD or Datadog.
+						"""
+					required: false
+					type: array: {
+						default: []
+						items: type: string: examples: ["http_client_rtt_seconds"]
+					}
+				}
+				summary_quantiles: {
+					common:      false
+					description: "The quantiles computed for the histograms of `summary_histograms`."
+					required:    false
+					type: array: {
+						default: [0.5, 0.9, 0.99]
+						items: type: float: examples: [0.5, 0.9, 0.99]
+					}
+				}
+			}
+		}
+
 		timezone: {
 			common:      false
 			description: """
@@ -0,0 +1,10 @@
+package metadata
+
+services: victorialogs: {
+	name:     "VictoriaLogs"
+	thing:    "a \(name) database"
+	url:      urls.victorialogs
+	versions: null
+
+	description: "[VictoriaLogs](\(urls.victorialogs)) is an open source, resource-efficient log database from VictoriaMetrics. It groups logs into streams identified by a set of stream fields and supports full-text search over the remaining fields."
+}
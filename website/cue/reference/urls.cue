@@ -223,6 +223,7 @@ urls: {
 	graphql:                                    "https://graphql.org"
 	graphql_playground:                         "\(github)/graphql/graphql-playground"
 	graphviz:                                   "https://graphviz.org/"
+	graylog_gelf:                               "https://go2docs.graylog.org/5-0/getting_in_log_data/gelf.html"
 	grok:                                       "https://grokdebug.herokuapp.com/"
 	grok_debugger:                              "https://grokdebug.herokuapp.com/"
 	grok_patterns:                              "\(github)/daschl/grok/tree/master/patterns"
@@ -363,6 +364,7 @@ urls: {
 	nixpkgs_9682:                               "\(github)/NixOS/nixpkgs/issues/9682"
 	openssl:                                    "https://www.openssl.org/"
 	opentelemetry:                              "https://opentelemetry.io/"
+	opentelemetry_otlp_http_json:               "https://opentelemetry.io/docs/reference/specification/protocol/otlp/#json-protobuf-encoding"
 	order_of_ops:                               "\(wikipedia)/wiki/Order_of_operations"
 	papertrail:                                 "https://www.papertrail.com/"
 	papertrail_syslog:                          "https://help.papertrailapp.com/kb/how-it-works/http-api/#submitting-log-messages"
@@ -599,6 +601,9 @@ urls: {
 	vector_twitter:                             "https://twitter.com/vectordotdev"
 	vector_unit_tests:                          "\(vector_configuration)/unit-tests"
 	vector_version_branches:                    "\(vector_repo)/branches/all?query=v"
+	victorialogs:                               "https://docs.victoriametrics.com/victorialogs/"
+	victorialogs_multitenancy:                  "https://docs.victoriametrics.com/victorialogs/#multitenancy"
+	victorialogs_stream_fields:                 "https://docs.victoriametrics.com/victorialogs/keyconcepts/#stream-fields"
 	vrl_announcement:                           "/blog/vector-remap-language"
 	vrl_boolean_expression:                     "\(vrl_reference)#boolean-expressions"
 	vrl_error_handling:                         "\(vrl_errors_reference)#handling"